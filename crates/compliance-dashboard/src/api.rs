@@ -4,8 +4,9 @@
 //! handling control updates, and managing real-time connections.
 
 use axum::{
-    extract::{Path, Query, State, WebSocketUpgrade},
+    extract::{Extension, Path, Query, State, WebSocketUpgrade},
     http::StatusCode,
+    middleware,
     response::{IntoResponse, Json},
     routing::{get, post, put},
     Router,
@@ -15,29 +16,150 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tower_http::cors::CorsLayer;
-use crate::{ComplianceDashboard, ImplementationStatus, DashboardData};
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, RequestId, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
+use crate::{ComplianceDashboard, ImplementationStatus, DashboardData, OverviewDiff, VelocityBucket};
+use crate::snapshot::resolve_snapshot;
+use crate::health::{self, ReadinessChecker};
+use crate::auth::{self, ApiKeys};
+use crate::rate_limit::{self, ClientIdentity, RateLimitConfig, RateLimiter, ANONYMOUS_IDENTITY};
+use crate::telemetry::{self, PrometheusMetrics};
+use gap_analysis::GapAnalysisWorkflowResult;
+
+/// Number of velocity buckets returned when `periods` is not given
+const DEFAULT_VELOCITY_PERIODS: usize = 12;
+/// Upper bound on requested velocity buckets
+const MAX_VELOCITY_PERIODS: usize = 520;
 
 /// API state containing the dashboard instance
 pub type ApiState = Arc<RwLock<ComplianceDashboard>>;
 
+/// Shared services used by the API router besides the dashboard
+#[derive(Debug, Clone, Default)]
+pub struct ApiConfig {
+    /// Prometheus registry exported by `/metrics`
+    pub metrics: Arc<PrometheusMetrics>,
+    /// Readiness checks run by `/ready`
+    pub readiness: Arc<ReadinessChecker>,
+    /// Interval of the overview snapshots taken while the server runs; none when unset
+    pub snapshot_interval: Option<std::time::Duration>,
+    /// Interval of metrics and KPI alert refreshes while the server runs; none when unset
+    pub metrics_refresh_interval: Option<std::time::Duration>,
+    /// Per-identity limits on `/api` requests; unlimited when unset
+    pub rate_limit: Option<RateLimitConfig>,
+    /// API keys identifying `/api` callers; every caller is anonymous when empty
+    pub api_keys: Arc<ApiKeys>,
+}
+
+impl ApiConfig {
+    /// Record into a shared Prometheus registry
+    ///
+    /// Pass the same registry to `DocumentParserFactory::with_metrics` and
+    /// `GapAnalysisServiceBuilder::metrics` so parse and workflow timings are
+    /// exported by `/metrics` alongside request metrics.
+    pub fn with_metrics(mut self, metrics: Arc<PrometheusMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Check baseline readiness against the given mappings directory
+    pub fn with_mappings_dir(mut self, mappings_dir: impl Into<std::path::PathBuf>) -> Self {
+        self.readiness = Arc::new(ReadinessChecker::new(mappings_dir));
+        self
+    }
+
+    /// Snapshot the dashboard overview every `interval` while the server runs
+    pub fn with_snapshot_interval(mut self, interval: std::time::Duration) -> Self {
+        self.snapshot_interval = Some(interval);
+        self
+    }
+
+    /// Recompute metrics and KPI alerts every `interval` while the server runs
+    ///
+    /// Status updates refresh them already; this catches KPIs that change
+    /// with time alone, such as overdue controls.
+    pub fn with_metrics_refresh_interval(mut self, interval: std::time::Duration) -> Self {
+        self.metrics_refresh_interval = Some(interval);
+        self
+    }
+
+    /// Authenticate `/api` callers with the given API keys
+    pub fn with_api_keys(mut self, keys: ApiKeys) -> Self {
+        self.api_keys = Arc::new(keys);
+        self
+    }
+
+    /// Rate limit `/api` requests per caller, rejecting invalid limits
+    ///
+    /// Callers are identified by the [`ClientIdentity`] resolved from their
+    /// API key (see [`with_api_keys`](Self::with_api_keys)); callers without
+    /// one share the anonymous limit. Health, readiness and Prometheus
+    /// endpoints are not limited.
+    pub fn with_rate_limit(mut self, limits: RateLimitConfig) -> fedramp_core::Result<Self> {
+        limits.validate()?;
+        self.rate_limit = Some(limits);
+        Ok(self)
+    }
+}
+
 /// Create the API router
 pub fn create_router(dashboard: ComplianceDashboard) -> Router {
-    let state = Arc::new(RwLock::new(dashboard));
+    create_router_with_config(dashboard, ApiConfig::default())
+}
 
-    Router::new()
+/// Create the API router with shared metrics and readiness services
+pub fn create_router_with_config(dashboard: ComplianceDashboard, config: ApiConfig) -> Router {
+    router_with_state(Arc::new(RwLock::new(dashboard)), config)
+}
+
+fn router_with_state(state: ApiState, config: ApiConfig) -> Router {
+    let mut api = Router::new()
         .route("/api/dashboard", get(get_dashboard_overview))
         .route("/api/dashboard/metrics", get(get_metrics))
         .route("/api/dashboard/widgets", get(get_widgets))
+        .route("/api/dashboard/velocity", get(get_velocity))
+        .route("/api/dashboard/snapshots", get(list_snapshots).post(take_snapshot))
+        .route("/api/dashboard/diff", get(get_overview_diff))
+        .route("/api/dashboard/alerts", get(get_active_alerts))
         .route("/api/controls", get(get_controls))
         .route("/api/controls/:id", get(get_control))
         .route("/api/controls/:id/status", put(update_control_status))
+        .route("/api/controls/status/bulk", post(bulk_update_control_status))
         .route("/api/frameworks", get(get_frameworks))
         .route("/api/frameworks/:id/controls", get(get_framework_controls))
+        .route("/api/gap-analysis/assessment-results", post(export_gap_assessment_results))
         .route("/api/realtime/stats", get(get_realtime_stats))
-        .route("/api/realtime/ws", get(websocket_handler))
-        .route("/health", get(health_check))
-        .layer(CorsLayer::permissive())
+        .route("/api/realtime/ws", get(websocket_handler));
+    if let Some(limits) = config.rate_limit {
+        let limiter = Arc::new(RateLimiter::new(limits));
+        api = api.layer(middleware::from_fn_with_state(limiter, rate_limit::rate_limit));
+    }
+    // Outside the limiter, so it sees the authenticated caller
+    api = api.layer(middleware::from_fn_with_state(config.api_keys, auth::authenticate));
+
+    api.route("/health", get(health_check))
         .with_state(state)
+        .route("/ready", get(health::readiness_check).with_state(config.readiness))
+        .route("/metrics", get(telemetry::metrics_handler).with_state(config.metrics.clone()))
+        .layer(middleware::from_fn_with_state(config.metrics, telemetry::track_requests))
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(TraceLayer::new_for_http().make_span_with(request_span))
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        .layer(CorsLayer::permissive())
+}
+
+/// Span of one API request, carrying the incoming `X-Request-Id` or the UUID generated for it
+fn request_span(request: &axum::extract::Request) -> tracing::Span {
+    let request_id = request.extensions()
+        .get::<RequestId>()
+        .and_then(|id| id.header_value().to_str().ok())
+        .unwrap_or_default();
+    tracing::info_span!(
+        "http_request",
+        method = %request.method(),
+        uri = %request.uri(),
+        request_id = %request_id,
+    )
 }
 
 /// Get dashboard overview
@@ -66,6 +188,84 @@ async fn get_metrics(State(state): State<ApiState>) -> Result<Json<serde_json::V
     Ok(Json(metrics))
 }
 
+/// Get control implementation velocity
+///
+/// Query parameters: `bucket` (`week` or `month`, default `week`) and
+/// `periods` (number of buckets, default 12).
+async fn get_velocity(
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<ApiState>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let bucket = match params.get("bucket") {
+        Some(bucket) => bucket.parse::<VelocityBucket>()
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?,
+        None => VelocityBucket::Week,
+    };
+
+    let periods = match params.get("periods") {
+        Some(periods) => periods.parse::<usize>()
+            .ok()
+            .filter(|p| (1..=MAX_VELOCITY_PERIODS).contains(p))
+            .ok_or_else(|| ApiError::BadRequest(format!(
+                "periods must be between 1 and {}", MAX_VELOCITY_PERIODS
+            )))?,
+        None => DEFAULT_VELOCITY_PERIODS,
+    };
+
+    let dashboard = state.read().await;
+    let velocity = dashboard.metrics.calculate_implementation_velocity(
+        dashboard.dashboard.status_history(),
+        bucket,
+        periods,
+    );
+
+    Ok(Json(serde_json::json!({
+        "bucket": bucket.as_str(),
+        "velocity": velocity
+    })))
+}
+
+/// List overview snapshots, oldest first
+async fn list_snapshots(State(state): State<ApiState>) -> Result<Json<serde_json::Value>, ApiError> {
+    let dashboard = state.read().await;
+    let snapshots = dashboard.snapshots.list()?;
+    Ok(Json(serde_json::json!({ "snapshots": snapshots })))
+}
+
+/// Snapshot the current overview
+async fn take_snapshot(State(state): State<ApiState>) -> Result<(StatusCode, Json<serde_json::Value>), ApiError> {
+    let dashboard = state.read().await;
+    let snapshot = dashboard.take_snapshot()?;
+    Ok((StatusCode::CREATED, Json(serde_json::json!({ "id": snapshot.id, "taken_at": snapshot.taken_at }))))
+}
+
+/// Compare the overview at two points in time
+///
+/// Query parameters: `from` and `to`, each a snapshot ID or an RFC 3339
+/// timestamp selecting the latest snapshot taken at or before it.
+async fn get_overview_diff(
+    Query(params): Query<HashMap<String, String>>,
+    State(state): State<ApiState>,
+) -> Result<Json<OverviewDiff>, ApiError> {
+    let reference = |name: &str| {
+        params.get(name)
+            .ok_or_else(|| ApiError::BadRequest(format!("Missing '{}' query parameter", name)))
+    };
+    let (from, to) = (reference("from")?, reference("to")?);
+
+    let dashboard = state.read().await;
+    let before = resolve_snapshot(dashboard.snapshots.as_ref(), from)?;
+    let after = resolve_snapshot(dashboard.snapshots.as_ref(), to)?;
+    Ok(Json(OverviewDiff::between(&before, &after)))
+}
+
+/// Get the KPI alerts active as of the last metrics refresh
+async fn get_active_alerts(State(state): State<ApiState>) -> Result<Json<serde_json::Value>, ApiError> {
+    let dashboard = state.read().await;
+    let alerts = dashboard.metrics.active_kpi_alerts();
+    Ok(Json(serde_json::json!({ "alerts": alerts })))
+}
+
 /// Get widgets configuration
 async fn get_widgets(State(state): State<ApiState>) -> Result<Json<serde_json::Value>, ApiError> {
     let dashboard = state.read().await;
@@ -90,7 +290,7 @@ async fn get_controls(
     }
     
     if let Some(status) = params.get("status") {
-        controls.retain(|c| format!("{:?}", c.implementation_status).to_lowercase() == status.to_lowercase());
+        controls.retain(|c| c.implementation_status.as_str() == status.to_lowercase());
     }
     
     Ok(Json(serde_json::json!({ "controls": controls })))
@@ -116,19 +316,15 @@ async fn get_control(
 async fn update_control_status(
     Path(control_id): Path<String>,
     State(state): State<ApiState>,
+    identity: Option<Extension<ClientIdentity>>,
     Json(payload): Json<UpdateControlStatusRequest>,
 ) -> Result<Json<serde_json::Value>, ApiError> {
     let mut dashboard = state.write().await;
     
-    let status = match payload.status.as_str() {
-        "not-implemented" => ImplementationStatus::NotImplemented,
-        "in-progress" => ImplementationStatus::InProgress,
-        "implemented" => ImplementationStatus::Implemented,
-        "not-applicable" => ImplementationStatus::NotApplicable,
-        _ => return Err(ApiError::BadRequest("Invalid status".to_string())),
-    };
+    let status: ImplementationStatus = payload.status.parse()
+        .map_err(|_| ApiError::BadRequest("Invalid status".to_string()))?;
     
-    dashboard.update_control_status(&control_id, status).await
+    dashboard.update_control_status_as(&control_id, status, &request_actor(identity)).await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
     
     Ok(Json(serde_json::json!({
@@ -137,6 +333,39 @@ async fn update_control_status(
     })))
 }
 
+/// Update many control statuses at once
+async fn bulk_update_control_status(
+    State(state): State<ApiState>,
+    identity: Option<Extension<ClientIdentity>>,
+    Json(payload): Json<BulkUpdateControlStatusRequest>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let updates = payload.updates.into_iter()
+        .map(|update| {
+            update.status.parse::<ImplementationStatus>()
+                .map(|status| (update.control_id, status))
+                .map_err(|_| ApiError::BadRequest(format!("Invalid status: {}", update.status)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut dashboard = state.write().await;
+    let result = dashboard.bulk_update_control_status_as(updates, payload.skip_missing, &request_actor(identity)).await
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "applied": result.applied,
+        "skipped": result.skipped,
+        "skipped_ids": result.skipped_ids
+    })))
+}
+
+/// Actor audited for a status update: the identity [`auth::authenticate`] resolved from the API key, if any
+fn request_actor(identity: Option<Extension<ClientIdentity>>) -> String {
+    identity
+        .map(|Extension(ClientIdentity(actor))| actor)
+        .unwrap_or_else(|| ANONYMOUS_IDENTITY.to_string())
+}
+
 /// Get frameworks
 async fn get_frameworks(State(state): State<ApiState>) -> Result<Json<serde_json::Value>, ApiError> {
     let dashboard = state.read().await;
@@ -158,6 +387,16 @@ async fn get_framework_controls(
     Ok(Json(serde_json::json!({ "controls": controls })))
 }
 
+/// Convert a posted gap analysis workflow result into an OSCAL assessment results document
+async fn export_gap_assessment_results(
+    Json(workflow): Json<GapAnalysisWorkflowResult>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    let document = gap_analysis::to_assessment_results(&workflow)?;
+    serde_json::to_value(document)
+        .map(Json)
+        .map_err(|e| ApiError::Internal(e.to_string()))
+}
+
 /// Get real-time connection statistics
 async fn get_realtime_stats(State(state): State<ApiState>) -> Result<Json<serde_json::Value>, ApiError> {
     let dashboard = state.read().await;
@@ -178,7 +417,7 @@ async fn websocket_handler(
     })
 }
 
-/// Health check endpoint
+/// Liveness endpoint; see `/ready` for baseline readiness
 async fn health_check() -> Json<serde_json::Value> {
     Json(serde_json::json!({
         "status": "healthy",
@@ -193,6 +432,22 @@ struct UpdateControlStatusRequest {
     status: String,
 }
 
+/// Request payload for bulk control status updates
+#[derive(Debug, Deserialize)]
+struct BulkUpdateControlStatusRequest {
+    updates: Vec<BulkControlStatus>,
+    /// Skip unknown control IDs instead of rejecting the whole batch
+    #[serde(default)]
+    skip_missing: bool,
+}
+
+/// Single entry of a bulk control status update
+#[derive(Debug, Deserialize)]
+struct BulkControlStatus {
+    control_id: String,
+    status: String,
+}
+
 /// API error types
 #[derive(Debug)]
 enum ApiError {
@@ -201,6 +456,16 @@ enum ApiError {
     Internal(String),
 }
 
+impl From<fedramp_core::Error> for ApiError {
+    fn from(error: fedramp_core::Error) -> Self {
+        match error.status_code() {
+            404 => ApiError::NotFound(error.to_string()),
+            400 => ApiError::BadRequest(error.to_string()),
+            _ => ApiError::Internal(error.to_string()),
+        }
+    }
+}
+
 impl IntoResponse for ApiError {
     fn into_response(self) -> axum::response::Response {
         let (status, message) = match self {
@@ -220,7 +485,23 @@ impl IntoResponse for ApiError {
 
 /// Start the API server
 pub async fn start_server(dashboard: ComplianceDashboard, port: u16) -> Result<(), Box<dyn std::error::Error>> {
-    let app = create_router(dashboard);
+    start_server_with_config(dashboard, port, ApiConfig::default()).await
+}
+
+/// Start the API server with shared metrics and readiness services
+pub async fn start_server_with_config(
+    dashboard: ComplianceDashboard,
+    port: u16,
+    config: ApiConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let state = Arc::new(RwLock::new(dashboard));
+    if let Some(interval) = config.snapshot_interval {
+        spawn_snapshot_task(state.clone(), interval);
+    }
+    if let Some(interval) = config.metrics_refresh_interval {
+        spawn_metrics_refresh_task(state.clone(), interval);
+    }
+    let app = router_with_state(state, config);
     let addr = format!("0.0.0.0:{}", port);
     
     println!("🚀 Compliance Dashboard API server starting on {}", addr);
@@ -230,3 +511,288 @@ pub async fn start_server(dashboard: ComplianceDashboard, port: u16) -> Result<(
     
     Ok(())
 }
+
+/// Snapshot the dashboard overview every `interval`, starting now
+pub fn spawn_snapshot_task(state: ApiState, interval: std::time::Duration) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = state.read().await.take_snapshot() {
+                tracing::warn!("Failed to snapshot dashboard overview: {}", e);
+            }
+        }
+    })
+}
+
+/// Recompute metrics and broadcast KPI alert changes every `interval`, starting now
+pub fn spawn_metrics_refresh_task(state: ApiState, interval: std::time::Duration) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = state.write().await.refresh_metrics().await {
+                tracing::warn!("Failed to refresh dashboard metrics: {}", e);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::extract::Request;
+    use tower::ServiceExt;
+
+    async fn send(router: &Router, request: Request) -> (StatusCode, serde_json::Value) {
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_diff_between_snapshots() {
+        let state = Arc::new(RwLock::new(ComplianceDashboard::with_sample_data()));
+        let router = router_with_state(state.clone(), ApiConfig::default());
+
+        let before = state.read().await.take_snapshot().unwrap();
+        state.write().await.update_control_status("ac-3", ImplementationStatus::InProgress).await.unwrap();
+        let (status, after) = send(&router, Request::post("/api/dashboard/snapshots").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let uri = format!("/api/dashboard/diff?from={}&to={}", before.id, after["id"].as_str().unwrap());
+        let (status, diff) = send(&router, Request::get(uri).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(diff["control_changes"][0]["control_id"], "ac-3");
+        assert_eq!(diff["control_changes"][0]["before"], "not-implemented");
+        assert_eq!(diff["control_changes"][0]["after"], "in-progress");
+
+        let (status, _) = send(&router, Request::get("/api/dashboard/diff?from=missing&to=missing").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&router, Request::get("/api/dashboard/diff?from=missing").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_alerts_endpoint_does_not_recompute_or_broadcast() {
+        let mut dashboard = ComplianceDashboard::with_sample_data();
+        dashboard.metrics.add_kpi_alert_rule(crate::KpiAlertRule {
+            id: "low-implementation".to_string(),
+            metric: "implementation-rate".to_string(),
+            operator: crate::ComparisonOperator::LessThan,
+            threshold: 100.0,
+            severity: crate::realtime::AlertSeverity::Warning,
+        }).unwrap();
+        let mut events = dashboard.realtime.get_event_receiver();
+        let state = Arc::new(RwLock::new(dashboard));
+        let router = router_with_state(state.clone(), ApiConfig::default());
+
+        let (status, body) = send(&router, Request::get("/api/dashboard/alerts").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["alerts"].as_array().unwrap().len(), 0);
+        assert!(events.try_recv().is_err());
+
+        state.write().await.refresh_metrics().await.unwrap();
+        let (_, body) = send(&router, Request::get("/api/dashboard/alerts").body(Body::empty()).unwrap()).await;
+        assert_eq!(body["alerts"].as_array().unwrap().len(), 1);
+    }
+
+    /// Records the `request_id` field of every new span
+    #[derive(Clone, Default)]
+    struct RequestIdRecorder {
+        request_ids: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+
+    struct RequestIdVisitor<'a>(&'a mut Vec<String>);
+
+    impl tracing::field::Visit for RequestIdVisitor<'_> {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "request_id" {
+                self.0.push(format!("{:?}", value));
+            }
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for RequestIdRecorder {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            _id: &tracing::span::Id,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            attrs.record(&mut RequestIdVisitor(&mut self.request_ids.lock().unwrap()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_id_is_propagated_and_traced() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let recorder = RequestIdRecorder::default();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+        let router = create_router(ComplianceDashboard::with_sample_data());
+
+        let request = Request::get("/health").header("x-request-id", "req-7f3a").body(Body::empty()).unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.headers()["x-request-id"], "req-7f3a");
+
+        let response = router.clone().oneshot(Request::get("/health").body(Body::empty()).unwrap()).await.unwrap();
+        let generated = response.headers()["x-request-id"].to_str().unwrap().to_string();
+        assert!(uuid::Uuid::parse_str(&generated).is_ok());
+
+        assert_eq!(*recorder.request_ids.lock().unwrap(), ["req-7f3a".to_string(), generated]);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_applies_to_api_routes_only() {
+        let limits = RateLimitConfig {
+            default: crate::RateLimit { requests: 2, window_seconds: 60 },
+            keys: HashMap::new(),
+        };
+        let config = ApiConfig::default().with_rate_limit(limits).unwrap();
+        let router = create_router_with_config(ComplianceDashboard::with_sample_data(), config);
+        let get = |uri: &str| Request::get(uri).body(Body::empty()).unwrap();
+
+        assert_eq!(send(&router, get("/api/frameworks")).await.0, StatusCode::OK);
+        assert_eq!(send(&router, get("/api/controls")).await.0, StatusCode::OK);
+        assert_eq!(send(&router, get("/api/frameworks")).await.0, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(send(&router, get("/health")).await.0, StatusCode::OK);
+
+        let zero = RateLimitConfig {
+            default: crate::RateLimit { requests: 0, window_seconds: 60 },
+            keys: HashMap::new(),
+        };
+        assert!(ApiConfig::default().with_rate_limit(zero).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_api_key_callers_are_rate_limited_separately() {
+        let limits = RateLimitConfig {
+            default: crate::RateLimit { requests: 2, window_seconds: 60 },
+            keys: HashMap::new(),
+        };
+        let keys = ApiKeys::new([
+            ("alice-key".to_string(), "alice".to_string()),
+            ("bob-key".to_string(), "bob".to_string()),
+        ]).unwrap();
+        let config = ApiConfig::default().with_api_keys(keys).with_rate_limit(limits).unwrap();
+        let router = create_router_with_config(ComplianceDashboard::with_sample_data(), config);
+        let bearer = |token: &str| {
+            Request::get("/api/frameworks").header("authorization", format!("Bearer {}", token)).body(Body::empty()).unwrap()
+        };
+        let api_key = |key: &str| Request::get("/api/frameworks").header("x-api-key", key).body(Body::empty()).unwrap();
+
+        assert_eq!(send(&router, bearer("alice-key")).await.0, StatusCode::OK);
+        assert_eq!(send(&router, api_key("alice-key")).await.0, StatusCode::OK);
+        assert_eq!(send(&router, bearer("alice-key")).await.0, StatusCode::TOO_MANY_REQUESTS);
+
+        // Alice's exhausted window leaves bob and anonymous callers their own
+        assert_eq!(send(&router, bearer("bob-key")).await.0, StatusCode::OK);
+        assert_eq!(send(&router, api_key("bob-key")).await.0, StatusCode::OK);
+        assert_eq!(send(&router, Request::get("/api/frameworks").body(Body::empty()).unwrap()).await.0, StatusCode::OK);
+
+        assert_eq!(send(&router, bearer("unknown-key")).await.0, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_status_updates_audit_the_caller() {
+        let state = Arc::new(RwLock::new(ComplianceDashboard::with_sample_data()));
+        let keys = ApiKeys::new([
+            ("alice-key".to_string(), "alice".to_string()),
+            ("ingest-key".to_string(), "bulk-ingest".to_string()),
+        ]).unwrap();
+        let router = router_with_state(state.clone(), ApiConfig::default().with_api_keys(keys));
+        let request = |method: &str, uri: &str, credential: Option<(&str, String)>, body: serde_json::Value| {
+            let mut builder = Request::builder().method(method).uri(uri).header("content-type", "application/json");
+            if let Some((name, value)) = credential {
+                builder = builder.header(name, value);
+            }
+            builder.body(Body::from(body.to_string())).unwrap()
+        };
+        let single = serde_json::json!({ "status": "implemented" });
+        let bulk = serde_json::json!({ "updates": [{ "control_id": "ac-3", "status": "in-progress" }] });
+
+        let alice = Some(("x-api-key", "alice-key".to_string()));
+        assert_eq!(send(&router, request("PUT", "/api/controls/ac-2/status", alice, single.clone())).await.0, StatusCode::OK);
+        let ingest = Some(("authorization", "Bearer ingest-key".to_string()));
+        assert_eq!(send(&router, request("POST", "/api/controls/status/bulk", ingest, bulk.clone())).await.0, StatusCode::OK);
+        assert_eq!(send(&router, request("POST", "/api/controls/status/bulk", None, bulk)).await.0, StatusCode::OK);
+
+        // A rejected key changes nothing and leaves no audit record
+        let forged = Some(("x-api-key", "forged-key".to_string()));
+        assert_eq!(send(&router, request("PUT", "/api/controls/ac-2/status", forged, single)).await.0, StatusCode::UNAUTHORIZED);
+
+        let entries = state.read().await.dashboard.query_audit_log(&crate::AuditFilter::default()).unwrap().items;
+        let actors: Vec<&str> = entries.iter().map(|entry| entry.metadata["actor"].as_str()).collect();
+        assert_eq!(actors, ["alice", "bulk-ingest", ANONYMOUS_IDENTITY]);
+    }
+
+    #[tokio::test]
+    async fn test_gap_analysis_workflow_exports_as_assessment_results() {
+        use gap_analysis::engine::{
+            BaselineMetadata, ControlImplementation, CurrentImplementation, ParameterRequirement, RequiredControl,
+            TargetBaseline,
+        };
+        use gap_analysis::{GapAnalysisEngine, PrioritizationEngine, WorkflowMetadata};
+
+        // AC-2 is partially implemented without the baseline's required parameter, a parameter gap
+        let current = CurrentImplementation {
+            system_id: "payments".to_string(),
+            controls: HashMap::from([("AC-2".to_string(), ControlImplementation {
+                control_id: "AC-2".to_string(),
+                status: gap_analysis::ImplementationStatus::PartiallyImplemented,
+                implementation_date: None,
+                evidence: Vec::new(),
+                parameters: HashMap::new(),
+                completion_fraction: None,
+                last_modified: None,
+            })]),
+            last_updated: chrono::Utc::now(),
+        };
+        let baseline = TargetBaseline {
+            framework_id: "nist-800-53".to_string(),
+            profile_name: "moderate".to_string(),
+            required_controls: HashMap::from([("AC-2".to_string(), RequiredControl {
+                control_id: "AC-2".to_string(),
+                required_status: gap_analysis::ImplementationStatus::Implemented,
+                enhancements: Vec::new(),
+                parameters: HashMap::from([("ac-2_prm_1".to_string(), ParameterRequirement {
+                    required_value: "90 days".to_string(),
+                    validation_rule: String::new(),
+                })]),
+            })]),
+            baseline_metadata: BaselineMetadata { version: "1.0".to_string(), last_updated: chrono::Utc::now() },
+        };
+        let analysis_result = GapAnalysisEngine::new().analyze_gaps(&current, &baseline).await.unwrap();
+        let prioritization = PrioritizationEngine::new();
+        let prioritized_gaps = prioritization.prioritize_gaps(&analysis_result.gaps).await.unwrap();
+        let workflow = GapAnalysisWorkflowResult {
+            prioritization_matrix: prioritization.generate_prioritization_matrix(&prioritized_gaps).unwrap(),
+            analysis_result,
+            prioritized_gaps,
+            remediation_plan: None,
+            workflow_metadata: WorkflowMetadata {
+                workflow_id: "workflow-1".to_string(),
+                execution_time_ms: 0,
+                steps_completed: Vec::new(),
+                warnings: Vec::new(),
+                performance_metrics: HashMap::new(),
+            },
+        };
+
+        let router = create_router(ComplianceDashboard::with_sample_data());
+        let request = Request::post("/api/gap-analysis/assessment-results")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&workflow).unwrap()))
+            .unwrap();
+        let (status, document) = send(&router, request).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let results = &document["assessment-results"];
+        assert_eq!(results["results"][0]["findings"][0]["target"]["target-id"], "ac-2");
+        let plan_uuid = results["back-matter"]["resources"][0]["uuid"].as_str().unwrap();
+        assert_eq!(results["import-ap"]["href"], format!("#{}", plan_uuid));
+    }
+}
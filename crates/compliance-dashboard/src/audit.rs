@@ -0,0 +1,206 @@
+// Modified: 2025-09-24
+
+//! Durable audit trail of control status changes
+//!
+//! Real-time events tell connected clients that a status changed; audit
+//! records keep who changed which control, from what to what, and when.
+//! `DashboardService` writes one record per applied update to an
+//! [`AuditSink`], which defaults to an in-memory store. When the sink is an
+//! [`AuditStore`] it also answers queries such as "all changes by this user
+//! in the last 30 days".
+
+use crate::dashboard::ImplementationStatus;
+use chrono::{DateTime, Utc};
+use fedramp_core::models::audit::{AuditCategory, AuditEventType, AuditLogEntry};
+use fedramp_core::types::{Page, PageRequest};
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+/// Actor recorded for updates made without a named user
+pub const SYSTEM_ACTOR: &str = "system";
+
+/// Destination of audit records
+///
+/// Implement this to persist records to a database or log pipeline. A
+/// failed write fails the update that produced it.
+pub trait AuditSink: Send + Sync + std::fmt::Debug {
+    /// Persist one audit record
+    fn record(&self, entry: AuditLogEntry) -> Result<()>;
+}
+
+/// Audit sink that can also be queried
+pub trait AuditStore: AuditSink {
+    /// Records matching a filter, oldest first, one page at a time
+    fn query(&self, filter: &AuditFilter) -> Result<Page<AuditLogEntry>>;
+}
+
+/// Criteria for querying audit records
+///
+/// Unset criteria match every record. The time range includes `since` and
+/// excludes `until`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditFilter {
+    /// User or service that made the change
+    pub actor: Option<String>,
+    /// Control the change applied to
+    pub control_id: Option<String>,
+    /// Earliest change time to include
+    pub since: Option<DateTime<Utc>>,
+    /// Change time to stop before
+    pub until: Option<DateTime<Utc>>,
+    /// Kind of change
+    pub change_type: Option<AuditEventType>,
+    /// Page of matching records to return
+    #[serde(default)]
+    pub page: PageRequest,
+}
+
+impl AuditFilter {
+    /// Whether a record meets every set criterion
+    pub fn matches(&self, entry: &AuditLogEntry) -> bool {
+        let metadata_is = |key: &str, expected: &Option<String>| {
+            expected.as_ref().is_none_or(|expected| entry.metadata.get(key) == Some(expected))
+        };
+
+        metadata_is("actor", &self.actor)
+            && metadata_is("control_id", &self.control_id)
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && self.until.is_none_or(|until| entry.timestamp < until)
+            && self.change_type.is_none_or(|change_type| entry.event_type == change_type)
+    }
+}
+
+/// Audit sink keeping records in memory
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAuditSink {
+    entries: Arc<Mutex<Vec<AuditLogEntry>>>,
+}
+
+impl InMemoryAuditSink {
+    /// Create an empty sink
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records written so far, oldest first
+    pub fn entries(&self) -> Vec<AuditLogEntry> {
+        self.entries.lock().map(|entries| entries.clone()).unwrap_or_default()
+    }
+}
+
+impl AuditSink for InMemoryAuditSink {
+    fn record(&self, entry: AuditLogEntry) -> Result<()> {
+        self.entries
+            .lock()
+            .map_err(|_| Error::internal("Audit log lock poisoned"))?
+            .push(entry);
+        Ok(())
+    }
+}
+
+impl AuditStore for InMemoryAuditSink {
+    fn query(&self, filter: &AuditFilter) -> Result<Page<AuditLogEntry>> {
+        let matching = self.entries
+            .lock()
+            .map_err(|_| Error::internal("Audit log lock poisoned"))?
+            .iter()
+            .filter(|entry| filter.matches(entry))
+            .cloned()
+            .collect();
+        Page::paginate(matching, &filter.page)
+    }
+}
+
+/// Audit record of a control status update
+///
+/// The actor and control ID are kept in `metadata`; the old and new status in
+/// the event details.
+pub fn status_change_entry(
+    actor: &str,
+    control_id: &str,
+    framework_id: &str,
+    old_status: &ImplementationStatus,
+    new_status: &ImplementationStatus,
+    changed_at: DateTime<Utc>,
+) -> AuditLogEntry {
+    let mut entry = AuditLogEntry::new(
+        AuditEventType::DataModification,
+        AuditCategory::ControlAssessment,
+        "update_control_status".to_string(),
+        format!(
+            "{} changed control {} from {} to {}",
+            actor,
+            control_id,
+            old_status.as_str(),
+            new_status.as_str()
+        ),
+        None,
+    )
+    .with_resource("control".to_string(), None)
+    .with_metadata("actor".to_string(), actor.to_string())
+    .with_metadata("control_id".to_string(), control_id.to_string())
+    .with_metadata("framework_id".to_string(), framework_id.to_string());
+
+    entry.details.previous_values = Some(json!({ "implementation_status": old_status.as_str() }));
+    entry.details.new_values = Some(json!({ "implementation_status": new_status.as_str() }));
+    entry.details.changed_fields = vec!["implementation_status".to_string()];
+    entry.timestamp = changed_at;
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_query_by_actor_and_time_window() {
+        let store = InMemoryAuditSink::new();
+        let now = Utc::now();
+        let changes = [
+            ("alice", "ac-2", 40),
+            ("alice", "ac-3", 20),
+            ("bob", "ac-2", 10),
+            ("alice", "ac-6", 5),
+            ("alice", "ac-7", 1),
+        ];
+        for (actor, control_id, days_ago) in changes {
+            let entry = status_change_entry(
+                actor,
+                control_id,
+                "nist-800-53",
+                &ImplementationStatus::InProgress,
+                &ImplementationStatus::Implemented,
+                now - Duration::days(days_ago),
+            );
+            store.record(entry).unwrap();
+        }
+
+        let filter = AuditFilter {
+            actor: Some("alice".to_string()),
+            since: Some(now - Duration::days(30)),
+            until: Some(now - Duration::days(2)),
+            ..AuditFilter::default()
+        };
+        let page = store.query(&filter).unwrap();
+        let controls: Vec<&str> = page.items.iter().map(|entry| entry.metadata["control_id"].as_str()).collect();
+        assert_eq!(controls, ["ac-3", "ac-6"]);
+        assert_eq!(page.total, 2);
+
+        let first = store.query(&AuditFilter {
+            actor: Some("alice".to_string()),
+            page: PageRequest::new(0, 2),
+            ..AuditFilter::default()
+        }).unwrap();
+        assert_eq!(first.total, 4);
+        assert!(first.has_more());
+
+        let by_type = store.query(&AuditFilter {
+            change_type: Some(AuditEventType::Authentication),
+            ..AuditFilter::default()
+        }).unwrap();
+        assert!(by_type.items.is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+// Modified: 2025-09-24
+
+//! API key authentication
+//!
+//! Resolves the caller of each `/api` request from an `Authorization: Bearer`
+//! token or an `X-Api-Key` header and inserts it as a [`ClientIdentity`], which
+//! the rate limiter and the audit trail key on. Requests without a credential
+//! proceed as [`ANONYMOUS_IDENTITY`]; a credential matching no configured key
+//! is rejected with `401 Unauthorized`.
+
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::rate_limit::{ClientIdentity, ANONYMOUS_IDENTITY};
+
+/// Header carrying an API key when no bearer token is sent
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// API keys and the identity each one authenticates as
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApiKeys {
+    /// Key to identity, e.g. `{"3f9c...": "bulk-ingest"}`
+    #[serde(default)]
+    pub keys: HashMap<String, String>,
+}
+
+impl ApiKeys {
+    /// Keys from key to identity pairs
+    pub fn new(keys: impl IntoIterator<Item = (String, String)>) -> Result<Self> {
+        let keys = Self { keys: keys.into_iter().collect() };
+        keys.validate()?;
+        Ok(keys)
+    }
+
+    /// Load the keys from a JSON file
+    pub fn from_file(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::configuration(format!("Failed to read API keys {}: {}", path, e)))?;
+        let keys: Self = serde_json::from_str(&content)
+            .map_err(|e| Error::configuration(format!("Invalid API keys {}: {}", path, e)))?;
+        keys.validate()?;
+        Ok(keys)
+    }
+
+    /// Check that keys and identities are non-empty and no key claims the anonymous identity
+    pub fn validate(&self) -> Result<()> {
+        for (key, identity) in &self.keys {
+            if key.trim().is_empty() || identity.trim().is_empty() {
+                return Err(Error::configuration("API keys and their identities must not be empty"));
+            }
+            if identity == ANONYMOUS_IDENTITY {
+                return Err(Error::configuration(format!(
+                    "API keys cannot authenticate as the '{}' identity", ANONYMOUS_IDENTITY
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Identity a credential authenticates as
+    pub fn identity_for(&self, credential: &str) -> Option<ClientIdentity> {
+        self.keys.get(credential).map(|identity| ClientIdentity(identity.clone()))
+    }
+}
+
+/// Credential of a request: a bearer token, else the `X-Api-Key` header
+fn credential(request: &Request) -> Option<&str> {
+    let headers = request.headers();
+    headers.get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .or_else(|| headers.get(API_KEY_HEADER).and_then(|value| value.to_str().ok()))
+        .map(str::trim)
+}
+
+/// Middleware setting the request's [`ClientIdentity`] from its credential
+///
+/// Any identity already in the extensions is discarded, so only a configured
+/// key can name the caller.
+pub async fn authenticate(
+    State(keys): State<Arc<ApiKeys>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    request.extensions_mut().remove::<ClientIdentity>();
+    if let Some(credential) = credential(&request) {
+        let Some(identity) = keys.identity_for(credential) else {
+            let body = Json(serde_json::json!({
+                "error": "Invalid API key",
+                "status": StatusCode::UNAUTHORIZED.as_u16()
+            }));
+            return (StatusCode::UNAUTHORIZED, [(header::WWW_AUTHENTICATE, "Bearer")], body).into_response();
+        };
+        request.extensions_mut().insert(identity);
+    }
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_reject_empty_and_anonymous_identities() {
+        assert!(ApiKeys::new([("key-1".to_string(), "alice".to_string())]).is_ok());
+        assert!(ApiKeys::new([("key-1".to_string(), " ".to_string())]).is_err());
+        assert!(ApiKeys::new([("key-1".to_string(), ANONYMOUS_IDENTITY.to_string())]).is_err());
+    }
+}
@@ -3,7 +3,7 @@
 //! A standalone server for the FedRAMP compliance dashboard that serves
 //! both the API endpoints and static frontend files.
 
-use compliance_dashboard::{ComplianceDashboard, start_server};
+use compliance_dashboard::{ApiConfig, ApiKeys, ComplianceDashboard, RateLimitConfig, start_server_with_config};
 use std::env;
 
 #[tokio::main]
@@ -20,6 +20,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .parse::<u16>()
         .unwrap_or(8080);
 
+    // Baseline data checked by the readiness endpoint
+    let mappings_dir = env::var("MAPPINGS_DIR").unwrap_or_else(|_| "mappings".to_string());
+
     // Create dashboard with sample data
     let mut dashboard = ComplianceDashboard::with_sample_data();
     
@@ -38,7 +41,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   GET  /api/frameworks/:id/controls - Framework controls");
     println!("   GET  /api/realtime/stats   - Real-time connection stats");
     println!("   GET  /api/realtime/ws      - WebSocket endpoint");
-    println!("   GET  /health               - Health check");
+    println!("   GET  /health               - Liveness check");
+    println!("   GET  /ready                - Readiness check (baseline data)");
+    println!("   GET  /metrics              - Prometheus metrics");
     println!();
     println!("🌐 Frontend available at: http://localhost:3000");
     println!("🔌 API available at: http://localhost:{}", port);
@@ -50,8 +55,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   • WebSocket support for live updates");
     println!();
 
+    let mut config = ApiConfig::default().with_mappings_dir(mappings_dir);
+
+    // Callers identified by API key, when a keys file is given
+    if let Ok(path) = env::var("API_KEYS_CONFIG") {
+        config = config.with_api_keys(ApiKeys::from_file(&path)?);
+        println!("🔑 API keys loaded from {}", path);
+    }
+
+    // Per-identity request limits, when a limits file is given
+    if let Ok(path) = env::var("RATE_LIMIT_CONFIG") {
+        config = config.with_rate_limit(RateLimitConfig::from_file(&path)?)?;
+        println!("🚦 Rate limits loaded from {}", path);
+    }
+
     // Start the server
-    start_server(dashboard, port).await?;
+    start_server_with_config(dashboard, port, config).await?;
 
     Ok(())
 }
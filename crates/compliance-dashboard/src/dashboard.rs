@@ -3,9 +3,14 @@
 //! This module provides the main dashboard service for managing compliance data,
 //! control status tracking, and real-time updates.
 
+use crate::audit::{status_change_entry, AuditFilter, AuditSink, AuditStore, InMemoryAuditSink, SYSTEM_ACTOR};
+use fedramp_core::clock::{system_clock, SharedClock};
+use fedramp_core::models::audit::AuditLogEntry;
+use fedramp_core::types::Page;
 use fedramp_core::{Result, Error};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 
@@ -18,6 +23,14 @@ pub struct DashboardService {
     controls: HashMap<String, Control>,
     /// Metrics cache
     metrics: HashMap<String, Metric>,
+    /// Status transitions in the order they were applied
+    status_history: Vec<StatusChange>,
+    /// Destination of status change audit records
+    audit_sink: Arc<dyn AuditSink>,
+    /// Queryable store of the audit records, unless they go to a write-only sink
+    audit_store: Option<Arc<dyn AuditStore>>,
+    /// Time source for status changes and update timestamps
+    clock: SharedClock,
     /// Last update timestamp
     last_updated: DateTime<Utc>,
 }
@@ -54,6 +67,29 @@ pub struct Control {
     pub last_updated: DateTime<Utc>,
 }
 
+/// Transition of a control from one implementation status to another
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub control_id: String,
+    pub framework_id: String,
+    pub old_status: ImplementationStatus,
+    pub new_status: ImplementationStatus,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Outcome of a bulk control status update
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkUpdateResult {
+    /// Number of updates applied to known controls
+    pub applied: usize,
+    /// Number of updates skipped because the control is unknown
+    pub skipped: usize,
+    /// Control IDs that were skipped
+    pub skipped_ids: Vec<String>,
+    /// Status transitions caused by the update, in application order
+    pub changes: Vec<StatusChange>,
+}
+
 /// Implementation status enumeration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
@@ -64,6 +100,52 @@ pub enum ImplementationStatus {
     NotApplicable,
 }
 
+impl ImplementationStatus {
+    /// Stable kebab-case identifier, matching the serde representation
+    pub fn as_str(&self) -> &'static str {
+        fedramp_core::types::ImplementationStatus::from(self.clone()).as_str()
+    }
+}
+
+impl From<ImplementationStatus> for fedramp_core::types::ImplementationStatus {
+    fn from(status: ImplementationStatus) -> Self {
+        match status {
+            ImplementationStatus::NotImplemented => Self::NotImplemented,
+            ImplementationStatus::InProgress => Self::InProgress,
+            ImplementationStatus::Implemented => Self::Implemented,
+            ImplementationStatus::NotApplicable => Self::NotApplicable,
+        }
+    }
+}
+
+impl TryFrom<fedramp_core::types::ImplementationStatus> for ImplementationStatus {
+    type Error = Error;
+
+    fn try_from(status: fedramp_core::types::ImplementationStatus) -> Result<Self> {
+        use fedramp_core::types::ImplementationStatus as Shared;
+        match status {
+            Shared::NotImplemented => Ok(Self::NotImplemented),
+            Shared::InProgress => Ok(Self::InProgress),
+            Shared::Implemented => Ok(Self::Implemented),
+            Shared::NotApplicable => Ok(Self::NotApplicable),
+            Shared::Planned | Shared::PartiallyImplemented | Shared::AlternativeImplementation => {
+                Err(Error::validation(format!(
+                    "Implementation status '{}' is not tracked by the dashboard",
+                    status
+                )))
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for ImplementationStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.parse::<fedramp_core::types::ImplementationStatus>()?.try_into()
+    }
+}
+
 /// Priority levels
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -111,14 +193,52 @@ pub struct DashboardOverview {
 impl DashboardService {
     /// Create a new dashboard service
     pub fn new() -> Self {
+        let audit_store = Arc::new(InMemoryAuditSink::new());
+        let clock = system_clock();
         Self {
             frameworks: HashMap::new(),
             controls: HashMap::new(),
             metrics: HashMap::new(),
-            last_updated: Utc::now(),
+            status_history: Vec::new(),
+            audit_sink: audit_store.clone(),
+            audit_store: Some(audit_store),
+            last_updated: clock.now(),
+            clock,
         }
     }
 
+    /// Stamp status changes and updates with `clock` instead of the system time
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Write status change audit records to `sink`, e.g. a database or log pipeline
+    ///
+    /// The records can no longer be queried through [`query_audit_log`](Self::query_audit_log);
+    /// use [`with_audit_store`](Self::with_audit_store) for a sink that can answer queries.
+    pub fn with_audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = sink;
+        self.audit_store = None;
+        self
+    }
+
+    /// Keep status change audit records in `store` instead of memory and query them from it
+    pub fn with_audit_store<S: AuditStore + 'static>(mut self, store: Arc<S>) -> Self {
+        self.audit_sink = store.clone();
+        self.audit_store = Some(store);
+        self
+    }
+
+    /// Audit records of status changes matching a filter
+    ///
+    /// Fails when the records go to a write-only [`AuditSink`].
+    pub fn query_audit_log(&self, filter: &AuditFilter) -> Result<Page<AuditLogEntry>> {
+        self.audit_store.as_ref()
+            .ok_or_else(|| Error::configuration("Audit records are written to a sink that cannot be queried"))?
+            .query(filter)
+    }
+
     /// Initialize with sample data for demonstration
     pub fn with_sample_data() -> Self {
         let mut service = Self::new();
@@ -175,16 +295,144 @@ impl DashboardService {
         Ok(controls)
     }
 
-    /// Update control status
-    pub fn update_control_status(&mut self, control_id: &str, status: ImplementationStatus) -> Result<()> {
-        if let Some(control) = self.controls.get_mut(control_id) {
-            control.implementation_status = status;
-            control.last_updated = Utc::now();
-            self.last_updated = Utc::now();
-            Ok(())
-        } else {
-            Err(Error::not_found(format!("Control not found: {}", control_id)))
+    /// Control with the given ID
+    pub fn control(&self, control_id: &str) -> Option<&Control> {
+        self.controls.get(control_id)
+    }
+
+    /// All controls, in no particular order
+    pub fn controls(&self) -> Vec<Control> {
+        self.controls.values().cloned().collect()
+    }
+
+    /// Add a control, replacing any existing control with the same ID
+    pub fn upsert_control(&mut self, control: Control) {
+        self.controls.insert(control.id.clone(), control);
+        self.last_updated = self.clock.now();
+    }
+
+    /// Update control status, returning the status it had before the update
+    ///
+    /// Actual transitions (old and new status differ) are appended to the status history.
+    pub fn update_control_status(&mut self, control_id: &str, status: ImplementationStatus) -> Result<ImplementationStatus> {
+        self.update_control_status_as(control_id, status, SYSTEM_ACTOR)
+    }
+
+    /// Update control status on behalf of `actor`, returning the status it had before the update
+    ///
+    /// Every applied update is written to the audit store with the actor.
+    pub fn update_control_status_as(
+        &mut self,
+        control_id: &str,
+        status: ImplementationStatus,
+        actor: &str,
+    ) -> Result<ImplementationStatus> {
+        let now = self.clock.now();
+        let (old_status, _) = self.apply_status(control_id, status, actor, now)?;
+        self.last_updated = now;
+        Ok(old_status)
+    }
+
+    /// Apply many status updates at once
+    ///
+    /// Unknown control IDs fail the whole batch without applying anything,
+    /// unless `skip_missing` is set, in which case they are skipped and reported.
+    pub fn bulk_update(
+        &mut self,
+        updates: Vec<(String, ImplementationStatus)>,
+        skip_missing: bool,
+    ) -> Result<BulkUpdateResult> {
+        self.bulk_update_as(updates, skip_missing, SYSTEM_ACTOR)
+    }
+
+    /// Apply many status updates at once on behalf of `actor`
+    ///
+    /// See [`DashboardService::bulk_update`]. A failed audit write stops the
+    /// batch; updates before it stay applied and audited.
+    pub fn bulk_update_as(
+        &mut self,
+        updates: Vec<(String, ImplementationStatus)>,
+        skip_missing: bool,
+        actor: &str,
+    ) -> Result<BulkUpdateResult> {
+        let missing: Vec<String> = updates.iter()
+            .filter(|(control_id, _)| !self.controls.contains_key(control_id))
+            .map(|(control_id, _)| control_id.clone())
+            .collect();
+
+        if !missing.is_empty() && !skip_missing {
+            return Err(Error::not_found(format!(
+                "Bulk update rejected, {} unknown control(s): {}",
+                missing.len(),
+                missing.join(", ")
+            )));
+        }
+
+        let now = self.clock.now();
+        let mut result = BulkUpdateResult {
+            skipped: missing.len(),
+            skipped_ids: missing,
+            ..Default::default()
+        };
+
+        for (control_id, status) in updates {
+            if !self.controls.contains_key(&control_id) {
+                continue;
+            }
+            let (_, change) = self.apply_status(&control_id, status, actor, now)?;
+            result.applied += 1;
+            result.changes.extend(change);
+        }
+
+        if result.applied > 0 {
+            self.last_updated = now;
+        }
+
+        Ok(result)
+    }
+
+    /// Audit the update, then set a control's status and record the transition, if any
+    ///
+    /// The audit record is written first, so a failed write leaves the control unchanged.
+    fn apply_status(
+        &mut self,
+        control_id: &str,
+        status: ImplementationStatus,
+        actor: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(ImplementationStatus, Option<StatusChange>)> {
+        let control = self.controls.get_mut(control_id)
+            .ok_or_else(|| Error::not_found(format!("Control not found: {}", control_id)))?;
+
+        self.audit_sink.record(status_change_entry(
+            actor,
+            control_id,
+            &control.framework_id,
+            &control.implementation_status,
+            &status,
+            now,
+        ))?;
+
+        let old_status = std::mem::replace(&mut control.implementation_status, status.clone());
+        control.last_updated = now;
+
+        let change = (old_status != status).then(|| StatusChange {
+            control_id: control_id.to_string(),
+            framework_id: control.framework_id.clone(),
+            old_status: old_status.clone(),
+            new_status: status,
+            changed_at: now,
+        });
+        if let Some(change) = &change {
+            self.status_history.push(change.clone());
         }
+
+        Ok((old_status, change))
+    }
+
+    /// Get the recorded status transitions, oldest first
+    pub fn status_history(&self) -> &[StatusChange] {
+        &self.status_history
     }
 
     /// Load sample data for demonstration
@@ -303,3 +551,160 @@ impl Default for DashboardService {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_implementation_status_round_trips_through_shared_status() {
+        let statuses = [
+            ImplementationStatus::NotImplemented,
+            ImplementationStatus::InProgress,
+            ImplementationStatus::Implemented,
+            ImplementationStatus::NotApplicable,
+        ];
+        for status in statuses {
+            let shared = fedramp_core::types::ImplementationStatus::from(status.clone());
+            assert_eq!(ImplementationStatus::try_from(shared).unwrap(), status);
+            assert_eq!(status.as_str().parse::<ImplementationStatus>().unwrap(), status);
+            assert_eq!(
+                serde_json::to_value(&status).unwrap(),
+                serde_json::Value::String(status.as_str().to_string())
+            );
+        }
+
+        assert!(ImplementationStatus::try_from(fedramp_core::types::ImplementationStatus::Planned).is_err());
+        assert!("InProgress".parse::<ImplementationStatus>().is_err());
+    }
+
+    #[test]
+    fn test_update_control_status_records_transitions() {
+        let mut service = DashboardService::with_sample_data();
+
+        let previous = service.update_control_status("ac-2", ImplementationStatus::Implemented).unwrap();
+        assert_eq!(previous, ImplementationStatus::InProgress);
+
+        // Re-applying the same status is not a transition
+        service.update_control_status("ac-2", ImplementationStatus::Implemented).unwrap();
+
+        let history = service.status_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].control_id, "ac-2");
+        assert_eq!(history[0].old_status, ImplementationStatus::InProgress);
+        assert_eq!(history[0].new_status, ImplementationStatus::Implemented);
+
+        assert!(service.update_control_status("missing", ImplementationStatus::Implemented).is_err());
+    }
+
+    #[test]
+    fn test_bulk_update_is_all_or_nothing_on_unknown_controls() {
+        let mut service = DashboardService::with_sample_data();
+        let updates = vec![
+            ("ac-2".to_string(), ImplementationStatus::Implemented),
+            ("zz-99".to_string(), ImplementationStatus::Implemented),
+            ("ac-3".to_string(), ImplementationStatus::InProgress),
+        ];
+
+        assert!(service.bulk_update(updates.clone(), false).is_err());
+        assert!(service.status_history().is_empty());
+
+        let result = service.bulk_update(updates, true).unwrap();
+        assert_eq!(result.applied, 2);
+        assert_eq!(result.skipped, 1);
+        assert_eq!(result.skipped_ids, vec!["zz-99".to_string()]);
+        assert_eq!(result.changes.len(), 2);
+        assert_eq!(service.status_history().len(), 2);
+    }
+
+    #[test]
+    fn test_status_update_writes_one_audit_record() {
+        let sink = Arc::new(InMemoryAuditSink::new());
+        let mut service = DashboardService::with_sample_data().with_audit_store(sink.clone());
+
+        service.update_control_status_as("ac-2", ImplementationStatus::Implemented, "alice").unwrap();
+
+        let entries = sink.entries();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.metadata["actor"], "alice");
+        assert_eq!(entry.metadata["control_id"], "ac-2");
+        assert_eq!(entry.details.previous_values, Some(serde_json::json!({ "implementation_status": "in-progress" })));
+        assert_eq!(entry.details.new_values, Some(serde_json::json!({ "implementation_status": "implemented" })));
+        assert_eq!(entry.timestamp, service.status_history()[0].changed_at);
+    }
+
+    /// Audit sink whose writes always fail
+    #[derive(Debug)]
+    struct FailingAuditSink;
+
+    impl AuditSink for FailingAuditSink {
+        fn record(&self, _entry: AuditLogEntry) -> Result<()> {
+            Err(Error::internal("audit backend unavailable"))
+        }
+    }
+
+    impl AuditStore for FailingAuditSink {
+        fn query(&self, filter: &AuditFilter) -> Result<Page<AuditLogEntry>> {
+            Page::paginate(Vec::new(), &filter.page)
+        }
+    }
+
+    #[test]
+    fn test_failed_audit_write_leaves_control_unchanged() {
+        let mut service = DashboardService::with_sample_data().with_audit_store(Arc::new(FailingAuditSink));
+        let before = service.controls().into_iter().find(|control| control.id == "ac-2").unwrap();
+
+        assert!(service.update_control_status_as("ac-2", ImplementationStatus::Implemented, "alice").is_err());
+        assert!(service.bulk_update_as(vec![("ac-2".to_string(), ImplementationStatus::Implemented)], false, "alice").is_err());
+
+        let after = service.controls().into_iter().find(|control| control.id == "ac-2").unwrap();
+        assert_eq!(after.implementation_status, before.implementation_status);
+        assert_eq!(after.last_updated, before.last_updated);
+        assert!(service.status_history().is_empty());
+    }
+
+    #[test]
+    fn test_bulk_update_audits_the_actor() {
+        let sink = Arc::new(InMemoryAuditSink::new());
+        let mut service = DashboardService::with_sample_data().with_audit_store(sink.clone());
+
+        service.bulk_update_as(vec![("ac-2".to_string(), ImplementationStatus::Implemented)], false, "bob").unwrap();
+
+        assert_eq!(sink.entries()[0].metadata["actor"], "bob");
+    }
+
+    /// Write-only audit sink, like a log pipeline
+    #[derive(Debug, Default)]
+    struct ForwardingAuditSink {
+        forwarded: std::sync::Mutex<Vec<AuditLogEntry>>,
+    }
+
+    impl AuditSink for ForwardingAuditSink {
+        fn record(&self, entry: AuditLogEntry) -> Result<()> {
+            self.forwarded.lock().unwrap().push(entry);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_only_audit_sink_receives_records_but_cannot_be_queried() {
+        let sink = Arc::new(ForwardingAuditSink::default());
+        let mut service = DashboardService::with_sample_data().with_audit_sink(sink.clone());
+
+        service.update_control_status_as("ac-2", ImplementationStatus::Implemented, "alice").unwrap();
+
+        assert_eq!(sink.forwarded.lock().unwrap()[0].metadata["actor"], "alice");
+        assert!(matches!(service.query_audit_log(&AuditFilter::default()), Err(Error::Configuration { .. })));
+    }
+
+    #[test]
+    fn test_audit_store_records_are_queryable() {
+        let store = Arc::new(InMemoryAuditSink::new());
+        let mut service = DashboardService::with_sample_data().with_audit_store(store);
+
+        service.update_control_status_as("ac-2", ImplementationStatus::Implemented, "alice").unwrap();
+
+        assert_eq!(service.query_audit_log(&AuditFilter::default()).unwrap().items.len(), 1);
+    }
+}
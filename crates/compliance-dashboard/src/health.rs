@@ -0,0 +1,180 @@
+// Modified: 2025-09-20
+
+//! Liveness and readiness checks
+//!
+//! `/health` only reports that the process is serving requests. `/ready`
+//! additionally verifies that baseline data loads, so a server whose mappings
+//! directory is missing is taken out of rotation instead of failing every
+//! gap analysis.
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+};
+use fedramp_core::types::Timestamp;
+use gap_analysis::BaselineManager;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Control mappings file inside the mappings directory
+const CONTROL_MAPPINGS_FILE: &str = "control_mappings.json";
+
+/// Overall or per-component readiness
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadinessStatus {
+    /// Ready to serve analyses
+    Ready,
+    /// Loaded, but without usable data
+    Degraded,
+    /// Failed to load
+    Unhealthy,
+}
+
+/// Result of one readiness check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentCheck {
+    pub name: String,
+    pub status: ReadinessStatus,
+    /// Frameworks with baseline data, when they could be listed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frameworks: Option<Vec<String>>,
+    /// Underlying error when the component is not ready
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Readiness of the service and its components
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadinessReport {
+    /// Worst status of any component
+    pub status: ReadinessStatus,
+    pub checks: Vec<ComponentCheck>,
+    pub timestamp: Timestamp,
+}
+
+/// Readiness checker that loads baselines from the mappings directory
+#[derive(Debug, Clone)]
+pub struct ReadinessChecker {
+    mappings_dir: PathBuf,
+}
+
+impl ReadinessChecker {
+    /// Create a checker for the given mappings directory
+    pub fn new(mappings_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            mappings_dir: mappings_dir.into(),
+        }
+    }
+
+    /// Check that baseline data loads and lists at least one framework
+    ///
+    /// The mappings are re-read on every check so files removed after startup
+    /// are detected.
+    pub async fn check(&self) -> ReadinessReport {
+        let mappings_path = self.mappings_dir.join(CONTROL_MAPPINGS_FILE);
+        let listed = tokio::task::spawn_blocking(move || {
+            BaselineManager::with_json_loader(mappings_path.to_string_lossy().into_owned())
+                .and_then(|manager| manager.get_available_frameworks())
+        })
+        .await
+        .unwrap_or_else(|e| Err(fedramp_core::Error::internal(format!("Baseline check task failed: {}", e))));
+
+        let baselines = match listed {
+            Ok(mut frameworks) if !frameworks.is_empty() => {
+                frameworks.sort();
+                ComponentCheck {
+                    name: "baselines".to_string(),
+                    status: ReadinessStatus::Ready,
+                    frameworks: Some(frameworks),
+                    error: None,
+                }
+            }
+            Ok(_) => ComponentCheck {
+                name: "baselines".to_string(),
+                status: ReadinessStatus::Degraded,
+                frameworks: Some(Vec::new()),
+                error: Some("No frameworks available in control mappings".to_string()),
+            },
+            Err(e) => ComponentCheck {
+                name: "baselines".to_string(),
+                status: ReadinessStatus::Unhealthy,
+                frameworks: None,
+                error: Some(e.to_string()),
+            },
+        };
+
+        ReadinessReport {
+            status: baselines.status,
+            checks: vec![baselines],
+            timestamp: chrono::Utc::now(),
+        }
+    }
+}
+
+impl Default for ReadinessChecker {
+    fn default() -> Self {
+        Self::new("mappings")
+    }
+}
+
+/// Readiness endpoint: `200 OK` when ready, `503 Service Unavailable` otherwise
+pub async fn readiness_check(State(checker): State<Arc<ReadinessChecker>>) -> Response {
+    let report = checker.check().await;
+    let status = match report.status {
+        ReadinessStatus::Ready => StatusCode::OK,
+        ReadinessStatus::Degraded | ReadinessStatus::Unhealthy => StatusCode::SERVICE_UNAVAILABLE,
+    };
+    (status, Json(report)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_router_with_config, ApiConfig, ComplianceDashboard};
+    use axum::body::{to_bytes, Body};
+    use axum::extract::Request;
+    use tower::ServiceExt;
+
+    async fn get(router: &axum::Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = router.clone()
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_empty_mappings_dir_is_not_ready_but_live() {
+        let mappings_dir = std::env::temp_dir().join(format!("empty-mappings-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&mappings_dir).unwrap();
+        let router = create_router_with_config(
+            ComplianceDashboard::new(),
+            ApiConfig::default().with_mappings_dir(&mappings_dir),
+        );
+
+        let (status, body) = get(&router, "/ready").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "unhealthy");
+        assert_eq!(body["checks"][0]["name"], "baselines");
+        assert!(body["checks"][0]["error"].as_str().unwrap().contains("Failed to read mappings file"));
+
+        let (status, _) = get(&router, "/health").await;
+        assert_eq!(status, StatusCode::OK);
+
+        std::fs::remove_dir_all(mappings_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_repository_mappings_are_ready() {
+        let checker = ReadinessChecker::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../../mappings"));
+        let report = checker.check().await;
+
+        assert_eq!(report.status, ReadinessStatus::Ready);
+        assert!(!report.checks[0].frameworks.as_ref().unwrap().is_empty());
+    }
+}
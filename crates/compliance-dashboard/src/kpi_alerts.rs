@@ -0,0 +1,246 @@
+// Modified: 2025-09-24
+
+//! KPI threshold alerts
+//!
+//! Rules compare a KPI against a threshold each time metrics are recomputed.
+//! Alert state is sticky: a rule raises one `AlertGenerated` event when it
+//! trips and one `AlertCleared` event when it clears, not one per refresh.
+
+use crate::metrics::KPI;
+use crate::realtime::{AlertCategory, AlertEvent, AlertSeverity, RealtimeEvent};
+use chrono::{DateTime, Utc};
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// Comparison of a KPI value against a rule's threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOperator {
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+}
+
+impl ComparisonOperator {
+    /// Whether `value` compares to `threshold` as the operator requires
+    pub fn holds(&self, value: f64, threshold: f64) -> bool {
+        match self {
+            ComparisonOperator::GreaterThan => value > threshold,
+            ComparisonOperator::GreaterThanOrEqual => value >= threshold,
+            ComparisonOperator::LessThan => value < threshold,
+            ComparisonOperator::LessThanOrEqual => value <= threshold,
+        }
+    }
+
+    /// Operator symbol, e.g. `>=`
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ComparisonOperator::GreaterThan => ">",
+            ComparisonOperator::GreaterThanOrEqual => ">=",
+            ComparisonOperator::LessThan => "<",
+            ComparisonOperator::LessThanOrEqual => "<=",
+        }
+    }
+}
+
+/// Alert raised while a KPI meets a condition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KpiAlertRule {
+    /// Unique rule ID
+    pub id: String,
+    /// KPI the rule watches, by ID (`implementation-rate`) or name
+    pub metric: String,
+    /// The rule trips while `value <operator> threshold` holds
+    pub operator: ComparisonOperator,
+    pub threshold: f64,
+    pub severity: AlertSeverity,
+}
+
+impl KpiAlertRule {
+    fn watches(&self, kpi: &KPI) -> bool {
+        kpi.id.eq_ignore_ascii_case(&self.metric) || kpi.name.eq_ignore_ascii_case(&self.metric)
+    }
+}
+
+/// Alert of a tripped rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveKpiAlert {
+    pub rule_id: String,
+    pub kpi_id: String,
+    /// KPI value when the rule tripped
+    pub value: f64,
+    pub threshold: f64,
+    pub alert: AlertEvent,
+    pub triggered_at: DateTime<Utc>,
+}
+
+/// KPI alert rules and the alerts they currently raise
+#[derive(Debug, Clone, Default)]
+pub struct KpiAlertMonitor {
+    rules: Vec<KpiAlertRule>,
+    /// Rule ID to its alert, while the rule is tripped
+    active: BTreeMap<String, ActiveKpiAlert>,
+}
+
+impl KpiAlertMonitor {
+    /// Create a monitor without rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a rule
+    ///
+    /// Rule IDs must be unique and thresholds finite. The rule is first
+    /// evaluated on the next metrics recomputation.
+    pub fn add_rule(&mut self, rule: KpiAlertRule) -> Result<()> {
+        if !rule.threshold.is_finite() {
+            return Err(Error::validation(format!("KPI alert rule '{}' has a non-finite threshold", rule.id)));
+        }
+        if self.rules.iter().any(|existing| existing.id == rule.id) {
+            return Err(Error::conflict(format!("KPI alert rule '{}' is already registered", rule.id)));
+        }
+        self.rules.push(rule);
+        Ok(())
+    }
+
+    /// Remove a rule, returning the `AlertCleared` event for its alert if it was tripped
+    pub fn remove_rule(&mut self, rule_id: &str, now: DateTime<Utc>) -> Option<RealtimeEvent> {
+        self.rules.retain(|rule| rule.id != rule_id);
+        self.active.remove(rule_id).map(|active| cleared(active, now))
+    }
+
+    /// Registered rules, in registration order
+    pub fn rules(&self) -> &[KpiAlertRule] {
+        &self.rules
+    }
+
+    /// Alerts of the currently tripped rules, by rule ID
+    pub fn active_alerts(&self) -> Vec<ActiveKpiAlert> {
+        self.active.values().cloned().collect()
+    }
+
+    /// Evaluate every rule against freshly computed KPIs
+    ///
+    /// Returns an event for each rule that tripped or cleared since the last
+    /// evaluation. A rule whose KPI is missing keeps its state.
+    pub fn evaluate(&mut self, kpis: &[KPI], now: DateTime<Utc>) -> Vec<RealtimeEvent> {
+        let mut events = Vec::new();
+        for rule in &self.rules {
+            let Some(kpi) = kpis.iter().find(|kpi| rule.watches(kpi)) else { continue };
+            let tripped = rule.operator.holds(kpi.value, rule.threshold);
+
+            match (tripped, self.active.contains_key(&rule.id)) {
+                (true, false) => {
+                    let active = tripped_alert(rule, kpi, now);
+                    events.push(RealtimeEvent::AlertGenerated {
+                        alert: active.alert.clone(),
+                        timestamp: now,
+                    });
+                    self.active.insert(rule.id.clone(), active);
+                }
+                (false, true) => {
+                    if let Some(active) = self.active.remove(&rule.id) {
+                        events.push(cleared(active, now));
+                    }
+                }
+                _ => {}
+            }
+        }
+        events
+    }
+}
+
+fn tripped_alert(rule: &KpiAlertRule, kpi: &KPI, now: DateTime<Utc>) -> ActiveKpiAlert {
+    ActiveKpiAlert {
+        rule_id: rule.id.clone(),
+        kpi_id: kpi.id.clone(),
+        value: kpi.value,
+        threshold: rule.threshold,
+        alert: AlertEvent {
+            id: Uuid::new_v4().to_string(),
+            title: format!("{} {} {}", kpi.name, rule.operator.symbol(), rule.threshold),
+            message: format!(
+                "{} is {} {}, which trips rule '{}' ({} {} {})",
+                kpi.name, kpi.value, kpi.unit, rule.id, rule.operator.symbol(), rule.threshold, kpi.unit
+            ),
+            severity: rule.severity.clone(),
+            category: AlertCategory::KpiThreshold,
+            related_control: None,
+            auto_generated: true,
+        },
+        triggered_at: now,
+    }
+}
+
+fn cleared(active: ActiveKpiAlert, now: DateTime<Utc>) -> RealtimeEvent {
+    RealtimeEvent::AlertCleared {
+        alert_id: active.alert.id,
+        timestamp: now,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::{KPIStatus, TrendDirection};
+
+    fn implementation_rate(value: f64) -> Vec<KPI> {
+        vec![KPI {
+            id: "implementation-rate".to_string(),
+            name: "Implementation Rate".to_string(),
+            value,
+            target: 85.0,
+            unit: "%".to_string(),
+            status: KPIStatus::OnTrack,
+            trend: TrendDirection::Stable,
+        }]
+    }
+
+    fn rule(id: &str) -> KpiAlertRule {
+        KpiAlertRule {
+            id: id.to_string(),
+            metric: "Implementation Rate".to_string(),
+            operator: ComparisonOperator::LessThan,
+            threshold: 50.0,
+            severity: AlertSeverity::Critical,
+        }
+    }
+
+    #[test]
+    fn test_alerts_fire_only_on_transitions() {
+        let mut monitor = KpiAlertMonitor::new();
+        monitor.add_rule(rule("low-implementation")).unwrap();
+        let now = Utc::now();
+
+        assert!(monitor.evaluate(&implementation_rate(60.0), now).is_empty());
+
+        let tripped = monitor.evaluate(&implementation_rate(40.0), now);
+        let alert_id = match tripped.as_slice() {
+            [RealtimeEvent::AlertGenerated { alert, .. }] => alert.id.clone(),
+            other => panic!("unexpected events: {:?}", other),
+        };
+        assert!(monitor.evaluate(&implementation_rate(35.0), now).is_empty());
+        assert!(monitor.evaluate(&[], now).is_empty());
+        assert_eq!(monitor.active_alerts().len(), 1);
+
+        let cleared = monitor.evaluate(&implementation_rate(55.0), now);
+        assert!(matches!(cleared.as_slice(), [RealtimeEvent::AlertCleared { alert_id: id, .. }] if *id == alert_id));
+        assert!(monitor.active_alerts().is_empty());
+    }
+
+    #[test]
+    fn test_rules_are_validated_and_removal_clears_alert() {
+        let mut monitor = KpiAlertMonitor::new();
+        monitor.add_rule(rule("low-implementation")).unwrap();
+        assert!(monitor.add_rule(rule("low-implementation")).is_err());
+        assert!(monitor.add_rule(KpiAlertRule { threshold: f64::NAN, ..rule("nan") }).is_err());
+
+        monitor.evaluate(&implementation_rate(10.0), Utc::now());
+        assert!(matches!(monitor.remove_rule("low-implementation", Utc::now()), Some(RealtimeEvent::AlertCleared { .. })));
+        assert!(monitor.rules().is_empty());
+        assert!(monitor.active_alerts().is_empty());
+    }
+}
@@ -9,14 +9,30 @@ pub mod metrics;
 pub mod widgets;
 pub mod realtime;
 pub mod api;
+pub mod telemetry;
+pub mod rate_limit;
+pub mod auth;
+pub mod health;
+pub mod audit;
+pub mod snapshot;
+pub mod kpi_alerts;
 
-pub use dashboard::{DashboardService, DashboardOverview, Control, Framework, ImplementationStatus, Priority};
-pub use metrics::{MetricsService, ComplianceMetrics, StatusBreakdown, KPI, TrendDirection};
+pub use dashboard::{DashboardService, DashboardOverview, Control, Framework, ImplementationStatus, Priority, StatusChange, BulkUpdateResult};
+pub use metrics::{MetricsService, ComplianceMetrics, StatusBreakdown, FamilyMetrics, KPI, TrendDirection, VelocityBucket, VelocityPeriod};
 pub use widgets::{WidgetService, Widget, WidgetType, WidgetData};
-pub use realtime::{RealtimeService, RealtimeEvent, WebSocketMessage, SystemStatus};
-pub use api::{create_router, start_server};
+pub use realtime::{RealtimeService, RealtimeEvent, WebSocketMessage, SystemStatus, ControlStatusChange, BackpressureConfig, OverflowPolicy, ClientEventQueue, SubscriptionFilter};
+pub use api::{create_router, create_router_with_config, start_server, start_server_with_config, ApiConfig};
+pub use telemetry::PrometheusMetrics;
+pub use rate_limit::{ClientIdentity, RateLimit, RateLimitConfig, RateLimiter};
+pub use auth::ApiKeys;
+pub use health::{ReadinessChecker, ReadinessReport, ReadinessStatus};
+pub use audit::{AuditFilter, AuditSink, AuditStore, InMemoryAuditSink, SYSTEM_ACTOR};
+pub use snapshot::{InMemorySnapshotStore, OverviewDiff, OverviewSnapshot, SnapshotStore};
+pub use kpi_alerts::{ActiveKpiAlert, ComparisonOperator, KpiAlertRule};
 
+use fedramp_core::clock::{system_clock, SharedClock};
 use fedramp_core::Result;
+use std::sync::Arc;
 
 /// Main dashboard application
 #[derive(Debug, Clone)]
@@ -29,6 +45,10 @@ pub struct ComplianceDashboard {
     pub widgets: WidgetService,
     /// Real-time service
     pub realtime: RealtimeService,
+    /// Overview snapshots for posture comparisons
+    pub snapshots: Arc<dyn SnapshotStore>,
+    /// Time source for snapshots and event timestamps
+    clock: SharedClock,
 }
 
 impl ComplianceDashboard {
@@ -39,6 +59,8 @@ impl ComplianceDashboard {
             metrics: MetricsService::new(),
             widgets: WidgetService::new(),
             realtime: RealtimeService::new(),
+            snapshots: Arc::new(InMemorySnapshotStore::new()),
+            clock: system_clock(),
         }
     }
 
@@ -49,6 +71,8 @@ impl ComplianceDashboard {
             metrics: MetricsService::new(),
             widgets: WidgetService::new(),
             realtime: RealtimeService::new(),
+            snapshots: Arc::new(InMemorySnapshotStore::new()),
+            clock: system_clock(),
         };
 
         // Initialize widgets with default configuration
@@ -59,6 +83,43 @@ impl ComplianceDashboard {
         dashboard
     }
 
+    /// Recompute compliance metrics and broadcast KPI alerts that tripped or cleared
+    ///
+    /// Status updates call this themselves; run it periodically for KPIs that
+    /// change with time alone, such as overdue controls.
+    pub async fn refresh_metrics(&mut self) -> Result<ComplianceMetrics> {
+        let overview = self.dashboard.get_overview()?;
+        let metrics = self.metrics.calculate_metrics(&self.dashboard.controls(), &overview.frameworks)?;
+        for event in self.metrics.take_kpi_alert_events() {
+            self.realtime.broadcast_event(event).await?;
+        }
+        Ok(metrics)
+    }
+
+    /// Use `clock` for snapshots, event timestamps, metrics, connection tracking and status history
+    ///
+    /// Replaces the metrics and real-time services, so call it before configuring them.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.dashboard = self.dashboard.with_clock(clock.clone());
+        self.metrics = MetricsService::with_clock(clock.clone());
+        self.realtime = RealtimeService::with_clock(clock.clone());
+        self.clock = clock;
+        self
+    }
+
+    /// Keep overview snapshots in `store` instead of memory
+    pub fn with_snapshot_store(mut self, store: Arc<dyn SnapshotStore>) -> Self {
+        self.snapshots = store;
+        self
+    }
+
+    /// Capture the current overview and save it to the snapshot store
+    pub fn take_snapshot(&self) -> Result<OverviewSnapshot> {
+        let snapshot = OverviewSnapshot::capture(&self.dashboard, &self.metrics, self.clock.now())?;
+        self.snapshots.save(snapshot.clone())?;
+        Ok(snapshot)
+    }
+
     /// Initialize the dashboard
     pub async fn initialize(&mut self) -> Result<()> {
         // Create default widgets
@@ -85,20 +146,73 @@ impl ComplianceDashboard {
 
     /// Update dashboard with new control data
     pub async fn update_control_status(&mut self, control_id: &str, status: ImplementationStatus) -> Result<()> {
+        self.update_control_status_as(control_id, status, SYSTEM_ACTOR).await
+    }
+
+    /// Update a control's status on behalf of `actor`, who is audited and named in the event
+    pub async fn update_control_status_as(
+        &mut self,
+        control_id: &str,
+        status: ImplementationStatus,
+        actor: &str,
+    ) -> Result<()> {
         // Update dashboard service
-        self.dashboard.update_control_status(control_id, status.clone())?;
+        let old_status = self.dashboard.update_control_status_as(control_id, status.clone(), actor)?;
+        self.metrics.invalidate_cache();
 
         // Broadcast real-time event
         self.realtime.broadcast_event(RealtimeEvent::ControlStatusUpdated {
             control_id: control_id.to_string(),
-            old_status: "unknown".to_string(), // In real implementation, we'd track the old status
-            new_status: format!("{:?}", status).to_lowercase(),
-            updated_by: Some("system".to_string()),
-            timestamp: chrono::Utc::now(),
+            framework_id: self.dashboard.control(control_id).map(|control| control.framework_id.clone()),
+            old_status: old_status.as_str().to_string(),
+            new_status: status.as_str().to_string(),
+            updated_by: Some(actor.to_string()),
+            timestamp: self.clock.now(),
         }).await?;
 
+        self.refresh_metrics().await?;
         Ok(())
     }
+
+    /// Apply many control status updates and broadcast a single real-time event
+    ///
+    /// See [`DashboardService::bulk_update`] for how unknown control IDs are handled.
+    pub async fn bulk_update_control_status(
+        &mut self,
+        updates: Vec<(String, ImplementationStatus)>,
+        skip_missing: bool,
+    ) -> Result<BulkUpdateResult> {
+        self.bulk_update_control_status_as(updates, skip_missing, SYSTEM_ACTOR).await
+    }
+
+    /// Apply many control status updates on behalf of `actor`
+    pub async fn bulk_update_control_status_as(
+        &mut self,
+        updates: Vec<(String, ImplementationStatus)>,
+        skip_missing: bool,
+        actor: &str,
+    ) -> Result<BulkUpdateResult> {
+        let result = self.dashboard.bulk_update_as(updates, skip_missing, actor)?;
+        self.metrics.invalidate_cache();
+
+        self.realtime.broadcast_event(RealtimeEvent::BulkControlsUpdated {
+            applied: result.applied,
+            skipped: result.skipped,
+            changes: result.changes.iter()
+                .map(|change| ControlStatusChange {
+                    control_id: change.control_id.clone(),
+                    framework_id: change.framework_id.clone(),
+                    old_status: change.old_status.as_str().to_string(),
+                    new_status: change.new_status.as_str().to_string(),
+                })
+                .collect(),
+            updated_by: Some(actor.to_string()),
+            timestamp: self.clock.now(),
+        }).await?;
+
+        self.refresh_metrics().await?;
+        Ok(result)
+    }
 }
 
 /// Complete dashboard data structure
@@ -114,3 +228,129 @@ impl Default for ComplianceDashboard {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fedramp_core::clock::{Clock, MockClock};
+
+    fn control(index: usize) -> Control {
+        Control {
+            id: format!("cm-{}", index),
+            framework_id: "nist-800-53".to_string(),
+            identifier: format!("CM-{}", index),
+            title: format!("Control {}", index),
+            description: String::new(),
+            implementation_status: ImplementationStatus::NotImplemented,
+            priority: Priority::Medium,
+            category: "Configuration Management".to_string(),
+            assigned_to: None,
+            due_date: None,
+            completion_date: None,
+            evidence_count: 0,
+            last_updated: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_update_emits_single_realtime_event() {
+        let mut dashboard = ComplianceDashboard::new();
+        for index in 0..100 {
+            dashboard.dashboard.upsert_control(control(index));
+        }
+        let mut events = dashboard.realtime.get_event_receiver();
+
+        let updates = (0..100)
+            .map(|index| (format!("cm-{}", index), ImplementationStatus::Implemented))
+            .collect();
+        let result = dashboard.bulk_update_control_status(updates, false).await.unwrap();
+        assert_eq!(result.applied, 100);
+        assert_eq!(result.skipped, 0);
+
+        match events.try_recv().unwrap() {
+            RealtimeEvent::BulkControlsUpdated { applied, changes, .. } => {
+                assert_eq!(applied, 100);
+                assert_eq!(changes.len(), 100);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_metrics_broadcasts_kpi_alert_once() {
+        let mut dashboard = ComplianceDashboard::new();
+        dashboard.dashboard.upsert_control(control(1));
+        dashboard.metrics.add_kpi_alert_rule(KpiAlertRule {
+            id: "low-implementation".to_string(),
+            metric: "implementation-rate".to_string(),
+            operator: ComparisonOperator::LessThan,
+            threshold: 50.0,
+            severity: realtime::AlertSeverity::Warning,
+        }).unwrap();
+        let mut events = dashboard.realtime.get_event_receiver();
+
+        dashboard.refresh_metrics().await.unwrap();
+        dashboard.refresh_metrics().await.unwrap();
+
+        match events.try_recv().unwrap() {
+            RealtimeEvent::AlertGenerated { alert, .. } => assert!(alert.message.contains("low-implementation")),
+            other => panic!("unexpected event: {:?}", other),
+        }
+        assert!(events.try_recv().is_err());
+        assert_eq!(dashboard.metrics.active_kpi_alerts().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_status_updates_refresh_kpi_alerts() {
+        let mut dashboard = ComplianceDashboard::new();
+        dashboard.dashboard.upsert_control(control(1));
+        dashboard.metrics.add_kpi_alert_rule(KpiAlertRule {
+            id: "low-implementation".to_string(),
+            metric: "implementation-rate".to_string(),
+            operator: ComparisonOperator::LessThan,
+            threshold: 50.0,
+            severity: realtime::AlertSeverity::Warning,
+        }).unwrap();
+        dashboard.refresh_metrics().await.unwrap();
+        assert_eq!(dashboard.metrics.active_kpi_alerts().len(), 1);
+        let mut events = dashboard.realtime.get_event_receiver();
+
+        // The cached metrics from the refresh above must not hide the update
+        dashboard.update_control_status("cm-1", ImplementationStatus::Implemented).await.unwrap();
+        assert!(dashboard.metrics.active_kpi_alerts().is_empty());
+        assert!(matches!(events.try_recv().unwrap(), RealtimeEvent::ControlStatusUpdated { .. }));
+        assert!(matches!(events.try_recv().unwrap(), RealtimeEvent::AlertCleared { .. }));
+
+        let updates = vec![("cm-1".to_string(), ImplementationStatus::NotImplemented)];
+        dashboard.bulk_update_control_status(updates, false).await.unwrap();
+        assert_eq!(dashboard.metrics.active_kpi_alerts().len(), 1);
+        assert!(matches!(events.try_recv().unwrap(), RealtimeEvent::BulkControlsUpdated { .. }));
+        assert!(matches!(events.try_recv().unwrap(), RealtimeEvent::AlertGenerated { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_snapshots_and_events_use_the_injected_clock() {
+        let clock = MockClock::new("2025-06-01T12:00:00Z".parse().unwrap());
+        let mut dashboard = ComplianceDashboard::new().with_clock(Arc::new(clock.clone()));
+        dashboard.dashboard.upsert_control(control(1));
+        let mut events = dashboard.realtime.get_event_receiver();
+
+        let snapshot = dashboard.take_snapshot().unwrap();
+        assert_eq!(snapshot.taken_at, clock.now());
+
+        clock.advance(chrono::Duration::days(1));
+        dashboard.update_control_status("cm-1", ImplementationStatus::Implemented).await.unwrap();
+        match events.try_recv().unwrap() {
+            RealtimeEvent::ControlStatusUpdated { timestamp, framework_id, .. } => {
+                assert_eq!(timestamp, clock.now());
+                assert_eq!(framework_id.as_deref(), Some("nist-800-53"));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        let history = dashboard.dashboard.status_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].changed_at, clock.now());
+        assert_eq!(dashboard.take_snapshot().unwrap().taken_at, clock.now());
+    }
+}
@@ -4,10 +4,19 @@
 //! KPIs, and performance indicators for the dashboard.
 
 use fedramp_core::{Result, Error};
+use fedramp_core::clock::{system_clock, SharedClock};
+use fedramp_core::models::Framework as CoreFramework;
+use fedramp_core::validation::validate_control_id;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use chrono::{DateTime, Utc, Duration};
-use crate::dashboard::{Control, Framework, ImplementationStatus, Priority};
+use std::collections::{BTreeMap, HashMap};
+use tracing::debug;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use crate::dashboard::{Control, Framework, ImplementationStatus, Priority, StatusChange};
+use crate::kpi_alerts::{ActiveKpiAlert, KpiAlertMonitor, KpiAlertRule};
+use crate::realtime::RealtimeEvent;
+
+/// IDs of KPIs for which a lower value is better
+pub(crate) const LOWER_IS_BETTER_KPIS: &[&str] = &["overdue-controls"];
 
 /// Metrics calculator service
 #[derive(Debug, Clone)]
@@ -18,6 +27,12 @@ pub struct MetricsService {
     cache: HashMap<String, CachedMetric>,
     /// Cache TTL in seconds
     cache_ttl: i64,
+    /// Time source for overdue and cache expiry checks
+    clock: SharedClock,
+    /// KPI alert rules and their active alerts
+    kpi_alerts: KpiAlertMonitor,
+    /// Alert events raised by recomputations and not yet taken
+    pending_alert_events: Vec<RealtimeEvent>,
 }
 
 /// Individual metric data point
@@ -66,6 +81,17 @@ pub struct StatusBreakdown {
     pub total: usize,
 }
 
+/// Status rollup for one control family, e.g. `AC`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FamilyMetrics {
+    /// Family as normalized by the control ID validator
+    pub family: String,
+    /// Controls in the family by status
+    pub status_breakdown: StatusBreakdown,
+    /// Percentage of the family's controls that are implemented
+    pub completion_percentage: f64,
+}
+
 /// Priority breakdown metrics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriorityBreakdown {
@@ -136,13 +162,127 @@ pub struct RiskIndicators {
     pub resource_allocation_risk: bool,
 }
 
+/// Bucket size for implementation velocity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VelocityBucket {
+    /// ISO weeks, starting Monday 00:00 UTC
+    Week,
+    /// Calendar months, starting on the 1st at 00:00 UTC
+    Month,
+}
+
+impl VelocityBucket {
+    /// Stable lowercase identifier, matching the serde representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VelocityBucket::Week => "week",
+            VelocityBucket::Month => "month",
+        }
+    }
+
+    /// Start of the bucket containing `timestamp`
+    fn start_of(&self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        let date = timestamp.date_naive();
+        let start = match self {
+            VelocityBucket::Week => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+            VelocityBucket::Month => date.with_day(1).unwrap_or(date),
+        };
+        start.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+    }
+
+    /// Start of the bucket following the one starting at `start`
+    fn next(&self, start: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            VelocityBucket::Week => start + Duration::weeks(1),
+            VelocityBucket::Month => {
+                let (year, month) = if start.month() == 12 {
+                    (start.year() + 1, 1)
+                } else {
+                    (start.year(), start.month() + 1)
+                };
+                first_of_month(year, month)
+            }
+        }
+    }
+
+    /// Start of the bucket preceding the one starting at `start`
+    fn previous(&self, start: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            VelocityBucket::Week => start - Duration::weeks(1),
+            VelocityBucket::Month => {
+                let (year, month) = if start.month() == 1 {
+                    (start.year() - 1, 12)
+                } else {
+                    (start.year(), start.month() - 1)
+                };
+                first_of_month(year, month)
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for VelocityBucket {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "week" | "weekly" => Ok(VelocityBucket::Week),
+            "month" | "monthly" => Ok(VelocityBucket::Month),
+            other => Err(Error::validation(format!("Unknown velocity bucket: {}", other))),
+        }
+    }
+}
+
+fn first_of_month(year: i32, month: u32) -> DateTime<Utc> {
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .unwrap_or_default()
+        .and_utc()
+}
+
+/// Core framework for a dashboard framework ID such as `nist-800-53`
+fn framework_for(framework_id: &str) -> Option<CoreFramework> {
+    let compact = framework_id.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+    if compact.contains("800171") {
+        Some(CoreFramework::Nist800171)
+    } else if compact.contains("80053") {
+        Some(CoreFramework::Nist80053)
+    } else if compact.starts_with("cis") {
+        Some(CoreFramework::Cis)
+    } else {
+        None
+    }
+}
+
+/// Number of controls that moved to Implemented within one bucket
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VelocityPeriod {
+    /// Inclusive start of the bucket
+    pub period_start: DateTime<Utc>,
+    /// Exclusive end of the bucket
+    pub period_end: DateTime<Utc>,
+    pub implemented_count: usize,
+}
+
 impl MetricsService {
     /// Create a new metrics service
     pub fn new() -> Self {
+        Self::with_clock(system_clock())
+    }
+
+    /// Create a metrics service using the given time source
+    pub fn with_clock(clock: SharedClock) -> Self {
         Self {
             history: HashMap::new(),
             cache: HashMap::new(),
             cache_ttl: 300, // 5 minutes
+            clock,
+            kpi_alerts: KpiAlertMonitor::new(),
+            pending_alert_events: Vec::new(),
         }
     }
 
@@ -152,25 +292,32 @@ impl MetricsService {
         
         // Check cache first
         if let Some(cached) = self.cache.get(cache_key) {
-            if cached.expires_at > Utc::now() {
+            if cached.expires_at > self.clock.now() {
                 return Ok(cached.value.clone());
             }
         }
 
         // Calculate fresh metrics
         let metrics = self.calculate_fresh_metrics(controls, frameworks)?;
+        let alert_events = self.kpi_alerts.evaluate(&metrics.kpis, metrics.calculated_at);
+        self.pending_alert_events.extend(alert_events);
         
         // Cache the result
         self.cache.insert(cache_key.to_string(), CachedMetric {
             value: metrics.clone(),
-            expires_at: Utc::now() + Duration::seconds(self.cache_ttl),
+            expires_at: self.clock.now() + Duration::seconds(self.cache_ttl),
         });
 
         Ok(metrics)
     }
 
+    /// Drop cached metrics so the next calculation reflects current control data
+    pub fn invalidate_cache(&mut self) {
+        self.cache.clear();
+    }
+
     /// Calculate fresh metrics without cache
-    fn calculate_fresh_metrics(&self, controls: &[Control], frameworks: &[Framework]) -> Result<ComplianceMetrics> {
+    pub(crate) fn calculate_fresh_metrics(&self, controls: &[Control], frameworks: &[Framework]) -> Result<ComplianceMetrics> {
         let status_breakdown = self.calculate_status_breakdown(controls);
         let priority_breakdown = self.calculate_priority_breakdown(controls);
         let framework_metrics = self.calculate_framework_metrics(controls, frameworks);
@@ -192,7 +339,7 @@ impl MetricsService {
             trends,
             kpis,
             risk_indicators,
-            calculated_at: Utc::now(),
+            calculated_at: self.clock.now(),
         })
     }
 
@@ -255,7 +402,7 @@ impl MetricsService {
             let overdue_count = framework_controls.iter()
                 .filter(|c| {
                     if let Some(due_date) = c.due_date {
-                        due_date < Utc::now() && c.implementation_status != ImplementationStatus::Implemented
+                        due_date < self.clock.now() && c.implementation_status != ImplementationStatus::Implemented
                     } else {
                         false
                     }
@@ -318,7 +465,7 @@ impl MetricsService {
                 value: controls.iter()
                     .filter(|c| {
                         if let Some(due_date) = c.due_date {
-                            due_date < Utc::now() && c.implementation_status != ImplementationStatus::Implemented
+                            due_date < self.clock.now() && c.implementation_status != ImplementationStatus::Implemented
                         } else {
                             false
                         }
@@ -337,7 +484,7 @@ impl MetricsService {
         let overdue_controls = controls.iter()
             .filter(|c| {
                 if let Some(due_date) = c.due_date {
-                    due_date < Utc::now() && c.implementation_status != ImplementationStatus::Implemented
+                    due_date < self.clock.now() && c.implementation_status != ImplementationStatus::Implemented
                 } else {
                     false
                 }
@@ -360,10 +507,108 @@ impl MetricsService {
         }
     }
 
+    /// Roll control status up by control family, ordered by family
+    ///
+    /// The family comes from the control identifier as normalized by
+    /// `validate_control_id` for the control's framework, defaulting to
+    /// NIST 800-53. Controls whose identifier does not validate are left out,
+    /// so only families with at least one control appear.
+    pub fn family_breakdown(&self, controls: &[Control]) -> Vec<FamilyMetrics> {
+        let mut families: BTreeMap<String, Vec<Control>> = BTreeMap::new();
+        for control in controls {
+            let framework = framework_for(&control.framework_id).unwrap_or(CoreFramework::Nist80053);
+            match validate_control_id(&control.identifier, framework) {
+                Ok(id) => families.entry(id.family).or_default().push(control.clone()),
+                Err(e) => debug!("Leaving control '{}' out of family rollup: {}", control.identifier, e),
+            }
+        }
+
+        families.into_iter()
+            .map(|(family, family_controls)| {
+                let status_breakdown = self.calculate_status_breakdown(&family_controls);
+                let completion_percentage =
+                    (status_breakdown.implemented as f64 / status_breakdown.total as f64) * 100.0;
+                FamilyMetrics {
+                    family,
+                    status_breakdown,
+                    completion_percentage,
+                }
+            })
+            .collect()
+    }
+
+    /// Calculate implementation velocity as a burn-up series
+    ///
+    /// Returns `periods` consecutive buckets ending with the one containing the
+    /// current time, oldest first. Each bucket counts the transitions into
+    /// `Implemented` from any other status; buckets without transitions are
+    /// included with a count of zero.
+    pub fn calculate_implementation_velocity(
+        &self,
+        changes: &[StatusChange],
+        bucket: VelocityBucket,
+        periods: usize,
+    ) -> Vec<VelocityPeriod> {
+        if periods == 0 {
+            return Vec::new();
+        }
+
+        let mut start = bucket.start_of(self.clock.now());
+        for _ in 1..periods {
+            start = bucket.previous(start);
+        }
+
+        let mut velocity = Vec::with_capacity(periods);
+        for _ in 0..periods {
+            let end = bucket.next(start);
+            let implemented_count = changes.iter()
+                .filter(|change| {
+                    change.new_status == ImplementationStatus::Implemented
+                        && change.old_status != ImplementationStatus::Implemented
+                        && change.changed_at >= start
+                        && change.changed_at < end
+                })
+                .count();
+
+            velocity.push(VelocityPeriod {
+                period_start: start,
+                period_end: end,
+                implemented_count,
+            });
+            start = end;
+        }
+
+        velocity
+    }
+
+    /// Register a KPI alert rule, evaluated whenever metrics are recomputed
+    pub fn add_kpi_alert_rule(&mut self, rule: KpiAlertRule) -> Result<()> {
+        self.kpi_alerts.add_rule(rule)
+    }
+
+    /// Remove a KPI alert rule; an active alert of the rule is cleared
+    pub fn remove_kpi_alert_rule(&mut self, rule_id: &str) {
+        let now = self.clock.now();
+        self.pending_alert_events.extend(self.kpi_alerts.remove_rule(rule_id, now));
+    }
+
+    /// Alerts of the KPI alert rules that are currently tripped
+    pub fn active_kpi_alerts(&self) -> Vec<ActiveKpiAlert> {
+        self.kpi_alerts.active_alerts()
+    }
+
+    /// Take the alert events raised since the last call, oldest first
+    ///
+    /// A rule raises `AlertGenerated` when it trips and `AlertCleared` when
+    /// it clears; callers broadcast these to realtime clients.
+    pub fn take_kpi_alert_events(&mut self) -> Vec<RealtimeEvent> {
+        std::mem::take(&mut self.pending_alert_events)
+    }
+
     /// Record a metric data point for historical tracking
     pub fn record_metric(&mut self, metric_id: &str, value: f64, metadata: HashMap<String, String>) {
         let data_point = MetricDataPoint {
-            timestamp: Utc::now(),
+            timestamp: self.clock.now(),
             value,
             metadata,
         };
@@ -375,7 +620,7 @@ impl MetricsService {
 
     /// Get historical data for a metric
     pub fn get_metric_history(&self, metric_id: &str, days: i64) -> Vec<MetricDataPoint> {
-        let cutoff = Utc::now() - Duration::days(days);
+        let cutoff = self.clock.now() - Duration::days(days);
         
         self.history.get(metric_id)
             .map(|history| {
@@ -393,3 +638,151 @@ impl Default for MetricsService {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fedramp_core::clock::{Clock, MockClock};
+    use std::sync::Arc;
+
+    fn control(id: &str, due_date: Option<DateTime<Utc>>, status: ImplementationStatus) -> Control {
+        Control {
+            id: id.to_string(),
+            framework_id: "nist-800-53".to_string(),
+            identifier: id.to_uppercase(),
+            title: format!("Control {}", id),
+            description: String::new(),
+            implementation_status: status,
+            priority: Priority::High,
+            category: "Access Control".to_string(),
+            assigned_to: None,
+            due_date,
+            completion_date: None,
+            evidence_count: 0,
+            last_updated: due_date.unwrap_or_else(Utc::now),
+        }
+    }
+
+    #[test]
+    fn test_overdue_controls_follow_injected_clock() {
+        let clock = MockClock::new(Utc::now());
+        let due = clock.now() + Duration::days(7);
+        let controls = vec![
+            control("ac-2", Some(due), ImplementationStatus::InProgress),
+            control("ac-3", Some(due), ImplementationStatus::Implemented),
+            control("ac-4", None, ImplementationStatus::NotImplemented),
+        ];
+        let mut service = MetricsService::with_clock(Arc::new(clock.clone()));
+
+        let metrics = service.calculate_metrics(&controls, &[]).unwrap();
+        assert_eq!(metrics.risk_indicators.overdue_controls, 0);
+
+        clock.advance(Duration::days(8));
+        let metrics = service.calculate_metrics(&controls, &[]).unwrap();
+        assert_eq!(metrics.risk_indicators.overdue_controls, 1);
+        assert_eq!(metrics.calculated_at, clock.now());
+    }
+
+    #[test]
+    fn test_family_breakdown_rolls_up_completion_per_family() {
+        let service = MetricsService::new();
+        let controls = vec![
+            control("ac-2", None, ImplementationStatus::Implemented),
+            control("ac-3", None, ImplementationStatus::Implemented),
+            control("ac-17(2)", None, ImplementationStatus::InProgress),
+            control("ac-6", None, ImplementationStatus::NotImplemented),
+            control("sc-7", None, ImplementationStatus::Implemented),
+            control("sc-8", None, ImplementationStatus::NotApplicable),
+            control("not a control", None, ImplementationStatus::Implemented),
+        ];
+
+        let families = service.family_breakdown(&controls);
+
+        let names: Vec<&str> = families.iter().map(|f| f.family.as_str()).collect();
+        assert_eq!(names, vec!["AC", "SC"]);
+        assert_eq!(families[0].status_breakdown.total, 4);
+        assert_eq!(families[0].status_breakdown.in_progress, 1);
+        assert_eq!(families[0].completion_percentage, 50.0);
+        assert_eq!(families[1].status_breakdown.not_applicable, 1);
+        assert_eq!(families[1].completion_percentage, 50.0);
+        assert!(service.family_breakdown(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_metrics_cache_expires_with_clock() {
+        let clock = MockClock::new(Utc::now());
+        let mut service = MetricsService::with_clock(Arc::new(clock.clone()));
+        let first = service.calculate_metrics(&[], &[]).unwrap();
+
+        clock.advance(Duration::seconds(299));
+        let cached = service.calculate_metrics(&[], &[]).unwrap();
+        assert_eq!(cached.calculated_at, first.calculated_at);
+
+        clock.advance(Duration::seconds(2));
+        let refreshed = service.calculate_metrics(&[], &[]).unwrap();
+        assert_eq!(refreshed.calculated_at, clock.now());
+    }
+
+    #[test]
+    fn test_metric_history_window_uses_clock() {
+        let clock = MockClock::new(Utc::now());
+        let mut service = MetricsService::with_clock(Arc::new(clock.clone()));
+        service.record_metric("implementation-rate", 40.0, HashMap::new());
+
+        clock.advance(Duration::days(10));
+        service.record_metric("implementation-rate", 55.0, HashMap::new());
+
+        assert_eq!(service.get_metric_history("implementation-rate", 30).len(), 2);
+        assert_eq!(service.get_metric_history("implementation-rate", 5).len(), 1);
+    }
+
+    fn change(day: &str, old_status: ImplementationStatus, new_status: ImplementationStatus) -> StatusChange {
+        StatusChange {
+            control_id: format!("ac-{}", day),
+            framework_id: "nist-800-53".to_string(),
+            old_status,
+            new_status,
+            changed_at: format!("{}T15:30:00Z", day).parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_weekly_velocity_counts_transitions_into_implemented() {
+        // Wednesday
+        let clock = MockClock::new("2025-03-12T12:00:00Z".parse().unwrap());
+        let service = MetricsService::with_clock(Arc::new(clock));
+        let changes = vec![
+            change("2025-02-01", ImplementationStatus::InProgress, ImplementationStatus::Implemented),
+            change("2025-02-24", ImplementationStatus::NotImplemented, ImplementationStatus::Implemented),
+            change("2025-03-02", ImplementationStatus::InProgress, ImplementationStatus::Implemented),
+            change("2025-03-04", ImplementationStatus::Implemented, ImplementationStatus::InProgress),
+            change("2025-03-05", ImplementationStatus::NotImplemented, ImplementationStatus::InProgress),
+            change("2025-03-10", ImplementationStatus::InProgress, ImplementationStatus::Implemented),
+        ];
+
+        let velocity = service.calculate_implementation_velocity(&changes, VelocityBucket::Week, 4);
+
+        let counts: Vec<usize> = velocity.iter().map(|p| p.implemented_count).collect();
+        assert_eq!(counts, vec![0, 2, 0, 1]);
+        assert_eq!(velocity[0].period_start, "2025-02-17T00:00:00Z".parse::<DateTime<Utc>>().unwrap());
+        assert_eq!(velocity[3].period_end, "2025-03-17T00:00:00Z".parse::<DateTime<Utc>>().unwrap());
+    }
+
+    #[test]
+    fn test_monthly_velocity_is_zero_filled_across_year_boundary() {
+        let clock = MockClock::new("2025-02-10T08:00:00Z".parse().unwrap());
+        let service = MetricsService::with_clock(Arc::new(clock));
+        let changes = vec![
+            change("2024-12-31", ImplementationStatus::InProgress, ImplementationStatus::Implemented),
+            change("2025-02-01", ImplementationStatus::InProgress, ImplementationStatus::Implemented),
+        ];
+
+        let velocity = service.calculate_implementation_velocity(&changes, VelocityBucket::Month, 3);
+
+        let counts: Vec<usize> = velocity.iter().map(|p| p.implemented_count).collect();
+        assert_eq!(counts, vec![1, 0, 1]);
+        assert_eq!(velocity[0].period_start, "2024-12-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap());
+        assert_eq!(velocity[1].period_start, "2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap());
+        assert!("fortnight".parse::<VelocityBucket>().is_err());
+    }
+}
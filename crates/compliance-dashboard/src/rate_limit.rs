@@ -0,0 +1,262 @@
+// Modified: 2025-09-20
+
+//! Per-identity API rate limiting
+//!
+//! Limits requests per authenticated caller instead of globally, so a bulk
+//! ingest service account can be given a higher ceiling than interactive
+//! users. The [`auth`](crate::auth) middleware identifies the caller by
+//! inserting a [`ClientIdentity`] into the request extensions; requests without
+//! one share the anonymous limit.
+
+use axum::{
+    extract::{Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Json, Response},
+};
+use chrono::Duration;
+use fedramp_core::clock::{system_clock, SharedClock};
+use fedramp_core::types::Timestamp;
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Identity used for requests without a [`ClientIdentity`]
+pub const ANONYMOUS_IDENTITY: &str = "anonymous";
+
+/// Number of tracked identities above which expired windows are pruned
+const PRUNE_THRESHOLD: usize = 1024;
+
+/// Authenticated caller, set by [`authenticate`](crate::auth::authenticate) from the request's API key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClientIdentity(pub String);
+
+/// Number of requests allowed per fixed window
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests allowed in each window
+    pub requests: u32,
+    /// Window length in seconds
+    pub window_seconds: u64,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            requests: 100,
+            window_seconds: 60,
+        }
+    }
+}
+
+/// Rate limits per identity with a default for everyone else
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Limit for identities without an override
+    #[serde(default)]
+    pub default: RateLimit,
+    /// Identity to limit overrides
+    #[serde(default)]
+    pub keys: HashMap<String, RateLimit>,
+}
+
+impl RateLimitConfig {
+    /// Load the configuration from a JSON file
+    pub fn from_file(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::configuration(format!("Failed to read rate limit config {}: {}", path, e)))?;
+        let config: Self = serde_json::from_str(&content)
+            .map_err(|e| Error::configuration(format!("Invalid rate limit config {}: {}", path, e)))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Check that every limit allows at least one request per non-empty window
+    pub fn validate(&self) -> Result<()> {
+        let limits = std::iter::once(("default", &self.default))
+            .chain(self.keys.iter().map(|(key, limit)| (key.as_str(), limit)));
+
+        for (key, limit) in limits {
+            if limit.requests == 0 || limit.window_seconds == 0 {
+                return Err(Error::configuration(format!(
+                    "Rate limit for '{}' must allow at least one request per non-empty window", key
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Limit that applies to an identity
+    pub fn limit_for(&self, identity: &str) -> RateLimit {
+        self.keys.get(identity).copied().unwrap_or(self.default)
+    }
+}
+
+/// Outcome of a rate limit check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitDecision {
+    /// The request may proceed
+    Allowed,
+    /// The identity has used its window; retry after the given duration
+    Throttled { retry_after: Duration },
+}
+
+/// Request count in the current window of one identity
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    started: Timestamp,
+    count: u32,
+}
+
+/// Fixed-window rate limiter keyed by client identity
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    windows: Mutex<HashMap<String, Window>>,
+    clock: SharedClock,
+}
+
+impl RateLimiter {
+    /// Create a rate limiter using the system clock
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self::with_clock(config, system_clock())
+    }
+
+    /// Create a rate limiter using the given time source
+    pub fn with_clock(config: RateLimitConfig, clock: SharedClock) -> Self {
+        Self {
+            config,
+            windows: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+
+    /// Count a request for an identity and decide whether it may proceed
+    pub fn check(&self, identity: &str) -> RateLimitDecision {
+        let limit = self.config.limit_for(identity);
+        let window_length = Duration::seconds(limit.window_seconds as i64);
+        let now = self.clock.now();
+
+        let mut windows = self.windows.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if windows.len() > PRUNE_THRESHOLD {
+            windows.retain(|_, window| now - window.started < window_length);
+        }
+
+        let window = windows.entry(identity.to_string()).or_insert(Window { started: now, count: 0 });
+        if now - window.started >= window_length {
+            *window = Window { started: now, count: 0 };
+        }
+
+        if window.count < limit.requests {
+            window.count += 1;
+            RateLimitDecision::Allowed
+        } else {
+            RateLimitDecision::Throttled {
+                retry_after: window.started + window_length - now,
+            }
+        }
+    }
+}
+
+/// Middleware rejecting requests over the caller's limit with `429 Too Many Requests`
+///
+/// Throttled responses carry a `Retry-After` header in whole seconds.
+pub async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let identity = request.extensions()
+        .get::<ClientIdentity>()
+        .map(|identity| identity.0.clone())
+        .unwrap_or_else(|| ANONYMOUS_IDENTITY.to_string());
+
+    match limiter.check(&identity) {
+        RateLimitDecision::Allowed => next.run(request).await,
+        RateLimitDecision::Throttled { retry_after } => {
+            // Round up so clients never retry before the window resets
+            let retry_after_secs = (retry_after.num_milliseconds() + 999) / 1000;
+            let body = Json(serde_json::json!({
+                "error": "Rate limit exceeded",
+                "status": StatusCode::TOO_MANY_REQUESTS.as_u16()
+            }));
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after_secs.max(1).to_string())],
+                body,
+            ).into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware, routing::get, Router};
+    use chrono::TimeZone;
+    use fedramp_core::MockClock;
+    use tower::ServiceExt;
+
+    /// Stand-in for the auth middleware: trusts an `X-Api-Key` header as the identity
+    async fn fake_auth(mut request: Request, next: Next) -> Response {
+        if let Some(key) = request.headers().get("x-api-key").and_then(|value| value.to_str().ok()) {
+            let identity = ClientIdentity(key.to_string());
+            request.extensions_mut().insert(identity);
+        }
+        next.run(request).await
+    }
+
+    fn router(limiter: Arc<RateLimiter>) -> Router {
+        Router::new()
+            .route("/api/controls", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(limiter, rate_limit))
+            .layer(middleware::from_fn(fake_auth))
+    }
+
+    async fn get_as(router: &Router, api_key: &str) -> Response {
+        router.clone()
+            .oneshot(Request::get("/api/controls").header("x-api-key", api_key).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    fn config() -> RateLimitConfig {
+        RateLimitConfig {
+            default: RateLimit { requests: 2, window_seconds: 60 },
+            keys: HashMap::from([("bulk-ingest".to_string(), RateLimit { requests: 5, window_seconds: 60 })]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_throttled_key_does_not_affect_other_keys() {
+        let clock = MockClock::new(chrono::Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        let router = router(Arc::new(RateLimiter::with_clock(config(), Arc::new(clock.clone()))));
+
+        assert_eq!(get_as(&router, "alice").await.status(), StatusCode::OK);
+        assert_eq!(get_as(&router, "alice").await.status(), StatusCode::OK);
+
+        clock.advance(Duration::seconds(15));
+        let throttled = get_as(&router, "alice").await;
+        assert_eq!(throttled.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(throttled.headers()[header::RETRY_AFTER], "45");
+
+        assert_eq!(get_as(&router, "bob").await.status(), StatusCode::OK);
+        for _ in 0..5 {
+            assert_eq!(get_as(&router, "bulk-ingest").await.status(), StatusCode::OK);
+        }
+        assert_eq!(get_as(&router, "bulk-ingest").await.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        clock.advance(Duration::seconds(45));
+        assert_eq!(get_as(&router, "alice").await.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_config_rejects_zero_limits() {
+        let mut config = config();
+        assert!(config.validate().is_ok());
+
+        config.keys.insert("broken".to_string(), RateLimit { requests: 0, window_seconds: 60 });
+        assert!(config.validate().is_err());
+    }
+}
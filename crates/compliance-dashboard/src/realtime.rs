@@ -2,15 +2,31 @@
 //!
 //! This module provides real-time communication capabilities for the dashboard,
 //! including WebSocket connections, event broadcasting, and live data updates.
+//!
+//! Each registered connection gets a bounded event queue. When a slow client
+//! lets its queue fill up, the configured [`OverflowPolicy`] either drops its
+//! oldest queued event or disconnects it, so one slow client cannot grow the
+//! server's memory without bound. Dropped events are counted in
+//! [`ConnectionStats`].
+//!
+//! A connection can narrow what it receives with a [`SubscriptionFilter`]
+//! sent along with its `Subscribe` message, e.g. only events about `AC-`
+//! controls. Events a connection does not receive are counted per connection.
 
 use fedramp_core::{Result, Error};
+use fedramp_core::clock::{system_clock, SharedClock};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::{broadcast, RwLock};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{broadcast, Notify, RwLock};
 use chrono::{DateTime, Utc};
+use tracing::warn;
 use uuid::Uuid;
 
+/// Default number of events queued per connection
+pub const DEFAULT_CLIENT_QUEUE_CAPACITY: usize = 256;
+
 /// Real-time service for managing WebSocket connections and events
 #[derive(Debug, Clone)]
 pub struct RealtimeService {
@@ -20,6 +36,165 @@ pub struct RealtimeService {
     event_sender: broadcast::Sender<RealtimeEvent>,
     /// Subscription manager
     subscriptions: Arc<RwLock<HashMap<String, Vec<String>>>>, // connection_id -> event_types
+    /// Time source for connection activity tracking
+    clock: SharedClock,
+    /// Bounded event queue per connection
+    queues: Arc<RwLock<HashMap<String, Arc<ClientQueue>>>>,
+    /// Queue size and overflow handling
+    backpressure: BackpressureConfig,
+    /// Events dropped from full queues, across all connections
+    dropped_events: Arc<AtomicU64>,
+    /// Connections closed because their queue overflowed
+    overflow_disconnects: Arc<AtomicU64>,
+}
+
+/// What to do when a connection's event queue is full
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OverflowPolicy {
+    /// Drop the oldest queued event to make room for the new one
+    #[default]
+    DropOldest,
+    /// Disconnect the client and discard its queued events
+    Disconnect,
+}
+
+/// Per-connection queue limits for broadcasts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackpressureConfig {
+    /// Events queued per connection before the overflow policy applies; at least 1
+    pub queue_capacity: usize,
+    /// Handling of events for a connection whose queue is full
+    pub overflow_policy: OverflowPolicy,
+}
+
+impl Default for BackpressureConfig {
+    fn default() -> Self {
+        Self {
+            queue_capacity: DEFAULT_CLIENT_QUEUE_CAPACITY,
+            overflow_policy: OverflowPolicy::DropOldest,
+        }
+    }
+}
+
+/// Bounded event queue of one connection
+#[derive(Debug, Default)]
+struct ClientQueue {
+    events: Mutex<VecDeque<RealtimeEvent>>,
+    notify: Notify,
+    closed: AtomicBool,
+    dropped: AtomicU64,
+    /// Broadcasts not queued because they did not match the subscriptions or filter
+    filtered: AtomicU64,
+}
+
+/// Outcome of queueing an event for a connection
+enum Delivery {
+    Queued,
+    /// Queued after dropping this many older events
+    DroppedOldest(u64),
+    /// Queue full under the disconnect policy; this many events were discarded
+    Overflowed(u64),
+}
+
+impl ClientQueue {
+    fn events(&self) -> std::sync::MutexGuard<'_, VecDeque<RealtimeEvent>> {
+        self.events.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn push(&self, event: RealtimeEvent, config: &BackpressureConfig) -> Delivery {
+        let mut events = self.events();
+        let capacity = config.queue_capacity.max(1);
+        let delivery = if events.len() < capacity {
+            events.push_back(event);
+            Delivery::Queued
+        } else {
+            match config.overflow_policy {
+                OverflowPolicy::DropOldest => {
+                    let mut dropped = 0;
+                    while events.len() >= capacity {
+                        events.pop_front();
+                        dropped += 1;
+                    }
+                    events.push_back(event);
+                    Delivery::DroppedOldest(dropped)
+                }
+                OverflowPolicy::Disconnect => {
+                    let dropped = events.len() as u64 + 1;
+                    events.clear();
+                    self.closed.store(true, Ordering::SeqCst);
+                    Delivery::Overflowed(dropped)
+                }
+            }
+        };
+        drop(events);
+
+        if let Delivery::DroppedOldest(dropped) | Delivery::Overflowed(dropped) = delivery {
+            self.dropped.fetch_add(dropped, Ordering::Relaxed);
+        }
+        self.notify.notify_one();
+        delivery
+    }
+
+    fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.notify.notify_one();
+    }
+}
+
+/// Receiving end of a connection's event queue
+#[derive(Debug, Clone)]
+pub struct ClientEventQueue {
+    queue: Arc<ClientQueue>,
+}
+
+impl ClientEventQueue {
+    /// Next queued event, waiting for one if the queue is empty
+    ///
+    /// Returns `None` once the connection is closed and its queue drained.
+    pub async fn recv(&self) -> Option<RealtimeEvent> {
+        loop {
+            let notified = self.queue.notify.notified();
+            if let Some(event) = self.try_recv() {
+                return Some(event);
+            }
+            if self.is_closed() {
+                return None;
+            }
+            notified.await;
+        }
+    }
+
+    /// Next queued event, if there is one
+    pub fn try_recv(&self) -> Option<RealtimeEvent> {
+        self.queue.events().pop_front()
+    }
+
+    /// Number of events waiting to be received
+    pub fn len(&self) -> usize {
+        self.queue.events().len()
+    }
+
+    /// Whether no events are waiting
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the connection was closed, e.g. after its queue overflowed
+    pub fn is_closed(&self) -> bool {
+        self.queue.closed.load(Ordering::SeqCst)
+    }
+
+    /// Events dropped from this queue because it was full
+    pub fn dropped_events(&self) -> u64 {
+        self.queue.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Broadcasts not queued because they did not match the connection's subscriptions or filter
+    pub fn filtered_events(&self) -> u64 {
+        self.queue.filtered.load(Ordering::Relaxed)
+    }
 }
 
 /// WebSocket connection information
@@ -30,6 +205,48 @@ pub struct Connection {
     pub connected_at: DateTime<Utc>,
     pub last_activity: DateTime<Utc>,
     pub subscriptions: Vec<String>,
+    /// Narrows the subscribed events further, if set
+    pub filter: Option<SubscriptionFilter>,
+}
+
+/// Which events a connection receives, beyond its event type subscriptions
+///
+/// Empty lists do not restrict. When `frameworks` or `control_prefixes` is
+/// set, only events about a listed framework or about a control whose ID
+/// starts with a listed prefix are delivered; events about neither, such as
+/// metrics updates, are not. Matching ignores case.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SubscriptionFilter {
+    /// Framework IDs, e.g. `nist-800-53`
+    pub frameworks: Vec<String>,
+    /// Control ID prefixes, e.g. `AC-` for the Access Control family
+    pub control_prefixes: Vec<String>,
+    /// Event types, as returned by [`RealtimeEvent::event_type`]
+    pub event_types: Vec<String>,
+}
+
+impl SubscriptionFilter {
+    /// Whether an event passes the filter
+    ///
+    /// A bulk update passes if any of its controls does.
+    pub fn matches(&self, event: &RealtimeEvent) -> bool {
+        if !self.event_types.is_empty()
+            && !self.event_types.iter().any(|event_type| event_type.eq_ignore_ascii_case(event.event_type()))
+        {
+            return false;
+        }
+        if self.frameworks.is_empty() && self.control_prefixes.is_empty() {
+            return true;
+        }
+
+        let framework_matches = event.framework_ids().into_iter()
+            .any(|framework_id| self.frameworks.iter().any(|framework| framework.eq_ignore_ascii_case(framework_id)));
+        framework_matches || event.control_ids().into_iter().any(|control_id| {
+            let control_id = control_id.to_uppercase();
+            self.control_prefixes.iter().any(|prefix| control_id.starts_with(&prefix.to_uppercase()))
+        })
+    }
 }
 
 /// Real-time event types
@@ -39,11 +256,20 @@ pub enum RealtimeEvent {
     /// Control status updated
     ControlStatusUpdated {
         control_id: String,
+        framework_id: Option<String>,
         old_status: String,
         new_status: String,
         updated_by: Option<String>,
         timestamp: DateTime<Utc>,
     },
+    /// Many control statuses updated in one bulk import
+    BulkControlsUpdated {
+        applied: usize,
+        skipped: usize,
+        changes: Vec<ControlStatusChange>,
+        updated_by: Option<String>,
+        timestamp: DateTime<Utc>,
+    },
     /// Metrics updated
     MetricsUpdated {
         metrics: MetricsUpdate,
@@ -54,6 +280,11 @@ pub enum RealtimeEvent {
         alert: AlertEvent,
         timestamp: DateTime<Utc>,
     },
+    /// Condition of an earlier alert no longer holds
+    AlertCleared {
+        alert_id: String,
+        timestamp: DateTime<Utc>,
+    },
     /// Framework data updated
     FrameworkUpdated {
         framework_id: String,
@@ -75,6 +306,51 @@ pub enum RealtimeEvent {
     },
 }
 
+impl RealtimeEvent {
+    /// Event type name, as used in subscriptions and the serialized `type` tag
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::ControlStatusUpdated { .. } => "ControlStatusUpdated",
+            Self::BulkControlsUpdated { .. } => "BulkControlsUpdated",
+            Self::MetricsUpdated { .. } => "MetricsUpdated",
+            Self::AlertGenerated { .. } => "AlertGenerated",
+            Self::AlertCleared { .. } => "AlertCleared",
+            Self::FrameworkUpdated { .. } => "FrameworkUpdated",
+            Self::SystemStatusChanged { .. } => "SystemStatusChanged",
+            Self::UserActivity { .. } => "UserActivity",
+        }
+    }
+
+    /// Frameworks the event is about
+    pub fn framework_ids(&self) -> Vec<&str> {
+        match self {
+            Self::FrameworkUpdated { framework_id, .. } => vec![framework_id.as_str()],
+            Self::ControlStatusUpdated { framework_id, .. } => framework_id.as_deref().into_iter().collect(),
+            Self::BulkControlsUpdated { changes, .. } => changes.iter().map(|change| change.framework_id.as_str()).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Controls the event is about
+    pub fn control_ids(&self) -> Vec<&str> {
+        match self {
+            Self::ControlStatusUpdated { control_id, .. } => vec![control_id.as_str()],
+            Self::BulkControlsUpdated { changes, .. } => changes.iter().map(|change| change.control_id.as_str()).collect(),
+            Self::AlertGenerated { alert, .. } => alert.related_control.as_deref().into_iter().collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Single control transition within a bulk update event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlStatusChange {
+    pub control_id: String,
+    pub framework_id: String,
+    pub old_status: String,
+    pub new_status: String,
+}
+
 /// Metrics update event data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetricsUpdate {
@@ -99,7 +375,7 @@ pub struct AlertEvent {
 }
 
 /// Alert severity levels
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum AlertSeverity {
     Info,
@@ -109,7 +385,7 @@ pub enum AlertSeverity {
 }
 
 /// Alert categories
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum AlertCategory {
     ComplianceGap,
@@ -117,6 +393,8 @@ pub enum AlertCategory {
     SystemIssue,
     DataQuality,
     SecurityConcern,
+    /// A KPI crossed an alert rule's threshold
+    KpiThreshold,
 }
 
 /// System status enumeration
@@ -133,11 +411,13 @@ pub enum SystemStatus {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum WebSocketMessage {
-    /// Subscribe to event types
+    /// Subscribe to event types, optionally replacing the connection's filter
     Subscribe {
         event_types: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filter: Option<SubscriptionFilter>,
     },
-    /// Unsubscribe from event types
+    /// Unsubscribe from event types and clear the connection's filter
     Unsubscribe {
         event_types: Vec<String>,
     },
@@ -163,29 +443,49 @@ pub enum WebSocketMessage {
 impl RealtimeService {
     /// Create a new real-time service
     pub fn new() -> Self {
+        Self::with_clock(system_clock())
+    }
+
+    /// Create a real-time service using the given time source
+    pub fn with_clock(clock: SharedClock) -> Self {
         let (event_sender, _) = broadcast::channel(1000);
         
         Self {
             connections: Arc::new(RwLock::new(HashMap::new())),
             event_sender,
             subscriptions: Arc::new(RwLock::new(HashMap::new())),
+            clock,
+            queues: Arc::new(RwLock::new(HashMap::new())),
+            backpressure: BackpressureConfig::default(),
+            dropped_events: Arc::new(AtomicU64::new(0)),
+            overflow_disconnects: Arc::new(AtomicU64::new(0)),
         }
     }
 
+    /// Use the given per-connection queue limits for broadcasts
+    pub fn with_backpressure(mut self, backpressure: BackpressureConfig) -> Self {
+        self.backpressure = backpressure;
+        self
+    }
+
     /// Register a new WebSocket connection
     pub async fn register_connection(&self, user_id: Option<String>) -> Result<String> {
         let connection_id = Uuid::new_v4().to_string();
         let connection = Connection {
             id: connection_id.clone(),
             user_id,
-            connected_at: Utc::now(),
-            last_activity: Utc::now(),
+            connected_at: self.clock.now(),
+            last_activity: self.clock.now(),
             subscriptions: Vec::new(),
+            filter: None,
         };
 
         let mut connections = self.connections.write().await;
         connections.insert(connection_id.clone(), connection);
 
+        let mut queues = self.queues.write().await;
+        queues.insert(connection_id.clone(), Arc::new(ClientQueue::default()));
+
         Ok(connection_id)
     }
 
@@ -197,9 +497,22 @@ impl RealtimeService {
         let mut subscriptions = self.subscriptions.write().await;
         subscriptions.remove(connection_id);
 
+        let mut queues = self.queues.write().await;
+        if let Some(queue) = queues.remove(connection_id) {
+            queue.close();
+        }
+
         Ok(())
     }
 
+    /// Event queue of a registered connection
+    pub async fn event_queue(&self, connection_id: &str) -> Result<ClientEventQueue> {
+        let queues = self.queues.read().await;
+        queues.get(connection_id)
+            .map(|queue| ClientEventQueue { queue: queue.clone() })
+            .ok_or_else(|| Error::not_found(format!("Connection not found: {}", connection_id)))
+    }
+
     /// Subscribe connection to event types
     pub async fn subscribe(&self, connection_id: &str, event_types: Vec<String>) -> Result<()> {
         // Update connection subscriptions
@@ -211,7 +524,7 @@ impl RealtimeService {
                         connection.subscriptions.push(event_type.clone());
                     }
                 }
-                connection.last_activity = Utc::now();
+                connection.last_activity = self.clock.now();
             } else {
                 return Err(Error::not_found(format!("Connection not found: {}", connection_id)));
             }
@@ -233,14 +546,28 @@ impl RealtimeService {
         Ok(())
     }
 
+    /// Set or replace the filter of a connection
+    pub async fn set_filter(&self, connection_id: &str, filter: SubscriptionFilter) -> Result<()> {
+        let mut connections = self.connections.write().await;
+        let connection = connections.get_mut(connection_id)
+            .ok_or_else(|| Error::not_found(format!("Connection not found: {}", connection_id)))?;
+        connection.filter = Some(filter);
+        connection.last_activity = self.clock.now();
+        Ok(())
+    }
+
     /// Unsubscribe connection from event types
+    ///
+    /// Also clears the connection's filter, so a later subscription starts
+    /// from its event types alone.
     pub async fn unsubscribe(&self, connection_id: &str, event_types: Vec<String>) -> Result<()> {
         // Update connection subscriptions
         {
             let mut connections = self.connections.write().await;
             if let Some(connection) = connections.get_mut(connection_id) {
                 connection.subscriptions.retain(|sub| !event_types.contains(sub));
-                connection.last_activity = Utc::now();
+                connection.filter = None;
+                connection.last_activity = self.clock.now();
             }
         }
 
@@ -255,13 +582,86 @@ impl RealtimeService {
         Ok(())
     }
 
+    /// Apply a message received from a connection
+    ///
+    /// Returns the reply to send back, if any: a `Pong` for a `Ping`.
+    pub async fn handle_message(&self, connection_id: &str, message: WebSocketMessage) -> Result<Option<WebSocketMessage>> {
+        match message {
+            WebSocketMessage::Subscribe { event_types, filter } => {
+                self.subscribe(connection_id, event_types).await?;
+                if let Some(filter) = filter {
+                    self.set_filter(connection_id, filter).await?;
+                }
+                Ok(None)
+            }
+            WebSocketMessage::Unsubscribe { event_types } => {
+                self.unsubscribe(connection_id, event_types).await?;
+                Ok(None)
+            }
+            WebSocketMessage::Ping { .. } => {
+                self.update_activity(connection_id).await?;
+                Ok(Some(WebSocketMessage::Pong { timestamp: self.clock.now() }))
+            }
+            WebSocketMessage::Pong { .. } => {
+                self.update_activity(connection_id).await?;
+                Ok(None)
+            }
+            WebSocketMessage::Event { .. } | WebSocketMessage::Error { .. } => {
+                Err(Error::validation("Event and Error messages are only sent by the server"))
+            }
+        }
+    }
+
     /// Broadcast an event to all subscribed connections
+    ///
+    /// The event is queued for every connection subscribed to its type, and
+    /// for connections without subscriptions, unless the connection's filter
+    /// rejects it. A full queue is handled by the configured overflow policy.
     pub async fn broadcast_event(&self, event: RealtimeEvent) -> Result<()> {
         // Send to broadcast channel
         if let Err(_) = self.event_sender.send(event.clone()) {
             // Channel might be full or have no receivers, which is okay
         }
 
+        let event_type = event.event_type();
+        let recipients: Vec<(String, Arc<ClientQueue>)> = {
+            let connections = self.connections.read().await;
+            let queues = self.queues.read().await;
+            connections.values()
+                .filter_map(|connection| {
+                    let queue = queues.get(&connection.id)?;
+                    let subscribed = connection.subscriptions.is_empty()
+                        || connection.subscriptions.iter().any(|subscription| subscription == event_type);
+                    if subscribed && connection.filter.as_ref().is_none_or(|filter| filter.matches(&event)) {
+                        Some((connection.id.clone(), queue.clone()))
+                    } else {
+                        queue.filtered.fetch_add(1, Ordering::Relaxed);
+                        None
+                    }
+                })
+                .collect()
+        };
+
+        let mut overflowed = Vec::new();
+        for (connection_id, queue) in recipients {
+            match queue.push(event.clone(), &self.backpressure) {
+                Delivery::Queued => {}
+                Delivery::DroppedOldest(dropped) => {
+                    self.dropped_events.fetch_add(dropped, Ordering::Relaxed);
+                }
+                Delivery::Overflowed(dropped) => {
+                    self.dropped_events.fetch_add(dropped, Ordering::Relaxed);
+                    overflowed.push(connection_id);
+                }
+            }
+        }
+
+        for connection_id in overflowed {
+            warn!("Disconnecting realtime connection {}: event queue overflowed", connection_id);
+            self.overflow_disconnects.fetch_add(1, Ordering::Relaxed);
+            self.unregister_connection(&connection_id).await?;
+        }
+
         Ok(())
     }
 
@@ -274,7 +674,7 @@ impl RealtimeService {
     pub async fn update_activity(&self, connection_id: &str) -> Result<()> {
         let mut connections = self.connections.write().await;
         if let Some(connection) = connections.get_mut(connection_id) {
-            connection.last_activity = Utc::now();
+            connection.last_activity = self.clock.now();
             Ok(())
         } else {
             Err(Error::not_found(format!("Connection not found: {}", connection_id)))
@@ -287,21 +687,28 @@ impl RealtimeService {
         let total_connections = connections.len();
         let active_connections = connections.values()
             .filter(|conn| {
-                let inactive_threshold = Utc::now() - chrono::Duration::minutes(5);
+                let inactive_threshold = self.clock.now() - chrono::Duration::minutes(5);
                 conn.last_activity > inactive_threshold
             })
             .count();
+        let filtered_events = self.queues.read().await
+            .iter()
+            .map(|(connection_id, queue)| (connection_id.clone(), queue.filtered.load(Ordering::Relaxed)))
+            .collect();
 
         ConnectionStats {
             total_connections,
             active_connections,
             inactive_connections: total_connections - active_connections,
+            dropped_events: self.dropped_events.load(Ordering::Relaxed),
+            overflow_disconnects: self.overflow_disconnects.load(Ordering::Relaxed),
+            filtered_events,
         }
     }
 
     /// Clean up inactive connections
     pub async fn cleanup_inactive_connections(&self, inactive_threshold_minutes: i64) -> Result<usize> {
-        let threshold = Utc::now() - chrono::Duration::minutes(inactive_threshold_minutes);
+        let threshold = self.clock.now() - chrono::Duration::minutes(inactive_threshold_minutes);
         let mut connections = self.connections.write().await;
         let mut subscriptions = self.subscriptions.write().await;
 
@@ -312,9 +719,13 @@ impl RealtimeService {
 
         let cleanup_count = inactive_connections.len();
 
+        let mut queues = self.queues.write().await;
         for connection_id in inactive_connections {
             connections.remove(&connection_id);
             subscriptions.remove(&connection_id);
+            if let Some(queue) = queues.remove(&connection_id) {
+                queue.close();
+            }
         }
 
         Ok(cleanup_count)
@@ -325,6 +736,7 @@ impl RealtimeService {
         // Control status update
         self.broadcast_event(RealtimeEvent::ControlStatusUpdated {
             control_id: "ac-1".to_string(),
+            framework_id: Some("nist-800-53".to_string()),
             old_status: "in-progress".to_string(),
             new_status: "implemented".to_string(),
             updated_by: Some("admin".to_string()),
@@ -368,6 +780,13 @@ pub struct ConnectionStats {
     pub total_connections: usize,
     pub active_connections: usize,
     pub inactive_connections: usize,
+    /// Events dropped from full connection queues
+    pub dropped_events: u64,
+    /// Connections closed because their queue overflowed
+    pub overflow_disconnects: u64,
+    /// Broadcasts not delivered to each connection because they did not match
+    /// its subscriptions or filter, by connection ID
+    pub filtered_events: HashMap<String, u64>,
 }
 
 impl Default for RealtimeService {
@@ -375,3 +794,205 @@ impl Default for RealtimeService {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_update(n: usize) -> RealtimeEvent {
+        RealtimeEvent::ControlStatusUpdated {
+            control_id: format!("ac-{}", n),
+            framework_id: None,
+            old_status: "planned".to_string(),
+            new_status: "implemented".to_string(),
+            updated_by: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    fn control_id(event: RealtimeEvent) -> String {
+        match event {
+            RealtimeEvent::ControlStatusUpdated { control_id, .. } => control_id,
+            other => panic!("unexpected event {}", other.event_type()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_consumer_drops_oldest_events() {
+        let service = RealtimeService::new().with_backpressure(BackpressureConfig {
+            queue_capacity: 3,
+            overflow_policy: OverflowPolicy::DropOldest,
+        });
+        let connection_id = service.register_connection(None).await.unwrap();
+        let queue = service.event_queue(&connection_id).await.unwrap();
+
+        // The consumer reads nothing while ten events are broadcast
+        for n in 1..=10 {
+            service.broadcast_event(status_update(n)).await.unwrap();
+        }
+
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.dropped_events(), 7);
+        let mut received = Vec::new();
+        while let Some(event) = queue.try_recv() {
+            received.push(control_id(event));
+        }
+        assert_eq!(received, ["ac-8", "ac-9", "ac-10"]);
+
+        let stats = service.get_connection_stats().await;
+        assert_eq!(stats.total_connections, 1);
+        assert_eq!(stats.dropped_events, 7);
+        assert_eq!(stats.overflow_disconnects, 0);
+    }
+
+    #[tokio::test]
+    async fn test_slow_consumer_is_disconnected_on_overflow() {
+        let service = RealtimeService::new().with_backpressure(BackpressureConfig {
+            queue_capacity: 2,
+            overflow_policy: OverflowPolicy::Disconnect,
+        });
+        let slow = service.register_connection(None).await.unwrap();
+        let fast = service.register_connection(None).await.unwrap();
+        let slow_queue = service.event_queue(&slow).await.unwrap();
+        let fast_queue = service.event_queue(&fast).await.unwrap();
+
+        for n in 1..=3 {
+            service.broadcast_event(status_update(n)).await.unwrap();
+            assert_eq!(control_id(fast_queue.recv().await.unwrap()), format!("ac-{}", n));
+        }
+
+        assert!(slow_queue.is_closed());
+        assert!(slow_queue.recv().await.is_none());
+        assert!(service.event_queue(&slow).await.is_err());
+        assert!(!fast_queue.is_closed());
+
+        let stats = service.get_connection_stats().await;
+        assert_eq!(stats.total_connections, 1);
+        assert_eq!(stats.dropped_events, 3);
+        assert_eq!(stats.overflow_disconnects, 1);
+    }
+
+    #[tokio::test]
+    async fn test_events_are_queued_only_for_subscribers() {
+        let service = RealtimeService::new();
+        let connection_id = service.register_connection(None).await.unwrap();
+        service.subscribe(&connection_id, vec!["AlertGenerated".to_string()]).await.unwrap();
+        let queue = service.event_queue(&connection_id).await.unwrap();
+
+        service.broadcast_event(status_update(1)).await.unwrap();
+
+        assert!(queue.is_empty());
+    }
+
+    fn framework_update(framework_id: &str) -> RealtimeEvent {
+        RealtimeEvent::FrameworkUpdated {
+            framework_id: framework_id.to_string(),
+            changes: Vec::new(),
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_filter_delivers_only_matching_events() {
+        let service = RealtimeService::new();
+        let filtered = service.register_connection(None).await.unwrap();
+        let unfiltered = service.register_connection(None).await.unwrap();
+        let message: WebSocketMessage = serde_json::from_value(serde_json::json!({
+            "type": "Subscribe",
+            "event_types": [],
+            "filter": { "frameworks": ["NIST-800-53"], "control_prefixes": ["AC-"] }
+        })).unwrap();
+        assert!(service.handle_message(&filtered, message).await.unwrap().is_none());
+        let queue = service.event_queue(&filtered).await.unwrap();
+
+        service.broadcast_event(status_update(2)).await.unwrap();
+        service.broadcast_event(RealtimeEvent::ControlStatusUpdated {
+            control_id: "ia-2".to_string(),
+            framework_id: None,
+            old_status: "planned".to_string(),
+            new_status: "implemented".to_string(),
+            updated_by: None,
+            timestamp: Utc::now(),
+        }).await.unwrap();
+        service.broadcast_event(framework_update("nist-800-53")).await.unwrap();
+        service.broadcast_event(framework_update("cis")).await.unwrap();
+
+        assert_eq!(control_id(queue.try_recv().unwrap()), "ac-2");
+        assert_eq!(queue.try_recv().unwrap().framework_ids(), ["nist-800-53"]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.filtered_events(), 2);
+
+        let stats = service.get_connection_stats().await;
+        assert_eq!(stats.filtered_events[&filtered], 2);
+        assert_eq!(stats.filtered_events[&unfiltered], 0);
+    }
+
+    #[tokio::test]
+    async fn test_framework_filter_matches_status_updates_by_framework() {
+        let service = RealtimeService::new();
+        let connection_id = service.register_connection(None).await.unwrap();
+        service.set_filter(&connection_id, SubscriptionFilter {
+            frameworks: vec!["nist-800-171".to_string()],
+            ..Default::default()
+        }).await.unwrap();
+        let queue = service.event_queue(&connection_id).await.unwrap();
+        let change = |control_id: &str, framework_id: &str| ControlStatusChange {
+            control_id: control_id.to_string(),
+            framework_id: framework_id.to_string(),
+            old_status: "planned".to_string(),
+            new_status: "implemented".to_string(),
+        };
+        let bulk = |changes: Vec<ControlStatusChange>| RealtimeEvent::BulkControlsUpdated {
+            applied: changes.len(),
+            skipped: 0,
+            changes,
+            updated_by: None,
+            timestamp: Utc::now(),
+        };
+
+        service.broadcast_event(RealtimeEvent::ControlStatusUpdated {
+            control_id: "3.1.1".to_string(),
+            framework_id: Some("nist-800-171".to_string()),
+            old_status: "planned".to_string(),
+            new_status: "implemented".to_string(),
+            updated_by: None,
+            timestamp: Utc::now(),
+        }).await.unwrap();
+        service.broadcast_event(status_update(2)).await.unwrap();
+        service.broadcast_event(bulk(vec![change("ac-2", "nist-800-53"), change("3.1.2", "nist-800-171")])).await.unwrap();
+        service.broadcast_event(bulk(vec![change("ac-3", "nist-800-53")])).await.unwrap();
+
+        assert_eq!(control_id(queue.try_recv().unwrap()), "3.1.1");
+        assert_eq!(queue.try_recv().unwrap().control_ids(), ["ac-2", "3.1.2"]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.filtered_events(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_and_reconnect_clear_the_filter() {
+        let service = RealtimeService::new();
+        let filter = SubscriptionFilter {
+            control_prefixes: vec!["AC-".to_string()],
+            ..Default::default()
+        };
+        let connection_id = service.register_connection(None).await.unwrap();
+        service.set_filter(&connection_id, filter.clone()).await.unwrap();
+        let queue = service.event_queue(&connection_id).await.unwrap();
+
+        service.broadcast_event(framework_update("cis")).await.unwrap();
+        assert!(queue.is_empty());
+
+        service.handle_message(&connection_id, WebSocketMessage::Unsubscribe { event_types: Vec::new() }).await.unwrap();
+        service.broadcast_event(framework_update("cis")).await.unwrap();
+        assert_eq!(queue.len(), 1);
+
+        service.set_filter(&connection_id, filter).await.unwrap();
+        service.unregister_connection(&connection_id).await.unwrap();
+        let reconnected = service.register_connection(None).await.unwrap();
+        let queue = service.event_queue(&reconnected).await.unwrap();
+        service.broadcast_event(framework_update("cis")).await.unwrap();
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue.filtered_events(), 0);
+        assert!(service.set_filter(&connection_id, SubscriptionFilter::default()).await.is_err());
+    }
+}
@@ -0,0 +1,346 @@
+// Modified: 2025-09-24
+
+//! Point-in-time snapshots of the dashboard overview
+//!
+//! Auditors compare compliance posture across time, so the overview is
+//! captured periodically into a [`SnapshotStore`] keyed by capture time. Two
+//! snapshots are compared with [`OverviewDiff::between`]: controls whose
+//! implementation status changed, movement in the status breakdown, and KPI
+//! deltas, each as a before/after pair.
+
+use crate::dashboard::{DashboardOverview, DashboardService, ImplementationStatus};
+use crate::metrics::{MetricsService, StatusBreakdown, TrendDirection, KPI, LOWER_IS_BETTER_KPIS};
+use chrono::{DateTime, Utc};
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Change in a value smaller than this is reported as stable
+const STABLE_EPSILON: f64 = 1e-9;
+
+/// Dashboard overview and metrics captured at one point in time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverviewSnapshot {
+    pub id: String,
+    pub taken_at: DateTime<Utc>,
+    pub overview: DashboardOverview,
+    pub status_breakdown: StatusBreakdown,
+    pub kpis: Vec<KPI>,
+    /// Implementation status of every control, by control ID
+    pub control_statuses: BTreeMap<String, ImplementationStatus>,
+}
+
+impl OverviewSnapshot {
+    /// Capture the current state of the dashboard
+    pub fn capture(dashboard: &DashboardService, metrics: &MetricsService, taken_at: DateTime<Utc>) -> Result<Self> {
+        let overview = dashboard.get_overview()?;
+        let controls = dashboard.controls();
+        let calculated = metrics.calculate_fresh_metrics(&controls, &overview.frameworks)?;
+
+        Ok(Self {
+            id: Uuid::new_v4().to_string(),
+            taken_at,
+            overview,
+            status_breakdown: calculated.status_breakdown,
+            kpis: calculated.kpis,
+            control_statuses: controls.into_iter()
+                .map(|control| (control.id, control.implementation_status))
+                .collect(),
+        })
+    }
+}
+
+/// Storage of overview snapshots, keyed by capture time
+pub trait SnapshotStore: Send + Sync + std::fmt::Debug {
+    /// Persist a snapshot, replacing any snapshot taken at the same time
+    fn save(&self, snapshot: OverviewSnapshot) -> Result<()>;
+
+    /// Snapshot with the given ID
+    fn get(&self, id: &str) -> Result<Option<OverviewSnapshot>>;
+
+    /// Latest snapshot taken at or before `timestamp`
+    fn at(&self, timestamp: DateTime<Utc>) -> Result<Option<OverviewSnapshot>>;
+
+    /// IDs and capture times of all snapshots, oldest first
+    fn list(&self) -> Result<Vec<SnapshotRef>>;
+}
+
+/// Snapshot store keeping snapshots in memory
+///
+/// With a retention limit, the oldest snapshots are discarded once the limit
+/// is reached.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySnapshotStore {
+    snapshots: Arc<Mutex<BTreeMap<DateTime<Utc>, OverviewSnapshot>>>,
+    retention: Option<usize>,
+}
+
+impl InMemorySnapshotStore {
+    /// Create an empty store without a retention limit
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most `max_snapshots` snapshots
+    pub fn with_retention(max_snapshots: usize) -> Self {
+        Self {
+            retention: Some(max_snapshots.max(1)),
+            ..Self::default()
+        }
+    }
+
+    fn snapshots(&self) -> Result<std::sync::MutexGuard<'_, BTreeMap<DateTime<Utc>, OverviewSnapshot>>> {
+        self.snapshots.lock().map_err(|_| Error::internal("Snapshot store lock poisoned"))
+    }
+}
+
+impl SnapshotStore for InMemorySnapshotStore {
+    fn save(&self, snapshot: OverviewSnapshot) -> Result<()> {
+        let mut snapshots = self.snapshots()?;
+        snapshots.insert(snapshot.taken_at, snapshot);
+        if let Some(retention) = self.retention {
+            while snapshots.len() > retention {
+                snapshots.pop_first();
+            }
+        }
+        Ok(())
+    }
+
+    fn get(&self, id: &str) -> Result<Option<OverviewSnapshot>> {
+        Ok(self.snapshots()?.values().find(|snapshot| snapshot.id == id).cloned())
+    }
+
+    fn at(&self, timestamp: DateTime<Utc>) -> Result<Option<OverviewSnapshot>> {
+        Ok(self.snapshots()?.range(..=timestamp).next_back().map(|(_, snapshot)| snapshot.clone()))
+    }
+
+    fn list(&self) -> Result<Vec<SnapshotRef>> {
+        Ok(self.snapshots()?.values().map(SnapshotRef::from).collect())
+    }
+}
+
+/// Snapshot for a reference given as a snapshot ID or an RFC 3339 timestamp
+///
+/// A timestamp selects the latest snapshot taken at or before it.
+pub fn resolve_snapshot(store: &dyn SnapshotStore, reference: &str) -> Result<OverviewSnapshot> {
+    let snapshot = match DateTime::parse_from_rfc3339(reference) {
+        Ok(timestamp) => store.at(timestamp.with_timezone(&Utc))?,
+        Err(_) => store.get(reference)?,
+    };
+    snapshot.ok_or_else(|| Error::not_found(format!("No overview snapshot for '{}'", reference)))
+}
+
+/// Identity of a snapshot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotRef {
+    pub id: String,
+    pub taken_at: DateTime<Utc>,
+}
+
+impl From<&OverviewSnapshot> for SnapshotRef {
+    fn from(snapshot: &OverviewSnapshot) -> Self {
+        Self {
+            id: snapshot.id.clone(),
+            taken_at: snapshot.taken_at,
+        }
+    }
+}
+
+/// Difference between two overview snapshots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverviewDiff {
+    pub before: SnapshotRef,
+    pub after: SnapshotRef,
+    /// Controls whose status differs, by control ID
+    pub control_changes: Vec<ControlStatusDiff>,
+    /// Movement of each status count
+    pub status_movement: Vec<CountDelta>,
+    pub implementation_percentage: ValueDelta,
+    /// KPIs present in either snapshot, by KPI ID
+    pub kpi_deltas: Vec<KpiDelta>,
+}
+
+/// Status of a control in two snapshots; `None` when the control is absent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlStatusDiff {
+    pub control_id: String,
+    pub before: Option<ImplementationStatus>,
+    pub after: Option<ImplementationStatus>,
+}
+
+/// Net movement of one status breakdown count
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CountDelta {
+    /// Breakdown field, e.g. `implemented`
+    pub status: String,
+    pub before: usize,
+    pub after: usize,
+    pub change: i64,
+}
+
+/// Change of a numeric value between two snapshots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValueDelta {
+    pub before: f64,
+    pub after: f64,
+    pub change: f64,
+}
+
+/// Change of a KPI between two snapshots
+///
+/// The direction accounts for KPIs where lower is better, such as overdue
+/// controls. A KPI missing from one snapshot counts as zero there.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KpiDelta {
+    pub id: String,
+    pub name: String,
+    pub unit: String,
+    pub before: Option<f64>,
+    pub after: Option<f64>,
+    pub change: f64,
+    pub direction: TrendDirection,
+}
+
+impl OverviewDiff {
+    /// Compare an earlier snapshot with a later one
+    pub fn between(before: &OverviewSnapshot, after: &OverviewSnapshot) -> Self {
+        let control_ids: BTreeSet<&String> = before.control_statuses.keys()
+            .chain(after.control_statuses.keys())
+            .collect();
+        let control_changes = control_ids.into_iter()
+            .filter_map(|control_id| {
+                let old = before.control_statuses.get(control_id);
+                let new = after.control_statuses.get(control_id);
+                (old != new).then(|| ControlStatusDiff {
+                    control_id: control_id.clone(),
+                    before: old.cloned(),
+                    after: new.cloned(),
+                })
+            })
+            .collect();
+
+        let (old, new) = (&before.status_breakdown, &after.status_breakdown);
+        let status_movement = [
+            ("implemented", old.implemented, new.implemented),
+            ("in_progress", old.in_progress, new.in_progress),
+            ("not_implemented", old.not_implemented, new.not_implemented),
+            ("not_applicable", old.not_applicable, new.not_applicable),
+            ("total", old.total, new.total),
+        ]
+        .into_iter()
+        .map(|(status, before, after)| CountDelta {
+            status: status.to_string(),
+            before,
+            after,
+            change: after as i64 - before as i64,
+        })
+        .collect();
+
+        let implementation_percentage = ValueDelta {
+            before: before.overview.implementation_percentage,
+            after: after.overview.implementation_percentage,
+            change: after.overview.implementation_percentage - before.overview.implementation_percentage,
+        };
+
+        Self {
+            before: SnapshotRef::from(before),
+            after: SnapshotRef::from(after),
+            control_changes,
+            status_movement,
+            implementation_percentage,
+            kpi_deltas: kpi_deltas(&before.kpis, &after.kpis),
+        }
+    }
+}
+
+fn kpi_deltas(before: &[KPI], after: &[KPI]) -> Vec<KpiDelta> {
+    let before: BTreeMap<&str, &KPI> = before.iter().map(|kpi| (kpi.id.as_str(), kpi)).collect();
+    let after: BTreeMap<&str, &KPI> = after.iter().map(|kpi| (kpi.id.as_str(), kpi)).collect();
+    let ids: BTreeSet<&str> = before.keys().chain(after.keys()).copied().collect();
+
+    ids.into_iter()
+        .map(|id| {
+            let old = before.get(id);
+            let new = after.get(id);
+            let kpi = new.or(old).expect("KPI ID comes from one of the snapshots");
+            let change = new.map_or(0.0, |kpi| kpi.value) - old.map_or(0.0, |kpi| kpi.value);
+            let improvement = if LOWER_IS_BETTER_KPIS.contains(&id) { -change } else { change };
+            KpiDelta {
+                id: id.to_string(),
+                name: kpi.name.clone(),
+                unit: kpi.unit.clone(),
+                before: old.map(|kpi| kpi.value),
+                after: new.map(|kpi| kpi.value),
+                change,
+                direction: if improvement > STABLE_EPSILON {
+                    TrendDirection::Improving
+                } else if improvement < -STABLE_EPSILON {
+                    TrendDirection::Declining
+                } else {
+                    TrendDirection::Stable
+                },
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_diff_reports_status_changes_and_kpi_directions() {
+        let mut dashboard = DashboardService::with_sample_data();
+        let metrics = MetricsService::new();
+        let start = Utc::now();
+        let before = OverviewSnapshot::capture(&dashboard, &metrics, start).unwrap();
+
+        dashboard.update_control_status("ac-2", ImplementationStatus::Implemented).unwrap();
+        let after = OverviewSnapshot::capture(&dashboard, &metrics, start + Duration::hours(1)).unwrap();
+
+        let diff = OverviewDiff::between(&before, &after);
+        assert_eq!(diff.control_changes, [ControlStatusDiff {
+            control_id: "ac-2".to_string(),
+            before: Some(ImplementationStatus::InProgress),
+            after: Some(ImplementationStatus::Implemented),
+        }]);
+        let movement: BTreeMap<&str, i64> = diff.status_movement.iter()
+            .map(|delta| (delta.status.as_str(), delta.change))
+            .collect();
+        assert_eq!(movement["implemented"], 1);
+        assert_eq!(movement["in_progress"], -1);
+        assert_eq!(movement["total"], 0);
+        assert!(diff.implementation_percentage.change > 0.0);
+
+        let rate = diff.kpi_deltas.iter().find(|kpi| kpi.id == "implementation-rate").unwrap();
+        assert!(matches!(rate.direction, TrendDirection::Improving));
+        let overdue = diff.kpi_deltas.iter().find(|kpi| kpi.id == "overdue-controls").unwrap();
+        assert!(matches!(overdue.direction, TrendDirection::Stable));
+    }
+
+    #[test]
+    fn test_store_resolves_ids_and_timestamps() {
+        let dashboard = DashboardService::with_sample_data();
+        let metrics = MetricsService::new();
+        let store = InMemorySnapshotStore::with_retention(2);
+        let start = Utc::now();
+        let snapshots: Vec<OverviewSnapshot> = (0..3)
+            .map(|hour| OverviewSnapshot::capture(&dashboard, &metrics, start + Duration::hours(hour)).unwrap())
+            .collect();
+        for snapshot in &snapshots {
+            store.save(snapshot.clone()).unwrap();
+        }
+
+        assert_eq!(store.list().unwrap().len(), 2);
+        assert!(store.get(&snapshots[0].id).unwrap().is_none());
+        assert_eq!(resolve_snapshot(&store, &snapshots[2].id).unwrap().id, snapshots[2].id);
+
+        let between = (start + Duration::minutes(90)).to_rfc3339();
+        assert_eq!(resolve_snapshot(&store, &between).unwrap().id, snapshots[1].id);
+        assert!(resolve_snapshot(&store, &start.to_rfc3339()).is_err());
+        assert!(resolve_snapshot(&store, "unknown").is_err());
+    }
+}
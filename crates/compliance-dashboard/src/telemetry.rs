@@ -0,0 +1,256 @@
+// Modified: 2025-09-20
+
+//! Prometheus metrics export
+//!
+//! Collects request counts and latencies per route, document parse durations
+//! and gap analysis workflow timings in one registry, rendered in the
+//! Prometheus text exposition format by the `/metrics` endpoint. Parse and
+//! workflow timings arrive through the [`MetricsRecorder`] implementation,
+//! injected with `DocumentParserFactory::with_metrics` and
+//! `GapAnalysisServiceBuilder::metrics`.
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use fedramp_core::{Error, MetricsRecorder, Result};
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, Registry, TextEncoder,
+};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Route label used for requests that matched no route
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Histogram buckets for workflow and document parse durations, in seconds
+const PROCESSING_BUCKETS: [f64; 10] = [0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// Prometheus metrics registry for the API server
+#[derive(Debug, Clone)]
+pub struct PrometheusMetrics {
+    registry: Registry,
+    http_requests_total: IntCounterVec,
+    http_request_duration_seconds: HistogramVec,
+    document_parse_duration_seconds: HistogramVec,
+    gap_analysis_workflows_total: IntCounter,
+    gap_analysis_workflow_duration_seconds: Histogram,
+    gap_analysis_step_duration_seconds: HistogramVec,
+}
+
+impl PrometheusMetrics {
+    /// Create a registry with all API server metrics registered
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests_total = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests handled, by route and status"),
+            &["method", "route", "status"],
+        ).expect("valid metric definition");
+        let http_request_duration_seconds = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "HTTP request latency, by route"),
+            &["method", "route"],
+        ).expect("valid metric definition");
+        let document_parse_duration_seconds = HistogramVec::new(
+            HistogramOpts::new("document_parse_duration_seconds", "Document parse duration, by document type")
+                .buckets(PROCESSING_BUCKETS.to_vec()),
+            &["document_type"],
+        ).expect("valid metric definition");
+        let gap_analysis_workflows_total = IntCounter::new(
+            "gap_analysis_workflows_total",
+            "Completed gap analysis workflows",
+        ).expect("valid metric definition");
+        let gap_analysis_workflow_duration_seconds = Histogram::with_opts(
+            HistogramOpts::new("gap_analysis_workflow_duration_seconds", "Total gap analysis workflow duration")
+                .buckets(PROCESSING_BUCKETS.to_vec()),
+        ).expect("valid metric definition");
+        let gap_analysis_step_duration_seconds = HistogramVec::new(
+            HistogramOpts::new("gap_analysis_step_duration_seconds", "Gap analysis workflow step duration, by step")
+                .buckets(PROCESSING_BUCKETS.to_vec()),
+            &["step"],
+        ).expect("valid metric definition");
+
+        // Names are fixed and distinct, so registration into a fresh registry cannot fail
+        registry.register(Box::new(http_requests_total.clone())).expect("unique metric name");
+        registry.register(Box::new(http_request_duration_seconds.clone())).expect("unique metric name");
+        registry.register(Box::new(document_parse_duration_seconds.clone())).expect("unique metric name");
+        registry.register(Box::new(gap_analysis_workflows_total.clone())).expect("unique metric name");
+        registry.register(Box::new(gap_analysis_workflow_duration_seconds.clone())).expect("unique metric name");
+        registry.register(Box::new(gap_analysis_step_duration_seconds.clone())).expect("unique metric name");
+
+        Self {
+            registry,
+            http_requests_total,
+            http_request_duration_seconds,
+            document_parse_duration_seconds,
+            gap_analysis_workflows_total,
+            gap_analysis_workflow_duration_seconds,
+            gap_analysis_step_duration_seconds,
+        }
+    }
+
+    /// Record a handled HTTP request
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        self.http_requests_total
+            .with_label_values(&[method, route, &status.to_string()])
+            .inc();
+        self.http_request_duration_seconds
+            .with_label_values(&[method, route])
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Render all metrics in the Prometheus text exposition format
+    pub fn render(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .map_err(|e| Error::internal(format!("Failed to encode Prometheus metrics: {}", e)))?;
+        String::from_utf8(buffer)
+            .map_err(|e| Error::internal(format!("Prometheus metrics are not valid UTF-8: {}", e)))
+    }
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MetricsRecorder for PrometheusMetrics {
+    fn observe_document_parse(&self, document_type: &str, duration: Duration) {
+        self.document_parse_duration_seconds
+            .with_label_values(&[document_type])
+            .observe(duration.as_secs_f64());
+    }
+
+    fn observe_workflow_step(&self, step: &str, duration: Duration) {
+        self.gap_analysis_step_duration_seconds
+            .with_label_values(&[step])
+            .observe(duration.as_secs_f64());
+    }
+
+    fn observe_workflow(&self, duration: Duration) {
+        self.gap_analysis_workflows_total.inc();
+        self.gap_analysis_workflow_duration_seconds.observe(duration.as_secs_f64());
+    }
+}
+
+/// Middleware recording the count and latency of every request
+///
+/// Requests are labelled with the matched route template (e.g.
+/// `/api/controls/:id`) rather than the raw path to keep label cardinality
+/// bounded.
+pub async fn track_requests(
+    State(metrics): State<Arc<PrometheusMetrics>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().to_string();
+    let route = request.extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+
+    let start = Instant::now();
+    let response = next.run(request).await;
+    metrics.observe_request(&method, &route, response.status().as_u16(), start.elapsed());
+
+    response
+}
+
+/// Serve the registry in the Prometheus text exposition format
+pub async fn metrics_handler(State(metrics): State<Arc<PrometheusMetrics>>) -> Response {
+    match metrics.render() {
+        Ok(body) => (
+            [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)],
+            body,
+        ).into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{create_router_with_config, ApiConfig, ComplianceDashboard};
+    use axum::body::{to_bytes, Body};
+    use tower::ServiceExt;
+
+    async fn get(router: &axum::Router, uri: &str) -> (StatusCode, Option<String>, String) {
+        let response = router.clone()
+            .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let content_type = response.headers()
+            .get(header::CONTENT_TYPE)
+            .map(|value| value.to_str().unwrap().to_string());
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, content_type, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_exports_prometheus_text_format() {
+        let metrics = Arc::new(PrometheusMetrics::new());
+        let router = create_router_with_config(ComplianceDashboard::new(), ApiConfig::default().with_metrics(metrics.clone()));
+
+        get(&router, "/health").await;
+        get(&router, "/health").await;
+        let (status, content_type, body) = get(&router, "/metrics").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(content_type.as_deref(), Some(prometheus::TEXT_FORMAT));
+        assert!(body.contains("# TYPE http_requests_total counter"));
+        assert!(body.contains("# TYPE http_request_duration_seconds histogram"));
+        assert!(body.contains(r#"http_requests_total{method="GET",route="/health",status="200"} 2"#));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_exports_parse_and_workflow_timings() {
+        let profile_path = std::env::temp_dir().join(format!("metrics-profile-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&profile_path, r#"{
+            "profile": {
+                "uuid": "8c000726-ba93-480f-a221-8894dc7747f5",
+                "metadata": { "title": "Test Moderate Baseline", "version": "1.0.0" },
+                "imports": [ { "href": "catalog.json", "include-controls": [ { "with-ids": ["ac-1", "ac-2"] } ] } ]
+            }
+        }"#).unwrap();
+        let metrics = Arc::new(PrometheusMetrics::new());
+        let router = create_router_with_config(ComplianceDashboard::new(), ApiConfig::default().with_metrics(metrics.clone()));
+
+        let mut service = gap_analysis::GapAnalysisService::builder()
+            .oscal_profile(profile_path.to_string_lossy(), "nist-800-53", "moderate")
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+        let implementation = gap_analysis::engine::CurrentImplementation {
+            system_id: "test-system".to_string(),
+            controls: std::collections::HashMap::new(),
+            last_updated: chrono::Utc::now(),
+        };
+        service.execute_workflow(&implementation, None, None).await.unwrap();
+        metrics.observe_document_parse("excel", Duration::from_millis(120));
+        let (_, _, body) = get(&router, "/metrics").await;
+
+        assert!(body.contains(r#"document_parse_duration_seconds_count{document_type="excel"} 1"#));
+        assert!(body.contains(r#"gap_analysis_step_duration_seconds_count{step="gap_analysis"} 1"#));
+        assert!(body.contains("gap_analysis_workflow_duration_seconds_count 1"));
+        assert!(body.contains("gap_analysis_workflows_total 1"));
+        std::fs::remove_file(profile_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_unmatched_requests_share_one_route_label() {
+        let metrics = Arc::new(PrometheusMetrics::new());
+        let router = create_router_with_config(ComplianceDashboard::new(), ApiConfig::default().with_metrics(metrics.clone()));
+
+        get(&router, "/does-not-exist").await;
+        get(&router, "/also/missing").await;
+
+        let body = metrics.render().unwrap();
+        assert!(body.contains(r#"http_requests_total{method="GET",route="unmatched",status="404"} 2"#));
+        assert!(!body.contains("/does-not-exist"));
+    }
+}
@@ -0,0 +1,347 @@
+// Modified: 2025-09-20
+
+//! Mapping Confidence Calibration
+//!
+//! Collects reviewer accept/reject feedback on control mappings and uses the
+//! historical acceptance rate of each mapping source to calibrate the
+//! confidence scores of the mappings that source produced.
+
+use chrono::{DateTime, Utc};
+use fedramp_core::clock::{system_clock, SharedClock};
+use fedramp_core::models::ControlMapping;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Reviewer decision on a proposed mapping
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedbackDecision {
+    /// Reviewer confirmed the mapping
+    Accepted,
+    /// Reviewer rejected the mapping
+    Rejected,
+}
+
+/// A single piece of reviewer feedback
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MappingFeedback {
+    /// Mapping the feedback applies to
+    pub mapping_id: Uuid,
+    /// Source that produced the mapping (e.g. "nist-official", "fuzzy-matcher")
+    pub source: String,
+    /// Reviewer decision
+    pub decision: FeedbackDecision,
+    /// Reviewer identifier, if known
+    pub reviewer: Option<String>,
+    /// When the feedback was recorded
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Calibration settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationConfig {
+    /// Acceptance rate assumed for a source with no feedback
+    pub prior_acceptance_rate: f64,
+    /// Weight of the prior, expressed as a number of virtual reviews
+    pub prior_weight: f64,
+    /// Lower bound for the confidence multiplier
+    pub min_factor: f64,
+    /// Upper bound for the confidence multiplier
+    pub max_factor: f64,
+}
+
+impl Default for CalibrationConfig {
+    fn default() -> Self {
+        Self {
+            prior_acceptance_rate: 0.8,
+            prior_weight: 5.0,
+            min_factor: 0.1,
+            max_factor: 1.25,
+        }
+    }
+}
+
+/// Accept/reject counts for a mapping source
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceAcceptance {
+    pub accepted: u32,
+    pub rejected: u32,
+}
+
+impl SourceAcceptance {
+    /// Total number of reviews
+    pub fn total(&self) -> u32 {
+        self.accepted + self.rejected
+    }
+}
+
+/// Result of a recalibration run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CalibrationSummary {
+    /// Number of mappings whose confidence changed
+    pub adjusted: usize,
+    /// Number of mappings with no known source, left untouched
+    pub skipped: usize,
+    /// Confidence multiplier applied per source
+    pub source_factors: HashMap<String, f64>,
+}
+
+/// In-memory store of reviewer feedback used for confidence calibration
+#[derive(Debug, Clone)]
+pub struct FeedbackStore {
+    /// Calibration settings
+    pub config: CalibrationConfig,
+    /// Time source for feedback and recalibration timestamps
+    clock: SharedClock,
+    feedback: Vec<MappingFeedback>,
+    acceptance: HashMap<String, SourceAcceptance>,
+    mapping_sources: HashMap<Uuid, String>,
+    base_confidences: HashMap<Uuid, f64>,
+}
+
+impl Default for FeedbackStore {
+    fn default() -> Self {
+        Self::with_config(CalibrationConfig::default())
+    }
+}
+
+impl FeedbackStore {
+    /// Create an empty store with default calibration settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty store with custom calibration settings
+    pub fn with_config(config: CalibrationConfig) -> Self {
+        Self {
+            config,
+            clock: system_clock(),
+            feedback: Vec::new(),
+            acceptance: HashMap::new(),
+            mapping_sources: HashMap::new(),
+            base_confidences: HashMap::new(),
+        }
+    }
+
+    /// Use the given time source for feedback and recalibration timestamps
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Associate a mapping with the source that produced it
+    ///
+    /// Mappings are also registered implicitly by [`record_feedback`](Self::record_feedback).
+    pub fn register_mapping(&mut self, mapping_id: Uuid, source: impl Into<String>) {
+        self.mapping_sources.insert(mapping_id, source.into());
+    }
+
+    /// Record a reviewer decision for a mapping
+    pub fn record_feedback(
+        &mut self,
+        mapping_id: Uuid,
+        source: impl Into<String>,
+        decision: FeedbackDecision,
+        reviewer: Option<String>,
+    ) {
+        let source = source.into();
+        let counts = self.acceptance.entry(source.clone()).or_default();
+        match decision {
+            FeedbackDecision::Accepted => counts.accepted += 1,
+            FeedbackDecision::Rejected => counts.rejected += 1,
+        }
+
+        self.mapping_sources.insert(mapping_id, source.clone());
+        self.feedback.push(MappingFeedback {
+            mapping_id,
+            source,
+            decision,
+            reviewer,
+            recorded_at: self.clock.now(),
+        });
+    }
+
+    /// All recorded feedback, oldest first
+    pub fn feedback(&self) -> &[MappingFeedback] {
+        &self.feedback
+    }
+
+    /// Accept/reject counts for a source
+    pub fn acceptance(&self, source: &str) -> SourceAcceptance {
+        self.acceptance.get(source).copied().unwrap_or_default()
+    }
+
+    /// Smoothed acceptance rate for a source
+    ///
+    /// The observed rate is blended with `prior_acceptance_rate` so that a
+    /// handful of reviews does not swing confidences to the extremes.
+    pub fn acceptance_rate(&self, source: &str) -> f64 {
+        let counts = self.acceptance(source);
+        let prior = self.config.prior_acceptance_rate;
+        let weight = self.config.prior_weight.max(0.0);
+        let total = f64::from(counts.total()) + weight;
+
+        if total <= 0.0 {
+            return prior;
+        }
+
+        (f64::from(counts.accepted) + prior * weight) / total
+    }
+
+    /// Confidence multiplier for a source
+    ///
+    /// A source with no feedback gets a factor of `1.0`.
+    pub fn calibration_factor(&self, source: &str) -> f64 {
+        let prior = self.config.prior_acceptance_rate;
+        if prior <= 0.0 {
+            return 1.0;
+        }
+
+        (self.acceptance_rate(source) / prior).clamp(self.config.min_factor, self.config.max_factor)
+    }
+
+    /// Effective confidence for a base score produced by a source
+    pub fn calibrated_confidence(&self, source: &str, base_confidence: f64) -> f64 {
+        (base_confidence * self.calibration_factor(source)).clamp(0.0, 1.0)
+    }
+
+    /// Recalibrate mapping confidences from the recorded feedback
+    ///
+    /// The first time a mapping is recalibrated its current confidence is kept
+    /// as the base score, so repeated runs do not compound adjustments.
+    /// Mappings without a registered source are left untouched.
+    pub fn recalibrate(&mut self, mappings: &mut [ControlMapping]) -> CalibrationSummary {
+        let mut summary = CalibrationSummary::default();
+
+        for mapping in mappings.iter_mut() {
+            let Some(source) = self.mapping_sources.get(&mapping.id) else {
+                summary.skipped += 1;
+                continue;
+            };
+
+            let base = *self
+                .base_confidences
+                .entry(mapping.id)
+                .or_insert(mapping.confidence_score);
+            let factor = self.calibration_factor(source);
+            let calibrated = (base * factor).clamp(0.0, 1.0);
+
+            summary.source_factors.insert(source.clone(), factor);
+            if (calibrated - mapping.confidence_score).abs() > f64::EPSILON {
+                mapping.confidence_score = calibrated;
+                mapping.updated_at = self.clock.now();
+                summary.adjusted += 1;
+            }
+        }
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use fedramp_core::models::{Framework, MappingType};
+    use fedramp_core::MockClock;
+    use std::sync::Arc;
+
+    fn mapping(confidence: f64) -> ControlMapping {
+        ControlMapping::new(
+            "3.1.1".to_string(),
+            Framework::Nist800171,
+            "AC-2".to_string(),
+            Framework::Nist80053,
+            confidence,
+            MappingType::Partial,
+        )
+    }
+
+    #[test]
+    fn test_no_feedback_keeps_confidence() {
+        let mut store = FeedbackStore::new();
+        let mut mappings = vec![mapping(0.7)];
+        store.register_mapping(mappings[0].id, "fuzzy-matcher");
+
+        let summary = store.recalibrate(&mut mappings);
+
+        assert_eq!(summary.adjusted, 0);
+        assert!((mappings[0].confidence_score - 0.7).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_repeated_rejections_lower_confidence() {
+        let mut store = FeedbackStore::new();
+        let mut mappings = vec![mapping(0.9), mapping(0.9)];
+        let reviewed = mappings[0].id;
+        store.register_mapping(mappings[1].id, "fuzzy-matcher");
+
+        let mut previous = mappings[1].confidence_score;
+        for _ in 0..5 {
+            store.record_feedback(reviewed, "fuzzy-matcher", FeedbackDecision::Rejected, None);
+            store.recalibrate(&mut mappings);
+            assert!(mappings[1].confidence_score < previous);
+            previous = mappings[1].confidence_score;
+        }
+
+        assert_eq!(store.acceptance("fuzzy-matcher").rejected, 5);
+        assert!(mappings[0].confidence_score < 0.9 * 0.6);
+    }
+
+    #[test]
+    fn test_recalibration_is_idempotent() {
+        let mut store = FeedbackStore::new();
+        let mut mappings = vec![mapping(0.8)];
+        store.record_feedback(mappings[0].id, "fuzzy-matcher", FeedbackDecision::Rejected, None);
+
+        store.recalibrate(&mut mappings);
+        let first = mappings[0].confidence_score;
+        let summary = store.recalibrate(&mut mappings);
+
+        assert_eq!(summary.adjusted, 0);
+        assert!((mappings[0].confidence_score - first).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sources_are_calibrated_independently() {
+        let mut store = FeedbackStore::new();
+        let mut mappings = vec![mapping(0.6), mapping(0.6), mapping(0.6)];
+        store.register_mapping(mappings[2].id, "manual");
+        for _ in 0..10 {
+            store.record_feedback(mappings[0].id, "nist-official", FeedbackDecision::Accepted, None);
+            store.record_feedback(mappings[1].id, "fuzzy-matcher", FeedbackDecision::Rejected, None);
+        }
+
+        let summary = store.recalibrate(&mut mappings);
+
+        assert!(mappings[0].confidence_score > 0.6);
+        assert!(mappings[1].confidence_score < 0.6);
+        assert!((mappings[2].confidence_score - 0.6).abs() < f64::EPSILON);
+        assert_eq!(summary.source_factors.len(), 3);
+    }
+
+    #[test]
+    fn test_unregistered_mappings_are_skipped() {
+        let mut store = FeedbackStore::new();
+        let mut mappings = vec![mapping(0.5)];
+
+        let summary = store.recalibrate(&mut mappings);
+
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.adjusted, 0);
+    }
+
+    #[test]
+    fn test_timestamps_come_from_the_clock() {
+        let now = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        let mut store = FeedbackStore::new().with_clock(Arc::new(MockClock::new(now)));
+        let mut mappings = vec![mapping(0.9)];
+        store.record_feedback(mappings[0].id, "fuzzy-matcher", FeedbackDecision::Rejected, Some("reviewer".to_string()));
+
+        store.recalibrate(&mut mappings);
+
+        assert_eq!(store.feedback()[0].recorded_at, now);
+        assert_eq!(mappings[0].updated_at, now);
+    }
+}
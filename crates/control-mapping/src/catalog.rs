@@ -0,0 +1,290 @@
+// Modified: 2025-09-20
+
+//! Control Catalog
+//!
+//! Queryable NIST SP 800-53 Rev 5 control catalog, so gaps and dashboards can
+//! show control titles and families instead of bare IDs. The bundled catalog
+//! carries every control and enhancement that is not withdrawn, with its
+//! title and statement; another release of the catalog can be loaded from
+//! OSCAL with [`Catalog::from_oscal_json`].
+
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Bundled NIST SP 800-53 Rev 5 families, controls and enhancements
+const NIST_800_53_REV5: &str = include_str!("../data/nist_800_53_rev5.json");
+
+/// Control family, e.g. `AC` Access Control
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogFamily {
+    /// Family prefix, uppercase
+    pub family_id: String,
+    /// Family title
+    pub title: String,
+}
+
+/// Control or control enhancement in a catalog
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogControl {
+    /// Control ID in display form, e.g. `AC-2` or `AC-2(1)`
+    pub control_id: String,
+    /// Control title
+    pub title: String,
+    /// Prefix of the control's family
+    pub family_id: String,
+    /// Control statement, when the catalog source includes it
+    pub description: Option<String>,
+}
+
+/// Controls of a catalog, indexed by control ID
+#[derive(Debug, Clone)]
+pub struct Catalog {
+    /// Catalog title
+    pub name: String,
+    /// Catalog release
+    pub version: String,
+    families: Vec<CatalogFamily>,
+    controls: Vec<CatalogControl>,
+    index: HashMap<String, usize>,
+}
+
+/// Layout of the bundled catalog file
+#[derive(Deserialize)]
+struct BundledCatalog {
+    catalog: String,
+    version: String,
+    families: Vec<BundledFamily>,
+}
+
+#[derive(Deserialize)]
+struct BundledFamily {
+    id: String,
+    title: String,
+    controls: Vec<BundledControl>,
+}
+
+#[derive(Deserialize)]
+struct BundledControl {
+    id: String,
+    title: String,
+    #[serde(default)]
+    statement: Option<String>,
+}
+
+impl Catalog {
+    /// Load the bundled NIST SP 800-53 Rev 5 catalog
+    ///
+    /// Covers every family, control and enhancement that is not withdrawn.
+    /// A control's description is its statement, one item per line.
+    pub fn load_nist_800_53_rev5() -> Result<Self> {
+        let bundled: BundledCatalog = serde_json::from_str(NIST_800_53_REV5)?;
+
+        let mut families = Vec::new();
+        let mut controls = Vec::new();
+        for family in bundled.families {
+            let family_id = family.id.to_uppercase();
+            controls.extend(family.controls.into_iter().map(|control| CatalogControl {
+                control_id: canonical_control_id(&control.id),
+                title: control.title,
+                family_id: family_id.clone(),
+                description: control.statement,
+            }));
+            families.push(CatalogFamily { family_id, title: family.title });
+        }
+
+        Ok(Self::new(bundled.catalog, bundled.version, families, controls))
+    }
+
+    /// Load a catalog from OSCAL catalog JSON, such as NIST's published 800-53 catalog
+    ///
+    /// Controls and enhancements are taken from every group; withdrawn ones
+    /// are skipped. A control's description is the prose of its statement.
+    pub fn from_oscal_json(json: &str) -> Result<Self> {
+        let document: Value = serde_json::from_str(json)?;
+        let catalog = document.get("catalog")
+            .ok_or_else(|| Error::validation("OSCAL catalog is missing the 'catalog' object"))?;
+        let metadata = &catalog["metadata"];
+
+        let mut families = Vec::new();
+        let mut controls = Vec::new();
+        for group in catalog["groups"].as_array().into_iter().flatten() {
+            let family_id = group["id"].as_str()
+                .ok_or_else(|| Error::validation("OSCAL catalog group is missing its 'id'"))?
+                .to_uppercase();
+            collect_oscal_controls(&group["controls"], &family_id, &mut controls);
+            families.push(CatalogFamily {
+                family_id,
+                title: group["title"].as_str().unwrap_or_default().to_string(),
+            });
+        }
+
+        Ok(Self::new(
+            metadata["title"].as_str().unwrap_or("OSCAL catalog").to_string(),
+            metadata["version"].as_str().unwrap_or_default().to_string(),
+            families,
+            controls,
+        ))
+    }
+
+    fn new(name: String, version: String, families: Vec<CatalogFamily>, controls: Vec<CatalogControl>) -> Self {
+        let index = controls.iter()
+            .enumerate()
+            .map(|(position, control)| (control.control_id.clone(), position))
+            .collect();
+        Self { name, version, families, controls, index }
+    }
+
+    /// Control by ID, in display (`AC-2(1)`) or OSCAL (`ac-2.1`) form
+    pub fn get(&self, control_id: &str) -> Option<&CatalogControl> {
+        self.index.get(&canonical_control_id(control_id)).map(|&position| &self.controls[position])
+    }
+
+    /// Title of a control, if the catalog has it
+    pub fn title(&self, control_id: &str) -> Option<&str> {
+        self.get(control_id).map(|control| control.title.as_str())
+    }
+
+    /// Controls of a family in catalog order, e.g. `family("AC")`
+    pub fn family(&self, prefix: &str) -> Vec<&CatalogControl> {
+        let prefix = prefix.trim().to_uppercase();
+        self.controls.iter().filter(|control| control.family_id == prefix).collect()
+    }
+
+    /// Families in catalog order
+    pub fn families(&self) -> &[CatalogFamily] {
+        &self.families
+    }
+
+    /// All controls in catalog order
+    pub fn controls(&self) -> &[CatalogControl] {
+        &self.controls
+    }
+}
+
+/// Add the non-withdrawn controls of an OSCAL `controls` array and their enhancements
+fn collect_oscal_controls(oscal_controls: &Value, family_id: &str, controls: &mut Vec<CatalogControl>) {
+    for control in oscal_controls.as_array().into_iter().flatten() {
+        let Some(id) = control["id"].as_str() else { continue };
+        let withdrawn = control["props"].as_array().into_iter().flatten()
+            .any(|prop| prop["name"] == "status" && prop["value"] == "withdrawn");
+        if withdrawn {
+            continue;
+        }
+
+        let statement: Vec<&str> = control["parts"].as_array().into_iter().flatten()
+            .filter(|part| part["name"] == "statement")
+            .flat_map(part_prose)
+            .collect();
+        controls.push(CatalogControl {
+            control_id: canonical_control_id(id),
+            title: control["title"].as_str().unwrap_or_default().to_string(),
+            family_id: family_id.to_string(),
+            description: (!statement.is_empty()).then(|| statement.join("\n")),
+        });
+        collect_oscal_controls(&control["controls"], family_id, controls);
+    }
+}
+
+/// Prose of a part and its subparts, depth first
+fn part_prose(part: &Value) -> Vec<&str> {
+    let mut prose: Vec<&str> = part["prose"].as_str().into_iter().collect();
+    for subpart in part["parts"].as_array().into_iter().flatten() {
+        prose.extend(part_prose(subpart));
+    }
+    prose
+}
+
+/// Display form of a control ID: `ac-02.3` and ` AC-2 (3)` become `AC-2(3)`
+pub(crate) fn canonical_control_id(control_id: &str) -> String {
+    let compact: String = control_id.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase();
+    let Some((family, rest)) = compact.split_once('-') else { return compact };
+    let (number, enhancement) = match rest.split_once(['.', '(']) {
+        Some((number, enhancement)) => (number, Some(enhancement.trim_end_matches(')'))),
+        None => (rest, None),
+    };
+    let trim_zeros = |digits: &str| match digits.trim_start_matches('0') {
+        "" if !digits.is_empty() => "0".to_string(),
+        trimmed => trimmed.to_string(),
+    };
+
+    match enhancement {
+        Some(enhancement) => format!("{}-{}({})", family, trim_zeros(number), trim_zeros(enhancement)),
+        None => format!("{}-{}", family, trim_zeros(number)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_catalog_has_ac_2_title() {
+        let catalog = Catalog::load_nist_800_53_rev5().unwrap();
+
+        assert_eq!(catalog.get("AC-2").unwrap().title, "Account Management");
+        assert_eq!(catalog.title("ac-02"), Some("Account Management"));
+        assert_eq!(catalog.get("AC-2").unwrap().family_id, "AC");
+        assert!(catalog.get("AC-13").is_none(), "withdrawn controls are not bundled");
+        assert!(catalog.get("AC-2").unwrap().description.as_deref().unwrap().starts_with("Define and document"));
+
+        assert_eq!(catalog.families().len(), 20);
+        let access_control = catalog.family("ac");
+        assert_eq!(access_control.first().unwrap().control_id, "AC-1");
+        assert!(access_control.iter().all(|control| control.family_id == "AC"));
+    }
+
+    #[test]
+    fn test_bundled_catalog_includes_enhancements() {
+        let catalog = Catalog::load_nist_800_53_rev5().unwrap();
+
+        let enhancement = catalog.get("ac-2.1").unwrap();
+        assert_eq!(enhancement.control_id, "AC-2(1)");
+        assert_eq!(enhancement.title, "Automated System Account Management");
+        assert_eq!(enhancement.family_id, "AC");
+        assert!(enhancement.description.as_deref().unwrap().contains("automated mechanisms"));
+        assert!(catalog.get("AC-2(10)").is_none(), "withdrawn enhancements are not bundled");
+
+        let account_management: Vec<&str> = catalog.family("AC").iter()
+            .map(|control| control.control_id.as_str())
+            .skip_while(|id| *id != "AC-2")
+            .take(3)
+            .collect();
+        assert_eq!(account_management, ["AC-2", "AC-2(1)", "AC-2(2)"]);
+    }
+
+    #[test]
+    fn test_oscal_catalog_includes_enhancements_and_statements() {
+        let json = r#"{
+            "catalog": {
+                "metadata": { "title": "Test Catalog", "version": "5.1.1" },
+                "groups": [{
+                    "id": "ac",
+                    "title": "Access Control",
+                    "controls": [
+                        {
+                            "id": "ac-2",
+                            "title": "Account Management",
+                            "parts": [{ "name": "statement", "parts": [
+                                { "name": "item", "prose": "Define allowed account types;" },
+                                { "name": "item", "prose": "Assign account managers;" }
+                            ]}],
+                            "controls": [{ "id": "ac-2.1", "title": "Automated System Account Management" }]
+                        },
+                        { "id": "ac-13", "title": "Supervision and Review", "props": [{ "name": "status", "value": "withdrawn" }] }
+                    ]
+                }]
+            }
+        }"#;
+
+        let catalog = Catalog::from_oscal_json(json).unwrap();
+
+        assert_eq!(catalog.version, "5.1.1");
+        let ac_2 = catalog.get("AC-2").unwrap();
+        assert_eq!(ac_2.description.as_deref(), Some("Define allowed account types;\nAssign account managers;"));
+        assert_eq!(catalog.title("AC-2 (1)"), Some("Automated System Account Management"));
+        assert!(catalog.get("AC-13").is_none());
+        assert_eq!(catalog.family("AC").len(), 2);
+    }
+}
@@ -0,0 +1,157 @@
+// Modified: 2025-09-20
+
+//! CIS Controls
+//!
+//! CIS Controls v8 safeguards and their implementation groups, and how much of
+//! an implementation group a NIST baseline covers through a CIS-to-NIST
+//! crosswalk.
+
+use crate::catalog::canonical_control_id;
+use fedramp_core::models::{ControlBaseline, ControlMapping, Framework};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// CIS Controls implementation group; each group includes the ones below it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ImplementationGroup {
+    Ig1,
+    Ig2,
+    Ig3,
+}
+
+/// CIS safeguard
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CisSafeguard {
+    /// Safeguard number, e.g. `5.3`
+    pub safeguard_id: String,
+    /// Safeguard title
+    pub title: String,
+    /// Lowest implementation group the safeguard belongs to
+    pub implementation_group: ImplementationGroup,
+}
+
+impl CisSafeguard {
+    /// Whether the safeguard is part of an implementation group
+    pub fn is_in(&self, group: ImplementationGroup) -> bool {
+        self.implementation_group <= group
+    }
+}
+
+/// Coverage of a CIS implementation group by a NIST baseline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CisBaselineCoverage {
+    pub implementation_group: ImplementationGroup,
+    /// Name of the NIST baseline
+    pub baseline_name: String,
+    /// Safeguards in the implementation group
+    pub total_safeguards: usize,
+    /// Safeguards with at least one mapping into the baseline
+    pub covered_safeguards: Vec<String>,
+    /// Safeguards without a mapping into the baseline, in input order
+    pub uncovered_safeguards: Vec<String>,
+    /// Covered share of the group's safeguards (0.0 - 100.0)
+    pub coverage_percentage: f64,
+}
+
+/// Share of an implementation group's safeguards that map into a NIST baseline
+///
+/// A safeguard is covered when a CIS to NIST 800-53 mapping in `crosswalk`
+/// has it as source and a control of `baseline` as target. Control IDs are
+/// compared in display form, so `ac-2.1` matches `AC-2(1)`. An empty group
+/// has 0% coverage.
+pub fn baseline_coverage(
+    safeguards: &[CisSafeguard],
+    crosswalk: &[ControlMapping],
+    group: ImplementationGroup,
+    baseline: &ControlBaseline,
+) -> CisBaselineCoverage {
+    let baseline_controls: HashSet<String> = baseline.control_ids.iter().map(|id| canonical_control_id(id)).collect();
+    let mapped_into_baseline: HashSet<&str> = crosswalk.iter()
+        .filter(|mapping| mapping.source_framework == Framework::Cis && mapping.target_framework == Framework::Nist80053)
+        .filter(|mapping| baseline_controls.contains(&canonical_control_id(&mapping.target_control_id)))
+        .map(|mapping| mapping.source_control_id.trim())
+        .collect();
+
+    let (covered_safeguards, uncovered_safeguards): (Vec<String>, Vec<String>) = safeguards.iter()
+        .filter(|safeguard| safeguard.is_in(group))
+        .map(|safeguard| safeguard.safeguard_id.clone())
+        .partition(|safeguard_id| mapped_into_baseline.contains(safeguard_id.trim()));
+
+    let total_safeguards = covered_safeguards.len() + uncovered_safeguards.len();
+    let coverage_percentage = if total_safeguards == 0 {
+        0.0
+    } else {
+        covered_safeguards.len() as f64 / total_safeguards as f64 * 100.0
+    };
+
+    CisBaselineCoverage {
+        implementation_group: group,
+        baseline_name: baseline.baseline_name.clone(),
+        total_safeguards,
+        covered_safeguards,
+        uncovered_safeguards,
+        coverage_percentage,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use fedramp_core::models::{BaselineLevel, MappingType};
+
+    fn safeguard(safeguard_id: &str, implementation_group: ImplementationGroup) -> CisSafeguard {
+        CisSafeguard {
+            safeguard_id: safeguard_id.to_string(),
+            title: format!("Safeguard {}", safeguard_id),
+            implementation_group,
+        }
+    }
+
+    fn cis_to_nist(safeguard_id: &str, control_id: &str) -> ControlMapping {
+        ControlMapping::new(
+            safeguard_id.to_string(),
+            Framework::Cis,
+            control_id.to_string(),
+            Framework::Nist80053,
+            0.9,
+            MappingType::Related,
+        )
+    }
+
+    #[test]
+    fn test_ig1_coverage_of_moderate_baseline() {
+        let safeguards = vec![
+            safeguard("1.1", ImplementationGroup::Ig1),
+            safeguard("4.1", ImplementationGroup::Ig1),
+            safeguard("5.3", ImplementationGroup::Ig1),
+            safeguard("6.3", ImplementationGroup::Ig1),
+            safeguard("13.1", ImplementationGroup::Ig2),
+        ];
+        let crosswalk = vec![
+            cis_to_nist("1.1", "cm-8"),
+            cis_to_nist("4.1", "CM-6"),
+            cis_to_nist("5.3", "AC-2(3)"),
+            cis_to_nist("6.3", "SC-7(21)"),
+            cis_to_nist("13.1", "SI-4"),
+        ];
+        let baseline = ControlBaseline {
+            id: uuid::Uuid::new_v4(),
+            baseline_name: "NIST Moderate".to_string(),
+            baseline_level: BaselineLevel::Moderate,
+            framework: Framework::Nist80053,
+            control_ids: vec!["CM-6".to_string(), "CM-8".to_string(), "ac-2.3".to_string(), "SI-4".to_string()],
+            description: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        let coverage = baseline_coverage(&safeguards, &crosswalk, ImplementationGroup::Ig1, &baseline);
+
+        assert_eq!(coverage.total_safeguards, 4);
+        assert_eq!(coverage.covered_safeguards, vec!["1.1", "4.1", "5.3"]);
+        assert_eq!(coverage.uncovered_safeguards, vec!["6.3"]);
+        assert_eq!(coverage.coverage_percentage, 75.0);
+    }
+}
@@ -0,0 +1,282 @@
+// Modified: 2025-09-24
+
+//! Cross-Framework Mapping
+//!
+//! Answers which controls of one framework satisfy a control of another,
+//! e.g. the NIST 800-53 controls behind a CIS safeguard, from a set of
+//! control mappings or a validated crosswalk. Every mapping can be followed in
+//! both directions, and the coverage seen from one side is consistent with the
+//! coverage seen from the other: if `A` is a subset of `B`, `B` fully covers
+//! `A` while `A` only partially covers `B`.
+
+use crate::crosswalk::{normalize_control_id, CrosswalkEntry, CrosswalkRelationship, CrosswalkValidation};
+use fedramp_core::models::{ControlMapping, Framework, MappingType};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How much of the requested control a mapped control covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MappingCoverage {
+    /// Implementing the mapped control satisfies the requested control
+    Full,
+    /// The mapped control satisfies part of the requested control
+    Partial,
+    /// The pair was reviewed and the mapped control does not satisfy the requested control
+    None,
+}
+
+/// Control of the target framework mapped from a requested control
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MappedControl {
+    /// Control ID, normalized for its framework
+    pub control_id: String,
+    pub framework: Framework,
+    /// Coverage of the requested control by this control
+    pub coverage: MappingCoverage,
+    /// Confidence of the underlying mapping (0.0 - 1.0)
+    pub confidence: f64,
+    /// Relationship from the requested control to this control
+    pub relationship: CrosswalkRelationship,
+}
+
+/// Mapping between two controls as stored by the mapper
+#[derive(Debug, Clone)]
+struct MappingEdge {
+    source: (Framework, String),
+    target: (Framework, String),
+    /// Relationship from source to target
+    relationship: CrosswalkRelationship,
+    confidence: f64,
+}
+
+/// Bidirectional lookup of control mappings between frameworks
+#[derive(Debug, Clone, Default)]
+pub struct CrossFrameworkMapper {
+    edges: Vec<MappingEdge>,
+    /// Framework and normalized control ID to the edges touching the control
+    index: HashMap<(Framework, String), Vec<usize>>,
+}
+
+impl CrossFrameworkMapper {
+    /// Create a mapper without mappings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a mapper from control mappings
+    pub fn from_mappings<'a>(mappings: impl IntoIterator<Item = &'a ControlMapping>) -> Self {
+        let mut mapper = Self::new();
+        for mapping in mappings {
+            mapper.add_mapping(mapping);
+        }
+        mapper
+    }
+
+    /// Create a mapper from the entries of a validated crosswalk
+    ///
+    /// Crosswalk entries keep their subset and superset direction, which
+    /// control mappings reduce to `partial`.
+    pub fn from_crosswalk(crosswalk: &CrosswalkValidation) -> Self {
+        let mut mapper = Self::new();
+        for entry in &crosswalk.entries {
+            mapper.add_crosswalk_entry(entry);
+        }
+        mapper
+    }
+
+    /// Add a control mapping
+    ///
+    /// Exact mappings are treated as equal controls and every other mapping
+    /// type as intersecting ones, since a control mapping does not record
+    /// which side is the larger. A mapping with zero confidence records a
+    /// reviewed pair without coverage.
+    pub fn add_mapping(&mut self, mapping: &ControlMapping) {
+        let relationship = match mapping.mapping_type {
+            MappingType::Exact => CrosswalkRelationship::Equal,
+            MappingType::Partial | MappingType::Related | MappingType::Derived => CrosswalkRelationship::IntersectsWith,
+        };
+        self.add_edge(
+            (&mapping.source_framework, &mapping.source_control_id),
+            (&mapping.target_framework, &mapping.target_control_id),
+            relationship,
+            mapping.confidence_score,
+        );
+    }
+
+    /// Add a validated crosswalk entry with full confidence
+    pub fn add_crosswalk_entry(&mut self, entry: &CrosswalkEntry) {
+        self.add_edge(
+            (&entry.source_framework, &entry.source_control_id),
+            (&entry.target_framework, &entry.target_control_id),
+            entry.relationship,
+            1.0,
+        );
+    }
+
+    fn add_edge(
+        &mut self,
+        (source_framework, source_id): (&Framework, &str),
+        (target_framework, target_id): (&Framework, &str),
+        relationship: CrosswalkRelationship,
+        confidence: f64,
+    ) {
+        let source = control_key(source_framework, source_id);
+        let target = control_key(target_framework, target_id);
+        let position = self.edges.len();
+        self.index.entry(source.clone()).or_default().push(position);
+        if target != source {
+            self.index.entry(target.clone()).or_default().push(position);
+        }
+        self.edges.push(MappingEdge { source, target, relationship, confidence });
+    }
+
+    /// Controls of `to_framework` mapped to a control of `from_framework`
+    ///
+    /// Mappings are followed in whichever direction they were recorded. When
+    /// several mappings link the same pair, the one with the best coverage,
+    /// then the highest confidence, is used. Results are ordered by coverage,
+    /// then confidence (highest first), then control ID. A control without
+    /// mappings into the framework gives an empty result.
+    pub fn map_control(&self, from_framework: &Framework, control_id: &str, to_framework: &Framework) -> Vec<MappedControl> {
+        let key = control_key(from_framework, control_id);
+        let mut mapped: HashMap<&str, MappedControl> = HashMap::new();
+
+        for &position in self.index.get(&key).into_iter().flatten() {
+            let edge = &self.edges[position];
+            let (other, relationship) = if edge.source == key {
+                (&edge.target, edge.relationship)
+            } else {
+                (&edge.source, edge.relationship.inverse())
+            };
+            if other.0 != *to_framework || *other == key {
+                continue;
+            }
+
+            let candidate = MappedControl {
+                control_id: other.1.clone(),
+                framework: other.0.clone(),
+                coverage: coverage(relationship, edge.confidence),
+                confidence: edge.confidence,
+                relationship,
+            };
+            match mapped.get(other.1.as_str()) {
+                Some(existing) if !is_better(&candidate, existing) => {}
+                _ => {
+                    mapped.insert(other.1.as_str(), candidate);
+                }
+            }
+        }
+
+        let mut mapped: Vec<MappedControl> = mapped.into_values().collect();
+        mapped.sort_by(|a, b| {
+            a.coverage.cmp(&b.coverage)
+                .then(b.confidence.total_cmp(&a.confidence))
+                .then_with(|| a.control_id.cmp(&b.control_id))
+        });
+        mapped
+    }
+
+    /// Number of mappings held
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Whether the mapper holds no mappings
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+}
+
+/// Coverage of the requested control given its relationship to the mapped control
+fn coverage(relationship: CrosswalkRelationship, confidence: f64) -> MappingCoverage {
+    if confidence <= 0.0 {
+        return MappingCoverage::None;
+    }
+    match relationship {
+        CrosswalkRelationship::Equal | CrosswalkRelationship::SubsetOf => MappingCoverage::Full,
+        CrosswalkRelationship::SupersetOf | CrosswalkRelationship::IntersectsWith => MappingCoverage::Partial,
+    }
+}
+
+fn is_better(candidate: &MappedControl, existing: &MappedControl) -> bool {
+    (candidate.coverage, -candidate.confidence) < (existing.coverage, -existing.confidence)
+}
+
+/// Lookup key of a control: its framework and normalized ID, or the trimmed
+/// uppercase ID when it does not validate for the framework
+fn control_key(framework: &Framework, control_id: &str) -> (Framework, String) {
+    let control_id = normalize_control_id(control_id, framework)
+        .unwrap_or_else(|_| control_id.trim().to_uppercase());
+    (framework.clone(), control_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crosswalk::{validate_crosswalk, CrosswalkRow};
+
+    fn cis_to_nist(safeguard_id: &str, control_id: &str, confidence: f64, mapping_type: MappingType) -> ControlMapping {
+        ControlMapping::new(
+            safeguard_id.to_string(),
+            Framework::Cis,
+            control_id.to_string(),
+            Framework::Nist80053,
+            confidence,
+            mapping_type,
+        )
+    }
+
+    fn ids(mapped: &[MappedControl]) -> Vec<(&str, MappingCoverage)> {
+        mapped.iter().map(|control| (control.control_id.as_str(), control.coverage)).collect()
+    }
+
+    #[test]
+    fn test_lookups_work_in_both_directions() {
+        let mapper = CrossFrameworkMapper::from_mappings(&[
+            cis_to_nist("5.3", "AC-2(3)", 0.9, MappingType::Exact),
+            cis_to_nist("5.3", "ac-02", 0.7, MappingType::Related),
+            cis_to_nist("6.3", "IA-2(1)", 0.8, MappingType::Partial),
+            cis_to_nist("6.3", "AC-7", 0.0, MappingType::Related),
+        ]);
+
+        let nist = mapper.map_control(&Framework::Cis, "5.3", &Framework::Nist80053);
+        assert_eq!(ids(&nist), [("AC-2(3)", MappingCoverage::Full), ("AC-2", MappingCoverage::Partial)]);
+        assert_eq!(nist[0].confidence, 0.9);
+
+        let cis = mapper.map_control(&Framework::Nist80053, "AC-2", &Framework::Cis);
+        assert_eq!(ids(&cis), [("5.3", MappingCoverage::Partial)]);
+        assert_eq!(cis[0].confidence, 0.7);
+
+        let nist = mapper.map_control(&Framework::Cis, "6.3", &Framework::Nist80053);
+        assert_eq!(ids(&nist), [("IA-2(1)", MappingCoverage::Partial), ("AC-7", MappingCoverage::None)]);
+    }
+
+    #[test]
+    fn test_subset_coverage_is_consistent_across_directions() {
+        let rows = vec![CrosswalkRow {
+            row: 2,
+            source_control_id: "AC-2".to_string(),
+            target_control_id: "3.1.1".to_string(),
+            relationship: "superset of".to_string(),
+        }];
+        let mapper = CrossFrameworkMapper::from_crosswalk(&validate_crosswalk(&rows, Framework::Nist80053, Framework::Nist800171));
+
+        let forward = mapper.map_control(&Framework::Nist80053, "ac-2", &Framework::Nist800171);
+        assert_eq!(ids(&forward), [("3.1.1", MappingCoverage::Partial)]);
+        assert_eq!(forward[0].relationship, CrosswalkRelationship::SupersetOf);
+
+        let backward = mapper.map_control(&Framework::Nist800171, "03.01.01", &Framework::Nist80053);
+        assert_eq!(ids(&backward), [("AC-2", MappingCoverage::Full)]);
+        assert_eq!(backward[0].relationship, CrosswalkRelationship::SubsetOf);
+    }
+
+    #[test]
+    fn test_unmapped_control_gives_empty_result() {
+        let mapper = CrossFrameworkMapper::from_mappings(&[cis_to_nist("5.3", "AC-2", 0.9, MappingType::Exact)]);
+
+        assert!(mapper.map_control(&Framework::Cis, "1.1", &Framework::Nist80053).is_empty());
+        assert!(mapper.map_control(&Framework::Cis, "5.3", &Framework::Nist800171).is_empty());
+        assert!(CrossFrameworkMapper::new().map_control(&Framework::Cis, "not a control", &Framework::Cis).is_empty());
+    }
+}
@@ -0,0 +1,369 @@
+// Modified: 2025-09-24
+
+//! Crosswalk Validation
+//!
+//! Checks a control crosswalk table (source control, target control,
+//! relationship) before it is loaded as control mappings: every control ID is
+//! validated and normalized for its framework, and repeated or contradictory
+//! rows are reported. A pair recorded in either direction is the same pair, so
+//! `A subset-of B` and `B superset-of A` are duplicates, while `A equal B`
+//! and `A subset-of B` contradict each other.
+
+use fedramp_core::models::{ControlMapping, Framework, MappingType};
+use fedramp_core::validation::validate_control_id;
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Set-theoretic relationship between a source and a target control
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CrosswalkRelationship {
+    /// The controls cover the same requirements
+    Equal,
+    /// The source control is covered by the target control
+    SubsetOf,
+    /// The source control covers the target control
+    SupersetOf,
+    /// The controls share some requirements
+    IntersectsWith,
+}
+
+impl CrosswalkRelationship {
+    /// Parse a relationship cell, e.g. `equal`, `=`, `Subset of` or `⊂`
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized: String = value.trim().to_lowercase().chars().filter(|c| c.is_alphanumeric() || "=⊂⊃∩".contains(*c)).collect();
+        match normalized.as_str() {
+            "equal" | "equals" | "equivalent" | "exact" | "=" => Some(Self::Equal),
+            "subset" | "subsetof" | "partial" | "⊂" => Some(Self::SubsetOf),
+            "superset" | "supersetof" | "⊃" => Some(Self::SupersetOf),
+            "intersects" | "intersectswith" | "related" | "∩" => Some(Self::IntersectsWith),
+            _ => None,
+        }
+    }
+
+    /// Relationship with source and target swapped
+    pub fn inverse(self) -> Self {
+        match self {
+            Self::SubsetOf => Self::SupersetOf,
+            Self::SupersetOf => Self::SubsetOf,
+            other => other,
+        }
+    }
+
+    /// Whether the two relationships cannot both hold for the same pair
+    ///
+    /// Equality rules out a proper subset or superset, and a proper subset
+    /// rules out a proper superset. Intersection is implied by the others.
+    pub fn contradicts(self, other: Self) -> bool {
+        use CrosswalkRelationship::*;
+        matches!(
+            (self, other),
+            (Equal, SubsetOf) | (Equal, SupersetOf) | (SubsetOf, Equal) | (SupersetOf, Equal)
+                | (SubsetOf, SupersetOf) | (SupersetOf, SubsetOf)
+        )
+    }
+
+    /// Mapping type recorded for the relationship
+    pub fn mapping_type(self) -> MappingType {
+        match self {
+            Self::Equal => MappingType::Exact,
+            Self::SubsetOf | Self::SupersetOf => MappingType::Partial,
+            Self::IntersectsWith => MappingType::Related,
+        }
+    }
+}
+
+/// Row of a crosswalk table as written
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrosswalkRow {
+    /// Row number in the source table, 1-based and counting the header row
+    pub row: usize,
+    pub source_control_id: String,
+    pub target_control_id: String,
+    pub relationship: String,
+}
+
+/// Validated crosswalk row with normalized control IDs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrosswalkEntry {
+    pub row: usize,
+    pub source_control_id: String,
+    pub source_framework: Framework,
+    pub target_control_id: String,
+    pub target_framework: Framework,
+    pub relationship: CrosswalkRelationship,
+}
+
+impl CrosswalkEntry {
+    /// Control mapping for the entry
+    ///
+    /// Crosswalks are curated, so the mapping gets full confidence.
+    pub fn to_control_mapping(&self) -> ControlMapping {
+        ControlMapping::new(
+            self.source_control_id.clone(),
+            self.source_framework.clone(),
+            self.target_control_id.clone(),
+            self.target_framework.clone(),
+            1.0,
+            self.relationship.mapping_type(),
+        )
+    }
+}
+
+/// Kind of problem found in a crosswalk row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CrosswalkIssueKind {
+    InvalidControlId,
+    UnknownRelationship,
+    Duplicate,
+    Contradiction,
+}
+
+/// Problem found in a crosswalk row; the row is left out of the entries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrosswalkIssue {
+    pub row: usize,
+    pub kind: CrosswalkIssueKind,
+    pub message: String,
+}
+
+/// Result of validating a crosswalk
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrosswalkValidation {
+    /// Rows that passed validation, in table order
+    pub entries: Vec<CrosswalkEntry>,
+    /// Problems found, in table order
+    pub issues: Vec<CrosswalkIssue>,
+}
+
+impl CrosswalkValidation {
+    /// Whether every row passed validation
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Control mappings for the valid entries
+    pub fn to_control_mappings(&self) -> Vec<ControlMapping> {
+        self.entries.iter().map(CrosswalkEntry::to_control_mapping).collect()
+    }
+}
+
+/// Read crosswalk rows from a table whose first row holds the headers
+///
+/// The source, target and relationship columns are the first headers
+/// containing `source`, `target` and `relation` (or `type`), in any case.
+/// Rows with every cell empty are skipped.
+pub fn crosswalk_rows_from_table(table: &[Vec<Value>]) -> Result<Vec<CrosswalkRow>> {
+    let (headers, rows) = table.split_first()
+        .ok_or_else(|| Error::validation("Crosswalk table is empty"))?;
+    let headers: Vec<String> = headers.iter().map(|cell| cell_text(Some(cell)).to_lowercase()).collect();
+    let column = |keywords: &[&str]| {
+        headers.iter()
+            .position(|header| keywords.iter().any(|keyword| header.contains(keyword)))
+            .ok_or_else(|| Error::validation(format!("Crosswalk table has no '{}' column", keywords[0])))
+    };
+    let (source, target, relationship) = (column(&["source"])?, column(&["target"])?, column(&["relation", "type"])?);
+
+    Ok(rows.iter()
+        .enumerate()
+        .map(|(index, cells)| CrosswalkRow {
+            row: index + 2,
+            source_control_id: cell_text(cells.get(source)),
+            target_control_id: cell_text(cells.get(target)),
+            relationship: cell_text(cells.get(relationship)),
+        })
+        .filter(|row| !(row.source_control_id.is_empty() && row.target_control_id.is_empty() && row.relationship.is_empty()))
+        .collect())
+}
+
+fn cell_text(cell: Option<&Value>) -> String {
+    match cell {
+        Some(Value::String(text)) => text.trim().to_string(),
+        Some(Value::Null) | None => String::new(),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// Validate crosswalk rows from `source_framework` to `target_framework`
+///
+/// Control IDs are normalized with [`validate_control_id`]; IDs of custom
+/// frameworks have no canonical form and are only trimmed. A row that repeats
+/// or contradicts an earlier row for the same pair of controls is reported
+/// against the earlier row and left out of the entries.
+pub fn validate_crosswalk(
+    rows: &[CrosswalkRow],
+    source_framework: Framework,
+    target_framework: Framework,
+) -> CrosswalkValidation {
+    let mut validation = CrosswalkValidation::default();
+    // Pair of controls, in a fixed order, to the relationship and row first recorded for it
+    let mut seen: HashMap<(String, String), (CrosswalkRelationship, usize)> = HashMap::new();
+
+    for row in rows {
+        let mut issue = |kind, message: String| {
+            validation.issues.push(CrosswalkIssue { row: row.row, kind, message });
+        };
+
+        let source = normalize_control_id(&row.source_control_id, &source_framework);
+        let target = normalize_control_id(&row.target_control_id, &target_framework);
+        let relationship = CrosswalkRelationship::parse(&row.relationship);
+
+        let (source, target) = match (source, target) {
+            (Ok(source), Ok(target)) => (source, target),
+            (source, target) => {
+                for error in [source.err(), target.err()].into_iter().flatten() {
+                    issue(CrosswalkIssueKind::InvalidControlId, error.to_string());
+                }
+                continue;
+            }
+        };
+        let Some(relationship) = relationship else {
+            issue(
+                CrosswalkIssueKind::UnknownRelationship,
+                format!("Unknown relationship '{}' between {} and {}", row.relationship, source, target),
+            );
+            continue;
+        };
+
+        // Record the pair with the smaller ID first so both directions share a key
+        let source_key = format!("{:?}:{}", source_framework, source);
+        let target_key = format!("{:?}:{}", target_framework, target);
+        let swapped = source_key > target_key;
+        let (key, keyed_relationship) = if swapped {
+            ((target_key, source_key), relationship.inverse())
+        } else {
+            ((source_key, target_key), relationship)
+        };
+
+        match seen.get(&key) {
+            Some(&(earlier, earlier_row)) if earlier == keyed_relationship => issue(
+                CrosswalkIssueKind::Duplicate,
+                format!("{} to {} repeats the mapping of row {}", source, target, earlier_row),
+            ),
+            Some(&(earlier, earlier_row)) if earlier.contradicts(keyed_relationship) => {
+                let earlier = if swapped { earlier.inverse() } else { earlier };
+                issue(
+                    CrosswalkIssueKind::Contradiction,
+                    format!(
+                        "{} is {} {}, but row {} records it as {} {}",
+                        source,
+                        relationship_label(relationship),
+                        target,
+                        earlier_row,
+                        relationship_label(earlier),
+                        target,
+                    ),
+                )
+            }
+            _ => {
+                seen.entry(key).or_insert((keyed_relationship, row.row));
+                validation.entries.push(CrosswalkEntry {
+                    row: row.row,
+                    source_control_id: source,
+                    source_framework: source_framework.clone(),
+                    target_control_id: target,
+                    target_framework: target_framework.clone(),
+                    relationship,
+                });
+            }
+        }
+    }
+
+    validation
+}
+
+pub(crate) fn normalize_control_id(control_id: &str, framework: &Framework) -> Result<String> {
+    match framework {
+        Framework::Custom if !control_id.trim().is_empty() => Ok(control_id.trim().to_string()),
+        framework => validate_control_id(control_id, framework.clone()).map(|id| id.to_string()),
+    }
+}
+
+fn relationship_label(relationship: CrosswalkRelationship) -> &'static str {
+    match relationship {
+        CrosswalkRelationship::Equal => "equal to",
+        CrosswalkRelationship::SubsetOf => "a subset of",
+        CrosswalkRelationship::SupersetOf => "a superset of",
+        CrosswalkRelationship::IntersectsWith => "intersecting with",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(row: usize, source: &str, target: &str, relationship: &str) -> CrosswalkRow {
+        CrosswalkRow {
+            row,
+            source_control_id: source.to_string(),
+            target_control_id: target.to_string(),
+            relationship: relationship.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_contradiction_and_duplicate_are_reported() {
+        let rows = vec![
+            row(2, "ac-02", "3.1.1", "Equal"),
+            row(3, "AC-2", "03.01.01", "subset of"),
+            row(4, "AC-3", "3.1.2", "="),
+            row(5, "AC-3", "3.1.2", "equivalent"),
+            row(6, "XX-99-1", "3.1.3", "equal"),
+            row(7, "AC-17", "3.1.12", "overlaps"),
+        ];
+
+        let validation = validate_crosswalk(&rows, Framework::Nist80053, Framework::Nist800171);
+
+        let issues: Vec<(usize, CrosswalkIssueKind)> = validation.issues.iter().map(|issue| (issue.row, issue.kind)).collect();
+        assert_eq!(issues, vec![
+            (3, CrosswalkIssueKind::Contradiction),
+            (5, CrosswalkIssueKind::Duplicate),
+            (6, CrosswalkIssueKind::InvalidControlId),
+            (7, CrosswalkIssueKind::UnknownRelationship),
+        ]);
+        assert!(validation.issues[0].message.contains("row 2"), "{}", validation.issues[0].message);
+
+        let entries: Vec<(&str, &str)> = validation.entries.iter()
+            .map(|entry| (entry.source_control_id.as_str(), entry.target_control_id.as_str()))
+            .collect();
+        assert_eq!(entries, vec![("AC-2", "3.1.1"), ("AC-3", "3.1.2")]);
+        assert_eq!(validation.to_control_mappings()[0].mapping_type, MappingType::Exact);
+    }
+
+    #[test]
+    fn test_reversed_pair_is_the_same_mapping() {
+        let rows = vec![
+            row(2, "AC-2", "AC-2(1)", "superset of"),
+            row(3, "AC-2(1)", "AC-2", "subset of"),
+            row(4, "AC-2(1)", "AC-2", "superset of"),
+        ];
+
+        let validation = validate_crosswalk(&rows, Framework::Nist80053, Framework::Nist80053);
+
+        let kinds: Vec<CrosswalkIssueKind> = validation.issues.iter().map(|issue| issue.kind).collect();
+        assert_eq!(kinds, vec![CrosswalkIssueKind::Duplicate, CrosswalkIssueKind::Contradiction]);
+        assert_eq!(
+            validation.issues[1].message,
+            "AC-2(1) is a superset of AC-2, but row 2 records it as a subset of AC-2"
+        );
+    }
+
+    #[test]
+    fn test_rows_from_table_find_columns_by_header() {
+        let table = vec![
+            vec![json!("Relationship"), json!("Source Control"), json!("Target Control")],
+            vec![json!("equal"), json!(" AC-2 "), json!("3.1.1")],
+            vec![json!(null), json!(""), json!(null)],
+            vec![json!("subset"), json!("AC-3"), json!(3.1)],
+        ];
+
+        let rows = crosswalk_rows_from_table(&table).unwrap();
+
+        assert_eq!(rows, vec![row(2, "AC-2", "3.1.1", "equal"), row(4, "AC-3", "3.1", "subset")]);
+        assert!(crosswalk_rows_from_table(&[vec![json!("Control")]]).is_err());
+    }
+}
@@ -5,11 +5,11 @@
 //! Cross-reference controls between different frameworks (NIST 800-53 Rev 5, NIST 800-171 R3, CIS).
 
 pub mod catalog;
-pub mod mapping;
 pub mod nist;
 pub mod cis;
-pub mod fedramp;
-pub mod quality;
+pub mod crosswalk;
+pub mod cross_framework;
+pub mod calibration;
 
 pub use catalog::*;
-pub use mapping::*;
+pub use cross_framework::{CrossFrameworkMapper, MappedControl, MappingCoverage};
@@ -0,0 +1,186 @@
+// Modified: 2025-09-24
+
+//! NIST SP 800-53 Revisions
+//!
+//! How NIST SP 800-53 Rev 4 controls carry over to Rev 5, for migrating legacy
+//! authorizations. The transition table is data: a table bundled with the
+//! crate is used by default, and an updated one can be loaded from a JSON file
+//! with [`TransitionTable::from_file`] without rebuilding. Like NIST's own
+//! comparison, a table lists the controls withdrawn in Rev 5, or already
+//! withdrawn in Rev 4; every other Rev 4 control carries over unchanged.
+
+use crate::catalog::canonical_control_id;
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Bundled NIST SP 800-53 Rev 4 to Rev 5 transition table
+const NIST_800_53_REV4_TO_REV5: &str = include_str!("../data/nist_800_53_rev4_to_rev5.json");
+
+/// What became of a Rev 4 control in Rev 5
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", content = "rev5", rename_all = "snake_case")]
+pub enum ControlTransition {
+    /// The control keeps its ID
+    Unchanged,
+    /// The control moved to a new ID
+    Renamed(String),
+    /// The control was incorporated into these existing Rev 5 controls
+    Merged(Vec<String>),
+    /// The control was divided across these Rev 5 controls
+    Split(Vec<String>),
+    /// The control has no Rev 5 counterpart
+    Withdrawn,
+}
+
+/// Layout of a transition table file
+#[derive(Deserialize)]
+struct TransitionFile {
+    transitions: Vec<TransitionRecord>,
+}
+
+#[derive(Deserialize)]
+struct TransitionRecord {
+    rev4: String,
+    outcome: String,
+    #[serde(default)]
+    rev5: Vec<String>,
+}
+
+/// Rev 4 to Rev 5 outcomes of the controls listed in a transition table
+#[derive(Debug, Clone, Default)]
+pub struct TransitionTable {
+    transitions: HashMap<String, ControlTransition>,
+}
+
+impl TransitionTable {
+    /// Load the bundled transition table
+    pub fn load_bundled() -> Result<Self> {
+        Self::from_json(NIST_800_53_REV4_TO_REV5)
+    }
+
+    /// Load a transition table file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Load a transition table from JSON
+    ///
+    /// The table lists `transitions`, each with the Rev 4 control (`rev4`), an
+    /// `outcome` (`unchanged`, `renamed`, `merged`, `split` or `withdrawn`)
+    /// and, except for unchanged and withdrawn controls, the Rev 5 controls
+    /// (`rev5`). A renamed control has exactly one Rev 5 control.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let file: TransitionFile = serde_json::from_str(json)?;
+
+        let mut transitions = HashMap::new();
+        for record in file.transitions {
+            let control_id = canonical_control_id(&record.rev4);
+            let mut rev5: Vec<String> = record.rev5.iter().map(|id| canonical_control_id(id)).collect();
+            let transition = match (record.outcome.trim().to_lowercase().as_str(), rev5.len()) {
+                ("unchanged", 0) => ControlTransition::Unchanged,
+                ("withdrawn", 0) => ControlTransition::Withdrawn,
+                ("renamed", 1) => ControlTransition::Renamed(rev5.remove(0)),
+                ("merged", 1..) => ControlTransition::Merged(rev5),
+                ("split", 1..) => ControlTransition::Split(rev5),
+                (outcome @ ("unchanged" | "withdrawn" | "renamed" | "merged" | "split"), count) => {
+                    return Err(Error::control_mapping(format!(
+                        "Transition of {} is {} but lists {} Rev 5 controls",
+                        control_id, outcome, count
+                    )));
+                }
+                (outcome, _) => {
+                    return Err(Error::control_mapping(format!(
+                        "Unknown transition outcome '{}' for {}",
+                        outcome, control_id
+                    )));
+                }
+            };
+            if transitions.insert(control_id.clone(), transition).is_some() {
+                return Err(Error::control_mapping(format!("Transition of {} is listed more than once", control_id)));
+            }
+        }
+
+        Ok(Self { transitions })
+    }
+
+    /// Outcome of a Rev 4 control, in display (`AC-2(1)`) or OSCAL (`ac-2.1`) form
+    ///
+    /// Controls the table does not list are unchanged.
+    pub fn transition(&self, control_id: &str) -> ControlTransition {
+        self.transitions
+            .get(&canonical_control_id(control_id))
+            .cloned()
+            .unwrap_or(ControlTransition::Unchanged)
+    }
+
+    /// Number of controls listed in the table
+    pub fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Whether the table lists no controls
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+}
+
+/// Outcome of a Rev 4 control according to the bundled transition table
+pub fn transition_rev4_to_rev5(control_id: &str) -> ControlTransition {
+    static BUNDLED: OnceLock<TransitionTable> = OnceLock::new();
+    BUNDLED
+        .get_or_init(|| TransitionTable::load_bundled().expect("bundled transition table is valid"))
+        .transition(control_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_withdrawn_and_split_controls() {
+        // Voice over IP was withdrawn in Rev 5 without a replacement
+        assert_eq!(transition_rev4_to_rev5("SC-19"), ControlTransition::Withdrawn);
+        assert_eq!(transition_rev4_to_rev5("sc-14"), ControlTransition::Withdrawn);
+        assert_eq!(transition_rev4_to_rev5("IA-2(3)"), ControlTransition::Merged(vec!["IA-2(1)".to_string()]));
+        assert_eq!(
+            transition_rev4_to_rev5("sa-12"),
+            ControlTransition::Split(vec!["SR-1".to_string(), "SR-2".to_string(), "SR-3".to_string()])
+        );
+        assert_eq!(
+            transition_rev4_to_rev5("SA-12(14)"),
+            ControlTransition::Split(vec!["SR-4(1)".to_string(), "SR-4(2)".to_string()])
+        );
+        assert_eq!(transition_rev4_to_rev5("sa-19.1"), ControlTransition::Renamed("SR-11(1)".to_string()));
+        assert_eq!(transition_rev4_to_rev5("CA-3(5)"), ControlTransition::Renamed("SC-7(5)".to_string()));
+        assert_eq!(transition_rev4_to_rev5("PE-18(1)"), ControlTransition::Renamed("PE-23".to_string()));
+
+        assert_eq!(transition_rev4_to_rev5("AC-2"), ControlTransition::Unchanged);
+        assert_eq!(transition_rev4_to_rev5("AC-2(1)"), ControlTransition::Unchanged);
+        assert_eq!(transition_rev4_to_rev5("CM-8(6)"), ControlTransition::Unchanged);
+    }
+
+    #[test]
+    fn test_table_from_json_checks_outcomes() {
+        let table = TransitionTable::from_json(
+            r#"{"transitions": [
+                {"rev4": "pl-2.3", "outcome": "Merged", "rev5": ["pl-02"]},
+                {"rev4": "AC-2", "outcome": "unchanged"}
+            ]}"#,
+        ).unwrap();
+        assert_eq!(table.transition("PL-2(3)"), ControlTransition::Merged(vec!["PL-2".to_string()]));
+        assert_eq!(table.transition("ac-2"), ControlTransition::Unchanged);
+        assert_eq!(table.transition("SC-19"), ControlTransition::Unchanged);
+
+        let invalid = [
+            r#"{"transitions": [{"rev4": "SA-19", "outcome": "renamed", "rev5": ["SR-11", "SR-12"]}]}"#,
+            r#"{"transitions": [{"rev4": "SC-14", "outcome": "retired"}]}"#,
+            r#"{"transitions": [{"rev4": "SC-9", "outcome": "withdrawn"}, {"rev4": "sc-09", "outcome": "withdrawn"}]}"#,
+        ];
+        for json in invalid {
+            assert!(TransitionTable::from_json(json).is_err(), "{}", json);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// Modified: 2025-09-20
+
+//! Content-addressed parse result caching
+//!
+//! Parse results are keyed by the SHA-256 hash of the input bytes, so an
+//! unchanged document is only parsed once no matter where it is read from.
+
+use async_trait::async_trait;
+use lru::LruCache;
+use sha2::{Digest, Sha256};
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+use crate::ParseResult;
+
+/// Default maximum number of entries kept by [`InMemoryParseCache`]
+pub const DEFAULT_MAX_CACHE_ENTRIES: usize = 128;
+
+/// Storage backend for cached parse results
+///
+/// Implement this for disk or Redis backends; [`InMemoryParseCache`] is the
+/// default. Keys are [`content_hash`] values.
+#[async_trait]
+pub trait ParseCache: Send + Sync {
+    /// Cached result for a content hash, if present
+    async fn get(&self, content_hash: &str) -> Option<ParseResult>;
+
+    /// Store the result for a content hash
+    async fn insert(&self, content_hash: String, result: ParseResult);
+}
+
+/// Hex-encoded SHA-256 hash of document bytes
+pub fn content_hash(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// In-memory parse cache evicting the least recently used entry when full
+pub struct InMemoryParseCache {
+    entries: Mutex<LruCache<String, ParseResult>>,
+}
+
+impl InMemoryParseCache {
+    /// Create a cache holding at most `max_entries` results (minimum one)
+    pub fn new(max_entries: usize) -> Self {
+        let capacity = NonZeroUsize::new(max_entries).unwrap_or(NonZeroUsize::MIN);
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Number of cached results
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// Whether the cache holds no results
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for InMemoryParseCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CACHE_ENTRIES)
+    }
+}
+
+#[async_trait]
+impl ParseCache for InMemoryParseCache {
+    async fn get(&self, content_hash: &str) -> Option<ParseResult> {
+        self.entries.lock().unwrap().get(content_hash).cloned()
+    }
+
+    async fn insert(&self, content_hash: String, result: ParseResult) {
+        self.entries.lock().unwrap().put(content_hash, result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DocumentParserFactory;
+    use std::sync::Arc;
+
+    fn workbook() -> Vec<u8> {
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_string(0, 0, "Control ID").unwrap();
+        worksheet.write_string(1, 0, "AC-2").unwrap();
+        workbook.save_to_buffer().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_identical_bytes_are_served_from_cache() {
+        let cache = Arc::new(InMemoryParseCache::default());
+        let factory = DocumentParserFactory::new().with_cache(cache.clone());
+        let data = workbook();
+        let hash = content_hash(&data);
+
+        let first = factory.parse_bytes(&data, "first.xlsx").await.unwrap();
+        assert_eq!(first.metadata["content_hash"], hash.as_str());
+        assert_eq!(cache.len(), 1);
+
+        // A marker entry proves the second parse never reaches the Excel parser
+        let mut marker = first.clone();
+        marker.quality_score = -1.0;
+        cache.insert(hash.clone(), marker).await;
+
+        let second = factory.parse_bytes(&data, "second.xlsx").await.unwrap();
+        assert_eq!(second.quality_score, -1.0);
+        assert_eq!(second.source_path, "second.xlsx");
+        assert_eq!(second.metadata["content_hash"], hash.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_cache_respects_max_entries() {
+        let cache = Arc::new(InMemoryParseCache::new(1));
+        let factory = DocumentParserFactory::new().with_cache(cache.clone());
+
+        factory.parse_bytes(br#"{"id": 1}"#, "one.json").await.unwrap();
+        factory.parse_bytes(br#"{"id": 2}"#, "two.json").await.unwrap();
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&content_hash(br#"{"id": 1}"#)).await.is_none());
+        assert!(cache.get(&content_hash(br#"{"id": 2}"#)).await.is_some());
+    }
+}
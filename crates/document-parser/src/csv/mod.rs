@@ -0,0 +1,11 @@
+// Modified: 2025-09-20
+
+//! CSV/TSV document parsing module
+//!
+//! Parses delimited text exports (e.g. CMDB inventory exports) into the same
+//! single-worksheet `content` shape produced by the Excel parser, so that
+//! column mapping and downstream processing are unchanged.
+
+pub mod parser;
+
+pub use parser::CsvParser;
@@ -0,0 +1,388 @@
+// Modified: 2025-09-20
+
+//! CSV/TSV parser implementation
+//!
+//! Reads delimited text with RFC 4180 quoting, infers cell types, sanitizes
+//! cells with the Excel validator, and emits a single-worksheet result.
+
+use crate::encoding::{decode_text, EncodingDetection};
+use crate::excel::core::file_parser::{cell_validation_errors, truncation_warning};
+use crate::excel::core::worksheet_parser::detect_header_row;
+use crate::excel::types::ValidationConfig;
+use crate::excel::validation::ExcelValidator;
+use crate::{DocumentParser, DocumentType, ParseResult, ValidationError, ValidationSeverity};
+use async_trait::async_trait;
+use chrono::Utc;
+use fedramp_core::{Error, Result};
+use serde_json::Value;
+use std::path::Path;
+use tokio::fs;
+use tracing::{debug, info};
+
+/// CSV/TSV document parser
+#[derive(Debug, Clone)]
+pub struct CsvParser {
+    /// Maximum file size to process (in bytes)
+    pub(crate) max_file_size: usize,
+    /// Field delimiter; inferred from the file extension when not set
+    pub(crate) delimiter: Option<u8>,
+    /// Whether to automatically detect headers
+    pub(crate) auto_detect_headers: bool,
+    /// Maximum number of rows to process
+    pub(crate) max_rows: Option<usize>,
+    /// Validation configuration applied to each cell
+    pub(crate) validation_config: ValidationConfig,
+    /// Input text encoding detection
+    pub(crate) encoding: EncodingDetection,
+}
+
+impl CsvParser {
+    /// Create a new CSV parser with default settings
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_file_size: 100 * 1024 * 1024, // 100MB
+            delimiter: None,
+            auto_detect_headers: true,
+            max_rows: None,
+            validation_config: ValidationConfig::default(),
+            encoding: EncodingDetection::Auto,
+        }
+    }
+
+    /// Use a fixed field delimiter instead of inferring it from the extension
+    #[must_use]
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Enable or disable header detection
+    #[must_use]
+    pub fn auto_detect_headers(mut self, auto_detect_headers: bool) -> Self {
+        self.auto_detect_headers = auto_detect_headers;
+        self
+    }
+
+    /// Limit the number of rows processed
+    #[must_use]
+    pub fn max_rows(mut self, max_rows: Option<usize>) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Set the cell validation configuration
+    #[must_use]
+    pub fn validation_config(mut self, validation_config: ValidationConfig) -> Self {
+        self.validation_config = validation_config;
+        self
+    }
+
+    /// Set how the input text encoding is chosen
+    #[must_use]
+    pub fn with_encoding(mut self, encoding: EncodingDetection) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Delimiter used for a file: the configured one, or tab for `.tsv`/`.tab` and comma otherwise
+    pub fn delimiter_for(&self, filename: &str) -> u8 {
+        if let Some(delimiter) = self.delimiter {
+            return delimiter;
+        }
+
+        match Path::new(filename).extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("tsv") || ext.eq_ignore_ascii_case("tab") => b'\t',
+            _ => b',',
+        }
+    }
+
+    /// Parse delimited text into a single-worksheet result
+    pub fn parse_text(&self, text: &str, filename: &str) -> Result<ParseResult> {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let delimiter = self.delimiter_for(filename);
+
+        let mut reader = ::csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(text.as_bytes());
+
+        let mut rows: Vec<Vec<Value>> = Vec::new();
+        let mut total_rows = 0;
+        for (index, record) in reader.records().enumerate() {
+            total_rows += 1;
+            if self.max_rows.is_some_and(|max_rows| index >= max_rows) {
+                // Keep counting so truncation can report the real total
+                continue;
+            }
+
+            let record = record.map_err(|e| {
+                Error::document_parsing(format!("Failed to read CSV record {}: {}", index + 1, e))
+            })?;
+            rows.push(record.iter().map(infer_cell_value).collect());
+        }
+
+        let column_count = rows.iter().map(Vec::len).max().unwrap_or(0);
+        debug!("Parsed {} CSV rows with {} columns", rows.len(), column_count);
+
+        // Sanitize cells with the same validator used for Excel worksheets
+        let validator = ExcelValidator::new(self.validation_config.clone());
+        let mut validation_results = Vec::with_capacity(rows.len() * column_count);
+        let data: Vec<Vec<Value>> = rows
+            .into_iter()
+            .enumerate()
+            .map(|(row_idx, mut row)| {
+                row.resize(column_count, Value::Null);
+                row.into_iter()
+                    .enumerate()
+                    .map(|(col_idx, value)| {
+                        let result = validator.validate_cell(&value, row_idx, col_idx);
+                        let sanitized = result.sanitized_value.clone().unwrap_or(value);
+                        validation_results.push(result);
+                        sanitized
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let validation_summary = validator.generate_summary(&validation_results);
+        let headers = if self.auto_detect_headers && !data.is_empty() {
+            detect_header_row(&data[0])
+        } else {
+            None
+        };
+
+        let sheet_name = Path::new(filename)
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("Sheet1")
+            .to_string();
+        let truncated = data.len() < total_rows;
+        let mut validation_errors = cell_validation_errors(0, &sheet_name, &validation_results);
+        if truncated {
+            validation_errors.push(truncation_warning(0, &sheet_name, total_rows, data.len()));
+        }
+        let quality_score = if data.is_empty() { 0.0 } else { validation_summary.average_confidence };
+
+        let metadata = serde_json::json!({
+            "file_info": {
+                "filename": filename,
+                "format": if delimiter == b'\t' { "tsv" } else { "csv" },
+                "delimiter": (delimiter as char).to_string(),
+                "total_worksheets": 1,
+                "parsed_worksheets": 1,
+                "total_rows": data.len()
+            },
+            "parsing_info": {
+                "parser_version": "1.0.0",
+                "parsed_at": Utc::now().to_rfc3339(),
+                "validation_config": self.validation_config
+            }
+        });
+
+        let content = serde_json::json!({
+            "worksheets": [{
+                "name": sheet_name,
+                "row_count": data.len(),
+                "total_row_count": total_rows,
+                "truncated": truncated,
+                "column_count": column_count,
+                "headers": headers,
+                "data": data,
+                "merged_cells": [],
+                "validation_summary": validation_summary
+            }]
+        });
+
+        Ok(ParseResult {
+            document_type: DocumentType::Csv,
+            source_path: filename.to_string(),
+            metadata,
+            content,
+            validation_errors,
+            quality_score,
+        })
+    }
+
+    fn check_file_size(&self, size: usize) -> Result<()> {
+        if size > self.max_file_size {
+            return Err(Error::file_too_large(size as u64, self.max_file_size as u64));
+        }
+        Ok(())
+    }
+}
+
+impl Default for CsvParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a numeric field starts with a zero that a number would drop, e.g. `00123`
+fn has_leading_zero(field: &str) -> bool {
+    let digits = field.strip_prefix(['-', '+']).unwrap_or(field).as_bytes();
+    digits.len() > 1 && digits[0] == b'0' && digits[1].is_ascii_digit()
+}
+
+/// Convert a raw field into a typed JSON value
+fn infer_cell_value(field: &str) -> Value {
+    let trimmed = field.trim();
+    if trimmed.is_empty() {
+        return Value::Null;
+    }
+
+    // Zero-padded identifiers such as asset tags and ZIP codes stay text
+    if has_leading_zero(trimmed) {
+        return Value::String(field.to_string());
+    }
+
+    if let Ok(integer) = trimmed.parse::<i64>() {
+        return Value::Number(integer.into());
+    }
+
+    if let Some(number) = trimmed.parse::<f64>().ok().and_then(serde_json::Number::from_f64) {
+        return Value::Number(number);
+    }
+
+    if trimmed.eq_ignore_ascii_case("true") {
+        return Value::Bool(true);
+    }
+    if trimmed.eq_ignore_ascii_case("false") {
+        return Value::Bool(false);
+    }
+
+    Value::String(field.to_string())
+}
+
+#[async_trait]
+impl DocumentParser for CsvParser {
+    async fn parse_file(&self, path: &Path) -> Result<ParseResult> {
+        info!("Parsing CSV file: {}", path.display());
+
+        let metadata = fs::metadata(path).await?;
+        self.check_file_size(metadata.len() as usize)?;
+
+        let data = fs::read(path).await?;
+        self.parse_bytes(&data, &path.to_string_lossy()).await
+    }
+
+    async fn parse_bytes(&self, data: &[u8], filename: &str) -> Result<ParseResult> {
+        self.check_file_size(data.len())?;
+
+        let decoded = decode_text(data, &self.encoding)?;
+        let mut result = self.parse_text(&decoded.text, filename)?;
+
+        result.metadata["file_info"]["encoding"] = decoded.metadata();
+        if let Some(warning) = decoded.warning() {
+            result.validation_errors.push(ValidationError::new("", warning, ValidationSeverity::Warning));
+        }
+
+        Ok(result)
+    }
+
+    async fn validate(&self, content: &serde_json::Value) -> Result<Vec<String>> {
+        let mut errors = Vec::new();
+
+        match content.get("worksheets").and_then(|v| v.as_array()) {
+            Some(worksheets) if worksheets.len() == 1 => {
+                if worksheets[0].get("data").and_then(|v| v.as_array()).is_none() {
+                    errors.push("Worksheet 0 missing required 'data' field".to_string());
+                }
+            }
+            Some(worksheets) => {
+                errors.push(format!("Expected a single worksheet, found {}", worksheets.len()));
+            }
+            None => errors.push("Content missing 'worksheets' array".to_string()),
+        }
+
+        Ok(errors)
+    }
+
+    fn supported_extensions(&self) -> Vec<&'static str> {
+        vec!["csv", "tsv", "tab"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quoted_fields_with_embedded_commas() {
+        let csv = "Asset ID,Hostname,Location,Port\n\
+                   A-001,web01,\"Reston, VA\",443\n\
+                   A-002,\"db01\",\"\"\"Primary\"\", US-East\",5432\n";
+
+        let result = CsvParser::new().parse_text(csv, "inventory.csv").unwrap();
+        let worksheet = &result.content["worksheets"][0];
+
+        assert_eq!(result.document_type, DocumentType::Csv);
+        assert_eq!(worksheet["name"], "inventory");
+        assert_eq!(worksheet["column_count"], 4);
+        assert_eq!(
+            worksheet["headers"],
+            serde_json::json!(["Asset ID", "Hostname", "Location", "Port"])
+        );
+        assert_eq!(worksheet["data"][1][2], "Reston, VA");
+        assert_eq!(worksheet["data"][1][3], 443);
+        assert_eq!(worksheet["data"][2][1], "db01");
+        assert_eq!(worksheet["data"][2][2], "\"Primary\", US-East");
+    }
+
+    #[test]
+    fn test_max_rows_truncation_is_reported() {
+        let csv = "id\n1\n2\n3\n4\n";
+
+        let result = CsvParser::new().max_rows(Some(2)).parse_text(csv, "inventory.csv").unwrap();
+        let worksheet = &result.content["worksheets"][0];
+
+        assert_eq!(worksheet["row_count"], 2);
+        assert_eq!(worksheet["total_row_count"], 5);
+        assert_eq!(worksheet["truncated"], true);
+        assert_eq!(result.validation_errors.len(), 1);
+        assert_eq!(result.validation_errors[0].severity, ValidationSeverity::Warning);
+    }
+
+    #[test]
+    fn test_tsv_delimiter_inferred_from_extension() {
+        let tsv = "Name\tOwner\nweb01\tOps, Team\n";
+
+        let result = CsvParser::new().parse_text(tsv, "export.tsv").unwrap();
+        let worksheet = &result.content["worksheets"][0];
+
+        assert_eq!(result.metadata["file_info"]["format"], "tsv");
+        assert_eq!(worksheet["data"][1][1], "Ops, Team");
+    }
+
+    #[test]
+    fn test_zero_padded_values_stay_strings() {
+        let csv = "Asset Tag,ZIP,Port,Weight\n00123,02139,0,0.5\n";
+
+        let result = CsvParser::new().parse_text(csv, "inventory.csv").unwrap();
+        let row = &result.content["worksheets"][0]["data"][1];
+
+        assert_eq!(row, &serde_json::json!(["00123", "02139", 0, 0.5]));
+    }
+
+    #[test]
+    fn test_ragged_rows_are_padded() {
+        let csv = "a,b,c\n1\n";
+
+        let result = CsvParser::new().with_delimiter(b',').parse_text(csv, "ragged.txt").unwrap();
+        let row = &result.content["worksheets"][0]["data"][1];
+
+        assert_eq!(row, &serde_json::json!([1, null, null]));
+    }
+
+    #[tokio::test]
+    async fn test_windows_1252_export_is_transcoded() {
+        let data = b"System,Owner\nPortal,Jos\xe9 Mu\xf1oz\n";
+
+        let result = CsvParser::new().parse_bytes(data, "owners.csv").await.unwrap();
+
+        assert_eq!(result.content["worksheets"][0]["data"][1][1], "José Muñoz");
+        assert_eq!(result.metadata["file_info"]["encoding"]["name"], "windows-1252");
+        assert!(result.validation_errors.is_empty());
+    }
+}
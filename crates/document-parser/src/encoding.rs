@@ -0,0 +1,213 @@
+// Modified: 2025-09-20
+
+//! Text encoding detection for text-based parsers
+//!
+//! Government systems frequently export Windows-1252 or UTF-16 text. Input is
+//! decoded to UTF-8 by sniffing a byte order mark, then looking for BOM-less
+//! UTF-16, then checking for valid UTF-8, then falling back to statistical
+//! detection. Malformed sequences are replaced rather than rejected, and the
+//! result records what happened.
+
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Number of leading bytes inspected by the UTF-16 heuristic
+const UTF16_SAMPLE_BYTES: usize = 1024;
+
+/// How text-based parsers choose an input encoding
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EncodingDetection {
+    /// Detect from BOM, UTF-16 zero bytes, UTF-8 validity, then content heuristics
+    #[default]
+    Auto,
+    /// Always decode with the given WHATWG label (e.g. `windows-1252`)
+    Fixed(String),
+}
+
+/// How the encoding of a decoded text was determined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EncodingSource {
+    /// Byte order mark at the start of the input
+    Bom,
+    /// Input was valid UTF-8
+    Utf8,
+    /// Content heuristics
+    Heuristic,
+    /// Encoding was configured explicitly
+    Configured,
+}
+
+/// Text decoded to UTF-8 with details of the source encoding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedText {
+    /// Decoded text, without any byte order mark
+    pub text: String,
+    /// Name of the source encoding, e.g. `UTF-8`, `windows-1252`, `UTF-16LE`
+    pub encoding: &'static str,
+    /// How the encoding was determined
+    pub source: EncodingSource,
+    /// Whether malformed sequences were replaced with U+FFFD
+    pub lossy: bool,
+}
+
+impl DecodedText {
+    /// Warning to surface when the conversion was lossy
+    pub fn warning(&self) -> Option<String> {
+        self.lossy.then(|| {
+            format!(
+                "Input contained byte sequences that are invalid in {}; they were replaced with U+FFFD",
+                self.encoding
+            )
+        })
+    }
+
+    /// Encoding details for parse result metadata
+    pub fn metadata(&self) -> serde_json::Value {
+        serde_json::json!({
+            "name": self.encoding,
+            "detected_by": self.source,
+            "lossy": self.lossy,
+        })
+    }
+}
+
+/// Decode raw bytes to UTF-8 according to the detection setting
+///
+/// Fails only if a fixed encoding label is not recognized.
+pub fn decode_text(data: &[u8], detection: &EncodingDetection) -> Result<DecodedText> {
+    let (encoding, source, bom_len) = match detection {
+        EncodingDetection::Fixed(label) => {
+            let encoding = Encoding::for_label(label.trim().as_bytes())
+                .ok_or_else(|| Error::configuration(format!("Unknown text encoding: {}", label)))?;
+            let bom_len = match Encoding::for_bom(data) {
+                Some((bom_encoding, bom_len)) if bom_encoding == encoding => bom_len,
+                _ => 0,
+            };
+            (encoding, EncodingSource::Configured, bom_len)
+        }
+        EncodingDetection::Auto => detect_encoding(data),
+    };
+
+    let (text, lossy) = encoding.decode_without_bom_handling(&data[bom_len..]);
+    let decoded = DecodedText {
+        text: text.into_owned(),
+        encoding: encoding.name(),
+        source,
+        lossy,
+    };
+
+    if let Some(warning) = decoded.warning() {
+        warn!("{}", warning);
+    }
+
+    Ok(decoded)
+}
+
+/// Pick an encoding and the length of any byte order mark to skip
+fn detect_encoding(data: &[u8]) -> (&'static Encoding, EncodingSource, usize) {
+    if let Some((encoding, bom_len)) = Encoding::for_bom(data) {
+        return (encoding, EncodingSource::Bom, bom_len);
+    }
+
+    // ASCII-range UTF-16 is also valid UTF-8, so check for it first
+    if let Some(encoding) = detect_utf16_without_bom(data) {
+        return (encoding, EncodingSource::Heuristic, 0);
+    }
+
+    if std::str::from_utf8(data).is_ok() {
+        return (UTF_8, EncodingSource::Utf8, 0);
+    }
+
+    let mut detector = chardetng::EncodingDetector::new();
+    detector.feed(data, true);
+    (detector.guess(None, true), EncodingSource::Heuristic, 0)
+}
+
+/// Recognize BOM-less UTF-16 from the distribution of zero bytes
+///
+/// Mostly-ASCII UTF-16 text has a zero in every other byte: odd offsets for
+/// little-endian, even offsets for big-endian.
+fn detect_utf16_without_bom(data: &[u8]) -> Option<&'static Encoding> {
+    let sample = &data[..data.len().min(UTF16_SAMPLE_BYTES) & !1];
+    if sample.len() < 4 {
+        return None;
+    }
+
+    let pairs = sample.len() / 2;
+    let even_zeros = sample.iter().step_by(2).filter(|&&b| b == 0).count();
+    let odd_zeros = sample.iter().skip(1).step_by(2).filter(|&&b| b == 0).count();
+
+    if odd_zeros * 10 >= pairs * 4 && even_zeros * 20 <= pairs {
+        Some(UTF_16LE)
+    } else if even_zeros * 10 >= pairs * 4 && odd_zeros * 20 <= pairs {
+        Some(UTF_16BE)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16le(text: &str, bom: bool) -> Vec<u8> {
+        let mut bytes = if bom { vec![0xFF, 0xFE] } else { Vec::new() };
+        bytes.extend(text.encode_utf16().flat_map(u16::to_le_bytes));
+        bytes
+    }
+
+    #[test]
+    fn test_utf8_passes_through() {
+        let decoded = decode_text("Access Control – AC-2".as_bytes(), &EncodingDetection::Auto).unwrap();
+
+        assert_eq!(decoded.text, "Access Control – AC-2");
+        assert_eq!(decoded.encoding, "UTF-8");
+        assert_eq!(decoded.source, EncodingSource::Utf8);
+        assert!(!decoded.lossy);
+    }
+
+    #[test]
+    fn test_utf16le_detected_with_and_without_bom() {
+        let with_bom = decode_text(&utf16le("# System Security Plan", true), &EncodingDetection::Auto).unwrap();
+        assert_eq!(with_bom.text, "# System Security Plan");
+        assert_eq!(with_bom.encoding, "UTF-16LE");
+        assert_eq!(with_bom.source, EncodingSource::Bom);
+
+        let without_bom = decode_text(&utf16le("# System Security Plan", false), &EncodingDetection::Auto).unwrap();
+        assert_eq!(without_bom.text, "# System Security Plan");
+        assert_eq!(without_bom.encoding, "UTF-16LE");
+        assert_eq!(without_bom.source, EncodingSource::Heuristic);
+    }
+
+    #[test]
+    fn test_windows_1252_detected() {
+        // "Café – résumé" with Windows-1252 accented letters and en dash
+        let data = b"Caf\xe9 \x96 r\xe9sum\xe9 for the system owner's review";
+
+        let decoded = decode_text(data, &EncodingDetection::Auto).unwrap();
+
+        assert_eq!(decoded.encoding, "windows-1252");
+        assert!(decoded.text.starts_with("Café – résumé"));
+        assert!(!decoded.lossy);
+    }
+
+    #[test]
+    fn test_fixed_encoding_falls_back_to_lossy() {
+        let decoded = decode_text(b"ok \xff\xfe bytes", &EncodingDetection::Fixed("utf-8".to_string())).unwrap();
+
+        assert_eq!(decoded.source, EncodingSource::Configured);
+        assert!(decoded.lossy);
+        assert!(decoded.text.contains('\u{fffd}'));
+        assert!(decoded.warning().is_some());
+    }
+
+    #[test]
+    fn test_unknown_fixed_encoding_is_rejected() {
+        let result = decode_text(b"text", &EncodingDetection::Fixed("klingon".to_string()));
+        assert!(matches!(result, Err(Error::Configuration { .. })));
+    }
+}
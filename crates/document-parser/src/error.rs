@@ -230,3 +230,37 @@ impl DocumentParserError {
         }
     }
 }
+
+impl From<DocumentParserError> for fedramp_core::Error {
+    /// Map to the typed platform variants where one exists, keeping the
+    /// message as a document parsing error otherwise
+    fn from(error: DocumentParserError) -> Self {
+        match error {
+            DocumentParserError::UnsupportedFormat { format } => Self::unsupported_format(format),
+            DocumentParserError::FileTooLarge { size, max_size } => Self::file_too_large(size, max_size),
+            DocumentParserError::FileNotFound { path } => Self::not_found(path),
+            DocumentParserError::Utf8 { source } => Self::encoding(source.to_string()),
+            DocumentParserError::Io { source } => Self::Io { source },
+            DocumentParserError::Json { source } => Self::Serialization { source },
+            other => Self::document_parsing(other.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fedramp_core::Error;
+
+    #[test]
+    fn test_converts_to_typed_platform_errors() {
+        let error: Error = DocumentParserError::file_too_large(2048, 1024).into();
+        assert!(matches!(error, Error::FileTooLarge { size: 2048, limit: 1024 }));
+
+        let error: Error = DocumentParserError::unsupported_format("pdf").into();
+        assert!(matches!(error, Error::UnsupportedFormat { format } if format == "pdf"));
+
+        let error: Error = DocumentParserError::parsing_failed("bad header").into();
+        assert!(matches!(error, Error::DocumentParsing { message } if message == "Parsing failed: bad header"));
+    }
+}
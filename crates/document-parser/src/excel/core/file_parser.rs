@@ -5,7 +5,7 @@
 //! This module contains the implementation for parsing Excel files from paths
 //! and byte arrays with comprehensive error handling and validation.
 
-use crate::{DocumentParser, ParseResult, DocumentType};
+use crate::{DocumentParser, ParseResult, DocumentType, ValidationError};
 use crate::excel::types::*;
 use async_trait::async_trait;
 use calamine::{Reader, Xlsx};
@@ -15,6 +15,7 @@ use tokio::fs;
 use tracing::{debug, info, warn};
 use chrono::Utc;
 
+use super::merged_cells::detect_merged_cells;
 use super::types::ExcelParser;
 use super::worksheet_detector::WorksheetDetector;
 use super::worksheet_parser::WorksheetParser;
@@ -44,11 +45,7 @@ impl ExcelParser {
 
         // Check file size
         if metadata.len() > self.max_file_size as u64 {
-            return Err(Error::document_parsing(format!(
-                "File size {} exceeds maximum limit of {} bytes",
-                metadata.len(),
-                self.max_file_size
-            )));
+            return Err(Error::file_too_large(metadata.len(), self.max_file_size as u64));
         }
 
         info!("Parsing Excel file: {} (size: {} bytes)", path.display(), metadata.len());
@@ -73,61 +70,90 @@ impl ExcelParser {
     ///
     /// # Errors
     ///
-    /// Returns error if Excel format is unsupported or file is corrupted
+    /// Returns error if Excel format is unsupported, the file is corrupted,
+    /// or it is password-protected
     pub async fn parse_excel_bytes(&self, data: &[u8], filename: &str) -> Result<ParseResult> {
         debug!("Parsing Excel data from bytes (size: {} bytes)", data.len());
 
+        if is_encrypted_ooxml(data) {
+            return Err(Error::encrypted_document(filename));
+        }
+
         // Create cursor for reading
         let cursor = std::io::Cursor::new(data);
         
         // Try to open as Excel file
         let mut workbook = Xlsx::new(cursor)
-            .map_err(|e| Error::document_parsing(format!("Failed to open Excel file: {}", e)))?;
+            .map_err(|e| Error::corrupt_archive(format!("Failed to open Excel file: {}", e)))?;
+
+        // Macros are reported for security review but do not stop parsing
+        let has_macros = workbook.vba_project().is_some();
 
         // Detect worksheets
         let detector = WorksheetDetector::new();
         let worksheet_info = detector.detect_worksheets(&mut workbook).await?;
+        let mut merged_cells = detect_merged_cells(data).unwrap_or_else(|e| {
+            warn!("Merged cells of {} could not be read: {}", filename, e);
+            Default::default()
+        });
         
         // Parse all worksheets
         let parser = WorksheetParser::new(self);
         let mut worksheets = Vec::new();
         let mut all_validation_errors = Vec::new();
+        if has_macros {
+            warn!("Workbook {} contains VBA macros", filename);
+            all_validation_errors.push(ValidationError::new(
+                "",
+                "Security: workbook contains VBA macros (xl/vbaProject.bin); review them before relying on its content",
+                crate::ValidationSeverity::Warning,
+            ));
+        }
         let mut total_quality_score = 0.0;
+        let mut skipped_worksheets = Vec::new();
 
         for sheet_metadata in &worksheet_info.sheets {
+            if !self.sheet_filter.allows(&sheet_metadata.name) {
+                debug!("Skipping filtered worksheet: {}", sheet_metadata.name);
+                skipped_worksheets.push(sheet_metadata.name.clone());
+                continue;
+            }
+
             if !sheet_metadata.has_data {
                 debug!("Skipping empty worksheet: {}", sheet_metadata.name);
                 continue;
             }
 
-            match parser.parse_worksheet(&mut workbook, &sheet_metadata.name).await {
+            let sheet_merged_cells = merged_cells.remove(&sheet_metadata.name).unwrap_or_default();
+            match parser.parse_worksheet_with_merged_cells(&mut workbook, &sheet_metadata.name, sheet_merged_cells).await {
                 Ok(worksheet) => {
                     total_quality_score += worksheet.validation_summary.average_confidence;
+
+                    if worksheet.truncated {
+                        all_validation_errors.push(truncation_warning(
+                            worksheets.len(),
+                            &worksheet.name,
+                            worksheet.total_row_count,
+                            worksheet.row_count,
+                        ));
+                    }
                     
                     // Collect validation errors
-                    for result in &worksheet.validation_results {
-                        for issue in &result.issues {
-                            if issue.severity >= ValidationSeverity::Error {
-                                all_validation_errors.push(format!(
-                                    "Sheet '{}' Row {} Col {}: {}",
-                                    worksheet.name,
-                                    result.row + 1,
-                                    result.column + 1,
-                                    issue.message
-                                ));
-                            }
-                        }
-                    }
+                    all_validation_errors.extend(cell_validation_errors(
+                        worksheets.len(),
+                        &worksheet.name,
+                        &worksheet.validation_results,
+                    ));
                     
                     worksheets.push(worksheet);
                 }
                 Err(e) => {
                     warn!("Failed to parse worksheet '{}': {}", sheet_metadata.name, e);
-                    all_validation_errors.push(format!(
+                    all_validation_errors.push(ValidationError::document(format!(
                         "Failed to parse worksheet '{}': {}",
                         sheet_metadata.name,
                         e
-                    ));
+                    )));
                 }
             }
         }
@@ -146,8 +172,10 @@ impl ExcelParser {
                 "format": worksheet_info.format,
                 "total_worksheets": worksheet_info.total_count,
                 "parsed_worksheets": worksheets.len(),
+                "skipped_worksheets": skipped_worksheets,
                 "total_cells": worksheet_info.total_cells,
-                "total_rows": worksheet_info.total_rows
+                "total_rows": worksheet_info.total_rows,
+                "has_macros": has_macros
             },
             "parsing_info": {
                 "parser_version": "1.0.0",
@@ -162,11 +190,15 @@ impl ExcelParser {
                 serde_json::json!({
                     "name": ws.name,
                     "row_count": ws.row_count,
+                    "total_row_count": ws.total_row_count,
+                    "truncated": ws.truncated,
                     "column_count": ws.column_count,
                     "headers": ws.headers,
+                    "header_row_count": ws.header_row_count,
                     "data": ws.data,
                     "merged_cells": ws.merged_cells,
-                    "validation_summary": ws.validation_summary
+                    "validation_summary": ws.validation_summary,
+                    "source_text": ws.source_text
                 })
             }).collect::<Vec<_>>()
         });
@@ -181,14 +213,53 @@ impl ExcelParser {
         })
     }
 
+    /// Read an Excel file's metadata without parsing worksheet rows
+    ///
+    /// Only the workbook part is read for the sheet names, so this is cheap
+    /// even for files above `max_file_size`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be opened as an Excel workbook
+    pub async fn parse_excel_metadata(&self, path: &Path) -> Result<serde_json::Value> {
+        let file_size = fs::metadata(path).await
+            .map_err(|e| Error::document_parsing(format!("Failed to read file metadata: {}", e)))?
+            .len();
+
+        let workbook_path = path.to_path_buf();
+        let sheet_names = tokio::task::spawn_blocking(move || -> Result<Vec<String>> {
+            let file = std::fs::File::open(&workbook_path)?;
+            let workbook = match Xlsx::new(std::io::BufReader::new(file)) {
+                Ok(workbook) => workbook,
+                Err(_) if is_encrypted_ooxml(&std::fs::read(&workbook_path)?) => {
+                    return Err(Error::encrypted_document(workbook_path.to_string_lossy()));
+                }
+                Err(e) => return Err(Error::corrupt_archive(format!("Failed to open Excel file: {}", e))),
+            };
+            Ok(workbook.sheet_names().to_owned())
+        })
+        .await
+        .map_err(|e| Error::internal(format!("Excel metadata task failed: {}", e)))??;
+
+        debug!("Read {} worksheet names from {}", sheet_names.len(), path.display());
+
+        Ok(serde_json::json!({
+            "file_info": {
+                "filename": path.to_string_lossy(),
+                "format": path.extension()
+                    .and_then(|extension| self.get_format_from_extension(&extension.to_string_lossy()))
+                    .unwrap_or(ExcelFormat::Xlsx),
+                "file_size": file_size,
+                "total_worksheets": sheet_names.len(),
+            },
+            "sheet_names": sheet_names,
+        }))
+    }
+
     /// Validate file size before processing
     pub fn validate_file_size(&self, file_size: u64) -> Result<()> {
         if file_size > self.max_file_size as u64 {
-            return Err(Error::document_parsing(format!(
-                "File size {} exceeds maximum limit of {} bytes",
-                file_size,
-                self.max_file_size
-            )));
+            return Err(Error::file_too_large(file_size, self.max_file_size as u64));
         }
         Ok(())
     }
@@ -268,6 +339,57 @@ fn get_available_memory() -> usize {
     1024 * 1024 * 1024 // 1GB default
 }
 
+/// Convert cell issues at `Error` severity or above into located validation errors
+///
+/// Paths point at the cell within the worksheet's `data` array in the parse
+/// result content, e.g. `/worksheets/0/data/5/2`.
+pub(crate) fn cell_validation_errors(
+    worksheet_index: usize,
+    worksheet_name: &str,
+    results: &[CellValidationResult],
+) -> Vec<ValidationError> {
+    results
+        .iter()
+        .flat_map(|result| {
+            result
+                .issues
+                .iter()
+                .filter(|issue| issue.severity >= ValidationSeverity::Error)
+                .map(move |issue| {
+                    ValidationError::new(
+                        format!("/worksheets/{}/data/{}/{}", worksheet_index, result.row, result.column),
+                        format!(
+                            "Sheet '{}' Row {} Col {}: {}",
+                            worksheet_name,
+                            result.row + 1,
+                            result.column + 1,
+                            issue.message
+                        ),
+                        issue.severity.clone().into(),
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Warning that a worksheet was cut off at `max_rows`, located at the worksheet
+pub(crate) fn truncation_warning(
+    worksheet_index: usize,
+    worksheet_name: &str,
+    total_rows: usize,
+    processed_rows: usize,
+) -> ValidationError {
+    warn!("Worksheet '{}' truncated to {} of {} rows", worksheet_name, processed_rows, total_rows);
+    ValidationError::new(
+        format!("/worksheets/{}", worksheet_index),
+        format!(
+            "Sheet '{}' has {} rows but only the first {} were processed (max_rows)",
+            worksheet_name, total_rows, processed_rows
+        ),
+        crate::ValidationSeverity::Warning,
+    )
+}
+
 /// Estimate processing time based on file size
 fn estimate_processing_time(file_size: u64) -> std::time::Duration {
     // Rough estimate: 1MB per second
@@ -275,6 +397,24 @@ fn estimate_processing_time(file_size: u64) -> std::time::Duration {
     std::time::Duration::from_secs(seconds)
 }
 
+/// Signature of an OLE compound file, the container Office uses for encrypted workbooks
+const COMPOUND_FILE_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// Whether data is a password-protected OOXML package
+///
+/// Office saves an encrypted workbook as a compound file holding an
+/// `EncryptionInfo` stream next to the encrypted package instead of a ZIP
+/// archive. Directory entry names are UTF-16LE, so the stream name is looked
+/// up as such.
+fn is_encrypted_ooxml(data: &[u8]) -> bool {
+    if !data.starts_with(&COMPOUND_FILE_SIGNATURE) {
+        return false;
+    }
+
+    let stream_name: Vec<u8> = "EncryptionInfo".encode_utf16().flat_map(u16::to_le_bytes).collect();
+    data.windows(stream_name.len()).any(|window| window == stream_name.as_slice())
+}
+
 #[async_trait]
 impl DocumentParser for ExcelParser {
     /// Parse Excel file from path
@@ -287,6 +427,11 @@ impl DocumentParser for ExcelParser {
         self.parse_excel_bytes(data, filename).await
     }
 
+    /// Read Excel metadata without parsing worksheet rows
+    async fn parse_metadata(&self, path: &Path) -> Result<serde_json::Value> {
+        self.parse_excel_metadata(path).await
+    }
+
     /// Validate parsed Excel content
     async fn validate(&self, content: &serde_json::Value) -> Result<Vec<String>> {
         let mut errors = Vec::new();
@@ -333,7 +478,10 @@ mod tests {
         assert!(parser.validate_file_size(50 * 1024 * 1024).is_ok());
         
         // Test file size too large
-        assert!(parser.validate_file_size(200 * 1024 * 1024).is_err());
+        assert!(matches!(
+            parser.validate_file_size(200 * 1024 * 1024),
+            Err(Error::FileTooLarge { size, .. }) if size == 200 * 1024 * 1024
+        ));
     }
 
     #[test]
@@ -357,6 +505,65 @@ mod tests {
         assert_eq!(parser.get_format_from_extension("pdf"), None);
     }
 
+    #[tokio::test]
+    async fn test_metadata_lists_sheets_without_parsing_rows() {
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        workbook.add_worksheet().set_name("POA&M Items").unwrap().write_string(0, 0, "Unique ID").unwrap();
+        workbook.add_worksheet().set_name("Instructions").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("poam.xlsx");
+        workbook.save(&path).unwrap();
+
+        // A size limit below the workbook refuses a full parse
+        let mut parser = ExcelParser::new();
+        parser.set_max_file_size(16);
+        assert!(parser.parse_file(&path).await.is_err());
+
+        let metadata = parser.parse_metadata(&path).await.unwrap();
+        assert_eq!(metadata["sheet_names"], serde_json::json!(["POA&M Items", "Instructions"]));
+        assert_eq!(metadata["file_info"]["total_worksheets"], 2);
+        assert!(metadata.get("worksheets").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sheet_filter_parses_only_included_sheets() {
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        for name in ["Open POA&M Items", "Closed POA&M Items", "Instructions"] {
+            workbook.add_worksheet().set_name(name).unwrap().write_string(0, 0, "Unique ID").unwrap();
+        }
+        let data = workbook.save_to_buffer().unwrap();
+
+        let parser = ExcelParser::builder()
+            .include_sheets(SheetNamePattern::Glob("open*".to_string()))
+            .unwrap()
+            .build();
+        let result = parser.parse_bytes(&data, "poam.xlsx").await.unwrap();
+
+        let worksheets = result.content["worksheets"].as_array().unwrap();
+        assert_eq!(worksheets.len(), 1);
+        assert_eq!(worksheets[0]["name"], "Open POA&M Items");
+        assert_eq!(
+            result.metadata["file_info"]["skipped_worksheets"],
+            serde_json::json!(["Closed POA&M Items", "Instructions"])
+        );
+
+        let all = ExcelParser::new().parse_bytes(&data, "poam.xlsx").await.unwrap();
+        assert_eq!(all.content["worksheets"].as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_invalid_sheet_regex_is_rejected() {
+        let invalid = SheetNamePattern::Regex("^Q[1-4".to_string());
+
+        assert!(ExcelParser::builder().include_sheets(invalid.clone()).is_err());
+        assert!(ExcelParser::builder().exclude_sheets(invalid.clone()).is_err());
+
+        let mut parser = ExcelParser::new();
+        let filter = SheetFilter { include: vec![invalid], exclude: Vec::new() };
+        assert!(parser.set_sheet_filter(filter).is_err());
+        assert!(parser.sheet_filter().is_empty());
+    }
+
     #[test]
     fn test_memory_estimation() {
         let parser = ExcelParser::new();
@@ -369,6 +576,44 @@ mod tests {
         assert!(estimated < file_size as usize * 5);
     }
 
+    #[test]
+    fn test_bad_cell_reports_json_pointer() {
+        let issue = |severity: ValidationSeverity, message: &str| ValidationIssue {
+            issue_type: ValidationIssueType::PotentialInjection,
+            severity,
+            message: message.to_string(),
+            suggestion: None,
+            auto_fixed: false,
+        };
+        let results = vec![
+            CellValidationResult {
+                row: 5,
+                column: 2,
+                original_value: Some(serde_json::json!("=cmd|' /C calc'!A0")),
+                sanitized_value: Some(serde_json::json!("'=cmd|' /C calc'!A0")),
+                is_valid: false,
+                issues: vec![issue(ValidationSeverity::Critical, "Potential formula injection")],
+                confidence: 0.1,
+            },
+            CellValidationResult {
+                row: 1,
+                column: 0,
+                original_value: Some(serde_json::json!("ok")),
+                sanitized_value: Some(serde_json::json!("ok")),
+                is_valid: true,
+                issues: vec![issue(ValidationSeverity::Warning, "Trailing whitespace")],
+                confidence: 0.9,
+            },
+        ];
+
+        let errors = cell_validation_errors(1, "Inventory", &results);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "/worksheets/1/data/5/2");
+        assert_eq!(errors[0].severity, crate::ValidationSeverity::Critical);
+        assert_eq!(errors[0].message, "Sheet 'Inventory' Row 6 Col 3: Potential formula injection");
+    }
+
     #[test]
     fn test_processing_capability() {
         let parser = ExcelParser::new();
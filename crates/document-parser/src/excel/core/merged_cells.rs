@@ -0,0 +1,209 @@
+//! Modified: 2025-09-24
+
+//! Merged cell detection
+//!
+//! calamine does not expose merged ranges, so they are read from the
+//! `<mergeCells>` element of each worksheet part in the XLSX archive. Sheet
+//! names are resolved to their parts through `xl/workbook.xml` and its
+//! relationships.
+
+use crate::excel::types::MergedCellRange;
+use fedramp_core::{Result, Error};
+use regex::Regex;
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek};
+use tracing::warn;
+use zip::ZipArchive;
+
+/// Most merged ranges read per worksheet; further ranges are ignored
+pub const MAX_MERGED_RANGES_PER_SHEET: usize = 10_000;
+
+/// Merged cell ranges of every worksheet in an XLSX file, keyed by sheet name
+///
+/// Ranges are returned without a `value`; the worksheet parser fills it in
+/// from the cell data. Worksheets without merged cells are left out.
+///
+/// # Errors
+///
+/// Returns error if the archive or its workbook part cannot be read
+pub fn detect_merged_cells(data: &[u8]) -> Result<HashMap<String, Vec<MergedCellRange>>> {
+    let mut archive = ZipArchive::new(Cursor::new(data))
+        .map_err(|e| Error::corrupt_archive(format!("Failed to open Excel file: {}", e)))?;
+    let workbook = read_part(&mut archive, "xl/workbook.xml")?;
+    let relationships = read_part(&mut archive, "xl/_rels/workbook.xml.rels")?;
+
+    let targets: HashMap<String, String> = elements(&relationships, "Relationship")
+        .into_iter()
+        .filter_map(|mut attributes| Some((attributes.remove("Id")?, attributes.remove("Target")?)))
+        .collect();
+
+    let mut merged_cells = HashMap::new();
+    for sheet in elements(&workbook, "sheet") {
+        let (Some(name), Some(target)) = (sheet.get("name"), sheet.get("id").and_then(|id| targets.get(id))) else {
+            continue;
+        };
+        let Ok(worksheet) = read_part(&mut archive, &part_path(target)) else {
+            warn!("Worksheet part {} of '{}' is missing; merged cells not read", target, name);
+            continue;
+        };
+
+        let references = elements(&worksheet, "mergeCell");
+        if references.len() > MAX_MERGED_RANGES_PER_SHEET {
+            warn!(
+                "Worksheet '{}' has {} merged ranges; only the first {} are read",
+                name,
+                references.len(),
+                MAX_MERGED_RANGES_PER_SHEET
+            );
+        }
+        let ranges: Vec<MergedCellRange> = references.iter()
+            .take(MAX_MERGED_RANGES_PER_SHEET)
+            .filter_map(|attributes| parse_range(attributes.get("ref")?))
+            .collect();
+        if !ranges.is_empty() {
+            merged_cells.insert(name.clone(), ranges);
+        }
+    }
+
+    Ok(merged_cells)
+}
+
+/// Parse an A1-style range such as `B1:C1`; single cells are not merged ranges
+pub fn parse_range(reference: &str) -> Option<MergedCellRange> {
+    let (start, end) = reference.split_once(':')?;
+    let (start_row, start_col) = parse_cell_reference(start)?;
+    let (end_row, end_col) = parse_cell_reference(end)?;
+    if end_row < start_row || end_col < start_col || (start_row, start_col) == (end_row, end_col) {
+        return None;
+    }
+
+    Some(MergedCellRange {
+        start_row,
+        start_col,
+        end_row,
+        end_col,
+        value: None,
+        cell_count: (end_row - start_row + 1) * (end_col - start_col + 1),
+    })
+}
+
+/// 0-based row and column of an A1-style cell reference, `$` anchors allowed
+pub fn parse_cell_reference(reference: &str) -> Option<(usize, usize)> {
+    let reference = reference.replace('$', "");
+    let digits = reference.find(|c: char| c.is_ascii_digit())?;
+    let (letters, row) = reference.split_at(digits);
+    if letters.is_empty() || letters.len() > 3 || !letters.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let column = letters.chars()
+        .fold(0usize, |column, letter| column * 26 + (letter.to_ascii_uppercase() as usize - 'A' as usize + 1));
+    let row: usize = row.parse().ok()?;
+    Some((row.checked_sub(1)?, column - 1))
+}
+
+fn read_part<R: Read + Seek>(archive: &mut ZipArchive<R>, path: &str) -> Result<String> {
+    let mut part = archive.by_name(path)
+        .map_err(|e| Error::document_parsing(format!("Failed to read {}: {}", path, e)))?;
+    let mut xml = String::new();
+    part.read_to_string(&mut xml)
+        .map_err(|e| Error::document_parsing(format!("Failed to read {}: {}", path, e)))?;
+    Ok(xml)
+}
+
+/// Archive path of a workbook relationship target
+fn part_path(target: &str) -> String {
+    match target.strip_prefix('/') {
+        Some(absolute) => absolute.to_string(),
+        None => format!("xl/{}", target),
+    }
+}
+
+/// Attributes of every `tag` element, with namespace prefixes dropped from names
+fn elements(xml: &str, tag: &str) -> Vec<HashMap<String, String>> {
+    let element = Regex::new(&format!(r"<(?:\w+:)?{}\s([^>]*)>", regex::escape(tag)))
+        .expect("element pattern is valid");
+    let attribute = Regex::new(r#"(?:\w+:)?(\w+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#)
+        .expect("attribute pattern is valid");
+
+    element.captures_iter(xml)
+        .map(|element| {
+            attribute.captures_iter(&element[1])
+                .map(|attribute| {
+                    let value = attribute.get(2).or_else(|| attribute.get(3)).map_or("", |value| value.as_str());
+                    (attribute[1].to_string(), unescape(value))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn unescape(value: &str) -> String {
+    value.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::excel::ExcelParser;
+    use rust_xlsxwriter::{Format, Workbook};
+    use serde_json::json;
+
+    /// POA&M sheet whose header has "Asset ID" merged down two rows and
+    /// "Point of Contact" merged over its "Name" and "Email" sub-headers
+    fn two_row_header_workbook() -> Vec<u8> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet().set_name("POA&M Items").unwrap();
+        let format = Format::new();
+        worksheet.merge_range(0, 0, 1, 0, "Asset ID", &format).unwrap();
+        worksheet.merge_range(0, 1, 0, 2, "Point of Contact", &format).unwrap();
+        worksheet.write_string(1, 1, "Name").unwrap();
+        worksheet.write_string(1, 2, "Email").unwrap();
+        worksheet.write_string(2, 0, "HW-001").unwrap();
+        worksheet.write_string(2, 1, "Jane Doe").unwrap();
+        worksheet.write_string(2, 2, "jane.doe@agency.gov").unwrap();
+        workbook.save_to_buffer().unwrap()
+    }
+
+    #[test]
+    fn test_merged_ranges_are_read_by_sheet_name() {
+        let merged_cells = detect_merged_cells(&two_row_header_workbook()).unwrap();
+
+        let ranges = &merged_cells["POA&M Items"];
+        let bounds: Vec<_> = ranges.iter().map(|range| (range.start_row, range.start_col, range.end_row, range.end_col)).collect();
+        assert_eq!(bounds, [(0, 0, 1, 0), (0, 1, 0, 2)]);
+        assert!(ranges.iter().all(|range| range.cell_count == 2));
+    }
+
+    #[test]
+    fn test_cell_references() {
+        assert_eq!(parse_cell_reference("A1"), Some((0, 0)));
+        assert_eq!(parse_cell_reference("$AB$12"), Some((11, 27)));
+        assert_eq!(parse_cell_reference("A0"), None);
+        assert!(parse_range("B2").is_none());
+        assert!(parse_range("C1:A1").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_merged_header_propagates_over_sub_headers() {
+        let data = two_row_header_workbook();
+
+        let parser = ExcelParser::builder().propagate_merged_headers(true).build();
+        let result = parser.parse_excel_bytes(&data, "poam.xlsx").await.unwrap();
+        let worksheet = &result.content["worksheets"][0];
+        assert_eq!(worksheet["headers"], json!(["Asset ID", "Point of Contact Name", "Point of Contact Email"]));
+        assert_eq!(worksheet["header_row_count"], 2);
+        assert_eq!(worksheet["merged_cells"][1]["value"], "Point of Contact");
+
+        // Without the flag only the first row is considered, and its blank merged cell rules it out
+        let result = ExcelParser::new().parse_excel_bytes(&data, "poam.xlsx").await.unwrap();
+        let worksheet = &result.content["worksheets"][0];
+        assert!(worksheet["headers"].is_null());
+        assert_eq!(worksheet["header_row_count"], 0);
+        assert_eq!(worksheet["merged_cells"].as_array().unwrap().len(), 2);
+    }
+}
@@ -11,17 +11,20 @@ pub mod types;
 pub mod file_parser;
 pub mod worksheet_detector;
 pub mod worksheet_parser;
+pub mod merged_cells;
 
 // Re-export all public types and functions for backward compatibility
 pub use types::*;
 pub use file_parser::*;
 pub use worksheet_detector::*;
 pub use worksheet_parser::*;
+pub use merged_cells::*;
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::excel::types::ValidationConfig;
+    use fedramp_core::Error;
 
     #[test]
     fn test_excel_parser_integration() {
@@ -203,9 +206,12 @@ mod tests {
         let worksheet = crate::excel::types::ExcelWorksheet {
             name: "TestSheet".to_string(),
             row_count: 3,
+            total_row_count: 3,
+            truncated: false,
             column_count: 3,
             data,
             headers: Some(vec!["Name".to_string(), "Age".to_string(), "Date".to_string()]),
+            header_row_count: 1,
             merged_cells: Vec::new(),
             cell_formatting: None,
             validation_results: Vec::new(),
@@ -218,6 +224,7 @@ mod tests {
                 issue_breakdown: std::collections::HashMap::new(),
                 max_severity: None,
             },
+            source_text: Vec::new(),
         };
         
         let stats = worksheet_parser.get_worksheet_statistics(&worksheet);
@@ -255,13 +262,17 @@ mod tests {
         let worksheet = crate::excel::types::ExcelWorksheet {
             name: "TestSheet".to_string(),
             row_count: 2,
+            total_row_count: 2,
+            truncated: false,
             column_count: 3,
             data,
             headers: None,
+            header_row_count: 0,
             merged_cells: Vec::new(),
             cell_formatting: None,
             validation_results: Vec::new(),
             validation_summary: crate::excel::types::ValidationSummary::default(),
+            source_text: Vec::new(),
         };
         
         // Extract columns 0 and 2
@@ -275,6 +286,77 @@ mod tests {
         assert_eq!(extracted[1][1], Value::String("C2".to_string()));
     }
 
+    /// Compound file header followed by a directory entry named `EncryptionInfo`
+    fn encrypted_workbook() -> Vec<u8> {
+        let mut data = vec![0u8; 1024];
+        data[..8].copy_from_slice(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]);
+        let name: Vec<u8> = "EncryptionInfo".encode_utf16().flat_map(u16::to_le_bytes).collect();
+        data[512..512 + name.len()].copy_from_slice(&name);
+        data
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_workbook_is_reported_as_encrypted() {
+        let parser = ExcelParser::new();
+
+        let result = parser.parse_excel_bytes(&encrypted_workbook(), "poam.xlsx").await;
+        assert!(matches!(result, Err(Error::EncryptedDocument { filename }) if filename == "poam.xlsx"));
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("inventory.xlsx");
+        std::fs::write(&path, encrypted_workbook()).unwrap();
+        let metadata = parser.parse_excel_metadata(&path).await;
+        assert!(matches!(metadata, Err(Error::EncryptedDocument { .. })));
+
+        let corrupt = parser.parse_excel_bytes(b"not a zip archive", "poam.xlsx").await;
+        assert!(matches!(corrupt, Err(Error::CorruptArchive { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_max_rows_truncation_is_reported() {
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let worksheet = workbook.add_worksheet().set_name("Inventory").unwrap();
+        for row in 0..5 {
+            worksheet.write_string(row, 0, format!("asset-{}", row)).unwrap();
+        }
+        let data = workbook.save_to_buffer().unwrap();
+
+        let parser = ExcelParser::builder().max_rows(Some(2)).build();
+        let result = parser.parse_excel_bytes(&data, "inventory.xlsx").await.unwrap();
+
+        let worksheet = &result.content["worksheets"][0];
+        assert_eq!(worksheet["row_count"], 2);
+        assert_eq!(worksheet["total_row_count"], 5);
+        assert_eq!(worksheet["truncated"], true);
+
+        let warning = result.validation_errors.iter().find(|error| error.path == "/worksheets/0").unwrap();
+        assert_eq!(warning.severity, crate::ValidationSeverity::Warning);
+        assert!(warning.message.contains("has 5 rows but only the first 2"), "{}", warning.message);
+    }
+
+    #[tokio::test]
+    async fn test_macro_enabled_workbook_is_flagged() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let vba_project = dir.path().join("vbaProject.bin");
+        std::fs::write(&vba_project, [0u8; 512]).unwrap();
+
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        workbook.add_worksheet().set_name("Inventory").unwrap().write_string(0, 0, "Asset ID").unwrap();
+        let plain = workbook.save_to_buffer().unwrap();
+        workbook.add_vba_project(&vba_project).unwrap();
+        let macro_enabled = workbook.save_to_buffer().unwrap();
+
+        let parser = ExcelParser::new();
+        let result = parser.parse_excel_bytes(&macro_enabled, "inventory.xlsm").await.unwrap();
+        assert_eq!(result.metadata["file_info"]["has_macros"], true);
+        assert!(result.validation_errors.iter().any(|error| error.message.contains("VBA macros")));
+        assert_eq!(result.content["worksheets"][0]["data"][0][0], "Asset ID");
+
+        let result = parser.parse_excel_bytes(&plain, "inventory.xlsx").await.unwrap();
+        assert_eq!(result.metadata["file_info"]["has_macros"], false);
+        assert!(result.validation_errors.is_empty());
+    }
+
     #[test]
     fn test_date_detection_patterns() {
         let parser = ExcelParser::new();
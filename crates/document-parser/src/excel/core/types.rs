@@ -19,6 +19,12 @@ pub struct ExcelParser {
     pub(crate) max_rows: Option<usize>,
     /// Validation configuration
     pub(crate) validation_config: ValidationConfig,
+    /// Locale for detecting numbers and dates in text cells; `None` keeps text as-is
+    pub(crate) locale: Option<ExcelLocale>,
+    /// Worksheets to parse by name; empty parses all sheets
+    pub(crate) sheet_filter: SheetFilter,
+    /// Whether merged header cells are spread over the columns they span
+    pub(crate) propagate_merged_headers: bool,
 }
 
 impl ExcelParser {
@@ -38,6 +44,9 @@ impl ExcelParser {
             auto_detect_headers: true,
             max_rows: None,
             validation_config: ValidationConfig::default(),
+            locale: None,
+            sheet_filter: SheetFilter::default(),
+            propagate_merged_headers: false,
         }
     }
 
@@ -67,6 +76,9 @@ impl ExcelParser {
             auto_detect_headers,
             max_rows,
             validation_config: ValidationConfig::default(),
+            locale: None,
+            sheet_filter: SheetFilter::default(),
+            propagate_merged_headers: false,
         }
     }
 
@@ -99,6 +111,9 @@ impl ExcelParser {
             auto_detect_headers,
             max_rows,
             validation_config,
+            locale: None,
+            sheet_filter: SheetFilter::default(),
+            propagate_merged_headers: false,
         }
     }
 
@@ -150,6 +165,43 @@ impl ExcelParser {
         f(&mut self.validation_config);
     }
 
+    /// Get the locale used to detect numbers and dates in text cells
+    pub fn locale(&self) -> Option<&ExcelLocale> {
+        self.locale.as_ref()
+    }
+
+    /// Set the locale used to detect numbers and dates in text cells
+    ///
+    /// Text cells are kept as strings unless a locale is set.
+    pub fn set_locale(&mut self, locale: Option<ExcelLocale>) {
+        self.locale = locale;
+    }
+
+    /// Get the filter selecting which worksheets are parsed
+    pub fn sheet_filter(&self) -> &SheetFilter {
+        &self.sheet_filter
+    }
+
+    /// Set the filter selecting which worksheets are parsed, rejecting invalid regexes
+    pub fn set_sheet_filter(&mut self, sheet_filter: SheetFilter) -> fedramp_core::Result<()> {
+        sheet_filter.validate()?;
+        self.sheet_filter = sheet_filter;
+        Ok(())
+    }
+
+    /// Get whether merged header cells are spread over the columns they span
+    pub fn propagate_merged_headers(&self) -> bool {
+        self.propagate_merged_headers
+    }
+
+    /// Set whether merged header cells are spread over the columns they span
+    ///
+    /// When set, a header cell merged over several columns names each of
+    /// them, combined with the sub-header below it in two-row headers.
+    pub fn set_propagate_merged_headers(&mut self, propagate_merged_headers: bool) {
+        self.propagate_merged_headers = propagate_merged_headers;
+    }
+
     /// Create a builder for configuring the parser
     pub fn builder() -> ExcelParserBuilder {
         ExcelParserBuilder::new()
@@ -169,6 +221,9 @@ pub struct ExcelParserBuilder {
     auto_detect_headers: bool,
     max_rows: Option<usize>,
     validation_config: ValidationConfig,
+    locale: Option<ExcelLocale>,
+    sheet_filter: SheetFilter,
+    propagate_merged_headers: bool,
 }
 
 impl ExcelParserBuilder {
@@ -179,6 +234,9 @@ impl ExcelParserBuilder {
             auto_detect_headers: true,
             max_rows: None,
             validation_config: ValidationConfig::default(),
+            locale: None,
+            sheet_filter: SheetFilter::default(),
+            propagate_merged_headers: false,
         }
     }
 
@@ -206,6 +264,34 @@ impl ExcelParserBuilder {
         self
     }
 
+    /// Detect numbers and dates in text cells using the given locale
+    pub fn locale(mut self, locale: ExcelLocale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Only parse worksheets whose names match the pattern, rejecting an invalid regex
+    ///
+    /// May be called repeatedly; a sheet matching any include pattern is parsed.
+    pub fn include_sheets(mut self, pattern: SheetNamePattern) -> fedramp_core::Result<Self> {
+        pattern.validate()?;
+        self.sheet_filter.include.push(pattern);
+        Ok(self)
+    }
+
+    /// Skip worksheets whose names match the pattern, rejecting an invalid regex
+    pub fn exclude_sheets(mut self, pattern: SheetNamePattern) -> fedramp_core::Result<Self> {
+        pattern.validate()?;
+        self.sheet_filter.exclude.push(pattern);
+        Ok(self)
+    }
+
+    /// Spread merged header cells over the columns they span
+    pub fn propagate_merged_headers(mut self, propagate_merged_headers: bool) -> Self {
+        self.propagate_merged_headers = propagate_merged_headers;
+        self
+    }
+
     /// Build the ExcelParser instance
     pub fn build(self) -> ExcelParser {
         ExcelParser {
@@ -213,6 +299,9 @@ impl ExcelParserBuilder {
             auto_detect_headers: self.auto_detect_headers,
             max_rows: self.max_rows,
             validation_config: self.validation_config,
+            locale: self.locale,
+            sheet_filter: self.sheet_filter,
+            propagate_merged_headers: self.propagate_merged_headers,
         }
     }
 }
@@ -8,6 +8,7 @@
 use crate::excel::types::*;
 use crate::excel::validation::ExcelValidator;
 use calamine::{Xlsx, DataType, Reader};
+use chrono::{Duration, NaiveDate};
 use fedramp_core::{Result, Error};
 use serde_json::Value;
 use tracing::{debug, warn};
@@ -46,13 +47,31 @@ impl<'a> WorksheetParser<'a> {
         &self,
         workbook: &mut Xlsx<R>,
         sheet_name: &str,
+    ) -> Result<ExcelWorksheet> {
+        self.parse_worksheet_with_merged_cells(workbook, sheet_name, Vec::new()).await
+    }
+
+    /// Parse a single worksheet given its merged cell ranges
+    ///
+    /// The ranges come from [`detect_merged_cells`](super::merged_cells::detect_merged_cells);
+    /// their values are filled in from the parsed data, and they are used for
+    /// header detection when the parser propagates merged headers.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if worksheet cannot be read or parsed
+    pub async fn parse_worksheet_with_merged_cells<R: std::io::Read + std::io::Seek>(
+        &self,
+        workbook: &mut Xlsx<R>,
+        sheet_name: &str,
+        mut merged_cells: Vec<MergedCellRange>,
     ) -> Result<ExcelWorksheet> {
         debug!("Parsing worksheet: {}", sheet_name);
 
         // Get worksheet range
         let range = workbook
             .worksheet_range(sheet_name)
-            .ok_or_else(|| Error::document_parsing(format!("Worksheet '{}' not found", sheet_name)))?
+            .ok_or_else(|| Error::worksheet_not_found(sheet_name))?
             .map_err(|e| Error::document_parsing(format!("Failed to read worksheet '{}': {}", sheet_name, e)))?;
 
         if range.is_empty() {
@@ -78,16 +97,19 @@ impl<'a> WorksheetParser<'a> {
         let validator = ExcelValidator::new(self.parser.validation_config.clone());
         let mut data = Vec::with_capacity(effective_row_count);
         let mut validation_results = Vec::new();
+        let mut source_text = Vec::new();
 
         for row_idx in 0..effective_row_count {
             let mut row_data = Vec::with_capacity(column_count);
 
             for col_idx in 0..column_count {
                 let cell_value = range.get_value((row_idx as u32, col_idx as u32));
-                let json_value = self.convert_cell_to_json(cell_value);
+                let (json_value, ambiguity) = self.convert_cell(cell_value);
+                source_text.extend(converted_source_text(cell_value, &json_value, row_idx, col_idx));
 
                 // Validate the cell
-                let validation_result = validator.validate_cell(&json_value, row_idx, col_idx);
+                let mut validation_result = validator.validate_cell(&json_value, row_idx, col_idx);
+                validation_result.issues.extend(ambiguity);
                 let sanitized_value = validation_result.sanitized_value.clone().unwrap_or(json_value);
 
                 validation_results.push(validation_result);
@@ -100,15 +122,12 @@ impl<'a> WorksheetParser<'a> {
         // Generate validation summary
         let validation_summary = validator.generate_summary(&validation_results);
 
-        // Detect headers if enabled
-        let headers = if self.parser.auto_detect_headers && !data.is_empty() {
-            self.detect_headers(&data[0])
-        } else {
-            None
-        };
+        for range in &mut merged_cells {
+            range.value = data.get(range.start_row).and_then(|row| row.get(range.start_col)).cloned();
+        }
 
-        // TODO: Detect merged cells (requires additional calamine features)
-        let merged_cells = Vec::new();
+        // Detect headers if enabled
+        let (headers, header_row_count) = self.detect_header_rows(&data, &merged_cells);
 
         // TODO: Extract cell formatting (requires additional calamine features)
         let cell_formatting = None;
@@ -116,13 +135,17 @@ impl<'a> WorksheetParser<'a> {
         Ok(ExcelWorksheet {
             name: sheet_name.to_string(),
             row_count: effective_row_count,
+            total_row_count: row_count,
+            truncated: effective_row_count < row_count,
             column_count,
             data,
             headers,
+            header_row_count,
             merged_cells,
             cell_formatting,
             validation_results,
             validation_summary,
+            source_text,
         })
     }
 
@@ -131,9 +154,12 @@ impl<'a> WorksheetParser<'a> {
         ExcelWorksheet {
             name: sheet_name.to_string(),
             row_count: 0,
+            total_row_count: 0,
+            truncated: false,
             column_count: 0,
             data: Vec::new(),
             headers: None,
+            header_row_count: 0,
             merged_cells: Vec::new(),
             cell_formatting: None,
             validation_results: Vec::new(),
@@ -146,6 +172,18 @@ impl<'a> WorksheetParser<'a> {
                 issue_breakdown: std::collections::HashMap::new(),
                 max_severity: None,
             },
+            source_text: Vec::new(),
+        }
+    }
+
+    /// Convert a cell to JSON, detecting numbers and dates in text cells when a locale is set
+    ///
+    /// Returns an `AmbiguousDate` issue alongside the value when a date could
+    /// be read either day- or month-first and the locale order was assumed.
+    fn convert_cell(&self, cell: Option<&DataType>) -> (Value, Option<ValidationIssue>) {
+        match (cell, &self.parser.locale) {
+            (Some(DataType::String(s)), Some(locale)) => convert_string_with_type_detection(s, locale),
+            _ => (self.convert_cell_to_json(cell), None),
         }
     }
 
@@ -162,9 +200,15 @@ impl<'a> WorksheetParser<'a> {
             }
             Some(DataType::String(s)) => Value::String(s.clone()),
             Some(DataType::Bool(b)) => Value::Bool(*b),
-            Some(DataType::DateTime(dt)) => {
-                // Convert Excel datetime to ISO string
-                Value::String(dt.to_string())
+            Some(DataType::DateTime(serial)) => {
+                // calamine shifts 1904-system serials into the 1900 system
+                match excel_serial_to_iso(*serial, ExcelDateSystem::Excel1900) {
+                    Some(iso) => Value::String(iso),
+                    None => {
+                        warn!("Excel date serial {} is not a valid date", serial);
+                        serde_json::Number::from_f64(*serial).map(Value::Number).unwrap_or(Value::Null)
+                    }
+                }
             }
             Some(DataType::Error(e)) => {
                 warn!("Excel cell error: {:?}", e);
@@ -185,23 +229,22 @@ impl<'a> WorksheetParser<'a> {
 
     /// Attempt to detect headers from the first row
     fn detect_headers(&self, first_row: &[Value]) -> Option<Vec<String>> {
-        // Simple heuristic: if all values in first row are strings, treat as headers
-        let all_strings = first_row.iter().all(|v| matches!(v, Value::String(_)));
-
-        if all_strings && !first_row.is_empty() {
-            let headers: Vec<String> = first_row
-                .iter()
-                .enumerate()
-                .map(|(i, v)| {
-                    match v {
-                        Value::String(s) if !s.trim().is_empty() => s.trim().to_string(),
-                        _ => format!("Column_{}", i + 1),
-                    }
-                })
-                .collect();
-            Some(headers)
-        } else {
-            None
+        detect_header_row(first_row)
+    }
+
+    /// Detect headers and the number of rows they span, if enabled
+    fn detect_header_rows(&self, data: &[Vec<Value>], merged_cells: &[MergedCellRange]) -> (Option<Vec<String>>, usize) {
+        if !self.parser.auto_detect_headers || data.is_empty() {
+            return (None, 0);
+        }
+        if self.parser.propagate_merged_headers {
+            if let Some((headers, rows)) = detect_merged_header_rows(data, merged_cells) {
+                return (Some(headers), rows);
+            }
+        }
+        match self.detect_headers(&data[0]) {
+            Some(headers) => (Some(headers), 1),
+            None => (None, 0),
         }
     }
 
@@ -217,7 +260,7 @@ impl<'a> WorksheetParser<'a> {
         // Get worksheet range
         let range = workbook
             .worksheet_range(sheet_name)
-            .ok_or_else(|| Error::document_parsing(format!("Worksheet '{}' not found", sheet_name)))?
+            .ok_or_else(|| Error::worksheet_not_found(sheet_name))?
             .map_err(|e| Error::document_parsing(format!("Failed to read worksheet '{}': {}", sheet_name, e)))?;
 
         if range.is_empty() {
@@ -235,15 +278,18 @@ impl<'a> WorksheetParser<'a> {
         let validator = ExcelValidator::new(validation_config);
         let mut data = Vec::with_capacity(effective_row_count);
         let mut validation_results = Vec::new();
+        let mut source_text = Vec::new();
 
         for row_idx in 0..effective_row_count {
             let mut row_data = Vec::with_capacity(dimensions.1);
 
             for col_idx in 0..dimensions.1 {
                 let cell_value = range.get_value((row_idx as u32, col_idx as u32));
-                let json_value = self.convert_cell_to_json(cell_value);
+                let (json_value, ambiguity) = self.convert_cell(cell_value);
+                source_text.extend(converted_source_text(cell_value, &json_value, row_idx, col_idx));
 
-                let validation_result = validator.validate_cell(&json_value, row_idx, col_idx);
+                let mut validation_result = validator.validate_cell(&json_value, row_idx, col_idx);
+                validation_result.issues.extend(ambiguity);
                 let sanitized_value = validation_result.sanitized_value.clone().unwrap_or(json_value);
 
                 validation_results.push(validation_result);
@@ -254,22 +300,22 @@ impl<'a> WorksheetParser<'a> {
         }
 
         let validation_summary = validator.generate_summary(&validation_results);
-        let headers = if self.parser.auto_detect_headers && !data.is_empty() {
-            self.detect_headers(&data[0])
-        } else {
-            None
-        };
+        let (headers, header_row_count) = self.detect_header_rows(&data, &[]);
 
         Ok(ExcelWorksheet {
             name: sheet_name.to_string(),
             row_count: effective_row_count,
+            total_row_count: dimensions.0,
+            truncated: effective_row_count < dimensions.0,
             column_count: dimensions.1,
             data,
             headers,
+            header_row_count,
             merged_cells: Vec::new(),
             cell_formatting: None,
             validation_results,
             validation_summary,
+            source_text,
         })
     }
 
@@ -390,6 +436,279 @@ pub struct WorksheetStatistics {
     pub data_density: f64,
 }
 
+/// A date detected in a text cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedDate {
+    /// The calendar date
+    pub date: NaiveDate,
+    /// Whether day and month could each have been the other, so the locale order was assumed
+    pub ambiguous: bool,
+}
+
+/// Convert a text cell to a number or ISO 8601 date string using locale conventions
+///
+/// Text that is neither a date nor a number is returned unchanged. Ambiguous
+/// dates are resolved by the locale's date order and flagged with an
+/// informational `AmbiguousDate` issue.
+pub fn convert_string_with_type_detection(value: &str, locale: &ExcelLocale) -> (Value, Option<ValidationIssue>) {
+    if let Some(detected) = detect_and_parse_date(value, locale) {
+        let iso = detected.date.format("%Y-%m-%d").to_string();
+        let issue = detected.ambiguous.then(|| ValidationIssue {
+            issue_type: ValidationIssueType::AmbiguousDate,
+            severity: ValidationSeverity::Info,
+            message: format!("Date '{}' is ambiguous; read as {} using {:?} order", value.trim(), iso, locale.date_order),
+            suggestion: Some("Use an unambiguous format such as YYYY-MM-DD".to_string()),
+            auto_fixed: false,
+        });
+        return (Value::String(iso), issue);
+    }
+
+    match detect_and_parse_number(value, locale) {
+        Some(number) => (number, None),
+        None => (Value::String(value.to_string()), None),
+    }
+}
+
+/// Original text of a text cell that type detection converted to another value
+fn converted_source_text(cell: Option<&DataType>, converted: &Value, row: usize, column: usize) -> Option<CellSourceText> {
+    match (cell, converted) {
+        (Some(DataType::String(text)), Value::String(value)) if value == text => None,
+        (Some(DataType::String(text)), _) => Some(CellSourceText { row, column, text: text.clone() }),
+        _ => None,
+    }
+}
+
+/// Parse a number written with the locale's decimal and thousands separators
+///
+/// Thousands separators must group exactly three digits. Integers with a
+/// leading zero, such as ZIP codes or identifiers, are not treated as numbers.
+pub fn detect_and_parse_number(value: &str, locale: &ExcelLocale) -> Option<Value> {
+    let trimmed = value.trim();
+    let unsigned = trimmed.strip_prefix(['-', '+']).unwrap_or(trimmed);
+
+    let (integer_part, fraction_part) = match unsigned.split_once(locale.decimal_separator) {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+
+    let mut groups = integer_part.split(locale.thousands_separator);
+    let leading = groups.next()?;
+    let grouped: Vec<&str> = groups.collect();
+    let all_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+
+    if !all_digits(leading) || grouped.iter().any(|group| group.len() != 3 || !all_digits(group)) {
+        return None;
+    }
+    if (leading.len() > 1 && leading.starts_with('0')) || (!grouped.is_empty() && leading.len() > 3) {
+        return None;
+    }
+    if fraction_part.is_some_and(|fraction| !all_digits(fraction)) {
+        return None;
+    }
+
+    let negative = trimmed.starts_with('-');
+    let mut normalized = String::with_capacity(trimmed.len());
+    if negative {
+        normalized.push('-');
+    }
+    normalized.push_str(leading);
+    grouped.iter().for_each(|group| normalized.push_str(group));
+
+    match fraction_part {
+        Some(fraction) => {
+            normalized.push('.');
+            normalized.push_str(fraction);
+            normalized.parse::<f64>().ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+        }
+        None => normalized.parse::<i64>().ok().map(|i| Value::Number(i.into())),
+    }
+}
+
+/// Parse a numeric date such as `25.12.2023`, `12/25/2023` or `2023-12-25`
+///
+/// Year-first dates are always read as year, month, day. Otherwise a part
+/// above 12 can only be the day; when both parts could be a month, the
+/// locale's date order decides and the date is marked ambiguous.
+pub fn detect_and_parse_date(value: &str, locale: &ExcelLocale) -> Option<DetectedDate> {
+    let trimmed = value.trim();
+    let separator = trimmed.chars().find(|c| matches!(c, '/' | '.' | '-'))?;
+
+    let parts: Vec<&str> = trimmed.split(separator).collect();
+    if parts.len() != 3 || parts.iter().any(|part| part.is_empty() || part.len() > 4 || !part.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+
+    if parts[0].len() == 4 {
+        let date = NaiveDate::from_ymd_opt(parts[0].parse().ok()?, parts[1].parse().ok()?, parts[2].parse().ok()?)?;
+        return Some(DetectedDate { date, ambiguous: false });
+    }
+    if parts[2].len() != 4 || parts[0].len() > 2 || parts[1].len() > 2 {
+        return None;
+    }
+
+    let year: i32 = parts[2].parse().ok()?;
+    let first: u32 = parts[0].parse().ok()?;
+    let second: u32 = parts[1].parse().ok()?;
+
+    let (month, day, ambiguous) = match (first > 12, second > 12) {
+        (true, true) => return None,
+        (true, false) => (second, first, false),
+        (false, true) => (first, second, false),
+        (false, false) => {
+            let ambiguous = first != second;
+            match locale.date_order {
+                DateOrder::MonthDayYear => (first, second, ambiguous),
+                DateOrder::DayMonthYear => (second, first, ambiguous),
+            }
+        }
+    };
+
+    let date = NaiveDate::from_ymd_opt(year, month, day)?;
+    Some(DetectedDate { date, ambiguous })
+}
+
+/// Date system of a workbook's serial dates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExcelDateSystem {
+    /// Serial 1 is 1900-01-01; serial 60 is the nonexistent 1900-02-29 Lotus kept
+    #[default]
+    Excel1900,
+    /// Serial 0 is 1904-01-01, as in older Mac workbooks
+    Excel1904,
+}
+
+/// Convert an Excel serial date to an ISO 8601 string
+///
+/// Whole serials become `YYYY-MM-DD`; a fractional part is the time of day and
+/// gives `YYYY-MM-DDTHH:MM:SS`, rounded to the second. In the 1900 system a
+/// serial below 1 is a time without a date and gives `HH:MM:SS`, and serials
+/// after 60 are shifted back a day to skip the phantom 1900-02-29. Returns
+/// `None` for serial 60, negative serials and serials outside chrono's range.
+pub fn excel_serial_to_iso(serial: f64, system: ExcelDateSystem) -> Option<String> {
+    const SECONDS_PER_DAY: i64 = 86_400;
+
+    if !serial.is_finite() || serial < 0.0 {
+        return None;
+    }
+    let total_seconds = (serial * SECONDS_PER_DAY as f64).round();
+    if total_seconds > i64::MAX as f64 {
+        return None;
+    }
+    let total_seconds = total_seconds as i64;
+    let days = total_seconds / SECONDS_PER_DAY;
+    let seconds = total_seconds % SECONDS_PER_DAY;
+    let time = chrono::NaiveTime::from_num_seconds_from_midnight_opt(seconds as u32, 0)?;
+
+    let date = match system {
+        ExcelDateSystem::Excel1900 => {
+            let days = match days {
+                0 => return Some(time.format("%H:%M:%S").to_string()),
+                60 => return None,
+                1..=59 => days,
+                _ => days - 1,
+            };
+            NaiveDate::from_ymd_opt(1899, 12, 31)?.checked_add_signed(Duration::try_days(days)?)?
+        }
+        ExcelDateSystem::Excel1904 => {
+            NaiveDate::from_ymd_opt(1904, 1, 1)?.checked_add_signed(Duration::try_days(days)?)?
+        }
+    };
+
+    Some(if seconds == 0 {
+        date.format("%Y-%m-%d").to_string()
+    } else {
+        date.and_time(time).format("%Y-%m-%dT%H:%M:%S").to_string()
+    })
+}
+
+/// Detect a header row from the first row of tabular data
+///
+/// Shared by the Excel and CSV parsers so both apply the same heuristic.
+pub(crate) fn detect_header_row(first_row: &[Value]) -> Option<Vec<String>> {
+    // Simple heuristic: if all values in first row are strings, treat as headers
+    let all_strings = first_row.iter().all(|v| matches!(v, Value::String(_)));
+
+    if all_strings && !first_row.is_empty() {
+        let headers: Vec<String> = first_row
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                match v {
+                    Value::String(s) if !s.trim().is_empty() => s.trim().to_string(),
+                    _ => format!("Column_{}", i + 1),
+                }
+            })
+            .collect();
+        Some(headers)
+    } else {
+        None
+    }
+}
+
+/// Detect a header whose cells may be merged over several columns or rows
+///
+/// Every merged range starting in the first row gives its value to all the
+/// header cells it covers. A range spanning several columns of the first row
+/// is taken as a group over sub-headers in the second row, so the header is
+/// two rows deep, or deeper when a range in the first row spans more rows.
+/// Each column's header joins the distinct texts of its header cells, e.g.
+/// "Point of Contact" over "Name" gives "Point of Contact Name".
+///
+/// Returns the headers and the number of header rows, or `None` when no
+/// merged range starts in the first row or a header cell is not text.
+pub(crate) fn detect_merged_header_rows(data: &[Vec<Value>], merged_cells: &[MergedCellRange]) -> Option<(Vec<String>, usize)> {
+    let top_ranges: Vec<&MergedCellRange> = merged_cells.iter().filter(|range| range.start_row == 0).collect();
+    if top_ranges.is_empty() || data.is_empty() {
+        return None;
+    }
+
+    let grouped = top_ranges.iter().any(|range| range.end_col > range.start_col);
+    let depth = top_ranges.iter()
+        .map(|range| range.end_row + 1)
+        .chain(grouped.then_some(2))
+        .max()?
+        .min(data.len());
+    let width = data[..depth].iter().map(Vec::len).max()?;
+
+    let mut header: Vec<Vec<Value>> = data[..depth].iter()
+        .map(|row| (0..width).map(|col| row.get(col).cloned().unwrap_or(Value::Null)).collect())
+        .collect();
+    for range in merged_cells.iter().filter(|range| range.start_row < depth) {
+        let value = data[range.start_row].get(range.start_col).cloned().unwrap_or(Value::Null);
+        for row in header.iter_mut().take(range.end_row + 1).skip(range.start_row) {
+            for cell in row.iter_mut().take(range.end_col + 1).skip(range.start_col) {
+                *cell = value.clone();
+            }
+        }
+    }
+
+    if header.iter().flatten().any(|cell| !matches!(cell, Value::String(_) | Value::Null)) {
+        return None;
+    }
+
+    let headers = (0..width)
+        .map(|col| {
+            let mut parts: Vec<&str> = Vec::new();
+            for row in &header {
+                if let Value::String(text) = &row[col] {
+                    let text = text.trim();
+                    if !text.is_empty() && parts.last() != Some(&text) {
+                        parts.push(text);
+                    }
+                }
+            }
+            if parts.is_empty() {
+                format!("Column_{}", col + 1)
+            } else {
+                parts.join(" ")
+            }
+        })
+        .collect();
+    Some((headers, depth))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -441,4 +760,129 @@ mod tests {
         assert!(empty_worksheet.data.is_empty());
         assert!(empty_worksheet.headers.is_none());
     }
+
+    #[tokio::test]
+    async fn test_missing_worksheet_is_typed_error() {
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        workbook.add_worksheet().set_name("Inventory").unwrap();
+        let data = workbook.save_to_buffer().unwrap();
+        let mut workbook = Xlsx::new(std::io::Cursor::new(data)).unwrap();
+
+        let parser = ExcelParser::new();
+        let result = WorksheetParser::new(&parser).parse_worksheet(&mut workbook, "POA&M Items").await;
+
+        assert!(matches!(result, Err(Error::WorksheetNotFound { name }) if name == "POA&M Items"));
+    }
+
+    #[test]
+    fn test_german_locale_numbers_and_dates() {
+        let german = ExcelLocale::german();
+
+        assert_eq!(detect_and_parse_number("1.234,56", &german), Some(serde_json::json!(1234.56)));
+        assert_eq!(detect_and_parse_number("-1.234.567", &german), Some(serde_json::json!(-1234567)));
+        assert_eq!(detect_and_parse_number("1,234.56", &german), None);
+        assert_eq!(detect_and_parse_number("02134", &german), None);
+
+        let (value, issue) = convert_string_with_type_detection("25.12.2023", &german);
+        assert_eq!(value, Value::String("2023-12-25".to_string()));
+        assert!(issue.is_none());
+    }
+
+    #[test]
+    fn test_uk_locale_resolves_ambiguous_dates_day_first_and_flags_them() {
+        let uk = ExcelLocale::united_kingdom();
+
+        assert_eq!(detect_and_parse_number("1,234.56", &uk), Some(serde_json::json!(1234.56)));
+
+        let (value, issue) = convert_string_with_type_detection("25/12/2023", &uk);
+        assert_eq!(value, Value::String("2023-12-25".to_string()));
+        assert!(issue.is_none());
+
+        let (value, issue) = convert_string_with_type_detection("03/04/2024", &uk);
+        assert_eq!(value, Value::String("2024-04-03".to_string()));
+        assert_eq!(issue.unwrap().issue_type, ValidationIssueType::AmbiguousDate);
+
+        // The same text reads month-first under the US locale
+        let detected = detect_and_parse_date("03/04/2024", &ExcelLocale::us()).unwrap();
+        assert_eq!(detected.date, NaiveDate::from_ymd_opt(2024, 3, 4).unwrap());
+        assert!(detected.ambiguous);
+        assert!(!detect_and_parse_date("04/04/2024", &uk).unwrap().ambiguous);
+        assert!(detect_and_parse_date("31/02/2024", &uk).is_none());
+    }
+
+    #[test]
+    fn test_excel_serial_dates_around_phantom_leap_day() {
+        let iso = |serial| excel_serial_to_iso(serial, ExcelDateSystem::Excel1900);
+
+        assert_eq!(iso(1.0).as_deref(), Some("1900-01-01"));
+        assert_eq!(iso(59.0).as_deref(), Some("1900-02-28"));
+        assert_eq!(iso(60.0), None);
+        assert_eq!(iso(61.0).as_deref(), Some("1900-03-01"));
+        assert_eq!(iso(45000.0).as_deref(), Some("2023-03-15"));
+        assert_eq!(iso(-1.0), None);
+    }
+
+    #[test]
+    fn test_fractional_excel_serial_is_time_of_day() {
+        assert_eq!(excel_serial_to_iso(45000.75, ExcelDateSystem::Excel1900).as_deref(), Some("2023-03-15T18:00:00"));
+        assert_eq!(excel_serial_to_iso(0.5, ExcelDateSystem::Excel1900).as_deref(), Some("12:00:00"));
+        // 14:30:15 is not exact in binary and rounds to the nearest second
+        let afternoon = (14.0 * 3600.0 + 30.0 * 60.0 + 15.0) / 86400.0;
+        assert_eq!(excel_serial_to_iso(45000.0 + afternoon, ExcelDateSystem::Excel1900).as_deref(), Some("2023-03-15T14:30:15"));
+    }
+
+    #[test]
+    fn test_excel_1904_serial_dates() {
+        // The same day is 1462 serials apart in the two systems
+        assert_eq!(excel_serial_to_iso(0.0, ExcelDateSystem::Excel1904).as_deref(), Some("1904-01-01"));
+        assert_eq!(excel_serial_to_iso(45000.0 - 1462.0, ExcelDateSystem::Excel1904).as_deref(), Some("2023-03-15"));
+        assert_eq!(excel_serial_to_iso(59.0, ExcelDateSystem::Excel1904).as_deref(), Some("1904-02-29"));
+    }
+
+    #[tokio::test]
+    async fn test_date_cells_are_read_as_iso_8601() {
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let worksheet = workbook.add_worksheet().set_name("POA&M Items").unwrap();
+        let date_format = rust_xlsxwriter::Format::new().set_num_format("yyyy-mm-dd");
+        let datetime_format = rust_xlsxwriter::Format::new().set_num_format("yyyy-mm-dd hh:mm");
+        worksheet.write_number_with_format(0, 0, 45000.0, &date_format).unwrap();
+        worksheet.write_number_with_format(0, 1, 45000.75, &datetime_format).unwrap();
+        let data = workbook.save_to_buffer().unwrap();
+
+        let parser = ExcelParser::builder().auto_detect_headers(false).build();
+        let mut xlsx = Xlsx::new(std::io::Cursor::new(data)).unwrap();
+        let sheet = WorksheetParser::new(&parser).parse_worksheet(&mut xlsx, "POA&M Items").await.unwrap();
+
+        assert_eq!(sheet.data[0], vec![
+            Value::String("2023-03-15".to_string()),
+            Value::String("2023-03-15T18:00:00".to_string()),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_text_cells_are_typed_only_with_a_locale() {
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let worksheet = workbook.add_worksheet().set_name("Inventory").unwrap();
+        worksheet.write_string(0, 0, "1.234,56").unwrap();
+        worksheet.write_string(0, 1, "01.02.2024").unwrap();
+        worksheet.write_string(0, 2, "AC-2").unwrap();
+        let data = workbook.save_to_buffer().unwrap();
+
+        let default_parser = ExcelParser::builder().auto_detect_headers(false).build();
+        let mut xlsx = Xlsx::new(std::io::Cursor::new(data.clone())).unwrap();
+        let sheet = WorksheetParser::new(&default_parser).parse_worksheet(&mut xlsx, "Inventory").await.unwrap();
+        assert_eq!(sheet.data[0][0], Value::String("1.234,56".to_string()));
+
+        let german_parser = ExcelParser::builder().auto_detect_headers(false).locale(ExcelLocale::german()).build();
+        let mut xlsx = Xlsx::new(std::io::Cursor::new(data)).unwrap();
+        let sheet = WorksheetParser::new(&german_parser).parse_worksheet(&mut xlsx, "Inventory").await.unwrap();
+        assert_eq!(sheet.data[0], vec![
+            serde_json::json!(1234.56),
+            Value::String("2024-02-01".to_string()),
+            Value::String("AC-2".to_string()),
+        ]);
+        assert_eq!(sheet.validation_summary.issue_breakdown.get("AmbiguousDate"), Some(&1));
+        let source_text: Vec<(usize, &str)> = sheet.source_text.iter().map(|cell| (cell.column, cell.text.as_str())).collect();
+        assert_eq!(source_text, vec![(0, "1.234,56"), (1, "01.02.2024")]);
+    }
 }
@@ -0,0 +1,334 @@
+// Modified: 2025-09-24
+
+//! Asset type classification for inventory worksheets
+//!
+//! Worksheets are first classified by keywords in their name. When no
+//! keyword or keywords for several asset types match, as for "Compute Nodes"
+//! or "Container Registry", the classifier scores every asset type against
+//! the sheet name, the column headers and a sample of cell values and picks
+//! the best one, reporting how much of the evidence supports it.
+
+use super::types::*;
+use crate::mapping::normalize_field_name;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::{debug, warn};
+
+/// Confidence of a classification made from sheet name keywords alone
+pub const KEYWORD_CONFIDENCE: f64 = 0.9;
+
+/// Name keywords of the fast first pass, matched as substrings
+const NAME_KEYWORDS: &[(AssetType, &[&str])] = &[
+    (AssetType::Hardware, &["hardware", "server", "device"]),
+    (AssetType::Software, &["software", "application", "app", "registry"]),
+    (AssetType::Network, &["network", "router", "switch"]),
+    (AssetType::Virtual, &["virtual", "vm", "container"]),
+    (AssetType::Data, &["data", "database", "storage"]),
+    (AssetType::Cloud, &["cloud", "service"]),
+];
+
+/// Weighted terms of the scored pass, matched as whole words
+///
+/// Generic terms such as `device` weigh less than terms that only occur for
+/// one asset type, so "Network Devices" scores as network.
+const SCORED_TERMS: &[(AssetType, &[(&str, f64)])] = &[
+    (AssetType::Hardware, &[
+        ("hardware", 1.0), ("server", 1.0), ("compute", 1.0), ("node", 0.5), ("host", 0.5), ("device", 0.5),
+        ("serial number", 1.0), ("manufacturer", 1.0), ("model", 0.5), ("rack", 1.0), ("firmware", 1.0),
+        ("bios", 1.0), ("cpu", 1.0), ("chassis", 1.0), ("asset tag", 0.5),
+    ]),
+    (AssetType::Software, &[
+        ("software", 1.0), ("application", 1.0), ("registry", 1.0), ("package", 1.0), ("license", 1.0),
+        ("version", 0.5), ("vendor", 0.5), ("patch level", 1.0), ("release", 0.5),
+    ]),
+    (AssetType::Network, &[
+        ("network", 1.0), ("router", 1.0), ("switch", 1.0), ("firewall", 1.0), ("vlan", 1.0), ("subnet", 1.0),
+        ("mac address", 1.0), ("gateway", 1.0), ("load balancer", 1.0), ("port", 0.5),
+    ]),
+    (AssetType::Virtual, &[
+        ("virtual", 1.0), ("vm", 1.0), ("container", 1.0), ("hypervisor", 1.0), ("image", 0.5),
+        ("kubernetes", 1.0), ("pod", 1.0), ("docker", 1.0),
+    ]),
+    (AssetType::Data, &[
+        ("data", 0.5), ("database", 1.0), ("storage", 1.0), ("bucket", 1.0), ("schema", 1.0),
+        ("retention", 1.0), ("data classification", 1.0),
+    ]),
+    (AssetType::Cloud, &[
+        ("cloud", 1.0), ("service", 0.5), ("region", 1.0), ("availability zone", 1.0), ("subscription", 1.0),
+        ("tenant", 1.0), ("aws", 1.0), ("azure", 1.0), ("gcp", 1.0), ("instance", 0.5),
+    ]),
+];
+
+/// Configuration for asset type classification
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassifierConfig {
+    /// Weight of a term in the sheet name
+    pub name_weight: f64,
+    /// Weight of a term in a column header
+    pub header_weight: f64,
+    /// Weight of a term in a sampled cell value
+    pub value_weight: f64,
+    /// Number of rows whose values are sampled
+    pub sample_rows: usize,
+    /// Classifications below this confidence are flagged
+    pub low_confidence_threshold: f64,
+}
+
+impl Default for ClassifierConfig {
+    fn default() -> Self {
+        Self {
+            name_weight: 1.5,
+            header_weight: 1.0,
+            value_weight: 0.25,
+            sample_rows: 20,
+            low_confidence_threshold: 0.6,
+        }
+    }
+}
+
+/// How an asset type was chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClassificationMethod {
+    /// Sheet name keywords named a single asset type
+    Keyword,
+    /// Scored against the sheet name, headers and sampled values
+    Scored,
+    /// Nothing matched; the template type's default was used
+    TemplateDefault,
+}
+
+/// Asset type chosen for a worksheet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetTypeClassification {
+    /// Best asset type
+    pub asset_type: AssetType,
+    /// Share of the evidence supporting the asset type (0.0 - 1.0)
+    pub confidence: f64,
+    /// How the asset type was chosen
+    pub method: ClassificationMethod,
+    /// Score of each asset type in the scored pass
+    pub scores: HashMap<AssetType, f64>,
+    /// Whether the confidence is below the configured threshold
+    pub low_confidence: bool,
+}
+
+/// Classifier choosing the asset type of an inventory worksheet
+#[derive(Debug, Clone, Default)]
+pub struct AssetTypeClassifier {
+    /// Scoring configuration
+    config: ClassifierConfig,
+}
+
+impl AssetTypeClassifier {
+    /// Create a classifier with the default configuration
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a classifier with custom configuration
+    pub fn with_config(config: ClassifierConfig) -> Self {
+        Self { config }
+    }
+
+    /// Classify a worksheet from its name and rows
+    ///
+    /// Headers are the keys of the rows; values are sampled from the first
+    /// `sample_rows` rows. Low-confidence classifications are logged.
+    pub fn classify(
+        &self,
+        worksheet_name: &str,
+        rows: &[HashMap<String, String>],
+        template_info: &InventoryTemplateInfo,
+    ) -> AssetTypeClassification {
+        let keyword_types = keyword_matches(worksheet_name);
+        let classification = if let [asset_type] = keyword_types.as_slice() {
+            AssetTypeClassification {
+                asset_type: asset_type.clone(),
+                confidence: KEYWORD_CONFIDENCE,
+                method: ClassificationMethod::Keyword,
+                scores: HashMap::new(),
+                low_confidence: KEYWORD_CONFIDENCE < self.config.low_confidence_threshold,
+            }
+        } else {
+            self.classify_scored(worksheet_name, rows, template_info)
+        };
+
+        if classification.low_confidence {
+            warn!(
+                "Low-confidence asset type {:?} ({:.2}) for worksheet '{}'",
+                classification.asset_type, classification.confidence, worksheet_name
+            );
+        } else {
+            debug!(
+                "Classified worksheet '{}' as {:?} ({:?}, {:.2})",
+                worksheet_name, classification.asset_type, classification.method, classification.confidence
+            );
+        }
+        classification
+    }
+
+    /// Score every asset type and pick the highest
+    fn classify_scored(
+        &self,
+        worksheet_name: &str,
+        rows: &[HashMap<String, String>],
+        template_info: &InventoryTemplateInfo,
+    ) -> AssetTypeClassification {
+        let mut scores: HashMap<AssetType, f64> = HashMap::new();
+        let mut add_evidence = |text: &str, weight: f64| {
+            let text = format!(" {} ", singular_words(&normalize_field_name(text)));
+            for (asset_type, terms) in SCORED_TERMS {
+                for (term, term_weight) in terms.iter() {
+                    if text.contains(&format!(" {} ", singular_words(term))) {
+                        *scores.entry(asset_type.clone()).or_insert(0.0) += weight * term_weight;
+                    }
+                }
+            }
+        };
+
+        add_evidence(worksheet_name, self.config.name_weight);
+
+        let mut headers: Vec<&String> = rows.iter().flat_map(|row| row.keys()).collect();
+        headers.sort();
+        headers.dedup();
+        for header in headers {
+            add_evidence(header, self.config.header_weight);
+        }
+
+        for row in rows.iter().take(self.config.sample_rows) {
+            for value in row.values() {
+                add_evidence(value, self.config.value_weight);
+            }
+        }
+
+        let total: f64 = scores.values().sum();
+        // Ties go to the asset type listed first
+        let best = SCORED_TERMS.iter()
+            .map(|(asset_type, _)| (asset_type, scores.get(asset_type).copied().unwrap_or(0.0)))
+            .fold(None, |best: Option<(&AssetType, f64)>, (asset_type, score)| match best {
+                Some((_, best_score)) if best_score >= score => best,
+                _ => Some((asset_type, score)),
+            });
+
+        match best {
+            Some((asset_type, score)) if score > 0.0 => {
+                let confidence = score / total;
+                AssetTypeClassification {
+                    asset_type: asset_type.clone(),
+                    confidence,
+                    method: ClassificationMethod::Scored,
+                    scores,
+                    low_confidence: confidence < self.config.low_confidence_threshold,
+                }
+            }
+            _ => AssetTypeClassification {
+                asset_type: template_default(&template_info.template_type),
+                confidence: 0.0,
+                method: ClassificationMethod::TemplateDefault,
+                scores,
+                low_confidence: true,
+            },
+        }
+    }
+
+    /// Get classifier configuration
+    pub fn get_config(&self) -> &ClassifierConfig {
+        &self.config
+    }
+}
+
+/// Asset types whose name keywords occur in the worksheet name
+fn keyword_matches(worksheet_name: &str) -> Vec<AssetType> {
+    let name_lower = worksheet_name.to_lowercase();
+    NAME_KEYWORDS.iter()
+        .filter(|(_, keywords)| keywords.iter().any(|keyword| name_lower.contains(keyword)))
+        .map(|(asset_type, _)| asset_type.clone())
+        .collect()
+}
+
+/// Drop a plural `s` from each word, so `Nodes` matches `node`
+///
+/// Applied to terms as well as text, so a term that itself ends in `s`
+/// still matches.
+fn singular_words(text: &str) -> String {
+    text.split(' ')
+        .map(|word| match word.strip_suffix('s') {
+            Some(stem) if stem.len() > 2 && !stem.ends_with('s') => stem,
+            _ => word,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Asset type assumed for worksheets of a template when nothing else matches
+fn template_default(template_type: &InventoryTemplateType) -> AssetType {
+    match template_type {
+        InventoryTemplateType::FedRampIntegrated => AssetType::Hardware,
+        InventoryTemplateType::NetworkInventory => AssetType::Network,
+        InventoryTemplateType::SoftwareInventory => AssetType::Software,
+        InventoryTemplateType::Custom => AssetType::Hardware,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template_info() -> InventoryTemplateInfo {
+        InventoryTemplateInfo {
+            template_type: InventoryTemplateType::FedRampIntegrated,
+            version: "1.0".to_string(),
+            asset_worksheets: Vec::new(),
+            relationship_worksheets: Vec::new(),
+            column_mappings: HashMap::new(),
+        }
+    }
+
+    fn row(cells: &[(&str, &str)]) -> HashMap<String, String> {
+        cells.iter().map(|(header, value)| (header.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn test_unambiguous_name_uses_keywords() {
+        let classification = AssetTypeClassifier::new().classify("Software Applications", &[], &template_info());
+
+        assert_eq!(classification.asset_type, AssetType::Software);
+        assert_eq!(classification.method, ClassificationMethod::Keyword);
+        assert!(!classification.low_confidence);
+    }
+
+    #[test]
+    fn test_ambiguous_name_with_hardware_columns_is_hardware() {
+        let rows = vec![
+            row(&[
+                ("Asset ID", "CR-01"), ("Asset Type", "Server"), ("Serial Number", "SN-4411"),
+                ("Manufacturer", "Dell"), ("Model", "R740"), ("Rack", "R12"), ("Firmware", "2.1"),
+            ]),
+            row(&[
+                ("Asset ID", "CR-02"), ("Asset Type", "Server"), ("Serial Number", "SN-4412"),
+                ("Manufacturer", "Dell"), ("Model", "R740"), ("Rack", "R12"), ("Firmware", "2.1"),
+            ]),
+        ];
+
+        let classification = AssetTypeClassifier::new().classify("Container Registry", &rows, &template_info());
+
+        assert_eq!(classification.asset_type, AssetType::Hardware);
+        assert_eq!(classification.method, ClassificationMethod::Scored);
+        assert!(classification.confidence > 0.6);
+        assert!(!classification.low_confidence);
+    }
+
+    #[test]
+    fn test_name_without_evidence_is_flagged() {
+        let classification = AssetTypeClassifier::new().classify("Container Registry", &[], &template_info());
+
+        assert_eq!(classification.method, ClassificationMethod::Scored);
+        assert!(classification.low_confidence);
+
+        let classification = AssetTypeClassifier::new().classify("Sheet1", &[], &template_info());
+
+        assert_eq!(classification.asset_type, AssetType::Hardware);
+        assert_eq!(classification.method, ClassificationMethod::TemplateDefault);
+        assert!(classification.low_confidence);
+    }
+}
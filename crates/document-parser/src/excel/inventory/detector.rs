@@ -7,6 +7,7 @@
 
 use super::types::*;
 use super::parser::MockWorkbook;
+use crate::mapping::engine::normalize_field_name;
 use crate::Result;
 use fedramp_core::Error;
 use serde::{Deserialize, Serialize};
@@ -388,11 +389,11 @@ impl InventoryTemplateDetector {
 
     /// Find matching header with fuzzy matching
     fn find_matching_header(&self, target: &str, headers: &[String]) -> Option<String> {
-        let target_lower = target.to_lowercase();
+        let target_lower = normalize_field_name(target);
 
         // Exact match
         for header in headers {
-            if header.to_lowercase() == target_lower {
+            if normalize_field_name(header) == target_lower {
                 return Some(header.clone());
             }
         }
@@ -400,7 +401,7 @@ impl InventoryTemplateDetector {
         // Fuzzy match
         if self.config.enable_fuzzy_matching {
             for header in headers {
-                let header_lower = header.to_lowercase();
+                let header_lower = normalize_field_name(header);
                 if header_lower.contains(&target_lower) || target_lower.contains(&header_lower) {
                     return Some(header.clone());
                 }
@@ -489,7 +490,7 @@ impl InventoryTemplateDetector {
 
     /// Map header to standard field name
     fn map_header_to_standard_field(&self, header: &str) -> String {
-        let header_lower = header.to_lowercase();
+        let header_lower = normalize_field_name(header);
         
         if header_lower.contains("asset") && header_lower.contains("id") {
             "asset_id".to_string()
@@ -513,7 +514,7 @@ impl InventoryTemplateDetector {
             "operating_system".to_string()
         } else {
             // Use header as-is for unknown fields
-            header.to_lowercase().replace(' ', "_")
+            header_lower.replace(' ', "_")
         }
     }
 
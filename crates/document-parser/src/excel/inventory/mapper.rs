@@ -10,7 +10,7 @@ use super::parser::MockWorkbook;
 use crate::Result;
 use fedramp_core::Error;
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
@@ -759,6 +759,111 @@ impl RelationshipMapper {
         ]
     }
 
+    /// Find cycles among `DependsOn` relationships
+    ///
+    /// Each cycle is reported once as the sorted IDs of the assets in it, so
+    /// `A -> B -> C -> A` and `B -> A` in the same inventory give one finding
+    /// covering all three. An asset depending on itself is a cycle of one.
+    pub fn find_dependency_cycles(&self, relationships: &[AssetRelationship]) -> Vec<Vec<String>> {
+        let mut graph: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for relationship in relationships.iter().filter(|r| r.relationship_type == RelationshipType::DependsOn) {
+            graph.entry(&relationship.source_asset_id).or_default().insert(&relationship.target_asset_id);
+            graph.entry(&relationship.target_asset_id).or_default();
+        }
+
+        let nodes: Vec<&str> = graph.keys().copied().collect();
+        let node_index: HashMap<&str, usize> = nodes.iter().enumerate().map(|(i, id)| (*id, i)).collect();
+        let edges: Vec<Vec<usize>> = graph.values()
+            .map(|targets| targets.iter().map(|target| node_index[target]).collect())
+            .collect();
+
+        // Tarjan's strongly connected components, iterative so long
+        // dependency chains cannot exhaust the stack
+        let mut index: Vec<Option<usize>> = vec![None; nodes.len()];
+        let mut lowlink = vec![0; nodes.len()];
+        let mut on_stack = vec![false; nodes.len()];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut cycles = Vec::new();
+
+        for root in 0..nodes.len() {
+            if index[root].is_some() {
+                continue;
+            }
+
+            let mut work = vec![(root, 0)];
+            while let Some((node, next_edge)) = work.pop() {
+                if index[node].is_none() {
+                    index[node] = Some(next_index);
+                    lowlink[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+
+                if let Some(&target) = edges[node].get(next_edge) {
+                    work.push((node, next_edge + 1));
+                    match index[target] {
+                        None => work.push((target, 0)),
+                        Some(target_index) if on_stack[target] => lowlink[node] = lowlink[node].min(target_index),
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                if index[node] == Some(lowlink[node]) {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(nodes[member].to_string());
+                        if member == node {
+                            break;
+                        }
+                    }
+                    if component.len() > 1 || edges[node].contains(&node) {
+                        component.sort();
+                        cycles.push(component);
+                    }
+                }
+
+                if let Some(&(parent, _)) = work.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[node]);
+                }
+            }
+        }
+
+        cycles.sort();
+        cycles
+    }
+
+    /// Record dependency cycles as validation errors
+    ///
+    /// Downstream processing orders assets by their dependencies, which is
+    /// impossible when they form a cycle.
+    pub fn report_dependency_cycles(
+        &self,
+        relationships: &[AssetRelationship],
+        results: &mut InventoryValidationResults,
+    ) {
+        let cycles = self.find_dependency_cycles(relationships);
+        if cycles.is_empty() {
+            return;
+        }
+
+        warn!("Found {} dependency cycle(s) among inventory assets", cycles.len());
+        for cycle in &cycles {
+            results.errors.push(ValidationError {
+                code: "DEPENDENCY_CYCLE".to_string(),
+                message: format!("Dependency cycle between assets: {}", cycle.join(", ")),
+                asset_id: cycle.first().cloned(),
+                field: Some("relationships".to_string()),
+                row: None,
+            });
+        }
+        results.summary.total_errors += cycles.len();
+        results.is_valid = false;
+    }
+
     /// Get mapper configuration
     pub fn get_config(&self) -> &MapperConfig {
         &self.config
@@ -805,4 +910,35 @@ mod tests {
         assert_eq!(mapper.parse_relationship_strength("critical"), RelationshipStrength::Critical);
         assert_eq!(mapper.parse_relationship_strength("unknown"), RelationshipStrength::Medium);
     }
+
+    fn depends_on(source: &str, target: &str) -> AssetRelationship {
+        AssetRelationship {
+            id: format!("{}-{}", source, target),
+            source_asset_id: source.to_string(),
+            target_asset_id: target.to_string(),
+            relationship_type: RelationshipType::DependsOn,
+            description: None,
+            strength: RelationshipStrength::Strong,
+            attributes: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_two_node_dependency_cycle_is_reported_once() {
+        let mapper = RelationshipMapper::new();
+        let relationships = vec![
+            depends_on("APP-1", "DB-1"),
+            depends_on("DB-1", "APP-1"),
+            depends_on("APP-1", "HOST-1"),
+        ];
+        let mut results = InventoryValidationResults::default();
+
+        mapper.report_dependency_cycles(&relationships, &mut results);
+
+        assert!(!results.is_valid);
+        assert_eq!(results.errors.len(), 1);
+        assert_eq!(results.errors[0].code, "DEPENDENCY_CYCLE");
+        assert!(results.errors[0].message.contains("APP-1, DB-1"));
+        assert!(!results.errors[0].message.contains("HOST-1"));
+    }
 }
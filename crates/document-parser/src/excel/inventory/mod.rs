@@ -15,6 +15,7 @@ pub mod column_mapper;
 pub mod transformers;
 pub mod asset_validator;
 pub mod network_processor;
+pub mod classifier;
 
 pub use types::*;
 pub use parser::*;
@@ -26,6 +27,7 @@ pub use column_mapper::*;
 pub use transformers::*;
 pub use asset_validator::*;
 pub use network_processor::*;
+pub use classifier::*;
 
 use parser::{InventoryParserCore, MockWorkbook};
 use crate::Result;
@@ -90,6 +92,11 @@ impl InventoryParser {
         self.core.parse_from_file(file_path).await
     }
 
+    /// Determine asset type from worksheet name alone
+    pub fn determine_asset_type(&self, worksheet_name: &str, template_info: &InventoryTemplateInfo) -> AssetType {
+        self.core.determine_asset_type(worksheet_name, template_info)
+    }
+
 
 
     /// Get parser configuration
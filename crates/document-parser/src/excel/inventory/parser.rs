@@ -6,7 +6,7 @@
 //! the various components of inventory processing.
 
 use super::types::*;
-use super::{InventoryTemplateDetector, AssetProcessor, RelationshipMapper, InventoryValidator, InventoryParserConfig};
+use super::{InventoryTemplateDetector, AssetProcessor, AssetTypeClassifier, RelationshipMapper, InventoryValidator, InventoryParserConfig};
 use crate::Result;
 use fedramp_core::Error;
 use serde::{Deserialize, Serialize};
@@ -22,6 +22,8 @@ pub struct InventoryParserCore {
     template_detector: InventoryTemplateDetector,
     /// Asset processor
     asset_processor: AssetProcessor,
+    /// Asset type classifier
+    asset_type_classifier: AssetTypeClassifier,
     /// Relationship mapper
     relationship_mapper: RelationshipMapper,
     /// Validator
@@ -45,6 +47,7 @@ impl InventoryParserCore {
             config,
             template_detector,
             asset_processor,
+            asset_type_classifier: AssetTypeClassifier::new(),
             relationship_mapper,
             validator,
         }
@@ -75,11 +78,12 @@ impl InventoryParserCore {
         };
 
         // Validate inventory data
-        let validation_results = if self.config.strict_validation {
+        let mut validation_results = if self.config.strict_validation {
             self.validator.validate_inventory(&assets, &relationships).await?
         } else {
             InventoryValidationResults::default()
         };
+        self.relationship_mapper.report_dependency_cycles(&relationships, &mut validation_results);
 
         // Create inventory document
         let inventory = InventoryDocument {
@@ -138,7 +142,9 @@ impl InventoryParserCore {
 
         // Get worksheet data
         let worksheet_data = workbook.get_worksheet_data(worksheet_name)?;
-        let asset_type = self.determine_asset_type(worksheet_name, template_info);
+        let asset_type = self.asset_type_classifier
+            .classify(worksheet_name, worksheet_data, template_info)
+            .asset_type;
         
         let mut assets = Vec::new();
 
@@ -164,31 +170,9 @@ impl InventoryParserCore {
         Ok(assets)
     }
 
-    /// Determine asset type from worksheet name
-    fn determine_asset_type(&self, worksheet_name: &str, template_info: &InventoryTemplateInfo) -> AssetType {
-        let name_lower = worksheet_name.to_lowercase();
-        
-        if name_lower.contains("hardware") || name_lower.contains("server") || name_lower.contains("device") {
-            AssetType::Hardware
-        } else if name_lower.contains("software") || name_lower.contains("application") || name_lower.contains("app") {
-            AssetType::Software
-        } else if name_lower.contains("network") || name_lower.contains("router") || name_lower.contains("switch") {
-            AssetType::Network
-        } else if name_lower.contains("virtual") || name_lower.contains("vm") || name_lower.contains("container") {
-            AssetType::Virtual
-        } else if name_lower.contains("data") || name_lower.contains("database") || name_lower.contains("storage") {
-            AssetType::Data
-        } else if name_lower.contains("cloud") || name_lower.contains("service") {
-            AssetType::Cloud
-        } else {
-            // Default based on template type
-            match template_info.template_type {
-                InventoryTemplateType::FedRampIntegrated => AssetType::Hardware,
-                InventoryTemplateType::NetworkInventory => AssetType::Network,
-                InventoryTemplateType::SoftwareInventory => AssetType::Software,
-                InventoryTemplateType::Custom => AssetType::Hardware,
-            }
-        }
+    /// Determine asset type from worksheet name alone
+    pub fn determine_asset_type(&self, worksheet_name: &str, template_info: &InventoryTemplateInfo) -> AssetType {
+        self.asset_type_classifier.classify(worksheet_name, &[], template_info).asset_type
     }
 
     /// Create a mock workbook for testing/placeholder purposes
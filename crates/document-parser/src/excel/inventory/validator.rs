@@ -6,7 +6,9 @@
 //! relationships, and data integrity checks.
 
 use super::types::*;
+use crate::quality::QualityConfig;
 use crate::Result;
+use fedramp_core::models::DocumentType;
 use fedramp_core::Error;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -26,6 +28,8 @@ pub struct InventoryValidator {
     relationship_rules: Vec<RelationshipValidationRule>,
     /// Field validators
     field_validators: HashMap<String, FieldValidator>,
+    /// Quality thresholds gating the inventory, with any inventory overrides applied
+    quality_config: Option<QualityConfig>,
 }
 
 /// Configuration for inventory validation
@@ -164,9 +168,19 @@ impl InventoryValidator {
             asset_rules,
             relationship_rules,
             field_validators,
+            quality_config: None,
         }
     }
 
+    /// Gate inventories on the quality thresholds of a configuration
+    ///
+    /// The configuration's inventory workbook overrides replace its global
+    /// thresholds.
+    pub fn with_quality_config(mut self, config: QualityConfig) -> Self {
+        self.quality_config = Some(config.for_document_type(DocumentType::IntegratedInventoryWorkbook));
+        self
+    }
+
     /// Validate complete inventory
     pub async fn validate_inventory(
         &self,
@@ -212,6 +226,10 @@ impl InventoryValidator {
             self.validate_business_rules(assets, relationships, &mut results).await?;
         }
 
+        if let Some(quality_config) = &self.quality_config {
+            Self::apply_completeness_gate(quality_config, &mut results);
+        }
+
         // Calculate summary
         results.summary = self.calculate_validation_summary(&results);
         results.is_valid = results.summary.total_errors == 0;
@@ -222,6 +240,37 @@ impl InventoryValidator {
         Ok(results)
     }
 
+    /// Share of validated assets with every required field present, 1.0 when there are none
+    pub fn completeness_score(results: &InventoryValidationResults) -> f64 {
+        if results.asset_results.is_empty() {
+            return 1.0;
+        }
+        let complete = results.asset_results.values()
+            .filter(|asset| {
+                let codes = asset.errors.iter().map(|e| &e.code).chain(asset.warnings.iter().map(|w| &w.code));
+                !codes.into_iter().any(|code| code == "MISSING_REQUIRED_FIELD")
+            })
+            .count();
+        complete as f64 / results.asset_results.len() as f64
+    }
+
+    /// Report an error when fewer assets are complete than the completeness threshold
+    fn apply_completeness_gate(quality_config: &QualityConfig, results: &mut InventoryValidationResults) {
+        let score = Self::completeness_score(results);
+        if score < quality_config.min_completeness_score {
+            results.errors.push(ValidationError {
+                code: "COMPLETENESS_BELOW_THRESHOLD".to_string(),
+                message: format!(
+                    "Inventory completeness {:.2} is below the minimum of {:.2}",
+                    score, quality_config.min_completeness_score
+                ),
+                asset_id: None,
+                field: None,
+                row: None,
+            });
+        }
+    }
+
     /// Validate asset ID uniqueness
     fn validate_asset_id_uniqueness(
         &self,
@@ -844,4 +893,43 @@ mod tests {
         assert_eq!(validator.get_asset_field_value(&asset, "asset_id"), "test_id");
         assert_eq!(validator.get_asset_field_value(&asset, "asset_name"), "test_name");
     }
+
+    #[tokio::test]
+    async fn test_inventory_completeness_uses_the_inventory_threshold() {
+        // Three of ten assets have no owner, so the inventory is 70% complete
+        let assets: Vec<Asset> = (0..10)
+            .map(|i| {
+                let mut asset = Asset::new(format!("asset-{}", i), format!("Server {}", i), AssetType::Hardware);
+                if i >= 3 {
+                    asset.owner = "ops".to_string();
+                }
+                asset
+            })
+            .collect();
+        let failing_code = |results: &InventoryValidationResults| {
+            results.errors.iter().any(|error| error.code == "COMPLETENESS_BELOW_THRESHOLD")
+        };
+
+        // The global completeness floor of 0.8 rejects it
+        let mut config = QualityConfig::default();
+        let results = InventoryValidator::new().with_quality_config(config.clone())
+            .validate_inventory(&assets, &[]).await.unwrap();
+        assert!(failing_code(&results));
+        assert!(!results.is_valid);
+
+        // A lower inventory floor accepts it, while POA&Ms keep a stricter one
+        for (document_type, min_completeness_score) in [
+            (DocumentType::IntegratedInventoryWorkbook, 0.6),
+            (DocumentType::PlanOfActionMilestones, 0.95),
+        ] {
+            config.document_type_thresholds.insert(document_type, crate::quality::QualityThresholdOverrides {
+                min_completeness_score: Some(min_completeness_score),
+                ..Default::default()
+            });
+        }
+        let results = InventoryValidator::new().with_quality_config(config)
+            .validate_inventory(&assets, &[]).await.unwrap();
+        assert_eq!(InventoryValidator::completeness_score(&results), 0.7);
+        assert!(!failing_code(&results));
+    }
 }
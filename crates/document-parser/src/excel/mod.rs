@@ -13,7 +13,7 @@ pub mod validation;
 
 // Re-export main types for convenience
 pub use core::ExcelParser;
-pub use poam::{PoamParser, PoamItem, PoamParseResult};
+pub use poam::{PoamParser, PoamItem, PoamParseResult, PoamWorkbookWriter};
 pub use inventory::{InventoryParser, Asset, InventoryDocument, AssetType, AssetCategory};
 pub use types::*;
 pub use validation::ExcelValidator;
@@ -4,6 +4,8 @@
 use serde_json::Value;
 use std::collections::HashMap;
 
+use crate::mapping::engine::OptimizedMappingLookup;
+
 use super::types::*;
 
 impl PoamTemplateDetector {
@@ -26,6 +28,14 @@ impl PoamTemplateDetector {
                     "Vulnerability ID".to_string(),
                     "Source".to_string(),
                     "Asset ID".to_string(),
+                    "Weakness Name".to_string(),
+                    "Original Detection Date".to_string(),
+                    "Scheduled Completion Date".to_string(),
+                    "Actual Completion Date".to_string(),
+                    "Point of Contact".to_string(),
+                    "Remediation Plan".to_string(),
+                    "Milestones".to_string(),
+                    "Comments".to_string(),
                 ],
                 required_worksheets: vec!["POA&M".to_string()],
             },
@@ -152,8 +162,8 @@ impl PoamTemplateDetector {
 
     /// Perform fuzzy matching between header and expected column name
     fn fuzzy_match(&self, header: &str, expected: &str) -> bool {
-        let header_clean = header.to_lowercase().replace(&[' ', '_', '-', '.'], "");
-        let expected_clean = expected.to_lowercase().replace(&[' ', '_', '-', '.'], "");
+        let header_clean = OptimizedMappingLookup::normalize_column_name(header);
+        let expected_clean = OptimizedMappingLookup::normalize_column_name(expected);
 
         // Exact match
         if header_clean == expected_clean {
@@ -4,7 +4,12 @@
 use serde_json::Value;
 use chrono::Utc;
 use std::collections::HashMap;
-use fedramp_core::Result;
+use fedramp_core::{Error, Result};
+use regex::Regex;
+use std::sync::OnceLock;
+use tracing::warn;
+
+use crate::mapping::{DateConverter, MilestoneProcessing, StatusTransformer};
 
 use super::types::*;
 
@@ -13,6 +18,8 @@ impl PoamFieldMapper {
     pub fn new() -> Self {
         Self {
             mapping_config: PoamMappingConfig::default(),
+            milestone_patterns: CompiledMilestonePatterns::shipped(),
+            status_transformer: StatusTransformer::new(),
         }
     }
 
@@ -20,6 +27,8 @@ impl PoamFieldMapper {
     pub fn with_config(config: PoamMappingConfig) -> Self {
         Self {
             mapping_config: config,
+            milestone_patterns: CompiledMilestonePatterns::shipped(),
+            status_transformer: StatusTransformer::new(),
         }
     }
 
@@ -33,58 +42,226 @@ impl PoamFieldMapper {
         self.mapping_config = config;
     }
 
+    /// Set the milestone separator and format patterns
+    ///
+    /// The patterns are compiled here; an invalid one is a configuration error.
+    pub fn set_milestone_processing(&mut self, processing: &MilestoneProcessing) -> Result<()> {
+        self.milestone_patterns = CompiledMilestonePatterns::compile(processing)?;
+        Ok(())
+    }
+
+    /// Add status spellings, such as `WIP`, to the default synonym table
+    pub fn set_status_synonyms(&mut self, synonyms: HashMap<String, String>) {
+        self.status_transformer = StatusTransformer::new().with_synonyms(synonyms);
+    }
+
+    /// Split a milestone cell into structured milestones
+    ///
+    /// The cell is split on the configured separator patterns and each part is
+    /// matched against the `milestone_format` patterns, whose capture groups
+    /// are named by `groups` (`description`, `date`, `status`). Parts that
+    /// match no pattern, or whose date cannot be read, are kept with the text
+    /// as description and reported in `warnings`.
+    pub fn parse_milestones(&self, cell: &str) -> MilestoneParseResult {
+        let patterns = &self.milestone_patterns;
+        let date_converter = DateConverter::new();
+
+        let parts: Vec<&str> = match &patterns.separator {
+            Some(separator) => separator.split(cell).collect(),
+            None => vec![cell],
+        };
+
+        let mut result = MilestoneParseResult::default();
+        for part in parts.into_iter().map(str::trim).filter(|part| !part.is_empty()) {
+            let captures = patterns.formats.iter().find_map(|format| format.captures(part));
+            let group = |name: &str| {
+                let index = patterns.groups.iter().position(|group| group == name)?;
+                captures.as_ref()?.get(index + 1).map(|m| m.as_str().trim().to_string())
+            };
+
+            if captures.is_none() {
+                result.warnings.push(format!("Milestone '{}' does not match a configured milestone format", part));
+            }
+
+            let date = group("date");
+            let scheduled_date = date.as_deref().and_then(|date| date_converter.parse_date(date).parsed_date);
+            if let (Some(date), None) = (&date, scheduled_date) {
+                result.warnings.push(format!("Milestone '{}' has an unreadable date '{}'", part, date));
+            }
+
+            result.milestones.push(PoamMilestone {
+                id: (result.milestones.len() + 1).to_string(),
+                description: group("description").unwrap_or_else(|| part.to_string()),
+                scheduled_date,
+                actual_date: None,
+                status: self.parse_milestone_status(&group("status")),
+                percent_complete: None,
+                comments: None,
+            });
+        }
+
+        result
+    }
+
     /// Map row data to POA&M fields based on column mappings
-    pub fn map_row_to_poam(&self, row_data: &[Value], column_mappings: &HashMap<String, String>) -> Result<PoamItem> {
-        // This is a simplified implementation
-        // In practice, this would use the mapping configuration to transform data
+    ///
+    /// `column_mappings` maps template column names (e.g. "Unique ID") to the
+    /// worksheet headers detected for them; `headers` gives the header order of
+    /// `row_data`.
+    pub fn map_row_to_poam(
+        &self,
+        row_data: &[Value],
+        headers: &[String],
+        column_mappings: &HashMap<String, String>,
+    ) -> Result<PoamItem> {
+        let field = |name: &str| self.extract_field(row_data, headers, column_mappings, name);
+        let date_converter = DateConverter::new();
+        let date = |name: &str| field(name).and_then(|value| date_converter.parse_date(&value).parsed_date);
 
-        let unique_id = self.extract_field(row_data, column_mappings, "Unique ID")
+        let unique_id = field("Unique ID")
             .unwrap_or_else(|| format!("POAM-{}", uuid::Uuid::new_v4()));
 
-        let weakness_description = self.extract_field(row_data, column_mappings, "Weakness Description")
+        let weakness_description = field("Weakness Description")
             .unwrap_or_else(|| "No description provided".to_string());
 
+        let milestones = match field("Milestones") {
+            Some(cell) => {
+                let parsed = self.parse_milestones(&cell);
+                for warning in &parsed.warnings {
+                    warn!("{}", warning);
+                }
+                parsed.milestones
+            }
+            None => Vec::new(),
+        };
+
+        let raw_status = field("Status");
+        let status = self.parse_status(&raw_status);
+        let original_status = raw_status.filter(|raw| raw != crate::poam::poam_status_label(&status));
+
+        let control_id = field("Control ID");
+        let security_controls = control_id.iter()
+            .flat_map(|ids| ids.split([',', ';']))
+            .map(|id| id.trim().to_string())
+            .filter(|id| !id.is_empty())
+            .collect();
+
         Ok(PoamItem {
             unique_id,
-            control_id: self.extract_field(row_data, column_mappings, "Control ID"),
-            cci: self.extract_field(row_data, column_mappings, "CCI"),
-            system_name: self.extract_field(row_data, column_mappings, "System Name"),
-            vulnerability_id: self.extract_field(row_data, column_mappings, "Vulnerability ID"),
+            control_id,
+            cci: field("CCI"),
+            system_name: field("System Name"),
+            vulnerability_id: field("Vulnerability ID"),
             weakness_description,
-            source_identifier: self.extract_field(row_data, column_mappings, "Source"),
-            asset_identifier: self.extract_field(row_data, column_mappings, "Asset ID"),
-            security_controls: Vec::new(), // TODO: Parse from control_id
-            office_organization: self.extract_field(row_data, column_mappings, "Office/Organization"),
+            source_identifier: field("Source"),
+            asset_identifier: field("Asset ID"),
+            security_controls,
+            office_organization: field("Office/Organization"),
             security_control_names: Vec::new(),
-            implementation_guidance: self.extract_field(row_data, column_mappings, "Implementation Guidance"),
-            severity: self.parse_severity(&self.extract_field(row_data, column_mappings, "Severity")),
+            implementation_guidance: field("Implementation Guidance"),
+            severity: self.parse_severity(&field("Severity")),
             likelihood: None, // TODO: Parse likelihood
             impact: None, // TODO: Parse impact
             risk_rating: None, // TODO: Calculate risk rating
-            status: self.parse_status(&self.extract_field(row_data, column_mappings, "Status")),
-            scheduled_completion_date: None, // TODO: Parse date
-            actual_completion_date: None, // TODO: Parse date
-            milestones: Vec::new(),
+            status,
+            original_status,
+            scheduled_completion_date: date("Scheduled Completion Date"),
+            actual_completion_date: date("Actual Completion Date"),
+            milestones,
             resources: Vec::new(),
-            point_of_contact: self.extract_field(row_data, column_mappings, "Point of Contact"),
-            remediation_plan: self.extract_field(row_data, column_mappings, "Remediation Plan"),
+            point_of_contact: field("Point of Contact"),
+            remediation_plan: field("Remediation Plan"),
             affected_assets: Vec::new(),
-            comments: self.extract_field(row_data, column_mappings, "Comments"),
-            vendor_information: self.extract_field(row_data, column_mappings, "Vendor Information"),
+            comments: field("Comments"),
+            vendor_information: field("Vendor Information"),
             cost_estimate: None, // TODO: Parse cost
-            detection_date: None, // TODO: Parse date
+            detection_date: date("Original Detection Date"),
             last_updated: Utc::now(),
+            source_sheet: None,
         })
     }
 
+    /// Flatten a POA&M item into the row fields read by `PoamItemProcessor`
+    ///
+    /// This is the input shape for `OscalGenerator::generate_poam_document`;
+    /// `PoamWorkbookWriter` performs the inverse mapping from OSCAL back to rows.
+    pub fn map_poam_to_oscal_row(&self, item: &PoamItem) -> HashMap<String, Value> {
+        let flattened = crate::poam::PoamItem::from(item);
+
+        let mut row = HashMap::new();
+        row.insert("uuid".to_string(), Value::String(item.unique_id.clone()));
+        row.insert("title".to_string(), Value::String(flattened.title));
+        row.insert("description".to_string(), Value::String(flattened.description));
+        row.insert("status".to_string(), Value::String(flattened.status));
+
+        let optional_fields = [
+            ("control_id", item.control_id.clone()),
+            ("severity", flattened.severity),
+            ("scheduled_completion_date", flattened.scheduled_completion_date),
+            ("actual_completion_date", flattened.actual_completion_date),
+            ("detection_date", item.detection_date.map(|d| d.to_rfc3339())),
+            ("point_of_contact", item.point_of_contact.clone()),
+            ("remediation_plan", item.remediation_plan.clone()),
+            ("comments", item.comments.clone()),
+        ];
+        for (name, value) in optional_fields {
+            if let Some(value) = value {
+                row.insert(name.to_string(), Value::String(value));
+            }
+        }
+
+        row
+    }
+
+    /// Restore the identifier columns of a row as trimmed strings
+    ///
+    /// Type detection may read identifiers such as `0001` or `1` as numbers.
+    /// Cells in `identifier_columns` take their original text from
+    /// `source_text`, keyed by row and column, when it was recorded; other
+    /// numbers are written without a fractional part when whole.
+    pub fn restore_identifier_columns(
+        &self,
+        row_data: &[Value],
+        row_index: usize,
+        identifier_columns: &[usize],
+        source_text: &HashMap<(usize, usize), String>,
+    ) -> Vec<Value> {
+        row_data.iter()
+            .enumerate()
+            .map(|(column_index, value)| {
+                if !identifier_columns.contains(&column_index) {
+                    return value.clone();
+                }
+                let text = match (source_text.get(&(row_index, column_index)), value) {
+                    (Some(text), _) => text.clone(),
+                    (None, Value::Number(n)) => match n.as_i64() {
+                        Some(i) => i.to_string(),
+                        None => n.as_f64().map(|f| f.to_string()).unwrap_or_else(|| n.to_string()),
+                    },
+                    (None, Value::String(s)) => s.clone(),
+                    (None, Value::Bool(b)) => b.to_string(),
+                    (None, other) => return other.clone(),
+                };
+                Value::String(text.trim().to_string())
+            })
+            .collect()
+    }
+
     /// Extract field value from row data using column mappings
-    fn extract_field(&self, _row_data: &[Value], _column_mappings: &HashMap<String, String>, _field_name: &str) -> Option<String> {
-        // Find the column index for this field
-        // if let Some(_column_name) = column_mappings.get(field_name) {
-        //     // TODO: Find column index by name and extract value
-        //     // For now, return None as this requires header-to-index mapping
-        // }
-        None
+    fn extract_field(
+        &self,
+        row_data: &[Value],
+        headers: &[String],
+        column_mappings: &HashMap<String, String>,
+        field_name: &str,
+    ) -> Option<String> {
+        let column_name = column_mappings.get(field_name)?;
+        let column_index = headers.iter().position(|header| header == column_name)?;
+
+        row_data.get(column_index)
+            .and_then(|value| self.extract_string_value(value))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
     }
 
     /// Parse severity from string value
@@ -100,13 +277,15 @@ impl PoamFieldMapper {
         }
     }
 
-    /// Parse status from string value
+    /// Parse status from string value, after coalescing status synonyms
     fn parse_status(&self, value: &Option<String>) -> PoamStatus {
-        let status_str = value.as_ref().map(|s| s.to_lowercase()).unwrap_or_default();
+        let status_str = value.as_deref()
+            .map(|s| self.status_transformer.canonical_status(s).unwrap_or(s).to_lowercase())
+            .unwrap_or_default();
         match status_str.as_str() {
             "open" => PoamStatus::Open,
             "in progress" | "inprogress" | "in-progress" => PoamStatus::InProgress,
-            "completed" | "complete" => PoamStatus::Completed,
+            "completed" | "complete" | "closed" => PoamStatus::Completed,
             "risk accepted" | "riskaccepted" | "accepted" => PoamStatus::RiskAccepted,
             "false positive" | "falsepositive" => PoamStatus::FalsePositive,
             "deferred" => PoamStatus::Deferred,
@@ -241,8 +420,134 @@ impl PoamFieldMapper {
     }
 }
 
+impl CompiledMilestonePatterns {
+    /// Compile the separator and format patterns of a milestone configuration
+    pub fn compile(processing: &MilestoneProcessing) -> Result<Self> {
+        let compile = |pattern: &str| {
+            Regex::new(pattern).map_err(|e| {
+                Error::configuration(format!("Invalid milestone pattern '{}': {}", pattern, e))
+            })
+        };
+        let patterns = &processing.patterns;
+        let separators = &patterns.multiple_milestones.separator_patterns;
+
+        Ok(Self {
+            separator: if separators.is_empty() { None } else { Some(compile(&separators.join("|"))?) },
+            formats: patterns.milestone_format.patterns.iter()
+                .map(|pattern| compile(pattern))
+                .collect::<Result<_>>()?,
+            groups: patterns.milestone_format.groups.clone(),
+        })
+    }
+
+    /// The patterns shipped in `mappings/poam_mappings.json`, compiled on first use
+    pub fn shipped() -> Self {
+        static SHIPPED: OnceLock<CompiledMilestonePatterns> = OnceLock::new();
+        SHIPPED.get_or_init(|| {
+            Self::compile(&MilestoneProcessing::default()).expect("shipped milestone patterns are valid regexes")
+        }).clone()
+    }
+}
+
 impl Default for PoamFieldMapper {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, NaiveDate};
+
+    #[test]
+    fn test_two_milestone_cell_yields_structured_milestones() {
+        let mapper = PoamFieldMapper::new();
+
+        let result = mapper.parse_milestones(
+            "Apply vendor patch - 03/15/2025 - Completed; Rescan hosts (2025-04-01) - In Progress",
+        );
+
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.milestones.len(), 2);
+        let first = &result.milestones[0];
+        assert_eq!(first.description, "Apply vendor patch");
+        assert_eq!(first.scheduled_date.map(|d| d.date_naive()), NaiveDate::from_ymd_opt(2025, 3, 15));
+        assert_eq!(first.status, MilestoneStatus::Completed);
+        let second = &result.milestones[1];
+        assert_eq!(second.description, "Rescan hosts");
+        assert_eq!(second.scheduled_date.map(|d| d.month()), Some(4));
+        assert_eq!(second.status, MilestoneStatus::InProgress);
+    }
+
+    #[test]
+    fn test_malformed_milestones_are_kept_as_descriptions_with_warnings() {
+        let mapper = PoamFieldMapper::new();
+
+        let result = mapper.parse_milestones("Decommission legacy VPN\n | Rotate keys - 13/45/2025 - Open");
+
+        let descriptions: Vec<&str> = result.milestones.iter().map(|m| m.description.as_str()).collect();
+        assert_eq!(descriptions, vec!["Decommission legacy VPN", "Rotate keys"]);
+        assert!(result.milestones.iter().all(|m| m.scheduled_date.is_none()));
+        assert_eq!(result.warnings.len(), 2);
+    }
+
+    #[test]
+    fn test_configured_milestone_patterns_replace_the_shipped_ones() {
+        let mut processing = MilestoneProcessing::default();
+        processing.patterns.multiple_milestones.separator_patterns = vec![r"\s*//\s*".to_string()];
+        let mut mapper = PoamFieldMapper::new();
+        mapper.set_milestone_processing(&processing).unwrap();
+
+        let result = mapper.parse_milestones("Apply vendor patch - 03/15/2025 - Completed // Rescan hosts; retest");
+
+        let descriptions: Vec<&str> = result.milestones.iter().map(|m| m.description.as_str()).collect();
+        assert_eq!(descriptions, vec!["Apply vendor patch", "Rescan hosts; retest"]);
+    }
+
+    #[test]
+    fn test_invalid_milestone_pattern_is_rejected() {
+        let mut processing = MilestoneProcessing::default();
+        processing.patterns.milestone_format.patterns.push("(unclosed".to_string());
+        let mut mapper = PoamFieldMapper::new();
+
+        let error = mapper.set_milestone_processing(&processing).unwrap_err();
+
+        assert!(error.to_string().contains("(unclosed"));
+        assert_eq!(mapper.milestone_patterns.formats.len(), 2);
+    }
+
+    fn row_with_status(mapper: &PoamFieldMapper, status: &str) -> PoamItem {
+        let headers = vec!["POA&M ID".to_string(), "Status".to_string()];
+        let column_mappings = HashMap::from([
+            ("Unique ID".to_string(), "POA&M ID".to_string()),
+            ("Status".to_string(), "Status".to_string()),
+        ]);
+        let row = [Value::String("V-1".to_string()), Value::String(status.to_string())];
+        mapper.map_row_to_poam(&row, &headers, &column_mappings).unwrap()
+    }
+
+    #[test]
+    fn test_status_synonyms_are_coalesced_while_mapping() {
+        use crate::quality::{AccuracyConfig, ValidationRuleExecutor};
+
+        let mut mapper = PoamFieldMapper::new();
+        let item = row_with_status(&mapper, "Ongoing");
+        assert_eq!(item.status, PoamStatus::InProgress);
+        assert_eq!(item.original_status.as_deref(), Some("Ongoing"));
+        assert_eq!(row_with_status(&mapper, "In Progress").original_status, None);
+
+        // The flattened item passes status validation and keeps the raw spelling
+        let flattened = crate::poam::PoamItem::from(&item);
+        assert_eq!(flattened.status, "In Progress");
+        assert_eq!(
+            flattened.metadata.get(crate::mapping::ORIGINAL_STATUS_METADATA_KEY).map(String::as_str),
+            Some("Ongoing")
+        );
+        let rule_executor = ValidationRuleExecutor::new(AccuracyConfig::default());
+        assert_eq!(rule_executor.validate_status_values(&[flattened]).unwrap().failed_items, 0);
+
+        mapper.set_status_synonyms(HashMap::from([("WIP".to_string(), "In Progress".to_string())]));
+        assert_eq!(row_with_status(&mapper, "wip").status, PoamStatus::InProgress);
+    }
+}
@@ -10,6 +10,7 @@ pub mod detector;
 pub mod mapper;
 pub mod validator;
 pub mod enricher;
+pub mod writer;
 
 // Re-export all public types and traits for backward compatibility
 pub use types::*;
@@ -18,3 +19,4 @@ pub use detector::*;
 pub use mapper::*;
 pub use validator::*;
 pub use enricher::*;
+pub use writer::*;
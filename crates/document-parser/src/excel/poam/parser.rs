@@ -2,9 +2,11 @@
 //! Modified: 2025-01-22
 
 use crate::excel::core::ExcelParser;
-use crate::excel::types::ValidationSeverity;
+use crate::excel::types::{CellSourceText, ValidationSeverity};
+use crate::mapping::{MappingConfigurationLoader, PoamMappings};
 use serde_json::Value;
-use chrono::Utc;
+use std::collections::HashMap;
+use std::path::Path;
 use tracing::{debug, info, warn};
 use fedramp_core::{Result, Error};
 
@@ -33,6 +35,24 @@ impl PoamParser {
         }
     }
 
+    /// Apply a loaded POA&M mapping configuration
+    ///
+    /// Milestone cells are then split and parsed with its milestone patterns.
+    pub fn set_poam_mappings(&mut self, mappings: &PoamMappings) -> Result<()> {
+        self.field_mapper.set_milestone_processing(&mappings.milestone_processing)
+    }
+
+    /// Add status spellings, such as `WIP`, coalesced to canonical labels while parsing
+    pub fn set_status_synonyms(&mut self, synonyms: HashMap<String, String>) {
+        self.field_mapper.set_status_synonyms(synonyms);
+    }
+
+    /// Load the POA&M mapping configuration under `base_dir` and apply it
+    pub async fn load_poam_mappings<P: AsRef<Path>>(&mut self, base_dir: P) -> Result<()> {
+        let mappings = MappingConfigurationLoader::new(base_dir).load_poam_mappings().await?;
+        self.set_poam_mappings(&mappings)
+    }
+
     /// Parse a POA&M Excel file from path
     ///
     /// # Arguments
@@ -62,10 +82,14 @@ impl PoamParser {
     }
 
     /// Parse POA&M data from Excel worksheets
+    ///
+    /// Items from every worksheet whose headers match a POA&M template are
+    /// concatenated and tagged with their source sheet; other sheets are skipped.
     async fn parse_poam_worksheets(&self, worksheets: &[Value]) -> Result<PoamParseResult> {
         let mut all_items = Vec::new();
         let mut all_validation_results = Vec::new();
-        let mut template_info = None;
+        let mut template_info: Option<TemplateInfo> = None;
+        let mut source_sheets = Vec::new();
         let mut total_rows = 0;
         let mut error_rows = 0;
         let mut skipped_rows = 0;
@@ -80,17 +104,27 @@ impl PoamParser {
 
             // Detect if this worksheet contains POA&M data
             if let Some(detected_template) = self.template_detector.detect_template(worksheet) {
-                info!("Detected POA&M template: {} v{}", detected_template.name, detected_template.version);
-                template_info = Some(detected_template);
+                info!(
+                    "Detected POA&M template in worksheet '{}': {} v{}",
+                    worksheet_name, detected_template.name, detected_template.version
+                );
+                let column_mappings = detected_template.column_mappings.clone();
+                if template_info.as_ref().is_none_or(|t| detected_template.confidence > t.confidence) {
+                    template_info = Some(detected_template);
+                }
 
                 // Parse the worksheet as POA&M data
-                match self.parse_poam_worksheet(worksheet).await {
+                match self.parse_poam_worksheet(worksheet, &column_mappings).await {
                     Ok(result) => {
                         total_rows += result.total_rows;
                         error_rows += result.error_rows;
                         skipped_rows += result.skipped_rows;
-                        all_items.extend(result.items);
+                        all_items.extend(result.items.into_iter().map(|mut item| {
+                            item.source_sheet = Some(worksheet_name.to_string());
+                            item
+                        }));
                         all_validation_results.extend(result.validation_results);
+                        source_sheets.push(worksheet_name.to_string());
                     }
                     Err(e) => {
                         warn!("Failed to parse POA&M worksheet '{}': {}", worksheet_name, e);
@@ -124,6 +158,7 @@ impl PoamParser {
         Ok(PoamParseResult {
             items: all_items,
             template_info,
+            source_sheets,
             statistics: PoamParsingStatistics {
                 total_rows,
                 parsed_items,
@@ -137,7 +172,11 @@ impl PoamParser {
     }
 
     /// Parse a single worksheet as POA&M data
-    async fn parse_poam_worksheet(&self, worksheet: &Value) -> Result<PoamWorksheetParseResult> {
+    async fn parse_poam_worksheet(
+        &self,
+        worksheet: &Value,
+        column_mappings: &HashMap<String, String>,
+    ) -> Result<PoamWorksheetParseResult> {
         let data = worksheet
             .get("data")
             .and_then(|v| v.as_array())
@@ -153,6 +192,20 @@ impl PoamParser {
                     .collect::<Vec<_>>()
             });
 
+        // Identifier columns are re-read as text once the template mapping is known
+        let identifier_columns: Vec<usize> = IDENTIFIER_COLUMNS.iter()
+            .filter_map(|field| column_mappings.get(*field))
+            .filter_map(|column| headers.as_ref()?.iter().position(|header| header == column))
+            .collect();
+        let source_text: HashMap<(usize, usize), String> = worksheet
+            .get("source_text")
+            .cloned()
+            .and_then(|value| serde_json::from_value::<Vec<CellSourceText>>(value).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|cell| ((cell.row, cell.column), cell.text))
+            .collect();
+
         let mut items = Vec::new();
         let mut validation_results = Vec::new();
         let mut error_rows = 0;
@@ -176,7 +229,9 @@ impl PoamParser {
                 continue;
             }
 
-            match self.parse_poam_row(row_array, row_index, &headers).await {
+            let row_array = self.field_mapper.restore_identifier_columns(row_array, row_index, &identifier_columns, &source_text);
+
+            match self.parse_poam_row(&row_array, row_index, &headers, column_mappings).await {
                 Ok(item) => {
                     items.push(item);
                 }
@@ -205,47 +260,19 @@ impl PoamParser {
     /// Parse a single row of POA&M data
     async fn parse_poam_row(
         &self,
-        _row_data: &[Value],
+        row_data: &[Value],
         row_index: usize,
-        _headers: &Option<Vec<String>>,
+        headers: &Option<Vec<String>>,
+        column_mappings: &HashMap<String, String>,
     ) -> Result<PoamItem> {
-        // This is a simplified implementation - in practice, this would use
-        // the field mapper to map columns to POA&M fields based on the detected template
-
-        // For now, create a basic POA&M item with placeholder data
-        let unique_id = format!("POAM-{:06}", row_index + 1);
-
-        Ok(PoamItem {
-            unique_id,
-            control_id: None,
-            cci: None,
-            system_name: None,
-            vulnerability_id: None,
-            weakness_description: "Placeholder description".to_string(),
-            source_identifier: None,
-            asset_identifier: None,
-            security_controls: Vec::new(),
-            office_organization: None,
-            security_control_names: Vec::new(),
-            implementation_guidance: None,
-            severity: PoamSeverity::Medium,
-            likelihood: None,
-            impact: None,
-            risk_rating: None,
-            status: PoamStatus::Open,
-            scheduled_completion_date: None,
-            actual_completion_date: None,
-            milestones: Vec::new(),
-            resources: Vec::new(),
-            point_of_contact: None,
-            remediation_plan: None,
-            affected_assets: Vec::new(),
-            comments: None,
-            vendor_information: None,
-            cost_estimate: None,
-            detection_date: None,
-            last_updated: Utc::now(),
-        })
+        let headers = headers.as_deref().unwrap_or_default();
+        let mut item = self.field_mapper.map_row_to_poam(row_data, headers, column_mappings)?;
+
+        if !column_mappings.contains_key("Unique ID") {
+            item.unique_id = format!("POAM-{:06}", row_index + 1);
+        }
+
+        Ok(item)
     }
 }
 
@@ -254,3 +281,69 @@ impl Default for PoamParser {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn poam_sheet(name: &str, rows: &[&str]) -> Value {
+        let mut data = vec![json!(["Unique ID", "Control ID", "Weakness Description", "Severity", "Status"])];
+        data.extend(rows.iter().map(|id| json!([id, "AC-2", "Stale accounts", "High", name])));
+        json!({
+            "name": name,
+            "headers": ["Unique ID", "Control ID", "Weakness Description", "Severity", "Status"],
+            "data": data,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_items_concatenated_across_sheets_with_source_tags() {
+        let worksheets = vec![
+            poam_sheet("Open POA&M Items", &["V-1", "V-2"]),
+            json!({ "name": "Instructions", "headers": ["Step", "Notes"], "data": [["Step", "Notes"]] }),
+            poam_sheet("Closed POA&M Items", &["V-3"]),
+        ];
+
+        let result = PoamParser::new().parse_poam_worksheets(&worksheets).await.unwrap();
+
+        assert_eq!(result.items.len(), 3);
+        assert_eq!(result.source_sheets, vec!["Open POA&M Items", "Closed POA&M Items"]);
+        let sheets: Vec<_> = result.items.iter().map(|item| item.source_sheet.as_deref()).collect();
+        assert_eq!(
+            sheets,
+            vec![Some("Open POA&M Items"), Some("Open POA&M Items"), Some("Closed POA&M Items")]
+        );
+        assert!(result.template_info.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_identifier_column_values_stay_strings() {
+        let headers = ["POA&M Item ID", "Control ID", "Weakness Description", "Severity", "Status"];
+        // Row 2 was typed as a number by locale detection; its original text is in `source_text`
+        let worksheet = json!({
+            "name": "POA&M Items",
+            "headers": headers,
+            "data": [
+                headers,
+                [" 0001 ", "AC-2", "Stale accounts", "High", "Open"],
+                [1, "AC-3", "Missing enforcement", "Low", "Open"],
+                [42.0, "AC-6", "Excess privilege", "Low", "Open"],
+            ],
+            "source_text": [{ "row": 2, "column": 0, "text": "1" }],
+        });
+        let column_mappings: HashMap<String, String> = [
+            ("Unique ID", "POA&M Item ID"),
+            ("Control ID", "Control ID"),
+            ("Weakness Description", "Weakness Description"),
+        ]
+        .into_iter()
+        .map(|(field, column)| (field.to_string(), column.to_string()))
+        .collect();
+
+        let result = PoamParser::new().parse_poam_worksheet(&worksheet, &column_mappings).await.unwrap();
+
+        let ids: Vec<&str> = result.items.iter().map(|item| item.unique_id.as_str()).collect();
+        assert_eq!(ids, vec!["0001", "1", "42"]);
+    }
+}
@@ -3,8 +3,10 @@
 
 use crate::excel::core::ExcelParser;
 use crate::excel::types::*;
+use crate::mapping::StatusTransformer;
 use serde::{Serialize, Deserialize};
 use serde_json::Value;
+use regex::Regex;
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 
@@ -45,11 +47,29 @@ pub struct TemplateSignature {
     pub version: String,
 }
 
+/// Template columns holding identifiers, which are kept as written instead of as numbers
+pub const IDENTIFIER_COLUMNS: &[&str] = &["Unique ID", "POA&M ID", "CCI", "Vulnerability ID", "Asset ID"];
+
 /// POA&M field mapper for column-to-field mapping
 #[derive(Debug, Clone)]
 pub struct PoamFieldMapper {
     /// Column mapping configuration
     pub mapping_config: PoamMappingConfig,
+    /// Separator and format patterns for milestone cells
+    pub milestone_patterns: CompiledMilestonePatterns,
+    /// Synonym table coalescing status spellings before they are parsed
+    pub status_transformer: StatusTransformer,
+}
+
+/// Milestone separator and format patterns, compiled once from a `MilestoneProcessing`
+#[derive(Debug, Clone)]
+pub struct CompiledMilestonePatterns {
+    /// Separator patterns as one alternation, if any are configured
+    pub separator: Option<Regex>,
+    /// Milestone format patterns, tried in order
+    pub formats: Vec<Regex>,
+    /// Names of the format patterns' capture groups, in order
+    pub groups: Vec<String>,
 }
 
 /// POA&M mapping configuration
@@ -105,6 +125,15 @@ pub struct PoamDataEnricher {
     pub risk_calculator: RiskCalculator,
 }
 
+/// Writer for FedRAMP POA&M Excel workbooks from OSCAL POA&M documents
+#[derive(Debug, Clone)]
+pub struct PoamWorkbookWriter {
+    /// Worksheet for items that are still open
+    pub open_worksheet_name: String,
+    /// Worksheet for completed items
+    pub closed_worksheet_name: String,
+}
+
 /// Risk calculation engine
 #[derive(Debug, Clone)]
 pub struct RiskCalculator {
@@ -160,6 +189,9 @@ pub struct PoamItem {
     pub risk_rating: Option<RiskRating>,
     /// Current status of remediation
     pub status: PoamStatus,
+    /// Status cell as written, when it differs from the label of `status`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_status: Option<String>,
     /// Scheduled completion date
     pub scheduled_completion_date: Option<DateTime<Utc>>,
     /// Actual completion date
@@ -184,6 +216,9 @@ pub struct PoamItem {
     pub detection_date: Option<DateTime<Utc>>,
     /// Last updated timestamp
     pub last_updated: DateTime<Utc>,
+    /// Worksheet the item was read from
+    #[serde(default)]
+    pub source_sheet: Option<String>,
 }
 
 /// Severity levels for POA&M items
@@ -407,6 +442,15 @@ pub struct PoamMilestone {
     pub comments: Option<String>,
 }
 
+/// Milestones parsed from a single POA&M cell
+#[derive(Debug, Clone, Default)]
+pub struct MilestoneParseResult {
+    /// Milestones in cell order, including description-only ones
+    pub milestones: Vec<PoamMilestone>,
+    /// Milestones that did not match a configured format or had an unreadable date
+    pub warnings: Vec<String>,
+}
+
 /// Resource required for POA&M remediation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PoamResource {
@@ -429,8 +473,10 @@ pub struct PoamResource {
 pub struct PoamParseResult {
     /// Parsed POA&M items
     pub items: Vec<PoamItem>,
-    /// Template information detected
+    /// Template information detected (highest confidence across sheets)
     pub template_info: Option<TemplateInfo>,
+    /// Names of the worksheets that matched the template, in workbook order
+    pub source_sheets: Vec<String>,
     /// Parsing statistics
     pub statistics: PoamParsingStatistics,
     /// Validation results
@@ -0,0 +1,242 @@
+//! POA&M Excel workbook generation from OSCAL
+//! Modified: 2025-09-20
+//!
+//! Inverse of the POA&M column mapping: writes each OSCAL `poam-item` as a row
+//! in the FedRAMP POA&M column layout so OSCAL POA&Ms can be reviewed in Excel.
+
+use chrono::DateTime;
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+use std::path::Path;
+use tracing::info;
+use fedramp_core::{Result, Error};
+
+use crate::oscal::{OscalPoamDocument, OscalPoamItem};
+use super::types::*;
+
+/// Column layout of generated workbooks, matching the FedRAMP POA&M template
+pub const FEDRAMP_POAM_COLUMNS: [&str; 12] = [
+    "Unique ID",
+    "Control ID",
+    "Weakness Name",
+    "Weakness Description",
+    "Severity",
+    "Status",
+    "Original Detection Date",
+    "Scheduled Completion Date",
+    "Actual Completion Date",
+    "Point of Contact",
+    "Remediation Plan",
+    "Comments",
+];
+
+impl PoamWorkbookWriter {
+    /// Create a writer using the FedRAMP template worksheet names
+    pub fn new() -> Self {
+        Self {
+            open_worksheet_name: "Open POA&M Items".to_string(),
+            closed_worksheet_name: "Closed POA&M Items".to_string(),
+        }
+    }
+
+    /// Generate an `.xlsx` workbook from an OSCAL POA&M document
+    ///
+    /// Completed or closed items are written to the closed worksheet, all
+    /// other items to the open worksheet.
+    pub fn write_oscal_poam(&self, document: &OscalPoamDocument) -> Result<Vec<u8>> {
+        let items = &document.plan_of_action_and_milestones.poam_items;
+        let (closed, open): (Vec<&OscalPoamItem>, Vec<&OscalPoamItem>) =
+            items.iter().partition(|item| is_closed(item));
+
+        let mut workbook = Workbook::new();
+        self.write_worksheet(workbook.add_worksheet(), &self.open_worksheet_name, &open)
+            .map_err(workbook_error)?;
+        self.write_worksheet(workbook.add_worksheet(), &self.closed_worksheet_name, &closed)
+            .map_err(workbook_error)?;
+
+        info!("Generated POA&M workbook with {} open and {} closed items", open.len(), closed.len());
+        workbook.save_to_buffer().map_err(workbook_error)
+    }
+
+    /// Generate an `.xlsx` workbook from an OSCAL POA&M document and write it to `path`
+    pub async fn write_oscal_poam_file(&self, document: &OscalPoamDocument, path: &Path) -> Result<()> {
+        let workbook = self.write_oscal_poam(document)?;
+        tokio::fs::write(path, workbook).await
+            .map_err(|e| Error::internal(format!("Failed to write POA&M workbook {}: {}", path.display(), e)))
+    }
+
+    /// Map an OSCAL POA&M item to a row in `FEDRAMP_POAM_COLUMNS` order
+    ///
+    /// The unique ID is the `poam-id` property when the item was converted from
+    /// a non-UUID identifier, otherwise the item UUID. Dates are written as
+    /// `YYYY-MM-DD`.
+    pub fn oscal_item_to_row(&self, item: &OscalPoamItem) -> Vec<String> {
+        let prop = |name: &str| property(item, name).map(str::to_string);
+        let date = |name: &str| property(item, name).map(format_date);
+        let tracking_entry = item.remediation_tracking.as_ref()
+            .and_then(|tracking| tracking.tracking_entries.first());
+
+        let scheduled_completion = date("scheduled-completion-date").or_else(|| {
+            tracking_entry
+                .and_then(|entry| entry.props.as_ref())
+                .and_then(|props| props.iter().find(|p| p.name == "scheduled-completion"))
+                .map(|p| format_date(&p.value))
+        });
+
+        vec![
+            prop("poam-id").unwrap_or_else(|| item.uuid.clone()),
+            prop("control-id").unwrap_or_default(),
+            item.title.clone(),
+            item.description.clone(),
+            prop("severity").unwrap_or_default(),
+            prop("status").unwrap_or_default(),
+            date("detection-date").unwrap_or_default(),
+            scheduled_completion.unwrap_or_default(),
+            date("actual-completion-date").unwrap_or_default(),
+            prop("point-of-contact").unwrap_or_default(),
+            tracking_entry.and_then(|entry| entry.description.clone()).unwrap_or_default(),
+            prop("comments").unwrap_or_default(),
+        ]
+    }
+
+    /// Write the header row and one row per item to a worksheet
+    fn write_worksheet(
+        &self,
+        worksheet: &mut Worksheet,
+        name: &str,
+        items: &[&OscalPoamItem],
+    ) -> std::result::Result<(), XlsxError> {
+        worksheet.set_name(name)?;
+
+        let header_format = Format::new().set_bold();
+        for (column, header) in FEDRAMP_POAM_COLUMNS.iter().enumerate() {
+            worksheet.write_string_with_format(0, column as u16, *header, &header_format)?;
+        }
+        worksheet.set_freeze_panes(1, 0)?;
+
+        for (index, item) in items.iter().enumerate() {
+            let row = index as u32 + 1;
+            for (column, value) in self.oscal_item_to_row(item).iter().enumerate() {
+                if !value.is_empty() {
+                    worksheet.write_string(row, column as u16, value)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for PoamWorkbookWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Value of a named property on a POA&M item
+fn property<'a>(item: &'a OscalPoamItem, name: &str) -> Option<&'a str> {
+    item.props.as_ref()?
+        .iter()
+        .find(|prop| prop.name == name)
+        .map(|prop| prop.value.as_str())
+}
+
+/// Whether an item belongs on the closed worksheet
+fn is_closed(item: &OscalPoamItem) -> bool {
+    property(item, "status")
+        .map(|status| matches!(status.to_lowercase().as_str(), "completed" | "complete" | "closed"))
+        .unwrap_or(false)
+}
+
+/// Format an RFC 3339 timestamp as a calendar date, leaving other values as-is
+fn format_date(value: &str) -> String {
+    DateTime::parse_from_rfc3339(value)
+        .map(|date| date.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|_| value.to_string())
+}
+
+fn workbook_error(error: XlsxError) -> Error {
+    Error::internal(format!("Failed to generate POA&M workbook: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oscal::OscalGenerator;
+
+    const SOURCE_ROWS: [[&str; 12]; 3] = [
+        [
+            "6f1c2a9e-4b7d-4e1a-9c3f-2d8e5b7a1c40", "AC-2", "Stale accounts",
+            "Stale accounts not disabled", "High", "Open", "2025-01-15", "2025-06-30", "",
+            "Identity Team", "Disable accounts after 90 days", "",
+        ],
+        [
+            "V-1001", "RA-5", "Unpatched OpenSSL", "Unpatched OpenSSL", "Critical", "In Progress",
+            "2025-02-01", "2025-03-03", "", "Platform Team", "Upgrade base images", "Vendor fix available",
+        ],
+        [
+            "V-1002", "SC-7", "Open ingress rule", "Open ingress rule", "Low", "Completed",
+            "2024-11-20", "2025-01-10", "2025-01-08", "Network Team", "Restrict security group", "",
+        ],
+    ];
+
+    fn source_workbook() -> Vec<u8> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name("POA&M Items").unwrap();
+        for (column, header) in FEDRAMP_POAM_COLUMNS.iter().enumerate() {
+            worksheet.write_string(0, column as u16, *header).unwrap();
+        }
+        for (row, values) in SOURCE_ROWS.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                if !value.is_empty() {
+                    worksheet.write_string(row as u32 + 1, column as u16, *value).unwrap();
+                }
+            }
+        }
+        workbook.save_to_buffer().unwrap()
+    }
+
+    async fn parse_workbook(bytes: Vec<u8>) -> Vec<PoamItem> {
+        let file = tempfile::Builder::new().suffix(".xlsx").tempfile().unwrap();
+        std::fs::write(file.path(), bytes).unwrap();
+        let mut items = PoamParser::new().parse_poam_file(file.path()).await.unwrap().items;
+        items.sort_by(|a, b| a.unique_id.cmp(&b.unique_id));
+        items
+    }
+
+    #[tokio::test]
+    async fn test_xlsx_oscal_xlsx_round_trip() {
+        let original = parse_workbook(source_workbook()).await;
+        assert_eq!(original.len(), SOURCE_ROWS.len());
+        assert!(original.iter().all(|item| item.control_id.is_some() && item.scheduled_completion_date.is_some()));
+
+        let mapper = PoamFieldMapper::new();
+        let rows: Vec<_> = original.iter().map(|item| mapper.map_poam_to_oscal_row(item)).collect();
+        let document = OscalGenerator::new().generate_poam_document(&rows, None).unwrap();
+
+        let poam_items = &document.plan_of_action_and_milestones.poam_items;
+        assert!(poam_items.iter().any(|item| item.uuid == SOURCE_ROWS[0][0]));
+
+        let workbook = PoamWorkbookWriter::new().write_oscal_poam(&document).unwrap();
+        let round_tripped = parse_workbook(workbook).await;
+
+        assert_eq!(round_tripped.len(), original.len());
+        for (before, after) in original.iter().zip(&round_tripped) {
+            assert_eq!(after.unique_id, before.unique_id);
+            assert_eq!(after.control_id, before.control_id);
+            assert_eq!(after.weakness_description, before.weakness_description);
+            assert_eq!(after.severity, before.severity);
+            assert_eq!(after.status, before.status);
+            assert_eq!(after.detection_date, before.detection_date);
+            assert_eq!(after.scheduled_completion_date, before.scheduled_completion_date);
+            assert_eq!(after.actual_completion_date, before.actual_completion_date);
+            assert_eq!(after.point_of_contact, before.point_of_contact);
+        }
+
+        let closed: Vec<_> = round_tripped.iter()
+            .filter(|item| item.source_sheet.as_deref() == Some("Closed POA&M Items"))
+            .map(|item| item.unique_id.as_str())
+            .collect();
+        assert_eq!(closed, vec!["V-1002"]);
+    }
+}
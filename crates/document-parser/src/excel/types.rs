@@ -37,14 +37,20 @@ pub enum ExcelError {
 pub struct ExcelWorksheet {
     /// Name of the worksheet
     pub name: String,
-    /// Number of rows with data
+    /// Number of rows processed
     pub row_count: usize,
+    /// Number of rows in the worksheet, including rows beyond `max_rows`
+    pub total_row_count: usize,
+    /// Whether rows were left out because of `max_rows`
+    pub truncated: bool,
     /// Number of columns with data
     pub column_count: usize,
     /// Raw data from the worksheet (sanitized)
     pub data: Vec<Vec<Value>>,
     /// Headers if detected
     pub headers: Option<Vec<String>>,
+    /// Number of leading rows the headers were read from; 0 without headers
+    pub header_row_count: usize,
     /// Merged cell ranges in the worksheet
     pub merged_cells: Vec<MergedCellRange>,
     /// Cell formatting information
@@ -53,6 +59,22 @@ pub struct ExcelWorksheet {
     pub validation_results: Vec<CellValidationResult>,
     /// Overall validation summary
     pub validation_summary: ValidationSummary,
+    /// Original text of cells converted to numbers or dates by locale detection
+    pub source_text: Vec<CellSourceText>,
+}
+
+/// Original text of a text cell whose value was converted by type detection
+///
+/// Lets consumers restore the cell as written, e.g. identifiers such as
+/// `0001` or `1.234` that must not be read as numbers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CellSourceText {
+    /// Row coordinate (0-based)
+    pub row: usize,
+    /// Column coordinate (0-based)
+    pub column: usize,
+    /// Cell text as written in the worksheet
+    pub text: String,
 }
 
 /// Summary of validation results for a worksheet
@@ -247,6 +269,8 @@ pub enum ValidationIssueType {
     SuspiciousPattern,
     /// Encoding issue
     EncodingIssue,
+    /// Date whose day and month order could not be determined from the value
+    AmbiguousDate,
 }
 
 /// Severity levels for validation issues
@@ -262,6 +286,17 @@ pub enum ValidationSeverity {
     Critical,
 }
 
+impl From<ValidationSeverity> for crate::validation::ValidationSeverity {
+    fn from(severity: ValidationSeverity) -> Self {
+        match severity {
+            ValidationSeverity::Info => Self::Info,
+            ValidationSeverity::Warning => Self::Warning,
+            ValidationSeverity::Error => Self::Error,
+            ValidationSeverity::Critical => Self::Critical,
+        }
+    }
+}
+
 /// Supported Excel file formats
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ExcelFormat {
@@ -379,3 +414,147 @@ impl Default for ValidationConfig {
         Self::default()
     }
 }
+
+/// Order of day and month in numeric dates such as `03/04/2024`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DateOrder {
+    /// Month first, e.g. `12/25/2023`
+    MonthDayYear,
+    /// Day first, e.g. `25.12.2023`
+    DayMonthYear,
+}
+
+/// Locale conventions for detecting numbers and dates in text cells
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExcelLocale {
+    /// Character separating the integer and fractional parts
+    pub decimal_separator: char,
+    /// Character grouping thousands in the integer part
+    pub thousands_separator: char,
+    /// Day and month order assumed for ambiguous dates
+    pub date_order: DateOrder,
+}
+
+impl ExcelLocale {
+    /// US conventions: `1,234.56` and `12/25/2023`
+    #[must_use]
+    pub fn us() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: ',',
+            date_order: DateOrder::MonthDayYear,
+        }
+    }
+
+    /// UK conventions: `1,234.56` and `25/12/2023`
+    #[must_use]
+    pub fn united_kingdom() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: ',',
+            date_order: DateOrder::DayMonthYear,
+        }
+    }
+
+    /// German conventions: `1.234,56` and `25.12.2023`
+    #[must_use]
+    pub fn german() -> Self {
+        Self {
+            decimal_separator: ',',
+            thousands_separator: '.',
+            date_order: DateOrder::DayMonthYear,
+        }
+    }
+}
+
+impl Default for ExcelLocale {
+    fn default() -> Self {
+        Self::us()
+    }
+}
+
+/// Pattern matched against worksheet names
+///
+/// Exact and glob patterns ignore case, as Excel does for sheet names. Globs
+/// support `*` (any run of characters) and `?` (one character). The parser
+/// builder and `ExcelParser::set_sheet_filter` reject invalid regexes; one
+/// that gets past them matches no sheet.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", content = "pattern", rename_all = "snake_case")]
+pub enum SheetNamePattern {
+    /// The whole sheet name, e.g. `POA&M Items`
+    Exact(String),
+    /// A wildcard pattern, e.g. `Open*`
+    Glob(String),
+    /// A regular expression searched in the sheet name, e.g. `^Q[1-4] `
+    Regex(String),
+}
+
+impl SheetNamePattern {
+    /// Check that a regex pattern compiles
+    pub fn validate(&self) -> fedramp_core::Result<()> {
+        if let SheetNamePattern::Regex(pattern) = self {
+            regex::Regex::new(pattern).map_err(|e| {
+                fedramp_core::Error::configuration(format!("Invalid sheet name regex '{}': {}", pattern, e))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Whether the sheet name matches the pattern
+    pub fn matches(&self, sheet_name: &str) -> bool {
+        match self {
+            SheetNamePattern::Exact(name) => name.eq_ignore_ascii_case(sheet_name),
+            SheetNamePattern::Glob(pattern) => {
+                let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+                let name: Vec<char> = sheet_name.to_lowercase().chars().collect();
+                glob_matches(&pattern, &name)
+            }
+            SheetNamePattern::Regex(pattern) => regex::Regex::new(pattern)
+                .map(|regex| regex.is_match(sheet_name))
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// Match a glob against a name, with `*` matching any run of characters
+fn glob_matches(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| glob_matches(rest, &name[skip..])),
+        Some(('?', rest)) => !name.is_empty() && glob_matches(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && glob_matches(rest, &name[1..]),
+    }
+}
+
+/// Worksheets to parse, selected by name
+///
+/// With no include patterns every sheet is included; a sheet matching any
+/// exclude pattern is skipped even if it is also included.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SheetFilter {
+    /// Sheets to parse; empty parses all sheets
+    #[serde(default)]
+    pub include: Vec<SheetNamePattern>,
+    /// Sheets to skip
+    #[serde(default)]
+    pub exclude: Vec<SheetNamePattern>,
+}
+
+impl SheetFilter {
+    /// Whether the named sheet should be parsed
+    pub fn allows(&self, sheet_name: &str) -> bool {
+        let included = self.include.is_empty() || self.include.iter().any(|pattern| pattern.matches(sheet_name));
+        included && !self.exclude.iter().any(|pattern| pattern.matches(sheet_name))
+    }
+
+    /// Whether the filter parses every sheet
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Check that every regex pattern compiles
+    pub fn validate(&self) -> fedramp_core::Result<()> {
+        self.include.iter().chain(&self.exclude).try_for_each(SheetNamePattern::validate)
+    }
+}
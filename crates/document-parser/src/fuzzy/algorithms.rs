@@ -3,11 +3,27 @@
 //! Fuzzy matching algorithms implementation
 //!
 //! This module contains implementations of various fuzzy string matching algorithms
-//! including Levenshtein distance, Jaro-Winkler, N-gram similarity, and Soundex.
+//! including Levenshtein distance, Jaro-Winkler, N-gram similarity, Soundex and
+//! token-set ratio.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use super::types::FuzzyAlgorithm;
 
+/// Names of the available algorithms, in the order a matcher runs them
+pub const ALGORITHM_NAMES: [&str; 5] = ["levenshtein", "jaro_winkler", "ngram", "soundex", "token_set"];
+
+/// Create the algorithm with the given name, if there is one
+pub fn algorithm_by_name(name: &str) -> Option<Box<dyn FuzzyAlgorithm>> {
+    match name {
+        "levenshtein" => Some(Box::new(LevenshteinAlgorithm)),
+        "jaro_winkler" => Some(Box::new(JaroWinklerAlgorithm)),
+        "ngram" => Some(Box::new(NgramAlgorithm::default())),
+        "soundex" => Some(Box::new(SoundexAlgorithm)),
+        "token_set" => Some(Box::new(TokenSetRatioAlgorithm)),
+        _ => None,
+    }
+}
+
 /// Levenshtein distance algorithm implementation
 #[derive(Debug, Clone)]
 pub struct LevenshteinAlgorithm;
@@ -321,3 +337,46 @@ impl FuzzyAlgorithm for SoundexAlgorithm {
         false // Soundex handles its own normalization
     }
 }
+
+/// Token-set ratio algorithm implementation
+///
+/// Compares the words two strings share separately from the words only one
+/// of them has, ignoring word order and repetition. Scores are the best
+/// normalized Levenshtein similarity among the shared words alone and the
+/// shared words followed by either string's remaining words.
+#[derive(Debug, Clone)]
+pub struct TokenSetRatioAlgorithm;
+
+impl FuzzyAlgorithm for TokenSetRatioAlgorithm {
+    fn name(&self) -> &'static str {
+        "token_set"
+    }
+
+    fn similarity(&self, s1: &str, s2: &str) -> f64 {
+        let tokens1: BTreeSet<&str> = s1.split_whitespace().collect();
+        let tokens2: BTreeSet<&str> = s2.split_whitespace().collect();
+        if tokens1 == tokens2 {
+            return 1.0;
+        }
+        if tokens1.is_empty() || tokens2.is_empty() {
+            return 0.0;
+        }
+
+        let join = |tokens: Vec<&str>| tokens.join(" ");
+        let shared = join(tokens1.intersection(&tokens2).copied().collect());
+        let with_rest = |rest: Vec<&str>| {
+            if shared.is_empty() {
+                join(rest)
+            } else {
+                format!("{} {}", shared, join(rest))
+            }
+        };
+        let combined1 = with_rest(tokens1.difference(&tokens2).copied().collect());
+        let combined2 = with_rest(tokens2.difference(&tokens1).copied().collect());
+
+        let levenshtein = LevenshteinAlgorithm;
+        levenshtein.similarity(&shared, &combined1)
+            .max(levenshtein.similarity(&shared, &combined2))
+            .max(levenshtein.similarity(&combined1, &combined2))
+    }
+}
@@ -5,9 +5,11 @@
 //! This module contains the core FuzzyMatcher struct that combines multiple
 //! algorithms and provides the main fuzzy matching functionality.
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher, DefaultHasher};
 use std::time::Instant;
+use fedramp_core::{Result, Error};
 use lru::LruCache;
 use tracing::{debug, trace};
 
@@ -16,6 +18,10 @@ use super::algorithms::*;
 use super::preprocessing::TextPreprocessor;
 
 /// Main fuzzy matcher that combines multiple algorithms
+///
+/// Match results are ordered deterministically, independent of the order of
+/// the targets: by highest confidence, then by the longest common prefix with
+/// the source (ignoring case), then by target name.
 pub struct FuzzyMatcher {
     /// Available fuzzy matching algorithms
     algorithms: Vec<Box<dyn FuzzyAlgorithm>>,
@@ -39,21 +45,11 @@ impl FuzzyMatcher {
 
     /// Create a new fuzzy matcher with custom configuration
     pub fn with_config(config: FuzzyMatchConfig) -> Self {
-        let mut algorithms: Vec<Box<dyn FuzzyAlgorithm>> = Vec::new();
-
         // Add algorithms based on weights in config
-        if config.algorithm_weights.contains_key("levenshtein") {
-            algorithms.push(Box::new(LevenshteinAlgorithm));
-        }
-        if config.algorithm_weights.contains_key("jaro_winkler") {
-            algorithms.push(Box::new(JaroWinklerAlgorithm));
-        }
-        if config.algorithm_weights.contains_key("ngram") {
-            algorithms.push(Box::new(NgramAlgorithm::default()));
-        }
-        if config.algorithm_weights.contains_key("soundex") {
-            algorithms.push(Box::new(SoundexAlgorithm));
-        }
+        let algorithms: Vec<Box<dyn FuzzyAlgorithm>> = ALGORITHM_NAMES.iter()
+            .filter(|name| config.algorithm_weights.contains_key(**name))
+            .filter_map(|name| algorithm_by_name(name))
+            .collect();
 
         let cache = if config.enable_caching {
             Some(LruCache::new(std::num::NonZeroUsize::new(config.cache_size).unwrap()))
@@ -73,6 +69,11 @@ impl FuzzyMatcher {
         }
     }
 
+    /// Builder starting from the default configuration
+    pub fn builder() -> FuzzyMatcherBuilder {
+        FuzzyMatcherBuilder::new()
+    }
+
     /// Create a fuzzy matcher optimized for FedRAMP column matching
     pub fn for_fedramp_columns() -> Self {
         let mut config = FuzzyMatchConfig::default();
@@ -135,6 +136,9 @@ impl FuzzyMatcher {
     }
     
     /// Find the best fuzzy matches for a source string against a list of targets
+    ///
+    /// Results are ordered best first; ties are broken as described on
+    /// [`FuzzyMatcher`].
     pub fn find_matches(&mut self, source: &str, targets: &[String]) -> Vec<FuzzyMatchResult> {
         let start_time = Instant::now();
 
@@ -174,8 +178,7 @@ impl FuzzyMatcher {
             }
         }
 
-        // Sort by confidence (descending)
-        results.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+        results.sort_by(|a, b| compare_matches(source, a, b));
 
         // Limit results
         results.truncate(self.config.max_results);
@@ -204,8 +207,7 @@ impl FuzzyMatcher {
             }
         }
 
-        // Sort by confidence (descending)
-        results.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
+        results.sort_by(|a, b| compare_matches(source, a, b));
 
         // Limit to max_results
         results.truncate(self.config.max_results);
@@ -403,6 +405,11 @@ impl FuzzyMatcher {
         }
     }
 
+    /// Weights of the enabled algorithms, by algorithm name
+    pub fn algorithm_weights(&self) -> &HashMap<String, f64> {
+        &self.config.algorithm_weights
+    }
+
     /// Update the configuration and clear cache if needed
     pub fn update_config(&mut self, new_config: FuzzyMatchConfig) {
         let new_hash = Self::calculate_config_hash(&new_config);
@@ -434,3 +441,194 @@ impl Default for FuzzyMatcher {
         Self::new()
     }
 }
+
+/// Builder for a [`FuzzyMatcher`] with a chosen set of algorithms
+///
+/// The combined score is the weighted mean of the enabled algorithms' scores,
+/// so weights are relative to each other. Algorithms are named as in
+/// [`ALGORITHM_NAMES`].
+#[derive(Debug, Clone)]
+pub struct FuzzyMatcherBuilder {
+    config: FuzzyMatchConfig,
+}
+
+impl FuzzyMatcherBuilder {
+    /// Create a new builder with the default configuration and algorithms
+    pub fn new() -> Self {
+        Self::from_config(FuzzyMatchConfig::default())
+    }
+
+    /// Create a new builder starting from a configuration
+    pub fn from_config(config: FuzzyMatchConfig) -> Self {
+        Self { config }
+    }
+
+    /// Enable an algorithm with a weight, or change the weight of an enabled one
+    pub fn algorithm(mut self, name: &str, weight: f64) -> Self {
+        self.config.algorithm_weights.insert(name.to_string(), weight);
+        self
+    }
+
+    /// Disable an algorithm
+    pub fn disable_algorithm(mut self, name: &str) -> Self {
+        self.config.algorithm_weights.remove(name);
+        self
+    }
+
+    /// Enable only the given algorithms, with their weights
+    pub fn only_algorithms(mut self, weights: &[(&str, f64)]) -> Self {
+        self.config.algorithm_weights = weights.iter()
+            .map(|(name, weight)| (name.to_string(), *weight))
+            .collect();
+        self
+    }
+
+    /// Set the minimum confidence threshold
+    pub fn min_confidence(mut self, min_confidence: f64) -> Self {
+        self.config.min_confidence = min_confidence;
+        self
+    }
+
+    /// Set the maximum number of results
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.config.max_results = max_results;
+        self
+    }
+
+    /// Build the fuzzy matcher
+    ///
+    /// # Errors
+    ///
+    /// Returns error if an algorithm name is unknown, a weight is negative or
+    /// not finite, or no algorithm has a positive weight
+    pub fn build(self) -> Result<FuzzyMatcher> {
+        for (name, weight) in &self.config.algorithm_weights {
+            if !ALGORITHM_NAMES.contains(&name.as_str()) {
+                return Err(Error::configuration(format!(
+                    "Unknown fuzzy matching algorithm '{}'; expected one of {}",
+                    name,
+                    ALGORITHM_NAMES.join(", ")
+                )));
+            }
+            if !weight.is_finite() || *weight < 0.0 {
+                return Err(Error::configuration(format!(
+                    "Weight of fuzzy matching algorithm '{}' must be a non-negative number, got {}",
+                    name, weight
+                )));
+            }
+        }
+        if !self.config.algorithm_weights.values().any(|weight| *weight > 0.0) {
+            return Err(Error::configuration("At least one fuzzy matching algorithm must have a positive weight"));
+        }
+
+        Ok(FuzzyMatcher::with_config(self.config))
+    }
+}
+
+impl Default for FuzzyMatcherBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Order two match results for a source, best first
+///
+/// Higher confidence wins; ties go to the target sharing the longer prefix
+/// with the source, ignoring case, and then to the lexicographically smaller
+/// target name.
+pub fn compare_matches(source: &str, a: &FuzzyMatchResult, b: &FuzzyMatchResult) -> Ordering {
+    b.confidence.total_cmp(&a.confidence)
+        .then_with(|| common_prefix_len(source, &b.target).cmp(&common_prefix_len(source, &a.target)))
+        .then_with(|| a.target.cmp(&b.target))
+}
+
+/// Number of leading characters two strings share, ignoring case
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.chars()
+        .zip(b.chars())
+        .take_while(|(x, y)| x.to_lowercase().eq(y.to_lowercase()))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levenshtein_only() -> FuzzyMatcher {
+        FuzzyMatcher::with_config(FuzzyMatchConfig {
+            algorithm_weights: HashMap::from([("levenshtein".to_string(), 1.0)]),
+            ..FuzzyMatchConfig::default()
+        })
+    }
+
+    #[test]
+    fn test_tied_matches_resolve_to_the_same_target_in_any_order() {
+        // Both targets are one edit from the source; "owned" shares the longer prefix
+        let targets = vec!["xwner".to_string(), "owned".to_string()];
+        let reversed: Vec<String> = targets.iter().rev().cloned().collect();
+
+        for targets in [targets, reversed] {
+            let results = levenshtein_only().find_matches("owner", &targets);
+            assert_eq!(results[0].confidence, results[1].confidence);
+            assert_eq!(results[0].target, "owned");
+        }
+    }
+
+    #[test]
+    fn test_equal_prefix_ties_fall_back_to_target_name() {
+        let targets = vec!["asset id".to_string(), "ASSET ID".to_string()];
+        let reversed: Vec<String> = targets.iter().rev().cloned().collect();
+
+        for targets in [targets, reversed] {
+            let results = FuzzyMatcher::new().find_matches("Asset ID", &targets);
+            let names: Vec<&str> = results.iter().map(|result| result.target.as_str()).collect();
+            assert_eq!(names, vec!["ASSET ID", "asset id"]);
+        }
+    }
+
+    #[test]
+    fn test_default_builder_matches_default_matcher() {
+        let targets = vec!["Component Name".to_string(), "Asset Type".to_string()];
+
+        let built = FuzzyMatcher::builder().build().unwrap().find_matches("Component ID", &targets);
+        assert_eq!(built, FuzzyMatcher::new().find_matches("Component ID", &targets));
+    }
+
+    #[test]
+    fn test_builder_selects_algorithms_and_weights() {
+        let targets = vec!["Component Name".to_string()];
+        let mut matcher = FuzzyMatcher::builder()
+            .disable_algorithm("jaro_winkler")
+            .algorithm("token_set", 0.5)
+            .min_confidence(0.0)
+            .build()
+            .unwrap();
+
+        let result = matcher.find_matches("Component ID", &targets).remove(0);
+        let mut algorithms: Vec<&str> = result.algorithm_scores.keys().map(String::as_str).collect();
+        algorithms.sort_unstable();
+        assert_eq!(algorithms, ["levenshtein", "ngram", "soundex", "token_set"]);
+
+        // The combined score is the weighted mean of the breakdown
+        let weights = matcher.algorithm_weights();
+        let weighted: f64 = result.algorithm_scores.iter().map(|(name, score)| score * weights[name]).sum();
+        let total: f64 = weights.values().sum();
+        assert!((result.confidence - weighted / total).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_algorithms() {
+        assert!(FuzzyMatcher::builder().algorithm("token_sort", 0.5).build().is_err());
+        assert!(FuzzyMatcher::builder().algorithm("ngram", -1.0).build().is_err());
+        assert!(FuzzyMatcher::builder().only_algorithms(&[("soundex", 0.0)]).build().is_err());
+    }
+
+    #[test]
+    fn test_token_set_ratio_ignores_word_order_and_repeats() {
+        let token_set = TokenSetRatioAlgorithm;
+        assert_eq!(token_set.similarity("asset id", "id id asset"), 1.0);
+        // "component" against "component id": 3 insertions over 12 characters
+        assert_eq!(token_set.similarity("component id", "component name"), 0.75);
+        assert_eq!(token_set.similarity("", "asset"), 0.0);
+    }
+}
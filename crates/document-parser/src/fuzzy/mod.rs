@@ -20,4 +20,4 @@ pub mod matcher;
 pub use types::*;
 pub use algorithms::*;
 pub use preprocessing::TextPreprocessor;
-pub use matcher::FuzzyMatcher;
+pub use matcher::{FuzzyMatcher, FuzzyMatcherBuilder, compare_matches};
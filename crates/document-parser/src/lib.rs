@@ -3,9 +3,10 @@
 //! # Document Parser
 //!
 //! Document parsing and OSCAL conversion for FedRAMP compliance documents.
-//! Supports Excel, Word, and Markdown document formats.
+//! Supports Excel, CSV/TSV, Word, and Markdown document formats.
 
 pub mod excel;
+pub mod csv;
 pub mod word;
 pub mod poam;
 
@@ -20,25 +21,37 @@ pub mod validation;
 pub mod oscal;
 pub mod quality;
 pub mod error;
+pub mod encoding;
+pub mod cache;
 
 use async_trait::async_trait;
-use fedramp_core::{Result, Error};
-use std::path::Path;
+use fedramp_core::{Result, Error, SharedMetricsRecorder};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
 use tokio::fs;
+use tokio::task::JoinSet;
+use tracing::debug;
 
 pub use error::DocumentParserError;
+pub use encoding::{DecodedText, EncodingDetection};
+pub use cache::{content_hash, InMemoryParseCache, ParseCache};
 pub use excel::ExcelParser;
+pub use csv::CsvParser;
 pub use word::WordParser;
 pub use markdown::MarkdownParser;
-pub use mapping::ColumnMapper;
+pub use mapping::{ColumnMapper, PiiPolicy};
 pub use validation::DocumentValidator;
 pub use oscal::OscalGenerator;
 pub use quality::PoamQualityChecker;
+pub use validation::ValidationSeverity;
 
 /// Supported document types
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DocumentType {
     Excel,
+    Csv,
     Word,
     Markdown,
     Json,
@@ -49,25 +62,92 @@ impl DocumentType {
     pub fn from_extension(path: &Path) -> Option<Self> {
         match path.extension()?.to_str()? {
             "xlsx" | "xls" => Some(Self::Excel),
+            "csv" | "tsv" | "tab" => Some(Self::Csv),
             "docx" => Some(Self::Word),
             "md" | "markdown" => Some(Self::Markdown),
             "json" => Some(Self::Json),
             _ => None,
         }
     }
+
+    /// Lowercase name of the type, e.g. `excel`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Excel => "excel",
+            Self::Csv => "csv",
+            Self::Word => "word",
+            Self::Markdown => "markdown",
+            Self::Json => "json",
+        }
+    }
 }
 
 /// Document parsing result
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParseResult {
     pub document_type: DocumentType,
     pub source_path: String,
     pub metadata: serde_json::Value,
     pub content: serde_json::Value,
-    pub validation_errors: Vec<String>,
+    pub validation_errors: Vec<ValidationError>,
     pub quality_score: f64,
 }
 
+impl ParseResult {
+    /// Validation error messages without locations
+    pub fn validation_messages(&self) -> Vec<String> {
+        self.validation_errors.iter().map(|error| error.message.clone()).collect()
+    }
+}
+
+/// Validation error located within parsed content
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationError {
+    /// JSON Pointer into [`ParseResult::content`], e.g. `/worksheets/0/data/5/2`;
+    /// empty when the error applies to the whole document
+    pub path: String,
+    /// Human-readable description
+    pub message: String,
+    /// Severity of the error
+    pub severity: ValidationSeverity,
+    /// Rule that raised the error, e.g. `mapping-schema/required`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+}
+
+impl ValidationError {
+    /// Create a validation error at a JSON Pointer location
+    pub fn new(path: impl Into<String>, message: impl Into<String>, severity: ValidationSeverity) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+            severity,
+            rule_id: None,
+        }
+    }
+
+    /// Attribute the error to a rule
+    pub fn with_rule_id(mut self, rule_id: impl Into<String>) -> Self {
+        self.rule_id = Some(rule_id.into());
+        self
+    }
+
+    /// Create an error-severity validation error for the whole document
+    pub fn document(message: impl Into<String>) -> Self {
+        Self::new("", message, ValidationSeverity::Error)
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
 /// Trait for document parsers
 #[async_trait]
 pub trait DocumentParser {
@@ -79,16 +159,29 @@ pub trait DocumentParser {
     
     /// Validate parsed content
     async fn validate(&self, content: &serde_json::Value) -> Result<Vec<String>>;
+
+    /// Read only the document's metadata, e.g. to route it before a full parse
+    ///
+    /// Defaults to the metadata of a full `parse_file`; parsers that can read
+    /// metadata without loading the content override this.
+    async fn parse_metadata(&self, path: &Path) -> Result<serde_json::Value> {
+        Ok(self.parse_file(path).await?.metadata)
+    }
     
     /// Get supported file extensions
     fn supported_extensions(&self) -> Vec<&'static str>;
 }
 
 /// Main document parser factory
+#[derive(Clone)]
 pub struct DocumentParserFactory {
     excel_parser: ExcelParser,
+    csv_parser: CsvParser,
     word_parser: WordParser,
     markdown_parser: MarkdownParser,
+    cache: Option<Arc<dyn ParseCache>>,
+    pii_policy: Option<PiiPolicy>,
+    metrics: Option<SharedMetricsRecorder>,
 }
 
 impl DocumentParserFactory {
@@ -96,18 +189,171 @@ impl DocumentParserFactory {
     pub fn new() -> Self {
         Self {
             excel_parser: ExcelParser::new(),
+            csv_parser: CsvParser::new(),
             word_parser: WordParser::new(),
             markdown_parser: MarkdownParser::new(),
+            cache: None,
+            pii_policy: None,
+            metrics: None,
         }
     }
 
+    /// Cache parse results by a hash of the document bytes
+    ///
+    /// Cached and freshly parsed results record the hash as `content_hash` in
+    /// their metadata.
+    pub fn with_cache(mut self, cache: Arc<dyn ParseCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Tag, and optionally mask, personal data in every parse result
+    ///
+    /// The policy runs after the cache, so cached results keep the original
+    /// values and a changed policy applies to them too.
+    pub fn with_pii_policy(mut self, policy: PiiPolicy) -> Self {
+        self.pii_policy = Some(policy);
+        self
+    }
+
+    /// Report how long each document takes to parse
+    ///
+    /// Only documents actually parsed are timed; cache hits are not.
+    pub fn with_metrics(mut self, metrics: SharedMetricsRecorder) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Parse a document based on its type
     pub async fn parse_document(&self, path: &Path) -> Result<ParseResult> {
+        let mut result = self.parse_document_cached(path).await?;
+        self.apply_pii_policy(&mut result);
+        Ok(result)
+    }
+
+    /// Parse a document, reusing a cached result if there is one
+    async fn parse_document_cached(&self, path: &Path) -> Result<ParseResult> {
         let document_type = DocumentType::from_extension(path)
-            .ok_or_else(|| Error::document_parsing("Unsupported file type"))?;
+            .ok_or_else(|| Error::unsupported_format(path.display().to_string()))?;
+
+        let Some(cache) = &self.cache else {
+            return self.parse_path(path, document_type).await;
+        };
+
+        let hash = content_hash(&fs::read(path).await?);
+        let source_path = path.to_string_lossy();
+        if let Some(result) = cached_result(cache.as_ref(), &hash, &document_type, &source_path).await {
+            return Ok(result);
+        }
+
+        let mut result = self.parse_path(path, document_type).await?;
+        record_content_hash(&mut result, &hash);
+        cache.insert(hash, result.clone()).await;
+        Ok(result)
+    }
+
+    /// Parse several documents, at most `jobs` at a time
+    ///
+    /// Each document is parsed on the blocking thread pool, so CPU-bound
+    /// parsing runs in parallel. Results are in the order of `paths`, and a
+    /// document that fails to parse only fails its own entry. A `jobs` of 0 is
+    /// treated as 1.
+    pub async fn parse_documents(&self, paths: &[PathBuf], jobs: usize) -> Vec<Result<ParseResult>> {
+        let factory = Arc::new(self.clone());
+        let runtime = tokio::runtime::Handle::current();
+        let spawn = |tasks: &mut JoinSet<(usize, Result<ParseResult>)>, (index, path): (usize, PathBuf)| {
+            let (factory, runtime) = (Arc::clone(&factory), runtime.clone());
+            tasks.spawn_blocking(move || (index, runtime.block_on(factory.parse_document(&path))));
+        };
+
+        let mut pending = paths.iter().cloned().enumerate();
+        let mut tasks = JoinSet::new();
+        for job in pending.by_ref().take(jobs.max(1)) {
+            spawn(&mut tasks, job);
+        }
+
+        let mut results: Vec<Option<Result<ParseResult>>> = paths.iter().map(|_| None).collect();
+        while let Some(joined) = tasks.join_next().await {
+            // A panicked parse leaves its entry empty and is reported below
+            if let Ok((index, result)) = joined {
+                results[index] = Some(result);
+            }
+            if let Some(job) = pending.next() {
+                spawn(&mut tasks, job);
+            }
+        }
+
+        results.into_iter()
+            .zip(paths)
+            .map(|(result, path)| {
+                result.unwrap_or_else(|| Err(Error::internal(format!("Parsing {} panicked", path.display()))))
+            })
+            .collect()
+    }
+
+    /// Read a document's metadata with the parser for its type, bypassing the cache
+    pub async fn parse_metadata(&self, path: &Path) -> Result<serde_json::Value> {
+        let document_type = DocumentType::from_extension(path)
+            .ok_or_else(|| Error::unsupported_format(path.display().to_string()))?;
 
+        match document_type {
+            DocumentType::Excel => self.excel_parser.parse_metadata(path).await,
+            DocumentType::Csv => self.csv_parser.parse_metadata(path).await,
+            DocumentType::Word => self.word_parser.parse_metadata(path).await,
+            DocumentType::Markdown => self.markdown_parser.parse_metadata(path).await,
+            DocumentType::Json => Ok(serde_json::json!({})),
+        }
+    }
+
+    /// Parse document from bytes with filename hint
+    pub async fn parse_bytes(&self, data: &[u8], filename: &str) -> Result<ParseResult> {
+        let mut result = self.parse_bytes_cached(data, filename).await?;
+        self.apply_pii_policy(&mut result);
+        Ok(result)
+    }
+
+    /// Parse bytes, reusing a cached result if there is one
+    async fn parse_bytes_cached(&self, data: &[u8], filename: &str) -> Result<ParseResult> {
+        let path = Path::new(filename);
+        let document_type = DocumentType::from_extension(path)
+            .ok_or_else(|| Error::unsupported_format(path.display().to_string()))?;
+
+        let Some(cache) = &self.cache else {
+            return self.parse_data(data, filename, document_type).await;
+        };
+
+        let hash = content_hash(data);
+        if let Some(result) = cached_result(cache.as_ref(), &hash, &document_type, filename).await {
+            return Ok(result);
+        }
+
+        let mut result = self.parse_data(data, filename, document_type).await?;
+        record_content_hash(&mut result, &hash);
+        cache.insert(hash, result.clone()).await;
+        Ok(result)
+    }
+
+    /// Apply the configured PII policy, if any
+    fn apply_pii_policy(&self, result: &mut ParseResult) {
+        if let Some(policy) = &self.pii_policy {
+            policy.apply(result);
+        }
+    }
+
+    /// Parse a file with the parser for its document type, reporting the duration
+    async fn parse_path(&self, path: &Path, document_type: DocumentType) -> Result<ParseResult> {
+        let start = Instant::now();
+        let label = document_type.as_str();
+        let result = self.parse_path_untimed(path, document_type).await;
+        self.observe_parse(label, start);
+        result
+    }
+
+    /// Parse a file with the parser for its document type
+    async fn parse_path_untimed(&self, path: &Path, document_type: DocumentType) -> Result<ParseResult> {
         match document_type {
             DocumentType::Excel => self.excel_parser.parse_file(path).await,
+            DocumentType::Csv => self.csv_parser.parse_file(path).await,
             DocumentType::Word => self.word_parser.parse_file(path).await,
             DocumentType::Markdown => self.markdown_parser.parse_file(path).await,
             DocumentType::Json => {
@@ -125,19 +371,25 @@ impl DocumentParserFactory {
         }
     }
 
-    /// Parse document from bytes with filename hint
-    pub async fn parse_bytes(&self, data: &[u8], filename: &str) -> Result<ParseResult> {
-        let path = Path::new(filename);
-        let document_type = DocumentType::from_extension(path)
-            .ok_or_else(|| Error::document_parsing("Unsupported file type"))?;
+    /// Parse bytes with the parser for their document type, reporting the duration
+    async fn parse_data(&self, data: &[u8], filename: &str, document_type: DocumentType) -> Result<ParseResult> {
+        let start = Instant::now();
+        let label = document_type.as_str();
+        let result = self.parse_data_untimed(data, filename, document_type).await;
+        self.observe_parse(label, start);
+        result
+    }
 
+    /// Parse bytes with the parser for their document type
+    async fn parse_data_untimed(&self, data: &[u8], filename: &str, document_type: DocumentType) -> Result<ParseResult> {
         match document_type {
             DocumentType::Excel => self.excel_parser.parse_bytes(data, filename).await,
+            DocumentType::Csv => self.csv_parser.parse_bytes(data, filename).await,
             DocumentType::Word => self.word_parser.parse_bytes(data, filename).await,
             DocumentType::Markdown => self.markdown_parser.parse_bytes(data, filename).await,
             DocumentType::Json => {
                 let content = String::from_utf8(data.to_vec())
-                    .map_err(|e| Error::document_parsing(format!("Invalid UTF-8: {}", e)))?;
+                    .map_err(|e| Error::encoding(format!("Invalid UTF-8: {}", e)))?;
                 let json: serde_json::Value = serde_json::from_str(&content)?;
                 Ok(ParseResult {
                     document_type,
@@ -151,10 +403,18 @@ impl DocumentParserFactory {
         }
     }
 
+    /// Report a parse that started at `start` to the metrics recorder, if any
+    fn observe_parse(&self, document_type: &str, start: Instant) {
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_document_parse(document_type, start.elapsed());
+        }
+    }
+
     /// Get all supported file extensions
     pub fn supported_extensions(&self) -> Vec<&'static str> {
         let mut extensions = Vec::new();
         extensions.extend(self.excel_parser.supported_extensions());
+        extensions.extend(self.csv_parser.supported_extensions());
         extensions.extend(self.word_parser.supported_extensions());
         extensions.extend(self.markdown_parser.supported_extensions());
         extensions.push("json");
@@ -168,6 +428,31 @@ impl Default for DocumentParserFactory {
     }
 }
 
+/// Cached result for a content hash, re-targeted at the requested source
+///
+/// Entries stored for a different document type (the same bytes under another
+/// extension) are treated as misses.
+async fn cached_result(
+    cache: &dyn ParseCache,
+    hash: &str,
+    document_type: &DocumentType,
+    source_path: &str,
+) -> Option<ParseResult> {
+    let mut result = cache.get(hash).await
+        .filter(|result| &result.document_type == document_type)?;
+
+    debug!("Parse cache hit for {} ({})", source_path, hash);
+    result.source_path = source_path.to_string();
+    Some(result)
+}
+
+/// Record the content hash in the result metadata
+fn record_content_hash(result: &mut ParseResult, hash: &str) {
+    if let Some(metadata) = result.metadata.as_object_mut() {
+        metadata.insert("content_hash".to_string(), serde_json::Value::String(hash.to_string()));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,6 +464,14 @@ mod tests {
             DocumentType::from_extension(&PathBuf::from("test.xlsx")),
             Some(DocumentType::Excel)
         );
+        assert_eq!(
+            DocumentType::from_extension(&PathBuf::from("test.csv")),
+            Some(DocumentType::Csv)
+        );
+        assert_eq!(
+            DocumentType::from_extension(&PathBuf::from("test.tab")),
+            Some(DocumentType::Csv)
+        );
         assert_eq!(
             DocumentType::from_extension(&PathBuf::from("test.docx")),
             Some(DocumentType::Word)
@@ -202,8 +495,106 @@ mod tests {
         let factory = DocumentParserFactory::new();
         let extensions = factory.supported_extensions();
         assert!(extensions.contains(&"xlsx"));
+        assert!(extensions.contains(&"csv"));
         assert!(extensions.contains(&"docx"));
         assert!(extensions.contains(&"md"));
         assert!(extensions.contains(&"json"));
     }
+
+    #[tokio::test]
+    async fn test_parse_failures_have_typed_errors() {
+        let factory = DocumentParserFactory::new();
+
+        let unsupported = factory.parse_bytes(b"%PDF-1.7", "scan.pdf").await;
+        assert!(matches!(unsupported, Err(Error::UnsupportedFormat { format }) if format == "scan.pdf"));
+
+        let corrupt = factory.parse_bytes(b"not a zip archive", "inventory.xlsx").await;
+        assert!(matches!(corrupt, Err(Error::CorruptArchive { .. })));
+
+        let encoding = factory.parse_bytes(&[0x7b, 0xff, 0xfe, 0x7d], "poam.json").await;
+        assert!(matches!(encoding, Err(Error::Encoding { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_parse_documents_isolates_failures_in_input_order() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let valid = dir.path().join("inventory.json");
+        let invalid = dir.path().join("broken.json");
+        std::fs::write(&valid, r#"{"assets": []}"#).unwrap();
+        std::fs::write(&invalid, "{not json").unwrap();
+
+        let factory = DocumentParserFactory::new();
+        let results = factory.parse_documents(&[invalid.clone(), valid.clone(), invalid], 2).await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_err());
+        assert_eq!(results[1].as_ref().unwrap().source_path, valid.to_string_lossy());
+        assert!(results[2].is_err());
+    }
+
+    /// Cache that blocks its thread on every lookup and records the thread
+    struct BlockingCache {
+        threads: std::sync::Mutex<Vec<std::thread::ThreadId>>,
+    }
+
+    #[async_trait]
+    impl ParseCache for BlockingCache {
+        async fn get(&self, _content_hash: &str) -> Option<ParseResult> {
+            self.threads.lock().unwrap().push(std::thread::current().id());
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            None
+        }
+
+        async fn insert(&self, _content_hash: String, _result: ParseResult) {}
+    }
+
+    #[tokio::test]
+    async fn test_parse_documents_parses_in_parallel() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let paths: Vec<PathBuf> = (0..4)
+            .map(|i| {
+                let path = dir.path().join(format!("inventory-{}.json", i));
+                std::fs::write(&path, r#"{"assets": []}"#).unwrap();
+                path
+            })
+            .collect();
+        let cache = Arc::new(BlockingCache { threads: std::sync::Mutex::new(Vec::new()) });
+        let factory = DocumentParserFactory::new().with_cache(cache.clone());
+
+        let start = std::time::Instant::now();
+        let results = factory.parse_documents(&paths, 4).await;
+
+        assert!(results.iter().all(|result| result.is_ok()));
+        // Four 200ms blocking parses overlap instead of running one after another
+        assert!(start.elapsed() < std::time::Duration::from_millis(600));
+        let threads: std::collections::HashSet<_> = cache.threads.lock().unwrap().iter().copied().collect();
+        assert!(threads.len() > 1);
+    }
+
+    /// Recorder keeping the document type of every observed parse
+    #[derive(Debug, Default)]
+    struct RecordingMetrics {
+        parses: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl fedramp_core::MetricsRecorder for RecordingMetrics {
+        fn observe_document_parse(&self, document_type: &str, _duration: std::time::Duration) {
+            self.parses.lock().unwrap().push(document_type.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parses_are_reported_to_metrics_except_cache_hits() {
+        let metrics = Arc::new(RecordingMetrics::default());
+        let factory = DocumentParserFactory::new()
+            .with_cache(Arc::new(crate::InMemoryParseCache::default()))
+            .with_metrics(metrics.clone());
+
+        factory.parse_bytes(br#"{"assets": []}"#, "inventory.json").await.unwrap();
+        factory.parse_bytes(br#"{"assets": []}"#, "inventory.json").await.unwrap();
+        factory.parse_bytes(b"Control ID,Status\nAC-2,Open\n", "poam.csv").await.unwrap();
+        assert!(factory.parse_bytes(b"{not json", "broken.json").await.is_err());
+
+        assert_eq!(*metrics.parses.lock().unwrap(), vec!["json", "csv", "json"]);
+    }
 }
@@ -2,13 +2,20 @@
 //!
 //! This module provides comprehensive date parsing, conversion, and validation
 //! capabilities to ensure OSCAL schema compliance and consistent date handling.
+//!
+//! OSCAL requires timezone-qualified timestamps. Inputs without an offset,
+//! including Excel serials, are read as wall-clock time in the configured
+//! timezone and reported with a `TimezoneAssumed` warning.
 
 use crate::{Error, Result};
-use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc, TimeZone, Datelike, Timelike};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc, TimeZone, Datelike, Timelike};
+use chrono::offset::{LocalResult, Offset};
 use chrono_tz::Tz;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::OnceLock;
 use thiserror::Error;
 
 /// Date parsing errors
@@ -30,6 +37,8 @@ pub enum DateWarning {
     AmbiguousFormat { original: String, assumed_format: String },
     TimezoneAssumed { original: String, assumed_timezone: String },
     YearAssumed { original: String, assumed_year: i32 },
+    /// A two-digit year expanded to more than `MAX_EXPANDED_YEAR_DISTANCE` years from now
+    ExpandedYearOutOfRange { original: String, assumed_year: i32 },
     LowConfidence { original: String, confidence: f64 },
 }
 
@@ -40,6 +49,7 @@ pub struct DateParsingResult {
     pub original_format: String,
     pub confidence: f64,
     pub warnings: Vec<DateWarning>,
+    /// RFC 3339 timestamp with the offset of the source or assumed timezone
     pub iso_string: Option<String>,
 }
 
@@ -61,7 +71,9 @@ pub trait DateParser: Send + Sync {
 /// Timezone configuration
 #[derive(Debug, Clone)]
 pub struct TimezoneConfig {
+    /// Timezone of inputs without an offset
     pub default_timezone: Tz,
+    /// Read date-only inputs as UTC midnight rather than in `default_timezone`
     pub assume_utc_for_dates: bool,
     pub business_timezone: Option<Tz>,
 }
@@ -89,11 +101,14 @@ impl Default for DateFormatPreferences {
         Self {
             prefer_mdy: true,  // US format preference
             prefer_4digit_year: true,
-            century_cutoff: 50,  // 50-99 = 1950-1999, 00-49 = 2000-2049
+            century_cutoff: 70,  // 70-99 = 1970-1999, 00-69 = 2000-2069
         }
     }
 }
 
+/// Years from the current year beyond which an expanded two-digit year is flagged
+pub const MAX_EXPANDED_YEAR_DISTANCE: i32 = 50;
+
 /// Date validation rule types
 #[derive(Debug, Clone, PartialEq)]
 pub enum DateRuleType {
@@ -204,6 +219,25 @@ impl DateConverter {
     
     /// Parse a date string with full result metadata
     pub fn parse_date(&self, input: &str) -> DateParsingResult {
+        self.parse_date_with_timezone(input, None)
+    }
+    
+    /// Convert date to ISO 8601 format
+    pub fn to_iso8601(&self, input: &str) -> Result<String> {
+        self.parse_date(input).iso_string.ok_or_else(|| {
+            Error::document_parsing(format!(
+                "Unable to parse date: {}",
+                input
+            ))
+        })
+    }
+
+    /// Parse date with timezone context
+    ///
+    /// An input without an offset is read in `source_timezone` if given, else
+    /// in the configured default timezone (UTC for date-only inputs when
+    /// `assume_utc_for_dates` is set).
+    pub fn parse_date_with_timezone(&self, input: &str, source_timezone: Option<Tz>) -> DateParsingResult {
         let trimmed_input = input.trim();
         
         if trimmed_input.is_empty() {
@@ -222,7 +256,6 @@ impl DateConverter {
                 match parser.parse(trimmed_input) {
                     Ok(datetime) => {
                         let confidence = parser.confidence(trimmed_input);
-                        let iso_string = datetime.to_rfc3339();
                         
                         let mut warnings = Vec::new();
                         if confidence < 0.8 {
@@ -231,13 +264,45 @@ impl DateConverter {
                                 confidence,
                             });
                         }
+
+                        if super::date_parsers::two_digit_year(trimmed_input).is_some() {
+                            let assumed_year = datetime.year();
+                            warnings.push(DateWarning::YearAssumed {
+                                original: input.to_string(),
+                                assumed_year,
+                            });
+                            if (assumed_year - Utc::now().year()).abs() > MAX_EXPANDED_YEAR_DISTANCE {
+                                warnings.push(DateWarning::ExpandedYearOutOfRange {
+                                    original: input.to_string(),
+                                    assumed_year,
+                                });
+                            }
+                        }
+
+                        let localized = if has_explicit_offset(trimmed_input) {
+                            DateTime::parse_from_rfc3339(trimmed_input).unwrap_or_else(|_| datetime.fixed_offset())
+                        } else {
+                            let timezone = source_timezone.unwrap_or_else(|| {
+                                let date_only = datetime.time() == NaiveTime::MIN && !trimmed_input.contains(':');
+                                if date_only && self.timezone_config.assume_utc_for_dates {
+                                    chrono_tz::UTC
+                                } else {
+                                    self.timezone_config.default_timezone
+                                }
+                            });
+                            warnings.push(DateWarning::TimezoneAssumed {
+                                original: input.to_string(),
+                                assumed_timezone: timezone.name().to_string(),
+                            });
+                            in_timezone(datetime.naive_utc(), timezone)
+                        };
                         
                         return DateParsingResult {
-                            parsed_date: Some(datetime),
+                            parsed_date: Some(localized.with_timezone(&Utc)),
                             original_format: parser.name().to_string(),
                             confidence,
                             warnings,
-                            iso_string: Some(iso_string),
+                            iso_string: Some(localized.to_rfc3339()),
                         };
                     }
                     Err(_) => continue,
@@ -257,42 +322,6 @@ impl DateConverter {
             iso_string: None,
         }
     }
-    
-    /// Convert date to ISO 8601 format
-    pub fn to_iso8601(&self, input: &str) -> Result<String> {
-        let result = self.parse_date(input);
-
-        match result.parsed_date {
-            Some(datetime) => Ok(datetime.to_rfc3339()),
-            None => Err(Error::document_parsing(format!(
-                "Unable to parse date: {}",
-                input
-            ))),
-        }
-    }
-
-    /// Parse date with timezone context
-    pub fn parse_date_with_timezone(&self, input: &str, source_timezone: Option<Tz>) -> DateParsingResult {
-        let mut result = self.parse_date(input);
-
-        if let Some(datetime) = result.parsed_date {
-            // If no timezone was specified in the original date and we have a source timezone
-            if let Some(tz) = source_timezone {
-                // Check if the original parsing included timezone info
-                if !input.contains('Z') && !input.contains('+') && !input.contains('-') {
-                    // Assume the date is in the source timezone and convert to UTC
-                    let local_dt = tz.from_utc_datetime(&datetime.naive_utc());
-                    result.parsed_date = Some(local_dt.with_timezone(&Utc));
-                    result.warnings.push(DateWarning::TimezoneAssumed {
-                        original: input.to_string(),
-                        assumed_timezone: tz.to_string(),
-                    });
-                }
-            }
-        }
-
-        result
-    }
 
     /// Convert datetime from one timezone to another
     pub fn convert_timezone(&self, datetime: &DateTime<Utc>, target_timezone: Tz) -> DateTime<Tz> {
@@ -500,6 +529,30 @@ impl DateConverter {
     }
 }
 
+/// Whether a date string carries its own UTC offset or zone designator
+fn has_explicit_offset(input: &str) -> bool {
+    static OFFSET_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = OFFSET_REGEX.get_or_init(|| {
+        Regex::new(r"(?i)(\d{1,2}:\d{2}(:\d{2}(\.\d+)?)?\s*([+-]\d{2}:?\d{2}|Z)|\s(UTC|GMT|Z))$").unwrap()
+    });
+    regex.is_match(input)
+}
+
+/// Wall-clock time in a timezone
+///
+/// Ambiguous times at the end of daylight saving take the earlier instant;
+/// times skipped at its start move forward by the gap.
+fn in_timezone(wall_clock: NaiveDateTime, timezone: Tz) -> DateTime<FixedOffset> {
+    let localized = match timezone.from_local_datetime(&wall_clock) {
+        LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => datetime,
+        LocalResult::None => {
+            let before = timezone.offset_from_utc_datetime(&(wall_clock - chrono::Duration::days(1))).fix();
+            timezone.from_utc_datetime(&(wall_clock - before))
+        }
+    };
+    localized.fixed_offset()
+}
+
 impl Default for DateConverter {
     fn default() -> Self {
         Self::new()
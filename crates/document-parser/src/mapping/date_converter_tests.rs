@@ -372,4 +372,53 @@ mod tests {
         // Should be very fast with format caching
         assert!(duration.as_millis() < 100, "Optimized parser too slow: {}ms", duration.as_millis());
     }
+
+    #[test]
+    fn test_naive_dates_use_configured_timezone() {
+        let converter = DateConverter::with_config(
+            TimezoneConfig {
+                default_timezone: chrono_tz::America::New_York,
+                assume_utc_for_dates: false,
+                business_timezone: None,
+            },
+            DateFormatPreferences::default(),
+        );
+
+        let result = converter.parse_date("2024-03-15");
+        assert_eq!(result.iso_string.as_deref(), Some("2024-03-15T00:00:00-04:00"));
+        assert_eq!(result.parsed_date, Some(Utc.with_ymd_and_hms(2024, 3, 15, 4, 0, 0).unwrap()));
+        assert!(result.warnings.iter().any(|w| matches!(
+            w,
+            DateWarning::TimezoneAssumed { assumed_timezone, .. } if assumed_timezone == "America/New_York"
+        )));
+
+        // Excel serials are naive too
+        assert_eq!(converter.to_iso8601("45366").unwrap(), "2024-03-15T00:00:00-04:00");
+        assert_eq!(converter.to_iso8601("2024-01-15T10:30:00").unwrap(), "2024-01-15T10:30:00-05:00");
+
+        // Explicit offsets are kept and nothing is assumed
+        let result = converter.parse_date("2024-03-15T10:00:00+02:00");
+        assert_eq!(result.iso_string.as_deref(), Some("2024-03-15T10:00:00+02:00"));
+        assert!(!result.warnings.iter().any(|w| matches!(w, DateWarning::TimezoneAssumed { .. })));
+    }
+
+    #[test]
+    fn test_two_digit_year_expansion_is_reported() {
+        let converter = DateConverter::new();
+
+        let result = converter.parse_date("12/25/23");
+        assert_eq!(result.parsed_date.unwrap().year(), 2023);
+        assert!(result.warnings.iter().any(|w| matches!(w, DateWarning::YearAssumed { assumed_year: 2023, .. })));
+        assert!(!result.warnings.iter().any(|w| matches!(w, DateWarning::ExpandedYearOutOfRange { .. })));
+        assert_eq!(converter.parse_date("12/25/70").parsed_date.unwrap().year(), 1970);
+
+        // Everything in the 1900s under a zero cutoff
+        let converter = DateConverter::with_config(
+            TimezoneConfig::default(),
+            DateFormatPreferences { century_cutoff: 0, ..DateFormatPreferences::default() },
+        );
+        let result = converter.parse_date("12/25/23 10:30");
+        assert_eq!(result.parsed_date.unwrap().year(), 1923);
+        assert!(result.warnings.iter().any(|w| matches!(w, DateWarning::ExpandedYearOutOfRange { assumed_year: 1923, .. })));
+    }
 }
@@ -9,6 +9,42 @@ use regex::Regex;
 use std::sync::OnceLock;
 use std::collections::HashMap;
 
+/// Expand a two-digit year: years below `century_cutoff` are 20xx, the rest 19xx
+pub fn expand_two_digit_year(two_digit_year: i32, century_cutoff: i32) -> i32 {
+    if two_digit_year < century_cutoff {
+        2000 + two_digit_year
+    } else {
+        1900 + two_digit_year
+    }
+}
+
+/// Two-digit year of a numeric day/month/year date such as `12/25/23`
+pub fn two_digit_year(input: &str) -> Option<i32> {
+    static TWO_DIGIT_YEAR_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = TWO_DIGIT_YEAR_REGEX.get_or_init(|| {
+        Regex::new(r"^\d{1,2}[/-]\d{1,2}[/-](\d{2})(\s|$)").unwrap()
+    });
+    regex.captures(input.trim()).and_then(|captures| captures[1].parse().ok())
+}
+
+/// Re-expand the year of a date parsed with `%y` using the configured cutoff
+///
+/// chrono expands `%y` with a fixed pivot of 69, so the parsed year is reduced
+/// back to two digits first.
+fn apply_century_cutoff(
+    datetime: NaiveDateTime,
+    format: &str,
+    preferences: &DateFormatPreferences,
+    input: &str,
+) -> std::result::Result<NaiveDateTime, DateParseError> {
+    if !format.contains("%y") {
+        return Ok(datetime);
+    }
+    let year = expand_two_digit_year(datetime.year().rem_euclid(100), preferences.century_cutoff);
+    datetime.with_year(year)
+        .ok_or_else(|| DateParseError::OutOfRange(format!("Invalid date: {}", input)))
+}
+
 /// ISO 8601 date parser (highest priority)
 pub struct Iso8601Parser;
 
@@ -146,26 +182,12 @@ impl DateParser for UsDateParser {
         
         for format in formats {
             if let Ok(dt) = NaiveDateTime::parse_from_str(trimmed, format) {
-                return Ok(dt.and_utc());
+                return Ok(apply_century_cutoff(dt, format, &self.preferences, input)?.and_utc());
             }
 
             if let Ok(date) = NaiveDate::parse_from_str(trimmed, format) {
-                let year = if format.contains("%y") {
-                    // For 2-digit years, chrono returns the actual 2-digit value
-                    let two_digit_year = date.year();
-                    if two_digit_year >= self.preferences.century_cutoff {
-                        1900 + two_digit_year
-                    } else {
-                        2000 + two_digit_year
-                    }
-                } else {
-                    date.year()
-                };
-
-                let adjusted_date = NaiveDate::from_ymd_opt(year, date.month(), date.day())
-                    .ok_or_else(|| DateParseError::OutOfRange(format!("Invalid date: {}", input)))?;
-
-                return Ok(adjusted_date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+                let dt = date.and_hms_opt(0, 0, 0).unwrap();
+                return Ok(apply_century_cutoff(dt, format, &self.preferences, input)?.and_utc());
             }
         }
         
@@ -239,26 +261,12 @@ impl DateParser for EuropeanDateParser {
         
         for format in formats {
             if let Ok(dt) = NaiveDateTime::parse_from_str(trimmed, format) {
-                return Ok(dt.and_utc());
+                return Ok(apply_century_cutoff(dt, format, &self.preferences, input)?.and_utc());
             }
             
             if let Ok(date) = NaiveDate::parse_from_str(trimmed, format) {
-                let year = if format.contains("%y") {
-                    // For 2-digit years, chrono returns the actual 2-digit value
-                    let two_digit_year = date.year();
-                    if two_digit_year >= self.preferences.century_cutoff {
-                        1900 + two_digit_year
-                    } else {
-                        2000 + two_digit_year
-                    }
-                } else {
-                    date.year()
-                };
-
-                let adjusted_date = NaiveDate::from_ymd_opt(year, date.month(), date.day())
-                    .ok_or_else(|| DateParseError::OutOfRange(format!("Invalid date: {}", input)))?;
-
-                return Ok(adjusted_date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+                let dt = date.and_hms_opt(0, 0, 0).unwrap();
+                return Ok(apply_century_cutoff(dt, format, &self.preferences, input)?.and_utc());
             }
         }
         
@@ -430,10 +438,11 @@ impl DateParser for OptimizedDateParser {
         if let Ok(cache) = self.format_cache.lock() {
             if let Some(cached_format) = cache.get(trimmed) {
                 if let Ok(dt) = NaiveDateTime::parse_from_str(trimmed, cached_format) {
-                    return Ok(dt.and_utc());
+                    return Ok(apply_century_cutoff(dt, cached_format, &self.preferences, input)?.and_utc());
                 }
                 if let Ok(date) = NaiveDate::parse_from_str(trimmed, cached_format) {
-                    return Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+                    let dt = date.and_hms_opt(0, 0, 0).unwrap();
+                    return Ok(apply_century_cutoff(dt, cached_format, &self.preferences, input)?.and_utc());
                 }
             }
         }
@@ -445,30 +454,18 @@ impl DateParser for OptimizedDateParser {
                 if let Ok(mut cache) = self.format_cache.lock() {
                     cache.insert(trimmed.to_string(), likely_format.to_string());
                 }
-                return Ok(dt.and_utc());
+                return Ok(apply_century_cutoff(dt, likely_format, &self.preferences, input)?.and_utc());
             }
 
             if let Ok(date) = NaiveDate::parse_from_str(trimmed, likely_format) {
-                let year = if likely_format.contains("%y") {
-                    let two_digit_year = date.year();
-                    if two_digit_year >= self.preferences.century_cutoff {
-                        1900 + two_digit_year
-                    } else {
-                        2000 + two_digit_year
-                    }
-                } else {
-                    date.year()
-                };
-
-                let adjusted_date = NaiveDate::from_ymd_opt(year, date.month(), date.day())
-                    .ok_or_else(|| DateParseError::OutOfRange(format!("Invalid date: {}", input)))?;
+                let adjusted = apply_century_cutoff(date.and_hms_opt(0, 0, 0).unwrap(), likely_format, &self.preferences, input)?;
 
                 // Cache successful format
                 if let Ok(mut cache) = self.format_cache.lock() {
                     cache.insert(trimmed.to_string(), likely_format.to_string());
                 }
 
-                return Ok(adjusted_date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+                return Ok(adjusted.and_utc());
             }
         }
 
@@ -532,7 +529,8 @@ impl DateParser for NaturalLanguageParser {
     }
     
     fn parse(&self, input: &str) -> std::result::Result<DateTime<Utc>, DateParseError> {
-        match dateparser::parse(input) {
+        // Read inputs without an offset as UTC wall-clock time, like the other parsers
+        match dateparser::parse_with_timezone(input, &Utc) {
             Ok(dt) => Ok(dt.with_timezone(&Utc)),
             Err(e) => Err(DateParseError::InvalidFormat(format!("Natural language parsing failed: {}", e))),
         }
@@ -15,6 +15,7 @@ use crate::mapping::inventory::InventoryMappings;
 use crate::mapping::poam::{PoamMappings, PoamValidationRules};
 use crate::mapping::ssp::SspSections;
 
+use super::normalization::normalize_field_name;
 use super::types::{
     OptimizedMappingLookup, MappingEntry, MappingSourceType, FuzzyCandidate,
     ValidationRule, ValidationType, MappingResult, MappingStatistics
@@ -227,13 +228,11 @@ impl OptimizedMappingLookup {
     }
 
     /// Normalize column name for consistent matching
+    ///
+    /// The canonical field name with word breaks removed, so `POA&M ID` and
+    /// `poamid` share a lookup key.
     pub fn normalize_column_name(name: &str) -> String {
-        name.to_lowercase()
-            .replace([' ', '_', '-', '.'], "")
-            .replace("&", "and")
-            .chars()
-            .filter(|c| c.is_alphanumeric())
-            .collect()
+        normalize_field_name(name).replace(' ', "")
     }
 
     /// Parse validation type from string
@@ -256,7 +255,7 @@ impl OptimizedMappingLookup {
         self.exact_matches.get(&normalized)
     }
 
-    /// Find fuzzy matches for column name
+    /// Find fuzzy matches for column name, best first
     pub fn find_fuzzy_matches(&mut self, column_name: &str, min_confidence: f64) -> Vec<MappingResult> {
         let fuzzy_results = self.fuzzy_matcher.find_matches(column_name, &self.fuzzy_targets);
         
@@ -276,6 +275,8 @@ impl OptimizedMappingLookup {
                         required: candidate.required,
                         validation: None, // TODO: Get validation from rules
                         exact_match: false,
+                        low_confidence: false,
+                        algorithm_scores: result.algorithm_scores.clone(),
                     })
             })
             .collect()
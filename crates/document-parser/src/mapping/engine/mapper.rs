@@ -4,6 +4,7 @@
 //! This module contains the main ColumnMapper implementation that provides
 //! the primary interface for column mapping operations.
 
+use fedramp_core::models::DocumentType;
 use fedramp_core::{Result, Error};
 use std::collections::HashMap;
 use std::path::Path;
@@ -11,8 +12,10 @@ use tracing::{debug, info, warn};
 
 use crate::mapping::config::ColumnMapping;
 use crate::mapping::loader::MappingConfigurationLoader;
+use crate::validation::OverrideContext;
 
-use super::types::{ColumnMapper, OptimizedMappingLookup, MappingResult, MappingEngineConfig};
+use super::normalization::normalize_field_name_with;
+use super::types::{ColumnMapper, OptimizedMappingLookup, MappingResult, MappingEngineConfig, NormalizationRule};
 use super::lookup::*;
 
 impl ColumnMapper {
@@ -24,6 +27,8 @@ impl ColumnMapper {
             optimized_lookup: None,
             min_confidence: 0.7,
             config_loader: None,
+            normalization_rules: Vec::new(),
+            document_type_thresholds: HashMap::new(),
         }
     }
 
@@ -35,6 +40,8 @@ impl ColumnMapper {
             optimized_lookup: None,
             min_confidence,
             config_loader: None,
+            normalization_rules: Vec::new(),
+            document_type_thresholds: HashMap::new(),
         }
     }
 
@@ -45,17 +52,25 @@ impl ColumnMapper {
             optimized_lookup: None,
             min_confidence: 0.7,
             config_loader: Some(MappingConfigurationLoader::new(base_dir)),
+            normalization_rules: Vec::new(),
+            document_type_thresholds: HashMap::new(),
         }
     }
 
     /// Create a new column mapper with custom configuration
     pub fn with_config(config: MappingEngineConfig) -> Self {
-        Self {
+        let mut mapper = Self {
             mappings: HashMap::new(),
             optimized_lookup: None,
             min_confidence: config.min_confidence,
             config_loader: None,
+            normalization_rules: config.normalization_rules,
+            document_type_thresholds: HashMap::new(),
+        };
+        for (document_type, min_confidence) in config.document_type_thresholds {
+            mapper.set_document_type_threshold(document_type, min_confidence);
         }
+        mapper
     }
 
     /// Load mapping configurations from JSON files
@@ -102,63 +117,100 @@ impl ColumnMapper {
 
     /// Map column headers to target fields
     pub fn map_columns(&mut self, headers: &[String]) -> Result<Vec<MappingResult>> {
-        let mut results = Vec::new();
-
-        for header in headers {
-            debug!("Mapping column: {}", header);
-
-            // Try optimized lookup first
-            if let Some(lookup) = &mut self.optimized_lookup {
-                // Try exact match first
-                if let Some(entry) = lookup.find_exact_match(header) {
-                    results.push(MappingResult {
-                        source_column: header.clone(),
-                        target_field: entry.target_field.clone(),
-                        confidence: 1.0, // Exact match
-                        source_type: entry.source_type.clone(),
-                        required: entry.required,
-                        validation: entry.validation.clone(),
-                        exact_match: true,
-                    });
-                    continue;
-                }
+        let min_confidence = self.min_confidence;
+        Ok(headers.iter().filter_map(|header| self.map_column(header, min_confidence)).collect())
+    }
 
-                // Try fuzzy matching
-                let fuzzy_matches = lookup.find_fuzzy_matches(header, self.min_confidence);
-                if !fuzzy_matches.is_empty() {
-                    // Take the best match
-                    if let Some(best_match) = fuzzy_matches.into_iter().max_by(|a, b| {
-                        a.confidence.partial_cmp(&b.confidence).unwrap_or(std::cmp::Ordering::Equal)
-                    }) {
-                        results.push(best_match);
-                        continue;
-                    }
-                }
-            }
+    /// Map column headers of a document, using its document type's threshold
+    pub fn map_columns_in_context(&mut self, headers: &[String], context: &OverrideContext) -> Result<Vec<MappingResult>> {
+        Ok(headers.iter().filter_map(|header| self.resolve_mapping(header, context)).collect())
+    }
+
+    /// Map one column header of a document, using its document type's threshold
+    ///
+    /// A fuzzy match accepted only because the document type's threshold is
+    /// below the global one is marked `low_confidence` for review. Contexts
+    /// whose document type is not a `DocumentType` use the global threshold.
+    pub fn resolve_mapping(&mut self, header: &str, context: &OverrideContext) -> Option<MappingResult> {
+        let min_confidence = match context.document_type.parse::<DocumentType>() {
+            Ok(document_type) => self.confidence_threshold_for(document_type),
+            Err(_) => self.min_confidence,
+        };
+        self.map_column(header, min_confidence)
+    }
+
+    /// Fuzzy matching threshold for a document type, or the global one
+    pub fn confidence_threshold_for(&self, document_type: DocumentType) -> f64 {
+        self.document_type_thresholds
+            .get(&document_type)
+            .copied()
+            .unwrap_or(self.min_confidence)
+    }
+
+    /// Set the fuzzy matching threshold for a document type
+    pub fn set_document_type_threshold(&mut self, document_type: DocumentType, min_confidence: f64) {
+        self.document_type_thresholds.insert(document_type, min_confidence.clamp(0.0, 1.0));
+    }
 
-            // Fall back to legacy mapping if no optimized lookup or no match found
-            if let Some(mapping) = self.mappings.get(header) {
-                results.push(MappingResult {
-                    source_column: header.clone(),
-                    target_field: mapping.target_field.clone(),
-                    confidence: 1.0, // Exact match from legacy mapping
-                    source_type: super::types::MappingSourceType::Custom,
-                    required: mapping.required,
-                    validation: mapping.data_type.clone(),
+    /// Map a single column header, accepting fuzzy matches at `min_confidence`
+    fn map_column(&mut self, header: &str, min_confidence: f64) -> Option<MappingResult> {
+        debug!("Mapping column: {}", header);
+
+        // Try optimized lookup first
+        if let Some(lookup) = &mut self.optimized_lookup {
+            let lookup_name = Self::apply_normalization_rules(&self.normalization_rules, header);
+
+            // Try exact match first
+            if let Some(entry) = lookup.find_exact_match(&lookup_name) {
+                return Some(MappingResult {
+                    source_column: header.to_string(),
+                    target_field: entry.target_field.clone(),
+                    confidence: 1.0, // Exact match
+                    source_type: entry.source_type.clone(),
+                    required: entry.required,
+                    validation: entry.validation.clone(),
                     exact_match: true,
+                    low_confidence: false,
+                    algorithm_scores: HashMap::new(),
                 });
-            } else {
-                debug!("No mapping found for column: {}", header);
+            }
+
+            // Try fuzzy matching
+            // Matches come best first with ties already broken deterministically
+            if let Some(mut best_match) = lookup.find_fuzzy_matches(&lookup_name, min_confidence).into_iter().next() {
+                best_match.source_column = header.to_string();
+                best_match.low_confidence = best_match.confidence < self.min_confidence;
+                return Some(best_match);
             }
         }
 
-        Ok(results)
+        // Fall back to legacy mapping if no optimized lookup or no match found
+        if let Some(mapping) = self.mappings.get(header) {
+            Some(MappingResult {
+                source_column: header.to_string(),
+                target_field: mapping.target_field.clone(),
+                confidence: 1.0, // Exact match from legacy mapping
+                source_type: super::types::MappingSourceType::Custom,
+                required: mapping.required,
+                validation: mapping.data_type.clone(),
+                exact_match: true,
+                low_confidence: false,
+                algorithm_scores: HashMap::new(),
+            })
+        } else {
+            debug!("No mapping found for column: {}", header);
+            None
+        }
     }
 
     /// Get mapping suggestions for a column name
     pub fn get_mapping_suggestions(&mut self, column_name: &str, max_suggestions: usize) -> Result<Vec<MappingResult>> {
         if let Some(lookup) = &mut self.optimized_lookup {
-            let mut suggestions = lookup.find_fuzzy_matches(column_name, 0.3); // Lower threshold for suggestions
+            let lookup_name = Self::apply_normalization_rules(&self.normalization_rules, column_name);
+            let mut suggestions = lookup.find_fuzzy_matches(&lookup_name, 0.3); // Lower threshold for suggestions
+            for suggestion in &mut suggestions {
+                suggestion.source_column = column_name.to_string();
+            }
             
             // Sort by confidence (highest first)
             suggestions.sort_by(|a, b| b.confidence.partial_cmp(&a.confidence).unwrap_or(std::cmp::Ordering::Equal));
@@ -172,6 +224,18 @@ impl ColumnMapper {
         }
     }
 
+    /// Header as passed to the lookup, after any custom normalization rules
+    ///
+    /// Without custom rules the header is passed unchanged; the lookup
+    /// applies the canonical normalization itself.
+    fn apply_normalization_rules(rules: &[NormalizationRule], header: &str) -> String {
+        if rules.is_empty() {
+            header.to_string()
+        } else {
+            normalize_field_name_with(header, rules)
+        }
+    }
+
     /// Validate mapped data against validation rules
     pub fn validate_mapped_data(&self, field_name: &str, value: &str) -> Result<bool> {
         if let Some(lookup) = &self.optimized_lookup {
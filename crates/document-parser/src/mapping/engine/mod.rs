@@ -8,12 +8,14 @@
 //! - `types`: Core type definitions and data structures
 //! - `lookup`: Optimized lookup functionality for fast column mapping
 //! - `mapper`: Main ColumnMapper implementation
+//! - `normalization`: Canonical field-name normalization shared by all mappers
 //! - `tests`: Comprehensive test suite
 
 // Module declarations
 pub mod types;
 pub mod lookup;
 pub mod mapper;
+pub mod normalization;
 
 #[cfg(test)]
 pub mod tests;
@@ -36,3 +38,4 @@ pub use types::{
 // Re-export main functionality
 pub use mapper::*;
 pub use lookup::*;
+pub use normalization::*;
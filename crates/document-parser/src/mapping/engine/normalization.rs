@@ -0,0 +1,73 @@
+//! Canonical field-name normalization shared by all mappers
+//! Modified: 2025-01-22
+//!
+//! Column headers are compared through [`normalize_field_name`] wherever they
+//! are matched, so the same header maps the same way whether it came from an
+//! Excel worksheet, a Word table or a CSV file. The canonical form is
+//! lowercase words separated by single spaces: `POA&M ID`, `poam id` and
+//! `POAM_ID` all become `poam id`.
+
+use fedramp_core::{Result, Error};
+use regex::Regex;
+use std::sync::OnceLock;
+
+use super::types::NormalizationRule;
+
+impl NormalizationRule {
+    /// Create a rule replacing every match of `pattern`
+    ///
+    /// The replacement may refer to capture groups as `$1`.
+    pub fn new(pattern: &str, replacement: impl Into<String>, description: impl Into<String>) -> Result<Self> {
+        let pattern = Regex::new(pattern)
+            .map_err(|e| Error::configuration(format!("Invalid normalization pattern '{}': {}", pattern, e)))?;
+        Ok(Self {
+            pattern,
+            replacement: replacement.into(),
+            description: description.into(),
+        })
+    }
+
+    /// Apply the rule to text
+    pub fn apply(&self, text: &str) -> String {
+        self.pattern.replace_all(text, self.replacement.as_str()).into_owned()
+    }
+}
+
+/// Rules every field name goes through after lowercasing, in order
+///
+/// 1. An ampersand inside a word is dropped, so `poa&m` reads as `poam`.
+/// 2. Any other ampersand is spelled out as `and`.
+/// 3. Runs of whitespace, punctuation and underscores become a single space.
+pub fn canonical_normalization_rules() -> &'static [NormalizationRule] {
+    static RULES: OnceLock<Vec<NormalizationRule>> = OnceLock::new();
+    RULES.get_or_init(|| {
+        [
+            (r"(\w)&(\w)", "$1$2", "Join ampersand abbreviations such as POA&M"),
+            (r"&", " and ", "Spell out standalone ampersands"),
+            (r"[^\p{L}\p{N}]+", " ", "Treat whitespace, punctuation and underscores as word breaks"),
+        ]
+        .into_iter()
+        .map(|(pattern, replacement, description)| {
+            NormalizationRule::new(pattern, replacement, description).expect("canonical normalization rule is valid")
+        })
+        .collect()
+    })
+}
+
+/// Canonical form of a column header or field name
+pub fn normalize_field_name(name: &str) -> String {
+    normalize_field_name_with(name, &[])
+}
+
+/// Canonical form of a field name with additional rules
+///
+/// `custom_rules` run on the lowercased name before the canonical rules, e.g.
+/// to expand a template-specific abbreviation.
+pub fn normalize_field_name_with(name: &str, custom_rules: &[NormalizationRule]) -> String {
+    custom_rules
+        .iter()
+        .chain(canonical_normalization_rules())
+        .fold(name.to_lowercase(), |text, rule| rule.apply(&text))
+        .trim()
+        .to_string()
+}
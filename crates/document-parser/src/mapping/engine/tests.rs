@@ -236,6 +236,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_poam_field_name_variants_share_canonical_key() {
+        for variant in ["POA&M ID", "poam id", "POAM_ID", "  POA&M-ID. "] {
+            assert_eq!(normalize_field_name(variant), "poam id", "variant {:?}", variant);
+        }
+        assert_eq!(normalize_field_name("Risk & Impact"), "risk and impact");
+
+        let custom = [NormalizationRule::new(r"\bplan of action and milestones\b", "poam", "Abbreviate POA&M").unwrap()];
+        assert_eq!(normalize_field_name_with("Plan of Action and Milestones ID", &custom), "poam id");
+        assert!(NormalizationRule::new("(", "", "Invalid").is_err());
+    }
+
     #[test]
     fn test_mapping_result_quality_score() {
         use super::types::{MappingResult, MappingSourceType};
@@ -255,6 +267,45 @@ mod tests {
         assert!(quality <= 1.0);
     }
 
+    #[tokio::test]
+    async fn test_document_type_threshold_overrides_global_threshold() {
+        use crate::validation::OverrideContext;
+        use fedramp_core::models::DocumentType;
+        use std::collections::HashMap;
+
+        let temp_dir = create_test_mappings_dir().await.unwrap();
+        let config = MappingEngineConfig {
+            min_confidence: 0.7,
+            document_type_thresholds: HashMap::from([
+                (DocumentType::IntegratedInventoryWorkbook, 0.6),
+                (DocumentType::PlanOfActionMilestones, 0.8),
+            ]),
+            ..MappingEngineConfig::default()
+        };
+        let mut mapper = ColumnMapper::with_config(config);
+        mapper.config_loader = Some(MappingConfigurationLoader::new(temp_dir.path()));
+        mapper.load_configurations().await.unwrap();
+
+        // "Asset Identifier" scores between the inventory and global thresholds
+        let inventory = OverrideContext::new("Inventory".to_string());
+        let poam = OverrideContext::new("poam".to_string());
+        let result = mapper.resolve_mapping("Asset Identifier", &inventory).unwrap();
+        assert_eq!(result.target_field, "uuid");
+        assert!(result.confidence < 0.7);
+        assert!(result.low_confidence);
+        assert!(mapper.resolve_mapping("Asset Identifier", &poam).is_none());
+        assert!(mapper.map_columns(&["Asset Identifier".to_string()]).unwrap().is_empty());
+
+        // Exact matches are never low confidence
+        let exact = mapper.resolve_mapping("Asset ID", &poam).unwrap();
+        assert!(exact.exact_match && !exact.low_confidence);
+
+        // Document types without an override use the global threshold
+        assert_eq!(mapper.confidence_threshold_for(DocumentType::SystemSecurityPlan), 0.7);
+        let unknown = OverrideContext::new("invntory".to_string());
+        assert!(mapper.resolve_mapping("Asset Identifier", &unknown).is_none());
+    }
+
     #[test]
     fn test_mapping_source_type_priority() {
         use super::types::MappingSourceType;
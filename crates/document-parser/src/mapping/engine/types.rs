@@ -7,6 +7,7 @@
 use std::collections::{HashMap, HashSet};
 use regex::Regex;
 use crate::fuzzy::FuzzyMatcher;
+use fedramp_core::models::DocumentType;
 
 /// Optimized lookup structures for fast column mapping
 pub struct OptimizedMappingLookup {
@@ -92,6 +93,10 @@ pub struct MappingResult {
     pub validation: Option<String>,
     /// Whether this was an exact match
     pub exact_match: bool,
+    /// Whether the match only passed a document-type threshold below the global one
+    pub low_confidence: bool,
+    /// Score of each fuzzy matching algorithm behind `confidence`; empty for exact matches
+    pub algorithm_scores: HashMap<String, f64>,
 }
 
 /// Column mapper for detecting and mapping document columns
@@ -104,6 +109,10 @@ pub struct ColumnMapper {
     pub min_confidence: f64,
     /// Configuration loader
     pub config_loader: Option<crate::mapping::loader::MappingConfigurationLoader>,
+    /// Custom normalization rules applied to headers before lookup
+    pub normalization_rules: Vec<NormalizationRule>,
+    /// Fuzzy matching thresholds overriding `min_confidence`, by document type
+    pub document_type_thresholds: HashMap<DocumentType, f64>,
 }
 
 /// Statistics about the mapping lookup structures
@@ -129,6 +138,9 @@ pub struct MappingEngineConfig {
     pub normalize_column_names: bool,
     /// Custom normalization rules
     pub normalization_rules: Vec<NormalizationRule>,
+    /// Fuzzy matching thresholds overriding `min_confidence` per document type,
+    /// e.g. a lower bar for inventory workbooks with messier headers
+    pub document_type_thresholds: HashMap<DocumentType, f64>,
 }
 
 /// Rule for normalizing column names
@@ -147,6 +159,7 @@ impl Default for MappingEngineConfig {
             enable_caching: true,
             normalize_column_names: true,
             normalization_rules: Vec::new(),
+            document_type_thresholds: HashMap::new(),
         }
     }
 }
@@ -218,6 +231,8 @@ impl MappingResult {
             required,
             validation,
             exact_match,
+            low_confidence: false,
+            algorithm_scores: HashMap::new(),
         }
     }
 
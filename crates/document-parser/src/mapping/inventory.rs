@@ -9,6 +9,7 @@ use crate::mapping::config::{
     ValidationRules, ComponentGrouping, ComponentTypeMapping, 
     SecurityMappings, ControlInheritance
 };
+use crate::mapping::pii::PiiClassification;
 
 /// Inventory mappings configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,4 +37,7 @@ pub struct InventoryColumnMapping {
     pub field: String,
     pub required: bool,
     pub validation: Option<String>,
+    /// Kind of personal data the column holds, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pii: Option<PiiClassification>,
 }
@@ -8,7 +8,7 @@
 use super::types::*;
 use fedramp_core::{Result, Error};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 use crate::mapping::config::MappingConfiguration;
 
@@ -51,13 +51,7 @@ impl MappingConfigurationLoader {
 
     /// Update file modification times cache
     pub async fn update_file_mtimes(&self) -> Result<()> {
-        let file_paths = vec![
-            self.base_dir.join("mappings").join("inventory_mappings.json"),
-            self.base_dir.join("mappings").join("poam_mappings.json"),
-            self.base_dir.join("mappings").join("ssp_sections.json"),
-            self.base_dir.join("schema").join("_controls.json"),
-            self.base_dir.join("schema").join("_document.json"),
-        ];
+        let file_paths = self.config_file_paths();
 
         let mut mtimes = self.file_mtimes.write().unwrap();
 
@@ -98,6 +92,26 @@ impl MappingConfigurationLoader {
         debug!("Cleared file modification times cache");
     }
 
+    /// Metadata of a configuration file as last read, if it has been read
+    pub fn get_file_metadata(&self, path: &Path) -> Option<FileMetadata> {
+        let metadata = self.file_metadata.read().unwrap();
+        metadata.get(path).cloned()
+    }
+
+    /// Record the metadata of a configuration file that was just read
+    pub(crate) fn record_file_metadata(&self, file_metadata: FileMetadata) {
+        let mut metadata = self.file_metadata.write().unwrap();
+        metadata.insert(file_metadata.path.clone(), file_metadata);
+    }
+
+    /// Forget a configuration file's metadata, so its next read counts as a change
+    pub fn forget_file_metadata(&self, path: &Path) {
+        let mut metadata = self.file_metadata.write().unwrap();
+        if metadata.remove(path).is_some() {
+            debug!("Forgot metadata for {}", path.display());
+        }
+    }
+
     /// Check if backup configuration exists
     pub fn has_backup(&self) -> bool {
         let backup = self.config_backup.read().unwrap();
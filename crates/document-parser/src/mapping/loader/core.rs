@@ -7,7 +7,8 @@
 
 use super::types::*;
 use fedramp_core::{Result, Error};
-use std::path::Path;
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use std::collections::HashMap;
 use tokio::fs;
@@ -18,6 +19,49 @@ use crate::mapping::poam::PoamMappings;
 use crate::mapping::ssp::SspSections;
 use crate::mapping::control_document::{ControlMappings, DocumentStructures};
 
+/// Extensions of configuration files, in lookup order
+pub const CONFIG_FILE_EXTENSIONS: [&str; 3] = ["json", "yaml", "yml"];
+
+/// Directory and file stem of each configuration file
+pub(crate) const CONFIG_FILES: [(&str, &str); 5] = [
+    ("mappings", "inventory_mappings"),
+    ("mappings", "poam_mappings"),
+    ("mappings", "ssp_sections"),
+    ("schema", "_controls"),
+    ("schema", "_document"),
+];
+
+/// Whether a configuration file is YAML, judged by its extension
+pub fn is_yaml_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("yaml") || extension.eq_ignore_ascii_case("yml"))
+}
+
+/// Deserialize configuration file text as JSON or YAML by the file's extension
+pub(crate) fn parse_config_content<T: DeserializeOwned>(file_path: &Path, content: &str, description: &str) -> Result<T> {
+    if is_yaml_file(file_path) {
+        serde_yaml::from_str(content).map_err(|e| {
+            Error::document_parsing(format!(
+                "Failed to parse {} YAML from {}: {}",
+                description,
+                file_path.display(),
+                e
+            ))
+        })
+    } else {
+        serde_json::from_str(content).map_err(|e| {
+            Error::document_parsing(format!(
+                "Failed to parse {} JSON from {}: {} at line {}",
+                description,
+                file_path.display(),
+                e,
+                e.line()
+            ))
+        })
+    }
+}
+
 impl MappingConfigurationLoader {
     /// Create a new configuration loader
     pub fn new<P: AsRef<Path>>(base_dir: P) -> Self {
@@ -26,8 +70,10 @@ impl MappingConfigurationLoader {
             cached_config: Arc::new(RwLock::new(None)),
             watcher: None,
             reload_tx: None,
+            change_events: None,
             config_backup: Arc::new(RwLock::new(None)),
             file_mtimes: Arc::new(RwLock::new(HashMap::new())),
+            file_metadata: Arc::new(RwLock::new(HashMap::new())),
             load_metrics: Arc::new(RwLock::new(LoadingMetrics::default())),
         }
     }
@@ -95,25 +141,7 @@ impl MappingConfigurationLoader {
 
     /// Load inventory mappings from file
     pub async fn load_inventory_mappings(&self) -> Result<InventoryMappings> {
-        let file_path = self.base_dir.join("mappings").join("inventory_mappings.json");
-        debug!("Loading inventory mappings from {}", file_path.display());
-
-        let content = fs::read_to_string(&file_path).await.map_err(|e| {
-            Error::document_parsing(format!(
-                "Failed to read inventory mappings file {}: {}",
-                file_path.display(),
-                e
-            ))
-        })?;
-
-        let inventory: InventoryMappings = serde_json::from_str(&content).map_err(|e| {
-            Error::document_parsing(format!(
-                "Failed to parse inventory mappings JSON from {}: {} at line {}",
-                file_path.display(),
-                e,
-                e.line()
-            ))
-        })?;
+        let inventory: InventoryMappings = self.read_config_file("mappings", "inventory_mappings", "inventory mappings").await?;
 
         debug!("Successfully loaded inventory mappings with {} required columns",
                inventory.fedramp_iiw_mappings.required_columns.len());
@@ -123,25 +151,7 @@ impl MappingConfigurationLoader {
 
     /// Load POA&M mappings from file
     pub async fn load_poam_mappings(&self) -> Result<PoamMappings> {
-        let file_path = self.base_dir.join("mappings").join("poam_mappings.json");
-        debug!("Loading POA&M mappings from {}", file_path.display());
-
-        let content = fs::read_to_string(&file_path).await.map_err(|e| {
-            Error::document_parsing(format!(
-                "Failed to read POA&M mappings file {}: {}",
-                file_path.display(),
-                e
-            ))
-        })?;
-
-        let poam: PoamMappings = serde_json::from_str(&content).map_err(|e| {
-            Error::document_parsing(format!(
-                "Failed to parse POA&M mappings JSON from {}: {} at line {}",
-                file_path.display(),
-                e,
-                e.line()
-            ))
-        })?;
+        let poam: PoamMappings = self.read_config_file("mappings", "poam_mappings", "POA&M mappings").await?;
 
         debug!("Successfully loaded POA&M mappings with {} required columns",
                poam.fedramp_v3_mappings.required_columns.len());
@@ -151,25 +161,7 @@ impl MappingConfigurationLoader {
 
     /// Load SSP sections from file
     pub async fn load_ssp_sections(&self) -> Result<SspSections> {
-        let file_path = self.base_dir.join("mappings").join("ssp_sections.json");
-        debug!("Loading SSP sections from {}", file_path.display());
-
-        let content = fs::read_to_string(&file_path).await.map_err(|e| {
-            Error::document_parsing(format!(
-                "Failed to read SSP sections file {}: {}",
-                file_path.display(),
-                e
-            ))
-        })?;
-
-        let ssp: SspSections = serde_json::from_str(&content).map_err(|e| {
-            Error::document_parsing(format!(
-                "Failed to parse SSP sections JSON from {}: {} at line {}",
-                file_path.display(),
-                e,
-                e.line()
-            ))
-        })?;
+        let ssp: SspSections = self.read_config_file("mappings", "ssp_sections", "SSP sections").await?;
 
         debug!("Successfully loaded SSP sections with {} mappings",
                ssp.section_mappings.mappings.len());
@@ -179,25 +171,7 @@ impl MappingConfigurationLoader {
 
     /// Load control mappings from file
     pub async fn load_control_mappings(&self) -> Result<ControlMappings> {
-        let file_path = self.base_dir.join("schema").join("_controls.json");
-        debug!("Loading control mappings from {}", file_path.display());
-
-        let content = fs::read_to_string(&file_path).await.map_err(|e| {
-            Error::document_parsing(format!(
-                "Failed to read control mappings file {}: {}",
-                file_path.display(),
-                e
-            ))
-        })?;
-
-        let controls: ControlMappings = serde_json::from_str(&content).map_err(|e| {
-            Error::document_parsing(format!(
-                "Failed to parse control mappings JSON from {}: {} at line {}",
-                file_path.display(),
-                e,
-                e.line()
-            ))
-        })?;
+        let controls: ControlMappings = self.read_config_file("schema", "_controls", "control mappings").await?;
 
         debug!("Successfully loaded control mappings");
 
@@ -206,29 +180,69 @@ impl MappingConfigurationLoader {
 
     /// Load document structures from file
     pub async fn load_document_structures(&self) -> Result<DocumentStructures> {
-        let file_path = self.base_dir.join("schema").join("_document.json");
-        debug!("Loading document structures from {}", file_path.display());
+        let documents: DocumentStructures = self.read_config_file("schema", "_document", "document structures").await?;
 
-        let content = fs::read_to_string(&file_path).await.map_err(|e| {
-            Error::document_parsing(format!(
-                "Failed to read document structures file {}: {}",
-                file_path.display(),
-                e
-            ))
-        })?;
+        debug!("Successfully loaded document structures");
 
-        let documents: DocumentStructures = serde_json::from_str(&content).map_err(|e| {
+        Ok(documents)
+    }
+
+    /// Path of a configuration file, given its directory and file stem
+    ///
+    /// The first existing of `<stem>.json`, `<stem>.yaml` and `<stem>.yml` is
+    /// used, so JSON wins when both formats exist. Falls back to the JSON
+    /// path when none exists.
+    pub fn config_file_path(&self, dir: &str, stem: &str) -> PathBuf {
+        let dir = self.base_dir.join(dir);
+        CONFIG_FILE_EXTENSIONS.iter()
+            .map(|extension| dir.join(format!("{}.{}", stem, extension)))
+            .find(|path| path.exists())
+            .unwrap_or_else(|| dir.join(format!("{}.json", stem)))
+    }
+
+    /// Paths of all configuration files the loader reads
+    pub fn config_file_paths(&self) -> Vec<PathBuf> {
+        CONFIG_FILES.iter()
+            .map(|(dir, stem)| self.config_file_path(dir, stem))
+            .collect()
+    }
+
+    /// Read and deserialize a configuration file as JSON or YAML by its extension
+    ///
+    /// The file's size and checksum are recorded for change detection.
+    async fn read_config_file<T: DeserializeOwned>(&self, dir: &str, stem: &str, description: &str) -> Result<T> {
+        let file_path = self.config_file_path(dir, stem);
+        debug!("Loading {} from {}", description, file_path.display());
+
+        let (content, metadata) = self.read_config_content(&file_path, description).await?;
+        let config = parse_config_content(&file_path, &content, description)?;
+        self.record_file_metadata(metadata);
+        Ok(config)
+    }
+
+    /// Read a configuration file's text along with its current metadata
+    pub(crate) async fn read_config_content(&self, file_path: &Path, description: &str) -> Result<(String, FileMetadata)> {
+        let read_error = |e: std::io::Error| {
             Error::document_parsing(format!(
-                "Failed to parse document structures JSON from {}: {} at line {}",
+                "Failed to read {} file {}: {}",
+                description,
                 file_path.display(),
-                e,
-                e.line()
+                e
             ))
-        })?;
-
-        debug!("Successfully loaded document structures");
-
-        Ok(documents)
+        };
+        let content = fs::read_to_string(file_path).await.map_err(read_error)?;
+        let modified = fs::metadata(file_path).await
+            .and_then(|metadata| metadata.modified())
+            .map_err(read_error)?;
+
+        let metadata = FileMetadata {
+            path: file_path.to_path_buf(),
+            size: content.len() as u64,
+            modified,
+            exists: true,
+            checksum: Some(crate::cache::content_hash(content.as_bytes())),
+        };
+        Ok((content, metadata))
     }
 
     /// Get cached configuration
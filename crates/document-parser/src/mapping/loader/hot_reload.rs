@@ -6,30 +6,47 @@
 //! for mapping configuration files with debouncing and error handling.
 
 use super::types::*;
+use super::core::{parse_config_content, CONFIG_FILES, CONFIG_FILE_EXTENSIONS};
 use fedramp_core::{Result, Error};
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
-use std::time::Duration;
-use tokio::sync::mpsc;
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::{broadcast, mpsc};
 use tracing::{debug, info, warn, error};
+use notify::event::ModifyKind;
 use notify::{Watcher, RecursiveMode, Event, EventKind};
 use crate::mapping::inventory::InventoryMappings;
 use crate::mapping::poam::PoamMappings;
 use crate::mapping::ssp::SspSections;
+use crate::mapping::config::MappingConfiguration;
 use crate::mapping::control_document::{ControlMappings, DocumentStructures};
+use crate::mapping::validation::{
+    validate_control_mappings, validate_document_structures, validate_inventory_mappings,
+    validate_poam_mappings, validate_ssp_sections,
+};
+
+/// Change events buffered per subscriber before it starts lagging
+const CHANGE_EVENT_CAPACITY: usize = 64;
 
 impl MappingConfigurationLoader {
     /// Create a new configuration loader with hot-reload support
+    ///
+    /// Once the handler is started, every reloaded file is announced to the
+    /// receivers from [`MappingConfigurationLoader::subscribe_to_changes`].
     pub fn with_hot_reload<P: AsRef<Path>>(base_dir: P) -> Result<(Self, HotReloadHandler)> {
         let (reload_tx, reload_rx) = mpsc::unbounded_channel();
+        let (change_events, _) = broadcast::channel(CHANGE_EVENT_CAPACITY);
 
         let mut loader = Self {
             base_dir: base_dir.as_ref().to_path_buf(),
             cached_config: Arc::new(RwLock::new(None)),
             watcher: None,
             reload_tx: Some(reload_tx),
+            change_events: Some(change_events),
             config_backup: Arc::new(RwLock::new(None)),
-            file_mtimes: Arc::new(RwLock::new(std::collections::HashMap::new())),
+            file_mtimes: Arc::new(RwLock::new(HashMap::new())),
+            file_metadata: Arc::new(RwLock::new(HashMap::new())),
             load_metrics: Arc::new(RwLock::new(crate::mapping::config::LoadingMetrics::default())),
         };
 
@@ -40,13 +57,24 @@ impl MappingConfigurationLoader {
         let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
             match res {
                 Ok(event) => {
-                    if matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
-                        for path in event.paths {
-                            if path.extension().map_or(false, |ext| ext == "json") {
-                                if let Some(relative_path) = path.strip_prefix(&base_dir_clone).ok() {
-                                    if let Err(e) = tx.send(relative_path.to_path_buf()) {
-                                        error!("Failed to send reload notification: {}", e);
-                                    }
+                    let change_type = match event.kind {
+                        EventKind::Create(_) => ChangeType::Created,
+                        EventKind::Modify(ModifyKind::Name(_)) => ChangeType::Renamed,
+                        EventKind::Modify(_) => ChangeType::Modified,
+                        EventKind::Remove(_) => ChangeType::Deleted,
+                        _ => return,
+                    };
+                    for path in event.paths {
+                        if path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| CONFIG_FILE_EXTENSIONS.contains(&ext)) {
+                            if let Ok(relative_path) = path.strip_prefix(&base_dir_clone) {
+                                let change = ConfigChangeEvent {
+                                    file_path: relative_path.to_path_buf(),
+                                    change_type: change_type.clone(),
+                                    timestamp: SystemTime::now(),
+                                    metadata: None,
+                                };
+                                if let Err(e) = tx.send(change) {
+                                    error!("Failed to send reload notification: {}", e);
                                 }
                             }
                         }
@@ -85,138 +113,182 @@ impl MappingConfigurationLoader {
                 cached_config: Arc::clone(&loader_ref.cached_config),
                 watcher: None, // Don't clone the watcher
                 reload_tx: None, // Don't clone the sender
+                change_events: loader_ref.change_events.clone(),
                 config_backup: Arc::clone(&loader_ref.config_backup),
                 file_mtimes: Arc::clone(&loader_ref.file_mtimes),
+                file_metadata: Arc::clone(&loader_ref.file_metadata),
                 load_metrics: Arc::clone(&loader_ref.load_metrics),
             }
         };
 
         Ok((loader_clone, handler))
     }
-}
 
-impl HotReloadHandler {
-    /// Start the hot-reload handler
-    pub async fn start(mut self) -> Result<()> {
-        info!("Starting hot-reload handler for mapping configurations");
+    /// Subscribe to configuration change events
+    ///
+    /// Events are sent after the hot-reload handler has reloaded the changed
+    /// file. A subscriber that falls more than a few dozen events behind
+    /// receives `RecvError::Lagged` and skips the oldest events; it never
+    /// holds up reloading or other subscribers. Returns `None` for a loader
+    /// created without hot-reload.
+    pub fn subscribe_to_changes(&self) -> Option<broadcast::Receiver<ConfigChangeEvent>> {
+        self.change_events.as_ref().map(broadcast::Sender::subscribe)
+    }
 
-        while let Some(changed_path) = self.reload_rx.recv().await {
-            info!("Configuration file changed: {}", changed_path.display());
+    /// Reload one changed configuration file into the cached configuration
+    ///
+    /// `relative_path` is relative to the base directory, as in
+    /// [`ConfigChangeEvent::file_path`]. Only that file is parsed and
+    /// validated, and only its section of the cached configuration is
+    /// replaced; an empty cache is started with that section alone. Returns
+    /// `None` when the path is not a configuration file in use or its content
+    /// checksum matches the last read.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the file cannot be read or parsed, leaving the cached
+    /// configuration unchanged
+    pub async fn reload_changed_file(&self, relative_path: &Path) -> Result<Option<LoadResult<MappingConfiguration>>> {
+        let start = Instant::now();
+        let file_path = self.base_dir.join(relative_path);
+        let Some((_, stem)) = CONFIG_FILES.iter().find(|(dir, stem)| self.config_file_path(dir, stem) == file_path) else {
+            debug!("Ignoring change to {}, which is not a configuration file in use", relative_path.display());
+            return Ok(None);
+        };
 
-            // Debounce rapid file changes
-            tokio::time::sleep(Duration::from_millis(100)).await;
+        let (content, metadata) = self.read_config_content(&file_path, "configuration").await?;
+        if self.get_file_metadata(&file_path).is_some_and(|previous| previous.checksum == metadata.checksum) {
+            debug!("Content of {} is unchanged", relative_path.display());
+            return Ok(None);
+        }
 
-            // Reload configuration
-            if let Err(e) = self.reload_configuration(&changed_path).await {
-                error!("Failed to reload configuration after file change: {}", e);
+        let warnings = match *stem {
+            "inventory_mappings" => {
+                let inventory: InventoryMappings = parse_config_content(&file_path, &content, "inventory mappings")?;
+                let warnings = validate_inventory_mappings(&inventory)?;
+                self.update_cached_config(|config| config.inventory_mappings = Some(inventory));
+                warnings
+            }
+            "poam_mappings" => {
+                let poam: PoamMappings = parse_config_content(&file_path, &content, "POA&M mappings")?;
+                let warnings = validate_poam_mappings(&poam)?;
+                self.update_cached_config(|config| config.poam_mappings = Some(poam));
+                warnings
+            }
+            "ssp_sections" => {
+                let ssp: SspSections = parse_config_content(&file_path, &content, "SSP sections")?;
+                let warnings = validate_ssp_sections(&ssp)?;
+                self.update_cached_config(|config| config.ssp_sections = Some(ssp));
+                warnings
             }
+            "_controls" => {
+                let controls: ControlMappings = parse_config_content(&file_path, &content, "control mappings")?;
+                let warnings = validate_control_mappings(&controls)?;
+                self.update_cached_config(|config| config.controls = Some(controls));
+                warnings
+            }
+            _ => {
+                let documents: DocumentStructures = parse_config_content(&file_path, &content, "document structures")?;
+                let warnings = validate_document_structures(&documents)?;
+                self.update_cached_config(|config| config.documents = Some(documents));
+                warnings
+            }
+        };
+        for warning in &warnings {
+            warn!("{}: {}", relative_path.display(), warning);
         }
 
-        Ok(())
+        let modified_time = metadata.modified;
+        self.file_mtimes.write().unwrap().insert(file_path.clone(), modified_time);
+        self.record_file_metadata(metadata);
+
+        let load_time_ms = start.elapsed().as_millis() as u64;
+        info!("Reloaded {} in {}ms", relative_path.display(), load_time_ms);
+        Ok(Some(LoadResult {
+            data: self.get_cached_configuration().expect("configuration was just cached"),
+            load_time_ms,
+            file_path,
+            modified_time,
+        }))
+    }
+
+    /// Apply an update to the cached configuration, starting an empty one if none is cached
+    fn update_cached_config(&self, update: impl FnOnce(&mut MappingConfiguration)) {
+        let mut cache = self.cached_config.write().unwrap();
+        update(cache.get_or_insert_with(|| MappingConfiguration {
+            inventory_mappings: None,
+            poam_mappings: None,
+            ssp_sections: None,
+            controls: None,
+            documents: None,
+        }));
     }
+}
 
-    /// Reload configuration after file change
-    async fn reload_configuration(&self, changed_path: &Path) -> Result<()> {
-        let loader = {
+impl HotReloadHandler {
+    /// Start the hot-reload handler
+    ///
+    /// Each changed file is reloaded on its own and merged into the cached
+    /// configuration. Events are only sent for files whose content changed
+    /// and reloaded cleanly, or that were deleted.
+    pub async fn start(mut self) -> Result<()> {
+        info!("Starting hot-reload handler for mapping configurations");
+
+        let (loader, change_events) = {
             let loader_ref = self.loader.read().unwrap();
-            MappingConfigurationLoader {
+            let loader = MappingConfigurationLoader {
                 base_dir: loader_ref.base_dir.clone(),
                 cached_config: Arc::clone(&loader_ref.cached_config),
                 watcher: None,
                 reload_tx: None,
+                change_events: None,
                 config_backup: Arc::clone(&loader_ref.config_backup),
                 file_mtimes: Arc::clone(&loader_ref.file_mtimes),
+                file_metadata: Arc::clone(&loader_ref.file_metadata),
                 load_metrics: Arc::clone(&loader_ref.load_metrics),
-            }
+            };
+            (loader, loader_ref.change_events.clone())
         };
 
-        // Determine which configuration to reload based on the changed file
-        match changed_path.file_name().and_then(|n| n.to_str()) {
-            Some("inventory_mappings.json") => {
-                info!("Reloading inventory mappings");
-                if let Ok(inventory) = loader.load_inventory_mappings().await {
-                    self.update_cached_inventory(inventory).await?;
-                }
-            }
-            Some("poam_mappings.json") => {
-                info!("Reloading POA&M mappings");
-                if let Ok(poam) = loader.load_poam_mappings().await {
-                    self.update_cached_poam(poam).await?;
-                }
-            }
-            Some("ssp_sections.json") => {
-                info!("Reloading SSP sections");
-                if let Ok(ssp) = loader.load_ssp_sections().await {
-                    self.update_cached_ssp(ssp).await?;
-                }
-            }
-            Some("_controls.json") => {
-                info!("Reloading control mappings");
-                if let Ok(controls) = loader.load_control_mappings().await {
-                    self.update_cached_controls(controls).await?;
-                }
-            }
-            Some("_document.json") => {
-                info!("Reloading document structures");
-                if let Ok(documents) = loader.load_document_structures().await {
-                    self.update_cached_documents(documents).await?;
-                }
+        while let Some(change) = self.reload_rx.recv().await {
+            // Debounce rapid file changes, keeping the latest change to each file
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let mut changes = vec![change];
+            while let Ok(change) = self.reload_rx.try_recv() {
+                changes.push(change);
             }
-            _ => {
-                warn!("Unknown configuration file changed: {}", changed_path.display());
+            let mut latest: HashMap<PathBuf, ConfigChangeEvent> = HashMap::new();
+            for change in changes {
+                latest.insert(change.file_path.clone(), change);
             }
-        }
-
-        Ok(())
-    }
-
-    /// Update cached inventory mappings
-    async fn update_cached_inventory(&self, inventory: InventoryMappings) -> Result<()> {
-        let loader = self.loader.read().unwrap();
-        let mut cache = loader.cached_config.write().unwrap();
-        if let Some(config) = cache.as_mut() {
-            config.inventory_mappings = Some(inventory);
-        }
-        Ok(())
-    }
 
-    /// Update cached POA&M mappings
-    async fn update_cached_poam(&self, poam: PoamMappings) -> Result<()> {
-        let loader = self.loader.read().unwrap();
-        let mut cache = loader.cached_config.write().unwrap();
-        if let Some(config) = cache.as_mut() {
-            config.poam_mappings = Some(poam);
-        }
-        Ok(())
-    }
+            for mut change in latest.into_values() {
+                info!("Configuration file {:?}: {}", change.change_type, change.file_path.display());
 
-    /// Update cached SSP sections
-    async fn update_cached_ssp(&self, ssp: SspSections) -> Result<()> {
-        let loader = self.loader.read().unwrap();
-        let mut cache = loader.cached_config.write().unwrap();
-        if let Some(config) = cache.as_mut() {
-            config.ssp_sections = Some(ssp);
-        }
-        Ok(())
-    }
+                let file_path = loader.base_dir.join(&change.file_path);
+                if change.change_type == ChangeType::Deleted || !file_path.exists() {
+                    loader.forget_file_metadata(&file_path);
+                    change.metadata = None;
+                } else {
+                    match loader.reload_changed_file(&change.file_path).await {
+                        Ok(Some(_)) => change.metadata = loader.get_file_metadata(&file_path),
+                        Ok(None) => continue,
+                        Err(e) => {
+                            error!("Failed to reload {} after file change: {}", change.file_path.display(), e);
+                            continue;
+                        }
+                    }
+                }
 
-    /// Update cached control mappings
-    async fn update_cached_controls(&self, controls: ControlMappings) -> Result<()> {
-        let loader = self.loader.read().unwrap();
-        let mut cache = loader.cached_config.write().unwrap();
-        if let Some(config) = cache.as_mut() {
-            config.controls = Some(controls);
+                // Sending never waits on subscribers; it only fails when there are none
+                if let Some(change_events) = &change_events {
+                    if change_events.send(change).is_err() {
+                        debug!("No subscribers for configuration change events");
+                    }
+                }
+            }
         }
-        Ok(())
-    }
 
-    /// Update cached document structures
-    async fn update_cached_documents(&self, documents: DocumentStructures) -> Result<()> {
-        let loader = self.loader.read().unwrap();
-        let mut cache = loader.cached_config.write().unwrap();
-        if let Some(config) = cache.as_mut() {
-            config.documents = Some(documents);
-        }
         Ok(())
     }
 }
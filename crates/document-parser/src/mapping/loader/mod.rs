@@ -2,7 +2,7 @@
 
 //! Mapping configuration loader module
 //!
-//! This module provides functionality to load mapping configurations from JSON files
+//! This module provides functionality to load mapping configurations from JSON or YAML files
 //! with support for hot-reload, validation, performance optimization, and caching.
 //!
 //! ## Module Structure
@@ -13,6 +13,8 @@
 //! - `performance` - Parallel loading and performance optimization
 //! - `cache` - Configuration caching and backup management
 //! - `validation` - Configuration validation and consistency checks
+//! - `scaffold` - Starter mapping configuration for custom templates
+//! - `schema` - JSON Schema validation of mapping configuration files
 //!
 //! ## Usage
 //!
@@ -25,7 +27,11 @@
 //!
 //! // With hot-reload support
 //! let (loader, handler) = MappingConfigurationLoader::with_hot_reload("/path/to/mappings")?;
+//! let mut changes = loader.subscribe_to_changes().expect("hot-reload loader");
 //! tokio::spawn(async move { handler.start().await });
+//! while let Ok(change) = changes.recv().await {
+//!     println!("{:?} {}", change.change_type, change.file_path.display());
+//! }
 //!
 //! // Optimized loading with parallel processing
 //! let config = loader.load_all_configurations_optimized().await?;
@@ -37,6 +43,8 @@ pub mod hot_reload;
 pub mod performance;
 pub mod cache;
 pub mod validation;
+pub mod scaffold;
+pub mod schema;
 
 // Re-export main types for backward compatibility
 pub use types::{
@@ -55,6 +63,8 @@ pub use types::{
 };
 
 pub use cache::{CacheStats};
+pub use scaffold::poam_mapping_skeleton;
+pub use schema::{INVENTORY_MAPPINGS_SCHEMA, POAM_MAPPINGS_SCHEMA, SCHEMA_RULE_PREFIX};
 
 // Re-export all functionality from core module
 pub use core::*;
@@ -290,6 +300,70 @@ mod tests {
         assert!(!warnings.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_colliding_column_names_are_reported_with_both_fields() {
+        let temp_dir = create_test_mappings_dir().await.unwrap();
+        let mut loader = MappingConfigurationLoader::new(temp_dir.path());
+        let mut config = loader.load_all_configurations().await.unwrap();
+
+        let columns = &mut config.inventory_mappings.as_mut().unwrap().fedramp_iiw_mappings.required_columns;
+        let mut component = columns["asset_id"].clone();
+        component.column_names = vec![" component id".to_string(), "Component Name".to_string()];
+        columns.insert("component_id".to_string(), component);
+
+        let warnings = loader.validate_configuration(&config).unwrap();
+        let collision = "Inventory column name 'Component ID' is claimed by multiple fields: 'asset_id', 'component_id'";
+        assert!(warnings.iter().any(|warning| warning == collision), "{:?}", warnings);
+        assert!(!warnings.iter().any(|warning| warning.contains("'Asset ID'")));
+    }
+
+    #[tokio::test]
+    async fn test_yaml_inventory_mappings_match_json_fixture() {
+        let json_dir = create_test_mappings_dir().await.unwrap();
+        let json_inventory = MappingConfigurationLoader::new(json_dir.path())
+            .load_inventory_mappings().await.unwrap();
+
+        let yaml_dir = TempDir::new().unwrap();
+        let mappings_dir = yaml_dir.path().join("mappings");
+        fs::create_dir_all(&mappings_dir).unwrap();
+        fs::write(mappings_dir.join("inventory_mappings.yaml"), r#"
+# Same content as the JSON fixture
+description: Test inventory mappings
+version: "1.0"
+fedramp_iiw_mappings:
+  required_columns:
+    asset_id:
+      column_names: [Asset ID, Component ID]
+      field: uuid
+      required: true
+      validation: unique_identifier
+validation_rules:
+  asset_types: [hardware, software]
+  boolean_values: ["yes", "no"]
+component_grouping:
+  strategies: {}
+component_type_mappings: {}
+security_mappings:
+  criticality_to_impact: {}
+  risk_factors: {}
+control_inheritance:
+  infrastructure_controls: []
+  platform_controls: []
+  inheritance_mappings: {}
+"#).unwrap();
+
+        let mut loader = MappingConfigurationLoader::new(yaml_dir.path());
+        let yaml_inventory = loader.load_inventory_mappings().await.unwrap();
+        assert_eq!(
+            serde_json::to_value(&yaml_inventory).unwrap(),
+            serde_json::to_value(&json_inventory).unwrap()
+        );
+
+        let config = loader.load_all_configurations().await.unwrap();
+        assert!(config.inventory_mappings.is_some());
+        assert!(loader.config_file_paths().contains(&mappings_dir.join("inventory_mappings.yaml")));
+    }
+
     #[tokio::test]
     async fn test_performance_sub_100ms() {
         let temp_dir = create_test_mappings_dir().await.unwrap();
@@ -302,6 +376,82 @@ mod tests {
         assert!(duration.as_millis() < 100, "Loading took {}ms, should be < 100ms", duration.as_millis());
     }
 
+    #[tokio::test]
+    async fn test_hot_reload_delivers_change_event_to_subscriber() {
+        let temp_dir = create_test_mappings_dir().await.unwrap();
+        let (loader, handler) = MappingConfigurationLoader::with_hot_reload(temp_dir.path()).unwrap();
+        let mut changes = loader.subscribe_to_changes().unwrap();
+        tokio::spawn(handler.start());
+
+        let ssp_path = temp_dir.path().join("mappings").join("ssp_sections.json");
+        let contents = fs::read_to_string(&ssp_path).unwrap();
+        fs::write(&ssp_path, contents).unwrap();
+
+        let change = tokio::time::timeout(std::time::Duration::from_secs(5), changes.recv())
+            .await
+            .expect("no change event within 5s")
+            .unwrap();
+        assert_eq!(change.file_path, std::path::Path::new("mappings").join("ssp_sections.json"));
+        assert_eq!(change.change_type, ChangeType::Modified);
+        assert!(MappingConfigurationLoader::new(temp_dir.path()).subscribe_to_changes().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reload_changed_file_merges_only_that_file() {
+        let temp_dir = create_test_mappings_dir().await.unwrap();
+        let mut loader = MappingConfigurationLoader::new(temp_dir.path());
+        loader.load_all_configurations().await.unwrap();
+
+        let inventory = std::path::Path::new("mappings").join("inventory_mappings.json");
+        let ssp = std::path::Path::new("mappings").join("ssp_sections.json");
+        let inventory_path = temp_dir.path().join(&inventory);
+        let recorded = loader.get_file_metadata(&inventory_path).unwrap();
+        assert!(recorded.checksum.is_some());
+
+        // Rewriting identical content is not a change
+        fs::write(&inventory_path, fs::read_to_string(&inventory_path).unwrap()).unwrap();
+        assert!(loader.reload_changed_file(&inventory).await.unwrap().is_none());
+        assert!(loader.reload_changed_file(&ssp).await.unwrap().is_none());
+
+        let contents = fs::read_to_string(&inventory_path).unwrap()
+            .replace("Test inventory mappings", "Updated inventory mappings");
+        fs::write(&inventory_path, contents).unwrap();
+        let result = loader.reload_changed_file(&inventory).await.unwrap().unwrap();
+        assert_eq!(result.file_path, inventory_path);
+        assert_eq!(result.data.inventory_mappings.unwrap().description, "Updated inventory mappings");
+        assert!(result.data.ssp_sections.is_some());
+        assert_ne!(loader.get_file_metadata(&inventory_path).unwrap().checksum, recorded.checksum);
+        assert!(loader.reload_changed_file(&inventory).await.unwrap().is_none());
+
+        // Files the loader does not read are ignored
+        assert!(loader.reload_changed_file(std::path::Path::new("mappings/notes.json")).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_hot_reload_skips_unchanged_content() {
+        let temp_dir = create_test_mappings_dir().await.unwrap();
+        let (mut loader, handler) = MappingConfigurationLoader::with_hot_reload(temp_dir.path()).unwrap();
+        loader.load_all_configurations().await.unwrap();
+        let mut changes = loader.subscribe_to_changes().unwrap();
+        tokio::spawn(handler.start());
+
+        let ssp_path = temp_dir.path().join("mappings").join("ssp_sections.json");
+        let inventory_path = temp_dir.path().join("mappings").join("inventory_mappings.json");
+        fs::write(&ssp_path, fs::read_to_string(&ssp_path).unwrap()).unwrap();
+        let contents = fs::read_to_string(&inventory_path).unwrap()
+            .replace("Test inventory mappings", "Updated inventory mappings");
+        fs::write(&inventory_path, contents).unwrap();
+
+        let change = tokio::time::timeout(std::time::Duration::from_secs(5), changes.recv())
+            .await
+            .expect("no change event within 5s")
+            .unwrap();
+        assert_eq!(change.file_path, std::path::Path::new("mappings").join("inventory_mappings.json"));
+        assert_eq!(change.metadata.unwrap().path, inventory_path);
+        let config = loader.get_cached_configuration().unwrap();
+        assert_eq!(config.inventory_mappings.unwrap().description, "Updated inventory mappings");
+    }
+
     #[tokio::test]
     async fn test_error_handling_missing_file() {
         let temp_dir = TempDir::new().unwrap();
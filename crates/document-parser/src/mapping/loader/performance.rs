@@ -200,13 +200,7 @@ impl MappingConfigurationLoader {
 
     /// Check if configuration files have changed since last load
     pub async fn has_configuration_changed(&self) -> Result<bool> {
-        let file_paths = vec![
-            self.base_dir.join("mappings").join("inventory_mappings.json"),
-            self.base_dir.join("mappings").join("poam_mappings.json"),
-            self.base_dir.join("mappings").join("ssp_sections.json"),
-            self.base_dir.join("schema").join("_controls.json"),
-            self.base_dir.join("schema").join("_document.json"),
-        ];
+        let file_paths = self.config_file_paths();
 
         let mtimes = self.file_mtimes.read().unwrap();
 
@@ -0,0 +1,174 @@
+// Modified: 2025-09-20
+
+//! Starter mapping configuration for custom templates
+//!
+//! Writes a POA&M mapping skeleton in the structure the loader expects, so a
+//! non-standard template can be onboarded by filling in column names instead
+//! of writing the configuration from scratch. JSON has no comments, so the
+//! guidance lives in `_comment` keys, which the loader ignores.
+
+use super::types::*;
+use fedramp_core::{Result, Error};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use tokio::fs;
+use tracing::info;
+
+/// Skeleton of a POA&M mapping configuration for a custom template
+pub fn poam_mapping_skeleton(template_name: &str) -> Value {
+    json!({
+        "_comment": "Mapping for a custom POA&M template. Replace the example column names with the headers of your template; a column matches if its header equals any listed name, ignoring case.",
+        "description": format!("Mapping configuration for the {} POA&M template", template_name),
+        "version": "1.0",
+        "fedramp_v3_mappings": {
+            "_comment": "One entry per field; each column name should appear under a single field.",
+            "required_columns": {
+                "poam_id": {
+                    "column_names": ["POA&M Item ID"],
+                    "oscal_field": "uuid",
+                    "required": true,
+                    "validation": "alphanumeric"
+                },
+                "weakness_description": {
+                    "column_names": ["Weakness Description"],
+                    "oscal_field": "description",
+                    "required": true
+                },
+                "security_control": {
+                    "column_names": ["Security Control Number"],
+                    "oscal_field": "related-controls",
+                    "required": true,
+                    "validation": "control_id_list"
+                },
+                "severity": {
+                    "column_names": ["Severity"],
+                    "oscal_field": "risk.severity",
+                    "required": true,
+                    "validation": "severity_level"
+                },
+                "status": {
+                    "column_names": ["Status"],
+                    "oscal_field": "risk.status",
+                    "required": true,
+                    "validation": "status_value"
+                },
+                "scheduled_completion_date": {
+                    "column_names": ["Scheduled Completion Date"],
+                    "oscal_field": "remediation.end",
+                    "required": false,
+                    "data_type": "date"
+                }
+            },
+            "validation_rules": {
+                "_comment": "Values accepted in the severity and status columns, the control ID format and the date formats to try.",
+                "severity_levels": ["Low", "Moderate", "High", "Critical"],
+                "status_values": ["Open", "Ongoing", "Completed", "Closed"],
+                "control_id_pattern": "^[A-Z]{2}-\\d+(\\(\\d+\\))?$",
+                "date_formats": ["%m/%d/%Y", "%Y-%m-%d"]
+            }
+        },
+        "risk_mappings": {
+            "_comment": "How each severity level maps to OSCAL risk impact and likelihood, and each status to an implementation state.",
+            "severity_to_risk_level": {
+                "Low": { "risk_impact": "low", "risk_likelihood": "low" },
+                "Moderate": { "risk_impact": "moderate", "risk_likelihood": "moderate" },
+                "High": { "risk_impact": "high", "risk_likelihood": "moderate" },
+                "Critical": { "risk_impact": "critical", "risk_likelihood": "high" }
+            },
+            "status_to_implementation": {
+                "Open": "planned",
+                "Ongoing": "partial",
+                "Completed": "implemented",
+                "Closed": "implemented"
+            }
+        },
+        "finding_mappings": {
+            "_comment": "Keywords in a finding that identify its OSCAL origin type.",
+            "origin_types": {
+                "assessment": {
+                    "keywords": ["assessment", "audit"],
+                    "oscal_origin_type": "assessment"
+                },
+                "continuous_monitoring": {
+                    "keywords": ["scan", "monitoring"],
+                    "oscal_origin_type": "continuous-monitoring"
+                }
+            }
+        },
+        "milestone_processing": {
+            "patterns": {
+                "multiple_milestones": {
+                    "separator_patterns": [";", "\\n"],
+                    "description": "Patterns to split multiple milestones in single cell"
+                },
+                "milestone_format": {
+                    "_comment": "Regexes capturing a milestone's parts, named in order by groups.",
+                    "patterns": ["^(.+?)\\s*-\\s*(\\d{1,2}/\\d{1,2}/\\d{4})\\s*-\\s*(.+?)$"],
+                    "groups": ["description", "date", "status"]
+                }
+            }
+        },
+        "quality_checks": {
+            "required_field_completeness": {
+                "critical_fields": ["poam_id", "weakness_description", "security_control", "severity", "status"],
+                "minimum_completion_rate": 0.95
+            },
+            "data_consistency": {
+                "date_logic": "scheduled_completion_date must not precede the detection date",
+                "status_logic": "completed items must have a completion date"
+            },
+            "control_validation": {
+                "verify_control_ids": true,
+                "validate_against_catalog": "NIST SP 800-53 Rev 5"
+            }
+        }
+    })
+}
+
+impl MappingConfigurationLoader {
+    /// Write a POA&M mapping skeleton for a custom template into the base directory
+    ///
+    /// The skeleton goes to `mappings/poam_mappings.json` and is loaded back
+    /// to check it parses. Fails if the file already exists.
+    pub async fn scaffold_poam_mapping(&self, template_name: &str) -> Result<PathBuf> {
+        let mappings_dir = self.base_dir.join("mappings");
+        let file_path = mappings_dir.join("poam_mappings.json");
+        if fs::try_exists(&file_path).await? {
+            return Err(Error::configuration(format!(
+                "Mapping configuration {} already exists",
+                file_path.display()
+            )));
+        }
+
+        fs::create_dir_all(&mappings_dir).await?;
+        let skeleton = serde_json::to_string_pretty(&poam_mapping_skeleton(template_name))?;
+        fs::write(&file_path, skeleton).await?;
+        self.load_poam_mappings().await?;
+
+        info!("Wrote POA&M mapping skeleton for '{}' to {}", template_name, file_path.display());
+        Ok(file_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_scaffolded_mapping_loads_through_loader() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut loader = MappingConfigurationLoader::new(temp_dir.path());
+
+        let path = loader.scaffold_poam_mapping("Agency Legacy").await.unwrap();
+        assert!(path.ends_with("mappings/poam_mappings.json"));
+
+        let config = loader.load_all_configurations().await.unwrap();
+        let poam = config.poam_mappings.as_ref().unwrap();
+        assert_eq!(poam.description, "Mapping configuration for the Agency Legacy POA&M template");
+        assert!(poam.fedramp_v3_mappings.required_columns["poam_id"].required);
+
+        let warnings = crate::mapping::validation::validate_poam_mappings(poam).unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert!(loader.scaffold_poam_mapping("Agency Legacy").await.is_err());
+    }
+}
@@ -0,0 +1,158 @@
+// Modified: 2025-09-20
+
+//! JSON Schema validation of mapping configuration files
+//!
+//! Typos in a mapping configuration otherwise only surface as serde errors
+//! when the file is loaded. Checking the file against the published schemas
+//! in `schemas/` first reports every problem with the JSON Pointer of the
+//! offending value, e.g. `/fedramp_iiw_mappings/required_columns/asset_id`.
+
+use super::core::is_yaml_file;
+use super::types::*;
+use crate::{ValidationError, ValidationSeverity};
+use fedramp_core::{Result, Error};
+use jsonschema::JSONSchema;
+use serde_json::Value;
+use std::path::Path;
+use std::sync::OnceLock;
+use tokio::fs;
+
+/// Schema of `mappings/inventory_mappings.json`
+pub const INVENTORY_MAPPINGS_SCHEMA: &str = include_str!("../../../schemas/inventory_mappings.schema.json");
+
+/// Schema of `mappings/poam_mappings.json`
+pub const POAM_MAPPINGS_SCHEMA: &str = include_str!("../../../schemas/poam_mappings.schema.json");
+
+/// Mapping configuration kinds with a published schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MappingSchema {
+    Inventory,
+    Poam,
+}
+
+impl MappingSchema {
+    /// Schema for a file, by its stem or else by its top-level column mapping key
+    fn detect(path: &Path, config: &Value) -> Option<Self> {
+        match path.file_stem().and_then(|stem| stem.to_str()) {
+            Some("inventory_mappings") => Some(Self::Inventory),
+            Some("poam_mappings") => Some(Self::Poam),
+            _ if config.get("fedramp_iiw_mappings").is_some() => Some(Self::Inventory),
+            _ if config.get("fedramp_v3_mappings").is_some() => Some(Self::Poam),
+            _ => None,
+        }
+    }
+
+    /// Compiled schema, built on first use
+    fn compiled(self) -> &'static JSONSchema {
+        static INVENTORY: OnceLock<JSONSchema> = OnceLock::new();
+        static POAM: OnceLock<JSONSchema> = OnceLock::new();
+
+        let (cell, source) = match self {
+            Self::Inventory => (&INVENTORY, INVENTORY_MAPPINGS_SCHEMA),
+            Self::Poam => (&POAM, POAM_MAPPINGS_SCHEMA),
+        };
+        cell.get_or_init(|| {
+            let schema: Value = serde_json::from_str(source).expect("published mapping schema is valid JSON");
+            JSONSchema::compile(&schema).expect("published mapping schema compiles")
+        })
+    }
+}
+
+impl MappingConfigurationLoader {
+    /// Check a mapping configuration file against its JSON Schema
+    ///
+    /// JSON and YAML files are accepted. The schema is chosen by the file
+    /// name (`inventory_mappings` or `poam_mappings`), or else by whether the
+    /// file has `fedramp_iiw_mappings` or `fedramp_v3_mappings`. Returns one
+    /// error per violation, located by JSON Pointer; an empty list means the
+    /// file matches. Fails if the file cannot be read or parsed, or if no
+    /// schema applies.
+    pub async fn validate_schema<P: AsRef<Path>>(path: P) -> Result<Vec<ValidationError>> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).await.map_err(|e| {
+            Error::document_parsing(format!("Failed to read mapping configuration {}: {}", path.display(), e))
+        })?;
+
+        let config: Value = if is_yaml_file(path) {
+            serde_yaml::from_str(&content).map_err(|e| {
+                Error::document_parsing(format!("Failed to parse YAML from {}: {}", path.display(), e))
+            })?
+        } else {
+            serde_json::from_str(&content).map_err(|e| {
+                Error::document_parsing(format!("Failed to parse JSON from {}: {} at line {}", path.display(), e, e.line()))
+            })?
+        };
+
+        let schema = MappingSchema::detect(path, &config).ok_or_else(|| {
+            Error::configuration(format!(
+                "No mapping configuration schema applies to {}; expected inventory or POA&M column mappings",
+                path.display()
+            ))
+        })?;
+
+        Ok(schema_violations(schema.compiled(), &config))
+    }
+}
+
+/// Prefix of the rule IDs of schema violations; the failing keyword follows, e.g. `mapping-schema/required`
+pub const SCHEMA_RULE_PREFIX: &str = "mapping-schema";
+
+/// Violations of a schema, located by JSON Pointer
+fn schema_violations(schema: &JSONSchema, config: &Value) -> Vec<ValidationError> {
+    match schema.validate(config) {
+        Ok(()) => Vec::new(),
+        Err(errors) => errors
+            .map(|error| {
+                let schema_path = error.schema_path.to_string();
+                let keyword = schema_path.rsplit('/').next().filter(|keyword| !keyword.is_empty()).unwrap_or("schema");
+                ValidationError::new(error.instance_path.to_string(), error.to_string(), ValidationSeverity::Error)
+                    .with_rule_id(format!("{}/{}", SCHEMA_RULE_PREFIX, keyword))
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mapping::loader::poam_mapping_skeleton;
+
+    #[tokio::test]
+    async fn test_missing_field_key_is_reported_at_its_location() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("inventory_mappings.yaml");
+        std::fs::write(&path, r#"
+description: Inventory mappings
+version: "1.0"
+fedramp_iiw_mappings:
+  required_columns:
+    asset_id:
+      column_names: [Asset ID]
+      required: true
+validation_rules: {}
+component_grouping: { strategies: {} }
+component_type_mappings: {}
+security_mappings: { criticality_to_impact: {}, risk_factors: {} }
+control_inheritance: { infrastructure_controls: [], platform_controls: [], inheritance_mappings: {} }
+"#).unwrap();
+
+        let errors = MappingConfigurationLoader::validate_schema(&path).await.unwrap();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "/fedramp_iiw_mappings/required_columns/asset_id");
+        assert!(errors[0].message.contains("\"field\" is a required property"), "{}", errors[0].message);
+        assert_eq!(errors[0].rule_id.as_deref(), Some("mapping-schema/required"));
+    }
+
+    #[tokio::test]
+    async fn test_scaffolded_poam_mapping_matches_schema() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("custom.json");
+        std::fs::write(&path, poam_mapping_skeleton("Custom").to_string()).unwrap();
+
+        assert!(MappingConfigurationLoader::validate_schema(&path).await.unwrap().is_empty());
+
+        std::fs::write(&path, "{}").unwrap();
+        assert!(MappingConfigurationLoader::validate_schema(&path).await.is_err());
+    }
+}
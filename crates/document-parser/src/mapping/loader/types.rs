@@ -9,7 +9,7 @@ use fedramp_core::Result;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 use crate::mapping::config::{MappingConfiguration, LoadingMetrics};
 
 /// Configuration loader for mapping files
@@ -22,11 +22,15 @@ pub struct MappingConfigurationLoader {
     /// Hot-reload watcher
     pub watcher: Option<notify::RecommendedWatcher>,
     /// Reload notification channel
-    pub reload_tx: Option<mpsc::UnboundedSender<PathBuf>>,
+    pub reload_tx: Option<mpsc::UnboundedSender<ConfigChangeEvent>>,
+    /// Broadcasts change events to subscribers once a changed file is reloaded
+    pub change_events: Option<broadcast::Sender<ConfigChangeEvent>>,
     /// Configuration backup for rollback
     pub config_backup: Arc<RwLock<Option<MappingConfiguration>>>,
     /// File modification times for change detection
     pub file_mtimes: Arc<RwLock<HashMap<PathBuf, std::time::SystemTime>>>,
+    /// Size and content checksum of each configuration file as last read
+    pub file_metadata: Arc<RwLock<HashMap<PathBuf, FileMetadata>>>,
     /// Loading performance metrics
     pub load_metrics: Arc<RwLock<LoadingMetrics>>,
 }
@@ -37,7 +41,7 @@ pub struct HotReloadHandler {
     /// Configuration loader reference
     pub loader: Arc<RwLock<MappingConfigurationLoader>>,
     /// Reload notification receiver
-    pub reload_rx: mpsc::UnboundedReceiver<PathBuf>,
+    pub reload_rx: mpsc::UnboundedReceiver<ConfigChangeEvent>,
 }
 
 /// Configuration loading result with timing information
@@ -62,6 +66,8 @@ pub struct ConfigChangeEvent {
     pub change_type: ChangeType,
     /// Timestamp of the change
     pub timestamp: std::time::SystemTime,
+    /// Metadata of the file as reloaded; `None` for deleted files
+    pub metadata: Option<FileMetadata>,
 }
 
 /// Type of configuration file change
@@ -118,7 +124,7 @@ impl Default for LoadingOptions {
 }
 
 /// Configuration file metadata
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FileMetadata {
     /// File path
     pub path: PathBuf,
@@ -12,6 +12,7 @@
 //! - `loader`: Configuration loading and hot-reload
 //! - `engine`: Core mapping engine and optimization
 //! - `validation`: Configuration validation
+//! - `pii`: Personal data tagging and masking
 
 pub mod config;
 pub mod inventory;
@@ -25,6 +26,7 @@ pub mod control_document;
 pub mod loader;
 pub mod engine;
 pub mod validation;
+pub mod pii;
 
 #[cfg(test)]
 pub mod poam_column_mapper_tests;
@@ -85,6 +87,7 @@ pub use poam_column_mapper::{
 pub use poam_transformers::{
     SeverityTransformer,
     StatusTransformer,
+    ORIGINAL_STATUS_METADATA_KEY,
     DateTransformer,
     ControlIdTransformer,
     TextNormalizerTransformer,
@@ -102,6 +105,7 @@ pub use date_converter::{
     DateConstraint,
     DateParseError,
     DateWarning,
+    MAX_EXPANDED_YEAR_DISTANCE,
 };
 
 pub use ssp::{
@@ -145,4 +149,15 @@ pub use engine::{
     ValidationType,
     MappingResult,
     ColumnMapper,
+    NormalizationRule,
+    normalize_field_name,
+    normalize_field_name_with,
+};
+
+pub use pii::{
+    PiiClassification,
+    PiiField,
+    PiiPolicy,
+    PiiTag,
+    DEFAULT_PII_MASK,
 };
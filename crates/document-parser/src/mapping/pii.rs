@@ -0,0 +1,249 @@
+//! Personal data tagging and masking for parsed documents
+//!
+//! Column mappings can mark a column as holding personal data with a `pii`
+//! classification. A [`PiiPolicy`] built from the mapping configuration finds
+//! those columns in the worksheets of a [`ParseResult`], records every
+//! non-empty value as a tag in the result metadata under `pii`, and can
+//! replace the values in the content with a mask.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::mapping::config::MappingConfiguration;
+use crate::mapping::engine::normalize_field_name;
+use crate::ParseResult;
+
+/// Text that replaces masked values by default
+pub const DEFAULT_PII_MASK: &str = "[PII]";
+
+/// Kind of personal data a column holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiClassification {
+    /// Email address
+    Email,
+    /// Name of a person
+    PersonalName,
+    /// Telephone number
+    PhoneNumber,
+    /// Postal address
+    Address,
+    /// Other personal data
+    Other,
+}
+
+/// Column holding personal data
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PiiField {
+    /// Mapped field name
+    pub field: String,
+    /// Column headers the field is read from
+    pub column_names: Vec<String>,
+    /// Kind of personal data
+    pub classification: PiiClassification,
+}
+
+/// Personal data value found in parsed content
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PiiTag {
+    /// JSON Pointer of the value in [`ParseResult::content`]
+    pub path: String,
+    /// Mapped field name
+    pub field: String,
+    /// Kind of personal data
+    pub classification: PiiClassification,
+}
+
+/// Which columns hold personal data and whether to mask them
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PiiPolicy {
+    /// Columns holding personal data
+    pub fields: Vec<PiiField>,
+    /// Replace tagged values in the content with `mask`
+    pub mask_values: bool,
+    /// Text that replaces masked values
+    pub mask: String,
+}
+
+impl PiiPolicy {
+    /// Policy tagging the given columns without masking them
+    pub fn new(fields: Vec<PiiField>) -> Self {
+        Self {
+            fields,
+            mask_values: false,
+            mask: DEFAULT_PII_MASK.to_string(),
+        }
+    }
+
+    /// Policy for the columns the inventory and POA&M mappings mark as PII
+    pub fn from_configuration(config: &MappingConfiguration) -> Self {
+        let inventory = config.inventory_mappings.iter()
+            .flat_map(|mappings| mappings.fedramp_iiw_mappings.required_columns.values())
+            .filter_map(|mapping| Some((mapping.pii?, &mapping.field, &mapping.column_names)));
+        let poam = config.poam_mappings.iter()
+            .flat_map(|mappings| mappings.fedramp_v3_mappings.required_columns.values())
+            .filter_map(|mapping| Some((mapping.pii?, &mapping.oscal_field, &mapping.column_names)));
+
+        let fields = inventory.chain(poam)
+            .map(|(classification, field, column_names)| PiiField {
+                field: field.clone(),
+                column_names: column_names.clone(),
+                classification,
+            })
+            .collect();
+        Self::new(fields)
+    }
+
+    /// Replace tagged values with the mask as well as tagging them
+    pub fn with_masking(mut self, mask: impl Into<String>) -> Self {
+        self.mask_values = true;
+        self.mask = mask.into();
+        self
+    }
+
+    /// Tag personal data in a parse result, masking it if configured
+    ///
+    /// The first row of each worksheet is taken as its header row. Tags are
+    /// stored in the metadata as `pii.tags`, next to `pii.masked`, and
+    /// returned.
+    pub fn apply(&self, result: &mut ParseResult) -> Vec<PiiTag> {
+        let mut tags = Vec::new();
+        if self.fields.is_empty() {
+            return tags;
+        }
+
+        let worksheets = result.content.get_mut("worksheets").and_then(Value::as_array_mut);
+        for (sheet_index, worksheet) in worksheets.into_iter().flatten().enumerate() {
+            let Some(rows) = worksheet.get_mut("data").and_then(Value::as_array_mut) else {
+                continue;
+            };
+            let columns = self.pii_columns(rows.first());
+
+            for (row_index, row) in rows.iter_mut().enumerate().skip(1) {
+                for (column_index, field) in &columns {
+                    let Some(cell) = row.get_mut(*column_index) else {
+                        continue;
+                    };
+                    if is_empty(cell) {
+                        continue;
+                    }
+
+                    tags.push(PiiTag {
+                        path: format!("/worksheets/{}/data/{}/{}", sheet_index, row_index, column_index),
+                        field: field.field.clone(),
+                        classification: field.classification,
+                    });
+                    if self.mask_values {
+                        *cell = Value::String(self.mask.clone());
+                    }
+                }
+            }
+        }
+
+        if let Some(metadata) = result.metadata.as_object_mut() {
+            metadata.insert("pii".to_string(), json!({
+                "tags": tags,
+                "masked": self.mask_values,
+            }));
+        }
+        tags
+    }
+
+    /// Indexes of the header cells naming a PII column, with their field
+    fn pii_columns(&self, header_row: Option<&Value>) -> Vec<(usize, &PiiField)> {
+        let Some(headers) = header_row.and_then(Value::as_array) else {
+            return Vec::new();
+        };
+
+        headers.iter()
+            .enumerate()
+            .filter_map(|(index, header)| {
+                let header = normalize_field_name(header.as_str()?);
+                self.fields.iter()
+                    .find(|field| field.column_names.iter().any(|name| normalize_field_name(name) == header))
+                    .map(|field| (index, field))
+            })
+            .collect()
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(text) => text.trim().is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DocumentType;
+
+    fn parse_result() -> ParseResult {
+        ParseResult {
+            document_type: DocumentType::Csv,
+            source_path: "inventory.csv".to_string(),
+            metadata: json!({}),
+            content: json!({
+                "worksheets": [{
+                    "name": "inventory",
+                    "data": [
+                        ["Asset ID", "Owner Email", "Environment"],
+                        ["HW-001", "jane.doe@agency.gov", "Production"],
+                        ["HW-002", null, "Production"],
+                        ["HW-003", "ops@agency.gov", "Staging"]
+                    ]
+                }]
+            }),
+            validation_errors: Vec::new(),
+            quality_score: 1.0,
+        }
+    }
+
+    fn policy() -> PiiPolicy {
+        PiiPolicy::new(vec![PiiField {
+            field: "owner_email".to_string(),
+            column_names: vec!["Owner Email".to_string(), "Owner E-mail".to_string()],
+            classification: PiiClassification::Email,
+        }])
+    }
+
+    #[test]
+    fn test_owner_email_values_are_tagged() {
+        let mut result = parse_result();
+        let tags = policy().apply(&mut result);
+
+        let paths: Vec<&str> = tags.iter().map(|tag| tag.path.as_str()).collect();
+        assert_eq!(paths, ["/worksheets/0/data/1/1", "/worksheets/0/data/3/1"]);
+        assert!(tags.iter().all(|tag| tag.classification == PiiClassification::Email));
+        assert_eq!(result.metadata["pii"]["tags"][0]["classification"], "email");
+        assert_eq!(result.metadata["pii"]["masked"], false);
+        assert_eq!(result.content.pointer("/worksheets/0/data/1/1").unwrap(), "jane.doe@agency.gov");
+    }
+
+    #[test]
+    fn test_column_mapping_reads_pii_classification() {
+        let mapping: crate::mapping::InventoryColumnMapping = serde_json::from_value(json!({
+            "column_names": ["Owner Email"],
+            "field": "owner_email",
+            "required": false,
+            "validation": null,
+            "pii": "email"
+        })).unwrap();
+        assert_eq!(mapping.pii, Some(PiiClassification::Email));
+    }
+
+    #[test]
+    fn test_masking_replaces_tagged_values() {
+        let mut result = parse_result();
+        let tags = policy().with_masking("***").apply(&mut result);
+
+        for tag in &tags {
+            assert_eq!(result.content.pointer(&tag.path).unwrap(), "***");
+        }
+        assert_eq!(result.content.pointer("/worksheets/0/data/2/1").unwrap(), &Value::Null);
+        assert_eq!(result.content.pointer("/worksheets/0/data/1/0").unwrap(), "HW-001");
+        assert_eq!(result.metadata["pii"]["masked"], true);
+    }
+}
@@ -5,6 +5,10 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use crate::mapping::pii::PiiClassification;
+
+/// POA&M mappings shipped with the repository, the source of built-in defaults
+const SHIPPED_POAM_MAPPINGS: &str = include_str!("../../../../mappings/poam_mappings.json");
 
 /// POA&M mappings configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +37,9 @@ pub struct PoamColumnMapping {
     pub required: bool,
     pub validation: Option<String>,
     pub data_type: Option<String>,
+    /// Kind of personal data the column holds, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pii: Option<PiiClassification>,
 }
 
 /// POA&M validation rules
@@ -77,6 +84,16 @@ pub struct MilestoneProcessing {
     pub patterns: MilestonePatterns,
 }
 
+impl Default for MilestoneProcessing {
+    /// The patterns shipped in `mappings/poam_mappings.json`
+    fn default() -> Self {
+        let shipped: serde_json::Value = serde_json::from_str(SHIPPED_POAM_MAPPINGS)
+            .expect("shipped POA&M mappings are valid JSON");
+        serde_json::from_value(shipped["milestone_processing"].clone())
+            .expect("shipped POA&M mappings include milestone processing")
+    }
+}
+
 /// Milestone parsing patterns
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MilestonePatterns {
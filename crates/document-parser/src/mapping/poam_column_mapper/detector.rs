@@ -2,6 +2,7 @@
 //! Modified: 2025-01-22
 
 use crate::{Error, Result};
+use crate::mapping::engine::normalize_field_name;
 use super::types::*;
 
 impl PoamTemplateDetector {
@@ -228,13 +229,7 @@ impl PoamTemplateDetector {
 
     /// Normalize header for comparison
     pub fn normalize_header(&self, header: &str) -> String {
-        header.to_lowercase()
-            .replace("&", "and")
-            .replace("-", " ")
-            .replace("_", " ")
-            .replace("  ", " ")
-            .trim()
-            .to_string()
+        normalize_field_name(header)
     }
 
     /// Simple fuzzy matching for headers
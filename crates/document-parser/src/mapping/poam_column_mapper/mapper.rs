@@ -3,9 +3,14 @@
 
 use crate::{Error, Result};
 use crate::mapping::ColumnMapper;
+use crate::mapping::poam::RiskMappings;
+use crate::quality::{QualityCategory, QualityFinding, QualitySeverity};
+use crate::validation::OverrideContext;
+use fedramp_core::models::DocumentType;
 use std::path::Path;
 use std::collections::HashMap;
-use tracing::info;
+use tracing::{info, warn};
+use uuid::Uuid;
 
 use super::types::*;
 
@@ -29,6 +34,29 @@ impl PoamColumnMapper {
         mapper
     }
 
+    /// Create a POA&M column mapper that loads its mappings from a configuration directory
+    pub fn with_config_loader<P: AsRef<Path>>(base_dir: P) -> Self {
+        let mut mapper = Self::new();
+        mapper.base_mapper = ColumnMapper::with_config_loader(base_dir);
+        mapper
+    }
+
+    /// Load the mapping configurations, including the POA&M risk mappings
+    ///
+    /// Rebuilds the column lookup and, when POA&M mappings are configured,
+    /// transforms severities with their `severity_to_risk_level` table.
+    pub async fn load_configurations(&mut self) -> Result<()> {
+        self.base_mapper.load_configurations().await?;
+
+        let poam_mappings = self.base_mapper.config_loader.as_ref()
+            .and_then(|loader| loader.get_cached_configuration())
+            .and_then(|config| config.poam_mappings);
+        if let Some(poam_mappings) = poam_mappings {
+            self.set_risk_mappings(&poam_mappings.risk_mappings);
+        }
+        Ok(())
+    }
+
     /// Clone the mapper (manual implementation due to trait objects)
     pub fn clone_mapper(&self) -> Self {
         let mut new_mapper = Self::new();
@@ -37,6 +65,22 @@ impl PoamColumnMapper {
         new_mapper
     }
 
+    /// Transform severities with the `severity_to_risk_level` table of a POA&M mapping configuration
+    pub fn set_risk_mappings(&mut self, risk_mappings: &RiskMappings) {
+        self.field_transformers.insert(
+            "severity".to_string(),
+            Box::new(crate::mapping::poam_transformers::SeverityTransformer::from_risk_mappings(risk_mappings)),
+        );
+    }
+
+    /// Transform statuses with extra spellings, such as `WIP`, added to the default synonym table
+    pub fn set_status_synonyms(&mut self, synonyms: HashMap<String, String>) {
+        self.field_transformers.insert(
+            "status".to_string(),
+            Box::new(crate::mapping::poam_transformers::StatusTransformer::new().with_synonyms(synonyms)),
+        );
+    }
+
     /// Get the mapping configuration
     pub fn mapping_config(&self) -> &PoamMappingConfig {
         &self.mapping_config
@@ -62,8 +106,9 @@ impl PoamColumnMapper {
         info!("Detected template: {} v{} (confidence: {:.2})", 
               template_info.name, template_info.version, template_info.confidence);
 
-        // Perform base column mapping
-        let base_results = self.base_mapper.map_columns(headers)?;
+        // Perform base column mapping with any POA&M confidence threshold
+        let context = OverrideContext::new(DocumentType::PlanOfActionMilestones.to_string());
+        let base_results = self.base_mapper.map_columns_in_context(headers, &context)?;
         
         // Apply POA&M-specific mapping logic
         let field_mappings = self.create_poam_field_mappings(&base_results, &template_info)?;
@@ -79,6 +124,7 @@ impl PoamColumnMapper {
         
         // Collect warnings
         let warnings = self.collect_warnings(&field_mappings, &validation_results, &quality_metrics);
+        let findings = self.low_confidence_required_findings(&field_mappings);
 
         Ok(PoamMappingResult {
             mapping_result: crate::mapping::MappingResult {
@@ -89,6 +135,8 @@ impl PoamColumnMapper {
                 required: true,
                 validation: None,
                 exact_match: false,
+                low_confidence: false,
+                algorithm_scores: HashMap::new(),
             },
             template_info: Some(template_info),
             field_mappings,
@@ -96,9 +144,50 @@ impl PoamColumnMapper {
             quality_metrics,
             transformation_results,
             warnings,
+            findings,
         })
     }
 
+    /// Report required fields whose mapping confidence is below the configured floor
+    ///
+    /// A required field mapped with low confidence was most likely matched to
+    /// the wrong column, e.g. after the template's headers changed, so each
+    /// such mapping becomes a `Mapping` finding naming the column and score.
+    pub fn low_confidence_required_findings(&self, field_mappings: &[PoamFieldMapping]) -> Vec<QualityFinding> {
+        let floor = self.mapping_config.quality_thresholds.required_field_min_confidence;
+
+        field_mappings.iter()
+            .filter(|mapping| mapping.required && mapping.confidence < floor)
+            .map(|mapping| {
+                warn!(
+                    "Required field '{}' mapped from column '{}' with low confidence {:.2}",
+                    mapping.target_field, mapping.source_column, mapping.confidence
+                );
+                QualityFinding {
+                    id: Uuid::new_v4().to_string(),
+                    severity: QualitySeverity::High,
+                    category: QualityCategory::Mapping,
+                    description: format!(
+                        "Required field '{}' was mapped from column '{}' with confidence {:.2}, below {:.2}",
+                        mapping.target_field, mapping.source_column, mapping.confidence, floor
+                    ),
+                    affected_items: vec![mapping.source_column.clone()],
+                    impact_assessment: "Values of the column may populate the wrong POA&M field".to_string(),
+                    recommendation: format!(
+                        "Check that column '{}' holds '{}' or add an explicit column mapping",
+                        mapping.source_column, mapping.target_field
+                    ),
+                    location: Some("column_mapping".to_string()),
+                    metadata: HashMap::from([
+                        ("source_column".to_string(), serde_json::json!(mapping.source_column)),
+                        ("target_field".to_string(), serde_json::json!(mapping.target_field)),
+                        ("confidence".to_string(), serde_json::json!(mapping.confidence)),
+                    ]),
+                }
+            })
+            .collect()
+    }
+
     /// Initialize field transformers
     fn initialize_transformers(&mut self) {
         use crate::mapping::poam_transformers::*;
@@ -4,6 +4,7 @@
 use crate::{Error, Result};
 use crate::mapping::{ColumnMapper, MappingResult};
 use crate::mapping::poam::PoamValidationRules;
+use crate::quality::QualityFinding;
 use crate::validation::{ColumnValidator, ValidationResult, DataType};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -116,6 +117,16 @@ pub struct QualityThresholds {
     pub quality_threshold: f64,
     /// Maximum validation errors allowed
     pub max_validation_errors: usize,
+    /// Confidence below which a required field's mapping is reported as a finding
+    ///
+    /// Must be above the base mapper's cutoff (`min_confidence`), since
+    /// matches below that cutoff are dropped before they can be reported.
+    #[serde(default = "default_required_field_min_confidence")]
+    pub required_field_min_confidence: f64,
+}
+
+fn default_required_field_min_confidence() -> f64 {
+    0.8
 }
 
 /// POA&M template detector
@@ -186,6 +197,8 @@ pub struct PoamMappingResult {
     pub transformation_results: Vec<TransformationResult>,
     /// Warnings and issues
     pub warnings: Vec<String>,
+    /// Quality findings, such as required fields mapped with low confidence
+    pub findings: Vec<QualityFinding>,
 }
 
 /// Template detection result
@@ -280,6 +293,7 @@ impl Default for QualityThresholds {
             required_coverage: 0.8,
             quality_threshold: 0.75,
             max_validation_errors: 5,
+            required_field_min_confidence: default_required_field_min_confidence(),
         }
     }
 }
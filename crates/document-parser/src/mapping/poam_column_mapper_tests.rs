@@ -6,6 +6,39 @@ mod tests {
     use super::super::poam_transformers::*;
     use serde_json::Value;
 
+    /// Write a minimal `mappings/poam_mappings.json` with the given risk levels
+    fn poam_mappings_dir(severity_to_risk_level: Value) -> tempfile::TempDir {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mappings_dir = temp_dir.path().join("mappings");
+        std::fs::create_dir_all(&mappings_dir).unwrap();
+        let poam_json = serde_json::json!({
+            "description": "Test POA&M mappings",
+            "version": "1.0",
+            "fedramp_v3_mappings": {
+                "required_columns": {
+                    "poam_id": {"column_names": ["POA&M Item ID"], "oscal_field": "uuid", "required": true},
+                    "vulnerability_description": {"column_names": ["Vulnerability Description"], "oscal_field": "title", "required": true}
+                },
+                "validation_rules": {"severity_levels": ["Low", "High"], "status_values": ["Open", "Closed"]}
+            },
+            "risk_mappings": {"severity_to_risk_level": severity_to_risk_level, "status_to_implementation": {}},
+            "finding_mappings": {"origin_types": {}},
+            "milestone_processing": {
+                "patterns": {
+                    "multiple_milestones": {"separator_patterns": [";"], "description": "Test patterns"},
+                    "milestone_format": {"patterns": ["test"], "groups": ["description"]}
+                }
+            },
+            "quality_checks": {
+                "required_field_completeness": {"critical_fields": ["poam_id"], "minimum_completion_rate": 0.95},
+                "data_consistency": {"date_logic": "test", "status_logic": "test"},
+                "control_validation": {"verify_control_ids": true, "validate_against_catalog": "test"}
+            }
+        });
+        std::fs::write(mappings_dir.join("poam_mappings.json"), poam_json.to_string()).unwrap();
+        temp_dir
+    }
+
     #[tokio::test]
     async fn test_poam_column_mapper_creation() {
         let mapper = PoamColumnMapper::new();
@@ -106,6 +139,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_severity_transformer_with_configured_risk_levels() {
+        let risk_mappings: crate::mapping::poam::RiskMappings = serde_json::from_value(serde_json::json!({
+            "severity_to_risk_level": {
+                "Critical": { "risk_impact": "Very High", "risk_likelihood": "high" },
+                "Minor": { "risk_impact": "Low", "risk_likelihood": "low" }
+            },
+            "status_to_implementation": {}
+        })).unwrap();
+        let transformer = SeverityTransformer::from_risk_mappings(&risk_mappings);
+
+        assert_eq!(
+            transformer.transform(&Value::String("CRITICAL".to_string())).unwrap(),
+            Value::String("Very High".to_string())
+        );
+        assert_eq!(
+            transformer.transform(&Value::String("minor".to_string())).unwrap(),
+            Value::String("Low".to_string())
+        );
+
+        // Unknown severities pass through unchanged
+        assert_eq!(
+            transformer.transform(&Value::String("Severe".to_string())).unwrap(),
+            Value::String("Severe".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_loaded_risk_mappings_drive_the_severity_transformer() {
+        let temp_dir = poam_mappings_dir(serde_json::json!({
+            "Critical": { "risk_impact": "Very High", "risk_likelihood": "high" }
+        }));
+        let mut mapper = PoamColumnMapper::with_config_loader(temp_dir.path());
+        mapper.load_configurations().await.unwrap();
+
+        let severity = &mapper.field_transformers["severity"];
+        assert_eq!(
+            severity.transform(&Value::String("critical".to_string())).unwrap(),
+            Value::String("Very High".to_string())
+        );
+    }
+
     #[test]
     fn test_status_transformer() {
         let transformer = StatusTransformer::new();
@@ -131,6 +206,12 @@ mod tests {
             Value::String("risk-accepted".to_string())
         );
         
+        // Synonyms are coalesced before the status is mapped
+        assert_eq!(
+            transformer.transform(&Value::String("Working".to_string())).unwrap(),
+            Value::String("in-progress".to_string())
+        );
+        
         // Test unknown values default to open
         assert_eq!(
             transformer.transform(&Value::String("unknown".to_string())).unwrap(),
@@ -250,20 +331,20 @@ mod tests {
         let detector = PoamTemplateDetector::new();
         
         // Test fuzzy matching with variations
-        assert!(detector.fuzzy_match("poaandm item id", "poaandm item id"));
+        assert!(detector.fuzzy_match("poam item id", "poam item id"));
         assert!(detector.fuzzy_match("vulnerability description", "vulnerability description"));
         assert!(detector.fuzzy_match("security control", "security control number"));
         assert!(detector.fuzzy_match("severity", "severity"));
 
         // Test non-matching
-        assert!(!detector.fuzzy_match("completely different", "poaandm item id"));
+        assert!(!detector.fuzzy_match("completely different", "poam item id"));
     }
 
     #[test]
     fn test_header_normalization() {
         let detector = PoamTemplateDetector::new();
         
-        assert_eq!(detector.normalize_header("POA&M Item ID"), "poaandm item id");
+        assert_eq!(detector.normalize_header("POA&M Item ID"), "poam item id");
         assert_eq!(detector.normalize_header("Security-Control_Number"), "security control number");
         assert_eq!(detector.normalize_header("  Vulnerability  Description  "), "vulnerability description");
     }
@@ -299,6 +380,65 @@ mod tests {
         assert_eq!(thresholds.required_coverage, 0.9);
         assert_eq!(thresholds.quality_threshold, 0.8);
         assert_eq!(thresholds.max_validation_errors, 5);
+        assert!(thresholds.required_field_min_confidence > thresholds.min_confidence);
+    }
+
+    #[test]
+    fn test_mislabeled_required_column_surfaces_mapping_finding() {
+        let mapper = PoamColumnMapper::new();
+        let field_mapping = |source: &str, target: &str, confidence: f64, required: bool| PoamFieldMapping {
+            source_column: source.to_string(),
+            target_field: target.to_string(),
+            confidence,
+            data_type: crate::validation::DataType::String,
+            required,
+            transformations: Vec::new(),
+            validation_status: true,
+        };
+        let mappings = vec![
+            field_mapping("POA&M Item ID", "uuid", 0.55, true),
+            field_mapping("Comments", "remarks", 0.55, false),
+            field_mapping("Severity", "severity", 0.95, true),
+        ];
+
+        let findings = mapper.low_confidence_required_findings(&mappings);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].category, crate::quality::QualityCategory::Mapping);
+        assert_eq!(findings[0].affected_items, vec!["POA&M Item ID".to_string()]);
+        assert!(findings[0].description.contains("0.55"));
+        assert_eq!(findings[0].metadata["target_field"], "uuid");
+    }
+
+    #[tokio::test]
+    async fn test_fuzzy_matched_required_column_surfaces_mapping_finding() {
+        let temp_dir = poam_mappings_dir(serde_json::json!({}));
+
+        let mut mapper = PoamColumnMapper::with_config_loader(temp_dir.path());
+        mapper.load_configurations().await.unwrap();
+
+        // "POA&M ID" clears the mapper's cutoff but only as a fuzzy match
+        let headers = vec!["POA&M ID".to_string(), "Vulnerability Description".to_string()];
+        let result = mapper.map_poam_columns(&headers).await.unwrap();
+
+        assert_eq!(result.findings.len(), 1);
+        assert_eq!(result.findings[0].affected_items, vec!["POA&M ID".to_string()]);
+        assert_eq!(result.findings[0].metadata["target_field"], "uuid");
+    }
+
+    #[tokio::test]
+    async fn test_poam_confidence_threshold_applies_to_poam_columns() {
+        let temp_dir = poam_mappings_dir(serde_json::json!({}));
+        let mut mapper = PoamColumnMapper::with_config_loader(temp_dir.path());
+        mapper.load_configurations().await.unwrap();
+
+        // A stricter POA&M threshold rejects the fuzzy "POA&M ID" match
+        mapper.base_mapper.set_document_type_threshold(fedramp_core::models::DocumentType::PlanOfActionMilestones, 0.8);
+        let headers = vec!["POA&M ID".to_string(), "Vulnerability Description".to_string()];
+        let result = mapper.map_poam_columns(&headers).await.unwrap();
+
+        assert!(result.findings.is_empty());
+        assert!(result.field_mappings.iter().all(|mapping| mapping.source_column != "POA&M ID"));
     }
 
     #[test]
@@ -5,15 +5,20 @@
 
 use crate::{Error, Result};
 use crate::mapping::poam_column_mapper::FieldTransformer;
+use crate::mapping::poam::RiskMappings;
+use crate::poam::PoamItem;
 use serde_json::Value;
 use chrono::{DateTime, NaiveDate, Utc};
 use std::collections::HashMap;
+use tracing::warn;
 
 /// Severity level transformer
 #[derive(Debug, Clone)]
 pub struct SeverityTransformer {
     /// Severity mapping table
     severity_map: HashMap<String, String>,
+    /// Pass unknown severities through unchanged instead of defaulting to low
+    pass_through_unknown: bool,
 }
 
 /// Status transformer
@@ -21,8 +26,13 @@ pub struct SeverityTransformer {
 pub struct StatusTransformer {
     /// Status mapping table
     status_map: HashMap<String, String>,
+    /// Synonym table from workbook spellings to canonical FedRAMP status labels
+    synonyms: HashMap<String, String>,
 }
 
+/// Metadata key holding a POA&M status as it was written before normalization
+pub const ORIGINAL_STATUS_METADATA_KEY: &str = "original_status";
+
 /// Date transformer using enhanced DateConverter
 #[derive(Debug)]
 pub struct DateTransformer {
@@ -87,7 +97,25 @@ impl SeverityTransformer {
         severity_map.insert("4".to_string(), "low".to_string());
         severity_map.insert("5".to_string(), "informational".to_string());
         
-        Self { severity_map }
+        Self { severity_map, pass_through_unknown: false }
+    }
+
+    /// Create a severity transformer from the `severity_to_risk_level` table of a POA&M mapping configuration
+    ///
+    /// Each configured severity maps to its `risk_impact`, matched
+    /// case-insensitively, so organizations can use their own vocabulary.
+    /// Severities missing from the table pass through unchanged with a
+    /// warning. An empty table falls back to the built-in mappings.
+    pub fn from_risk_mappings(risk_mappings: &RiskMappings) -> Self {
+        if risk_mappings.severity_to_risk_level.is_empty() {
+            return Self::new();
+        }
+
+        let severity_map = risk_mappings.severity_to_risk_level.iter()
+            .map(|(severity, risk_level)| (severity.trim().to_lowercase(), risk_level.risk_impact.clone()))
+            .collect();
+
+        Self { severity_map, pass_through_unknown: true }
     }
 }
 
@@ -101,6 +129,9 @@ impl FieldTransformer for SeverityTransformer {
 
         if let Some(mapped_severity) = self.severity_map.get(&input_str) {
             Ok(Value::String(mapped_severity.clone()))
+        } else if self.pass_through_unknown {
+            warn!("Severity '{}' has no configured risk level; passing it through unchanged", input_str);
+            Ok(value.clone())
         } else {
             // Default to low if unknown
             Ok(Value::String("low".to_string()))
@@ -136,15 +167,90 @@ impl StatusTransformer {
         status_map.insert("risk accepted".to_string(), "risk-accepted".to_string());
         status_map.insert("deferred".to_string(), "deferred".to_string());
         status_map.insert("rejected".to_string(), "rejected".to_string());
-        
-        Self { status_map }
+
+        // Common FedRAMP status spellings
+        let mut synonyms = HashMap::new();
+        for (variant, canonical) in [
+            ("open", "Open"),
+            ("new", "Open"),
+            ("not started", "Open"),
+            ("in progress", "In Progress"),
+            ("inprogress", "In Progress"),
+            ("ongoing", "In Progress"),
+            ("on going", "In Progress"),
+            ("started", "In Progress"),
+            ("working", "In Progress"),
+            ("completed", "Completed"),
+            ("complete", "Completed"),
+            ("done", "Completed"),
+            ("remediated", "Completed"),
+            ("closed", "Closed"),
+            ("cancelled", "Cancelled"),
+            ("canceled", "Cancelled"),
+            ("on hold", "On Hold"),
+            ("onhold", "On Hold"),
+            ("hold", "On Hold"),
+            ("risk accepted", "Risk Accepted"),
+            ("accepted", "Risk Accepted"),
+            ("deferred", "Deferred"),
+            ("false positive", "False Positive"),
+        ] {
+            synonyms.insert(variant.to_string(), canonical.to_string());
+        }
+
+        Self { status_map, synonyms }
+    }
+
+    /// Add or override synonyms; keys are matched case- and separator-insensitively
+    pub fn with_synonyms(mut self, synonyms: HashMap<String, String>) -> Self {
+        for (variant, canonical) in synonyms {
+            self.add_synonym(&variant, canonical);
+        }
+        self
+    }
+
+    /// Add or override a single synonym
+    pub fn add_synonym(&mut self, variant: &str, canonical: String) {
+        self.synonyms.insert(Self::status_key(variant), canonical);
+    }
+
+    /// Canonical label for a status spelling, if it is a known synonym
+    pub fn canonical_status(&self, status: &str) -> Option<&str> {
+        self.synonyms.get(&Self::status_key(status)).map(String::as_str)
+    }
+
+    /// Replace a POA&M item's status with its canonical label
+    ///
+    /// The original spelling is kept in the item metadata under
+    /// [`ORIGINAL_STATUS_METADATA_KEY`]. Returns whether the status changed.
+    pub fn normalize_poam_item(&self, item: &mut PoamItem) -> bool {
+        let Some(canonical) = self.canonical_status(&item.status) else {
+            return false;
+        };
+        if canonical == item.status {
+            return false;
+        }
+
+        let original = std::mem::replace(&mut item.status, canonical.to_string());
+        item.metadata.insert(ORIGINAL_STATUS_METADATA_KEY.to_string(), original);
+        true
+    }
+
+    /// Lowercase, treat `-` and `_` as spaces, and collapse whitespace
+    fn status_key(status: &str) -> String {
+        status
+            .to_lowercase()
+            .replace(['-', '_'], " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 }
 
 impl FieldTransformer for StatusTransformer {
     fn transform(&self, value: &Value) -> Result<Value> {
         let input_str = match value {
-            Value::String(s) => s.to_lowercase().trim().to_string(),
+            Value::String(s) => Self::status_key(self.canonical_status(s).unwrap_or(s)),
             _ => return Err(Error::document_parsing("Invalid status value type".to_string())),
         };
 
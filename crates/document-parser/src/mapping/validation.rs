@@ -5,6 +5,7 @@
 
 use fedramp_core::Result;
 use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::mapping::config::MappingConfiguration;
 use crate::mapping::inventory::InventoryMappings;
@@ -44,6 +45,11 @@ pub fn validate_inventory_mappings(inventory: &InventoryMappings) -> Result<Vec<
         }
     }
 
+    warnings.extend(detect_column_name_collisions(
+        "Inventory",
+        inventory.fedramp_iiw_mappings.required_columns.iter().map(|(key, mapping)| (key, &mapping.column_names)),
+    ));
+
     // Validate component type mappings
     for (key, mapping) in &inventory.component_type_mappings {
         if mapping.keywords.is_empty() {
@@ -69,6 +75,11 @@ pub fn validate_poam_mappings(poam: &PoamMappings) -> Result<Vec<String>> {
         }
     }
 
+    warnings.extend(detect_column_name_collisions(
+        "POA&M",
+        poam.fedramp_v3_mappings.required_columns.iter().map(|(key, mapping)| (key, &mapping.column_names)),
+    ));
+
     // Validate control ID pattern
     if let Some(pattern) = &poam.fedramp_v3_mappings.validation_rules.control_id_pattern {
         if let Err(e) = Regex::new(pattern) {
@@ -86,6 +97,46 @@ pub fn validate_poam_mappings(poam: &PoamMappings) -> Result<Vec<String>> {
     Ok(warnings)
 }
 
+/// Report column names claimed by more than one field of a mapping document
+///
+/// Names are compared ignoring case and surrounding whitespace. A collision
+/// would let the mapper route the column to either field, so each one is
+/// reported with all the fields claiming it, in field ID order, using the
+/// spelling of the first field.
+pub fn detect_column_name_collisions<'a>(
+    document: &str,
+    fields: impl IntoIterator<Item = (&'a String, &'a Vec<String>)>,
+) -> Vec<String> {
+    let mut fields: Vec<_> = fields.into_iter().collect();
+    fields.sort_by_key(|(field_id, _)| *field_id);
+
+    // Keyed by normalized name; the first spelling seen is the one reported
+    let mut claims: BTreeMap<String, (&str, BTreeSet<&str>)> = BTreeMap::new();
+    for (field_id, column_names) in fields {
+        for column_name in column_names {
+            claims
+                .entry(column_name.trim().to_lowercase())
+                .or_insert_with(|| (column_name.as_str(), BTreeSet::new()))
+                .1
+                .insert(field_id.as_str());
+        }
+    }
+
+    claims
+        .values()
+        .filter(|(_, field_ids)| field_ids.len() > 1)
+        .map(|(column_name, field_ids)| {
+            let field_ids: Vec<String> = field_ids.iter().map(|id| format!("'{}'", id)).collect();
+            format!(
+                "{} column name '{}' is claimed by multiple fields: {}",
+                document,
+                column_name,
+                field_ids.join(", ")
+            )
+        })
+        .collect()
+}
+
 /// Validate SSP sections
 pub fn validate_ssp_sections(ssp: &SspSections) -> Result<Vec<String>> {
     let mut warnings = Vec::new();
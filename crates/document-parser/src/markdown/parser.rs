@@ -5,7 +5,8 @@
 //! This module provides the main Markdown parser implementation with comprehensive
 //! SSP support and DocumentParser trait implementation.
 
-use crate::{DocumentParser, ParseResult, DocumentType};
+use crate::{DocumentParser, ParseResult, DocumentType, ValidationError, ValidationSeverity};
+use crate::encoding::decode_text;
 use async_trait::async_trait;
 use fedramp_core::{Result, Error};
 use std::path::Path;
@@ -64,19 +65,22 @@ impl MarkdownParser {
         info!("Parsing Markdown file: {}", path.display());
 
         // Read file
-        let file_data = tokio::fs::read_to_string(path).await
+        let file_data = tokio::fs::read(path).await
             .map_err(|e| Error::document_parsing(format!("Failed to read file: {}", e)))?;
 
         // Check file size
-        if file_data.len() > self.max_file_size {
-            return Err(Error::document_parsing(format!(
-                "File size {} exceeds maximum allowed size {}",
-                file_data.len(),
-                self.max_file_size
-            )));
-        }
+        self.check_file_size(file_data.len())?;
+
+        let decoded = decode_text(&file_data, &self.config.encoding)?;
+        self.parse_markdown_content(&decoded.text, &path.to_string_lossy()).await
+    }
 
-        self.parse_markdown_content(&file_data, &path.to_string_lossy()).await
+    /// Reject input larger than the configured maximum
+    fn check_file_size(&self, size: usize) -> Result<()> {
+        if size > self.max_file_size {
+            return Err(Error::file_too_large(size as u64, self.max_file_size as u64));
+        }
+        Ok(())
     }
 
     /// Parse Markdown document from string content
@@ -139,64 +143,23 @@ impl DocumentParser for MarkdownParser {
     async fn parse_file(&self, path: &Path) -> Result<ParseResult> {
         info!("Parsing Markdown file: {}", path.display());
 
-        // Parse the Markdown document
-        let markdown_doc = self.parse_markdown_file(path).await?;
-
-        // Calculate quality score
-        let quality_score = self.calculate_quality_score(&markdown_doc);
-
-        // Convert to ParseResult format
-        let content = serde_json::to_value(&markdown_doc)
-            .map_err(|e| Error::document_parsing(format!("Failed to serialize Markdown document: {}", e)))?;
-
-        // Validate content
-        let validation_errors = self.validate(&content).await?;
+        let data = tokio::fs::read(path).await
+            .map_err(|e| Error::document_parsing(format!("Failed to read file: {}", e)))?;
 
-        Ok(ParseResult {
-            document_type: DocumentType::Markdown,
-            source_path: path.to_string_lossy().to_string(),
-            metadata: serde_json::json!({
-                "source_file": path.to_string_lossy(),
-                "source_type": "markdown",
-                "extraction_date": chrono::Utc::now().to_rfc3339(),
-                "parser_version": env!("CARGO_PKG_VERSION"),
-                "title": markdown_doc.metadata.title,
-                "author": markdown_doc.metadata.author,
-                "description": markdown_doc.metadata.description,
-                "tags": markdown_doc.metadata.tags,
-                "word_count": markdown_doc.content.word_count,
-                "character_count": markdown_doc.content.character_count,
-                "heading_count": markdown_doc.structure.headings.len(),
-                "section_count": markdown_doc.structure.sections.len(),
-                "table_count": markdown_doc.tables.len(),
-                "code_block_count": markdown_doc.code_blocks.len(),
-                "link_count": markdown_doc.links.len(),
-                "image_count": markdown_doc.images.len()
-            }),
-            content,
-            validation_errors,
-            quality_score,
-        })
+        self.parse_bytes(&data, &path.to_string_lossy()).await
     }
 
     async fn parse_bytes(&self, data: &[u8], filename: &str) -> Result<ParseResult> {
         info!("Parsing Markdown bytes for file: {}", filename);
 
-        // Convert bytes to string
-        let content = String::from_utf8(data.to_vec())
-            .map_err(|e| Error::document_parsing(format!("Invalid UTF-8 in Markdown file: {}", e)))?;
-
         // Check file size
-        if content.len() > self.max_file_size {
-            return Err(Error::document_parsing(format!(
-                "File size {} exceeds maximum allowed size {}",
-                content.len(),
-                self.max_file_size
-            )));
-        }
+        self.check_file_size(data.len())?;
+
+        // Transcode to UTF-8
+        let decoded = decode_text(data, &self.config.encoding)?;
 
         // Parse the Markdown document
-        let markdown_doc = self.parse_markdown_content(&content, filename).await?;
+        let markdown_doc = self.parse_markdown_content(&decoded.text, filename).await?;
 
         // Calculate quality score
         let quality_score = self.calculate_quality_score(&markdown_doc);
@@ -206,7 +169,13 @@ impl DocumentParser for MarkdownParser {
             .map_err(|e| Error::document_parsing(format!("Failed to serialize Markdown document: {}", e)))?;
 
         // Validate content
-        let validation_errors = self.validate(&content_value).await?;
+        let mut validation_errors: Vec<ValidationError> = self.validate(&content_value).await?
+            .into_iter()
+            .map(ValidationError::document)
+            .collect();
+        if let Some(warning) = decoded.warning() {
+            validation_errors.push(ValidationError::new("", warning, ValidationSeverity::Warning));
+        }
 
         Ok(ParseResult {
             document_type: DocumentType::Markdown,
@@ -216,6 +185,7 @@ impl DocumentParser for MarkdownParser {
                 "source_type": "markdown",
                 "extraction_date": chrono::Utc::now().to_rfc3339(),
                 "parser_version": env!("CARGO_PKG_VERSION"),
+                "encoding": decoded.metadata(),
                 "title": markdown_doc.metadata.title,
                 "author": markdown_doc.metadata.author,
                 "description": markdown_doc.metadata.description,
@@ -243,3 +213,26 @@ impl DocumentParser for MarkdownParser {
         vec!["md", "markdown", "mdown", "mkd", "mkdn"]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_utf16le_markdown_is_transcoded() {
+        let mut data = vec![0xFF, 0xFE];
+        data.extend("# System Security Plan\n\n## Access Control\n".encode_utf16().flat_map(u16::to_le_bytes));
+
+        let result = MarkdownParser::new().parse_bytes(&data, "ssp.md").await.unwrap();
+
+        let headings: Vec<&str> = result.content["structure"]["headings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|heading| heading["text"].as_str())
+            .collect();
+        assert_eq!(headings, vec!["System Security Plan", "Access Control"]);
+        assert_eq!(result.metadata["encoding"]["name"], "UTF-16LE");
+        assert_eq!(result.metadata["encoding"]["detected_by"], "bom");
+    }
+}
@@ -5,6 +5,7 @@
 //! This module contains all the data structures, enums, and type definitions
 //! used throughout the Markdown parsing system.
 
+use crate::encoding::EncodingDetection;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -27,6 +28,8 @@ pub struct MarkdownParserConfig {
     pub max_heading_depth: usize,
     /// Enable math support
     pub enable_math: bool,
+    /// Input text encoding detection
+    pub encoding: EncodingDetection,
 }
 
 impl Default for MarkdownParserConfig {
@@ -40,6 +43,7 @@ impl Default for MarkdownParserConfig {
             parse_frontmatter: true,
             max_heading_depth: 6,
             enable_math: false,
+            encoding: EncodingDetection::Auto,
         }
     }
 }
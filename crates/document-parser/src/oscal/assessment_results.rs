@@ -0,0 +1,542 @@
+// Modified: 2025-09-24
+
+//! OSCAL assessment results
+//!
+//! [`AssessmentResultsBuilder`] assembles a single-result assessment results
+//! document and is shared by every exporter. Assessed items and the imported
+//! assessment plan become back-matter resources, so each observation's
+//! `subject-uuid` and the `import-ap` href resolve within the document.
+//!
+//! A quality assessment becomes a single result. Each quality finding becomes
+//! an observation whose subjects are the finding's affected items, a risk
+//! carrying the finding's recommendation and any related quality
+//! recommendations as remediations, and a finding tying the two together.
+//! Findings target the quality category they were raised under.
+
+use chrono::{DateTime, Utc};
+use fedramp_core::Result;
+use std::collections::HashMap;
+use tracing::info;
+
+use super::documents::*;
+use super::generator::OscalGenerator;
+use super::types::*;
+use super::utils::{OscalUtils, UuidGenerator};
+use crate::quality::{QualityAssessment, QualityCategory, QualityFinding, QualitySeverity};
+
+/// Builder of an assessment results document with one result
+///
+/// Observations, risks and findings are added as they are produced; all of
+/// them share the tool origin returned by [`origins`](Self::origins).
+#[derive(Debug)]
+pub struct AssessmentResultsBuilder {
+    uuids: UuidGenerator,
+    collected: String,
+    origins: Vec<OscalOrigin>,
+    plan_uuid: String,
+    plan: serde_json::Value,
+    /// Back-matter resources of assessed items, in first-seen order
+    subjects: Vec<serde_json::Value>,
+    subject_uuids: HashMap<String, String>,
+    observations: Vec<OscalObservation>,
+    risks: Vec<OscalRisk>,
+    findings: Vec<OscalFinding>,
+}
+
+impl AssessmentResultsBuilder {
+    /// Start a result collected at `collected` by a tool with the given actor properties
+    ///
+    /// The imported assessment plan is described by `plan_title` and
+    /// `plan_description`, with `plan_props` identifying what was assessed.
+    pub fn new(
+        collected: DateTime<Utc>,
+        actor_props: Option<Vec<OscalProperty>>,
+        plan_title: &str,
+        plan_description: &str,
+        plan_props: Vec<OscalProperty>,
+    ) -> Self {
+        let mut uuids = UuidGenerator::new();
+        let origins = vec![OscalOrigin {
+            actors: vec![OscalActor {
+                actor_type: "tool".to_string(),
+                actor_uuid: uuids.generate_uuid(),
+                role_id: None,
+                props: actor_props,
+            }],
+            related_tasks: None,
+        }];
+        let plan_uuid = uuids.generate_uuid();
+        let plan = serde_json::json!({
+            "uuid": plan_uuid,
+            "title": plan_title,
+            "description": plan_description,
+            "props": plan_props,
+        });
+        Self {
+            uuids,
+            collected: collected.to_rfc3339(),
+            origins,
+            plan_uuid,
+            plan,
+            subjects: Vec::new(),
+            subject_uuids: HashMap::new(),
+            observations: Vec::new(),
+            risks: Vec::new(),
+            findings: Vec::new(),
+        }
+    }
+
+    /// New UUID, unique within the document
+    pub fn generate_uuid(&mut self) -> String {
+        self.uuids.generate_uuid()
+    }
+
+    /// Origins shared by the result's observations, risks and findings
+    pub fn origins(&self) -> &[OscalOrigin] {
+        &self.origins
+    }
+
+    /// RFC 3339 time the result was collected
+    pub fn collected(&self) -> &str {
+        &self.collected
+    }
+
+    /// Subject referencing the assessed item `title`
+    ///
+    /// The same title is the same subject across observations; the item is
+    /// defined once as a back-matter resource.
+    pub fn subject(&mut self, title: &str) -> OscalSubject {
+        let subject_uuid = match self.subject_uuids.get(title) {
+            Some(uuid) => uuid.clone(),
+            None => {
+                let uuid = self.uuids.generate_uuid();
+                self.subjects.push(serde_json::json!({ "uuid": uuid, "title": title }));
+                self.subject_uuids.insert(title.to_string(), uuid.clone());
+                uuid
+            }
+        };
+        OscalSubject {
+            subject_type: "resource".to_string(),
+            title: Some(title.to_string()),
+            subject_uuid: Some(subject_uuid),
+            props: None,
+        }
+    }
+
+    /// Add an observation to the result
+    pub fn add_observation(&mut self, observation: OscalObservation) {
+        self.observations.push(observation);
+    }
+
+    /// Add a risk to the result
+    pub fn add_risk(&mut self, risk: OscalRisk) {
+        self.risks.push(risk);
+    }
+
+    /// Add a finding to the result
+    pub fn add_finding(&mut self, finding: OscalFinding) {
+        self.findings.push(finding);
+    }
+
+    /// Finish the document with the result's title, description, properties and reviewed controls
+    ///
+    /// Empty observation, risk and finding lists are omitted.
+    pub fn build(
+        mut self,
+        metadata: OscalMetadata,
+        title: String,
+        description: String,
+        props: Vec<OscalProperty>,
+        reviewed_controls: ReviewedControls,
+    ) -> OscalAssessmentResultsDocument {
+        let mut resources = vec![self.plan];
+        resources.append(&mut self.subjects);
+        OscalAssessmentResultsDocument {
+            assessment_results: AssessmentResults {
+                uuid: self.uuids.generate_uuid(),
+                metadata,
+                import_ap: ImportAp {
+                    href: format!("#{}", self.plan_uuid),
+                    remarks: None,
+                },
+                local_definitions: None,
+                results: vec![AssessmentResult {
+                    uuid: self.uuids.generate_uuid(),
+                    title,
+                    description,
+                    start: self.collected,
+                    end: None,
+                    props: (!props.is_empty()).then_some(props),
+                    reviewed_controls,
+                    observations: (!self.observations.is_empty()).then_some(self.observations),
+                    risks: (!self.risks.is_empty()).then_some(self.risks),
+                    findings: (!self.findings.is_empty()).then_some(self.findings),
+                }],
+                back_matter: Some(serde_json::json!({ "resources": resources })),
+            },
+        }
+    }
+}
+
+impl OscalGenerator {
+    /// Generate an assessment results document from a quality assessment
+    ///
+    /// The document is validated before it is returned unless output
+    /// validation is disabled.
+    pub fn generate_assessment_results(&self, assessment: &QualityAssessment) -> Result<OscalAssessmentResultsDocument> {
+        info!(
+            "Generating OSCAL Assessment Results document from {} quality findings",
+            assessment.findings.len()
+        );
+
+        let mut builder = AssessmentResultsBuilder::new(
+            assessment.timestamp,
+            None,
+            &format!("Data quality assessment {}", assessment.assessment_id),
+            &format!("Automated data quality checks of the documents of {}", self.system_name),
+            vec![OscalUtils::create_property("assessment-id", &assessment.assessment_id, None)],
+        );
+        for finding in &assessment.findings {
+            let subjects: Vec<OscalSubject> = finding.affected_items.iter().map(|item| builder.subject(item)).collect();
+
+            let observation_uuid = builder.generate_uuid();
+            builder.add_observation(OscalObservation {
+                uuid: observation_uuid.clone(),
+                title: Some(format!("Quality finding {}", finding.id)),
+                description: finding.description.clone(),
+                props: None,
+                methods: vec!["TEST".to_string()],
+                types: Some(vec!["finding".to_string()]),
+                origins: builder.origins().to_vec(),
+                subjects: (!subjects.is_empty()).then_some(subjects),
+                relevant_evidence: None,
+                collected: builder.collected().to_string(),
+                expires: None,
+                remarks: finding.location.as_ref().map(|location| format!("Location: {}", location)),
+            });
+
+            let risk_uuid = builder.generate_uuid();
+            let remediations = remediations(assessment, finding, &mut builder);
+            builder.add_risk(OscalRisk {
+                uuid: risk_uuid.clone(),
+                title: format!("{} quality issue {}", category_title(&finding.category), finding.id),
+                description: finding.description.clone(),
+                statement: non_empty(&finding.impact_assessment).unwrap_or(&finding.description).to_string(),
+                props: Some(vec![OscalUtils::create_property("severity", severity_name(&finding.severity), None)]),
+                status: "open".to_string(),
+                origins: Some(builder.origins().to_vec()),
+                threat_ids: None,
+                characterizations: None,
+                mitigating_factors: None,
+                deadline: None,
+                remediations: Some(remediations).filter(|remediations| !remediations.is_empty()),
+                risk_log: None,
+                related_observations: Some(vec![OscalRelatedObservation { observation_uuid: observation_uuid.clone() }]),
+            });
+
+            let mut props = vec![
+                OscalUtils::create_property("severity", severity_name(&finding.severity), None),
+                OscalUtils::create_property("quality-finding-id", &finding.id, None),
+            ];
+            if let Some(location) = &finding.location {
+                props.push(OscalUtils::create_property("location", location, None));
+            }
+            let finding_uuid = builder.generate_uuid();
+            let origins = builder.origins().to_vec();
+            builder.add_finding(OscalFinding {
+                uuid: finding_uuid,
+                title: format!("{} finding {}", category_title(&finding.category), finding.id),
+                description: finding.description.clone(),
+                props: Some(props),
+                links: None,
+                origins,
+                target: OscalTarget {
+                    target_type: "objective-id".to_string(),
+                    target_id: category_objective_id(&finding.category).to_string(),
+                    title: Some(category_title(&finding.category).to_string()),
+                    description: None,
+                    props: None,
+                    links: None,
+                    status: Some(OscalImplementationStatus {
+                        state: "not-satisfied".to_string(),
+                        reason: Some(severity_name(&finding.severity).to_string()),
+                    }),
+                    implementation_status: None,
+                },
+                implementation_statement_uuid: None,
+                related_observations: Some(vec![OscalRelatedObservation { observation_uuid }]),
+                related_risks: Some(vec![OscalRelatedRisk { risk_uuid }]),
+            });
+        }
+
+        let mut metadata = self.metadata_builder.build_assessment_results_metadata(&self.system_name, None);
+        metadata.oscal_version = self.oscal_version.clone();
+
+        let document = builder.build(
+            metadata,
+            format!("Quality assessment {}", assessment.assessment_id),
+            format!(
+                "{} quality findings for {}, overall score {:.2}",
+                assessment.findings.len(),
+                self.system_name,
+                assessment.overall_score
+            ),
+            vec![
+                OscalUtils::create_property("overall-score", &format!("{:.2}", assessment.overall_score), None),
+                OscalUtils::create_property("completeness-score", &format!("{:.2}", assessment.completeness_score), None),
+                OscalUtils::create_property("accuracy-score", &format!("{:.2}", assessment.accuracy_score), None),
+                OscalUtils::create_property("consistency-score", &format!("{:.2}", assessment.consistency_score), None),
+                OscalUtils::create_property("compliance-score", &format!("{:.2}", assessment.compliance_score), None),
+            ],
+            ReviewedControls {
+                description: Some("Data quality of the assessed document".to_string()),
+                control_selections: vec![ControlSelection {
+                    include_all: Some(IncludeAll { include_controls: None }),
+                    include_controls: None,
+                }],
+            },
+        );
+
+        if self.validate_output {
+            self.schema_validator.validate_assessment_results_document(&document)?;
+        }
+
+        info!("Successfully generated OSCAL Assessment Results document");
+        Ok(document)
+    }
+}
+
+/// The finding's recommendation, then each quality recommendation naming the finding
+fn remediations(
+    assessment: &QualityAssessment,
+    finding: &QualityFinding,
+    builder: &mut AssessmentResultsBuilder,
+) -> Vec<OscalRemediation> {
+    let mut remediations = Vec::new();
+    if let Some(recommendation) = non_empty(&finding.recommendation) {
+        remediations.push(remediation(builder, "Recommended action".to_string(), recommendation.to_string(), None));
+    }
+    for recommendation in assessment.recommendations.iter().filter(|recommendation| recommendation.related_findings.contains(&finding.id)) {
+        let props = vec![
+            OscalUtils::create_property("priority", severity_name(&recommendation.priority), None),
+            OscalUtils::create_property("quality-recommendation-id", &recommendation.id, None),
+        ];
+        remediations.push(remediation(
+            builder,
+            recommendation.title.clone(),
+            recommendation.description.clone(),
+            Some(props),
+        ));
+    }
+    remediations
+}
+
+fn remediation(
+    builder: &mut AssessmentResultsBuilder,
+    title: String,
+    description: String,
+    props: Option<Vec<OscalProperty>>,
+) -> OscalRemediation {
+    OscalRemediation {
+        uuid: builder.generate_uuid(),
+        lifecycle: "recommendation".to_string(),
+        title,
+        description,
+        props,
+        origins: Some(builder.origins().to_vec()),
+        required_assets: None,
+        tasks: None,
+        remarks: None,
+    }
+}
+
+/// OSCAL severity value of a quality severity
+fn severity_name(severity: &QualitySeverity) -> &'static str {
+    match severity {
+        QualitySeverity::Critical => "critical",
+        QualitySeverity::High => "high",
+        QualitySeverity::Medium => "moderate",
+        QualitySeverity::Low => "low",
+        QualitySeverity::Info => "informational",
+    }
+}
+
+/// Objective ID findings of a quality category target
+fn category_objective_id(category: &QualityCategory) -> &'static str {
+    match category {
+        QualityCategory::Completeness => "data-quality-completeness",
+        QualityCategory::Accuracy => "data-quality-accuracy",
+        QualityCategory::Consistency => "data-quality-consistency",
+        QualityCategory::Compliance => "data-quality-compliance",
+        QualityCategory::Format => "data-quality-format",
+        QualityCategory::BusinessLogic => "data-quality-business-logic",
+        QualityCategory::ReferenceIntegrity => "data-quality-reference-integrity",
+        QualityCategory::Mapping => "data-quality-mapping",
+    }
+}
+
+fn category_title(category: &QualityCategory) -> &'static str {
+    match category {
+        QualityCategory::Completeness => "Completeness",
+        QualityCategory::Accuracy => "Accuracy",
+        QualityCategory::Consistency => "Consistency",
+        QualityCategory::Compliance => "Compliance",
+        QualityCategory::Format => "Format",
+        QualityCategory::BusinessLogic => "Business logic",
+        QualityCategory::ReferenceIntegrity => "Reference integrity",
+        QualityCategory::Mapping => "Mapping",
+    }
+}
+
+fn non_empty(value: &str) -> Option<&str> {
+    Some(value.trim()).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quality::{QualityMetrics, QualityRecommendation};
+
+    fn quality_finding(id: &str, severity: QualitySeverity, affected_items: &[&str]) -> QualityFinding {
+        QualityFinding {
+            id: id.to_string(),
+            severity,
+            category: QualityCategory::Completeness,
+            description: format!("Scheduled completion date missing ({})", id),
+            affected_items: affected_items.iter().map(|item| item.to_string()).collect(),
+            impact_assessment: "Remediation progress cannot be tracked".to_string(),
+            recommendation: "Add a scheduled completion date".to_string(),
+            location: Some("POA&M Items!H".to_string()),
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn assessment() -> QualityAssessment {
+        QualityAssessment {
+            assessment_id: "qa-1".to_string(),
+            timestamp: chrono::Utc::now(),
+            overall_score: 0.72,
+            completeness_score: 0.6,
+            accuracy_score: 0.8,
+            consistency_score: 0.9,
+            compliance_score: 0.7,
+            quality_metrics: QualityMetrics {
+                total_items: 3,
+                complete_items: 1,
+                incomplete_items: 2,
+                error_count: 1,
+                warning_count: 1,
+                missing_required_fields: 2,
+                data_quality_issues: 2,
+                field_completeness: HashMap::new(),
+                category_metrics: HashMap::new(),
+            },
+            findings: vec![
+                quality_finding("QF-1", QualitySeverity::High, &["V-001", "V-002"]),
+                quality_finding("QF-2", QualitySeverity::Low, &["V-002"]),
+            ],
+            recommendations: vec![QualityRecommendation {
+                id: "QR-1".to_string(),
+                priority: QualitySeverity::High,
+                title: "Backfill completion dates".to_string(),
+                description: "Agree completion dates with control owners".to_string(),
+                expected_impact: "Completeness above 90%".to_string(),
+                effort_estimate: "1 day".to_string(),
+                related_findings: vec!["QF-1".to_string()],
+            }],
+            config_summary: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_quality_findings_become_assessment_findings() {
+        let generator = OscalGenerator::new().with_system_name("Payments".to_string());
+        let document = generator.generate_assessment_results(&assessment()).unwrap();
+
+        let results = &document.assessment_results;
+        assert_eq!(results.metadata.title, "Assessment Results for Payments");
+        assert_eq!(results.metadata.oscal_version, "1.1.2");
+
+        let result = &results.results[0];
+        let findings = result.findings.as_ref().unwrap();
+        let observations = result.observations.as_ref().unwrap();
+        let risks = result.risks.as_ref().unwrap();
+        assert_eq!((findings.len(), observations.len(), risks.len()), (2, 2, 2));
+
+        let severity = findings[0].props.as_ref().unwrap().iter().find(|prop| prop.name == "severity").unwrap();
+        assert_eq!(severity.value, "high");
+        assert_eq!(findings[0].target.target_id, "data-quality-completeness");
+
+        // Affected items are subjects, shared between findings
+        let subjects = |index: usize| observations[index].subjects.as_ref().unwrap().clone();
+        let titles: Vec<String> = subjects(0).into_iter().filter_map(|subject| subject.title).collect();
+        assert_eq!(titles, ["V-001", "V-002"]);
+        assert_eq!(subjects(0)[1].subject_uuid, subjects(1)[0].subject_uuid);
+
+        // Subjects and the imported plan are defined as back-matter resources
+        let json = serde_json::to_value(&document).unwrap();
+        let resources = json["assessment-results"]["back-matter"]["resources"].as_array().unwrap();
+        let resource_uuids: Vec<&str> = resources.iter().map(|resource| resource["uuid"].as_str().unwrap()).collect();
+        assert_eq!(resource_uuids.len(), 3);
+        let href = json["assessment-results"]["import-ap"]["href"].as_str().unwrap();
+        assert_eq!(href, format!("#{}", resource_uuids[0]));
+        for subject in subjects(0) {
+            assert!(resource_uuids.contains(&subject.subject_uuid.as_deref().unwrap()));
+        }
+
+        // Recommendations become remediations of the finding's risk
+        assert_eq!(findings[0].related_risks.as_ref().unwrap()[0].risk_uuid, risks[0].uuid);
+        let remediations = risks[0].remediations.as_ref().unwrap();
+        let titles: Vec<&str> = remediations.iter().map(|remediation| remediation.title.as_str()).collect();
+        assert_eq!(titles, ["Recommended action", "Backfill completion dates"]);
+        assert_eq!(remediations[0].description, "Add a scheduled completion date");
+        assert_eq!(risks[1].remediations.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_assessment_without_findings_still_validates() {
+        let mut assessment = assessment();
+        assessment.findings.clear();
+
+        let document = OscalGenerator::new().generate_assessment_results(&assessment).unwrap();
+        let result = &document.assessment_results.results[0];
+        assert!(result.findings.is_none() && result.observations.is_none() && result.risks.is_none());
+    }
+
+    #[test]
+    fn test_generated_results_match_oscal_schema() {
+        let validator = super::super::OscalSchemaValidator::new();
+        let document = OscalGenerator::new().generate_assessment_results(&assessment()).unwrap();
+        validator.validate_assessment_results_document(&document).unwrap();
+
+        let json = serde_json::to_value(&document).unwrap();
+        let actor = &json["assessment-results"]["results"][0]["observations"][0]["origins"][0]["actors"][0];
+        assert!(actor.get("actor-uuid").is_some() && actor.get("role-id").is_none());
+
+        // Well-formed but not OSCAL: an untimed start and an unknown objective state
+        let mut untimed = document.clone();
+        untimed.assessment_results.results[0].start = "yesterday".to_string();
+        let error = validator.validate_assessment_results_document(&untimed).unwrap_err();
+        assert!(error.to_string().contains("/assessment-results/results/0/start"), "{}", error);
+
+        let mut unknown_state = document;
+        let findings = unknown_state.assessment_results.results[0].findings.as_mut().unwrap();
+        findings[0].target.status.as_mut().unwrap().state = "failed".to_string();
+        assert!(validator.validate_assessment_results_document(&unknown_state).is_err());
+    }
+
+    #[test]
+    fn test_unresolved_references_fail_validation() {
+        let validator = super::super::OscalSchemaValidator::new();
+        let document = OscalGenerator::new().generate_assessment_results(&assessment()).unwrap();
+
+        let mut unresolved_plan = document.clone();
+        unresolved_plan.assessment_results.import_ap.href = "#qa-1".to_string();
+        assert!(validator.validate_assessment_results_document(&unresolved_plan).is_err());
+
+        let mut undefined_subject = document;
+        undefined_subject.assessment_results.back_matter = Some(serde_json::json!({ "resources": [] }));
+        undefined_subject.assessment_results.import_ap.href = "plan.json".to_string();
+        assert!(validator.validate_assessment_results_document(&undefined_subject).is_err());
+    }
+}
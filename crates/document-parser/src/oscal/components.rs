@@ -0,0 +1,329 @@
+// Modified: 2025-09-24
+
+//! OSCAL component definitions from inventory data
+//!
+//! Each inventory asset becomes one component. An asset that names one of
+//! the `control_inheritance` mappings, through its `control_inheritance`
+//! custom attribute or a tag, gets a control implementation with one stub
+//! implemented requirement per inherited control. Asset relationships become
+//! component links pointing at the related component's UUID.
+
+use fedramp_core::models::Framework;
+use fedramp_core::validation::validate_control_id;
+use fedramp_core::Result;
+use std::collections::HashMap;
+use tracing::{info, warn};
+
+use super::documents::*;
+use super::generator::OscalGenerator;
+use super::types::{OscalLink, OscalProperty};
+use crate::excel::inventory::{Asset, AssetType, InventoryDocument};
+use crate::mapping::{normalize_field_name, ControlInheritance, InheritanceMapping};
+
+/// Catalog the control implementation stubs refer to
+pub const NIST_800_53_CATALOG_SOURCE: &str =
+    "https://raw.githubusercontent.com/usnistgov/oscal-content/main/nist.gov/SP800-53/rev5/json/NIST_SP-800-53_rev5_catalog.json";
+
+/// Custom attribute naming an asset's inheritance mapping
+const INHERITANCE_ATTRIBUTE: &str = "control_inheritance";
+
+impl OscalGenerator {
+    /// Generate a component definition with one component per inventory asset
+    pub fn generate_inventory_component_definition(
+        &mut self,
+        inventory: &InventoryDocument,
+        inheritance: &ControlInheritance,
+        metadata_overrides: Option<HashMap<String, String>>,
+    ) -> Result<OscalComponentDefinitionDocument> {
+        info!("Generating OSCAL Component Definition document from {} inventory assets", inventory.assets.len());
+
+        let document_uuid = self.uuid_generator.generate_uuid();
+        let metadata = self.build_component_metadata(metadata_overrides)?;
+
+        // Allocate every UUID up front so links can point forward
+        let component_uuids: HashMap<&str, String> = inventory.assets.iter()
+            .map(|asset| (asset.asset_id.as_str(), self.uuid_generator.generate_uuid()))
+            .collect();
+
+        let mut components = Vec::new();
+        for asset in &inventory.assets {
+            let control_implementations = self.inventory_control_implementations(asset, inheritance);
+            let links = asset_links(asset, inventory, &component_uuids);
+
+            components.push(Component {
+                uuid: component_uuids[asset.asset_id.as_str()].clone(),
+                component_type: component_type(&asset.asset_type).to_string(),
+                title: non_empty(&asset.asset_name).unwrap_or(&asset.asset_id).to_string(),
+                description: non_empty(&asset.description)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("Inventory asset {}", asset.asset_id)),
+                purpose: None,
+                props: Some(asset_props(asset)),
+                links: if links.is_empty() { None } else { Some(links) },
+                responsible_roles: None,
+                control_implementations: if control_implementations.is_empty() { None } else { Some(control_implementations) },
+            });
+        }
+
+        let document = OscalComponentDefinitionDocument {
+            component_definition: ComponentDefinition {
+                uuid: document_uuid,
+                metadata,
+                import_component_definitions: None,
+                components: if components.is_empty() { None } else { Some(components) },
+                capabilities: None,
+                back_matter: None,
+            },
+        };
+
+        if self.validate_output {
+            self.validate_component_definition(&document)?;
+        }
+
+        info!("Successfully generated OSCAL Component Definition document from inventory");
+        Ok(document)
+    }
+
+    /// Control implementation stub for the asset's inheritance mapping, if it has one
+    fn inventory_control_implementations(
+        &mut self,
+        asset: &Asset,
+        inheritance: &ControlInheritance,
+    ) -> Vec<ControlImplementation> {
+        let Some((name, mapping)) = inheritance_mapping(asset, inheritance) else {
+            return Vec::new();
+        };
+
+        let implemented_requirements: Vec<ImplementedRequirement> = inherited_controls(mapping, inheritance)
+            .into_iter()
+            .filter_map(|control| match oscal_control_id(&control) {
+                Some(control_id) => Some(ImplementedRequirement {
+                    uuid: self.uuid_generator.generate_uuid(),
+                    control_id,
+                    description: format!("{} is inherited from the provider", control),
+                    props: Some(vec![property("provider-responsibility", &mapping.provider_responsibility)]),
+                    links: None,
+                    set_parameters: None,
+                    statements: None,
+                    remarks: None,
+                }),
+                None => {
+                    warn!("Skipping invalid control ID '{}' in inheritance mapping '{}'", control, name);
+                    None
+                }
+            })
+            .collect();
+
+        if implemented_requirements.is_empty() {
+            return Vec::new();
+        }
+
+        vec![ControlImplementation {
+            uuid: self.uuid_generator.generate_uuid(),
+            source: NIST_800_53_CATALOG_SOURCE.to_string(),
+            description: format!("Controls inherited by {} through '{}'", asset.asset_id, name),
+            props: Some(vec![property("inheritance-mapping", name)]),
+            links: None,
+            set_parameters: None,
+            implemented_requirements,
+        }]
+    }
+}
+
+/// Inheritance mapping an asset names through its custom attribute or a tag
+///
+/// Names are compared in canonical form, so `Cloud Infrastructure` selects
+/// the `cloud_infrastructure` mapping.
+fn inheritance_mapping<'a>(
+    asset: &Asset,
+    inheritance: &'a ControlInheritance,
+) -> Option<(&'a str, &'a InheritanceMapping)> {
+    let candidates = asset.custom_attributes.get(INHERITANCE_ATTRIBUTE).into_iter().chain(&asset.tags);
+    for candidate in candidates {
+        let candidate = normalize_field_name(candidate);
+        let found = inheritance.inheritance_mappings.iter()
+            .find(|(name, _)| normalize_field_name(name) == candidate);
+        if let Some((name, mapping)) = found {
+            return Some((name.as_str(), mapping));
+        }
+    }
+    None
+}
+
+/// Controls a mapping inherits
+///
+/// `inherited_controls` names one of the control lists, or lists control IDs
+/// directly, separated by commas.
+fn inherited_controls(mapping: &InheritanceMapping, inheritance: &ControlInheritance) -> Vec<String> {
+    match mapping.inherited_controls.trim() {
+        "infrastructure_controls" => inheritance.infrastructure_controls.clone(),
+        "platform_controls" => inheritance.platform_controls.clone(),
+        controls => controls.split(',')
+            .map(str::trim)
+            .filter(|control| !control.is_empty())
+            .map(str::to_string)
+            .collect(),
+    }
+}
+
+/// NIST 800-53 control ID in OSCAL form, e.g. `ac-2` or `ac-2.1`
+fn oscal_control_id(control: &str) -> Option<String> {
+    let id = validate_control_id(control, Framework::Nist80053).ok()?;
+    let base = format!("{}-{}", id.family.to_lowercase(), id.number);
+    Some(match id.enhancement {
+        Some(enhancement) => format!("{}.{}", base, enhancement),
+        None => base,
+    })
+}
+
+/// OSCAL component type of an asset type
+fn component_type(asset_type: &AssetType) -> &'static str {
+    match asset_type {
+        AssetType::Hardware | AssetType::Network => "hardware",
+        AssetType::Software | AssetType::Virtual | AssetType::Data => "software",
+        AssetType::Cloud | AssetType::Service => "service",
+    }
+}
+
+/// Links from an asset's component to the components of related assets
+///
+/// Relationships come from the inventory's relationship list, where the asset
+/// is the source, and from the asset's own related IDs. Relationships to
+/// assets outside the inventory are dropped.
+fn asset_links(
+    asset: &Asset,
+    inventory: &InventoryDocument,
+    component_uuids: &HashMap<&str, String>,
+) -> Vec<OscalLink> {
+    let typed = inventory.relationships.iter()
+        .filter(|relationship| relationship.source_asset_id == asset.asset_id)
+        .map(|relationship| (relationship.target_asset_id.as_str(), relationship.relationship_type.to_string()));
+    let untyped = asset.relationships.iter().map(|target| (target.as_str(), "related".to_string()));
+
+    let mut links: Vec<OscalLink> = Vec::new();
+    for (target, rel) in typed.chain(untyped) {
+        let Some(target_uuid) = component_uuids.get(target) else {
+            warn!("Asset {} relates to unknown asset {}", asset.asset_id, target);
+            continue;
+        };
+        let href = format!("#{}", target_uuid);
+        if links.iter().any(|link| link.href == href) {
+            continue;
+        }
+        links.push(OscalLink {
+            href,
+            rel: Some(rel),
+            media_type: None,
+            resource_fragment: None,
+            text: Some(target.to_string()),
+        });
+    }
+    links
+}
+
+/// Inventory properties carried onto the component
+fn asset_props(asset: &Asset) -> Vec<OscalProperty> {
+    vec![
+        property("asset-id", &asset.asset_id),
+        property("asset-type", asset.asset_type_string()),
+        property("environment", &asset.environment.to_string()),
+        property("criticality", &asset.criticality.to_string()),
+    ]
+}
+
+fn property(name: &str, value: &str) -> OscalProperty {
+    OscalProperty {
+        name: name.to_string(),
+        value: value.to_string(),
+        class: None,
+    }
+}
+
+fn non_empty(value: &str) -> Option<&str> {
+    Some(value.trim()).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::excel::inventory::*;
+    use crate::oscal::validation::OscalSchemaValidator;
+
+    fn inventory() -> InventoryDocument {
+        let mut host = Asset::new("HW-001".to_string(), "Web host".to_string(), AssetType::Hardware);
+        host.custom_attributes.insert("control_inheritance".to_string(), "Cloud Infrastructure".to_string());
+        let app = Asset::new("SW-001".to_string(), "Web app".to_string(), AssetType::Software);
+
+        InventoryDocument {
+            metadata: InventoryMetadata {
+                template_type: InventoryTemplateType::FedRampIntegrated,
+                template_version: "1.0".to_string(),
+                source_file: "inventory.xlsx".to_string(),
+                parsed_at: chrono::Utc::now(),
+                asset_count: 2,
+                relationship_count: 1,
+            },
+            assets: vec![host, app],
+            relationships: vec![AssetRelationship {
+                id: "REL-1".to_string(),
+                source_asset_id: "SW-001".to_string(),
+                target_asset_id: "HW-001".to_string(),
+                relationship_type: RelationshipType::DependsOn,
+                description: None,
+                strength: RelationshipStrength::Strong,
+                attributes: HashMap::new(),
+            }],
+            validation_results: InventoryValidationResults::default(),
+            template_info: InventoryTemplateInfo {
+                template_type: InventoryTemplateType::FedRampIntegrated,
+                version: "1.0".to_string(),
+                asset_worksheets: vec!["Inventory".to_string()],
+                relationship_worksheets: Vec::new(),
+                column_mappings: HashMap::new(),
+            },
+        }
+    }
+
+    fn inheritance() -> ControlInheritance {
+        ControlInheritance {
+            infrastructure_controls: vec!["PE-3".to_string(), "SC-7(5)".to_string(), "bogus".to_string()],
+            platform_controls: vec!["AC-2".to_string()],
+            inheritance_mappings: HashMap::from([(
+                "cloud_infrastructure".to_string(),
+                InheritanceMapping {
+                    inherited_controls: "infrastructure_controls".to_string(),
+                    provider_responsibility: "full".to_string(),
+                },
+            )]),
+        }
+    }
+
+    #[test]
+    fn test_inventory_assets_become_linked_components() {
+        let mut generator = OscalGenerator::new();
+        let document = generator.generate_inventory_component_definition(&inventory(), &inheritance(), None).unwrap();
+        OscalSchemaValidator::new().validate_component_definition(&document).unwrap();
+
+        let components = document.component_definition.components.as_ref().unwrap();
+        assert_eq!(components.len(), 2);
+        let (host, app) = (&components[0], &components[1]);
+
+        assert_eq!((host.title.as_str(), host.component_type.as_str()), ("Web host", "hardware"));
+        assert_eq!((app.title.as_str(), app.component_type.as_str()), ("Web app", "software"));
+
+        let implementations = host.control_implementations.as_ref().unwrap();
+        assert_eq!(implementations.len(), 1);
+        let control_ids: Vec<&str> = implementations[0].implemented_requirements.iter()
+            .map(|requirement| requirement.control_id.as_str())
+            .collect();
+        assert_eq!(control_ids, ["pe-3", "sc-7.5"]);
+        assert_eq!(implementations[0].implemented_requirements[0].props.as_ref().unwrap()[0].value, "full");
+        assert!(app.control_implementations.is_none());
+
+        assert!(host.links.is_none());
+        let links = app.links.as_ref().unwrap();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].href, format!("#{}", host.uuid));
+        assert_eq!(links[0].rel.as_deref(), Some("depends-on"));
+    }
+}
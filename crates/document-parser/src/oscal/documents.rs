@@ -62,6 +62,74 @@ pub struct LocalDefinitions {
     pub tasks: Option<Vec<OscalTask>>,
 }
 
+/// OSCAL Assessment Results Document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OscalAssessmentResultsDocument {
+    #[serde(rename = "assessment-results")]
+    pub assessment_results: AssessmentResults,
+}
+
+/// Assessment Results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssessmentResults {
+    pub uuid: String,
+    pub metadata: OscalMetadata,
+    #[serde(rename = "import-ap")]
+    pub import_ap: ImportAp,
+    #[serde(rename = "local-definitions", skip_serializing_if = "Option::is_none")]
+    pub local_definitions: Option<LocalDefinitions>,
+    pub results: Vec<AssessmentResult>,
+    #[serde(rename = "back-matter", skip_serializing_if = "Option::is_none")]
+    pub back_matter: Option<serde_json::Value>,
+}
+
+/// Import Assessment Plan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportAp {
+    pub href: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remarks: Option<String>,
+}
+
+/// Assessment Result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssessmentResult {
+    pub uuid: String,
+    pub title: String,
+    pub description: String,
+    pub start: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub props: Option<Vec<OscalProperty>>,
+    #[serde(rename = "reviewed-controls")]
+    pub reviewed_controls: ReviewedControls,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub observations: Option<Vec<OscalObservation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub risks: Option<Vec<OscalRisk>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub findings: Option<Vec<OscalFinding>>,
+}
+
+/// Reviewed Controls
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewedControls {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "control-selections")]
+    pub control_selections: Vec<ControlSelection>,
+}
+
+/// Control Selection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlSelection {
+    #[serde(rename = "include-all", skip_serializing_if = "Option::is_none")]
+    pub include_all: Option<IncludeAll>,
+    #[serde(rename = "include-controls", skip_serializing_if = "Option::is_none")]
+    pub include_controls: Option<Vec<IncludeControl>>,
+}
+
 /// OSCAL Component Definition Document
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OscalComponentDefinitionDocument {
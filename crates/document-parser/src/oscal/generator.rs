@@ -32,11 +32,11 @@ pub struct OscalGenerator {
     /// System name for metadata
     pub system_name: String,
     /// Schema validator
-    schema_validator: OscalSchemaValidator,
+    pub(super) schema_validator: OscalSchemaValidator,
     /// UUID generator
-    uuid_generator: UuidGenerator,
+    pub(super) uuid_generator: UuidGenerator,
     /// Metadata builder
-    metadata_builder: MetadataBuilder,
+    pub(super) metadata_builder: MetadataBuilder,
     /// POA&M processor
     poam_processor: PoamItemProcessor,
     /// Risk processor
@@ -225,7 +225,7 @@ impl OscalGenerator {
     }
 
     /// Build component definition metadata
-    fn build_component_metadata(
+    pub(super) fn build_component_metadata(
         &self,
         overrides: Option<HashMap<String, String>>,
     ) -> Result<OscalMetadata> {
@@ -340,7 +340,7 @@ impl OscalGenerator {
     }
 
     /// Validate component definition document
-    fn validate_component_definition(&self, document: &OscalComponentDefinitionDocument) -> Result<()> {
+    pub(super) fn validate_component_definition(&self, document: &OscalComponentDefinitionDocument) -> Result<()> {
         debug!("Validating OSCAL Component Definition document");
         
         // Basic validation
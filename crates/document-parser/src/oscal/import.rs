@@ -0,0 +1,287 @@
+// Modified: 2025-09-24
+
+//! OSCAL POA&M import
+//!
+//! Reads a `plan-of-action-and-milestones` document back into [`PoamItem`]s,
+//! reversing the mapping of [`PoamItemProcessor`](super::PoamItemProcessor).
+//! Item properties with a `PoamItem` field fill that field; every other
+//! property, related observation and risk, and the remediation plan of the
+//! tracking entries goes into the item's metadata map.
+//!
+//! Problems with individual items are collected as validation errors located
+//! by JSON Pointer in the document, and the remaining items are still read.
+//! Items without a valid UUID are skipped.
+
+use fedramp_core::{Result, Error};
+use serde_json::Value;
+use tracing::info;
+
+use crate::poam::PoamItem;
+use crate::{DocumentType, ParseResult, ValidationError, ValidationSeverity};
+
+/// Pointer to the POA&M items of a document
+const POAM_ITEMS_POINTER: &str = "/plan-of-action-and-milestones/poam-items";
+
+/// Metadata key of the OSCAL UUID when the item's source ID differs from it
+pub const OSCAL_UUID_METADATA_KEY: &str = "oscal_uuid";
+
+/// POA&M items read from an OSCAL document
+#[derive(Debug, Clone)]
+pub struct OscalPoamImport {
+    /// Items that could be read, in document order
+    pub items: Vec<PoamItem>,
+    /// The document as content, with the problems found in it
+    pub parse_result: ParseResult,
+}
+
+/// Read the POA&M items of an OSCAL plan-of-action-and-milestones JSON document
+///
+/// Property names are accepted in OSCAL's kebab-case and in snake_case.
+///
+/// # Errors
+///
+/// Returns error if the data is not JSON or has no
+/// `plan-of-action-and-milestones` object; problems with individual items
+/// are reported in the parse result instead
+pub fn parse_oscal_poam(data: &[u8], source_path: &str) -> Result<OscalPoamImport> {
+    let document: Value = serde_json::from_slice(data)
+        .map_err(|e| Error::document_parsing(format!("Failed to parse OSCAL JSON from {}: {}", source_path, e)))?;
+    let poam = document.get("plan-of-action-and-milestones")
+        .and_then(Value::as_object)
+        .ok_or_else(|| Error::document_parsing(format!("{} is not an OSCAL plan-of-action-and-milestones document", source_path)))?;
+
+    let mut validation_errors = Vec::new();
+    let entries = match field(poam, "poam-items") {
+        Some(Value::Array(entries)) => entries.as_slice(),
+        _ => {
+            validation_errors.push(ValidationError::new(
+                "/plan-of-action-and-milestones",
+                "Document has no poam-items array",
+                ValidationSeverity::Error,
+            ));
+            &[]
+        }
+    };
+
+    let items: Vec<PoamItem> = entries.iter()
+        .enumerate()
+        .filter_map(|(index, entry)| import_item(entry, &format!("{}/{}", POAM_ITEMS_POINTER, index), &mut validation_errors))
+        .collect();
+
+    info!("Imported {} of {} OSCAL POA&M items from {}", items.len(), entries.len(), source_path);
+
+    let metadata = poam.get("metadata");
+    let parse_result = ParseResult {
+        document_type: DocumentType::Json,
+        source_path: source_path.to_string(),
+        metadata: serde_json::json!({
+            "uuid": poam.get("uuid"),
+            "title": metadata.and_then(|metadata| metadata.get("title")),
+            "oscal_version": metadata.and_then(|metadata| field_in(metadata, "oscal-version")),
+            "item_count": entries.len(),
+            "imported_item_count": items.len(),
+        }),
+        quality_score: if entries.is_empty() { 1.0 } else { items.len() as f64 / entries.len() as f64 },
+        content: document,
+        validation_errors,
+    };
+
+    Ok(OscalPoamImport { items, parse_result })
+}
+
+/// Read one POA&M item; `None` when it is not an object or has no valid UUID
+fn import_item(entry: &Value, pointer: &str, errors: &mut Vec<ValidationError>) -> Option<PoamItem> {
+    let mut error = |path: String, message: String| errors.push(ValidationError::new(path, message, ValidationSeverity::Error));
+
+    let Some(entry) = entry.as_object() else {
+        error(pointer.to_string(), "POA&M item is not an object".to_string());
+        return None;
+    };
+    let uuid = match entry.get("uuid").and_then(Value::as_str) {
+        Some(uuid) if is_uuid(uuid) => uuid.to_string(),
+        Some(uuid) => {
+            error(format!("{}/uuid", pointer), format!("POA&M item UUID '{}' is not a valid UUID", uuid));
+            return None;
+        }
+        None => {
+            error(pointer.to_string(), "POA&M item has no uuid".to_string());
+            return None;
+        }
+    };
+
+    let mut item = PoamItem::new(uuid.clone(), String::new(), String::new(), String::new());
+    for (name, target) in [("title", &mut item.title), ("description", &mut item.description)] {
+        match non_empty_str(entry.get(name)) {
+            Some(value) => *target = value.to_string(),
+            None => error(pointer.to_string(), format!("POA&M item {} has no {}", uuid, name)),
+        }
+    }
+
+    let props = match field(entry, "props") {
+        Some(Value::Array(props)) => props.as_slice(),
+        _ => &[],
+    };
+    for (index, prop) in props.iter().enumerate() {
+        let (Some(name), Some(value)) = (non_empty_str(prop.get("name")), prop.get("value").and_then(Value::as_str)) else {
+            error(format!("{}/props/{}", pointer, index), "Property needs a name and a value".to_string());
+            continue;
+        };
+        let value = value.trim().to_string();
+        match name {
+            "poam-id" => {
+                item.metadata.insert(OSCAL_UUID_METADATA_KEY.to_string(), uuid.clone());
+                item.uuid = value;
+            }
+            "control-id" => item.controls.extend(value.split(',').map(str::trim).filter(|id| !id.is_empty()).map(str::to_string)),
+            "severity" => item.severity = Some(value),
+            "status" => item.status = value,
+            "scheduled-completion-date" => item.scheduled_completion_date = Some(value),
+            "actual-completion-date" => item.actual_completion_date = Some(value),
+            "point-of-contact" => item.responsible_entity = Some(value),
+            _ => {
+                item.metadata.insert(name.replace('-', "_"), value);
+            }
+        }
+    }
+    if item.status.is_empty() {
+        error(format!("{}/props", pointer), format!("POA&M item {} has no status property", uuid));
+    }
+
+    for (key, collection, reference) in [
+        ("related_observations", "related-observations", "observation-uuid"),
+        ("related_risks", "related-risks", "risk-uuid"),
+    ] {
+        let references: Vec<&str> = match field(entry, collection) {
+            Some(Value::Array(related)) => related.iter().filter_map(|related| non_empty_str(field_in(related, reference))).collect(),
+            _ => Vec::new(),
+        };
+        if !references.is_empty() {
+            item.metadata.insert(key.to_string(), references.join(","));
+        }
+    }
+
+    let remediation_plan = field(entry, "remediation-tracking")
+        .and_then(|tracking| field_in(tracking, "tracking-entries"))
+        .and_then(Value::as_array)
+        .and_then(|entries| entries.iter().find_map(|entry| non_empty_str(entry.get("description"))));
+    if let Some(remediation_plan) = remediation_plan {
+        item.metadata.insert("remediation_plan".to_string(), remediation_plan.to_string());
+    }
+
+    Some(item)
+}
+
+/// A member by its OSCAL name, or by the snake_case form of it
+fn field<'a>(object: &'a serde_json::Map<String, Value>, name: &str) -> Option<&'a Value> {
+    object.get(name).or_else(|| object.get(&name.replace('-', "_")))
+}
+
+fn field_in<'a>(value: &'a Value, name: &str) -> Option<&'a Value> {
+    value.as_object().and_then(|object| field(object, name))
+}
+
+fn non_empty_str(value: Option<&Value>) -> Option<&str> {
+    value.and_then(Value::as_str).map(str::trim).filter(|value| !value.is_empty())
+}
+
+/// Whether a string is a UUID in the hyphenated form OSCAL requires
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36 && uuid::Uuid::parse_str(value).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oscal::OscalGenerator;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn row(fields: &[(&str, &str)]) -> HashMap<String, Value> {
+        fields.iter().map(|(name, value)| (name.to_string(), json!(value))).collect()
+    }
+
+    #[test]
+    fn test_generated_poam_round_trips() {
+        let rows = vec![
+            row(&[
+                ("uuid", "V-001"),
+                ("title", "Outdated TLS configuration"),
+                ("description", "TLS 1.0 is enabled on the load balancer"),
+                ("control_id", "SC-8"),
+                ("severity", "High"),
+                ("status", "Open"),
+                ("detection_date", "2024-01-15"),
+                ("scheduled_completion_date", "2024-03-31"),
+                ("point_of_contact", "Network Team"),
+                ("remediation_plan", "Disable TLS 1.0 and 1.1"),
+            ]),
+            row(&[
+                ("uuid", "6f1f6c1e-6a53-4c2e-9a8e-0d5b7a1c2e3f"),
+                ("title", "Missing MFA"),
+                ("description", "Administrators can sign in without MFA"),
+                ("status", "Completed"),
+                ("actual_completion_date", "2024-02-01"),
+            ]),
+        ];
+        let document = OscalGenerator::new().generate_poam_document(&rows, None).unwrap();
+        let json = serde_json::to_vec(&document).unwrap();
+
+        let import = parse_oscal_poam(&json, "poam.json").unwrap();
+        assert!(import.parse_result.validation_errors.is_empty(), "{:?}", import.parse_result.validation_errors);
+        assert_eq!(import.items.len(), 2);
+
+        let tls = &import.items[0];
+        assert_eq!(tls.uuid, "V-001");
+        assert_eq!(tls.title, "Outdated TLS configuration");
+        assert_eq!(tls.description, "TLS 1.0 is enabled on the load balancer");
+        assert_eq!(tls.controls, ["SC-8"]);
+        assert_eq!((tls.severity.as_deref(), tls.status.as_str()), (Some("High"), "Open"));
+        assert_eq!(tls.scheduled_completion_date.as_deref(), Some("2024-03-31"));
+        assert_eq!(tls.responsible_entity.as_deref(), Some("Network Team"));
+        assert_eq!(tls.metadata["detection_date"], "2024-01-15");
+        assert_eq!(tls.metadata["remediation_plan"], "Disable TLS 1.0 and 1.1");
+        assert_eq!(tls.metadata[OSCAL_UUID_METADATA_KEY], document.plan_of_action_and_milestones.poam_items[0].uuid);
+
+        let mfa = &import.items[1];
+        assert_eq!(mfa.uuid, "6f1f6c1e-6a53-4c2e-9a8e-0d5b7a1c2e3f");
+        assert!(!mfa.metadata.contains_key(OSCAL_UUID_METADATA_KEY));
+        assert_eq!(mfa.actual_completion_date.as_deref(), Some("2024-02-01"));
+        assert!(mfa.is_closed());
+    }
+
+    #[test]
+    fn test_item_problems_accumulate() {
+        let document = json!({
+            "plan-of-action-and-milestones": {
+                "uuid": "0c6f5d0e-3b1a-4a55-8d2c-7f0e9b8a1d2c",
+                "metadata": { "title": "Reviewed POA&M", "oscal-version": "1.1.2" },
+                "poam-items": [
+                    { "uuid": "not-a-uuid", "title": "Bad", "description": "Bad UUID" },
+                    {
+                        "uuid": "9b2e4f6a-1c3d-4e5f-8a7b-6c5d4e3f2a1b",
+                        "title": "Unpatched kernel",
+                        "props": [{ "name": "vendor-dependency", "value": "Yes" }],
+                        "related-risks": [{ "risk-uuid": "1d2c3b4a-5e6f-4a7b-8c9d-0e1f2a3b4c5d" }]
+                    },
+                    "not an item"
+                ]
+            }
+        });
+
+        let import = parse_oscal_poam(&serde_json::to_vec(&document).unwrap(), "reviewed.json").unwrap();
+        assert_eq!(import.items.len(), 1);
+        let item = &import.items[0];
+        assert_eq!(item.metadata["vendor_dependency"], "Yes");
+        assert_eq!(item.metadata["related_risks"], "1d2c3b4a-5e6f-4a7b-8c9d-0e1f2a3b4c5d");
+
+        let paths: Vec<&str> = import.parse_result.validation_errors.iter().map(|error| error.path.as_str()).collect();
+        assert_eq!(paths, [
+            "/plan-of-action-and-milestones/poam-items/0/uuid",
+            "/plan-of-action-and-milestones/poam-items/1",
+            "/plan-of-action-and-milestones/poam-items/1/props",
+            "/plan-of-action-and-milestones/poam-items/2",
+        ]);
+        assert_eq!(import.parse_result.metadata["imported_item_count"], 1);
+        assert!(parse_oscal_poam(b"{}", "empty.json").is_err());
+    }
+}
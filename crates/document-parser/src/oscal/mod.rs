@@ -10,6 +10,10 @@
 //! - `documents`: Top-level OSCAL document containers
 //! - `processors`: Business logic for transforming data into OSCAL structures
 //! - `generator`: Main OSCAL generator orchestrating document creation
+//! - `components`: Component definitions generated from inventory assets
+//! - `assessment_results`: Assessment results builder shared by exporters, and its use for quality assessments
+//! - `import`: POA&M items read back from OSCAL POA&M documents
+//! - `streaming`: Incremental JSON output of large documents
 //! - `validation`: Schema validation and structural validation
 //! - `utils`: Utility functions for UUID generation, metadata building, etc.
 
@@ -17,6 +21,10 @@ pub mod types;
 pub mod documents;
 pub mod processors;
 pub mod generator;
+pub mod components;
+pub mod assessment_results;
+pub mod import;
+pub mod streaming;
 pub mod validation;
 pub mod utils;
 
@@ -25,5 +33,7 @@ pub use types::*;
 pub use documents::*;
 pub use processors::{PoamItemProcessor, RiskProcessor, ObservationProcessor};
 pub use generator::OscalGenerator;
+pub use assessment_results::AssessmentResultsBuilder;
+pub use import::{parse_oscal_poam, OscalPoamImport};
 pub use validation::OscalSchemaValidator;
 pub use utils::{UuidGenerator, MetadataBuilder, OscalUtils};
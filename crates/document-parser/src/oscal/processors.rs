@@ -125,11 +125,24 @@ impl PoamItemProcessor {
             .or_else(|| self.extract_string_field(row, "finding_description"))
             .unwrap_or_else(|| "No description provided".to_string());
 
-        // Generate UUID
-        let uuid = Uuid::new_v4().to_string();
+        // Keep the source UUID when it is one, otherwise generate a UUID and
+        // record the source identifier so it can be written back out
+        let source_id = self.extract_string_field(row, "uuid");
+        let uuid = source_id.as_deref()
+            .and_then(|id| Uuid::parse_str(id).ok())
+            .unwrap_or_else(Uuid::new_v4)
+            .to_string();
 
         // Extract properties
         let mut props = Vec::new();
+
+        if let Some(source_id) = source_id.filter(|id| !id.eq_ignore_ascii_case(&uuid)) {
+            props.push(OscalProperty {
+                name: "poam-id".to_string(),
+                value: source_id,
+                class: None,
+            });
+        }
         
         // Add control ID if available
         if let Some(control_id) = self.extract_string_field(row, "control_id") {
@@ -158,6 +171,24 @@ impl PoamItemProcessor {
             });
         }
 
+        // Add dates and point of contact if available
+        let item_fields = [
+            ("detection_date", "detection-date", Some("date")),
+            ("scheduled_completion_date", "scheduled-completion-date", Some("date")),
+            ("actual_completion_date", "actual-completion-date", Some("date")),
+            ("point_of_contact", "point-of-contact", None),
+            ("comments", "comments", None),
+        ];
+        for (field, name, class) in item_fields {
+            if let Some(value) = self.extract_string_field(row, field) {
+                props.push(OscalProperty {
+                    name: name.to_string(),
+                    value,
+                    class: class.map(str::to_string),
+                });
+            }
+        }
+
         // Process related observations if configured
         let related_observations = if self.config.include_observations {
             self.process_related_observations(row)?
@@ -0,0 +1,186 @@
+// Modified: 2025-09-22
+
+//! Streaming OSCAL JSON serialization
+//!
+//! Serializing a large POA&M with `to_json_string` holds the whole
+//! pretty-printed document in memory on top of the document itself. The
+//! writers here emit the same bytes to a sink piece by piece instead: the
+//! document envelope first, then observations, risks, findings and POA&M
+//! items one element at a time.
+
+use fedramp_core::{Result, Error};
+use serde::Serialize;
+use std::io::Write;
+use std::iter;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use super::documents::{OscalPoamDocument, PlanOfActionAndMilestones};
+use super::generator::OscalGenerator;
+
+/// Indentation of the members of `plan-of-action-and-milestones`
+const MEMBER_INDENT: &str = "    ";
+
+/// Indentation of the elements of its arrays
+const ELEMENT_INDENT: &str = "      ";
+
+/// Pieces of serialized output, produced lazily
+type Chunks<'a> = Box<dyn Iterator<Item = Result<String>> + 'a>;
+
+impl OscalGenerator {
+    /// Write a POA&M document as pretty-printed JSON to a sink, one element at a time
+    ///
+    /// The output is byte-for-byte identical to [`OscalGenerator::to_json_string`].
+    pub fn write_poam_json<W: Write>(&self, document: &OscalPoamDocument, mut writer: W) -> Result<()> {
+        for chunk in poam_json_chunks(document) {
+            writer.write_all(chunk?.as_bytes()).map_err(write_error)?;
+        }
+        writer.flush().map_err(write_error)
+    }
+
+    /// Asynchronous counterpart of [`OscalGenerator::write_poam_json`]
+    pub async fn write_poam_json_async<W: AsyncWrite + Unpin>(
+        &self,
+        document: &OscalPoamDocument,
+        mut writer: W,
+    ) -> Result<()> {
+        for chunk in poam_json_chunks(document) {
+            writer.write_all(chunk?.as_bytes()).await.map_err(write_error)?;
+        }
+        writer.flush().await.map_err(write_error)
+    }
+}
+
+/// Serialized pieces of a POA&M document in `serde_json` pretty-printed layout
+///
+/// Members are listed in the field order of `PlanOfActionAndMilestones`, with
+/// the same optional members skipped, so the concatenated chunks match its
+/// derived `Serialize` output. The struct is destructured exhaustively, so a
+/// new field fails to compile until it is written here too.
+fn poam_json_chunks(document: &OscalPoamDocument) -> Chunks<'_> {
+    let PlanOfActionAndMilestones {
+        uuid,
+        metadata,
+        import_ssp,
+        system_id,
+        local_definitions,
+        observations,
+        risks,
+        findings,
+        poam_items,
+        back_matter,
+    } = &document.plan_of_action_and_milestones;
+
+    let mut members: Vec<Chunks<'_>> = vec![member("uuid", uuid), member("metadata", metadata)];
+    if let Some(import_ssp) = import_ssp {
+        members.push(member("import-ssp", import_ssp));
+    }
+    if let Some(system_id) = system_id {
+        members.push(member("system-id", system_id));
+    }
+    if let Some(local_definitions) = local_definitions {
+        members.push(member("local-definitions", local_definitions));
+    }
+    if let Some(observations) = observations {
+        members.push(array_member("observations", observations));
+    }
+    if let Some(risks) = risks {
+        members.push(array_member("risks", risks));
+    }
+    if let Some(findings) = findings {
+        members.push(array_member("findings", findings));
+    }
+    members.push(array_member("poam-items", poam_items));
+    if let Some(back_matter) = back_matter {
+        members.push(member("back-matter", back_matter));
+    }
+
+    let members = members.into_iter().enumerate().flat_map(|(index, chunks)| {
+        let separator = if index == 0 { "\n" } else { ",\n" };
+        iter::once(Ok(separator.to_string())).chain(chunks)
+    });
+
+    Box::new(
+        iter::once(Ok("{\n  \"plan-of-action-and-milestones\": {".to_string()))
+            .chain(members)
+            .chain(iter::once(Ok("\n  }\n}".to_string()))),
+    )
+}
+
+/// Member serialized in one piece
+fn member<'a, T: Serialize>(key: &'static str, value: &'a T) -> Chunks<'a> {
+    Box::new(iter::once_with(move || {
+        render(value, MEMBER_INDENT).map(|json| format!("{}\"{}\": {}", MEMBER_INDENT, key, json))
+    }))
+}
+
+/// Array member serialized one element at a time
+fn array_member<'a, T: Serialize>(key: &'static str, elements: &'a [T]) -> Chunks<'a> {
+    if elements.is_empty() {
+        return Box::new(iter::once(Ok(format!("{}\"{}\": []", MEMBER_INDENT, key))));
+    }
+
+    let elements = elements.iter().enumerate().map(|(index, element)| {
+        let separator = if index == 0 { "" } else { "," };
+        render(element, ELEMENT_INDENT).map(|json| format!("{}\n{}{}", separator, ELEMENT_INDENT, json))
+    });
+
+    Box::new(
+        iter::once(Ok(format!("{}\"{}\": [", MEMBER_INDENT, key)))
+            .chain(elements)
+            .chain(iter::once(Ok(format!("\n{}]", MEMBER_INDENT)))),
+    )
+}
+
+/// Pretty-printed JSON of a value nested at `indent`
+///
+/// Pretty-printed JSON escapes newlines inside strings, so every line break
+/// is structural and can take the extra indentation.
+fn render<T: Serialize>(value: &T, indent: &str) -> Result<String> {
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| Error::validation(format!("Failed to serialize OSCAL document: {}", e)))?;
+    Ok(json.replace('\n', &format!("\n{}", indent)))
+}
+
+fn write_error(e: std::io::Error) -> Error {
+    Error::internal(format!("Failed to write OSCAL document: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+
+    fn poam_rows(count: usize) -> Vec<HashMap<String, Value>> {
+        (0..count)
+            .map(|index| {
+                let row = json!({
+                    "uuid": format!("item-{}", index),
+                    "title": format!("Finding \"{}\"", index),
+                    "description": format!("Line one\nLine two of finding {}", index),
+                    "control_id": "AC-2",
+                    "severity": if index % 2 == 0 { "High" } else { "Low" },
+                    "status": "Open",
+                    "scheduled_completion_date": "2025-12-31",
+                    "remediation_plan": "Patch the affected hosts",
+                });
+                serde_json::from_value(row).unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_streamed_poam_matches_buffered_output() {
+        let mut generator = OscalGenerator::new();
+        let document = generator.generate_poam_document(&poam_rows(250), None).unwrap();
+        let buffered = generator.to_json_string(&document).unwrap();
+
+        let mut streamed = Vec::new();
+        generator.write_poam_json(&document, &mut streamed).unwrap();
+        assert_eq!(String::from_utf8(streamed).unwrap(), buffered);
+
+        let mut streamed_async = Vec::new();
+        generator.write_poam_json_async(&document, &mut streamed_async).await.unwrap();
+        assert_eq!(String::from_utf8(streamed_async).unwrap(), buffered);
+    }
+}
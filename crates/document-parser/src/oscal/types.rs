@@ -25,162 +25,219 @@ pub enum OscalDocumentType {
 
 /// OSCAL metadata structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalMetadata {
     pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub published: Option<String>,
     pub last_modified: String,
     pub version: String,
     pub oscal_version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub responsible_parties: Option<HashMap<String, OscalResponsibleParty>>,
 }
 
 /// OSCAL property
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalProperty {
     pub name: String,
     pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub class: Option<String>,
 }
 
 /// OSCAL responsible party
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalResponsibleParty {
     pub party_uuids: Vec<String>,
 }
 
 /// OSCAL POA&M Item
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalPoamItem {
     pub uuid: String,
     pub title: String,
     pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub related_observations: Option<Vec<OscalRelatedObservation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub related_risks: Option<Vec<OscalRelatedRisk>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remediation_tracking: Option<OscalRemediationTracking>,
 }
 
 /// OSCAL Related Observation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRelatedObservation {
     pub observation_uuid: String,
 }
 
 /// OSCAL Related Risk
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRelatedRisk {
     pub risk_uuid: String,
 }
 
 /// OSCAL Remediation Tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRemediationTracking {
     pub tracking_entries: Vec<OscalTrackingEntry>,
 }
 
 /// OSCAL Tracking Entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalTrackingEntry {
     pub uuid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status_change: Option<String>,
     pub date_time_stamp: String,
 }
 
 /// OSCAL Observation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalObservation {
     pub uuid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
     pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
     pub methods: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub types: Option<Vec<String>>,
     pub origins: Vec<OscalOrigin>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subjects: Option<Vec<OscalSubject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub relevant_evidence: Option<Vec<OscalRelevantEvidence>>,
     pub collected: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remarks: Option<String>,
 }
 
 /// OSCAL Origin
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalOrigin {
     pub actors: Vec<OscalActor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub related_tasks: Option<Vec<OscalRelatedTask>>,
 }
 
 /// OSCAL Actor
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalActor {
     #[serde(rename = "type")]
     pub actor_type: String,
     pub actor_uuid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub role_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
 }
 
 /// OSCAL Related Task
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRelatedTask {
     pub task_uuid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
 }
 
 /// OSCAL Subject
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalSubject {
     #[serde(rename = "type")]
     pub subject_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subject_uuid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
 }
 
 /// OSCAL Relevant Evidence
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRelevantEvidence {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub href: Option<String>,
     pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remarks: Option<String>,
 }
 
 /// OSCAL Risk
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRisk {
     pub uuid: String,
     pub title: String,
     pub description: String,
     pub statement: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
     pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub origins: Option<Vec<OscalOrigin>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub threat_ids: Option<Vec<OscalThreatId>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub characterizations: Option<Vec<OscalCharacterization>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub mitigating_factors: Option<Vec<OscalMitigatingFactor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub deadline: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remediations: Option<Vec<OscalRemediation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub risk_log: Option<OscalRiskLog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub related_observations: Option<Vec<OscalRelatedObservation>>,
 }
 
 /// OSCAL Threat ID
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalThreatId {
     pub system: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub href: Option<String>,
     pub id: String,
 }
 
 /// OSCAL Characterization
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalCharacterization {
     pub props: Vec<OscalProperty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<OscalLink>>,
     pub origin: OscalOrigin,
     pub facets: Vec<OscalFacet>,
@@ -188,82 +245,115 @@ pub struct OscalCharacterization {
 
 /// OSCAL Facet
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalFacet {
     pub name: String,
     pub system: String,
     pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
 }
 
 /// OSCAL Mitigating Factor
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalMitigatingFactor {
     pub uuid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub implementation_uuid: Option<String>,
     pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subjects: Option<Vec<OscalSubject>>,
 }
 
 /// OSCAL Remediation
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRemediation {
     pub uuid: String,
     pub lifecycle: String,
     pub title: String,
     pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub origins: Option<Vec<OscalOrigin>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub required_assets: Option<Vec<OscalRequiredAsset>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tasks: Option<Vec<OscalTask>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remarks: Option<String>,
 }
 
 /// OSCAL Required Asset
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRequiredAsset {
     pub uuid: String,
     pub subjects: Vec<OscalSubject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remarks: Option<String>,
 }
 
 /// OSCAL Task
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalTask {
     pub uuid: String,
     #[serde(rename = "type")]
     pub task_type: String,
     pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub timing: Option<OscalTiming>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dependencies: Option<Vec<OscalDependency>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub tasks: Option<Vec<OscalTask>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub associated_activities: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subjects: Option<Vec<OscalSubject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub responsible_roles: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remarks: Option<String>,
 }
 
 /// OSCAL Timing
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalTiming {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub on_date: Option<OscalOnDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub within_date_range: Option<OscalWithinDateRange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub at_frequency: Option<OscalAtFrequency>,
 }
 
 /// OSCAL On Date
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalOnDate {
     pub date: String,
 }
 
 /// OSCAL Within Date Range
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalWithinDateRange {
     pub start: String,
     pub end: String,
@@ -271,6 +361,7 @@ pub struct OscalWithinDateRange {
 
 /// OSCAL At Frequency
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalAtFrequency {
     pub period: String,
     pub unit: String,
@@ -278,78 +369,109 @@ pub struct OscalAtFrequency {
 
 /// OSCAL Dependency
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalDependency {
     pub task_uuid: String,
 }
 
 /// OSCAL Risk Log
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRiskLog {
     pub entries: Vec<OscalRiskLogEntry>,
 }
 
 /// OSCAL Risk Log Entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalRiskLogEntry {
     pub uuid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
     pub logged: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub logged_by: Option<OscalLoggedBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub related_responses: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub remarks: Option<String>,
 }
 
 /// OSCAL Logged By
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalLoggedBy {
     pub party_uuid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub role_id: Option<String>,
 }
 
 /// OSCAL Link
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalLink {
     pub href: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub rel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub media_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_fragment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
 }
 
 /// OSCAL Finding
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalFinding {
     pub uuid: String,
     pub title: String,
     pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<OscalLink>>,
     pub origins: Vec<OscalOrigin>,
     pub target: OscalTarget,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub implementation_statement_uuid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub related_observations: Option<Vec<OscalRelatedObservation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub related_risks: Option<Vec<OscalRelatedRisk>>,
 }
 
 /// OSCAL Target
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalTarget {
     #[serde(rename = "type")]
     pub target_type: String,
     pub target_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub props: Option<Vec<OscalProperty>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub links: Option<Vec<OscalLink>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<OscalImplementationStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub implementation_status: Option<OscalImplementationStatus>,
 }
 
 /// OSCAL Implementation Status
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub struct OscalImplementationStatus {
     pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reason: Option<String>,
 }
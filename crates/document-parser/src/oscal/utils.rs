@@ -181,6 +181,16 @@ impl MetadataBuilder {
         let title = format!("System Security Plan for {}", system_name);
         self.build_metadata(&title, "1.0", "1.1.2", overrides)
     }
+
+    /// Build assessment results specific metadata
+    pub fn build_assessment_results_metadata(
+        &self,
+        system_name: &str,
+        overrides: Option<&HashMap<String, String>>,
+    ) -> OscalMetadata {
+        let title = format!("Assessment Results for {}", system_name);
+        self.build_metadata(&title, "1.0", "1.1.2", overrides)
+    }
 }
 
 /// Utility functions for OSCAL operations
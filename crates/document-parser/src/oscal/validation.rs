@@ -3,15 +3,22 @@
 //! OSCAL schema validation
 //!
 //! This module provides validation functionality for OSCAL documents
-//! against official OSCAL schemas.
+//! against official OSCAL schemas. The OSCAL 1.1.2 assessment results JSON
+//! Schema is bundled; other document types get structural checks only.
 
 use fedramp_core::{Result, Error};
-use std::collections::HashMap;
+use jsonschema::JSONSchema;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
 use tracing::{debug, info, warn};
 
 use super::types::*;
 use super::documents::*;
 
+/// OSCAL 1.1.2 assessment results JSON Schema
+pub const OSCAL_ASSESSMENT_RESULTS_SCHEMA: &str = include_str!("../../schemas/oscal_assessment-results_schema.json");
+
 /// OSCAL schema validator
 #[derive(Debug, Clone)]
 pub struct OscalSchemaValidator {
@@ -84,6 +91,22 @@ impl OscalSchemaValidator {
         Ok(())
     }
 
+    /// Validate an assessment results document
+    ///
+    /// Besides the structural checks, the document is always validated
+    /// against the bundled OSCAL 1.1.2 assessment results schema.
+    pub fn validate_assessment_results_document(&self, document: &OscalAssessmentResultsDocument) -> Result<()> {
+        debug!("Validating Assessment Results document");
+        
+        // Basic structural validation
+        self.validate_assessment_results_structure(document)?;
+        
+        self.validate_against_schema(document, &OscalDocumentType::AssessmentResults)?;
+        
+        info!("Assessment Results document validation completed successfully");
+        Ok(())
+    }
+
     /// Validate POA&M document structure
     fn validate_poam_structure(&self, document: &OscalPoamDocument) -> Result<()> {
         let poam = &document.plan_of_action_and_milestones;
@@ -169,6 +192,137 @@ impl OscalSchemaValidator {
         Ok(())
     }
 
+    /// Validate assessment results structure
+    fn validate_assessment_results_structure(&self, document: &OscalAssessmentResultsDocument) -> Result<()> {
+        let assessment_results = &document.assessment_results;
+        
+        // Validate required fields
+        if assessment_results.uuid.is_empty() {
+            return Err(Error::validation("Assessment Results UUID is required"));
+        }
+
+        if assessment_results.metadata.title.is_empty() {
+            return Err(Error::validation("Assessment Results title is required"));
+        }
+
+        if assessment_results.metadata.oscal_version.is_empty() {
+            return Err(Error::validation("OSCAL version is required"));
+        }
+
+        if assessment_results.import_ap.href.is_empty() {
+            return Err(Error::validation("Assessment Results must import an assessment plan"));
+        }
+
+        // Fragment references must name a back-matter resource
+        let resources: HashSet<&str> = assessment_results.back_matter.as_ref()
+            .and_then(|back_matter| back_matter["resources"].as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|resource| resource["uuid"].as_str())
+            .collect();
+        if let Some(plan_uuid) = assessment_results.import_ap.href.strip_prefix('#') {
+            if !resources.contains(plan_uuid) {
+                return Err(Error::validation(format!(
+                    "Assessment Results imports unknown assessment plan resource {}",
+                    plan_uuid
+                )));
+            }
+        }
+        
+        if assessment_results.results.is_empty() {
+            return Err(Error::validation("Assessment Results must contain at least one result"));
+        }
+        
+        for (index, result) in assessment_results.results.iter().enumerate() {
+            if result.uuid.is_empty() || !self.is_valid_uuid_format(&result.uuid) {
+                return Err(Error::validation(format!("Result {} has invalid UUID format", index)));
+            }
+
+            if result.title.is_empty() || result.description.is_empty() || result.start.is_empty() {
+                return Err(Error::validation(format!("Result {} title, description and start are required", index)));
+            }
+
+            if result.reviewed_controls.control_selections.is_empty() {
+                return Err(Error::validation(format!("Result {} must select the reviewed controls", index)));
+            }
+
+            let observations = result.observations.as_deref().unwrap_or_default();
+            for (index, observation) in observations.iter().enumerate() {
+                self.validate_observation(observation, index)?;
+
+                // Resource subjects must be defined in back-matter
+                let subjects = observation.subjects.iter().flatten().filter(|subject| subject.subject_type == "resource");
+                for subject_uuid in subjects.filter_map(|subject| subject.subject_uuid.as_deref()) {
+                    if !resources.contains(subject_uuid) {
+                        return Err(Error::validation(format!(
+                            "Observation {} references unknown subject {}",
+                            index, subject_uuid
+                        )));
+                    }
+                }
+            }
+
+            let risks = result.risks.as_deref().unwrap_or_default();
+            for (index, risk) in risks.iter().enumerate() {
+                self.validate_risk(risk, index)?;
+            }
+
+            for (index, finding) in result.findings.iter().flatten().enumerate() {
+                self.validate_finding(finding, index)?;
+
+                // Related risks must resolve within the result
+                for related in finding.related_risks.iter().flatten() {
+                    if !risks.iter().any(|risk| risk.uuid == related.risk_uuid) {
+                        return Err(Error::validation(format!(
+                            "Finding {} references unknown risk {}",
+                            index, related.risk_uuid
+                        )));
+                    }
+                }
+
+                // Related observations must resolve within the result
+                for related in finding.related_observations.iter().flatten() {
+                    if !observations.iter().any(|observation| observation.uuid == related.observation_uuid) {
+                        return Err(Error::validation(format!(
+                            "Finding {} references unknown observation {}",
+                            index, related.observation_uuid
+                        )));
+                    }
+                }
+            }
+        }
+        
+        Ok(())
+    }
+
+    /// Validate a finding
+    fn validate_finding(&self, finding: &OscalFinding, index: usize) -> Result<()> {
+        if finding.uuid.is_empty() || !self.is_valid_uuid_format(&finding.uuid) {
+            return Err(Error::validation(format!("Finding {} has invalid UUID format", index)));
+        }
+
+        if finding.title.is_empty() {
+            return Err(Error::validation(format!("Finding {} title is required", index)));
+        }
+
+        if finding.description.is_empty() {
+            return Err(Error::validation(format!("Finding {} description is required", index)));
+        }
+
+        if !matches!(finding.target.target_type.as_str(), "objective-id" | "statement-id") {
+            return Err(Error::validation(format!(
+                "Finding {} target type must be 'objective-id' or 'statement-id', got '{}'",
+                index, finding.target.target_type
+            )));
+        }
+
+        if finding.target.target_id.is_empty() {
+            return Err(Error::validation(format!("Finding {} target ID is required", index)));
+        }
+        
+        Ok(())
+    }
+
     /// Validate a POA&M item
     fn validate_poam_item(&self, item: &OscalPoamItem, index: usize) -> Result<()> {
         if item.uuid.is_empty() {
@@ -279,18 +433,26 @@ impl OscalSchemaValidator {
         Ok(())
     }
 
-    /// Validate against OSCAL schema (placeholder implementation)
-    fn validate_against_schema<T>(&self, _document: &T, document_type: &OscalDocumentType) -> Result<()> {
-        // This would implement actual JSON schema validation
-        // For now, just log that schema validation would occur
-        debug!("Schema validation for {:?} (placeholder)", document_type);
-        
-        // In a real implementation, this would:
-        // 1. Load the appropriate OSCAL schema for the document type
-        // 2. Serialize the document to JSON
-        // 3. Validate the JSON against the schema
-        // 4. Return validation errors if any
-        
+    /// Validate against the bundled OSCAL schema of the document type, if there is one
+    ///
+    /// Every violation is reported, located by JSON Pointer.
+    fn validate_against_schema<T: Serialize>(&self, document: &T, document_type: &OscalDocumentType) -> Result<()> {
+        let Some(schema) = bundled_schema(document_type) else {
+            debug!("No bundled OSCAL schema for {:?}; skipping schema validation", document_type);
+            return Ok(());
+        };
+
+        let instance = serde_json::to_value(document)?;
+        if let Err(violations) = schema.validate(&instance) {
+            let violations: Vec<String> = violations
+                .map(|violation| format!("{}: {}", violation.instance_path, violation))
+                .collect();
+            return Err(Error::validation(format!(
+                "Document does not match the bundled OSCAL schema for {:?}: {}",
+                document_type,
+                violations.join("; ")
+            )));
+        }
         Ok(())
     }
 
@@ -326,6 +488,20 @@ impl OscalSchemaValidator {
     }
 }
 
+/// Compiled bundled schema of a document type, built on first use
+fn bundled_schema(document_type: &OscalDocumentType) -> Option<&'static JSONSchema> {
+    static ASSESSMENT_RESULTS: OnceLock<JSONSchema> = OnceLock::new();
+
+    match document_type {
+        OscalDocumentType::AssessmentResults => Some(ASSESSMENT_RESULTS.get_or_init(|| {
+            let schema: serde_json::Value =
+                serde_json::from_str(OSCAL_ASSESSMENT_RESULTS_SCHEMA).expect("bundled OSCAL schema is valid JSON");
+            JSONSchema::compile(&schema).expect("bundled OSCAL schema compiles")
+        })),
+        _ => None,
+    }
+}
+
 impl Default for OscalSchemaValidator {
     fn default() -> Self {
         Self::new()
@@ -2,11 +2,13 @@
 //! 
 //! Simple POA&M item structure for quality assessment and processing
 
+use crate::excel::poam::{self as excel_poam, MilestoneStatus, PoamSeverity, PoamStatus, RiskRating};
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use std::collections::HashMap;
 
 /// Simple POA&M item structure for quality assessment
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PoamItem {
     /// Unique identifier for the POA&M item
     pub uuid: String,
@@ -30,6 +32,12 @@ pub struct PoamItem {
     pub risk_assessment: Option<String>,
     /// List of milestones
     pub milestones: Option<Vec<PoamMilestone>>,
+    /// Security controls the item cites, e.g. `AC-2`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub controls: Vec<String>,
+    /// Additional metadata, such as values replaced during normalization
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub metadata: HashMap<String, String>,
 }
 
 /// POA&M milestone structure
@@ -60,6 +68,8 @@ impl PoamItem {
             resources_required: None,
             risk_assessment: None,
             milestones: None,
+            controls: Vec::new(),
+            metadata: HashMap::new(),
         }
     }
 
@@ -90,6 +100,8 @@ impl PoamItem {
                     status: Some("Not Started".to_string()),
                 },
             ]),
+            controls: vec!["RA-5".to_string()],
+            metadata: HashMap::new(),
         }
     }
 
@@ -142,6 +154,105 @@ impl PoamMilestone {
     }
 }
 
+impl From<&excel_poam::PoamItem> for PoamItem {
+    /// Flatten a parsed Excel POA&M item into the shape used for quality assessment
+    fn from(item: &excel_poam::PoamItem) -> Self {
+        let title = item.weakness_description
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        let resources = item.resources.iter()
+            .map(|r| r.description.as_str())
+            .filter(|d| !d.is_empty())
+            .collect::<Vec<_>>();
+
+        Self {
+            uuid: item.unique_id.clone(),
+            title,
+            description: item.weakness_description.clone(),
+            status: poam_status_label(&item.status).to_string(),
+            severity: Some(poam_severity_label(&item.severity).to_string()),
+            scheduled_completion_date: item.scheduled_completion_date.map(|d| d.to_rfc3339()),
+            actual_completion_date: item.actual_completion_date.map(|d| d.to_rfc3339()),
+            responsible_entity: item.point_of_contact.clone().or_else(|| item.office_organization.clone()),
+            resources_required: (!resources.is_empty()).then(|| resources.join("; ")),
+            risk_assessment: item.risk_rating.as_ref().map(|r| format!("{} risk", risk_rating_label(r))),
+            milestones: (!item.milestones.is_empty()).then(|| {
+                item.milestones.iter()
+                    .map(|m| PoamMilestone {
+                        description: m.description.clone(),
+                        scheduled_date: m.scheduled_date.map(|d| d.to_rfc3339()),
+                        actual_date: m.actual_date.map(|d| d.to_rfc3339()),
+                        status: Some(milestone_status_label(&m.status).to_string()),
+                    })
+                    .collect()
+            }),
+            controls: cited_controls(item),
+            metadata: item.source_sheet.iter()
+                .map(|sheet| ("source_sheet".to_string(), sheet.clone()))
+                .chain(item.original_status.iter()
+                    .map(|status| (crate::mapping::ORIGINAL_STATUS_METADATA_KEY.to_string(), status.clone())))
+                .collect(),
+        }
+    }
+}
+
+/// Controls an Excel item cites, in its control ID column or its list of security controls
+fn cited_controls(item: &excel_poam::PoamItem) -> Vec<String> {
+    let mut controls: Vec<String> = Vec::new();
+    for control in item.control_id.iter().chain(&item.security_controls) {
+        let control = control.trim();
+        if !control.is_empty() && !controls.iter().any(|c| c == control) {
+            controls.push(control.to_string());
+        }
+    }
+    controls
+}
+
+/// Display label of a parsed POA&M status, e.g. `In Progress`
+pub(crate) fn poam_status_label(status: &PoamStatus) -> &'static str {
+    match status {
+        PoamStatus::Open => "Open",
+        PoamStatus::InProgress => "In Progress",
+        PoamStatus::Completed => "Completed",
+        PoamStatus::RiskAccepted => "Risk Accepted",
+        PoamStatus::FalsePositive => "False Positive",
+        PoamStatus::Deferred => "Deferred",
+    }
+}
+
+fn poam_severity_label(severity: &PoamSeverity) -> &'static str {
+    match severity {
+        PoamSeverity::Critical => "Critical",
+        PoamSeverity::High => "High",
+        PoamSeverity::Medium => "Medium",
+        PoamSeverity::Low => "Low",
+        PoamSeverity::Info => "Informational",
+    }
+}
+
+fn milestone_status_label(status: &MilestoneStatus) -> &'static str {
+    match status {
+        MilestoneStatus::NotStarted => "Not Started",
+        MilestoneStatus::InProgress => "In Progress",
+        MilestoneStatus::Completed => "Completed",
+        MilestoneStatus::Delayed => "Delayed",
+        MilestoneStatus::Cancelled => "Cancelled",
+    }
+}
+
+fn risk_rating_label(rating: &RiskRating) -> &'static str {
+    match rating {
+        RiskRating::VeryHigh => "Very high",
+        RiskRating::High => "High",
+        RiskRating::Medium => "Medium",
+        RiskRating::Low => "Low",
+        RiskRating::VeryLow => "Very low",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
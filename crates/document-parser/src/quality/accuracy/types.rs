@@ -318,4 +318,16 @@ impl ValidationRuleResult {
     pub fn total_items(&self) -> usize {
         self.passed_items + self.failed_items
     }
+    
+    /// Add the result of the same rule over the items following this result's items
+    pub fn merge(&mut self, other: ValidationRuleResult) {
+        self.passed_items += other.passed_items;
+        self.failed_items += other.failed_items;
+        self.failed_item_uuids.extend(other.failed_item_uuids);
+        self.success_rate = if self.total_items() > 0 {
+            self.passed_items as f64 / self.total_items() as f64
+        } else {
+            1.0
+        };
+    }
 }
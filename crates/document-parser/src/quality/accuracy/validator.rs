@@ -86,6 +86,15 @@ impl AccuracyValidator {
         let rule_results = self.run_validation_rules(poam_items)?;
         debug!("Completed {} validation rules", rule_results.len());
         
+        self.validate_with_rule_results(poam_items, rule_results)
+    }
+    
+    /// Complete validation of non-empty items from their precomputed rule results
+    pub(crate) fn validate_with_rule_results(
+        &self,
+        poam_items: &[PoamItem],
+        rule_results: Vec<super::types::ValidationRuleResult>,
+    ) -> Result<AccuracyResult> {
         // Calculate field-level accuracy
         let field_accuracy = self.field_analyzer.calculate_field_accuracy(poam_items)?;
         debug!("Analyzed accuracy for {} fields", field_accuracy.len());
@@ -117,7 +126,7 @@ impl AccuracyValidator {
     /// 
     /// Executes all configured validation rules and returns detailed results
     /// for each rule including pass/fail counts and affected items.
-    fn run_validation_rules(&self, poam_items: &[PoamItem]) -> Result<Vec<super::types::ValidationRuleResult>> {
+    pub(crate) fn run_validation_rules(&self, poam_items: &[PoamItem]) -> Result<Vec<super::types::ValidationRuleResult>> {
         let mut rule_results = Vec::new();
         
         // UUID format validation
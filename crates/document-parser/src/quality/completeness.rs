@@ -122,12 +122,21 @@ impl CompletenessAnalyzer {
             });
         }
 
-        // Calculate field-level statistics
-        let field_stats = self.calculate_field_statistics(poam_items)?;
-
         // Calculate item-level scores
         let item_scores = self.calculate_item_scores(poam_items)?;
 
+        self.analyze_with_item_scores(poam_items, item_scores)
+    }
+
+    /// Complete the analysis of non-empty items from their precomputed item scores
+    pub(crate) fn analyze_with_item_scores(
+        &self,
+        poam_items: &[PoamItem],
+        item_scores: Vec<ItemCompletenessScore>,
+    ) -> Result<CompletenessResult> {
+        // Calculate field-level statistics
+        let field_stats = self.calculate_field_statistics(poam_items)?;
+
         // Generate findings based on completeness analysis
         let findings = self.generate_completeness_findings(&field_stats, &item_scores)?;
 
@@ -201,7 +210,7 @@ impl CompletenessAnalyzer {
     }
 
     /// Calculate item-level completeness scores
-    fn calculate_item_scores(&self, poam_items: &[PoamItem]) -> Result<Vec<ItemCompletenessScore>> {
+    pub(crate) fn calculate_item_scores(&self, poam_items: &[PoamItem]) -> Result<Vec<ItemCompletenessScore>> {
         let mut item_scores = Vec::new();
 
         for item in poam_items {
@@ -4,6 +4,8 @@
 
 use super::*;
 use crate::poam::PoamItem;
+use fedramp_core::models::{ControlBaseline, Framework};
+use fedramp_core::validation::validate_control_id;
 use fedramp_core::{Result, Error};
 use tracing::{debug, info};
 use uuid::Uuid;
@@ -21,6 +23,19 @@ pub struct ConsistencyResult {
     pub cross_reference_results: Vec<CrossReferenceResult>,
     /// Timeline consistency results
     pub timeline_results: Vec<TimelineConsistencyResult>,
+    /// Control references outside the active baseline
+    pub orphaned_controls: Vec<OrphanedControlReference>,
+}
+
+/// Control cited by a POA&M item that is not in the active baseline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedControlReference {
+    /// Item UUID citing the control
+    pub item_uuid: String,
+    /// Control ID as the item cites it
+    pub control_id: String,
+    /// Name of the baseline the control is missing from
+    pub baseline_name: String,
 }
 
 /// Cross-reference validation result
@@ -75,6 +90,16 @@ pub struct ConsistencyChecker {
     strict_timeline_validation: bool,
     /// Maximum allowed date variance (in days)
     max_date_variance_days: i64,
+    /// Baseline that cited controls must belong to
+    baseline: Option<ActiveBaseline>,
+}
+
+/// Baseline with its control IDs normalized for lookup
+#[derive(Debug, Clone)]
+struct ActiveBaseline {
+    name: String,
+    framework: Framework,
+    control_ids: HashSet<String>,
 }
 
 impl ConsistencyChecker {
@@ -108,6 +133,7 @@ impl ConsistencyChecker {
             valid_status_transitions: valid_transitions,
             strict_timeline_validation: false,
             max_date_variance_days: 30,
+            baseline: None,
         }
     }
 
@@ -115,9 +141,28 @@ impl ConsistencyChecker {
     pub fn with_config(config: &QualityConfig) -> Self {
         let mut checker = Self::new();
         checker.strict_timeline_validation = config.strict_mode;
+        if let Some(baseline) = &config.baseline {
+            checker = checker.with_baseline(baseline);
+        }
         checker
     }
 
+    /// Flag controls cited by POA&M items that are not in the baseline
+    ///
+    /// Control IDs are compared in normalized form, so an item citing `ac-02`
+    /// matches a baseline listing `AC-2`.
+    pub fn with_baseline(mut self, baseline: &ControlBaseline) -> Self {
+        let framework = baseline.framework.clone();
+        self.baseline = Some(ActiveBaseline {
+            name: baseline.baseline_name.clone(),
+            control_ids: baseline.control_ids.iter()
+                .map(|control_id| normalize_control_reference(control_id, &framework))
+                .collect(),
+            framework,
+        });
+        self
+    }
+
     /// Check consistency of POA&M items
     pub fn check(&self, poam_items: &[PoamItem]) -> Result<ConsistencyResult> {
         info!("Checking consistency for {} POA&M items", poam_items.len());
@@ -128,17 +173,25 @@ impl ConsistencyChecker {
                 findings: Vec::new(),
                 cross_reference_results: Vec::new(),
                 timeline_results: Vec::new(),
+                orphaned_controls: Vec::new(),
             });
         }
 
         // Perform cross-reference validation
-        let cross_reference_results = self.validate_cross_references(poam_items)?;
+        let mut cross_reference_results = self.validate_cross_references(poam_items)?;
+
+        // Check cited controls against the baseline
+        let orphaned_controls = self.find_orphaned_controls(poam_items);
+        if self.baseline.is_some() {
+            cross_reference_results.push(self.check_baseline_references(poam_items, &orphaned_controls));
+        }
 
         // Perform timeline consistency checks
         let timeline_results = self.validate_timeline_consistency(poam_items)?;
 
         // Generate consistency findings
-        let findings = self.generate_consistency_findings(&cross_reference_results, &timeline_results)?;
+        let mut findings = self.generate_consistency_findings(&cross_reference_results, &timeline_results)?;
+        findings.extend(self.orphaned_control_findings(&orphaned_controls));
 
         // Calculate overall consistency score
         let overall_score = self.calculate_consistency_score(&cross_reference_results, &timeline_results);
@@ -154,9 +207,91 @@ impl ConsistencyChecker {
             findings,
             cross_reference_results,
             timeline_results,
+            orphaned_controls,
         })
     }
 
+    /// Controls cited by the items that are not in the baseline
+    ///
+    /// Returns nothing when no baseline is configured.
+    pub fn find_orphaned_controls(&self, poam_items: &[PoamItem]) -> Vec<OrphanedControlReference> {
+        let Some(baseline) = &self.baseline else {
+            return Vec::new();
+        };
+
+        poam_items.iter()
+            .flat_map(|item| item.controls.iter().map(move |control_id| (item, control_id)))
+            .filter(|(_, control_id)| {
+                !baseline.control_ids.contains(&normalize_control_reference(control_id, &baseline.framework))
+            })
+            .map(|(item, control_id)| OrphanedControlReference {
+                item_uuid: item.uuid.clone(),
+                control_id: control_id.clone(),
+                baseline_name: baseline.name.clone(),
+            })
+            .collect()
+    }
+
+    /// Share of control-citing items whose controls are all in the baseline
+    fn check_baseline_references(
+        &self,
+        poam_items: &[PoamItem],
+        orphaned_controls: &[OrphanedControlReference],
+    ) -> CrossReferenceResult {
+        let orphaned_items: HashSet<&str> = orphaned_controls.iter()
+            .map(|orphan| orphan.item_uuid.as_str())
+            .collect();
+        let citing_items = poam_items.iter().filter(|item| !item.controls.is_empty()).count();
+        let invalid_count = orphaned_items.len();
+        let valid_count = citing_items - invalid_count;
+
+        let success_rate = if citing_items > 0 {
+            valid_count as f64 / citing_items as f64
+        } else {
+            1.0
+        };
+
+        CrossReferenceResult {
+            reference_type: "baseline_controls".to_string(),
+            valid_references: valid_count,
+            invalid_references: invalid_count,
+            missing_references: poam_items.len() - citing_items,
+            success_rate,
+            invalid_details: orphaned_controls.iter()
+                .map(|orphan| format!(
+                    "Item '{}' cites control '{}' outside baseline '{}'",
+                    orphan.item_uuid, orphan.control_id, orphan.baseline_name
+                ))
+                .collect(),
+        }
+    }
+
+    /// One finding per control reference outside the baseline
+    fn orphaned_control_findings(&self, orphaned_controls: &[OrphanedControlReference]) -> Vec<QualityFinding> {
+        orphaned_controls.iter()
+            .map(|orphan| QualityFinding {
+                id: Uuid::new_v4().to_string(),
+                severity: QualitySeverity::High,
+                category: QualityCategory::ReferenceIntegrity,
+                description: format!(
+                    "Item '{}' cites control '{}', which is not in baseline '{}'",
+                    orphan.item_uuid, orphan.control_id, orphan.baseline_name
+                ),
+                affected_items: vec![orphan.item_uuid.clone()],
+                impact_assessment: "Weaknesses tied to controls outside the baseline cannot be traced to an implemented control".to_string(),
+                recommendation: format!(
+                    "Correct the control reference '{}' or confirm the control belongs in the baseline",
+                    orphan.control_id
+                ),
+                location: Some(format!("consistency_check.baseline_controls.{}", orphan.item_uuid)),
+                metadata: HashMap::from([
+                    ("control_id".to_string(), serde_json::json!(orphan.control_id)),
+                    ("baseline".to_string(), serde_json::json!(orphan.baseline_name)),
+                ]),
+            })
+            .collect()
+    }
+
     /// Validate cross-references between POA&M items
     fn validate_cross_references(&self, poam_items: &[PoamItem]) -> Result<Vec<CrossReferenceResult>> {
         let mut results = Vec::new();
@@ -416,8 +551,8 @@ impl ConsistencyChecker {
     ) -> Result<Vec<QualityFinding>> {
         let mut findings = Vec::new();
 
-        // Generate findings for cross-reference issues
-        for result in cross_reference_results {
+        // Generate findings for cross-reference issues; baseline references get one finding per orphan
+        for result in cross_reference_results.iter().filter(|result| result.reference_type != "baseline_controls") {
             if result.success_rate < 0.95 && result.invalid_references > 0 {
                 let severity = if result.success_rate < 0.8 {
                     QualitySeverity::High
@@ -526,8 +661,76 @@ impl ConsistencyChecker {
     }
 }
 
+/// Control ID in the framework's canonical form, or upper-cased if it does not parse
+fn normalize_control_reference(control_id: &str, framework: &Framework) -> String {
+    validate_control_id(control_id, framework.clone())
+        .map(|id| id.to_string())
+        .unwrap_or_else(|_| control_id.trim().to_uppercase())
+}
+
 impl Default for ConsistencyChecker {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fedramp_core::models::BaselineLevel;
+
+    fn moderate_baseline() -> ControlBaseline {
+        ControlBaseline {
+            id: Uuid::new_v4(),
+            baseline_name: "FedRAMP Moderate".to_string(),
+            baseline_level: BaselineLevel::Moderate,
+            framework: Framework::Nist80053,
+            control_ids: vec!["AC-2".to_string(), "RA-5".to_string(), "SC-7(5)".to_string()],
+            description: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn item(uuid: &str, controls: &[&str]) -> PoamItem {
+        let mut item = PoamItem::new(uuid.to_string(), "Weakness".to_string(), "Weakness".to_string(), "Open".to_string());
+        item.controls = controls.iter().map(|control| control.to_string()).collect();
+        item
+    }
+
+    #[test]
+    fn test_control_outside_baseline_is_flagged() {
+        let checker = ConsistencyChecker::new().with_baseline(&moderate_baseline());
+        let items = vec![
+            item("item-1", &["ac-02", "SC-7 (5)"]),
+            item("item-2", &["RA-5", "ZZ-99"]),
+            item("item-3", &[]),
+        ];
+
+        let result = checker.check(&items).unwrap();
+
+        assert_eq!(result.orphaned_controls, vec![OrphanedControlReference {
+            item_uuid: "item-2".to_string(),
+            control_id: "ZZ-99".to_string(),
+            baseline_name: "FedRAMP Moderate".to_string(),
+        }]);
+        let finding = result.findings.iter()
+            .find(|finding| finding.category == QualityCategory::ReferenceIntegrity)
+            .unwrap();
+        assert_eq!(finding.affected_items, ["item-2"]);
+        assert_eq!(finding.metadata["control_id"], "ZZ-99");
+
+        let baseline_result = result.cross_reference_results.iter()
+            .find(|result| result.reference_type == "baseline_controls")
+            .unwrap();
+        assert_eq!((baseline_result.valid_references, baseline_result.invalid_references), (1, 1));
+        assert_eq!(baseline_result.missing_references, 1);
+    }
+
+    #[test]
+    fn test_no_baseline_skips_control_check() {
+        let result = ConsistencyChecker::new().check(&[item("item-1", &["ZZ-99"])]).unwrap();
+        assert!(result.orphaned_controls.is_empty());
+        assert!(result.cross_reference_results.iter().all(|result| result.reference_type != "baseline_controls"));
+    }
+}
@@ -0,0 +1,182 @@
+//! Quality assessment history
+//!
+//! Stores the quality assessments of each document so that quality trends can
+//! be computed across runs.
+
+use super::QualityAssessment;
+use async_trait::async_trait;
+use fedramp_core::{Result, Error};
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tracing::{debug, warn};
+
+/// Storage backend for historical quality assessments
+///
+/// Assessments are keyed by document and timestamp; [`FileQualityHistoryStore`]
+/// is the default.
+#[async_trait]
+pub trait QualityHistoryStore: Send + Sync {
+    /// Add an assessment to the history of a document
+    async fn append(&self, document: &str, assessment: &QualityAssessment) -> Result<()>;
+
+    /// Assessments of a document, oldest first
+    async fn history(&self, document: &str) -> Result<Vec<QualityAssessment>>;
+}
+
+/// Quality history kept as one JSON file per assessment
+///
+/// Each document gets a directory under the root holding files named
+/// `<timestamp>-<assessment id>.json`, so a document's directory can also be
+/// passed to `fedramp report trend --inputs`.
+#[derive(Debug, Clone)]
+pub struct FileQualityHistoryStore {
+    root: PathBuf,
+}
+
+impl FileQualityHistoryStore {
+    /// Create a store under a root directory, created on first append
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Root directory of the store
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Directory holding the assessments of a document
+    ///
+    /// Characters other than ASCII letters, digits, `.`, `-` and `_` are
+    /// replaced; a hash of the document name is then appended so that
+    /// different names cannot share a directory.
+    pub fn document_dir(&self, document: &str) -> PathBuf {
+        let name = file_name_component(document);
+        if name == document && !name.starts_with('.') {
+            self.root.join(name)
+        } else {
+            let hash = crate::cache::content_hash(document.as_bytes());
+            self.root.join(format!("{}-{}", name, &hash[..12]))
+        }
+    }
+}
+
+#[async_trait]
+impl QualityHistoryStore for FileQualityHistoryStore {
+    async fn append(&self, document: &str, assessment: &QualityAssessment) -> Result<()> {
+        let dir = self.document_dir(document);
+        fs::create_dir_all(&dir).await
+            .map_err(|e| Error::internal(format!("Failed to create quality history directory {}: {}", dir.display(), e)))?;
+
+        let path = dir.join(format!(
+            "{}-{}.json",
+            assessment.timestamp.format("%Y%m%dT%H%M%S%.6fZ"),
+            file_name_component(&assessment.assessment_id)
+        ));
+        let json = serde_json::to_vec_pretty(assessment)
+            .map_err(|e| Error::internal(format!("Failed to serialize quality assessment: {}", e)))?;
+        fs::write(&path, json).await
+            .map_err(|e| Error::internal(format!("Failed to write quality history file {}: {}", path.display(), e)))?;
+
+        debug!("Appended quality assessment {} of {} to {}", assessment.assessment_id, document, path.display());
+        Ok(())
+    }
+
+    async fn history(&self, document: &str) -> Result<Vec<QualityAssessment>> {
+        let dir = self.document_dir(document);
+        let mut entries = match fs::read_dir(&dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(Error::internal(format!("Failed to read quality history directory {}: {}", dir.display(), e))),
+        };
+
+        let mut assessments = Vec::new();
+        while let Some(entry) = entries.next_entry().await
+            .map_err(|e| Error::internal(format!("Failed to read quality history directory {}: {}", dir.display(), e)))?
+        {
+            let path = entry.path();
+            if path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+                continue;
+            }
+            let content = fs::read(&path).await
+                .map_err(|e| Error::internal(format!("Failed to read quality history file {}: {}", path.display(), e)))?;
+            match serde_json::from_slice::<QualityAssessment>(&content) {
+                Ok(assessment) => assessments.push(assessment),
+                Err(e) => warn!("Skipping quality history file {}: {}", path.display(), e),
+            }
+        }
+
+        assessments.sort_by_key(|assessment| assessment.timestamp);
+        Ok(assessments)
+    }
+}
+
+/// Text with every character unsafe in a file name replaced by `_`
+fn file_name_component(text: &str) -> String {
+    let name: String = text.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' })
+        .collect();
+    if name.is_empty() { "_".to_string() } else { name }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quality::QualityMetrics;
+    use chrono::{TimeZone, Utc};
+    use std::collections::HashMap;
+
+    fn assessment(id: &str, day: u32) -> QualityAssessment {
+        QualityAssessment {
+            assessment_id: id.to_string(),
+            timestamp: Utc.with_ymd_and_hms(2025, 4, day, 9, 30, 0).unwrap(),
+            overall_score: 0.8,
+            completeness_score: 0.9,
+            accuracy_score: 0.8,
+            consistency_score: 0.85,
+            compliance_score: 0.7,
+            quality_metrics: QualityMetrics {
+                total_items: 10,
+                complete_items: 8,
+                incomplete_items: 2,
+                error_count: 0,
+                warning_count: 0,
+                missing_required_fields: 0,
+                data_quality_issues: 0,
+                field_completeness: HashMap::new(),
+                category_metrics: HashMap::new(),
+            },
+            findings: Vec::new(),
+            recommendations: Vec::new(),
+            config_summary: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_history_is_kept_per_document_in_timestamp_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileQualityHistoryStore::new(dir.path());
+
+        store.append("poam.xlsx", &assessment("second", 12)).await.unwrap();
+        store.append("poam.xlsx", &assessment("first", 3)).await.unwrap();
+        store.append("POA&M Q2.xlsx", &assessment("other", 5)).await.unwrap();
+
+        let ids: Vec<String> = store.history("poam.xlsx").await.unwrap()
+            .into_iter()
+            .map(|assessment| assessment.assessment_id)
+            .collect();
+        assert_eq!(ids, ["first", "second"]);
+        assert_eq!(store.history("POA&M Q2.xlsx").await.unwrap().len(), 1);
+        assert!(store.history("inventory.xlsx").await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_document_dirs_do_not_collide() {
+        let store = FileQualityHistoryStore::new("/history");
+
+        assert_eq!(store.document_dir("poam.xlsx"), Path::new("/history/poam.xlsx"));
+        assert_ne!(store.document_dir("a/b.xlsx"), store.document_dir("a_b.xlsx"));
+        assert_ne!(store.document_dir(".."), Path::new("/history/.."));
+    }
+}
@@ -5,12 +5,16 @@
 //! - Accuracy validation
 //! - Consistency checking
 //! - Compliance assessment
+//! - Assessment history for quality trends
+//! - Parallel checking of large documents
 
 pub mod poam_quality;
 pub mod completeness;
 pub mod accuracy;
 pub mod consistency;
 pub mod compliance;
+pub mod history;
+pub mod parallel;
 
 pub use poam_quality::*;
 pub use completeness::*;
@@ -20,6 +24,7 @@ pub use accuracy::{
     FieldAccuracyAnalyzer
 };
 pub use consistency::*;
+pub use history::{FileQualityHistoryStore, QualityHistoryStore};
 pub use compliance::{
     ComplianceAssessor, ComplianceResult, ComplianceCheckResult, ComplianceViolation,
     ComplianceConfig, ComplianceCheckType, ComplianceStatistics,
@@ -29,6 +34,8 @@ pub use compliance::{
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use chrono::{DateTime, Utc};
+use fedramp_core::models::{ControlBaseline, DocumentType};
+use fedramp_core::{Result, Error};
 
 /// Quality severity levels for findings
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -62,6 +69,8 @@ pub enum QualityCategory {
     BusinessLogic,
     /// Reference integrity problems
     ReferenceIntegrity,
+    /// Doubtful column to field mappings
+    Mapping,
 }
 
 /// Quality finding with detailed information
@@ -194,6 +203,28 @@ pub struct QualityConfig {
     pub strict_mode: bool,
     /// Custom quality thresholds
     pub custom_thresholds: HashMap<String, f64>,
+    /// Threshold overrides by document type; names such as `poam` or `inventory` deserialize to their `DocumentType`
+    #[serde(default)]
+    pub document_type_thresholds: HashMap<DocumentType, QualityThresholdOverrides>,
+    /// Active control baseline; POA&M items citing controls outside it are flagged
+    #[serde(default)]
+    pub baseline: Option<ControlBaseline>,
+    /// Most worker threads used to check items; the available parallelism when unset
+    #[serde(default)]
+    pub max_workers: Option<usize>,
+}
+
+/// Minimum scores replacing the global thresholds for one document type
+///
+/// Thresholds left unset fall back to the global value in `QualityConfig`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QualityThresholdOverrides {
+    pub min_overall_score: Option<f64>,
+    pub min_completeness_score: Option<f64>,
+    pub min_accuracy_score: Option<f64>,
+    pub min_consistency_score: Option<f64>,
+    pub min_compliance_score: Option<f64>,
 }
 
 /// Weights for different quality dimensions
@@ -251,7 +282,94 @@ impl Default for QualityConfig {
             field_rules: HashMap::new(),
             strict_mode: false,
             custom_thresholds: HashMap::new(),
+            document_type_thresholds: HashMap::new(),
+            baseline: None,
+            max_workers: None,
+        }
+    }
+}
+
+impl QualityConfig {
+    /// Configuration with the thresholds of a document type applied
+    ///
+    /// A type without overrides gets the global thresholds unchanged.
+    pub fn for_document_type(&self, document_type: DocumentType) -> QualityConfig {
+        let mut config = self.clone();
+        if let Some(overrides) = self.document_type_thresholds.get(&document_type) {
+            config.min_overall_score = overrides.min_overall_score.unwrap_or(self.min_overall_score);
+            config.min_completeness_score = overrides.min_completeness_score.unwrap_or(self.min_completeness_score);
+            config.min_accuracy_score = overrides.min_accuracy_score.unwrap_or(self.min_accuracy_score);
+            config.min_consistency_score = overrides.min_consistency_score.unwrap_or(self.min_consistency_score);
+            config.min_compliance_score = overrides.min_compliance_score.unwrap_or(self.min_compliance_score);
+        }
+        config
+    }
+
+    /// Check that the dimension weights sum to 1.0, every minimum score,
+    /// including document type overrides, lies in [0, 1] and the worker cap
+    /// is at least one
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error listing every violation
+    pub fn validate(&self) -> Result<()> {
+        let mut violations = Vec::new();
+
+        let weight_sum = self.dimension_weights.sum();
+        if weight_sum.is_nan() || (weight_sum - 1.0).abs() > DIMENSION_WEIGHT_TOLERANCE {
+            violations.push(format!("dimension weights sum to {}, expected 1.0", weight_sum));
+        }
+
+        let mut check_score = |name: String, score: f64| {
+            if !(0.0..=1.0).contains(&score) {
+                violations.push(format!("{} is {}, expected a value in [0, 1]", name, score));
+            }
+        };
+        for (name, score) in [
+            ("min_overall_score", self.min_overall_score),
+            ("min_completeness_score", self.min_completeness_score),
+            ("min_accuracy_score", self.min_accuracy_score),
+            ("min_consistency_score", self.min_consistency_score),
+            ("min_compliance_score", self.min_compliance_score),
+        ] {
+            check_score(name.to_string(), score);
+        }
+
+        let mut document_types: Vec<_> = self.document_type_thresholds.iter().collect();
+        document_types.sort_by_key(|(document_type, _)| document_type.as_str());
+        for (document_type, overrides) in document_types {
+            for (name, score) in [
+                ("min_overall_score", overrides.min_overall_score),
+                ("min_completeness_score", overrides.min_completeness_score),
+                ("min_accuracy_score", overrides.min_accuracy_score),
+                ("min_consistency_score", overrides.min_consistency_score),
+                ("min_compliance_score", overrides.min_compliance_score),
+            ] {
+                if let Some(score) = score {
+                    check_score(format!("document_type_thresholds.{}.{}", document_type, name), score);
+                }
+            }
         }
+
+        if self.max_workers == Some(0) {
+            violations.push("max_workers is 0, expected at least 1".to_string());
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::configuration(format!("Invalid quality configuration: {}", violations.join("; "))))
+        }
+    }
+}
+
+/// Largest difference from 1.0 accepted for the sum of the dimension weights
+pub const DIMENSION_WEIGHT_TOLERANCE: f64 = 1e-6;
+
+impl DimensionWeights {
+    /// Sum of the four dimension weights
+    pub fn sum(&self) -> f64 {
+        self.completeness + self.accuracy + self.consistency + self.compliance
     }
 }
 
@@ -280,8 +398,25 @@ impl QualitySeverity {
 }
 
 impl QualityAssessment {
+    /// Document type the assessment was made for, as recorded in its config summary
+    pub fn document_type(&self) -> Option<DocumentType> {
+        self.config_summary.get("document_type")
+            .and_then(|value| value.as_str())
+            .and_then(|name| name.parse().ok())
+    }
+
     /// Check if the assessment passes minimum quality thresholds
+    ///
+    /// The thresholds of the assessment's document type are used when the
+    /// configuration overrides them.
     pub fn passes_quality_gates(&self, config: &QualityConfig) -> bool {
+        match self.document_type() {
+            Some(document_type) => self.passes_thresholds(&config.for_document_type(document_type)),
+            None => self.passes_thresholds(config),
+        }
+    }
+
+    fn passes_thresholds(&self, config: &QualityConfig) -> bool {
         self.overall_score >= config.min_overall_score
             && self.completeness_score >= config.min_completeness_score
             && self.accuracy_score >= config.min_accuracy_score
@@ -351,6 +486,34 @@ mod tests {
         assert!((total - 1.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_default_quality_config_is_valid() {
+        assert!(QualityConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_quality_config_validation_lists_every_violation() {
+        let mut config = QualityConfig::default();
+        config.dimension_weights.completeness = 0.8;
+        config.min_overall_score = 1.2;
+        config.min_accuracy_score = -0.1;
+        config.document_type_thresholds.insert(DocumentType::PlanOfActionMilestones, QualityThresholdOverrides {
+            min_compliance_score: Some(f64::NAN),
+            ..Default::default()
+        });
+
+        let message = config.validate().unwrap_err().to_string();
+        for violation in [
+            "dimension weights sum to 1.5",
+            "min_overall_score is 1.2",
+            "min_accuracy_score is -0.1",
+            "document_type_thresholds.plan-of-action-milestones.min_compliance_score is NaN",
+        ] {
+            assert!(message.contains(violation), "{} not in {}", violation, message);
+        }
+        assert!(!message.contains("min_completeness_score"));
+    }
+
     #[test]
     fn test_quality_assessment_gates() {
         let config = QualityConfig::default();
@@ -391,6 +554,62 @@ mod tests {
         assert!(!failing_assessment.passes_quality_gates(&config));
     }
 
+    #[test]
+    fn test_document_type_thresholds_override_global_floors() {
+        let mut config = QualityConfig::default();
+        config.document_type_thresholds.insert(DocumentType::PlanOfActionMilestones, QualityThresholdOverrides {
+            min_completeness_score: Some(0.95),
+            ..Default::default()
+        });
+        config.document_type_thresholds.insert(DocumentType::IntegratedInventoryWorkbook, QualityThresholdOverrides {
+            min_completeness_score: Some(0.6),
+            ..Default::default()
+        });
+
+        let assessment_for = |document_type: &str| QualityAssessment {
+            assessment_id: document_type.to_string(),
+            timestamp: chrono::Utc::now(),
+            overall_score: 0.8,
+            completeness_score: 0.7,
+            accuracy_score: 0.95,
+            consistency_score: 0.85,
+            compliance_score: 0.92,
+            quality_metrics: QualityMetrics {
+                total_items: 10,
+                complete_items: 7,
+                incomplete_items: 3,
+                error_count: 0,
+                warning_count: 0,
+                missing_required_fields: 3,
+                data_quality_issues: 0,
+                field_completeness: HashMap::new(),
+                category_metrics: HashMap::new(),
+            },
+            findings: Vec::new(),
+            recommendations: Vec::new(),
+            config_summary: HashMap::from([("document_type".to_string(), serde_json::json!(document_type))]),
+        };
+
+        assert!(assessment_for("integrated-inventory-workbook").passes_quality_gates(&config));
+        assert!(!assessment_for("plan-of-action-milestones").passes_quality_gates(&config));
+        // Types without overrides keep the global 0.8 completeness floor
+        assert!(!assessment_for("system-security-plan").passes_quality_gates(&config));
+        assert_eq!(config.for_document_type(DocumentType::IntegratedInventoryWorkbook).min_accuracy_score, 0.9);
+    }
+
+    #[test]
+    fn test_document_type_threshold_keys_must_name_a_document_type() {
+        let config_with = |document_type: &str| {
+            let mut config = serde_json::to_value(QualityConfig::default()).unwrap();
+            config["document_type_thresholds"] = serde_json::json!({ document_type: { "min_overall_score": 0.5 } });
+            serde_json::from_value::<QualityConfig>(config)
+        };
+
+        let config = config_with("inventory").unwrap();
+        assert_eq!(config.for_document_type(DocumentType::IntegratedInventoryWorkbook).min_overall_score, 0.5);
+        assert!(config_with("inventroy").is_err());
+    }
+
     #[test]
     fn test_quality_finding_filtering() {
         let findings = vec![
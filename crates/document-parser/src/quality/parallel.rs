@@ -0,0 +1,99 @@
+//! Parallel execution of quality checks
+//!
+//! Runs checks on a bounded set of scoped worker threads. Results always come
+//! back in task order, so an assessment does not depend on the worker count.
+
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Fewest items given to one worker; smaller inputs are checked on the calling thread
+pub const MIN_ITEMS_PER_WORKER: usize = 250;
+
+/// A unit of work for [`run_tasks`]
+pub(crate) type Task<'a, R> = Box<dyn FnOnce() -> R + Send + 'a>;
+
+/// Number of workers to use: the available parallelism, capped by `max_workers`
+pub fn worker_count(max_workers: Option<usize>) -> usize {
+    let available = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    max_workers.map_or(available, |max_workers| available.min(max_workers)).max(1)
+}
+
+/// Number of item chunks to split `item_count` items into for `workers` workers
+pub fn chunk_count(item_count: usize, workers: usize) -> usize {
+    workers.min(item_count / MIN_ITEMS_PER_WORKER).max(1)
+}
+
+/// Split items into `chunks` contiguous chunks of near-equal size
+pub(crate) fn split_chunks<T>(items: &[T], chunks: usize) -> impl Iterator<Item = &[T]> {
+    let chunk_size = items.len().div_ceil(chunks.max(1)).max(1);
+    items.chunks(chunk_size)
+}
+
+/// Run tasks on at most `workers` threads and return their results in task order
+///
+/// Tasks run on the calling thread when there is one worker or one task. A
+/// panicking task panics the caller once all workers have stopped.
+pub(crate) fn run_tasks<'a, R: Send>(tasks: Vec<Task<'a, R>>, workers: usize) -> Vec<R> {
+    if workers <= 1 || tasks.len() <= 1 {
+        return tasks.into_iter().map(|task| task()).collect();
+    }
+
+    let task_count = tasks.len();
+    let tasks: Vec<Mutex<Option<Task<'a, R>>>> = tasks.into_iter().map(|task| Mutex::new(Some(task))).collect();
+    let results: Vec<Mutex<Option<R>>> = (0..task_count).map(|_| Mutex::new(None)).collect();
+    let next_task = AtomicUsize::new(0);
+
+    thread::scope(|scope| {
+        for _ in 0..workers.min(task_count) {
+            scope.spawn(|| loop {
+                let index = next_task.fetch_add(1, Ordering::Relaxed);
+                if index >= task_count {
+                    break;
+                }
+                let task = tasks[index].lock().unwrap().take().expect("each task is taken once");
+                let result = task();
+                *results[index].lock().unwrap() = Some(result);
+            });
+        }
+    });
+
+    results.into_iter()
+        .map(|result| result.into_inner().unwrap().expect("every task has run"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results_keep_task_order() {
+        let tasks: Vec<Task<usize>> = (0..20)
+            .map(|index| -> Task<usize> {
+                Box::new(move || {
+                    thread::sleep(std::time::Duration::from_millis((20 - index) as u64));
+                    index * 2
+                })
+            })
+            .collect();
+
+        assert_eq!(run_tasks(tasks, 4), (0..20).map(|index| index * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_worker_and_chunk_counts() {
+        assert_eq!(worker_count(Some(1)), 1);
+        assert_eq!(worker_count(Some(0)), 1);
+        assert!(worker_count(None) >= 1);
+
+        assert_eq!(chunk_count(100, 8), 1);
+        assert_eq!(chunk_count(5_000, 8), 8);
+        assert_eq!(chunk_count(600, 8), 2);
+
+        let items: Vec<usize> = (0..10).collect();
+        let chunks: Vec<&[usize]> = split_chunks(&items, 3).collect();
+        assert_eq!(chunks, [&[0, 1, 2, 3][..], &[4, 5, 6, 7], &[8, 9]]);
+    }
+}
@@ -3,6 +3,7 @@
 //! Main quality checker that orchestrates all quality assessment components
 
 use super::*;
+use super::parallel::{chunk_count, run_tasks, split_chunks, worker_count, Task};
 use crate::poam::PoamItem;
 use fedramp_core::{Result, Error};
 use tracing::{debug, info, warn};
@@ -38,14 +39,22 @@ impl PoamQualityChecker {
     }
 
     /// Create a new quality checker with custom configuration
-    pub fn with_config(config: QualityConfig) -> Self {
-        Self {
+    ///
+    /// POA&M threshold overrides in the configuration replace the global thresholds.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the configuration does not pass [`QualityConfig::validate`]
+    pub fn with_config(config: QualityConfig) -> Result<Self> {
+        config.validate()?;
+        let config = config.for_document_type(DocumentType::PlanOfActionMilestones);
+        Ok(Self {
             completeness_analyzer: CompletenessAnalyzer::with_config(&config),
             accuracy_validator: AccuracyValidator::with_config(&config),
             consistency_checker: ConsistencyChecker::with_config(&config),
             compliance_assessor: ComplianceAssessor::with_config((&config).into()),
             quality_config: config,
-        }
+        })
     }
 
     /// Perform comprehensive quality assessment on POA&M items
@@ -55,11 +64,19 @@ impl PoamQualityChecker {
         let assessment_id = Uuid::new_v4().to_string();
         let timestamp = Utc::now();
 
-        // Perform individual quality assessments
-        let completeness_result = self.completeness_analyzer.analyze(poam_items)?;
-        let accuracy_result = self.accuracy_validator.validate(poam_items)?;
-        let consistency_result = self.consistency_checker.check(poam_items)?;
-        let compliance_result = self.compliance_assessor.assess(poam_items)?;
+        // Perform individual quality assessments, fanned out over items for large documents
+        let workers = worker_count(self.quality_config.max_workers);
+        let (completeness_result, accuracy_result, consistency_result, compliance_result) =
+            if chunk_count(poam_items.len(), workers) > 1 {
+                self.assess_dimensions_in_parallel(poam_items, workers)?
+            } else {
+                (
+                    self.completeness_analyzer.analyze(poam_items)?,
+                    self.accuracy_validator.validate(poam_items)?,
+                    self.consistency_checker.check(poam_items)?,
+                    self.compliance_assessor.assess(poam_items)?,
+                )
+            };
 
         // Combine findings from all assessments
         let mut all_findings = Vec::new();
@@ -67,6 +84,7 @@ impl PoamQualityChecker {
         all_findings.extend(accuracy_result.findings);
         all_findings.extend(consistency_result.findings);
         all_findings.extend(compliance_result.findings);
+        sort_findings(&mut all_findings);
 
         // Calculate overall scores using configured weights
         let weights = &self.quality_config.dimension_weights;
@@ -108,6 +126,71 @@ impl PoamQualityChecker {
         Ok(assessment)
     }
 
+    /// Run the four quality dimensions on a pool of `workers` threads
+    ///
+    /// Completeness item scores and accuracy rules are computed per chunk of
+    /// items and merged in item order, so the results match a sequential run.
+    /// Consistency and compliance compare items with each other and run as
+    /// whole-document tasks alongside the chunks.
+    fn assess_dimensions_in_parallel(
+        &self,
+        poam_items: &[PoamItem],
+        workers: usize,
+    ) -> Result<(CompletenessResult, AccuracyResult, ConsistencyResult, ComplianceResult)> {
+        enum Output {
+            Consistency(ConsistencyResult),
+            Compliance(ComplianceResult),
+            Chunk(Vec<ItemCompletenessScore>, Vec<ValidationRuleResult>),
+        }
+
+        let chunks = chunk_count(poam_items.len(), workers);
+        debug!("Checking {} POA&M items in {} chunks on {} workers", poam_items.len(), chunks, workers);
+
+        let mut tasks: Vec<Task<Result<Output>>> = vec![
+            Box::new(|| self.consistency_checker.check(poam_items).map(Output::Consistency)),
+            Box::new(|| self.compliance_assessor.assess(poam_items).map(Output::Compliance)),
+        ];
+        for chunk in split_chunks(poam_items, chunks) {
+            tasks.push(Box::new(move || {
+                Ok(Output::Chunk(
+                    self.completeness_analyzer.calculate_item_scores(chunk)?,
+                    self.accuracy_validator.run_validation_rules(chunk)?,
+                ))
+            }));
+        }
+
+        let mut consistency_result = None;
+        let mut compliance_result = None;
+        let mut item_scores = Vec::with_capacity(poam_items.len());
+        let mut rule_results: Option<Vec<ValidationRuleResult>> = None;
+        for output in run_tasks(tasks, workers) {
+            match output? {
+                Output::Consistency(result) => consistency_result = Some(result),
+                Output::Compliance(result) => compliance_result = Some(result),
+                Output::Chunk(chunk_scores, chunk_rules) => {
+                    item_scores.extend(chunk_scores);
+                    match &mut rule_results {
+                        Some(rule_results) => {
+                            for (rule_result, chunk_rule) in rule_results.iter_mut().zip(chunk_rules) {
+                                rule_result.merge(chunk_rule);
+                            }
+                        }
+                        None => rule_results = Some(chunk_rules),
+                    }
+                }
+            }
+        }
+
+        let missing = || Error::internal("Parallel quality check did not produce every result");
+        Ok((
+            self.completeness_analyzer.analyze_with_item_scores(poam_items, item_scores)?,
+            self.accuracy_validator.validate_with_rule_results(poam_items, rule_results.ok_or_else(missing)?)?,
+            consistency_result.ok_or_else(missing)?,
+            compliance_result.ok_or_else(missing)?,
+        ))
+    }
+
+
     /// Calculate comprehensive quality metrics
     fn calculate_quality_metrics(
         &self,
@@ -253,6 +336,8 @@ impl PoamQualityChecker {
     /// Create configuration summary for the assessment
     fn create_config_summary(&self) -> HashMap<String, serde_json::Value> {
         let mut summary = HashMap::new();
+        summary.insert("document_type".to_string(),
+                      serde_json::Value::String(DocumentType::PlanOfActionMilestones.to_string()));
         summary.insert("min_overall_score".to_string(), 
                       serde_json::Value::Number(serde_json::Number::from_f64(self.quality_config.min_overall_score).unwrap()));
         summary.insert("strict_mode".to_string(), 
@@ -315,6 +400,14 @@ impl PoamQualityChecker {
     }
 }
 
+/// Order findings by the first affected item UUID, then by the check and
+/// description that raised them, independent of the order checks finished in
+fn sort_findings(findings: &mut [QualityFinding]) {
+    findings.sort_by_cached_key(|finding| {
+        (finding.affected_items.iter().min().cloned(), finding.location.clone(), finding.description.clone())
+    });
+}
+
 impl Default for PoamQualityChecker {
     fn default() -> Self {
         Self::new()
@@ -339,11 +432,76 @@ mod tests {
         config.min_overall_score = 0.9;
         config.strict_mode = true;
 
-        let checker = PoamQualityChecker::with_config(config.clone());
+        let checker = PoamQualityChecker::with_config(config.clone()).unwrap();
         assert_eq!(checker.quality_config.min_overall_score, 0.9);
         assert!(checker.quality_config.strict_mode);
     }
 
+    #[test]
+    fn test_quality_checker_rejects_invalid_config() {
+        let mut config = QualityConfig::default();
+        config.dimension_weights.compliance = 0.7;
+
+        let error = PoamQualityChecker::with_config(config).unwrap_err();
+        assert!(error.to_string().contains("dimension weights sum to 1.5"), "{}", error);
+    }
+
+    #[test]
+    fn test_parallel_assessment_matches_sequential() {
+        let items: Vec<PoamItem> = (0..1_200)
+            .map(|index| {
+                let mut item = PoamItem::sample();
+                item.uuid = format!("item-{:04}", index);
+                if index % 7 == 0 {
+                    item.scheduled_completion_date = None;
+                }
+                if index % 11 == 0 {
+                    item.status = "Unknown".to_string();
+                }
+                item
+            })
+            .collect();
+        let checker = PoamQualityChecker::new();
+
+        let (completeness, accuracy, consistency, compliance) = checker.assess_dimensions_in_parallel(&items, 4).unwrap();
+        assert_eq!(completeness.score, checker.completeness_analyzer.analyze(&items).unwrap().score);
+        assert_eq!(consistency.score, checker.consistency_checker.check(&items).unwrap().score);
+        assert_eq!(compliance.score, checker.compliance_assessor.assess(&items).unwrap().score);
+
+        let sequential = checker.accuracy_validator.validate(&items).unwrap();
+        assert_eq!(accuracy.score, sequential.score);
+        let failures = |result: &AccuracyResult| -> Vec<(String, usize, Vec<String>)> {
+            result.rule_results.iter()
+                .map(|rule| (rule.rule_name.clone(), rule.failed_items, rule.failed_item_uuids.clone()))
+                .collect()
+        };
+        assert_eq!(failures(&accuracy), failures(&sequential));
+        assert_eq!(completeness.item_scores.len(), items.len());
+        assert_eq!(completeness.item_scores[1_199].item_uuid, "item-1199");
+    }
+
+    #[test]
+    fn test_findings_are_sorted_by_item_and_check() {
+        let mut config = QualityConfig::default();
+        config.max_workers = Some(1);
+        let checker = PoamQualityChecker::with_config(config).unwrap();
+        let mut late = PoamItem::sample();
+        late.uuid = "b-item".to_string();
+        late.status = "Unknown".to_string();
+        let mut early = PoamItem::sample();
+        early.uuid = "a-item".to_string();
+        early.scheduled_completion_date = None;
+
+        let findings = checker.assess_quality(&[late, early]).unwrap().findings;
+        let keys: Vec<_> = findings.iter()
+            .map(|finding| (finding.affected_items.iter().min().cloned(), finding.location.clone(), finding.description.clone()))
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+        assert!(findings.iter().any(|finding| finding.affected_items.contains(&"a-item".to_string())));
+    }
+
     #[tokio::test]
     async fn test_assess_quality_empty_items() {
         let checker = PoamQualityChecker::new();
@@ -11,6 +11,7 @@
 //! - `overrides`: Mapping override system for custom column mappings
 //! - `reports`: Report generation system for mapping validation
 //! - `validators`: Main validator implementations
+//! - `sarif`: SARIF 2.1.0 output of validation errors for CI annotations
 //! - `tests`: Comprehensive test suite
 
 // Submodule declarations
@@ -22,6 +23,7 @@ pub mod reports;
 pub mod poam_reports;
 pub mod validators;
 pub mod poam_validator;
+pub mod sarif;
 
 #[cfg(test)]
 mod tests;
@@ -50,7 +52,7 @@ pub use overrides::{
 };
 pub use reports::{
     MappingReportGenerator, ReportConfig, MappingReport, ReportType, ReportFormat,
-    DocumentInfo, MappingSummary, FieldMappingResult, MappingAlternative, MappingIssue,
+    DocumentInfo, MappingSummary, ConfidenceHistogram, ConfidenceBucket, FieldMappingResult, MappingAlternative, MappingIssue,
     DataQualityAssessment, QualityMetrics, Recommendation, ValidationSummary,
     ValidationFailureInfo, ValidationPerformanceMetrics, SlowValidationInfo,
     OverrideSummary, OverrideUsageInfo, OverridePerformanceMetrics, ProcessingMetrics,
@@ -63,6 +65,7 @@ pub use poam_reports::{
     PoamReportGenerator, PoamReportExporter, PoamVisualizationEngine,
     PoamValidationReport, PoamReportType, PoamReportFormat, ProcessingSummary,
     ValidationResults, ComplianceStatus, RecommendationCategory, RecommendationPriority,
+    PoamAgingReport, AgingBucket, PoamQualityTrendReport, calculate_quality_trend,
 };
 pub use validators::{
     ColumnValidator, DocumentValidator, ColumnValidationConfig, DocumentValidationConfig,
@@ -76,6 +79,7 @@ pub use poam_validator::{
     ValidationSuggestion, FieldValidationResult, BusinessRuleResult,
     SeverityValidator, StatusValidator, BusinessRuleValidator, CrossFieldValidator, CrossFieldRule,
 };
+pub use sarif::{SarifReport, sarif_level, pointer_line, SARIF_VERSION, SARIF_SCHEMA_URI, DEFAULT_SARIF_RULE_ID};
 
 // Convenience functions for common validation operations
 
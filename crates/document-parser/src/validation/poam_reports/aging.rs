@@ -0,0 +1,168 @@
+// Modified: 2025-09-20
+
+//! POA&M aging analysis
+//!
+//! Buckets open POA&M items by how many days they are past their scheduled
+//! completion date, for the aging section of POA&M validation reports.
+
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use std::collections::HashMap;
+
+use crate::mapping::DateConverter;
+use crate::poam::PoamItem;
+use crate::quality::{QualityCategory, QualityFinding, QualitySeverity};
+
+/// Days-overdue bucket for an open POA&M item
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum AgingBucket {
+    /// Not yet past the scheduled completion date
+    Current,
+    /// 1 to 30 days overdue
+    Overdue1To30,
+    /// 31 to 90 days overdue
+    Overdue31To90,
+    /// More than 90 days overdue
+    OverdueOver90,
+    /// No usable scheduled completion date
+    Undated,
+}
+
+impl AgingBucket {
+    /// All buckets in report order
+    pub const ALL: [AgingBucket; 5] = [
+        AgingBucket::Current,
+        AgingBucket::Overdue1To30,
+        AgingBucket::Overdue31To90,
+        AgingBucket::OverdueOver90,
+        AgingBucket::Undated,
+    ];
+
+    /// Bucket for a number of days past the scheduled completion date
+    pub fn for_days_overdue(days_overdue: i64) -> Self {
+        match days_overdue {
+            i64::MIN..=0 => AgingBucket::Current,
+            1..=30 => AgingBucket::Overdue1To30,
+            31..=90 => AgingBucket::Overdue31To90,
+            _ => AgingBucket::OverdueOver90,
+        }
+    }
+
+    /// Human-readable bucket label
+    pub fn label(&self) -> &'static str {
+        match self {
+            AgingBucket::Current => "0 days",
+            AgingBucket::Overdue1To30 => "1-30 days",
+            AgingBucket::Overdue31To90 => "31-90 days",
+            AgingBucket::OverdueOver90 => "90+ days",
+            AgingBucket::Undated => "Undated",
+        }
+    }
+}
+
+/// Items that fall into one aging bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgingBucketSummary {
+    /// Aging bucket
+    pub bucket: AgingBucket,
+    /// Bucket label for display
+    pub label: String,
+    /// Number of open items in the bucket
+    pub item_count: usize,
+    /// Identifiers of the items in the bucket
+    pub item_ids: Vec<String>,
+}
+
+/// Aging section of a POA&M validation report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoamAgingReport {
+    /// Date the days overdue were computed against
+    pub as_of: DateTime<Utc>,
+    /// Number of open items considered
+    pub total_open_items: usize,
+    /// Item counts per bucket, in `AgingBucket::ALL` order
+    pub buckets: Vec<AgingBucketSummary>,
+    /// Largest number of days any open item is overdue
+    pub max_days_overdue: i64,
+    /// Findings raised while aging the items (e.g. undated items)
+    pub findings: Vec<QualityFinding>,
+}
+
+impl PoamAgingReport {
+    /// Number of items in a bucket
+    pub fn count(&self, bucket: AgingBucket) -> usize {
+        self.buckets.iter()
+            .find(|summary| summary.bucket == bucket)
+            .map_or(0, |summary| summary.item_count)
+    }
+}
+
+/// Compute the aging of open POA&M items as of the given date
+///
+/// Days overdue compare calendar dates of `scheduled_completion_date` and
+/// `as_of`. Closed items are ignored; open items without a parseable scheduled
+/// date are placed in the undated bucket and reported as a finding.
+pub fn calculate_aging(poam_items: &[PoamItem], as_of: DateTime<Utc>) -> PoamAgingReport {
+    let converter = DateConverter::new();
+    let mut bucket_items: HashMap<AgingBucket, Vec<String>> = HashMap::new();
+    let mut total_open_items = 0;
+    let mut max_days_overdue = 0;
+
+    for item in poam_items.iter().filter(|item| !item.is_closed()) {
+        total_open_items += 1;
+
+        let scheduled = item.scheduled_completion_date
+            .as_deref()
+            .and_then(|date| converter.parse_date(date).parsed_date);
+
+        let bucket = match scheduled {
+            Some(scheduled) => {
+                let days_overdue = (as_of.date_naive() - scheduled.date_naive()).num_days();
+                max_days_overdue = max_days_overdue.max(days_overdue);
+                AgingBucket::for_days_overdue(days_overdue)
+            }
+            None => AgingBucket::Undated,
+        };
+
+        bucket_items.entry(bucket).or_default().push(item.uuid.clone());
+    }
+
+    let buckets: Vec<AgingBucketSummary> = AgingBucket::ALL.iter()
+        .map(|bucket| {
+            let item_ids = bucket_items.remove(bucket).unwrap_or_default();
+            AgingBucketSummary {
+                bucket: *bucket,
+                label: bucket.label().to_string(),
+                item_count: item_ids.len(),
+                item_ids,
+            }
+        })
+        .collect();
+
+    let mut findings = Vec::new();
+    if let Some(undated) = buckets.iter().find(|summary| summary.bucket == AgingBucket::Undated && summary.item_count > 0) {
+        findings.push(QualityFinding {
+            id: Uuid::new_v4().to_string(),
+            severity: QualitySeverity::Medium,
+            category: QualityCategory::Completeness,
+            description: format!(
+                "{} open POA&M items have no valid scheduled completion date",
+                undated.item_count
+            ),
+            affected_items: undated.item_ids.clone(),
+            impact_assessment: "Undated items cannot be tracked against remediation timelines".to_string(),
+            recommendation: "Set a scheduled completion date for every open POA&M item".to_string(),
+            location: Some("aging.scheduled_completion_date".to_string()),
+            metadata: HashMap::new(),
+        });
+    }
+
+    PoamAgingReport {
+        as_of,
+        total_open_items,
+        buckets,
+        max_days_overdue,
+        findings,
+    }
+}
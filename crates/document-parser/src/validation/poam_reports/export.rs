@@ -5,7 +5,9 @@
 //! This module provides export capabilities for POA&M validation reports
 //! in various formats including HTML, PDF, JSON, CSV, Markdown, and Excel.
 
+use super::redaction::{RedactionAuditEntry, RedactionConfig, Redactor};
 use super::types::*;
+use chrono::Utc;
 use fedramp_core::{Result, Error};
 use serde_json;
 use tracing::{debug, info, warn};
@@ -18,6 +20,8 @@ pub struct PoamReportExporter {
     config: ExportConfig,
     /// Template cache for performance
     template_cache: HashMap<PoamReportFormat, String>,
+    /// Redactions made by this exporter, one entry per exported file
+    redaction_audit: Vec<RedactionAuditEntry>,
 }
 
 /// Export configuration
@@ -33,6 +37,8 @@ pub struct ExportConfig {
     pub max_file_size: u64,
     /// Custom templates directory
     pub templates_directory: Option<String>,
+    /// Mask sensitive values in the rendered output before it is written
+    pub redaction: Option<RedactionConfig>,
 }
 
 impl Default for ExportConfig {
@@ -43,6 +49,7 @@ impl Default for ExportConfig {
             compress_output: false,
             max_file_size: 50 * 1024 * 1024, // 50MB
             templates_directory: None,
+            redaction: None,
         }
     }
 }
@@ -53,9 +60,15 @@ impl PoamReportExporter {
         Self {
             config,
             template_cache: HashMap::new(),
+            redaction_audit: Vec::new(),
         }
     }
 
+    /// Redactions made so far, one entry per file exported with redaction enabled
+    pub fn redaction_audit(&self) -> &[RedactionAuditEntry] {
+        &self.redaction_audit
+    }
+
     /// Export report to specified format
     pub fn export_report(
         &mut self,
@@ -82,6 +95,7 @@ impl PoamReportExporter {
         let filename = format!("poam_report_{}.html", report.report_id);
         let filepath = format!("{}/{}", self.config.output_directory, filename);
         
+        let content = self.redact_output(report, PoamReportFormat::Html, &filepath, content)?;
         std::fs::write(&filepath, content)
             .map_err(|e| Error::document_parsing(format!("Failed to write HTML report: {}", e)))?;
         
@@ -97,6 +111,7 @@ impl PoamReportExporter {
         let filename = format!("poam_report_{}.pdf", report.report_id);
         let filepath = format!("{}/{}", self.config.output_directory, filename);
         
+        let html_content = self.redact_output(report, PoamReportFormat::Pdf, &filepath, html_content)?;
         // In a real implementation, this would use a PDF generation library
         // For now, we'll save as HTML with PDF extension as placeholder
         std::fs::write(&filepath, html_content)
@@ -114,6 +129,7 @@ impl PoamReportExporter {
         let filename = format!("poam_report_{}.json", report.report_id);
         let filepath = format!("{}/{}", self.config.output_directory, filename);
         
+        let json_content = self.redact_output(report, PoamReportFormat::Json, &filepath, json_content)?;
         std::fs::write(&filepath, json_content)
             .map_err(|e| Error::document_parsing(format!("Failed to write JSON report: {}", e)))?;
         
@@ -127,6 +143,7 @@ impl PoamReportExporter {
         let filename = format!("poam_report_{}.csv", report.report_id);
         let filepath = format!("{}/{}", self.config.output_directory, filename);
         
+        let csv_content = self.redact_output(report, PoamReportFormat::Csv, &filepath, csv_content)?;
         std::fs::write(&filepath, csv_content)
             .map_err(|e| Error::document_parsing(format!("Failed to write CSV report: {}", e)))?;
         
@@ -140,6 +157,7 @@ impl PoamReportExporter {
         let filename = format!("poam_report_{}.md", report.report_id);
         let filepath = format!("{}/{}", self.config.output_directory, filename);
         
+        let markdown_content = self.redact_output(report, PoamReportFormat::Markdown, &filepath, markdown_content)?;
         std::fs::write(&filepath, markdown_content)
             .map_err(|e| Error::document_parsing(format!("Failed to write Markdown report: {}", e)))?;
         
@@ -154,6 +172,7 @@ impl PoamReportExporter {
         let filename = format!("poam_report_{}.xlsx", report.report_id);
         let filepath = format!("{}/{}", self.config.output_directory, filename);
         
+        let csv_content = self.redact_output(report, PoamReportFormat::Excel, &filepath, csv_content)?;
         // In a real implementation, this would use an Excel generation library
         std::fs::write(&filepath, csv_content)
             .map_err(|e| Error::document_parsing(format!("Failed to write Excel report: {}", e)))?;
@@ -162,6 +181,34 @@ impl PoamReportExporter {
         Ok(filepath)
     }
 
+    /// Mask sensitive values in rendered output when redaction is configured
+    ///
+    /// Records an audit entry with the number of masked values for the file
+    /// about to be written.
+    fn redact_output(
+        &mut self,
+        report: &PoamValidationReport,
+        format: PoamReportFormat,
+        filepath: &str,
+        content: String,
+    ) -> Result<String> {
+        let Some(redaction) = &self.config.redaction else {
+            return Ok(content);
+        };
+
+        let (content, redactions) = Redactor::new(redaction)?.redact(&content);
+        info!("Redacted {} sensitive value(s) from {}", redactions, filepath);
+        self.redaction_audit.push(RedactionAuditEntry {
+            report_id: report.report_id,
+            format,
+            output_path: filepath.to_string(),
+            redactions,
+            redacted_at: Utc::now(),
+        });
+
+        Ok(content)
+    }
+
     /// Get HTML template for report rendering
     fn get_html_template(&mut self) -> Result<String> {
         if let Some(template) = self.template_cache.get(&PoamReportFormat::Html) {
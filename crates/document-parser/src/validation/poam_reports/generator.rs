@@ -6,8 +6,10 @@
 //! orchestrating data aggregation, analysis, and report creation.
 
 use super::types::*;
+use super::aging::calculate_aging;
+use super::trend::{calculate_quality_trend_with_config, QualityTrendConfig};
 use crate::validation::poam_validator::types::{PoamValidationResult, PoamValidationConfig};
-use crate::quality::{QualityAssessment, PoamQualityChecker};
+use crate::quality::{QualityAssessment, QualityHistoryStore, PoamQualityChecker};
 use crate::poam::PoamItem;
 use fedramp_core::{Result, Error};
 use tracing::{debug, info, warn};
@@ -37,6 +39,8 @@ pub struct PoamReportConfig {
     pub organization_name: String,
     /// System name for reports
     pub system_name: String,
+    /// Window and regression delta of quality trend reports
+    pub quality_trend: QualityTrendConfig,
 }
 
 impl Default for PoamReportConfig {
@@ -51,6 +55,7 @@ impl Default for PoamReportConfig {
             include_recommendations: true,
             organization_name: "Organization".to_string(),
             system_name: "System".to_string(),
+            quality_trend: QualityTrendConfig::default(),
         }
     }
 }
@@ -66,6 +71,8 @@ pub struct PoamReportGenerator {
     report_cache: HashMap<String, CachedReport>,
     /// Generation metrics
     generation_metrics: GenerationMetrics,
+    /// Earlier assessments of the document, for quality trend reports
+    quality_history: Vec<QualityAssessment>,
 }
 
 /// Cached report entry
@@ -79,10 +86,10 @@ struct CachedReport {
 /// Report generation metrics
 #[derive(Debug, Default)]
 struct GenerationMetrics {
-    total_reports_generated: u64,
-    total_generation_time: Duration,
-    cache_hits: u64,
-    cache_misses: u64,
+    pub(crate) total_reports_generated: u64,
+    pub(crate) total_generation_time: Duration,
+    pub(crate) cache_hits: u64,
+    pub(crate) cache_misses: u64,
 }
 
 impl PoamReportGenerator {
@@ -93,9 +100,23 @@ impl PoamReportGenerator {
             quality_checker: PoamQualityChecker::new(),
             report_cache: HashMap::new(),
             generation_metrics: GenerationMetrics::default(),
+            quality_history: Vec::new(),
         }
     }
 
+    /// Use earlier assessments of the document for quality trend reports
+    pub fn set_quality_history(&mut self, history: Vec<QualityAssessment>) {
+        self.quality_history = history;
+    }
+
+    /// Load earlier assessments of a document from a history store for
+    /// quality trend reports
+    pub async fn load_quality_history(&mut self, store: &dyn QualityHistoryStore, document: &str) -> Result<()> {
+        self.quality_history = store.history(document).await?;
+        debug!("Loaded {} historical quality assessments of {}", self.quality_history.len(), document);
+        Ok(())
+    }
+
     /// Generate a comprehensive POA&M validation report
     pub fn generate_report(
         &mut self,
@@ -155,6 +176,19 @@ impl PoamReportGenerator {
             Vec::new()
         };
 
+        // Age open items against their scheduled completion dates
+        let aging = (report_type == PoamReportType::Aging)
+            .then(|| calculate_aging(poam_items, generated_at));
+
+        // Trend the history together with this run's assessment
+        let quality_trend = if report_type == PoamReportType::QualityTrend {
+            let mut assessments = self.quality_history.clone();
+            assessments.push(quality_assessment.clone());
+            Some(calculate_quality_trend_with_config(&assessments, &self.config.quality_trend)?)
+        } else {
+            None
+        };
+
         // Create report metadata
         let metadata = self.create_report_metadata(start_time)?;
 
@@ -169,6 +203,8 @@ impl PoamReportGenerator {
             quality_assessment,
             compliance_status,
             recommendations,
+            aging,
+            quality_trend,
             metadata,
         };
 
@@ -306,6 +342,9 @@ impl PoamReportGenerator {
 
         let mut hasher = DefaultHasher::new();
         report_type.hash(&mut hasher);
+        if *report_type == PoamReportType::QualityTrend {
+            self.quality_history.len().hash(&mut hasher);
+        }
         poam_items.len().hash(&mut hasher);
         validation_results.len().hash(&mut hasher);
         
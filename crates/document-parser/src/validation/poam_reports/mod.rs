@@ -10,6 +10,9 @@ pub mod types;
 pub mod generator;
 pub mod export;
 pub mod visualization;
+pub mod aging;
+pub mod trend;
+pub mod redaction;
 
 #[cfg(test)]
 mod tests;
@@ -19,3 +22,9 @@ pub use types::*;
 pub use generator::PoamReportGenerator;
 pub use export::PoamReportExporter;
 pub use visualization::PoamVisualizationEngine;
+pub use aging::{calculate_aging, AgingBucket, AgingBucketSummary, PoamAgingReport};
+pub use trend::{
+    calculate_quality_trend, calculate_quality_trend_with_config, DimensionTrend, PoamQualityTrendReport,
+    QualityRegression, QualityTrendConfig, QualityTrendPoint, DEFAULT_REGRESSION_DELTA,
+};
+pub use redaction::{RedactionAuditEntry, RedactionConfig, Redactor};
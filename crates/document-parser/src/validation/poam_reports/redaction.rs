@@ -0,0 +1,147 @@
+// Modified: 2025-09-23
+
+//! Redaction of sensitive values in exported reports
+//!
+//! Reports built from source documents can carry internal IP addresses, email
+//! addresses and hostnames. When redaction is configured, the exporter masks
+//! them in the rendered output, after format rendering and before the file
+//! is written, and records how many values it masked.
+
+use super::types::PoamReportFormat;
+use chrono::{DateTime, Utc};
+use fedramp_core::{Result, Error};
+use regex::{NoExpand, Regex};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// IPv4 addresses, and IPv6 addresses in full or `::`-compressed form
+const IP_ADDRESS_PATTERNS: [&str; 3] = [
+    r"\b(?:(?:25[0-5]|2[0-4]\d|1\d\d|[1-9]?\d)\.){3}(?:25[0-5]|2[0-4]\d|1\d\d|[1-9]?\d)\b",
+    r"(?i)\b(?:[0-9a-f]{1,4}:){7}[0-9a-f]{1,4}\b",
+    r"(?i)\b(?:[0-9a-f]{1,4}:)+:(?:[0-9a-f]{1,4}:)*[0-9a-f]{1,4}\b",
+];
+
+/// Email addresses
+const EMAIL_PATTERN: &str = r"[A-Za-z0-9._%+-]+@[A-Za-z0-9-]+(?:\.[A-Za-z0-9-]+)*\.[A-Za-z]{2,}";
+
+/// Which values to mask in exported reports
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactionConfig {
+    /// Mask IPv4 and IPv6 addresses
+    pub redact_ip_addresses: bool,
+    /// Mask email addresses
+    pub redact_emails: bool,
+    /// Additional regular expressions to mask, e.g. internal hostnames
+    pub custom_patterns: Vec<String>,
+    /// Text that replaces each masked value
+    pub replacement: String,
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            redact_ip_addresses: true,
+            redact_emails: true,
+            custom_patterns: Vec::new(),
+            replacement: "[REDACTED]".to_string(),
+        }
+    }
+}
+
+/// Record of a redaction pass over an exported report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactionAuditEntry {
+    /// Report the export was made from
+    pub report_id: Uuid,
+    /// Export format
+    pub format: PoamReportFormat,
+    /// Path of the written file
+    pub output_path: String,
+    /// Number of values masked
+    pub redactions: usize,
+    /// When the export was redacted
+    pub redacted_at: DateTime<Utc>,
+}
+
+/// Compiled redaction patterns
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    patterns: Vec<Regex>,
+    replacement: String,
+}
+
+impl Redactor {
+    /// Compile the patterns of a configuration
+    ///
+    /// Fails if a custom pattern is not a valid regular expression.
+    pub fn new(config: &RedactionConfig) -> Result<Self> {
+        let mut sources: Vec<&str> = Vec::new();
+        if config.redact_ip_addresses {
+            sources.extend(IP_ADDRESS_PATTERNS);
+        }
+        if config.redact_emails {
+            sources.push(EMAIL_PATTERN);
+        }
+
+        let mut patterns = sources.into_iter()
+            .map(|source| Regex::new(source).expect("built-in redaction pattern is valid"))
+            .collect::<Vec<_>>();
+        for source in &config.custom_patterns {
+            patterns.push(Regex::new(source).map_err(|e| {
+                Error::configuration(format!("Invalid redaction pattern '{}': {}", source, e))
+            })?);
+        }
+
+        Ok(Self {
+            patterns,
+            replacement: config.replacement.clone(),
+        })
+    }
+
+    /// Mask every match of the patterns, returning the text and the number of values masked
+    ///
+    /// Patterns apply in order (IP addresses, emails, then custom patterns),
+    /// each to the output of the previous one.
+    pub fn redact(&self, text: &str) -> (String, usize) {
+        let mut redacted = text.to_string();
+        let mut redactions = 0;
+        for pattern in &self.patterns {
+            let matches = pattern.find_iter(&redacted).count();
+            if matches > 0 {
+                redactions += matches;
+                redacted = pattern.replace_all(&redacted, NoExpand(&self.replacement)).into_owned();
+            }
+        }
+        (redacted, redactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masks_ips_emails_and_custom_patterns() {
+        let redactor = Redactor::new(&RedactionConfig {
+            custom_patterns: vec![r"\b[a-z0-9-]+\.corp\.internal\b".to_string()],
+            ..RedactionConfig::default()
+        }).unwrap();
+
+        let (text, redactions) = redactor.redact(
+            "Scanner 10.1.2.3 and fe80::1 flagged db01.corp.internal; contact ops@example.gov at 10:30:00",
+        );
+
+        assert_eq!(text, "Scanner [REDACTED] and [REDACTED] flagged [REDACTED]; contact [REDACTED] at 10:30:00");
+        assert_eq!(redactions, 4);
+    }
+
+    #[test]
+    fn test_invalid_custom_pattern_is_rejected() {
+        let config = RedactionConfig {
+            custom_patterns: vec!["(".to_string()],
+            ..RedactionConfig::default()
+        };
+
+        assert!(Redactor::new(&config).is_err());
+    }
+}
@@ -0,0 +1,439 @@
+// Modified: 2025-09-20
+
+//! POA&M quality trend analysis
+//!
+//! Orders archived quality assessments by their timestamp and compares the
+//! first and latest scores of each quality dimension, for the quality trend
+//! report. Drops between consecutive assessments larger than a configurable
+//! delta are flagged as regressions.
+
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use fedramp_core::{Error, Result};
+
+use crate::quality::{QualityAssessment, QualityHistoryStore};
+use crate::validation::types::TrendDirection;
+
+/// Score change below which a dimension is reported as stable
+const STABLE_TOLERANCE: f64 = 0.01;
+
+/// Default score drop between consecutive assessments flagged as a regression
+pub const DEFAULT_REGRESSION_DELTA: f64 = 0.05;
+
+/// Reads the score of one dimension from a trend point
+type DimensionScore = fn(&QualityTrendPoint) -> f64;
+
+/// Score of each dimension in a trend point, in report order
+const DIMENSIONS: [(&str, DimensionScore); 5] = [
+    ("Overall", |point| point.overall_score),
+    ("Completeness", |point| point.completeness_score),
+    ("Accuracy", |point| point.accuracy_score),
+    ("Consistency", |point| point.consistency_score),
+    ("Compliance", |point| point.compliance_score),
+];
+
+/// Options for computing a quality trend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityTrendConfig {
+    /// Number of most recent assessments included; all when unset
+    pub window: Option<usize>,
+    /// Score drop between consecutive assessments above which a dimension
+    /// is flagged as a regression
+    pub regression_delta: f64,
+}
+
+impl Default for QualityTrendConfig {
+    fn default() -> Self {
+        Self {
+            window: None,
+            regression_delta: DEFAULT_REGRESSION_DELTA,
+        }
+    }
+}
+
+/// Scores of one assessment in the trend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityTrendPoint {
+    /// Identifier of the assessment
+    pub assessment_id: String,
+    /// When the assessment was made
+    pub timestamp: DateTime<Utc>,
+    pub overall_score: f64,
+    pub completeness_score: f64,
+    pub accuracy_score: f64,
+    pub consistency_score: f64,
+    pub compliance_score: f64,
+    /// Number of findings raised by the assessment
+    pub finding_count: usize,
+}
+
+/// Change of one quality dimension between the first and latest assessment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DimensionTrend {
+    /// Dimension name, e.g. `Compliance`
+    pub dimension: String,
+    /// Score in the earliest assessment
+    pub first_score: f64,
+    /// Score in the latest assessment
+    pub latest_score: f64,
+    /// Latest minus first score
+    pub change: f64,
+    /// Direction of the change; stable for a single assessment
+    pub direction: TrendDirection,
+}
+
+/// Drop of a quality dimension between consecutive assessments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityRegression {
+    /// Dimension name, e.g. `Compliance`
+    pub dimension: String,
+    /// Assessment before the drop
+    pub previous_assessment_id: String,
+    /// Assessment in which the score dropped
+    pub assessment_id: String,
+    /// When the assessment with the drop was made
+    pub timestamp: DateTime<Utc>,
+    pub previous_score: f64,
+    pub score: f64,
+    /// Previous minus current score
+    pub drop: f64,
+}
+
+/// Quality trend across archived POA&M quality assessments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoamQualityTrendReport {
+    /// When the trend was computed
+    pub generated_at: DateTime<Utc>,
+    /// Timestamp of the earliest assessment
+    pub period_start: DateTime<Utc>,
+    /// Timestamp of the latest assessment
+    pub period_end: DateTime<Utc>,
+    /// Assessments in timestamp order
+    pub points: Vec<QualityTrendPoint>,
+    /// Overall, completeness, accuracy, consistency and compliance trends
+    pub dimensions: Vec<DimensionTrend>,
+    /// Drops between consecutive assessments beyond the regression delta
+    #[serde(default)]
+    pub regressions: Vec<QualityRegression>,
+}
+
+impl PoamQualityTrendReport {
+    /// Trend over the assessment history of a document
+    ///
+    /// Fails if the store has no assessments of the document.
+    pub async fn from_history(
+        store: &dyn QualityHistoryStore,
+        document: &str,
+        config: &QualityTrendConfig,
+    ) -> Result<Self> {
+        let assessments = store.history(document).await?;
+        if assessments.is_empty() {
+            return Err(Error::validation(format!("No quality history recorded for {}", document)));
+        }
+        calculate_quality_trend_with_config(&assessments, config)
+    }
+
+    /// Trend of a dimension by name
+    pub fn dimension(&self, name: &str) -> Option<&DimensionTrend> {
+        self.dimensions.iter().find(|trend| trend.dimension.eq_ignore_ascii_case(name))
+    }
+
+    /// Render the trend as a standalone HTML page
+    ///
+    /// Improvements and regressions are marked with arrows; a report built
+    /// from a single assessment shows flat trends without arrows.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <title>POA&amp;M Quality Trend</title>
+    <style>
+        body { font-family: Arial, sans-serif; margin: 20px; }
+        .header { background-color: #f0f0f0; padding: 20px; border-radius: 5px; }
+        .section { margin: 20px 0; }
+        .improving { color: #388e3c; }
+        .declining { color: #d32f2f; }
+        table { border-collapse: collapse; width: 100%; }
+        th, td { border: 1px solid #ddd; padding: 8px; text-align: left; }
+        th { background-color: #f2f2f2; }
+    </style>
+</head>
+<body>
+"#,
+        );
+
+        html.push_str(&format!(
+            r#"<div class="header">
+    <h1>POA&amp;M Quality Trend</h1>
+    <p><strong>Period:</strong> {} to {}</p>
+    <p><strong>Assessments:</strong> {}</p>
+    <p><strong>Generated:</strong> {}</p>
+</div>
+"#,
+            self.period_start.format("%Y-%m-%d"),
+            self.period_end.format("%Y-%m-%d"),
+            self.points.len(),
+            self.generated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        ));
+
+        html.push_str(r#"<div class="section"><h2>Summary</h2><table>
+<tr><th>Dimension</th><th>First</th><th>Latest</th><th>Change</th></tr>
+"#);
+        for trend in &self.dimensions {
+            let (class, arrow) = match trend.direction {
+                TrendDirection::Improving => ("improving", "&#9650; "),
+                TrendDirection::Declining => ("declining", "&#9660; "),
+                TrendDirection::Stable | TrendDirection::InsufficientData => ("stable", ""),
+            };
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{:.1}%</td><td>{:.1}%</td><td class=\"{}\">{}{:+.1} pts</td></tr>\n",
+                trend.dimension,
+                trend.first_score * 100.0,
+                trend.latest_score * 100.0,
+                class,
+                arrow,
+                trend.change * 100.0,
+            ));
+        }
+        html.push_str("</table></div>\n");
+
+        if !self.regressions.is_empty() {
+            html.push_str(r#"<div class="section"><h2>Regressions</h2><table>
+<tr><th>Date</th><th>Assessment</th><th>Dimension</th><th>Previous</th><th>Score</th><th>Drop</th></tr>
+"#);
+            for regression in &self.regressions {
+                html.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{:.1}%</td><td>{:.1}%</td><td class=\"declining\">&#9660; {:.1} pts</td></tr>\n",
+                    regression.timestamp.format("%Y-%m-%d %H:%M"),
+                    escape_html(&regression.assessment_id),
+                    regression.dimension,
+                    regression.previous_score * 100.0,
+                    regression.score * 100.0,
+                    regression.drop * 100.0,
+                ));
+            }
+            html.push_str("</table></div>\n");
+        }
+
+        html.push_str(r#"<div class="section"><h2>Assessments</h2><table>
+<tr><th>Date</th><th>Assessment</th><th>Overall</th><th>Completeness</th><th>Accuracy</th><th>Consistency</th><th>Compliance</th><th>Findings</th></tr>
+"#);
+        for point in &self.points {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{:.1}%</td><td>{:.1}%</td><td>{:.1}%</td><td>{:.1}%</td><td>{:.1}%</td><td>{}</td></tr>\n",
+                point.timestamp.format("%Y-%m-%d %H:%M"),
+                escape_html(&point.assessment_id),
+                point.overall_score * 100.0,
+                point.completeness_score * 100.0,
+                point.accuracy_score * 100.0,
+                point.consistency_score * 100.0,
+                point.compliance_score * 100.0,
+                point.finding_count,
+            ));
+        }
+        html.push_str("</table></div>\n</body>\n</html>\n");
+
+        html
+    }
+}
+
+/// Compute quality trends across assessments, ordered by their timestamp
+///
+/// Fails if no assessments are given.
+pub fn calculate_quality_trend(assessments: &[QualityAssessment]) -> Result<PoamQualityTrendReport> {
+    calculate_quality_trend_with_config(assessments, &QualityTrendConfig::default())
+}
+
+/// Compute quality trends over the window of most recent assessments
+///
+/// Fails if no assessments are in the window.
+pub fn calculate_quality_trend_with_config(
+    assessments: &[QualityAssessment],
+    config: &QualityTrendConfig,
+) -> Result<PoamQualityTrendReport> {
+    let mut points: Vec<QualityTrendPoint> = assessments.iter()
+        .map(|assessment| QualityTrendPoint {
+            assessment_id: assessment.assessment_id.clone(),
+            timestamp: assessment.timestamp,
+            overall_score: assessment.overall_score,
+            completeness_score: assessment.completeness_score,
+            accuracy_score: assessment.accuracy_score,
+            consistency_score: assessment.consistency_score,
+            compliance_score: assessment.compliance_score,
+            finding_count: assessment.findings.len(),
+        })
+        .collect();
+    points.sort_by_key(|point| point.timestamp);
+    if let Some(window) = config.window {
+        points.drain(..points.len().saturating_sub(window));
+    }
+
+    let (first, latest) = match (points.first(), points.last()) {
+        (Some(first), Some(latest)) => (first, latest),
+        _ => return Err(Error::validation("At least one quality assessment is required for a trend")),
+    };
+
+    let dimensions = DIMENSIONS.iter()
+        .map(|(dimension, score)| {
+            let change = score(latest) - score(first);
+            let direction = if change >= STABLE_TOLERANCE {
+                TrendDirection::Improving
+            } else if change <= -STABLE_TOLERANCE {
+                TrendDirection::Declining
+            } else {
+                TrendDirection::Stable
+            };
+            DimensionTrend {
+                dimension: dimension.to_string(),
+                first_score: score(first),
+                latest_score: score(latest),
+                change,
+                direction,
+            }
+        })
+        .collect();
+
+    let regressions = points.windows(2)
+        .flat_map(|pair| {
+            let (previous, current) = (&pair[0], &pair[1]);
+            DIMENSIONS.iter().filter_map(move |(dimension, score)| {
+                let drop = score(previous) - score(current);
+                (drop > config.regression_delta).then(|| QualityRegression {
+                    dimension: dimension.to_string(),
+                    previous_assessment_id: previous.assessment_id.clone(),
+                    assessment_id: current.assessment_id.clone(),
+                    timestamp: current.timestamp,
+                    previous_score: score(previous),
+                    score: score(current),
+                    drop,
+                })
+            })
+        })
+        .collect();
+
+    Ok(PoamQualityTrendReport {
+        generated_at: Utc::now(),
+        period_start: first.timestamp,
+        period_end: latest.timestamp,
+        dimensions,
+        regressions,
+        points,
+    })
+}
+
+/// Escape text for inclusion in HTML
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quality::QualityMetrics;
+    use chrono::TimeZone;
+    use std::collections::HashMap;
+
+    fn assessment(id: &str, day: u32, overall: f64, compliance: f64) -> QualityAssessment {
+        QualityAssessment {
+            assessment_id: id.to_string(),
+            timestamp: Utc.with_ymd_and_hms(2025, 3, day, 12, 0, 0).unwrap(),
+            overall_score: overall,
+            completeness_score: 0.9,
+            accuracy_score: 0.8,
+            consistency_score: 0.85,
+            compliance_score: compliance,
+            quality_metrics: QualityMetrics {
+                total_items: 10,
+                complete_items: 8,
+                incomplete_items: 2,
+                error_count: 0,
+                warning_count: 0,
+                missing_required_fields: 0,
+                data_quality_issues: 0,
+                field_completeness: HashMap::new(),
+                category_metrics: HashMap::new(),
+            },
+            findings: Vec::new(),
+            recommendations: Vec::new(),
+            config_summary: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_trend_orders_assessments_by_timestamp() {
+        let report = calculate_quality_trend(&[
+            assessment("march-20", 20, 0.82, 0.60),
+            assessment("march-01", 1, 0.70, 0.75),
+            assessment("march-10", 10, 0.76, 0.70),
+        ]).unwrap();
+
+        let ids: Vec<&str> = report.points.iter().map(|point| point.assessment_id.as_str()).collect();
+        assert_eq!(ids, vec!["march-01", "march-10", "march-20"]);
+        assert_eq!(report.dimension("overall").unwrap().direction, TrendDirection::Improving);
+        assert_eq!(report.dimension("compliance").unwrap().direction, TrendDirection::Declining);
+        assert_eq!(report.dimension("accuracy").unwrap().direction, TrendDirection::Stable);
+
+        let html = report.to_html();
+        assert!(html.contains("&#9650; +12.0 pts"));
+        assert!(html.contains("&#9660; -15.0 pts"));
+    }
+
+    #[test]
+    fn test_single_assessment_has_flat_trend_without_arrows() {
+        let report = calculate_quality_trend(&[assessment("only", 5, 0.8, 0.7)]).unwrap();
+
+        assert_eq!(report.period_start, report.period_end);
+        assert!(report.dimensions.iter().all(|trend| trend.direction == TrendDirection::Stable && trend.change == 0.0));
+
+        let html = report.to_html();
+        assert!(!html.contains("&#9650;") && !html.contains("&#9660;"));
+        assert!(calculate_quality_trend(&[]).is_err());
+    }
+
+    #[test]
+    fn test_drops_between_consecutive_runs_are_regressions() {
+        let assessments = [
+            assessment("march-01", 1, 0.70, 0.80),
+            assessment("march-10", 10, 0.76, 0.70),
+            assessment("march-20", 20, 0.82, 0.67),
+            assessment("march-30", 30, 0.74, 0.75),
+        ];
+
+        let report = calculate_quality_trend(&assessments).unwrap();
+        let regressions: Vec<(&str, &str)> = report.regressions.iter()
+            .map(|regression| (regression.dimension.as_str(), regression.assessment_id.as_str()))
+            .collect();
+        assert_eq!(regressions, [("Compliance", "march-10"), ("Overall", "march-30")]);
+        assert!((report.regressions[0].drop - 0.10).abs() < 1e-9);
+        assert!(report.to_html().contains("<h2>Regressions</h2>"));
+
+        let config = QualityTrendConfig { window: Some(2), regression_delta: 0.02 };
+        let report = calculate_quality_trend_with_config(&assessments, &config).unwrap();
+        let ids: Vec<&str> = report.points.iter().map(|point| point.assessment_id.as_str()).collect();
+        assert_eq!(ids, ["march-20", "march-30"]);
+        assert_eq!(report.regressions.len(), 1);
+        assert_eq!(report.dimension("compliance").unwrap().direction, TrendDirection::Improving);
+    }
+
+    #[tokio::test]
+    async fn test_trend_from_history_store() {
+        use crate::quality::FileQualityHistoryStore;
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = FileQualityHistoryStore::new(dir.path());
+        assert!(PoamQualityTrendReport::from_history(&store, "poam.xlsx", &QualityTrendConfig::default()).await.is_err());
+
+        store.append("poam.xlsx", &assessment("march-20", 20, 0.60, 0.70)).await.unwrap();
+        store.append("poam.xlsx", &assessment("march-01", 1, 0.80, 0.70)).await.unwrap();
+
+        let report = PoamQualityTrendReport::from_history(&store, "poam.xlsx", &QualityTrendConfig::default()).await.unwrap();
+        assert_eq!(report.points[0].assessment_id, "march-01");
+        assert_eq!(report.regressions.len(), 1);
+        assert_eq!(report.dimension("overall").unwrap().direction, TrendDirection::Declining);
+    }
+}
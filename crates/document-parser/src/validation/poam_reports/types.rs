@@ -31,6 +31,8 @@ pub enum PoamReportType {
     ExecutiveSummary,
     /// Performance analysis report
     Performance,
+    /// Aging of open items past their scheduled completion date
+    Aging,
 }
 
 /// Output format for POA&M reports
@@ -73,6 +75,12 @@ pub struct PoamValidationReport {
     pub compliance_status: ComplianceStatus,
     /// Actionable recommendations
     pub recommendations: Vec<Recommendation>,
+    /// Aging of open items (present for aging reports)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aging: Option<super::aging::PoamAgingReport>,
+    /// Quality trend over the assessment history (present for quality trend reports)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality_trend: Option<super::trend::PoamQualityTrendReport>,
     /// Report metadata and generation info
     pub metadata: ReportMetadata,
 }
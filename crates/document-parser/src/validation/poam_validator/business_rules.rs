@@ -7,7 +7,7 @@
 
 use super::types::{
     BusinessRule, BusinessRuleResult, RuleCondition, RuleAction, LogicalOperator,
-    CustomValidationRule, ValidationError, ValidationWarning
+    CustomValidationRule, ValidationError, ValidationWarning, PoamStatus
 };
 use crate::validation::types::ValidationSeverity;
 use fedramp_core::Result;
@@ -17,6 +17,10 @@ use regex::Regex;
 use chrono::NaiveDate;
 use tracing::{info, warn};
 
+/// Days an actual completion may precede the scheduled date before it is
+/// flagged as implausible
+pub const MAX_EARLY_COMPLETION_DAYS: i64 = 365;
+
 /// Business rule validator
 #[derive(Debug, Clone)]
 pub struct BusinessRuleValidator {
@@ -276,6 +280,85 @@ impl BusinessRuleValidator {
         hasher.finish()
     }
 
+    /// Check that the status agrees with the completion dates
+    ///
+    /// A `Completed` item needs an `actual_completion_date` that is not after
+    /// `today`; an `Open` or `In Progress` item must not have one; and the
+    /// actual date must not precede `scheduled_completion_date` by more than
+    /// [`MAX_EARLY_COMPLETION_DAYS`]. Dates that do not parse are left to
+    /// field validation.
+    pub fn validate_status_dates(
+        &self,
+        status: &PoamStatus,
+        poam_data: &HashMap<String, Value>,
+        today: NaiveDate,
+    ) -> Vec<ValidationError> {
+        let actual_value = poam_data.get("actual_completion_date")
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|v| !v.is_empty());
+        let actual_date = actual_value.and_then(parse_date);
+        let scheduled_date = poam_data.get("scheduled_completion_date")
+            .and_then(|v| v.as_str())
+            .and_then(|v| parse_date(v.trim()));
+
+        let mut errors = Vec::new();
+        match (status, actual_value) {
+            (PoamStatus::Completed, None) => errors.push(ValidationError {
+                code: "RULE_COMPLETED_REQUIRES_ACTUAL_DATE".to_string(),
+                message: "status is 'Completed' but actual_completion_date is missing".to_string(),
+                field: Some("actual_completion_date".to_string()),
+                severity: ValidationSeverity::Error,
+                suggested_fix: Some(today.format("%Y-%m-%d").to_string()),
+            }),
+            (PoamStatus::Open | PoamStatus::InProgress, Some(actual)) => errors.push(ValidationError {
+                code: "RULE_OPEN_HAS_ACTUAL_DATE".to_string(),
+                message: format!(
+                    "status is '{}' but actual_completion_date is set to '{}'",
+                    if *status == PoamStatus::Open { "Open" } else { "In Progress" },
+                    actual
+                ),
+                field: Some("actual_completion_date".to_string()),
+                severity: ValidationSeverity::Error,
+                suggested_fix: Some("Clear actual_completion_date or set status to 'Completed'".to_string()),
+            }),
+            _ => {}
+        }
+
+        if let Some(actual) = actual_date {
+            if *status == PoamStatus::Completed && actual > today {
+                errors.push(ValidationError {
+                    code: "RULE_ACTUAL_DATE_IN_FUTURE".to_string(),
+                    message: format!(
+                        "status is 'Completed' but actual_completion_date {} is in the future",
+                        actual
+                    ),
+                    field: Some("actual_completion_date".to_string()),
+                    severity: ValidationSeverity::Error,
+                    suggested_fix: Some(today.format("%Y-%m-%d").to_string()),
+                });
+            }
+
+            if let Some(scheduled) = scheduled_date {
+                let days_early = (scheduled - actual).num_days();
+                if days_early > MAX_EARLY_COMPLETION_DAYS {
+                    errors.push(ValidationError {
+                        code: "RULE_ACTUAL_DATE_BEFORE_SCHEDULED".to_string(),
+                        message: format!(
+                            "actual_completion_date {} is {} days before scheduled_completion_date {}",
+                            actual, days_early, scheduled
+                        ),
+                        field: Some("actual_completion_date".to_string()),
+                        severity: ValidationSeverity::Warning,
+                        suggested_fix: None,
+                    });
+                }
+            }
+        }
+
+        errors
+    }
+
     /// Add a new business rule
     pub fn add_rule(&mut self, rule: BusinessRule) {
         self.rules.push(rule);
@@ -322,3 +405,67 @@ impl BusinessRuleValidator {
         (self.rule_cache.len(), self.rules.len())
     }
 }
+
+/// Parse a date in one of the formats POA&M templates use
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    ["%Y-%m-%d", "%m/%d/%Y", "%d/%m/%Y"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn item(status: &str, scheduled: &str, actual: Option<&str>) -> HashMap<String, Value> {
+        let mut data = HashMap::new();
+        data.insert("status".to_string(), json!(status));
+        data.insert("scheduled_completion_date".to_string(), json!(scheduled));
+        if let Some(actual) = actual {
+            data.insert("actual_completion_date".to_string(), json!(actual));
+        }
+        data
+    }
+
+    fn codes(status: PoamStatus, data: &HashMap<String, Value>) -> Vec<String> {
+        let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+        BusinessRuleValidator::new(&[])
+            .validate_status_dates(&status, data, today)
+            .into_iter()
+            .map(|error| error.code)
+            .collect()
+    }
+
+    #[test]
+    fn test_completed_without_actual_date_fails() {
+        let data = item("Completed", "2025-05-01", None);
+        let today = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+
+        let errors = BusinessRuleValidator::new(&[]).validate_status_dates(&PoamStatus::Completed, &data, today);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, "RULE_COMPLETED_REQUIRES_ACTUAL_DATE");
+        assert!(errors[0].message.contains("status") && errors[0].message.contains("actual_completion_date"));
+        assert_eq!(errors[0].severity, ValidationSeverity::Error);
+    }
+
+    #[test]
+    fn test_status_date_pairings() {
+        assert!(codes(PoamStatus::Completed, &item("Completed", "2025-05-01", Some("2025-04-20"))).is_empty());
+        assert_eq!(
+            codes(PoamStatus::Completed, &item("Completed", "2025-05-01", Some("2025-07-01"))),
+            vec!["RULE_ACTUAL_DATE_IN_FUTURE"]
+        );
+        assert_eq!(
+            codes(PoamStatus::InProgress, &item("In Progress", "2025-05-01", Some("2025-04-20"))),
+            vec!["RULE_OPEN_HAS_ACTUAL_DATE"]
+        );
+        assert_eq!(
+            codes(PoamStatus::Completed, &item("Completed", "2025-05-01", Some("2023-01-01"))),
+            vec!["RULE_ACTUAL_DATE_BEFORE_SCHEDULED"]
+        );
+        assert!(codes(PoamStatus::Open, &item("Open", "2025-05-01", None)).is_empty());
+    }
+}
@@ -129,6 +129,25 @@ impl PoamValidator {
             }
         }
 
+        // Status and completion dates must agree
+        let status = poam_data.get("status")
+            .and_then(|v| v.as_str())
+            .and_then(|v| self.status_validator.resolve_status(v));
+        if let Some(status) = status {
+            let today = chrono::Utc::now().date_naive();
+            for error in self.business_rule_validator.validate_status_dates(&status, poam_data, today) {
+                match error.severity {
+                    ValidationSeverity::Warning => warnings.push(ValidationWarning {
+                        code: error.code,
+                        message: error.message,
+                        field: error.field,
+                        recommendation: error.suggested_fix,
+                    }),
+                    _ => errors.push(error),
+                }
+            }
+        }
+
         // 3. Validate cross-field relationships
         let cross_field_results = self.cross_field_validator.validate_cross_fields(poam_data)?;
         for result in cross_field_results {
@@ -275,6 +275,11 @@ impl StatusValidator {
         })
     }
 
+    /// Status a value or alias stands for, e.g. `done` for `Completed`
+    pub fn resolve_status(&self, status_str: &str) -> Option<PoamStatus> {
+        self.status_aliases.get(&status_str.trim().to_lowercase()).cloned()
+    }
+
     /// Validate status transition
     pub fn validate_status_transition(&self, from_status: &PoamStatus, to_status: &PoamStatus) -> Result<bool> {
         if let Some(allowed_transitions) = self.status_transitions.get(from_status) {
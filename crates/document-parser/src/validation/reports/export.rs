@@ -81,6 +81,17 @@ impl ReportExporter {
         md_content.push_str(&format!("- **Average Confidence:** {:.1}%\n", report.mapping_summary.average_confidence * 100.0));
         md_content.push_str(&format!("- **Quality Grade:** {:?}\n\n", report.quality_metrics.quality_grade));
 
+        let histogram = &report.confidence_histogram;
+        md_content.push_str("## Confidence Distribution\n\n");
+        md_content.push_str("| Confidence | Mappings |\n|---|---|\n");
+        for bucket in &histogram.buckets {
+            md_content.push_str(&format!("| {:.1}-{:.1} | {} |\n", bucket.lower_bound, bucket.upper_bound, bucket.count));
+        }
+        md_content.push_str(&format!(
+            "\n- **Auto-accepted (>= {:.2}):** {}\n- **Flagged for review:** {}\n\n",
+            histogram.auto_accept_threshold, histogram.auto_accepted, histogram.flagged
+        ));
+
         md_content.push_str("## Recommendations\n\n");
         for (i, rec) in report.recommendations.iter().enumerate() {
             md_content.push_str(&format!("{}. **{}** (Priority: {:?})\n", i + 1, rec.title, rec.priority));
@@ -251,7 +262,7 @@ impl ReportExporter {
         } else if template_path.ends_with(".md") {
             self.export_to_markdown(report)
         } else {
-            Err(Error::document_parsing(format!("Unsupported template format: {}", template_path)))
+            Err(Error::unsupported_format(format!("template {}", template_path)))
         }
     }
 
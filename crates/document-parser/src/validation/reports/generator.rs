@@ -123,6 +123,9 @@ impl MappingReportGenerator {
         // Create mapping summary
         let mapping_summary = self.create_mapping_summary(validation_results);
 
+        // Create confidence histogram
+        let confidence_histogram = self.create_confidence_histogram(validation_results);
+
         // Create detailed results
         let detailed_results = self.create_detailed_results(validation_results);
 
@@ -159,6 +162,7 @@ impl MappingReportGenerator {
             generated_at: chrono::Utc::now(),
             document_info,
             mapping_summary,
+            confidence_histogram,
             detailed_results,
             quality_metrics,
             validation_summary,
@@ -248,6 +252,17 @@ impl MappingReportGenerator {
         }
     }
 
+    /// Create the confidence histogram of the mapped fields
+    ///
+    /// Fields without a source column have no mapping and are left out.
+    fn create_confidence_histogram(&self, validation_results: &ValidationResults) -> ConfidenceHistogram {
+        let scores = validation_results.field_results.iter()
+            .filter(|r| r.source_column.is_some())
+            .map(|r| r.confidence_score);
+
+        ConfidenceHistogram::new(scores, self.config.confidence_buckets, self.config.auto_accept_threshold)
+    }
+
     /// Create detailed field mapping results
     fn create_detailed_results(&self, validation_results: &ValidationResults) -> Vec<FieldMappingResult> {
         validation_results.field_results.iter().map(|field_result| {
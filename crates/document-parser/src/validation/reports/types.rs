@@ -29,6 +29,20 @@ pub struct ReportConfig {
     pub include_recommendations: bool,
     /// Report template directory
     pub template_directory: Option<String>,
+    /// Number of equal-width buckets in the confidence histogram
+    #[serde(default = "default_confidence_buckets")]
+    pub confidence_buckets: usize,
+    /// Confidence at or above which a mapping is accepted without review
+    #[serde(default = "default_auto_accept_threshold")]
+    pub auto_accept_threshold: f64,
+}
+
+fn default_confidence_buckets() -> usize {
+    10
+}
+
+fn default_auto_accept_threshold() -> f64 {
+    0.8
 }
 
 /// Comprehensive mapping validation report
@@ -46,6 +60,8 @@ pub struct MappingReport {
     pub document_info: DocumentInfo,
     /// High-level mapping summary
     pub mapping_summary: MappingSummary,
+    /// Distribution of mapping confidence scores
+    pub confidence_histogram: ConfidenceHistogram,
     /// Detailed field mapping results
     pub detailed_results: Vec<FieldMappingResult>,
     /// Data quality assessment
@@ -144,6 +160,62 @@ pub struct MappingSummary {
     pub quality_score: f64,
 }
 
+/// Distribution of mapping confidence scores over equal-width buckets of 0.0-1.0
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfidenceHistogram {
+    /// Buckets in ascending order of confidence
+    pub buckets: Vec<ConfidenceBucket>,
+    /// Threshold separating auto-accepted from flagged mappings
+    pub auto_accept_threshold: f64,
+    /// Mappings at or above the threshold
+    pub auto_accepted: usize,
+    /// Mappings below the threshold, which need review
+    pub flagged: usize,
+}
+
+/// Confidence range of a histogram bucket and the mappings that fall in it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfidenceBucket {
+    /// Inclusive lower bound
+    pub lower_bound: f64,
+    /// Upper bound; exclusive except for the last bucket, which includes 1.0
+    pub upper_bound: f64,
+    /// Number of mappings in the range
+    pub count: usize,
+}
+
+impl ConfidenceHistogram {
+    /// Bucket confidence scores, clamping them to 0.0-1.0
+    ///
+    /// A `bucket_count` of 0 is treated as 1.
+    pub fn new(scores: impl IntoIterator<Item = f64>, bucket_count: usize, auto_accept_threshold: f64) -> Self {
+        let bucket_count = bucket_count.max(1);
+        let width = 1.0 / bucket_count as f64;
+        let mut buckets: Vec<ConfidenceBucket> = (0..bucket_count)
+            .map(|index| ConfidenceBucket {
+                lower_bound: index as f64 * width,
+                upper_bound: (index + 1) as f64 * width,
+                count: 0,
+            })
+            .collect();
+
+        let mut auto_accepted = 0;
+        let mut flagged = 0;
+        for score in scores {
+            let score = score.clamp(0.0, 1.0);
+            let index = ((score * bucket_count as f64) as usize).min(bucket_count - 1);
+            buckets[index].count += 1;
+            if score >= auto_accept_threshold {
+                auto_accepted += 1;
+            } else {
+                flagged += 1;
+            }
+        }
+
+        Self { buckets, auto_accept_threshold, auto_accepted, flagged }
+    }
+}
+
 /// Detailed result for a single field mapping
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldMappingResult {
@@ -374,6 +446,8 @@ impl Default for ReportConfig {
             include_detailed_analysis: true,
             include_recommendations: true,
             template_directory: None,
+            confidence_buckets: default_confidence_buckets(),
+            auto_accept_threshold: default_auto_accept_threshold(),
         }
     }
 }
@@ -0,0 +1,193 @@
+//! SARIF 2.1.0 output of validation errors
+//!
+//! CI security gates ingest SARIF to annotate pull requests inline. Each
+//! validation error becomes one result: its rule is the error's `rule_id`,
+//! its level follows the error's severity, and it is located by file and, when
+//! the file's text is available, by the line holding the error's JSON Pointer.
+//! The pointer itself is kept as a logical location.
+
+use crate::{ValidationError, ValidationSeverity};
+use serde_json::{json, Value};
+
+/// SARIF version written to the log
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// Schema URI written to the log
+pub const SARIF_SCHEMA_URI: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Rule ID of errors not attributed to a rule
+pub const DEFAULT_SARIF_RULE_ID: &str = "validation";
+
+/// SARIF log of validation errors from one tool run
+#[derive(Debug, Clone)]
+pub struct SarifReport {
+    tool_name: String,
+    tool_version: String,
+    rule_ids: Vec<String>,
+    results: Vec<Value>,
+}
+
+impl SarifReport {
+    /// Empty report for a tool
+    pub fn new(tool_name: impl Into<String>, tool_version: impl Into<String>) -> Self {
+        Self {
+            tool_name: tool_name.into(),
+            tool_version: tool_version.into(),
+            rule_ids: Vec::new(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Add the validation errors of one file
+    ///
+    /// `source` is the file's text; when given, each error is placed on the
+    /// line of the value its JSON Pointer names.
+    pub fn add_file(&mut self, uri: &str, source: Option<&str>, errors: &[ValidationError]) {
+        for error in errors {
+            let rule_id = error.rule_id.as_deref().unwrap_or(DEFAULT_SARIF_RULE_ID);
+            let rule_index = match self.rule_ids.iter().position(|id| id == rule_id) {
+                Some(index) => index,
+                None => {
+                    self.rule_ids.push(rule_id.to_string());
+                    self.rule_ids.len() - 1
+                }
+            };
+
+            let mut physical_location = json!({ "artifactLocation": { "uri": uri } });
+            if let Some(line) = source.and_then(|source| pointer_line(source, &error.path)) {
+                physical_location["region"] = json!({ "startLine": line });
+            }
+            let mut location = json!({ "physicalLocation": physical_location });
+            if !error.path.is_empty() {
+                location["logicalLocations"] = json!([{ "fullyQualifiedName": error.path }]);
+            }
+
+            self.results.push(json!({
+                "ruleId": rule_id,
+                "ruleIndex": rule_index,
+                "level": sarif_level(&error.severity),
+                "message": { "text": error.message },
+                "locations": [location],
+            }));
+        }
+    }
+
+    /// Number of results added so far
+    pub fn result_count(&self) -> usize {
+        self.results.len()
+    }
+
+    /// The SARIF log as JSON
+    pub fn to_json(&self) -> Value {
+        let rules: Vec<Value> = self.rule_ids.iter().map(|id| json!({ "id": id })).collect();
+        json!({
+            "$schema": SARIF_SCHEMA_URI,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": self.tool_name,
+                        "version": self.tool_version,
+                        "rules": rules,
+                    }
+                },
+                "results": self.results,
+            }]
+        })
+    }
+}
+
+/// SARIF result level of a validation severity
+pub fn sarif_level(severity: &ValidationSeverity) -> &'static str {
+    match severity {
+        ValidationSeverity::Info => "note",
+        ValidationSeverity::Warning => "warning",
+        ValidationSeverity::Error | ValidationSeverity::Critical => "error",
+    }
+}
+
+/// 1-based line of the value a JSON Pointer names in JSON or YAML text
+///
+/// Object keys are searched for in order, each after the previous one, as
+/// `"key":` or `key:`; array indexes are skipped, so the line of the
+/// enclosing key is used for array elements. Returns `None` for an empty
+/// pointer or a key that cannot be found.
+pub fn pointer_line(source: &str, pointer: &str) -> Option<usize> {
+    let mut offset = None;
+    for segment in pointer.split('/').skip(1) {
+        let key = segment.replace("~1", "/").replace("~0", "~");
+        if key.parse::<usize>().is_ok() {
+            continue;
+        }
+        let start = offset.unwrap_or(0);
+        offset = Some(start + find_key(&source[start..], &key)?);
+    }
+    offset.map(|offset| source[..offset].matches('\n').count() + 1)
+}
+
+/// Byte offset just past a `key:` entry, quoted or not
+fn find_key(text: &str, key: &str) -> Option<usize> {
+    text.match_indices(key).find_map(|(index, _)| {
+        let before = text[..index].chars().next_back();
+        let after = &text[index + key.len()..];
+        let (quoted, after) = match before {
+            Some(quote @ ('"' | '\'')) => (true, after.strip_prefix(quote)?),
+            _ => (false, after),
+        };
+        if !quoted && before.is_some_and(|c| !c.is_whitespace() && c != '{' && c != ',') {
+            return None;
+        }
+        after.trim_start_matches([' ', '\t']).starts_with(':').then(|| index + key.len())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonschema::JSONSchema;
+
+    /// The OASIS SARIF 2.1.0 schema
+    const SARIF_SCHEMA: &str = include_str!("../../schemas/sarif-schema-2.1.0.json");
+
+    #[test]
+    fn test_report_matches_sarif_schema() {
+        let source = "{\n  \"fedramp_iiw_mappings\": {\n    \"required_columns\": {\n      \"asset_id\": {\n        \"column_names\": [\"Asset ID\"]\n      }\n    }\n  }\n}\n";
+        let errors = vec![
+            ValidationError::new(
+                "/fedramp_iiw_mappings/required_columns/asset_id",
+                "\"field\" is a required property",
+                ValidationSeverity::Error,
+            ).with_rule_id("mapping-schema/required"),
+            ValidationError::new("", "Mapping has no description", ValidationSeverity::Warning),
+        ];
+
+        let mut report = SarifReport::new("fedramp", "0.1.0");
+        report.add_file("mappings/inventory_mappings.json", Some(source), &errors);
+        let log = report.to_json();
+
+        let schema: Value = serde_json::from_str(SARIF_SCHEMA).unwrap();
+        let schema = JSONSchema::compile(&schema).unwrap();
+        if let Err(violations) = schema.validate(&log) {
+            let violations: Vec<String> = violations.map(|violation| violation.to_string()).collect();
+            panic!("SARIF log violates the schema: {:?}", violations);
+        }
+
+        let results = &log["runs"][0]["results"];
+        assert_eq!(results[0]["ruleId"], "mapping-schema/required");
+        assert_eq!(results[0]["level"], "error");
+        assert_eq!(results[0]["locations"][0]["physicalLocation"]["region"]["startLine"], 4);
+        assert_eq!(results[1]["ruleId"], DEFAULT_SARIF_RULE_ID);
+        assert_eq!(results[1]["level"], "warning");
+        assert!(results[1]["locations"][0]["physicalLocation"].get("region").is_none());
+        assert_eq!(log["runs"][0]["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_pointer_line_in_yaml() {
+        let source = "description: Inventory\nfedramp_iiw_mappings:\n  required_columns:\n    asset_id:\n      required: true\n";
+
+        assert_eq!(pointer_line(source, "/fedramp_iiw_mappings/required_columns/asset_id"), Some(4));
+        assert_eq!(pointer_line(source, "/fedramp_iiw_mappings/missing"), None);
+        assert_eq!(pointer_line(source, ""), None);
+    }
+}
@@ -303,6 +303,18 @@ mod tests {
         assert_eq!(config.cache_expiration_minutes, 60);
     }
 
+    #[test]
+    fn test_confidence_histogram_buckets_and_threshold() {
+        let scores = [0.05, 0.35, 0.45, 0.72, 0.79, 0.8, 0.95, 1.0];
+        let histogram = ConfidenceHistogram::new(scores, 5, 0.8);
+
+        let counts: Vec<usize> = histogram.buckets.iter().map(|bucket| bucket.count).collect();
+        assert_eq!(counts, vec![1, 1, 1, 2, 3]);
+        assert_eq!(histogram.buckets[4].lower_bound, 0.8);
+        assert_eq!(histogram.auto_accepted, 3);
+        assert_eq!(histogram.flagged, 5);
+    }
+
     #[test]
     fn test_report_type_serialization() {
         let report_type = ReportType::Summary;
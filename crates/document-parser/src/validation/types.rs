@@ -146,6 +146,23 @@ pub struct EnumerationFailureInfo {
     pub invalid_percentage: f64,
 }
 
+/// Information about values that do not match a field rule pattern
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternFailureInfo {
+    /// Field identifier
+    pub field_id: String,
+    /// Source column name
+    pub source_column: String,
+    /// Regular expression the values must match
+    pub pattern: String,
+    /// Sample of the values that did not match
+    pub invalid_values: Vec<String>,
+    /// Number of non-null values that did not match
+    pub invalid_count: usize,
+    /// Percentage of invalid values
+    pub invalid_percentage: f64,
+}
+
 /// Cross-field validation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CrossFieldValidationResult {
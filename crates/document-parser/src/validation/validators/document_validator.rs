@@ -108,6 +108,7 @@ impl DocumentValidator {
         debug!("Starting document validation");
 
         let mut field_results = Vec::new();
+        let mut pattern_failures = Vec::new();
         let mut overall_passed = true;
         let mut total_validation_time = Duration::new(0, 0);
 
@@ -169,6 +170,61 @@ impl DocumentValidator {
                     });
                 }
             }
+
+            // Apply configured field rule patterns to their mapped columns
+            let mut pattern_fields: Vec<(&String, &String)> = column_validator.field_rules.iter()
+                .filter_map(|(field_id, rule)| rule.pattern.as_ref().map(|pattern| (field_id, pattern)))
+                .collect();
+            pattern_fields.sort();
+
+            for (field_id, pattern) in pattern_fields {
+                let Some(column_data) = document_data.get(field_id.as_str()) else {
+                    continue;
+                };
+                let field_start = Instant::now();
+
+                let (passed, message, sample_invalid_values) = match column_validator
+                    .validate_column_pattern(field_id, field_id, column_data, pattern)
+                {
+                    Ok(None) => (true, format!("All values match pattern '{}'", pattern), Vec::new()),
+                    Ok(Some(failure)) => {
+                        let result = (
+                            false,
+                            format!(
+                                "{} values ({:.1}%) do not match pattern '{}'",
+                                failure.invalid_count, failure.invalid_percentage, pattern
+                            ),
+                            failure.invalid_values.clone(),
+                        );
+                        pattern_failures.push(failure);
+                        result
+                    }
+                    Err(e) => {
+                        warn!("Failed to apply pattern for field '{}': {}", field_id, e);
+                        (false, format!("Pattern validation failed: {}", e), Vec::new())
+                    }
+                };
+
+                if !passed {
+                    overall_passed = false;
+                }
+
+                let validation_time = field_start.elapsed();
+                total_validation_time += validation_time;
+
+                field_results.push(ColumnValidationResult {
+                    field_id: field_id.clone(),
+                    source_column: field_id.clone(),
+                    passed,
+                    status: if passed { ValidationStatus::Valid } else { ValidationStatus::Invalid },
+                    severity: if passed { ValidationSeverity::Info } else { ValidationSeverity::Warning },
+                    message,
+                    expected_type: Some("Pattern".to_string()),
+                    actual_type: Some(super::validation_helpers::ValidationHelpers::detect_data_type(column_data)),
+                    sample_invalid_values,
+                    validation_time_us: validation_time.as_micros() as u64,
+                });
+            }
         }
 
         // Apply custom validation rules
@@ -213,6 +269,7 @@ impl DocumentValidator {
         Ok(DocumentValidationResult {
             passed: overall_passed && meets_threshold,
             field_results,
+            pattern_failures,
             quality_metrics,
             validation_time_ms: total_time.as_millis() as u64,
             meets_quality_threshold: meets_threshold,
@@ -9,13 +9,19 @@ use super::types::*;
 use super::validation_helpers::ValidationHelpers;
 use crate::{Result};
 use crate::mapping::MappingConfiguration;
+use crate::quality::FieldValidationRule;
 use super::super::types::*;
 use super::super::rules::DataType;
+use regex::Regex;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 use std::time::Instant;
 use tracing::debug;
 
+/// Maximum number of non-matching values kept as samples for a pattern failure
+const MAX_PATTERN_SAMPLES: usize = 5;
+
 impl ColumnValidator {
     /// Create a new column validator with mapping configuration
     pub fn new(mapping_config: MappingConfiguration) -> Self {
@@ -24,6 +30,8 @@ impl ColumnValidator {
             min_quality_threshold: 0.8,
             performance_target_ms: 50,
             custom_validators: HashMap::new(),
+            field_rules: HashMap::new(),
+            pattern_cache: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -34,6 +42,8 @@ impl ColumnValidator {
             min_quality_threshold: config.min_quality_threshold,
             performance_target_ms: config.performance_target_ms,
             custom_validators: HashMap::new(),
+            field_rules: HashMap::new(),
+            pattern_cache: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -51,6 +61,21 @@ impl ColumnValidator {
         self.custom_validators.remove(name).is_some()
     }
 
+    /// Set the declarative rule applied to the column mapped to a field
+    pub fn set_field_rule(&mut self, field_id: String, rule: FieldValidationRule) {
+        self.field_rules.insert(field_id, rule);
+    }
+
+    /// Remove the rule for a field
+    pub fn remove_field_rule(&mut self, field_id: &str) -> Option<FieldValidationRule> {
+        self.field_rules.remove(field_id)
+    }
+
+    /// Get the rule for a field
+    pub fn get_field_rule(&self, field_id: &str) -> Option<&FieldValidationRule> {
+        self.field_rules.get(field_id)
+    }
+
     /// Get the minimum quality threshold
     pub fn get_min_quality_threshold(&self) -> f64 {
         self.min_quality_threshold
@@ -156,6 +181,72 @@ impl ColumnValidator {
         })
     }
 
+    /// Check every value in a column against a regular expression
+    ///
+    /// Null values are skipped and numbers are matched against their textual
+    /// form. Returns `None` when all values match.
+    pub fn validate_column_pattern(
+        &self,
+        field_id: &str,
+        source_column: &str,
+        column_data: &[Value],
+        pattern: &str,
+    ) -> Result<Option<PatternFailureInfo>> {
+        let regex = self.compiled_pattern(pattern)?;
+
+        let mut invalid_values = Vec::new();
+        let mut invalid_count = 0;
+        let mut total_non_null = 0;
+
+        for value in column_data {
+            let text = match value {
+                Value::Null => continue,
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            total_non_null += 1;
+
+            if !regex.is_match(&text) {
+                invalid_count += 1;
+                if invalid_values.len() < MAX_PATTERN_SAMPLES {
+                    invalid_values.push(text);
+                }
+            }
+        }
+
+        if invalid_count == 0 {
+            return Ok(None);
+        }
+
+        Ok(Some(PatternFailureInfo {
+            field_id: field_id.to_string(),
+            source_column: source_column.to_string(),
+            pattern: pattern.to_string(),
+            invalid_values,
+            invalid_count,
+            invalid_percentage: invalid_count as f64 / total_non_null as f64 * 100.0,
+        }))
+    }
+
+    /// Compile a pattern, reusing an earlier compilation of the same pattern
+    fn compiled_pattern(&self, pattern: &str) -> Result<Regex> {
+        if let Some(regex) = self.pattern_cache.read().ok().and_then(|cache| cache.get(pattern).cloned()) {
+            return Ok(regex);
+        }
+
+        let regex = Regex::new(pattern)
+            .map_err(|e| crate::Error::validation(format!("Invalid pattern '{}': {}", pattern, e)))?;
+        if let Ok(mut cache) = self.pattern_cache.write() {
+            cache.insert(pattern.to_string(), regex.clone());
+        }
+        Ok(regex)
+    }
+
+    /// Number of distinct patterns compiled so far
+    pub fn compiled_pattern_count(&self) -> usize {
+        self.pattern_cache.read().map(|cache| cache.len()).unwrap_or(0)
+    }
+
     /// Validate string values
     pub fn validate_string_values(&self, values: &[Value]) -> Result<(ValidationStatus, String, Vec<String>)> {
         let mut invalid_values = Vec::new();
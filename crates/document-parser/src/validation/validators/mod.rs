@@ -248,4 +248,56 @@ mod tests {
         assert!(!patterns.ip_pattern.is_empty());
         assert!(!patterns.uuid_pattern.is_empty());
     }
+
+    fn empty_mapping_config() -> MappingConfiguration {
+        MappingConfiguration {
+            inventory_mappings: None,
+            poam_mappings: None,
+            ssp_sections: None,
+            controls: None,
+            documents: None,
+        }
+    }
+
+    #[test]
+    fn test_field_rule_pattern_reports_offending_sample() {
+        let mut validator = ColumnValidator::new(empty_mapping_config());
+        validator.set_field_rule("source_identifier".to_string(), crate::quality::FieldValidationRule {
+            required: true,
+            min_length: None,
+            max_length: None,
+            pattern: Some(r"^VULN-\d{4}$".to_string()),
+            allowed_values: None,
+            custom_validator: None,
+        });
+        let mut document_validator = DocumentValidator::with_column_validator(validator);
+
+        let mut document_data = HashMap::new();
+        document_data.insert("source_identifier".to_string(), vec![
+            Value::String("VULN-0001".to_string()),
+            Value::String("VULN-1234".to_string()),
+            Value::String("CVE-2024-1".to_string()),
+        ]);
+
+        let result = document_validator.validate_document(&document_data, &HashMap::new()).unwrap();
+
+        assert!(!result.passed);
+        assert_eq!(result.pattern_failures.len(), 1);
+        let failure = &result.pattern_failures[0];
+        assert_eq!(failure.field_id, "source_identifier");
+        assert_eq!(failure.invalid_count, 1);
+        assert_eq!(failure.invalid_values, vec!["CVE-2024-1".to_string()]);
+    }
+
+    #[test]
+    fn test_pattern_is_compiled_once() {
+        let validator = ColumnValidator::new(empty_mapping_config());
+        let values = vec![Value::String("AC-2".to_string())];
+
+        for _ in 0..3 {
+            assert!(validator.validate_column_pattern("control_id", "Control", &values, r"^[A-Z]{2}-\d+$").unwrap().is_none());
+        }
+        assert_eq!(validator.compiled_pattern_count(), 1);
+        assert!(validator.validate_column_pattern("control_id", "Control", &values, "(").is_err());
+    }
 }
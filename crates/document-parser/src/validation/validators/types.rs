@@ -6,10 +6,13 @@
 //! for field-level and document-level validation.
 
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
+use regex::Regex;
 use serde_json::Value;
 use crate::{Result};
 use crate::mapping::MappingConfiguration;
+use crate::quality::FieldValidationRule;
 use super::super::types::ValidationStatus;
 
 /// Column validator for validating individual columns
@@ -23,6 +26,10 @@ pub struct ColumnValidator {
     pub(crate) performance_target_ms: u64,
     /// Custom validation functions
     pub(crate) custom_validators: HashMap<String, fn(&[Value]) -> Result<(ValidationStatus, String)>>,
+    /// Declarative rules applied to the column mapped to each field
+    pub(crate) field_rules: HashMap<String, FieldValidationRule>,
+    /// Compiled rule patterns keyed by pattern source, shared between clones
+    pub(crate) pattern_cache: Arc<RwLock<HashMap<String, Regex>>>,
 }
 
 /// Document validator for comprehensive document validation
@@ -102,6 +109,8 @@ pub struct DocumentValidationResult {
     pub passed: bool,
     /// Individual field validation results
     pub field_results: Vec<super::super::types::ColumnValidationResult>,
+    /// Columns with values that do not match their field rule pattern
+    pub pattern_failures: Vec<super::super::types::PatternFailureInfo>,
     /// Quality metrics for the document
     pub quality_metrics: super::super::types::QualityMetrics,
     /// Total validation time in milliseconds
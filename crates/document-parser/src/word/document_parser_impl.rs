@@ -6,7 +6,7 @@
 //! for WordParser with comprehensive parsing and validation capabilities.
 
 use super::parser::WordParser;
-use crate::{DocumentParser, ParseResult, DocumentType};
+use crate::{DocumentParser, ParseResult, DocumentType, ValidationError};
 use async_trait::async_trait;
 use fedramp_core::Result;
 use std::path::Path;
@@ -63,7 +63,10 @@ impl DocumentParser for WordParser {
         let quality_score = self.calculate_quality_score(&docx_document);
 
         // Validate document and get validation errors (before moving data)
-        let validation_errors = self.validate_document(&docx_document);
+        let validation_errors = self.validate_document(&docx_document)
+            .into_iter()
+            .map(ValidationError::document)
+            .collect();
 
         // Build content JSON
         let mut content_map = serde_json::Map::new();
@@ -145,7 +148,10 @@ impl DocumentParser for WordParser {
         let quality_score = self.calculate_quality_score(&docx_document);
 
         // Validate document and get validation errors (before moving data)
-        let validation_errors = self.validate_document(&docx_document);
+        let validation_errors = self.validate_document(&docx_document)
+            .into_iter()
+            .map(ValidationError::document)
+            .collect();
 
         // Build content JSON
         let mut content_map = serde_json::Map::new();
@@ -63,11 +63,7 @@ impl WordParser {
 
         // Check file size
         if file_data.len() > self.max_file_size {
-            return Err(Error::document_parsing(format!(
-                "File size {} exceeds maximum allowed size {}",
-                file_data.len(),
-                self.max_file_size
-            )));
+            return Err(Error::file_too_large(file_data.len() as u64, self.max_file_size as u64));
         }
 
         self.parse_docx_bytes(&file_data, &path.to_string_lossy()).await
@@ -79,7 +75,7 @@ impl WordParser {
 
         // Parse DOCX using docx-rs
         let docx = read_docx(data)
-            .map_err(|e| Error::document_parsing(format!("Failed to parse DOCX: {}", e)))?;
+            .map_err(|e| Error::corrupt_archive(format!("Failed to parse DOCX: {}", e)))?;
 
         // Extract metadata
         let metadata = self.metadata_processor.extract_metadata(&docx)?;
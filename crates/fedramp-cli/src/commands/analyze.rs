@@ -0,0 +1,172 @@
+// Modified: 2025-09-24
+
+//! `fedramp analyze` - compliance gap analysis
+//!
+//! Reads a system's implementation data (a `CurrentImplementation` JSON file)
+//! and compares it against a baseline profile from the control mappings.
+//! With `--since <RFC3339>` only controls whose implementation data changed at
+//! or after that time are analyzed and reported, so large systems can be
+//! re-checked on every commit without a full run.
+
+use crate::config::Config;
+use crate::utils::{read_json, write_output};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use clap::Args;
+use gap_analysis::engine::CurrentImplementation;
+use gap_analysis::{BaselineManager, GapAnalysisEngine, GapAnalysisResult, TargetBaseline};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Arguments for the `analyze` subcommand
+#[derive(Args, Debug)]
+pub struct AnalyzeArgs {
+    /// Implementation data to analyze (`CurrentImplementation` JSON)
+    pub implementation: PathBuf,
+
+    /// Control mappings JSON defining the baseline profiles
+    #[arg(long, value_name = "FILE", default_value = "mappings/control_mappings.json")]
+    pub mappings: PathBuf,
+
+    /// Framework of the target baseline
+    #[arg(long, default_value = "nist-800-53")]
+    pub framework: String,
+
+    /// Baseline profile to analyze against [default: `compliance.default_baseline` from the configuration]
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// Only analyze controls changed at or after this time (RFC 3339)
+    #[arg(long, value_name = "RFC3339", value_parser = parse_rfc3339)]
+    pub since: Option<DateTime<Utc>>,
+
+    /// File to write the `GapAnalysisResult` JSON to instead of standard output
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+pub async fn execute(args: AnalyzeArgs, config: &Config) -> Result<()> {
+    let implementation = load_implementation(&args.implementation).await?;
+    let profile = config.profile_or_default(args.profile.as_deref());
+
+    let mappings = args.mappings.to_string_lossy().to_string();
+    let baseline = BaselineManager::with_json_loader(mappings)
+        .with_context(|| format!("Failed to load control mappings {}", args.mappings.display()))?
+        .get_baseline(&args.framework, profile)
+        .await
+        .with_context(|| format!("No baseline {}:{}", args.framework, profile))?;
+
+    let result = analyze(&implementation, &baseline, args.since).await?;
+    info!(
+        "Found {} gap(s) in {} against {}:{}",
+        result.gaps.len(),
+        args.implementation.display(),
+        args.framework,
+        profile
+    );
+
+    write_output(&serde_json::to_string_pretty(&result)?, args.output.as_deref()).await
+}
+
+/// Full analysis, or only the controls changed since `since` when given
+async fn analyze(
+    implementation: &CurrentImplementation,
+    baseline: &TargetBaseline,
+    since: Option<DateTime<Utc>>,
+) -> Result<GapAnalysisResult> {
+    let engine = GapAnalysisEngine::new();
+    let result = match since {
+        Some(since) => {
+            info!(
+                "Analyzing {} control(s) changed since {}",
+                implementation.controls_changed_since(since).len(),
+                since.to_rfc3339()
+            );
+            engine.analyze_gaps_since(implementation, baseline, since).await?
+        }
+        None => engine.analyze_gaps(implementation, baseline).await?,
+    };
+    Ok(result)
+}
+
+async fn load_implementation(path: &Path) -> Result<CurrentImplementation> {
+    read_json(path, "valid implementation data").await
+}
+
+fn parse_rfc3339(value: &str) -> std::result::Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Utc))
+        .map_err(|e| format!("expected an RFC 3339 timestamp such as 2025-09-01T00:00:00Z: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gap_analysis::engine::{BaselineMetadata, ControlImplementation, ParameterRequirement, RequiredControl};
+    use gap_analysis::ImplementationStatus;
+    use std::collections::HashMap;
+
+    fn fixtures(now: DateTime<Utc>) -> (CurrentImplementation, TargetBaseline) {
+        let touched = [("AC-1", 30), ("AC-2", 1), ("AU-2", 2)];
+        let controls = touched.iter()
+            .map(|(control_id, days_ago)| (control_id.to_string(), ControlImplementation {
+                control_id: control_id.to_string(),
+                status: ImplementationStatus::PartiallyImplemented,
+                implementation_date: None,
+                evidence: Vec::new(),
+                parameters: HashMap::new(),
+                completion_fraction: None,
+                last_modified: Some(now - chrono::Duration::days(*days_ago)),
+            }))
+            .collect();
+        let required_controls = touched.iter()
+            .map(|(control_id, _)| (control_id.to_string(), RequiredControl {
+                control_id: control_id.to_string(),
+                required_status: ImplementationStatus::Implemented,
+                enhancements: Vec::new(),
+                parameters: HashMap::from([("frequency".to_string(), ParameterRequirement {
+                    required_value: "monthly".to_string(),
+                    validation_rule: "non-empty".to_string(),
+                })]),
+            }))
+            .collect();
+
+        let implementation = CurrentImplementation { system_id: "system".to_string(), controls, last_updated: now };
+        let baseline = TargetBaseline {
+            framework_id: "nist-800-53".to_string(),
+            profile_name: "moderate".to_string(),
+            required_controls,
+            baseline_metadata: BaselineMetadata { version: "1.0".to_string(), last_updated: now },
+        };
+        (implementation, baseline)
+    }
+
+    #[tokio::test]
+    async fn test_since_matches_full_run_for_touched_controls() {
+        let now = Utc::now();
+        let (implementation, baseline) = fixtures(now);
+        let since = parse_rfc3339(&(now - chrono::Duration::days(7)).to_rfc3339()).unwrap();
+
+        let incremental = analyze(&implementation, &baseline, Some(since)).await.unwrap();
+        let full = analyze(&implementation, &baseline, None).await.unwrap();
+
+        let descriptions = |result: &GapAnalysisResult| {
+            let mut descriptions: Vec<String> = result.gaps.iter()
+                .filter(|gap| gap.control_id != "AC-1")
+                .map(|gap| gap.description.clone())
+                .collect();
+            descriptions.sort();
+            descriptions
+        };
+        // A partial and a parameter gap per touched control
+        assert_eq!(incremental.gaps.len(), 4);
+        assert_eq!(full.gaps.len(), 6);
+        assert_eq!(descriptions(&incremental), descriptions(&full));
+    }
+
+    #[test]
+    fn test_since_must_be_rfc3339() {
+        assert!(parse_rfc3339("2025-09-01T00:00:00Z").is_ok());
+        assert!(parse_rfc3339("yesterday").is_err());
+    }
+}
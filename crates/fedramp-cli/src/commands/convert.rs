@@ -0,0 +1,327 @@
+// Modified: 2025-09-24
+
+//! `fedramp convert` - framework and POA&M conversion
+//!
+//! With `--crosswalk <xlsx>` reads a control crosswalk workbook (source
+//! control, target control, relationship), validates every row with
+//! `control_mapping::crosswalk` and writes the normalized crosswalk and an
+//! error report next to the workbook, so large crosswalks are checked before
+//! they are loaded as control mappings.
+//!
+//! With `--poam <xlsx|json>` converts a POA&M between a FedRAMP Excel
+//! workbook and an OSCAL POA&M document. The output format defaults to the
+//! one the input is not in; `--format` names it explicitly.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use clap::{Args, ValueEnum};
+use control_mapping::crosswalk::{crosswalk_rows_from_table, validate_crosswalk, CrosswalkValidation};
+use document_parser::excel::poam::PoamFieldMapper;
+use document_parser::excel::{ExcelParser, PoamParser, PoamWorkbookWriter};
+use document_parser::oscal::OscalPoamDocument;
+use document_parser::OscalGenerator;
+use fedramp_core::models::Framework;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Arguments for the `convert` subcommand
+#[derive(Args, Debug)]
+pub struct ConvertArgs {
+    /// Crosswalk workbook to validate and normalize (.xlsx); the first worksheet is read
+    #[arg(long, value_name = "XLSX", conflicts_with = "poam")]
+    pub crosswalk: Option<PathBuf>,
+
+    /// POA&M to convert: a FedRAMP workbook (.xlsx) or an OSCAL POA&M document (.json)
+    #[arg(long, value_name = "FILE")]
+    pub poam: Option<PathBuf>,
+
+    /// Format to convert the POA&M to (default: the format the input is not in)
+    #[arg(long, value_enum)]
+    pub format: Option<PoamFormat>,
+
+    /// Framework of the source controls
+    #[arg(long, value_enum, default_value_t = FrameworkArg::Nist80053)]
+    pub source_framework: FrameworkArg,
+
+    /// Framework of the target controls
+    #[arg(long, value_enum, default_value_t = FrameworkArg::Nist800171)]
+    pub target_framework: FrameworkArg,
+
+    /// Normalized crosswalk JSON (default: `<workbook>.crosswalk.json`), or the
+    /// converted POA&M (default: `<input>.oscal.json` or `<input>.xlsx`)
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Error report JSON (default: `<workbook>.crosswalk-errors.json`)
+    #[arg(long, value_name = "FILE")]
+    pub report: Option<PathBuf>,
+}
+
+/// Control framework named on the command line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum FrameworkArg {
+    #[value(name = "nist-800-53")]
+    Nist80053,
+    #[value(name = "nist-800-171")]
+    Nist800171,
+    Cis,
+    Custom,
+}
+
+/// POA&M file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PoamFormat {
+    /// OSCAL plan-of-action-and-milestones JSON
+    Oscal,
+    /// FedRAMP POA&M Excel workbook
+    Xlsx,
+}
+
+impl PoamFormat {
+    /// Format of a POA&M file, by extension
+    fn of(path: &Path) -> Result<Self> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("xlsx") => Ok(Self::Xlsx),
+            Some("json") => Ok(Self::Oscal),
+            _ => bail!("{} is neither a POA&M workbook (.xlsx) nor an OSCAL POA&M (.json)", path.display()),
+        }
+    }
+
+    /// Default output path for a POA&M converted to this format
+    fn output_path(self, input: &Path) -> PathBuf {
+        match self {
+            Self::Oscal => input.with_extension("oscal.json"),
+            Self::Xlsx => input.with_extension("xlsx"),
+        }
+    }
+}
+
+impl From<FrameworkArg> for Framework {
+    fn from(framework: FrameworkArg) -> Self {
+        match framework {
+            FrameworkArg::Nist80053 => Framework::Nist80053,
+            FrameworkArg::Nist800171 => Framework::Nist800171,
+            FrameworkArg::Cis => Framework::Cis,
+            FrameworkArg::Custom => Framework::Custom,
+        }
+    }
+}
+
+pub async fn execute(args: ConvertArgs, _config: &Config) -> Result<()> {
+    match (&args.crosswalk, &args.poam) {
+        (Some(path), _) => convert_crosswalk(path, &args).await,
+        (None, Some(path)) => convert_poam(path, &args).await,
+        (None, None) => bail!("Nothing to convert; pass --crosswalk <xlsx> or --poam <xlsx|json>"),
+    }
+}
+
+/// Convert a POA&M workbook to OSCAL or an OSCAL POA&M to a workbook
+async fn convert_poam(path: &Path, args: &ConvertArgs) -> Result<()> {
+    let input_format = PoamFormat::of(path)?;
+    let format = args.format.unwrap_or(match input_format {
+        PoamFormat::Oscal => PoamFormat::Xlsx,
+        PoamFormat::Xlsx => PoamFormat::Oscal,
+    });
+    if format == input_format {
+        bail!("{} is already in the {:?} format", path.display(), format);
+    }
+
+    let output = args.output.clone().unwrap_or_else(|| format.output_path(path));
+    if output == path {
+        bail!("Output {} must differ from the input", output.display());
+    }
+
+    let converted = match format {
+        PoamFormat::Oscal => serde_json::to_vec_pretty(&oscal_poam_from_workbook(path).await?)?,
+        PoamFormat::Xlsx => PoamWorkbookWriter::new().write_oscal_poam(&read_oscal_poam(path).await?)?,
+    };
+    tokio::fs::write(&output, converted)
+        .await
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+
+    info!("Converted {} to {}", path.display(), output.display());
+    Ok(())
+}
+
+/// OSCAL POA&M document of the items in a FedRAMP POA&M workbook
+async fn oscal_poam_from_workbook(path: &Path) -> Result<OscalPoamDocument> {
+    let parsed = PoamParser::new()
+        .parse_poam_file(path)
+        .await
+        .with_context(|| format!("Failed to parse POA&M workbook {}", path.display()))?;
+
+    let mapper = PoamFieldMapper::new();
+    let rows: Vec<_> = parsed.items.iter().map(|item| mapper.map_poam_to_oscal_row(item)).collect();
+    OscalGenerator::new()
+        .generate_poam_document(&rows, None)
+        .with_context(|| format!("Failed to generate an OSCAL POA&M from {}", path.display()))
+}
+
+/// Read an OSCAL plan-of-action-and-milestones JSON document
+async fn read_oscal_poam(path: &Path) -> Result<OscalPoamDocument> {
+    let content = tokio::fs::read(path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_slice(&content).with_context(|| format!("{} is not an OSCAL POA&M document", path.display()))
+}
+
+/// Validate a crosswalk workbook, write the normalized crosswalk and error report, and fail on any issue
+async fn convert_crosswalk(path: &Path, args: &ConvertArgs) -> Result<()> {
+    let table = read_first_worksheet(path).await?;
+    let rows = crosswalk_rows_from_table(&table)
+        .with_context(|| format!("Failed to read crosswalk rows from {}", path.display()))?;
+    let validation = validate_crosswalk(&rows, args.source_framework.into(), args.target_framework.into());
+
+    let output = args.output.clone().unwrap_or_else(|| path.with_extension("crosswalk.json"));
+    let report = args.report.clone().unwrap_or_else(|| path.with_extension("crosswalk-errors.json"));
+    write_results(&validation, &output, &report).await?;
+
+    info!(
+        "Wrote {} crosswalk entries to {} and {} issue(s) to {}",
+        validation.entries.len(),
+        output.display(),
+        validation.issues.len(),
+        report.display()
+    );
+
+    if !validation.is_valid() {
+        for issue in &validation.issues {
+            println!("row {}: {:?}: {}", issue.row, issue.kind, issue.message);
+        }
+        bail!("{} has {} crosswalk issue(s)", path.display(), validation.issues.len());
+    }
+
+    Ok(())
+}
+
+/// Cell values of a workbook's first worksheet, header row included
+async fn read_first_worksheet(path: &Path) -> Result<Vec<Vec<Value>>> {
+    let result = ExcelParser::new()
+        .parse_excel_file(path)
+        .await
+        .with_context(|| format!("Failed to parse crosswalk workbook {}", path.display()))?;
+
+    let data = result.content["worksheets"]
+        .get(0)
+        .map(|worksheet| worksheet["data"].clone())
+        .with_context(|| format!("{} has no worksheets", path.display()))?;
+    Ok(serde_json::from_value(data)?)
+}
+
+/// Write the normalized entries and the issues as pretty-printed JSON
+async fn write_results(validation: &CrosswalkValidation, output: &Path, report: &Path) -> Result<()> {
+    tokio::fs::write(output, serde_json::to_string_pretty(&validation.entries)?)
+        .await
+        .with_context(|| format!("Failed to write {}", output.display()))?;
+    tokio::fs::write(report, serde_json::to_string_pretty(&validation.issues)?)
+        .await
+        .with_context(|| format!("Failed to write {}", report.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_contradiction_is_written_to_error_report() {
+        let table = vec![
+            vec![json!("Source Control"), json!("Target Control"), json!("Relationship")],
+            vec![json!("AC-2"), json!("3.1.1"), json!("equal")],
+            vec![json!("ac-02"), json!("3.1.1"), json!("subset of")],
+        ];
+        let rows = crosswalk_rows_from_table(&table).unwrap();
+        let validation = validate_crosswalk(&rows, Framework::Nist80053, Framework::Nist800171);
+
+        let dir = tempfile::tempdir().unwrap();
+        let (output, report) = (dir.path().join("crosswalk.json"), dir.path().join("errors.json"));
+        write_results(&validation, &output, &report).await.unwrap();
+
+        let entries: Value = serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+        assert_eq!(entries.as_array().unwrap().len(), 1);
+        let issues: Value = serde_json::from_str(&std::fs::read_to_string(&report).unwrap()).unwrap();
+        assert_eq!(issues[0]["kind"], "contradiction");
+        assert_eq!(issues[0]["row"], 3);
+    }
+
+    fn poam_args(poam: &Path, output: &Path) -> ConvertArgs {
+        ConvertArgs {
+            crosswalk: None,
+            poam: Some(poam.to_path_buf()),
+            format: None,
+            source_framework: FrameworkArg::Nist80053,
+            target_framework: FrameworkArg::Nist800171,
+            output: Some(output.to_path_buf()),
+            report: None,
+        }
+    }
+
+    /// FedRAMP POA&M workbook with one open and one closed item
+    fn write_source_workbook(path: &Path) {
+        let rows = [
+            [
+                "V-1001", "RA-5", "Unpatched OpenSSL", "Unpatched OpenSSL", "Critical", "Open",
+                "2025-02-01", "2025-03-03", "", "Platform Team", "Upgrade base images", "",
+            ],
+            [
+                "V-1002", "SC-7", "Open ingress rule", "Open ingress rule", "Low", "Completed",
+                "2024-11-20", "2025-01-10", "2025-01-08", "Network Team", "Restrict security group", "",
+            ],
+        ];
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name("POA&M Items").unwrap();
+        for (column, header) in document_parser::excel::poam::FEDRAMP_POAM_COLUMNS.iter().enumerate() {
+            worksheet.write_string(0, column as u16, *header).unwrap();
+        }
+        for (row, values) in rows.iter().enumerate() {
+            for (column, value) in values.iter().enumerate().filter(|(_, value)| !value.is_empty()) {
+                worksheet.write_string(row as u32 + 1, column as u16, *value).unwrap();
+            }
+        }
+        workbook.save(path).unwrap();
+    }
+
+    async fn workbook_items(path: &Path) -> Vec<document_parser::excel::PoamItem> {
+        let mut items = PoamParser::new().parse_poam_file(path).await.unwrap().items;
+        items.sort_by(|a, b| a.unique_id.cmp(&b.unique_id));
+        items
+    }
+
+    #[tokio::test]
+    async fn test_poam_xlsx_oscal_xlsx_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("poam.xlsx");
+        let (oscal, workbook) = (dir.path().join("poam.json"), dir.path().join("round-trip.xlsx"));
+        write_source_workbook(&source);
+
+        execute(poam_args(&source, &oscal), &Config::default()).await.unwrap();
+        execute(poam_args(&oscal, &workbook), &Config::default()).await.unwrap();
+
+        let document: OscalPoamDocument = serde_json::from_slice(&std::fs::read(&oscal).unwrap()).unwrap();
+        assert_eq!(document.plan_of_action_and_milestones.poam_items.len(), 2);
+        let (original, round_tripped) = (workbook_items(&source).await, workbook_items(&workbook).await);
+        assert_eq!(round_tripped.len(), original.len());
+        for (before, after) in original.iter().zip(&round_tripped) {
+            assert_eq!(after.unique_id, before.unique_id);
+            assert_eq!(after.control_id, before.control_id);
+            assert_eq!(after.severity, before.severity);
+            assert_eq!(after.status, before.status);
+            assert_eq!(after.scheduled_completion_date, before.scheduled_completion_date);
+            assert_eq!(after.actual_completion_date, before.actual_completion_date);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poam_conversion_rejects_same_format_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("poam.xlsx");
+        write_source_workbook(&source);
+
+        let mut args = poam_args(&source, &dir.path().join("copy.xlsx"));
+        args.format = Some(PoamFormat::Xlsx);
+        assert!(execute(args, &Config::default()).await.is_err());
+    }
+}
@@ -0,0 +1,188 @@
+// Modified: 2025-09-24
+
+//! `fedramp diff` - baseline and gap analysis comparison
+//!
+//! Compares two gap analysis results (`GapAnalysisResult` JSON, as written by
+//! `fedramp analyze`) or two control mappings files, whose baseline profile
+//! selected by `--framework` and `--profile` is compared. With
+//! `--max-differences` the command fails when the files differ in more
+//! controls than allowed, so baseline drift can gate CI pipelines.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use clap::{Args, ValueEnum};
+use gap_analysis::{BaselineDelta, BaselineManager, GapAnalysisResult, GapDelta, TargetBaseline};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Arguments for the `diff` subcommand
+#[derive(Args, Debug)]
+pub struct DiffArgs {
+    /// Earlier gap analysis result or control mappings file
+    pub before: PathBuf,
+
+    /// Later gap analysis result or control mappings file
+    pub after: PathBuf,
+
+    /// Framework of the baselines to compare when diffing control mappings
+    #[arg(long, default_value = "nist-800-53")]
+    pub framework: String,
+
+    /// Baseline profile to compare when diffing control mappings [default: `compliance.default_baseline` from the configuration]
+    #[arg(long)]
+    pub profile: Option<String>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = DiffFormat::Text)]
+    pub format: DiffFormat,
+
+    /// Fail when more than this many controls differ
+    #[arg(long, value_name = "COUNT")]
+    pub max_differences: Option<usize>,
+}
+
+/// Output format for the comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DiffFormat {
+    /// Summary listing each difference
+    Text,
+    /// Full `GapDelta` or `BaselineDelta` as JSON
+    Json,
+}
+
+/// Contents of a file to compare
+enum DiffInput {
+    Analysis(Box<GapAnalysisResult>),
+    Baseline(TargetBaseline),
+}
+
+pub async fn execute(args: DiffArgs, config: &Config) -> Result<()> {
+    let profile = config.profile_or_default(args.profile.as_deref());
+    let before = load_input(&args.before, &args.framework, profile).await?;
+    let after = load_input(&args.after, &args.framework, profile).await?;
+
+    let differences = match (&before, &after) {
+        (DiffInput::Analysis(before), DiffInput::Analysis(after)) => {
+            let delta = GapDelta::between(before, after)?;
+            match args.format {
+                DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&delta)?),
+                DiffFormat::Text => print_gap_delta(&delta),
+            }
+            delta.difference_count()
+        }
+        (DiffInput::Baseline(before), DiffInput::Baseline(after)) => {
+            let delta = BaselineDelta::between(before, after)?;
+            match args.format {
+                DiffFormat::Json => println!("{}", serde_json::to_string_pretty(&delta)?),
+                DiffFormat::Text => print_baseline_delta(&delta),
+            }
+            delta.difference_count()
+        }
+        _ => bail!(
+            "Cannot compare a gap analysis result with a control mappings file ({} and {})",
+            args.before.display(),
+            args.after.display()
+        ),
+    };
+    info!("Found {} difference(s) between {} and {}", differences, args.before.display(), args.after.display());
+
+    check_threshold(differences, args.max_differences)
+}
+
+/// Fail when the number of differences exceeds the allowed maximum
+fn check_threshold(differences: usize, max_differences: Option<usize>) -> Result<()> {
+    match max_differences {
+        Some(max) if differences > max => bail!("{} difference(s) exceed the allowed maximum of {}", differences, max),
+        _ => Ok(()),
+    }
+}
+
+/// Read a gap analysis result, falling back to the baseline of a control mappings file
+async fn load_input(path: &Path, framework: &str, profile: &str) -> Result<DiffInput> {
+    let raw = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    if let Ok(result) = serde_json::from_str::<GapAnalysisResult>(&raw) {
+        return Ok(DiffInput::Analysis(Box::new(result)));
+    }
+
+    let baseline = BaselineManager::with_json_loader(path.to_string_lossy().to_string())
+        .with_context(|| format!("{} is neither a gap analysis result nor control mappings", path.display()))?
+        .get_baseline(framework, profile)
+        .await
+        .with_context(|| format!("No baseline {}:{} in {}", framework, profile, path.display()))?;
+    Ok(DiffInput::Baseline(baseline))
+}
+
+fn print_gap_delta(delta: &GapDelta) {
+    println!("Gap analysis comparison ({})", delta.framework_id);
+    println!();
+    println!("  Opened:    {}", delta.opened.len());
+    println!("  Resolved:  {}", delta.resolved.len());
+    println!("  Changed:   {}", delta.changed.len());
+    println!("  Unchanged: {}", delta.unchanged);
+
+    if delta.difference_count() > 0 {
+        println!();
+    }
+    for gap in &delta.opened {
+        println!("  + {} [{:?}] {}", gap.control_id, gap.severity, gap.description);
+    }
+    for gap in &delta.resolved {
+        println!("  - {} [{:?}] {}", gap.control_id, gap.severity, gap.description);
+    }
+    for change in &delta.changed {
+        println!(
+            "  ~ {} severity {:?} -> {:?}, status {:?} -> {:?}",
+            change.control_id, change.before_severity, change.after_severity, change.before_status, change.after_status
+        );
+    }
+}
+
+fn print_baseline_delta(delta: &BaselineDelta) {
+    println!(
+        "Baseline comparison ({}: {} -> {})",
+        delta.framework_id, delta.before_profile, delta.after_profile
+    );
+    println!();
+    println!("  Added:     {}", delta.added.len());
+    println!("  Removed:   {}", delta.removed.len());
+    println!("  Changed:   {}", delta.changed.len());
+    println!("  Unchanged: {}", delta.unchanged);
+
+    if delta.difference_count() > 0 {
+        println!();
+    }
+    for control_id in &delta.added {
+        println!("  + {}", control_id);
+    }
+    for control_id in &delta.removed {
+        println!("  - {}", control_id);
+    }
+    for change in &delta.changed {
+        let mut details = Vec::new();
+        if change.before_status != change.after_status {
+            details.push(format!("status {:?} -> {:?}", change.before_status, change.after_status));
+        }
+        if !change.added_enhancements.is_empty() {
+            details.push(format!("enhancements added: {}", change.added_enhancements.join(", ")));
+        }
+        if !change.removed_enhancements.is_empty() {
+            details.push(format!("enhancements removed: {}", change.removed_enhancements.join(", ")));
+        }
+        println!("  ~ {} {}", change.control_id, details.join("; "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threshold_fails_only_when_exceeded() {
+        assert!(check_threshold(3, None).is_ok());
+        assert!(check_threshold(3, Some(3)).is_ok());
+        assert!(check_threshold(4, Some(3)).is_err());
+        assert!(check_threshold(1, Some(0)).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+// Modified: 2025-09-20
+
+//! `fedramp init` - project bootstrap
+//!
+//! Creates the `mappings/` and `schema/` directories the mapping loader reads.
+//! With `--mapping <name>` it instead scaffolds a starter POA&M mapping
+//! configuration for a custom template under `<dir>/<name>`, ready to be
+//! filled in and loaded with `MappingConfigurationLoader`.
+
+use crate::config::Config;
+use anyhow::{Context, Result};
+use clap::Args;
+use document_parser::mapping::MappingConfigurationLoader;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Arguments for the `init` subcommand
+#[derive(Args, Debug)]
+pub struct InitArgs {
+    /// Directory to initialize
+    #[arg(long, value_name = "DIR", default_value = ".")]
+    pub dir: PathBuf,
+
+    /// Scaffold a mapping configuration for a custom template with this name
+    #[arg(long, value_name = "NAME")]
+    pub mapping: Option<String>,
+}
+
+pub async fn execute(args: InitArgs, _config: &Config) -> Result<()> {
+    match &args.mapping {
+        Some(name) => {
+            let path = scaffold_mapping(&args.dir, name).await?;
+            println!("Created {}; replace the example column names with your template's headers", path.display());
+        }
+        None => init_project(&args.dir).await?,
+    }
+    Ok(())
+}
+
+/// Create the directories the mapping loader reads
+async fn init_project(dir: &Path) -> Result<()> {
+    for subdir in ["mappings", "schema"] {
+        let path = dir.join(subdir);
+        tokio::fs::create_dir_all(&path)
+            .await
+            .with_context(|| format!("Failed to create {}", path.display()))?;
+    }
+    info!("Initialized project in {}", dir.display());
+    Ok(())
+}
+
+/// Write a POA&M mapping skeleton under `<dir>/<name>` and check it loads
+async fn scaffold_mapping(dir: &Path, name: &str) -> Result<PathBuf> {
+    let loader = MappingConfigurationLoader::new(dir.join(name));
+    loader
+        .scaffold_poam_mapping(name)
+        .await
+        .with_context(|| format!("Failed to scaffold mapping configuration '{}'", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_scaffolded_mapping_round_trips_through_loader() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let path = scaffold_mapping(dir.path(), "legacy-poam").await.unwrap();
+
+        assert_eq!(path, dir.path().join("legacy-poam").join("mappings").join("poam_mappings.json"));
+        let mut loader = MappingConfigurationLoader::new(dir.path().join("legacy-poam"));
+        let config = loader.load_all_configurations().await.unwrap();
+        assert!(config.poam_mappings.is_some());
+    }
+}
@@ -0,0 +1,15 @@
+// Modified: 2025-09-24
+
+//! CLI subcommands
+//!
+//! Each module holds one subcommand's clap arguments and its `execute` entry
+//! point, dispatched from `main`.
+
+pub mod analyze;
+pub mod convert;
+pub mod diff;
+pub mod init;
+pub mod parse;
+pub mod quality;
+pub mod report;
+pub mod validate;
@@ -0,0 +1,285 @@
+// Modified: 2025-09-24
+
+//! `fedramp parse` - document parsing
+//!
+//! Parses a document, or every supported document in a directory, and prints
+//! each document's quality score and validation error count. With `--output`
+//! the `ParseResult` JSON is written to a file, or for a directory input to
+//! `<output>/<file name>.json` (e.g. `poam.xlsx.json`), which keeps the
+//! results of `poam.xlsx` and `poam.csv` apart.
+//!
+//! With `--watch` the input stays watched after the first pass and changed
+//! documents are parsed again, so analysts can keep a POA&M spreadsheet open
+//! and see the effect of each save. Writes arriving in quick succession are
+//! parsed once, and a failed parse is reported without ending the watch.
+
+use crate::config::Config;
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use document_parser::{DocumentParserFactory, DocumentType, ParseResult};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, error, info};
+
+/// Quiet period after the last write before a changed document is parsed
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Arguments for the `parse` subcommand
+#[derive(Args, Debug)]
+pub struct ParseArgs {
+    /// Document, or directory of documents, to parse
+    #[arg(short, long, value_name = "PATH")]
+    pub input: PathBuf,
+
+    /// File, or directory for a directory input, to write the `ParseResult` JSON to; must not be the input
+    #[arg(short, long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+
+    /// Keep watching the input and parse documents again when they change
+    #[arg(short, long)]
+    pub watch: bool,
+}
+
+pub async fn execute(args: ParseArgs, _config: &Config) -> Result<()> {
+    let parser = DocumentParserFactory::new();
+
+    if let Some(output) = &args.output {
+        if resolved(output) == resolved(&args.input) {
+            bail!("Output {} must differ from the input", output.display());
+        }
+    }
+
+    let documents = documents_in(&args.input)?;
+    if documents.is_empty() && !args.watch {
+        bail!("No supported documents in {}", args.input.display());
+    }
+    for path in &documents {
+        if args.watch {
+            // Report failures but start watching anyway, so they can be fixed in place
+            if let Err(e) = parse_and_report(&parser, path, &args).await {
+                report_failure(path, &e);
+            }
+        } else {
+            parse_and_report(&parser, path, &args).await?;
+        }
+    }
+
+    if args.watch {
+        watch(&parser, &args).await?;
+    }
+    Ok(())
+}
+
+/// The input document, or the supported documents directly inside an input directory
+fn documents_in(input: &Path) -> Result<Vec<PathBuf>> {
+    if !input.is_dir() {
+        return Ok(vec![input.to_path_buf()]);
+    }
+
+    let mut documents: Vec<PathBuf> = std::fs::read_dir(input)
+        .with_context(|| format!("Failed to read directory {}", input.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && DocumentType::from_extension(path).is_some())
+        .collect();
+    documents.sort();
+    Ok(documents)
+}
+
+/// Parse a document, print its quality summary and write the result if requested
+async fn parse_and_report(parser: &DocumentParserFactory, path: &Path, args: &ParseArgs) -> Result<()> {
+    let result = parser
+        .parse_document(path)
+        .await
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+
+    println!("{}", summary_line(path, &result));
+    for error in &result.validation_errors {
+        debug!("{}: {}", path.display(), error);
+    }
+
+    if let Some(output) = &args.output {
+        let destination = output_path(&args.input, output, path);
+        if let Some(parent) = destination.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        tokio::fs::write(&destination, serde_json::to_string_pretty(&result)?)
+            .await
+            .with_context(|| format!("Failed to write {}", destination.display()))?;
+        info!("Wrote parse result of {} to {}", path.display(), destination.display());
+    }
+    Ok(())
+}
+
+fn summary_line(path: &Path, result: &ParseResult) -> String {
+    format!(
+        "{}: quality score {:.2}, {} validation error(s)",
+        path.display(),
+        result.quality_score,
+        result.validation_errors.len()
+    )
+}
+
+fn report_failure(path: &Path, e: &anyhow::Error) {
+    error!("{:#}", e);
+    println!("{}: parse failed", path.display());
+}
+
+/// Where to write the result of `document`: `output` itself for a document
+/// input, `<output>/<file name>.json` for a directory input
+fn output_path(input: &Path, output: &Path, document: &Path) -> PathBuf {
+    if !input.is_dir() {
+        return output.to_path_buf();
+    }
+    let mut name = document.file_name().unwrap_or(document.as_os_str()).to_os_string();
+    name.push(".json");
+    output.join(name)
+}
+
+/// Absolute form of a path that may not exist yet, with symlinks resolved as
+/// far as it exists, for comparison with the paths the watcher reports
+fn resolved(path: &Path) -> PathBuf {
+    if let Ok(path) = path.canonicalize() {
+        return path;
+    }
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => resolved(if parent.as_os_str().is_empty() { Path::new(".") } else { parent }).join(name),
+        _ => std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()),
+    }
+}
+
+/// Whether a change the watcher reports should be parsed
+///
+/// Results written under `output` are `.json` documents themselves, and
+/// parsing them again would rewrite them and trigger another change.
+fn is_watched_change(path: &Path, only_file: Option<&Path>, output: Option<&Path>) -> bool {
+    if output.is_some_and(|output| path.starts_with(output)) {
+        return false;
+    }
+    match only_file {
+        Some(file) => path == file,
+        None => DocumentType::from_extension(path).is_some(),
+    }
+}
+
+/// Parse documents again as they change, until interrupted
+async fn watch(parser: &DocumentParserFactory, args: &ParseArgs) -> Result<()> {
+    let input = args
+        .input
+        .canonicalize()
+        .with_context(|| format!("Failed to resolve {}", args.input.display()))?;
+    // Watch the directory of a single document too: editors often save by
+    // replacing the file, which would end a watch on the file itself
+    let (watched_dir, only_file) = if input.is_dir() {
+        (input.clone(), None)
+    } else {
+        let parent = input.parent().map(Path::to_path_buf).unwrap_or_else(|| PathBuf::from("."));
+        (parent, Some(input.clone()))
+    };
+
+    let output = args.output.as_deref().map(resolved);
+
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+        Ok(event) => {
+            if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                return;
+            }
+            for path in event.paths {
+                if is_watched_change(&path, only_file.as_deref(), output.as_deref()) && tx.send(path).is_err() {
+                    debug!("Watch ended before a change could be queued");
+                }
+            }
+        }
+        Err(e) => error!("File watcher error: {}", e),
+    })
+    .context("Failed to create file watcher")?;
+    watcher
+        .watch(&watched_dir, RecursiveMode::NonRecursive)
+        .with_context(|| format!("Failed to watch {}", watched_dir.display()))?;
+
+    println!("Watching {} for changes (Ctrl-C to stop)", args.input.display());
+    loop {
+        let changed = tokio::select! {
+            changed = next_changes(&mut rx, WATCH_DEBOUNCE) => changed,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+        let Some(changed) = changed else { break };
+
+        for path in changed.into_iter().filter(|path| path.is_file()) {
+            if let Err(e) = parse_and_report(parser, &path, args).await {
+                report_failure(&path, &e);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Wait for a change, then collect further changes until none arrives for `quiet`
+///
+/// Returns each changed path once, or `None` when the watcher is gone.
+async fn next_changes(rx: &mut mpsc::UnboundedReceiver<PathBuf>, quiet: Duration) -> Option<BTreeSet<PathBuf>> {
+    let mut changed = BTreeSet::from([rx.recv().await?]);
+    while let Ok(Some(path)) = tokio::time::timeout(quiet, rx.recv()).await {
+        changed.insert(path);
+    }
+    Some(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rapid_changes_are_debounced_into_one_batch() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let poam = PathBuf::from("poam.xlsx");
+        for _ in 0..3 {
+            tx.send(poam.clone()).unwrap();
+        }
+        tx.send(PathBuf::from("inventory.csv")).unwrap();
+
+        let changed = next_changes(&mut rx, Duration::from_millis(20)).await.unwrap();
+        assert_eq!(changed, BTreeSet::from([PathBuf::from("inventory.csv"), poam]));
+
+        drop(tx);
+        assert!(next_changes(&mut rx, Duration::from_millis(20)).await.is_none());
+    }
+
+    #[test]
+    fn test_directory_inputs_write_one_result_per_document() {
+        let dir = tempfile::tempdir().unwrap();
+        let (xlsx, csv) = (dir.path().join("poam.xlsx"), dir.path().join("poam.csv"));
+        assert_eq!(output_path(dir.path(), Path::new("out"), &xlsx), PathBuf::from("out/poam.xlsx.json"));
+        assert_eq!(output_path(dir.path(), Path::new("out"), &csv), PathBuf::from("out/poam.csv.json"));
+        assert_eq!(output_path(&xlsx, Path::new("result.json"), &xlsx), PathBuf::from("result.json"));
+    }
+
+    #[test]
+    fn test_written_results_are_not_parsed_again() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = resolved(&dir.path().join("results"));
+        let result = output.join("poam.xlsx.json");
+        assert!(!is_watched_change(&result, None, Some(&output)));
+        assert!(is_watched_change(&resolved(dir.path()).join("inventory.json"), None, Some(&output)));
+
+        let document = resolved(dir.path()).join("poam.xlsx");
+        assert!(is_watched_change(&document, Some(&document), Some(&output)));
+        assert!(!is_watched_change(&output, Some(&document), Some(&output)));
+    }
+
+    #[tokio::test]
+    async fn test_output_must_differ_from_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let args = ParseArgs {
+            input: dir.path().to_path_buf(),
+            output: Some(dir.path().join(".")),
+            watch: false,
+        };
+        assert!(execute(args, &Config::default()).await.is_err());
+    }
+}
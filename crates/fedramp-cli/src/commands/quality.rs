@@ -0,0 +1,197 @@
+// Modified: 2025-09-20
+
+//! `fedramp quality` - POA&M quality gate
+//!
+//! Parses a POA&M document, runs the `PoamQualityChecker` against it and fails
+//! when the assessment does not pass the configured quality gates, so teams can
+//! gate submissions locally before upload.
+
+use crate::config::Config;
+use crate::utils::read_json;
+use anyhow::{bail, Result};
+use clap::{Args, ValueEnum};
+use document_parser::excel::PoamParser;
+use document_parser::poam::PoamItem;
+use document_parser::quality::{
+    FileQualityHistoryStore, QualityAssessment, QualityConfig, QualityFinding, QualityHistoryStore,
+};
+use document_parser::PoamQualityChecker;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Arguments for the `quality` subcommand
+#[derive(Args, Debug)]
+pub struct QualityArgs {
+    /// POA&M document to assess (.xlsx, .xls, .xlsm or a JSON array of POA&M items)
+    pub file: PathBuf,
+
+    /// JSON file with a `QualityConfig` overriding the default thresholds and weights
+    #[arg(long, value_name = "FILE")]
+    pub quality_config: Option<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = QualityFormat::Text)]
+    pub format: QualityFormat,
+
+    /// Number of findings to list in text output
+    #[arg(long, default_value_t = 10)]
+    pub top: usize,
+
+    /// Quality history directory to append the assessment to, keyed by the document's file name
+    #[arg(long, value_name = "DIR")]
+    pub history: Option<PathBuf>,
+}
+
+/// Output format for the quality assessment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum QualityFormat {
+    /// Dimension scores and top findings
+    Text,
+    /// Full `QualityAssessment` as JSON
+    Json,
+}
+
+pub async fn execute(args: QualityArgs, _config: &Config) -> Result<()> {
+    check(&args).await
+}
+
+/// Print the assessment and fail if it does not pass the quality gates
+async fn check(args: &QualityArgs) -> Result<()> {
+    let (assessment, quality_config) = assess(args).await?;
+
+    if let Some(history) = &args.history {
+        let document = args
+            .file
+            .file_name()
+            .map_or_else(|| args.file.display().to_string(), |name| name.to_string_lossy().into_owned());
+        FileQualityHistoryStore::new(history).append(&document, &assessment).await?;
+        info!("Recorded quality assessment of {} in {}", document, history.display());
+    }
+
+    match args.format {
+        QualityFormat::Json => println!("{}", serde_json::to_string_pretty(&assessment)?),
+        QualityFormat::Text => print_summary(&assessment, &quality_config, args.top),
+    }
+
+    if !assessment.passes_quality_gates(&quality_config) {
+        bail!("POA&M quality gates failed for {}", args.file.display());
+    }
+
+    Ok(())
+}
+
+/// Load the quality configuration and POA&M items, then run the assessment
+async fn assess(args: &QualityArgs) -> Result<(QualityAssessment, QualityConfig)> {
+    let quality_config = match &args.quality_config {
+        Some(path) => read_json(path, "a quality config").await?,
+        None => QualityConfig::default(),
+    };
+
+    let items = load_poam_items(&args.file).await?;
+    info!("Assessing quality of {} POA&M items from {}", items.len(), args.file.display());
+
+    let assessment = PoamQualityChecker::with_config(quality_config.clone())?.assess_quality(&items)?;
+    Ok((assessment, quality_config))
+}
+
+/// Read POA&M items from an Excel workbook or a JSON export
+async fn load_poam_items(path: &Path) -> Result<Vec<PoamItem>> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "xlsx" | "xls" | "xlsm" => {
+            let result = PoamParser::new().parse_poam_file(path).await?;
+            Ok(result.items.iter().map(PoamItem::from).collect())
+        }
+        "json" => read_json(path, "a JSON array of POA&M items").await,
+        other => bail!("Unsupported POA&M file type: .{}", other),
+    }
+}
+
+fn print_summary(assessment: &QualityAssessment, config: &QualityConfig, top: usize) {
+    println!("POA&M quality assessment ({} items)", assessment.quality_metrics.total_items);
+    println!();
+
+    // Show the thresholds the gates use for the assessed document type
+    let config = &match assessment.document_type() {
+        Some(document_type) => config.for_document_type(document_type),
+        None => config.clone(),
+    };
+
+    let dimensions = [
+        ("Overall", assessment.overall_score, config.min_overall_score),
+        ("Completeness", assessment.completeness_score, config.min_completeness_score),
+        ("Accuracy", assessment.accuracy_score, config.min_accuracy_score),
+        ("Consistency", assessment.consistency_score, config.min_consistency_score),
+        ("Compliance", assessment.compliance_score, config.min_compliance_score),
+    ];
+    for (name, score, minimum) in dimensions {
+        let verdict = if score >= minimum { "pass" } else { "FAIL" };
+        println!("  {:<13} {:>5.1}%  (min {:>5.1}%)  {}", name, score * 100.0, minimum * 100.0, verdict);
+    }
+
+    let mut findings: Vec<&QualityFinding> = assessment.findings.iter().collect();
+    findings.sort_by_key(|finding| std::cmp::Reverse(finding.severity.weight()));
+
+    if !findings.is_empty() {
+        println!();
+        println!("Top findings ({} of {}):", findings.len().min(top), findings.len());
+        for finding in findings.into_iter().take(top) {
+            println!("  [{:?}] {:?}: {}", finding.severity, finding.category, finding.description);
+        }
+    }
+
+    println!();
+    if assessment.passes_quality_gates(config) {
+        println!("Quality gates: PASSED");
+    } else {
+        println!("Quality gates: FAILED");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn args_for(file: PathBuf) -> QualityArgs {
+        QualityArgs {
+            file,
+            quality_config: None,
+            format: QualityFormat::Text,
+            top: 10,
+            history: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_known_bad_poam_fails_quality_gates() {
+        let mut fixture = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        write!(
+            fixture,
+            r#"[
+                {{"uuid": "not-a-uuid", "title": "", "description": "tbd", "status": "Unknown",
+                  "severity": null, "scheduled_completion_date": "someday", "actual_completion_date": null,
+                  "responsible_entity": null, "resources_required": null, "risk_assessment": null,
+                  "milestones": null}}
+            ]"#
+        )
+        .unwrap();
+
+        let (assessment, config) = assess(&args_for(fixture.path().to_path_buf())).await.unwrap();
+        assert!(!assessment.passes_quality_gates(&config));
+
+        let result = check(&args_for(fixture.path().to_path_buf())).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_extension_is_rejected() {
+        let fixture = tempfile::Builder::new().suffix(".txt").tempfile().unwrap();
+        assert!(load_poam_items(fixture.path()).await.is_err());
+    }
+}
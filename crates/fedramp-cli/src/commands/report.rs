@@ -0,0 +1,193 @@
+// Modified: 2025-09-20
+
+//! `fedramp report` - report generation
+//!
+//! `fedramp report trend` reads a directory of archived `QualityAssessment`
+//! JSON files (as written by `fedramp quality --format json`) and renders how
+//! quality and compliance scores moved across them. A document's directory in
+//! a quality history (as written by `fedramp quality --history`) is such a
+//! directory.
+
+use crate::config::Config;
+use crate::utils::read_json;
+use anyhow::{bail, Context, Result};
+use clap::{Args, Subcommand, ValueEnum};
+use document_parser::quality::QualityAssessment;
+use document_parser::validation::poam_reports::{
+    calculate_quality_trend_with_config, PoamQualityTrendReport, QualityTrendConfig, DEFAULT_REGRESSION_DELTA,
+};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Arguments for the `report` subcommand
+#[derive(Args, Debug)]
+pub struct ReportArgs {
+    #[command(subcommand)]
+    pub command: ReportCommand,
+}
+
+/// Reports that can be generated
+#[derive(Subcommand, Debug)]
+pub enum ReportCommand {
+    /// Quality and compliance trend across archived quality assessments
+    Trend(TrendArgs),
+}
+
+/// Arguments for `report trend`
+#[derive(Args, Debug)]
+pub struct TrendArgs {
+    /// Directory of `QualityAssessment` JSON files; other files are ignored
+    #[arg(long, value_name = "DIR")]
+    pub inputs: PathBuf,
+
+    /// Number of most recent assessments to include; all by default
+    #[arg(long, value_name = "N")]
+    pub window: Option<usize>,
+
+    /// Score drop between consecutive assessments reported as a regression
+    #[arg(long, value_name = "DELTA", default_value_t = DEFAULT_REGRESSION_DELTA)]
+    pub regression_delta: f64,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = TrendFormat::Html)]
+    pub format: TrendFormat,
+
+    /// File to write the report to instead of standard output
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+/// Output format for the trend report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum TrendFormat {
+    /// Standalone HTML page
+    Html,
+    /// `PoamQualityTrendReport` as JSON
+    Json,
+}
+
+pub async fn execute(args: ReportArgs, _config: &Config) -> Result<()> {
+    match args.command {
+        ReportCommand::Trend(args) => trend(&args).await,
+    }
+}
+
+/// Render the trend report to the output file or standard output
+async fn trend(args: &TrendArgs) -> Result<()> {
+    let config = QualityTrendConfig {
+        window: args.window,
+        regression_delta: args.regression_delta,
+    };
+    let report = build_trend(&args.inputs, &config).await?;
+
+    let rendered = match args.format {
+        TrendFormat::Html => report.to_html(),
+        TrendFormat::Json => serde_json::to_string_pretty(&report)?,
+    };
+
+    match &args.output {
+        Some(path) => {
+            tokio::fs::write(path, rendered)
+                .await
+                .with_context(|| format!("Failed to write trend report {}", path.display()))?;
+            info!("Wrote quality trend over {} assessments to {}", report.points.len(), path.display());
+        }
+        None => println!("{}", rendered),
+    }
+
+    Ok(())
+}
+
+/// Load every assessment in the directory and compute the trend
+async fn build_trend(inputs: &Path, config: &QualityTrendConfig) -> Result<PoamQualityTrendReport> {
+    let assessments = load_assessments(inputs).await?;
+    if assessments.is_empty() {
+        bail!("No quality assessment JSON files found in {}", inputs.display());
+    }
+    info!("Computing quality trend over {} assessments from {}", assessments.len(), inputs.display());
+
+    Ok(calculate_quality_trend_with_config(&assessments, config)?)
+}
+
+/// Read the `*.json` files in a directory as quality assessments
+async fn load_assessments(inputs: &Path) -> Result<Vec<QualityAssessment>> {
+    let mut entries = tokio::fs::read_dir(inputs)
+        .await
+        .with_context(|| format!("Failed to read input directory {}", inputs.display()))?;
+
+    let mut assessments = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        let is_json = path.extension().and_then(|e| e.to_str()).is_some_and(|e| e.eq_ignore_ascii_case("json"));
+        if !is_json || !entry.file_type().await?.is_file() {
+            continue;
+        }
+
+        let assessment: QualityAssessment = read_json(&path, "a quality assessment").await?;
+        assessments.push(assessment);
+    }
+
+    Ok(assessments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_assessment(dir: &Path, file_name: &str, timestamp: &str, overall: f64) {
+        let assessment = serde_json::json!({
+            "assessment_id": file_name,
+            "timestamp": timestamp,
+            "overall_score": overall,
+            "completeness_score": 0.9,
+            "accuracy_score": 0.8,
+            "consistency_score": 0.85,
+            "compliance_score": 0.7,
+            "quality_metrics": {
+                "total_items": 10, "complete_items": 8, "incomplete_items": 2, "error_count": 0,
+                "warning_count": 0, "missing_required_fields": 0, "data_quality_issues": 0,
+                "field_completeness": {}, "category_metrics": {}
+            },
+            "findings": [],
+            "recommendations": [],
+            "config_summary": {}
+        });
+        std::fs::write(dir.join(file_name), assessment.to_string()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_trend_orders_archived_assessments_by_timestamp() {
+        let dir = tempfile::tempdir().unwrap();
+        // File names deliberately sort opposite to the embedded timestamps
+        write_assessment(dir.path(), "a-latest.json", "2025-06-01T00:00:00Z", 0.9);
+        write_assessment(dir.path(), "b-earliest.json", "2025-01-01T00:00:00Z", 0.6);
+        std::fs::write(dir.path().join("notes.txt"), "not an assessment").unwrap();
+
+        let report = build_trend(dir.path(), &QualityTrendConfig::default()).await.unwrap();
+
+        let ids: Vec<&str> = report.points.iter().map(|point| point.assessment_id.as_str()).collect();
+        assert_eq!(ids, vec!["b-earliest.json", "a-latest.json"]);
+        assert!(report.to_html().contains("&#9650; +30.0 pts"));
+    }
+
+    #[tokio::test]
+    async fn test_single_assessment_produces_flat_trend() {
+        let dir = tempfile::tempdir().unwrap();
+        write_assessment(dir.path(), "only.json", "2025-03-01T00:00:00Z", 0.8);
+
+        let report = build_trend(dir.path(), &QualityTrendConfig::default()).await.unwrap();
+
+        assert_eq!(report.points.len(), 1);
+        assert!(report.dimensions.iter().all(|trend| trend.change == 0.0));
+        assert!(!report.to_html().contains("&#9660;"));
+    }
+
+    #[tokio::test]
+    async fn test_empty_or_invalid_inputs_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(build_trend(dir.path(), &QualityTrendConfig::default()).await.is_err());
+
+        std::fs::write(dir.path().join("broken.json"), "{}").unwrap();
+        assert!(build_trend(dir.path(), &QualityTrendConfig::default()).await.is_err());
+    }
+}
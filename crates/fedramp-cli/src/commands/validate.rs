@@ -0,0 +1,129 @@
+// Modified: 2025-09-20
+
+//! `fedramp validate` - document and configuration validation
+//!
+//! With `--mapping <file>` checks a mapping configuration against its
+//! published JSON Schema and lists every violation by JSON Pointer, so a
+//! broken custom mapping is caught before the loader rejects it.
+//!
+//! `--format sarif` writes the violations as a SARIF 2.1.0 log, with rule IDs,
+//! levels and file/line locations, for CI gates that annotate pull requests;
+//! `--format json` writes the violations as a JSON array.
+
+use crate::config::Config;
+use crate::utils::write_output;
+use anyhow::{bail, Context, Result};
+use clap::{Args, ValueEnum};
+use document_parser::mapping::MappingConfigurationLoader;
+use document_parser::validation::SarifReport;
+use document_parser::ValidationError;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+/// Arguments for the `validate` subcommand
+#[derive(Args, Debug)]
+pub struct ValidateArgs {
+    /// Mapping configuration to check against its schema (JSON or YAML)
+    #[arg(long, value_name = "FILE")]
+    pub mapping: Option<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = ValidateFormat::Text)]
+    pub format: ValidateFormat,
+
+    /// File to write the JSON or SARIF output to instead of standard output
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+}
+
+/// Output format for validation findings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ValidateFormat {
+    /// One line per violation
+    Text,
+    /// Violations as a JSON array
+    Json,
+    /// SARIF 2.1.0 log for CI annotations
+    Sarif,
+}
+
+pub async fn execute(args: ValidateArgs, _config: &Config) -> Result<()> {
+    match &args.mapping {
+        Some(path) => validate_mapping(path, &args).await,
+        None => bail!("Nothing to validate; pass --mapping <file>"),
+    }
+}
+
+/// Report each schema violation of a mapping configuration and fail if there are any
+async fn validate_mapping(path: &Path, args: &ValidateArgs) -> Result<()> {
+    let errors = MappingConfigurationLoader::validate_schema(path)
+        .await
+        .with_context(|| format!("Failed to validate mapping configuration {}", path.display()))?;
+
+    match args.format {
+        ValidateFormat::Text => {
+            for error in &errors {
+                println!("{}: {}", error.path, error.message);
+            }
+        }
+        ValidateFormat::Json => write_output(&serde_json::to_string_pretty(&errors)?, args.output.as_deref()).await?,
+        ValidateFormat::Sarif => {
+            let sarif = sarif_log(path, &errors).await?;
+            write_output(&serde_json::to_string_pretty(&sarif)?, args.output.as_deref()).await?;
+        }
+    }
+
+    if !errors.is_empty() {
+        bail!("{} has {} schema violation(s)", path.display(), errors.len());
+    }
+
+    info!("{} matches the mapping configuration schema", path.display());
+    Ok(())
+}
+
+/// SARIF log of a file's violations, placed on the lines they point at
+async fn sarif_log(path: &Path, errors: &[ValidationError]) -> Result<serde_json::Value> {
+    let source = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let uri = path.to_string_lossy().replace('\\', "/");
+
+    let mut report = SarifReport::new("fedramp", env!("CARGO_PKG_VERSION"));
+    report.add_file(&uri, Some(&source), errors);
+    Ok(report.to_json())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(format: ValidateFormat, output: Option<PathBuf>) -> ValidateArgs {
+        ValidateArgs { mapping: None, format, output }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_mapping_fails_validation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("poam_mappings.json");
+        std::fs::write(&path, r#"{"description": "Custom", "version": "1.0"}"#).unwrap();
+
+        assert!(validate_mapping(&path, &args(ValidateFormat::Text, None)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_sarif_output_is_written_before_failing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("poam_mappings.json");
+        std::fs::write(&path, "{\n  \"description\": \"Custom\",\n  \"version\": \"1.0\"\n}\n").unwrap();
+        let output = dir.path().join("results.sarif");
+
+        assert!(validate_mapping(&path, &args(ValidateFormat::Sarif, Some(output.clone()))).await.is_err());
+
+        let sarif: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert!(!results.is_empty());
+        assert!(results.iter().all(|result| result["level"] == "error"));
+        assert!(results[0]["ruleId"].as_str().unwrap().starts_with("mapping-schema/"));
+    }
+}
@@ -0,0 +1,83 @@
+// Modified: 2025-09-24
+
+//! CLI configuration
+//!
+//! Settings come from `config/default.toml` when it exists, then from the file
+//! passed with `--config`, then from `FEDRAMP__<SECTION>__<KEY>` environment
+//! variables, each source overriding the ones before it. Unknown sections,
+//! such as the server settings shared with the API, are ignored.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Project-wide defaults, relative to the working directory
+const DEFAULT_CONFIG: &str = "config/default";
+
+/// Settings shared by the subcommands
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    #[serde(default)]
+    pub compliance: ComplianceSettings,
+}
+
+/// `[compliance]` settings
+#[derive(Debug, Clone, Deserialize)]
+pub struct ComplianceSettings {
+    /// Baseline profile used when a command is not given `--profile`
+    #[serde(default = "default_baseline")]
+    pub default_baseline: String,
+}
+
+impl Default for ComplianceSettings {
+    fn default() -> Self {
+        Self {
+            default_baseline: default_baseline(),
+        }
+    }
+}
+
+fn default_baseline() -> String {
+    "moderate".to_string()
+}
+
+impl Config {
+    /// Load the configuration, requiring `path` to exist when it is given
+    pub fn load(path: Option<&str>) -> Result<Self> {
+        let mut builder = config::Config::builder()
+            .add_source(config::File::with_name(DEFAULT_CONFIG).required(false));
+        if let Some(path) = path {
+            builder = builder.add_source(config::File::with_name(path));
+        }
+        builder
+            .add_source(config::Environment::with_prefix("FEDRAMP").separator("__"))
+            .build()
+            .and_then(config::Config::try_deserialize)
+            .with_context(|| match path {
+                Some(path) => format!("Failed to load configuration from {}", path),
+                None => "Failed to load configuration".to_string(),
+            })
+    }
+
+    /// `profile` if given, otherwise the configured default baseline
+    pub fn profile_or_default<'a>(&'a self, profile: Option<&'a str>) -> &'a str {
+        profile.unwrap_or(&self.compliance.default_baseline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_config_file_overrides_default_baseline() {
+        let mut file = tempfile::Builder::new().suffix(".toml").tempfile().unwrap();
+        writeln!(file, "[server]\nport = 8080\n\n[compliance]\ndefault_baseline = \"high\"").unwrap();
+
+        let config = Config::load(Some(file.path().to_str().unwrap())).unwrap();
+        assert_eq!(config.profile_or_default(None), "high");
+        assert_eq!(config.profile_or_default(Some("low")), "low");
+
+        assert!(Config::load(Some("does/not/exist.toml")).is_err());
+    }
+}
@@ -44,15 +44,18 @@ enum Commands {
     /// Generate reports
     Report(report::ReportArgs),
     
-    /// Generate System Security Plans
-    Ssp(ssp::SspArgs),
-    
     /// Convert between frameworks
     Convert(convert::ConvertArgs),
     
     /// Validate documents and data
     Validate(validate::ValidateArgs),
     
+    /// Check POA&M quality against configured gates
+    Quality(quality::QualityArgs),
+    
+    /// Compare two baselines or gap analysis results
+    Diff(diff::DiffArgs),
+    
     /// Initialize new project
     Init(init::InitArgs),
     
@@ -64,10 +67,11 @@ enum Commands {
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
-    // Initialize logging
+    // Initialize logging on stderr, keeping stdout for command output such as JSON
     let level = if cli.verbose { Level::DEBUG } else { Level::INFO };
     let subscriber = FmtSubscriber::builder()
         .with_max_level(level)
+        .with_writer(std::io::stderr)
         .finish();
     tracing::subscriber::set_global_default(subscriber)?;
 
@@ -81,9 +85,10 @@ async fn main() -> Result<()> {
         Commands::Parse(args) => parse::execute(args, &config).await,
         Commands::Analyze(args) => analyze::execute(args, &config).await,
         Commands::Report(args) => report::execute(args, &config).await,
-        Commands::Ssp(args) => ssp::execute(args, &config).await,
         Commands::Convert(args) => convert::execute(args, &config).await,
         Commands::Validate(args) => validate::execute(args, &config).await,
+        Commands::Quality(args) => quality::execute(args, &config).await,
+        Commands::Diff(args) => diff::execute(args, &config).await,
         Commands::Init(args) => init::execute(args, &config).await,
         Commands::Version => {
             println!("fedramp-cli {}", env!("CARGO_PKG_VERSION"));
@@ -106,7 +111,97 @@ mod tests {
 
     #[test]
     fn test_version_command() {
-        let cli = Cli::parse_from(&["fedramp", "version"]);
+        let cli = Cli::parse_from(["fedramp", "version"]);
         assert!(matches!(cli.command, Commands::Version));
     }
+
+    #[test]
+    fn test_quality_command() {
+        let cli = Cli::parse_from(["fedramp", "quality", "poam.xlsx", "--format", "json"]);
+        match cli.command {
+            Commands::Quality(args) => {
+                assert_eq!(args.file, std::path::PathBuf::from("poam.xlsx"));
+                assert_eq!(args.format, quality::QualityFormat::Json);
+            }
+            _ => panic!("expected quality command"),
+        }
+    }
+
+    #[test]
+    fn test_report_trend_command() {
+        let cli = Cli::parse_from(["fedramp", "report", "trend", "--inputs", "archive", "--format", "html"]);
+        match cli.command {
+            Commands::Report(report::ReportArgs { command: report::ReportCommand::Trend(args) }) => {
+                assert_eq!(args.inputs, std::path::PathBuf::from("archive"));
+                assert_eq!(args.format, report::TrendFormat::Html);
+                assert!(args.output.is_none());
+            }
+            _ => panic!("expected report trend command"),
+        }
+    }
+
+    #[test]
+    fn test_analyze_since_command() {
+        let cli = Cli::parse_from(["fedramp", "analyze", "implementation.json", "--since", "2025-09-01T00:00:00Z"]);
+        match cli.command {
+            Commands::Analyze(args) => {
+                assert_eq!(args.implementation, std::path::PathBuf::from("implementation.json"));
+                assert_eq!(args.since.map(|since| since.to_rfc3339()).as_deref(), Some("2025-09-01T00:00:00+00:00"));
+            }
+            _ => panic!("expected analyze command"),
+        }
+        assert!(Cli::try_parse_from(["fedramp", "analyze", "implementation.json", "--since", "last week"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_watch_command() {
+        let cli = Cli::parse_from(["fedramp", "parse", "--input", "poam.xlsx", "--watch"]);
+        match cli.command {
+            Commands::Parse(args) => {
+                assert_eq!(args.input, std::path::PathBuf::from("poam.xlsx"));
+                assert!(args.watch);
+                assert!(args.output.is_none());
+            }
+            _ => panic!("expected parse command"),
+        }
+    }
+
+    #[test]
+    fn test_diff_command() {
+        let cli = Cli::parse_from(["fedramp", "diff", "before.json", "after.json", "--format", "json", "--max-differences", "5"]);
+        match cli.command {
+            Commands::Diff(args) => {
+                assert_eq!(args.before, std::path::PathBuf::from("before.json"));
+                assert_eq!(args.after, std::path::PathBuf::from("after.json"));
+                assert_eq!(args.format, diff::DiffFormat::Json);
+                assert_eq!(args.max_differences, Some(5));
+            }
+            _ => panic!("expected diff command"),
+        }
+    }
+
+    #[test]
+    fn test_convert_crosswalk_command() {
+        let cli = Cli::parse_from(["fedramp", "convert", "--crosswalk", "crosswalk.xlsx", "--target-framework", "cis"]);
+        match cli.command {
+            Commands::Convert(args) => {
+                assert_eq!(args.crosswalk, Some(std::path::PathBuf::from("crosswalk.xlsx")));
+                assert_eq!(args.source_framework, convert::FrameworkArg::Nist80053);
+                assert_eq!(args.target_framework, convert::FrameworkArg::Cis);
+            }
+            _ => panic!("expected convert command"),
+        }
+    }
+
+    #[test]
+    fn test_convert_poam_command() {
+        let cli = Cli::parse_from(["fedramp", "convert", "--poam", "poam.json", "--format", "xlsx"]);
+        match cli.command {
+            Commands::Convert(args) => {
+                assert_eq!(args.poam, Some(std::path::PathBuf::from("poam.json")));
+                assert_eq!(args.format, Some(convert::PoamFormat::Xlsx));
+            }
+            _ => panic!("expected convert command"),
+        }
+    }
 }
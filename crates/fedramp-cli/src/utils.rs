@@ -0,0 +1,28 @@
+// Modified: 2025-09-24
+
+//! Helpers shared by the subcommands
+
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use std::path::Path;
+
+/// Read and deserialize a JSON file, describing the expected content in errors
+pub async fn read_json<T: DeserializeOwned>(path: &Path, expected: &str) -> Result<T> {
+    let raw = tokio::fs::read_to_string(path)
+        .await
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&raw).with_context(|| format!("{} is not {}", path.display(), expected))
+}
+
+/// Write `contents` to `output`, or print it to standard output when there is none
+pub async fn write_output(contents: &str, output: Option<&Path>) -> Result<()> {
+    match output {
+        Some(path) => tokio::fs::write(path, contents)
+            .await
+            .with_context(|| format!("Failed to write {}", path.display())),
+        None => {
+            println!("{}", contents);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,46 @@
+// Modified: 2025-09-24
+
+//! `fedramp diff` run as a subprocess, checking what reaches stdout
+
+use assert_cmd::Command;
+use serde_json::{json, Value};
+use std::path::Path;
+
+/// Control mappings file whose moderate baseline selects `controls`
+fn write_mappings(path: &Path, controls: &[&str]) {
+    let mappings = json!({
+        "control_mappings": {
+            "description": "Test mappings",
+            "version": "1.0",
+            "frameworks": {
+                "nist-800-53": {
+                    "baseline_profiles": {
+                        "moderate": { "profile_url": null, "control_count": controls.len(), "controls": controls }
+                    }
+                }
+            }
+        }
+    });
+    std::fs::write(path, serde_json::to_string_pretty(&mappings).unwrap()).unwrap();
+}
+
+#[test]
+fn test_json_format_writes_only_json_to_stdout() {
+    let dir = tempfile::tempdir().unwrap();
+    let before = dir.path().join("before.json");
+    let after = dir.path().join("after.json");
+    write_mappings(&before, &["ac-1", "ac-2"]);
+    write_mappings(&after, &["ac-1", "ac-2", "au-2"]);
+
+    let output = Command::cargo_bin("fedramp").unwrap()
+        .current_dir(dir.path())
+        .args(["diff", "before.json", "after.json", "--profile", "moderate", "--format", "json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let delta: Value = serde_json::from_slice(&output.stdout)
+        .unwrap_or_else(|e| panic!("stdout is not JSON ({}): {}", e, String::from_utf8_lossy(&output.stdout)));
+    assert!(delta.is_object());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Found 1 difference(s)"));
+}
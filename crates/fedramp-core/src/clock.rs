@@ -0,0 +1,108 @@
+// Modified: 2025-09-20
+
+//! Injectable time source for FedRAMP compliance automation.
+//!
+//! Services that reason about "now" (overdue items, cache expiry, stale
+//! connections) take a [`SharedClock`] instead of calling `Utc::now()`
+//! directly, so time-dependent logic can be tested with a [`MockClock`].
+
+use crate::types::Timestamp;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// Source of the current time
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Current UTC timestamp
+    fn now(&self) -> Timestamp;
+}
+
+/// Shared, thread-safe clock handle
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Clock backed by the system time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        chrono::Utc::now()
+    }
+}
+
+/// Manually controlled clock for tests
+///
+/// Clones share the same underlying time, so a test can keep one handle and
+/// advance the clock seen by a service it injected another handle into.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<RwLock<Timestamp>>,
+}
+
+impl MockClock {
+    /// Create a mock clock frozen at the given time
+    pub fn new(now: Timestamp) -> Self {
+        Self {
+            now: Arc::new(RwLock::new(now)),
+        }
+    }
+
+    /// Set the current time
+    pub fn set(&self, now: Timestamp) {
+        let mut guard = self.now.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *guard = now;
+    }
+
+    /// Move the current time forward (or backward, for negative durations)
+    pub fn advance(&self, duration: chrono::Duration) {
+        let mut guard = self.now.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *guard += duration;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Timestamp {
+        *self.now.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Shared handle to the system clock
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    #[test]
+    fn test_mock_clock_set_and_advance() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let clock = MockClock::new(start);
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::days(3));
+        assert_eq!(clock.now(), start + Duration::days(3));
+
+        let later = Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap();
+        clock.set(later);
+        assert_eq!(clock.now(), later);
+    }
+
+    #[test]
+    fn test_mock_clock_clones_share_time() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let clock = MockClock::new(start);
+        let shared: SharedClock = Arc::new(clock.clone());
+
+        clock.advance(Duration::hours(1));
+        assert_eq!(shared.now(), start + Duration::hours(1));
+    }
+
+    #[test]
+    fn test_system_clock_is_current() {
+        let before = Utc::now();
+        let now = system_clock().now();
+        assert!(now >= before);
+    }
+}
@@ -34,6 +34,29 @@ pub enum Error {
     #[error("Document parsing error: {message}")]
     DocumentParsing { message: String },
 
+    #[error("Document parsing error: File size {size} exceeds maximum allowed size {limit}")]
+    FileTooLarge {
+        /// Size of the rejected input in bytes
+        size: u64,
+        /// Configured maximum in bytes
+        limit: u64,
+    },
+
+    #[error("Document parsing error: Unsupported format: {format}")]
+    UnsupportedFormat { format: String },
+
+    #[error("Document parsing error: Corrupt archive: {message}")]
+    CorruptArchive { message: String },
+
+    #[error("Document parsing error: {filename} is encrypted; remove its password and upload it again")]
+    EncryptedDocument { filename: String },
+
+    #[error("Document parsing error: Worksheet '{name}' not found")]
+    WorksheetNotFound { name: String },
+
+    #[error("Document parsing error: Invalid text encoding: {message}")]
+    Encoding { message: String },
+
     #[error("OSCAL validation error: {message}")]
     OscalValidation { message: String },
 
@@ -67,6 +90,20 @@ pub enum Error {
     #[error("Timeout error: {operation}")]
     Timeout { operation: String },
 
+    #[error("Step '{step}' timed out after {elapsed:?}")]
+    StepTimeout {
+        step: String,
+        /// Time spent in the step before it was abandoned
+        elapsed: std::time::Duration,
+    },
+
+    #[error("Operation cancelled: {operation}")]
+    Cancelled {
+        operation: String,
+        /// Steps that finished before the cancellation was observed
+        completed_steps: Vec<String>,
+    },
+
     #[error("Unknown error: {message}")]
     Unknown { message: String },
 }
@@ -93,6 +130,46 @@ impl Error {
         }
     }
 
+    /// Create an error for input larger than the configured maximum
+    pub fn file_too_large(size: u64, limit: u64) -> Self {
+        Self::FileTooLarge { size, limit }
+    }
+
+    /// Create an unsupported document format error
+    pub fn unsupported_format(format: impl Into<String>) -> Self {
+        Self::UnsupportedFormat {
+            format: format.into(),
+        }
+    }
+
+    /// Create an error for a ZIP container (XLSX, DOCX) that cannot be opened
+    pub fn corrupt_archive(message: impl Into<String>) -> Self {
+        Self::CorruptArchive {
+            message: message.into(),
+        }
+    }
+
+    /// Create an error for a password-protected document, which cannot be parsed
+    pub fn encrypted_document(filename: impl Into<String>) -> Self {
+        Self::EncryptedDocument {
+            filename: filename.into(),
+        }
+    }
+
+    /// Create a missing worksheet error
+    pub fn worksheet_not_found(name: impl Into<String>) -> Self {
+        Self::WorksheetNotFound {
+            name: name.into(),
+        }
+    }
+
+    /// Create a text encoding error
+    pub fn encoding(message: impl Into<String>) -> Self {
+        Self::Encoding {
+            message: message.into(),
+        }
+    }
+
     /// Create an OSCAL validation error
     pub fn oscal_validation(message: impl Into<String>) -> Self {
         Self::OscalValidation {
@@ -164,6 +241,22 @@ impl Error {
         }
     }
 
+    /// Create a step timeout error
+    pub fn step_timeout(step: impl Into<String>, elapsed: std::time::Duration) -> Self {
+        Self::StepTimeout {
+            step: step.into(),
+            elapsed,
+        }
+    }
+
+    /// Create a cancellation error
+    pub fn cancelled(operation: impl Into<String>, completed_steps: Vec<String>) -> Self {
+        Self::Cancelled {
+            operation: operation.into(),
+            completed_steps,
+        }
+    }
+
     /// Create an unknown error
     pub fn unknown(message: impl Into<String>) -> Self {
         Self::Unknown {
@@ -181,6 +274,12 @@ impl Error {
                 | Self::NotFound { .. }
                 | Self::Conflict { .. }
                 | Self::RateLimit
+                | Self::FileTooLarge { .. }
+                | Self::UnsupportedFormat { .. }
+                | Self::CorruptArchive { .. }
+                | Self::EncryptedDocument { .. }
+                | Self::WorksheetNotFound { .. }
+                | Self::Encoding { .. }
         )
     }
 
@@ -192,10 +291,35 @@ impl Error {
                 | Self::Internal { .. }
                 | Self::ExternalService { .. }
                 | Self::Timeout { .. }
+                | Self::StepTimeout { .. }
                 | Self::Unknown { .. }
         )
     }
 
+    /// Check if this error is temporary, so retrying the operation may succeed
+    ///
+    /// Covers timeouts, rate limiting, external service failures, pool
+    /// exhaustion and I/O errors from dropped or refused connections.
+    /// Not-found, parsing and validation errors are never transient.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::ExternalService { .. } | Self::Timeout { .. } | Self::RateLimit => true,
+            Self::Database { source } => matches!(source, sqlx::Error::PoolTimedOut | sqlx::Error::Io(_)),
+            Self::Io { source } => matches!(
+                source.kind(),
+                std::io::ErrorKind::TimedOut
+                    | std::io::ErrorKind::Interrupted
+                    | std::io::ErrorKind::WouldBlock
+                    | std::io::ErrorKind::ConnectionRefused
+                    | std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::NotConnected
+                    | std::io::ErrorKind::BrokenPipe
+            ),
+            _ => false,
+        }
+    }
+
     /// Get HTTP status code for this error
     pub fn status_code(&self) -> u16 {
         match self {
@@ -204,11 +328,18 @@ impl Error {
             Self::Authorization { .. } => 403,
             Self::NotFound { .. } => 404,
             Self::Conflict { .. } => 409,
+            Self::FileTooLarge { .. } => 413,
+            Self::UnsupportedFormat { .. } => 415,
+            Self::CorruptArchive { .. }
+            | Self::EncryptedDocument { .. }
+            | Self::WorksheetNotFound { .. }
+            | Self::Encoding { .. } => 422,
             Self::RateLimit => 429,
             Self::Database { .. }
             | Self::Internal { .. }
             | Self::ExternalService { .. }
             | Self::Timeout { .. }
+            | Self::StepTimeout { .. }
             | Self::Unknown { .. } => 500,
             _ => 500,
         }
@@ -217,3 +348,48 @@ impl Error {
 
 /// Result type alias for the FedRAMP platform
 pub type Result<T> = std::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_document_errors_map_to_client_status_codes() {
+        let cases = [
+            (Error::file_too_large(2048, 1024), 413),
+            (Error::unsupported_format("pdf"), 415),
+            (Error::corrupt_archive("invalid zip header"), 422),
+            (Error::encrypted_document("poam.xlsx"), 422),
+            (Error::worksheet_not_found("POA&M Items"), 422),
+            (Error::encoding("invalid utf-8 sequence"), 422),
+        ];
+
+        for (error, status) in cases {
+            assert!(error.is_client_error(), "{} should be a client error", error);
+            assert_eq!(error.status_code(), status);
+        }
+    }
+
+    #[test]
+    fn test_only_temporary_failures_are_transient() {
+        assert!(Error::external_service("baseline-store", "503 Service Unavailable").is_transient());
+        assert!(Error::timeout("baseline read").is_transient());
+        assert!(Error::from(std::io::Error::from(std::io::ErrorKind::ConnectionReset)).is_transient());
+
+        assert!(!Error::not_found("nist-800-53:moderate").is_transient());
+        assert!(!Error::document_parsing("Failed to parse mappings JSON").is_transient());
+        assert!(!Error::from(std::io::Error::from(std::io::ErrorKind::NotFound)).is_transient());
+    }
+
+    #[test]
+    fn test_document_error_messages_keep_parsing_prefix() {
+        assert_eq!(
+            Error::file_too_large(2048, 1024).to_string(),
+            "Document parsing error: File size 2048 exceeds maximum allowed size 1024"
+        );
+        assert_eq!(
+            Error::worksheet_not_found("POA&M Items").to_string(),
+            "Document parsing error: Worksheet 'POA&M Items' not found"
+        );
+    }
+}
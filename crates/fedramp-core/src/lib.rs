@@ -11,9 +11,13 @@ pub mod error;
 pub mod validation;
 pub mod config;
 pub mod utils;
+pub mod clock;
+pub mod metrics;
 
 // Re-export commonly used types
 pub use error::{Error, Result};
+pub use clock::{Clock, MockClock, SharedClock, SystemClock};
+pub use metrics::{MetricsRecorder, SharedMetricsRecorder};
 pub use models::*;
 pub use types::*;
 
@@ -0,0 +1,29 @@
+// Modified: 2025-09-24
+
+//! Injectable processing metrics for FedRAMP compliance automation.
+//!
+//! Document parsing and gap analysis take a [`SharedMetricsRecorder`] and
+//! report their timings through it, so a service can export them (e.g. as
+//! Prometheus histograms) without those crates depending on an exporter.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Sink for document parse and gap analysis workflow timings
+///
+/// Every method defaults to doing nothing, so recorders only implement the
+/// timings they export.
+pub trait MetricsRecorder: Send + Sync + fmt::Debug {
+    /// A document of the given type (e.g. `excel`) was parsed
+    fn observe_document_parse(&self, _document_type: &str, _duration: Duration) {}
+
+    /// A gap analysis workflow step (e.g. `gap_analysis`) completed
+    fn observe_workflow_step(&self, _step: &str, _duration: Duration) {}
+
+    /// A gap analysis workflow completed
+    fn observe_workflow(&self, _duration: Duration) {}
+}
+
+/// Shared, thread-safe metrics recorder handle
+pub type SharedMetricsRecorder = Arc<dyn MetricsRecorder>;
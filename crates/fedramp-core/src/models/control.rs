@@ -8,16 +8,7 @@ use schemars::JsonSchema;
 use uuid::Uuid;
 use validator::Validate;
 
-/// Security control implementation status
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
-#[serde(rename_all = "kebab-case")]
-pub enum ImplementationStatus {
-    Implemented,
-    PartiallyImplemented,
-    Planned,
-    AlternativeImplementation,
-    NotApplicable,
-}
+pub use crate::types::ImplementationStatus;
 
 /// Control responsibility type
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
@@ -30,7 +21,7 @@ pub enum ResponsibilityType {
 }
 
 /// Security framework type
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash)]
 #[serde(rename_all = "kebab-case")]
 pub enum Framework {
     #[serde(rename = "NIST-800-53")]
@@ -8,6 +8,8 @@
 use crate::types::{EntityId, Result, Timestamp};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 use uuid::Uuid;
 use validator::Validate;
 
@@ -16,8 +18,10 @@ use validator::Validate;
 #[serde(rename_all = "kebab-case")]
 pub enum DocumentType {
     /// System Security Plan
+    #[serde(alias = "ssp")]
     SystemSecurityPlan,
     /// Plan of Action and Milestones
+    #[serde(alias = "poam")]
     PlanOfActionMilestones,
     /// Security Assessment Plan
     SecurityAssessmentPlan,
@@ -31,6 +35,68 @@ pub enum DocumentType {
     ConfigurationManagementPlan,
     /// Contingency Plan
     ContingencyPlan,
+    /// Integrated Inventory Workbook
+    #[serde(alias = "inventory")]
+    IntegratedInventoryWorkbook,
+}
+
+impl DocumentType {
+    /// All document types
+    pub const ALL: [DocumentType; 9] = [
+        DocumentType::SystemSecurityPlan,
+        DocumentType::PlanOfActionMilestones,
+        DocumentType::SecurityAssessmentPlan,
+        DocumentType::SecurityAssessmentReport,
+        DocumentType::ContinuousMonitoringPlan,
+        DocumentType::IncidentResponsePlan,
+        DocumentType::ConfigurationManagementPlan,
+        DocumentType::ContingencyPlan,
+        DocumentType::IntegratedInventoryWorkbook,
+    ];
+
+    /// Stable kebab-case identifier, matching the serde representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentType::SystemSecurityPlan => "system-security-plan",
+            DocumentType::PlanOfActionMilestones => "plan-of-action-milestones",
+            DocumentType::SecurityAssessmentPlan => "security-assessment-plan",
+            DocumentType::SecurityAssessmentReport => "security-assessment-report",
+            DocumentType::ContinuousMonitoringPlan => "continuous-monitoring-plan",
+            DocumentType::IncidentResponsePlan => "incident-response-plan",
+            DocumentType::ConfigurationManagementPlan => "configuration-management-plan",
+            DocumentType::ContingencyPlan => "contingency-plan",
+            DocumentType::IntegratedInventoryWorkbook => "integrated-inventory-workbook",
+        }
+    }
+
+    /// Short name accepted in place of the identifier, e.g. `poam`
+    fn alias(&self) -> Option<&'static str> {
+        match self {
+            DocumentType::SystemSecurityPlan => Some("ssp"),
+            DocumentType::PlanOfActionMilestones => Some("poam"),
+            DocumentType::IntegratedInventoryWorkbook => Some("inventory"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DocumentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DocumentType {
+    type Err = crate::error::Error;
+
+    /// Parse an identifier or short name, ignoring case
+    fn from_str(s: &str) -> Result<Self> {
+        let name = s.trim().to_lowercase();
+        DocumentType::ALL
+            .into_iter()
+            .find(|document_type| document_type.as_str() == name || document_type.alias() == Some(name.as_str()))
+            .ok_or_else(|| crate::error::Error::validation(format!("Unknown document type: {}", s)))
+    }
 }
 
 /// Document status
@@ -277,4 +343,18 @@ mod tests {
         assert_eq!(doc.status, DocumentStatus::Published);
         assert!(!doc.is_editable());
     }
+
+    #[test]
+    fn test_document_type_names_round_trip() {
+        for document_type in DocumentType::ALL {
+            assert_eq!(document_type.as_str().parse::<DocumentType>().unwrap(), document_type);
+            assert_eq!(serde_json::to_value(document_type).unwrap(), document_type.as_str());
+        }
+        assert_eq!("POAM".parse::<DocumentType>().unwrap(), DocumentType::PlanOfActionMilestones);
+        assert_eq!(
+            serde_json::from_str::<DocumentType>("\"inventory\"").unwrap(),
+            DocumentType::IntegratedInventoryWorkbook
+        );
+        assert!("inventroy".parse::<DocumentType>().is_err());
+    }
 }
@@ -285,8 +285,12 @@ impl PoamFinding {
 
     /// Check if finding is overdue
     pub fn is_overdue(&self) -> bool {
+        self.is_overdue_at(crate::utils::current_timestamp())
+    }
+
+    /// Check if finding is overdue as of the given time
+    pub fn is_overdue_at(&self, now: Timestamp) -> bool {
         if let Some(planned_completion) = self.remediation.timeline.planned_completion {
-            let now = crate::utils::current_timestamp();
             now > planned_completion && !self.is_closed()
         } else {
             false
@@ -306,7 +310,11 @@ impl PoamFinding {
 
     /// Get finding age in days
     pub fn age_days(&self) -> i64 {
-        let now = crate::utils::current_timestamp();
+        self.age_days_at(crate::utils::current_timestamp())
+    }
+
+    /// Get finding age in days as of the given time
+    pub fn age_days_at(&self, now: Timestamp) -> i64 {
         (now - self.created_at).num_days()
     }
 }
@@ -405,4 +413,30 @@ mod tests {
         finding.assign_to(assignee_id, user_id);
         assert_eq!(finding.assigned_to, Some(assignee_id));
     }
+
+    #[test]
+    fn test_finding_overdue_with_mock_clock() {
+        use crate::clock::{Clock, MockClock};
+
+        let clock = MockClock::new(crate::utils::current_timestamp());
+        let mut finding = PoamFinding::new(
+            "Stale Finding".to_string(),
+            "Remediation is behind schedule".to_string(),
+            RiskLevel::High,
+            FindingSource::SecurityAssessment,
+            Uuid::new_v4(),
+        );
+        finding.created_at = clock.now();
+        finding.remediation.timeline.planned_completion = Some(clock.now() + chrono::Duration::days(30));
+
+        assert!(!finding.is_overdue_at(clock.now()));
+        assert_eq!(finding.age_days_at(clock.now()), 0);
+
+        clock.advance(chrono::Duration::days(31));
+        assert!(finding.is_overdue_at(clock.now()));
+        assert_eq!(finding.age_days_at(clock.now()), 31);
+
+        finding.status = FindingStatus::Closed;
+        assert!(!finding.is_overdue_at(clock.now()));
+    }
 }
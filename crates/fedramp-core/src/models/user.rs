@@ -313,18 +313,35 @@ impl User {
 
     /// Check if user account is active
     pub fn is_active(&self) -> bool {
-        self.status == UserStatus::Active && !self.is_locked()
+        self.is_active_at(crate::utils::current_timestamp())
+    }
+
+    /// Check if user account is active as of the given time
+    pub fn is_active_at(&self, now: Timestamp) -> bool {
+        self.status == UserStatus::Active && !self.is_locked_at(now)
     }
 
     /// Check if user account is locked
     pub fn is_locked(&self) -> bool {
+        self.is_locked_at(crate::utils::current_timestamp())
+    }
+
+    /// Check if user account is locked as of the given time
+    pub fn is_locked_at(&self, now: Timestamp) -> bool {
         if let Some(locked_until) = self.locked_until {
-            crate::utils::current_timestamp() < locked_until
+            now < locked_until
         } else {
             false
         }
     }
 
+    /// Check if a pending password reset has expired as of the given time
+    pub fn is_password_reset_expired_at(&self, now: Timestamp) -> bool {
+        self.password_reset_expires
+            .map(|expires| now >= expires)
+            .unwrap_or(false)
+    }
+
     /// Add a role to the user
     pub fn add_role(&mut self, role: UserRole) {
         if !self.roles.contains(&role) {
@@ -362,6 +379,13 @@ impl User {
     }
 }
 
+impl UserSession {
+    /// Check if the session has expired as of the given time
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        !self.active || now >= self.expires_at
+    }
+}
+
 impl Default for UserPreferences {
     fn default() -> Self {
         Self {
@@ -494,4 +518,27 @@ mod tests {
         assert_eq!(user.status, UserStatus::Locked);
         assert!(user.is_locked());
     }
+
+    #[test]
+    fn test_lockout_expiry_with_mock_clock() {
+        use crate::clock::{Clock, MockClock};
+
+        let clock = MockClock::new(crate::utils::current_timestamp());
+        let mut user = User::new(
+            "locked".to_string(),
+            "locked@example.com".to_string(),
+            "Locked".to_string(),
+            "User".to_string(),
+            None,
+        );
+        user.status = UserStatus::Active;
+        user.locked_until = Some(clock.now() + chrono::Duration::minutes(30));
+
+        assert!(user.is_locked_at(clock.now()));
+        assert!(!user.is_active_at(clock.now()));
+
+        clock.advance(chrono::Duration::minutes(31));
+        assert!(!user.is_locked_at(clock.now()));
+        assert!(user.is_active_at(clock.now()));
+    }
 }
@@ -5,8 +5,10 @@
 //! This module provides shared type definitions, enums, and constants
 //! used throughout the FedRAMP compliance automation platform.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 /// OSCAL version compatibility
 pub const OSCAL_VERSION: &str = "1.1.2";
@@ -97,6 +99,200 @@ pub enum DeploymentModel {
     Hybrid,
 }
 
+/// Control implementation status shared across crates
+///
+/// Crate-local status enums convert to and from this type so that status
+/// strings stay stable when variants are added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum ImplementationStatus {
+    /// Control is not implemented
+    NotImplemented,
+    /// Implementation is planned
+    Planned,
+    /// Implementation is underway
+    InProgress,
+    /// Control is partially implemented
+    PartiallyImplemented,
+    /// Control is met by an alternative implementation
+    AlternativeImplementation,
+    /// Control is fully implemented
+    Implemented,
+    /// Control does not apply to the system
+    NotApplicable,
+}
+
+impl ImplementationStatus {
+    /// All statuses, in lifecycle order
+    pub const ALL: [ImplementationStatus; 7] = [
+        ImplementationStatus::NotImplemented,
+        ImplementationStatus::Planned,
+        ImplementationStatus::InProgress,
+        ImplementationStatus::PartiallyImplemented,
+        ImplementationStatus::AlternativeImplementation,
+        ImplementationStatus::Implemented,
+        ImplementationStatus::NotApplicable,
+    ];
+
+    /// Stable kebab-case identifier, matching the serde representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImplementationStatus::NotImplemented => "not-implemented",
+            ImplementationStatus::Planned => "planned",
+            ImplementationStatus::InProgress => "in-progress",
+            ImplementationStatus::PartiallyImplemented => "partially-implemented",
+            ImplementationStatus::AlternativeImplementation => "alternative-implementation",
+            ImplementationStatus::Implemented => "implemented",
+            ImplementationStatus::NotApplicable => "not-applicable",
+        }
+    }
+}
+
+impl fmt::Display for ImplementationStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ImplementationStatus {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        ImplementationStatus::ALL
+            .into_iter()
+            .find(|status| status.as_str() == s)
+            .ok_or_else(|| crate::error::Error::validation(format!("Unknown implementation status: {}", s)))
+    }
+}
+
+/// Page size used when a request does not name one
+pub const DEFAULT_PAGE_LIMIT: usize = 50;
+
+/// Largest page size a request may ask for
+pub const MAX_PAGE_LIMIT: usize = 1000;
+
+/// Prefix of cursor tokens, so tokens from other sources are rejected
+const CURSOR_PREFIX: &str = "o";
+
+/// Position and size of a requested page
+///
+/// A cursor from a previous page takes precedence over `offset`. Limits
+/// above [`MAX_PAGE_LIMIT`] are clamped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct PageRequest {
+    /// Number of items to skip
+    pub offset: usize,
+    /// Maximum number of items to return
+    pub limit: usize,
+    /// Cursor token returned as `next_cursor` by the previous page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            limit: DEFAULT_PAGE_LIMIT,
+            cursor: None,
+        }
+    }
+}
+
+impl PageRequest {
+    /// Request `limit` items starting at `offset`
+    pub fn new(offset: usize, limit: usize) -> Self {
+        Self { offset, limit, cursor: None }
+    }
+
+    /// Request the page a cursor token points at
+    pub fn from_cursor(cursor: impl Into<String>, limit: usize) -> Self {
+        Self { offset: 0, limit, cursor: Some(cursor.into()) }
+    }
+
+    /// Offset the page starts at, from the cursor if one is set
+    pub fn start(&self) -> Result<usize> {
+        match &self.cursor {
+            Some(cursor) => decode_cursor(cursor),
+            None => Ok(self.offset),
+        }
+    }
+
+    /// Page size after clamping to [`MAX_PAGE_LIMIT`]
+    pub fn effective_limit(&self) -> usize {
+        self.limit.min(MAX_PAGE_LIMIT)
+    }
+}
+
+/// One page of a larger result set, as returned by list endpoints
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Page<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+    /// Offset of the first item on this page
+    pub offset: usize,
+    /// Page size the items were sliced with
+    pub limit: usize,
+    /// Number of items across all pages
+    pub total: usize,
+    /// Cursor for the following page; absent on the last page
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Slice the requested page out of a full result set
+    ///
+    /// An offset past the end gives an empty page. A limit of zero gives an
+    /// empty page without a next cursor, since following it would never
+    /// advance. Fails if the request carries a malformed cursor.
+    pub fn paginate(items: Vec<T>, request: &PageRequest) -> Result<Self> {
+        let total = items.len();
+        let offset = request.start()?;
+        let limit = request.effective_limit();
+        let end = offset.saturating_add(limit).min(total);
+
+        let items: Vec<T> = if offset < total {
+            items.into_iter().skip(offset).take(limit).collect()
+        } else {
+            Vec::new()
+        };
+        let next_cursor = (limit > 0 && end < total).then(|| encode_cursor(end));
+
+        Ok(Self { items, offset, limit, total, next_cursor })
+    }
+
+    /// Whether a following page exists
+    pub fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    /// Convert the items, keeping the page position
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            offset: self.offset,
+            limit: self.limit,
+            total: self.total,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+/// Opaque cursor token for a page starting at `offset`
+pub fn encode_cursor(offset: usize) -> String {
+    format!("{}{:x}", CURSOR_PREFIX, offset)
+}
+
+/// Offset a cursor token from [`encode_cursor`] points at
+pub fn decode_cursor(cursor: &str) -> Result<usize> {
+    cursor
+        .strip_prefix(CURSOR_PREFIX)
+        .and_then(|hex| usize::from_str_radix(hex, 16).ok())
+        .ok_or_else(|| crate::error::Error::validation(format!("Invalid page cursor: {}", cursor)))
+}
+
 /// Common identifier type for entities
 pub type EntityId = uuid::Uuid;
 
@@ -134,6 +330,55 @@ mod tests {
         assert_eq!(cat.overall_impact(), RiskLevel::Low);
     }
 
+    #[test]
+    fn test_implementation_status_string_round_trip() {
+        for status in ImplementationStatus::ALL {
+            assert_eq!(status.as_str().parse::<ImplementationStatus>().unwrap(), status);
+            assert_eq!(
+                serde_json::to_value(status).unwrap(),
+                serde_json::Value::String(status.as_str().to_string())
+            );
+        }
+        assert!("InProgress".parse::<ImplementationStatus>().is_err());
+    }
+
+    #[test]
+    fn test_page_follows_cursors_to_the_end() {
+        let first = Page::paginate((1..=5).collect(), &PageRequest::new(0, 2)).unwrap();
+        assert_eq!(first.items, vec![1, 2]);
+        assert_eq!(first.total, 5);
+
+        let second = Page::paginate((1..=5).collect(), &PageRequest::from_cursor(first.next_cursor.unwrap(), 2)).unwrap();
+        assert_eq!((second.items, second.offset), (vec![3, 4], 2));
+
+        let last = Page::paginate((1..=5).collect(), &PageRequest::from_cursor(second.next_cursor.unwrap(), 2)).unwrap();
+        assert_eq!(last.items, vec![5]);
+        assert!(!last.has_more());
+        assert!(serde_json::to_value(&last).unwrap().get("next_cursor").is_none());
+    }
+
+    #[test]
+    fn test_page_boundaries() {
+        let past_end = Page::paginate(vec![1, 2, 3], &PageRequest::new(10, 2)).unwrap();
+        assert!(past_end.items.is_empty());
+        assert_eq!(past_end.total, 3);
+        assert!(!past_end.has_more());
+
+        let zero_limit = Page::paginate(vec![1, 2, 3], &PageRequest::new(0, 0)).unwrap();
+        assert!(zero_limit.items.is_empty());
+        assert!(!zero_limit.has_more());
+
+        let exact = Page::paginate(vec![1, 2, 3], &PageRequest::new(1, 2)).unwrap();
+        assert_eq!(exact.items, vec![2, 3]);
+        assert!(!exact.has_more());
+
+        let clamped = Page::paginate(vec![0; MAX_PAGE_LIMIT + 1], &PageRequest::new(0, usize::MAX)).unwrap();
+        assert_eq!(clamped.items.len(), MAX_PAGE_LIMIT);
+        assert_eq!(decode_cursor(clamped.next_cursor.as_deref().unwrap()).unwrap(), MAX_PAGE_LIMIT);
+
+        assert!(Page::paginate(vec![1], &PageRequest::from_cursor("not-a-cursor", 2)).is_err());
+    }
+
     #[test]
     fn test_risk_level_display() {
         assert_eq!(RiskLevel::Low.to_string(), "low");
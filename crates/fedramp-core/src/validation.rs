@@ -6,9 +6,12 @@
 //! data integrity and compliance with FedRAMP requirements.
 
 use crate::error::Error;
+use crate::models::control::Framework;
 use crate::types::Result;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::fmt;
 use validator::{Validate, ValidationError, ValidationErrors};
 
 /// Trait for validating FedRAMP compliance data
@@ -122,6 +125,169 @@ pub fn validate_url(url: &str) -> Result<()> {
     Ok(())
 }
 
+/// Structured, normalized control identifier
+///
+/// Produced by [`validate_control_id`]. The meaning of `family` depends on the
+/// framework: the two-letter family for NIST 800-53 (`AC`), the chapter and
+/// family for NIST 800-171 (`3.1`), and the control number for CIS (`4`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NormalizedControlId {
+    /// Framework the identifier belongs to
+    pub framework: Framework,
+    /// Control family
+    pub family: String,
+    /// Control number within the family
+    pub number: u32,
+    /// Control enhancement (800-53) or sub-safeguard (CIS), if any
+    pub enhancement: Option<u32>,
+}
+
+impl fmt::Display for NormalizedControlId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.framework, self.enhancement) {
+            (Framework::Nist80053, Some(enhancement)) => {
+                write!(f, "{}-{}({})", self.family, self.number, enhancement)
+            }
+            (Framework::Nist80053, None) => write!(f, "{}-{}", self.family, self.number),
+            (_, Some(enhancement)) => write!(f, "{}.{}.{}", self.family, self.number, enhancement),
+            (_, None) => write!(f, "{}.{}", self.family, self.number),
+        }
+    }
+}
+
+/// Validate and normalize a control identifier for the given framework
+///
+/// Accepted formats:
+/// - NIST 800-53: `AC-2`, `AC-2(3)`, `ac-2.3`, `AC 2 (3)`
+/// - NIST 800-171: `3.1.1`, `03.01.01`
+/// - CIS: `4.1`, `1.1.1`
+///
+/// Whitespace is ignored, letters are upper-cased and leading zeros are
+/// dropped, so `ac-02 (3)` and `AC-2(3)` normalize to the same value.
+pub fn validate_control_id(id: &str, framework: Framework) -> Result<NormalizedControlId> {
+    let compact: String = id
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase();
+
+    if compact.is_empty() {
+        return Err(Error::validation("Control ID must not be empty"));
+    }
+
+    match framework {
+        Framework::Nist80053 => parse_nist_800_53_id(id, &compact),
+        Framework::Nist800171 => parse_nist_800_171_id(id, &compact),
+        Framework::Cis => parse_cis_id(id, &compact),
+        Framework::Custom => Err(Error::validation(format!(
+            "Cannot validate control ID '{}': custom frameworks have no canonical ID format",
+            id
+        ))),
+    }
+}
+
+fn parse_nist_800_53_id(original: &str, compact: &str) -> Result<NormalizedControlId> {
+    let invalid = || {
+        Error::validation(format!(
+            "Invalid NIST 800-53 control ID '{}': expected a two-letter family and number such as 'AC-2' or 'AC-2(3)'",
+            original
+        ))
+    };
+
+    let family: String = compact.chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+    if family.len() != 2 {
+        return Err(invalid());
+    }
+
+    let rest = &compact[family.len()..];
+    let rest = rest.strip_prefix('-').unwrap_or(rest);
+    let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let number = parse_control_number(&rest[..digits_end]).ok_or_else(invalid)?;
+
+    let suffix = &rest[digits_end..];
+    let enhancement = if suffix.is_empty() {
+        None
+    } else if let Some(inner) = suffix.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        Some(parse_control_number(inner).ok_or_else(invalid)?)
+    } else if let Some(inner) = suffix.strip_prefix('.') {
+        Some(parse_control_number(inner).ok_or_else(invalid)?)
+    } else {
+        return Err(invalid());
+    };
+
+    Ok(NormalizedControlId {
+        framework: Framework::Nist80053,
+        family,
+        number,
+        enhancement,
+    })
+}
+
+fn parse_nist_800_171_id(original: &str, compact: &str) -> Result<NormalizedControlId> {
+    let invalid = || {
+        Error::validation(format!(
+            "Invalid NIST 800-171 requirement ID '{}': expected three dot-separated numbers such as '3.1.1'",
+            original
+        ))
+    };
+
+    let parts = compact
+        .split('.')
+        .map(parse_control_number)
+        .collect::<Option<Vec<u32>>>()
+        .ok_or_else(invalid)?;
+
+    match parts.as_slice() {
+        [3, family, number] => Ok(NormalizedControlId {
+            framework: Framework::Nist800171,
+            family: format!("3.{}", family),
+            number: *number,
+            enhancement: None,
+        }),
+        [chapter, _, _] => Err(Error::validation(format!(
+            "Invalid NIST 800-171 requirement ID '{}': security requirements are numbered under chapter 3, found chapter {}",
+            original, chapter
+        ))),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_cis_id(original: &str, compact: &str) -> Result<NormalizedControlId> {
+    let invalid = || {
+        Error::validation(format!(
+            "Invalid CIS control ID '{}': expected two or three dot-separated numbers such as '4.1' or '1.1.1'",
+            original
+        ))
+    };
+
+    let parts = compact
+        .split('.')
+        .map(parse_control_number)
+        .collect::<Option<Vec<u32>>>()
+        .ok_or_else(invalid)?;
+
+    let (family, number, enhancement) = match parts.as_slice() {
+        [family, number] => (*family, *number, None),
+        [family, number, enhancement] => (*family, *number, Some(*enhancement)),
+        _ => return Err(invalid()),
+    };
+
+    Ok(NormalizedControlId {
+        framework: Framework::Cis,
+        family: family.to_string(),
+        number,
+        enhancement,
+    })
+}
+
+/// Parse a non-zero control number made only of ASCII digits
+fn parse_control_number(segment: &str) -> Option<u32> {
+    if segment.is_empty() || !segment.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    segment.parse::<u32>().ok().filter(|n| *n > 0)
+}
+
 /// Custom validation error for FedRAMP-specific rules
 pub fn fedramp_validation_error(message: &str) -> ValidationError {
     ValidationError::new("fedramp_compliance")
@@ -183,4 +349,83 @@ mod tests {
         assert!(validate_url("http://example.com").is_ok());
         assert!(validate_url("invalid-url").is_err());
     }
+
+    #[test]
+    fn test_validate_control_id_nist_800_53() {
+        let id = validate_control_id("AC-2", Framework::Nist80053).unwrap();
+        assert_eq!(id.family, "AC");
+        assert_eq!(id.number, 2);
+        assert_eq!(id.enhancement, None);
+        assert_eq!(id.to_string(), "AC-2");
+
+        let id = validate_control_id(" ac-02 (3) ", Framework::Nist80053).unwrap();
+        assert_eq!(id.family, "AC");
+        assert_eq!(id.number, 2);
+        assert_eq!(id.enhancement, Some(3));
+        assert_eq!(id.to_string(), "AC-2(3)");
+
+        assert_eq!(
+            validate_control_id("ac-2.3", Framework::Nist80053).unwrap(),
+            validate_control_id("AC-2(3)", Framework::Nist80053).unwrap()
+        );
+
+        for invalid in ["", "AC", "A-2", "ACC-2", "AC-0", "AC-2(", "AC-2()", "AC-2(3)x", "3.1.1"] {
+            assert!(
+                validate_control_id(invalid, Framework::Nist80053).is_err(),
+                "expected '{}' to be rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_control_id_nist_800_171() {
+        let id = validate_control_id("3.1.1", Framework::Nist800171).unwrap();
+        assert_eq!(id.family, "3.1");
+        assert_eq!(id.number, 1);
+        assert_eq!(id.to_string(), "3.1.1");
+
+        let id = validate_control_id("03.13.11", Framework::Nist800171).unwrap();
+        assert_eq!(id.family, "3.13");
+        assert_eq!(id.number, 11);
+        assert_eq!(id.to_string(), "3.13.11");
+
+        for invalid in ["3.1", "3.1.1.1", "2.1.1", "3.a.1", "3..1", "AC-2"] {
+            assert!(
+                validate_control_id(invalid, Framework::Nist800171).is_err(),
+                "expected '{}' to be rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_control_id_cis() {
+        let id = validate_control_id("4.10", Framework::Cis).unwrap();
+        assert_eq!(id.family, "4");
+        assert_eq!(id.number, 10);
+        assert_eq!(id.enhancement, None);
+        assert_eq!(id.to_string(), "4.10");
+
+        let id = validate_control_id("1.1.1", Framework::Cis).unwrap();
+        assert_eq!(id.enhancement, Some(1));
+        assert_eq!(id.to_string(), "1.1.1");
+
+        for invalid in ["4", "1.1.1.1", "CIS-1", "1.x", ".1"] {
+            assert!(
+                validate_control_id(invalid, Framework::Cis).is_err(),
+                "expected '{}' to be rejected",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_control_id_error_message() {
+        let err = validate_control_id("XYZ", Framework::Nist80053).unwrap_err();
+        assert!(err.to_string().contains("'XYZ'"));
+        assert!(err.to_string().contains("AC-2(3)"));
+
+        assert!(validate_control_id("AC-2", Framework::Custom).is_err());
+    }
 }
@@ -6,7 +6,7 @@
 use gap_analysis::{
     GapAnalysisService, 
     engine::{CurrentImplementation, ControlImplementation, ImplementationStatus},
-    GapAnalysisServiceConfig
+    GapAnalysisServiceConfig, RetryPolicy, StepTimeouts
 };
 use std::collections::HashMap;
 use chrono::Utc;
@@ -35,6 +35,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         auto_generate_plans: true,
         cache_results: true,
         max_gaps_per_analysis: 100,
+        step_timeouts: StepTimeouts::default(),
+        baseline_retry: RetryPolicy::default(),
     };
     gap_service.update_config(config);
 
@@ -101,6 +103,8 @@ fn create_sample_implementation() -> CurrentImplementation {
         implementation_date: Some(Utc::now()),
         evidence: vec![],
         parameters: HashMap::new(),
+        completion_fraction: None,
+        last_modified: None,
     });
 
     controls.insert("AC-2".to_string(), ControlImplementation {
@@ -109,6 +113,8 @@ fn create_sample_implementation() -> CurrentImplementation {
         implementation_date: Some(Utc::now()),
         evidence: vec![],
         parameters: HashMap::new(),
+        completion_fraction: None,
+        last_modified: None,
     });
 
     // Add some partially implemented controls
@@ -118,6 +124,8 @@ fn create_sample_implementation() -> CurrentImplementation {
         implementation_date: None,
         evidence: vec![],
         parameters: HashMap::new(),
+        completion_fraction: Some(0.5),
+        last_modified: None,
     });
 
     // Add some planned controls
@@ -127,6 +135,8 @@ fn create_sample_implementation() -> CurrentImplementation {
         implementation_date: None,
         evidence: vec![],
         parameters: HashMap::new(),
+        completion_fraction: None,
+        last_modified: None,
     });
 
     // Add some not implemented controls (these will show as gaps)
@@ -136,6 +146,8 @@ fn create_sample_implementation() -> CurrentImplementation {
         implementation_date: None,
         evidence: vec![],
         parameters: HashMap::new(),
+        completion_fraction: None,
+        last_modified: None,
     });
 
     CurrentImplementation {
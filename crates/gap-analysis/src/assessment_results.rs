@@ -0,0 +1,217 @@
+// Modified: 2025-09-20
+
+//! OSCAL Assessment Results Export
+//!
+//! Converts a gap analysis workflow result into an OSCAL `assessment-results`
+//! document for GRC tools. The analysis becomes a single result reviewing the
+//! whole baseline; each gap becomes an observation and a finding targeting the
+//! gap's control, annotated with its severity and priority. The document is
+//! assembled by the shared [`AssessmentResultsBuilder`]; the imported plan is
+//! a back-matter resource naming the framework and baseline the analysis was
+//! run against.
+
+use document_parser::oscal::{
+    AssessmentResultsBuilder, ControlSelection, IncludeAll, MetadataBuilder, OscalAssessmentResultsDocument,
+    OscalFinding, OscalImplementationStatus, OscalObservation, OscalProperty, OscalRelatedObservation,
+    OscalSchemaValidator, OscalTarget, OscalUtils, ReviewedControls,
+};
+use fedramp_core::Result;
+
+use crate::engine::{Gap, GapSeverity, ImplementationStatus};
+use crate::GapAnalysisWorkflowResult;
+
+/// Convert a workflow result into a validated OSCAL assessment results document
+pub fn to_assessment_results(workflow: &GapAnalysisWorkflowResult) -> Result<OscalAssessmentResultsDocument> {
+    let analysis = &workflow.analysis_result;
+    let system_name = analysis.system_ids.join(", ");
+    let mut builder = AssessmentResultsBuilder::new(
+        analysis.analysis_timestamp,
+        Some(vec![OscalUtils::create_property("engine-version", &analysis.metadata.engine_version, None)]),
+        &format!("{} {} baseline gap analysis", analysis.framework_id, analysis.baseline_profile),
+        &format!(
+            "Controls of the {} {} baseline compared with the current implementation of {}",
+            analysis.framework_id, analysis.baseline_profile, system_name
+        ),
+        vec![
+            OscalUtils::create_property("framework-id", &analysis.framework_id, None),
+            OscalUtils::create_property("baseline-profile", &analysis.baseline_profile, None),
+            OscalUtils::create_property("analysis-id", &analysis.analysis_id, None),
+        ],
+    );
+
+    for gap in &analysis.gaps {
+        let observation_uuid = builder.generate_uuid();
+        builder.add_observation(OscalObservation {
+            uuid: observation_uuid.clone(),
+            title: Some(format!("{} gap analysis", gap.control_id)),
+            description: gap.description.clone(),
+            props: None,
+            methods: vec!["EXAMINE".to_string()],
+            types: Some(vec!["finding".to_string()]),
+            origins: builder.origins().to_vec(),
+            subjects: None,
+            relevant_evidence: None,
+            collected: builder.collected().to_string(),
+            expires: None,
+            remarks: None,
+        });
+        let finding_uuid = builder.generate_uuid();
+        let origins = builder.origins().to_vec();
+        builder.add_finding(OscalFinding {
+            uuid: finding_uuid,
+            title: format!("{} {} gap", gap.control_id, gap.gap_type),
+            description: gap.description.clone(),
+            props: Some(finding_props(workflow, gap)),
+            links: None,
+            origins,
+            target: OscalTarget {
+                target_type: "objective-id".to_string(),
+                target_id: oscal_control_id(&gap.control_id),
+                title: Some(gap.control_id.clone()),
+                description: None,
+                props: None,
+                links: None,
+                status: Some(OscalImplementationStatus {
+                    state: "not-satisfied".to_string(),
+                    reason: Some(gap.gap_type.to_string()),
+                }),
+                implementation_status: Some(OscalImplementationStatus {
+                    state: oscal_state(&gap.current_status).to_string(),
+                    reason: None,
+                }),
+            },
+            implementation_statement_uuid: None,
+            related_observations: Some(vec![OscalRelatedObservation { observation_uuid }]),
+            related_risks: None,
+        });
+    }
+
+    let document = builder.build(
+        MetadataBuilder::new().build_assessment_results_metadata(&system_name, None),
+        format!("Gap analysis {}", analysis.analysis_id),
+        format!(
+            "{} gaps against the {} {} baseline for {}",
+            analysis.summary.total_gaps, analysis.framework_id, analysis.baseline_profile, system_name
+        ),
+        vec![
+            OscalUtils::create_property("workflow-id", &workflow.workflow_metadata.workflow_id, None),
+            OscalUtils::create_property(
+                "compliance-score",
+                &format!("{:.2}", analysis.summary.overall_compliance_score),
+                None,
+            ),
+        ],
+        ReviewedControls {
+            description: Some(format!("Controls of the {} {} baseline", analysis.framework_id, analysis.baseline_profile)),
+            control_selections: vec![ControlSelection {
+                include_all: Some(IncludeAll { include_controls: None }),
+                include_controls: None,
+            }],
+        },
+    );
+
+    OscalSchemaValidator::new().validate_assessment_results_document(&document)?;
+    Ok(document)
+}
+
+/// Severity, gap type and, when prioritized, rank and score of a gap
+fn finding_props(workflow: &GapAnalysisWorkflowResult, gap: &Gap) -> Vec<OscalProperty> {
+    let severity = match gap.severity {
+        GapSeverity::Critical => "critical",
+        GapSeverity::High => "high",
+        GapSeverity::Medium => "medium",
+        GapSeverity::Low => "low",
+        GapSeverity::Informational => "informational",
+    };
+    let mut props = vec![
+        OscalUtils::create_property("severity", severity, None),
+        OscalUtils::create_property("gap-type", &gap.gap_type.to_string(), None),
+    ];
+    if let Some(prioritized) = workflow.prioritized_gaps.iter().find(|prioritized| prioritized.gap.gap_id == gap.gap_id) {
+        props.push(OscalUtils::create_property("priority-rank", &prioritized.priority_rank.to_string(), None));
+        props.push(OscalUtils::create_property("priority-score", &format!("{:.2}", prioritized.priority_score), None));
+    }
+    props
+}
+
+/// OSCAL form of a control ID, e.g. `AC-2(3)` becomes `ac-2.3`
+fn oscal_control_id(control_id: &str) -> String {
+    control_id.chars()
+        .filter(|c| !c.is_whitespace() && *c != ')')
+        .map(|c| if c == '(' { '.' } else { c.to_ascii_lowercase() })
+        .collect()
+}
+
+/// OSCAL implementation state of a status
+fn oscal_state(status: &ImplementationStatus) -> &'static str {
+    match status {
+        ImplementationStatus::Implemented => "implemented",
+        ImplementationStatus::PartiallyImplemented => "partial",
+        ImplementationStatus::Planned => "planned",
+        ImplementationStatus::NotApplicable => "not-applicable",
+        ImplementationStatus::NotImplemented => "not-implemented",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::tests::gap;
+    use crate::{GapAnalysisEngine, PrioritizationEngine, WorkflowMetadata};
+    use std::collections::HashMap;
+
+    async fn workflow_result(gaps: Vec<Gap>) -> GapAnalysisWorkflowResult {
+        let (current, baseline) = crate::engine::tests::fixtures();
+        let mut analysis_result = GapAnalysisEngine::new().analyze_gaps(&current, &baseline).await.unwrap();
+        analysis_result.gaps = gaps;
+        analysis_result.summary.total_gaps = analysis_result.gaps.len();
+
+        let prioritization = PrioritizationEngine::new();
+        let prioritized_gaps = prioritization.prioritize_gaps(&analysis_result.gaps).await.unwrap();
+        let prioritization_matrix = prioritization.generate_prioritization_matrix(&prioritized_gaps).unwrap();
+
+        GapAnalysisWorkflowResult {
+            analysis_result,
+            prioritized_gaps,
+            prioritization_matrix,
+            remediation_plan: None,
+            workflow_metadata: WorkflowMetadata {
+                workflow_id: "workflow-1".to_string(),
+                execution_time_ms: 0,
+                steps_completed: Vec::new(),
+                warnings: Vec::new(),
+                performance_metrics: HashMap::new(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_each_gap_becomes_a_finding_targeting_its_control() {
+        let workflow = workflow_result(vec![gap("AC-2(3)", GapSeverity::High), gap("AU-2", GapSeverity::Low)]).await;
+
+        let document = to_assessment_results(&workflow).unwrap();
+
+        let result = &document.assessment_results.results[0];
+        let findings = result.findings.as_ref().unwrap();
+        let targets: Vec<&str> = findings.iter().map(|finding| finding.target.target_id.as_str()).collect();
+        assert_eq!(targets, vec!["ac-2.3", "au-2"]);
+        assert!(findings.iter().all(|finding| finding.target.target_type == "objective-id"));
+
+        let observations = result.observations.as_ref().unwrap();
+        assert_eq!(observations.len(), 2);
+        let related = &findings[0].related_observations.as_ref().unwrap()[0];
+        assert_eq!(related.observation_uuid, observations[0].uuid);
+
+        let rank = findings[0].props.as_ref().unwrap().iter().find(|prop| prop.name == "priority-rank").unwrap();
+        assert_eq!(rank.value, "1");
+
+        let json = serde_json::to_value(&document).unwrap();
+        assert_eq!(json["assessment-results"]["results"][0]["reviewed-controls"]["control-selections"][0]["include-all"], serde_json::json!({}));
+
+        // The imported plan resolves to a back-matter resource
+        let href = json["assessment-results"]["import-ap"]["href"].as_str().unwrap();
+        let plan = &json["assessment-results"]["back-matter"]["resources"][0];
+        assert_eq!(href, format!("#{}", plan["uuid"].as_str().unwrap()));
+        assert_eq!(plan["props"][1]["value"], workflow.analysis_result.baseline_profile.as_str());
+    }
+}
@@ -3,20 +3,32 @@
 //! Handles loading, caching, and managing framework baselines for gap analysis.
 
 use fedramp_core::{Result, Error};
+use fedramp_core::clock::{system_clock, SharedClock};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use chrono::{DateTime, Utc};
 use crate::engine::{TargetBaseline, RequiredControl, ImplementationStatus, BaselineMetadata, ParameterRequirement};
+use crate::retry::RetryPolicy;
+use tracing::warn;
 
 /// Baseline manager for loading and caching framework baselines
+///
+/// Generic over the [`BaselineStore`] baselines are read from. The default
+/// [`BaselineSource`] covers the JSON mappings file, OSCAL profiles and
+/// caller-supplied loaders.
 #[derive(Debug, Clone)]
-pub struct BaselineManager {
+pub struct BaselineManager<S: BaselineStore = BaselineSource> {
     /// Cached baselines
     baselines: HashMap<String, CachedBaseline>,
-    /// JSON baseline loader
-    json_loader: Option<JsonBaselineLoader>,
+    /// Store baselines are loaded from
+    store: Arc<S>,
     /// Configuration
     config: BaselineConfig,
+    /// Time source for cache expiry
+    clock: SharedClock,
+    /// Retry policy for transient store failures
+    retry_policy: RetryPolicy,
 }
 
 /// Cached baseline with metadata
@@ -37,8 +49,37 @@ pub struct BaselineConfig {
     pub validation_enabled: bool,
 }
 
+/// Storage backend for framework baselines
+///
+/// Implement this to serve baselines from object storage or a database without
+/// touching the engine. Calls are made on the blocking thread pool.
+pub trait BaselineStore: Send + Sync + std::fmt::Debug {
+    /// Load the baseline for a framework profile
+    fn get(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline>;
+    /// Frameworks the store holds baselines for
+    fn list_frameworks(&self) -> Result<Vec<String>>;
+    /// Profiles available for a framework
+    fn list_profiles(&self, framework_id: &str) -> Result<Vec<String>>;
+    /// Validate a baseline loaded from this store
+    fn validate(&self, baseline: &TargetBaseline) -> Result<ValidationResult>;
+}
+
+/// Built-in baseline stores
+#[derive(Debug, Clone, Default)]
+pub enum BaselineSource {
+    /// No source configured; every baseline lookup fails
+    #[default]
+    Unconfigured,
+    /// Local JSON control mappings file
+    Json(JsonBaselineLoader),
+    /// Single OSCAL profile
+    OscalProfile(OscalProfileLoader),
+    /// Caller-supplied loader
+    Loader(Arc<dyn BaselineLoader>),
+}
+
 /// Trait for loading baselines from different sources
-pub trait BaselineLoader: Send + Sync {
+pub trait BaselineLoader: Send + Sync + std::fmt::Debug {
     fn load_baseline(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline>;
     fn get_available_profiles(&self, framework_id: &str) -> Result<Vec<String>>;
     fn validate_baseline(&self, baseline: &TargetBaseline) -> Result<ValidationResult>;
@@ -51,6 +92,17 @@ pub struct JsonBaselineLoader {
     pub control_mappings: ControlMappings,
 }
 
+/// OSCAL profile baseline loader for a single framework profile
+#[derive(Debug, Clone)]
+pub struct OscalProfileLoader {
+    pub profile_path: String,
+    pub framework_id: String,
+    pub profile_name: String,
+    pub version: String,
+    /// Control IDs selected by the profile, as written in the profile
+    pub control_ids: Vec<String>,
+}
+
 /// Control mappings structure from JSON files
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ControlMappings {
@@ -143,17 +195,11 @@ pub struct ValidationStatistics {
 impl BaselineManager {
     /// Create a new baseline manager
     pub fn new() -> Self {
-        Self {
-            baselines: HashMap::new(),
-            json_loader: None,
-            config: BaselineConfig::default(),
-        }
+        Self::with_store(BaselineSource::Unconfigured)
     }
 
     /// Create baseline manager with JSON loader
     pub fn with_json_loader(mappings_path: String) -> Result<Self> {
-        let mut manager = Self::new();
-        
         // Load control mappings from JSON file
         let mappings_content = std::fs::read_to_string(&mappings_path)
             .map_err(|e| Error::document_parsing(format!("Failed to read mappings file: {}", e)))?;
@@ -166,8 +212,51 @@ impl BaselineManager {
             control_mappings,
         };
 
-        manager.json_loader = Some(json_loader);
-        Ok(manager)
+        Ok(Self::with_store(BaselineSource::Json(json_loader)))
+    }
+
+    /// Create baseline manager with an OSCAL profile loader
+    pub fn with_oscal_profile(profile_path: String, framework_id: String, profile_name: String) -> Result<Self> {
+        let loader = OscalProfileLoader::from_file(profile_path, framework_id, profile_name)?;
+        Ok(Self::with_store(BaselineSource::OscalProfile(loader)))
+    }
+
+    /// Create baseline manager with a caller-supplied loader
+    ///
+    /// The loader runs on the blocking thread pool, so a slow load does not
+    /// stall the async runtime and can be bounded by a timeout.
+    pub fn with_loader(loader: Arc<dyn BaselineLoader>) -> Self {
+        Self::with_store(BaselineSource::Loader(loader))
+    }
+}
+
+impl<S: BaselineStore + 'static> BaselineManager<S> {
+    /// Create baseline manager reading from the given store
+    pub fn with_store(store: S) -> Self {
+        Self {
+            baselines: HashMap::new(),
+            store: Arc::new(store),
+            config: BaselineConfig::default(),
+            clock: system_clock(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Use the given time source for cache expiry
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Retry transient store failures according to the given policy
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Replace the retry policy for transient store failures
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
     }
 
     /// Get baseline for framework and profile
@@ -176,7 +265,7 @@ impl BaselineManager {
         
         // Check cache first
         if let Some(cached) = self.baselines.get(&cache_key) {
-            if cached.cached_at + cached.cache_ttl > Utc::now() {
+            if cached.cached_at + cached.cache_ttl > self.clock.now() {
                 return Ok(cached.baseline.clone());
             }
         }
@@ -190,21 +279,40 @@ impl BaselineManager {
         Ok(baseline)
     }
 
-    /// Load baseline from configured source
+    /// Load baseline from the store, retrying transient failures with backoff
     async fn load_baseline_from_source(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline> {
-        // Try JSON loader first
-        if let Some(loader) = &self.json_loader {
-            return loader.load_baseline(framework_id, profile);
+        let mut attempt = 1;
+        loop {
+            match self.read_store(framework_id, profile).await {
+                Err(e) if self.retry_policy.should_retry(attempt, &e) => {
+                    let delay = self.retry_policy.backoff(attempt);
+                    warn!(
+                        "Baseline load for {}:{} failed (attempt {} of {}), retrying in {:?}: {}",
+                        framework_id, profile, attempt, self.retry_policy.max_attempts, delay, e
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
         }
+    }
 
-        Err(fedramp_core::Error::not_found(format!("No loader available for framework: {}", framework_id)))
+    /// Read a baseline from the store on the blocking thread pool
+    async fn read_store(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline> {
+        let store = Arc::clone(&self.store);
+        let framework_id = framework_id.to_string();
+        let profile = profile.to_string();
+        tokio::task::spawn_blocking(move || store.get(&framework_id, &profile))
+            .await
+            .map_err(|e| Error::internal(format!("Baseline loader task failed: {}", e)))?
     }
 
     /// Cache baseline with TTL
     async fn cache_baseline(&mut self, cache_key: String, baseline: TargetBaseline) -> Result<()> {
         let cached_baseline = CachedBaseline {
             baseline,
-            cached_at: Utc::now(),
+            cached_at: self.clock.now(),
             cache_ttl: chrono::Duration::hours(self.config.cache_ttl_hours),
             source_checksum: "".to_string(), // TODO: implement checksum
         };
@@ -215,18 +323,12 @@ impl BaselineManager {
 
     /// Get available frameworks
     pub fn get_available_frameworks(&self) -> Result<Vec<String>> {
-        if let Some(json_loader) = &self.json_loader {
-            return Ok(json_loader.control_mappings.control_mappings.frameworks.keys().cloned().collect());
-        }
-        Ok(Vec::new())
+        self.store.list_frameworks()
     }
 
     /// Get available profiles for a framework
     pub fn get_available_profiles(&self, framework_id: &str) -> Result<Vec<String>> {
-        if let Some(loader) = &self.json_loader {
-            return loader.get_available_profiles(framework_id);
-        }
-        Ok(Vec::new())
+        self.store.list_profiles(framework_id)
     }
 
     /// Validate all cached baselines
@@ -234,16 +336,89 @@ impl BaselineManager {
         let mut results = HashMap::new();
 
         for (cache_key, cached_baseline) in &self.baselines {
-            if let Some(loader) = &self.json_loader {
-                let validation_result = loader.validate_baseline(&cached_baseline.baseline)?;
-                results.insert(cache_key.clone(), validation_result);
-            }
+            let validation_result = self.store.validate(&cached_baseline.baseline)?;
+            results.insert(cache_key.clone(), validation_result);
         }
 
         Ok(results)
     }
 }
 
+impl BaselineStore for BaselineSource {
+    fn get(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline> {
+        match self {
+            BaselineSource::Unconfigured => {
+                Err(Error::not_found(format!("No loader available for framework: {}", framework_id)))
+            }
+            BaselineSource::Json(loader) => loader.get(framework_id, profile),
+            BaselineSource::OscalProfile(loader) => loader.get(framework_id, profile),
+            BaselineSource::Loader(loader) => loader.load_baseline(framework_id, profile),
+        }
+    }
+
+    fn list_frameworks(&self) -> Result<Vec<String>> {
+        match self {
+            BaselineSource::Json(loader) => loader.list_frameworks(),
+            BaselineSource::OscalProfile(loader) => loader.list_frameworks(),
+            BaselineSource::Unconfigured | BaselineSource::Loader(_) => Ok(Vec::new()),
+        }
+    }
+
+    fn list_profiles(&self, framework_id: &str) -> Result<Vec<String>> {
+        match self {
+            BaselineSource::Unconfigured => Ok(Vec::new()),
+            BaselineSource::Json(loader) => loader.list_profiles(framework_id),
+            BaselineSource::OscalProfile(loader) => loader.list_profiles(framework_id),
+            BaselineSource::Loader(loader) => loader.get_available_profiles(framework_id),
+        }
+    }
+
+    fn validate(&self, baseline: &TargetBaseline) -> Result<ValidationResult> {
+        match self {
+            BaselineSource::Unconfigured => Err(Error::configuration("No baseline source configured")),
+            BaselineSource::Json(loader) => loader.validate(baseline),
+            BaselineSource::OscalProfile(loader) => loader.validate(baseline),
+            BaselineSource::Loader(loader) => loader.validate_baseline(baseline),
+        }
+    }
+}
+
+impl BaselineStore for JsonBaselineLoader {
+    fn get(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline> {
+        self.load_baseline(framework_id, profile)
+    }
+
+    fn list_frameworks(&self) -> Result<Vec<String>> {
+        Ok(self.control_mappings.control_mappings.frameworks.keys().cloned().collect())
+    }
+
+    fn list_profiles(&self, framework_id: &str) -> Result<Vec<String>> {
+        self.get_available_profiles(framework_id)
+    }
+
+    fn validate(&self, baseline: &TargetBaseline) -> Result<ValidationResult> {
+        self.validate_baseline(baseline)
+    }
+}
+
+impl BaselineStore for OscalProfileLoader {
+    fn get(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline> {
+        self.load_baseline(framework_id, profile)
+    }
+
+    fn list_frameworks(&self) -> Result<Vec<String>> {
+        Ok(vec![self.framework_id.clone()])
+    }
+
+    fn list_profiles(&self, framework_id: &str) -> Result<Vec<String>> {
+        self.get_available_profiles(framework_id)
+    }
+
+    fn validate(&self, baseline: &TargetBaseline) -> Result<ValidationResult> {
+        self.validate_baseline(baseline)
+    }
+}
+
 impl BaselineLoader for JsonBaselineLoader {
     fn load_baseline(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline> {
         let framework_data = self.control_mappings.control_mappings.frameworks
@@ -395,6 +570,171 @@ impl JsonBaselineLoader {
     }
 }
 
+impl OscalProfileLoader {
+    /// Load an OSCAL profile document from disk
+    pub fn from_file(profile_path: String, framework_id: String, profile_name: String) -> Result<Self> {
+        let profile_content = std::fs::read_to_string(&profile_path)
+            .map_err(|e| Error::document_parsing(format!("Failed to read OSCAL profile: {}", e)))?;
+
+        let document: serde_json::Value = serde_json::from_str(&profile_content)
+            .map_err(|e| Error::document_parsing(format!("Failed to parse OSCAL profile JSON: {}", e)))?;
+
+        Self::from_value(&document, profile_path, framework_id, profile_name)
+    }
+
+    /// Build a loader from a parsed OSCAL profile document
+    pub fn from_value(
+        document: &serde_json::Value,
+        profile_path: String,
+        framework_id: String,
+        profile_name: String,
+    ) -> Result<Self> {
+        let profile = document
+            .get("profile")
+            .ok_or_else(|| Error::document_parsing("OSCAL profile is missing the 'profile' root element"))?;
+
+        let version = profile
+            .pointer("/metadata/version")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        let imports = profile
+            .get("imports")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| Error::document_parsing("OSCAL profile has no imports"))?;
+
+        let mut control_ids = Vec::new();
+        for import in imports {
+            let include_controls = import
+                .get("include-controls")
+                .and_then(|v| v.as_array())
+                .map(|v| v.as_slice())
+                .unwrap_or_default();
+
+            for selection in include_controls {
+                let with_ids = selection
+                    .get("with-ids")
+                    .and_then(|v| v.as_array())
+                    .map(|v| v.as_slice())
+                    .unwrap_or_default();
+
+                for id in with_ids.iter().filter_map(|id| id.as_str()) {
+                    if !control_ids.iter().any(|existing| existing == id) {
+                        control_ids.push(id.to_string());
+                    }
+                }
+            }
+        }
+
+        if control_ids.is_empty() {
+            return Err(Error::document_parsing(format!(
+                "OSCAL profile '{}' does not select any controls",
+                profile_path
+            )));
+        }
+
+        Ok(Self {
+            profile_path,
+            framework_id,
+            profile_name,
+            version,
+            control_ids,
+        })
+    }
+
+    /// Convert an OSCAL control ID (`ac-2.1`) to the catalog form used in gaps (`AC-2(1)`)
+    fn normalize_control_id(control_id: &str) -> String {
+        fedramp_core::validation::validate_control_id(control_id, fedramp_core::models::Framework::Nist80053)
+            .map(|id| id.to_string())
+            .unwrap_or_else(|_| control_id.to_uppercase())
+    }
+}
+
+impl BaselineLoader for OscalProfileLoader {
+    fn load_baseline(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline> {
+        if framework_id != self.framework_id || profile != self.profile_name {
+            return Err(Error::not_found(format!(
+                "Profile not found: {} for framework {}",
+                profile, framework_id
+            )));
+        }
+
+        let mut required_controls: HashMap<String, RequiredControl> = HashMap::new();
+
+        for raw_id in &self.control_ids {
+            let (base_id, enhancement_id) =
+                match fedramp_core::validation::validate_control_id(raw_id, fedramp_core::models::Framework::Nist80053) {
+                    Ok(id) if id.enhancement.is_some() => {
+                        (format!("{}-{}", id.family, id.number), Some(id.to_string()))
+                    }
+                    _ => (Self::normalize_control_id(raw_id), None),
+                };
+
+            let required_control = required_controls
+                .entry(base_id.clone())
+                .or_insert_with(|| RequiredControl {
+                    control_id: base_id,
+                    required_status: ImplementationStatus::Implemented,
+                    enhancements: Vec::new(),
+                    parameters: HashMap::new(),
+                });
+
+            if let Some(enhancement_id) = enhancement_id {
+                required_control.enhancements.push(enhancement_id);
+            }
+        }
+
+        Ok(TargetBaseline {
+            framework_id: self.framework_id.clone(),
+            profile_name: self.profile_name.clone(),
+            required_controls,
+            baseline_metadata: BaselineMetadata {
+                version: self.version.clone(),
+                last_updated: Utc::now(),
+            },
+        })
+    }
+
+    fn get_available_profiles(&self, framework_id: &str) -> Result<Vec<String>> {
+        if framework_id != self.framework_id {
+            return Err(Error::not_found(format!("Framework not found: {}", framework_id)));
+        }
+
+        Ok(vec![self.profile_name.clone()])
+    }
+
+    fn validate_baseline(&self, baseline: &TargetBaseline) -> Result<ValidationResult> {
+        let mut errors = Vec::new();
+        let mut valid_controls = 0;
+
+        for control_id in baseline.required_controls.keys() {
+            match fedramp_core::validation::validate_control_id(control_id, fedramp_core::models::Framework::Nist80053) {
+                Ok(_) => valid_controls += 1,
+                Err(e) => errors.push(ValidationError {
+                    error_type: "invalid_control_id".to_string(),
+                    message: e.to_string(),
+                    control_id: Some(control_id.clone()),
+                }),
+            }
+        }
+
+        let total_controls = baseline.required_controls.len();
+        Ok(ValidationResult {
+            is_valid: errors.is_empty(),
+            statistics: ValidationStatistics {
+                total_controls,
+                valid_controls,
+                invalid_controls: errors.len(),
+                missing_enhancements: 0,
+                missing_parameters: 0,
+            },
+            errors,
+            warnings: Vec::new(),
+        })
+    }
+}
+
 impl Default for BaselineConfig {
     fn default() -> Self {
         Self {
@@ -0,0 +1,129 @@
+// Modified: 2025-09-20
+
+//! Control Criticality
+//!
+//! Floors the severity of gaps on critical controls, so a gap on a control
+//! like `SC-7` outranks one on a low-impact administrative control even when
+//! their generic scores tie. Floors are derived from the `criticality_to_impact`
+//! section of the inventory security mappings and can be overridden per level.
+
+use fedramp_core::{Result, Error};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::engine::GapSeverity;
+
+/// Confidentiality, integrity and availability impact of a criticality level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriticalityImpact {
+    pub confidentiality_impact: String,
+    pub integrity_impact: String,
+    pub availability_impact: String,
+}
+
+impl CriticalityImpact {
+    /// Severity floor for the highest of the three impacts
+    ///
+    /// `high` floors at `High`, `moderate` at `Medium` and `low` at `Low`.
+    pub fn severity_floor(&self) -> Option<GapSeverity> {
+        [&self.confidentiality_impact, &self.integrity_impact, &self.availability_impact]
+            .into_iter()
+            .filter_map(|impact| match impact.to_lowercase().as_str() {
+                "high" => Some(GapSeverity::High),
+                "moderate" | "medium" => Some(GapSeverity::Medium),
+                "low" => Some(GapSeverity::Low),
+                _ => None,
+            })
+            .max_by_key(GapSeverity::rank)
+    }
+}
+
+/// Control criticality configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ControlCriticality {
+    /// Criticality level per control ID, e.g. `SC-7` → `High`
+    #[serde(default)]
+    pub control_criticality: HashMap<String, String>,
+    /// Criticality level to impact, from `security_mappings.criticality_to_impact`
+    #[serde(default)]
+    pub criticality_to_impact: HashMap<String, CriticalityImpact>,
+    /// Severity floors per criticality level, taking precedence over derived floors
+    #[serde(default)]
+    pub severity_floors: HashMap<String, GapSeverity>,
+}
+
+impl ControlCriticality {
+    /// Load `criticality_to_impact` from the security mappings of an inventory mappings file
+    pub fn from_inventory_mappings_file(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::document_parsing(format!("Failed to read inventory mappings file: {}", e)))?;
+
+        let document: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| Error::document_parsing(format!("Failed to parse inventory mappings JSON: {}", e)))?;
+
+        let section = document
+            .pointer("/inventory_mappings/security_mappings/criticality_to_impact")
+            .or_else(|| document.pointer("/security_mappings/criticality_to_impact"))
+            .ok_or_else(|| Error::document_parsing("Inventory mappings have no 'security_mappings.criticality_to_impact' section"))?;
+
+        let criticality_to_impact = serde_json::from_value(section.clone())
+            .map_err(|e| Error::document_parsing(format!("Invalid criticality_to_impact section: {}", e)))?;
+
+        Ok(Self {
+            criticality_to_impact,
+            ..Self::default()
+        })
+    }
+
+    /// Assign a criticality level to a control
+    pub fn with_control(mut self, control_id: impl Into<String>, criticality: impl Into<String>) -> Self {
+        self.control_criticality.insert(control_id.into(), criticality.into());
+        self
+    }
+
+    /// Override the severity floor of a criticality level
+    pub fn with_severity_floor(mut self, criticality: impl Into<String>, floor: GapSeverity) -> Self {
+        self.severity_floors.insert(criticality.into(), floor);
+        self
+    }
+
+    /// Severity floor for gaps on a control, if it has a criticality
+    ///
+    /// Control IDs and criticality levels are compared case-insensitively.
+    pub fn severity_floor_for(&self, control_id: &str) -> Option<GapSeverity> {
+        let criticality = self.control_criticality.iter()
+            .find(|(id, _)| id.eq_ignore_ascii_case(control_id))
+            .map(|(_, criticality)| criticality)?;
+
+        find_level(&self.severity_floors, criticality)
+            .cloned()
+            .or_else(|| find_level(&self.criticality_to_impact, criticality)?.severity_floor())
+    }
+}
+
+/// Entry for a criticality level, compared case-insensitively
+fn find_level<'a, T>(levels: &'a HashMap<String, T>, criticality: &str) -> Option<&'a T> {
+    levels.iter()
+        .find(|(level, _)| level.eq_ignore_ascii_case(criticality))
+        .map(|(_, value)| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floors_derive_from_inventory_criticality_to_impact() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../mappings/inventory_mappings.json");
+        let criticality = ControlCriticality::from_inventory_mappings_file(path).unwrap()
+            .with_control("SC-7", "High")
+            .with_control("PL-4", "Low")
+            .with_control("IR-4", "Critical")
+            .with_severity_floor("critical", GapSeverity::Critical);
+
+        assert_eq!(criticality.severity_floor_for("sc-7"), Some(GapSeverity::High));
+        assert_eq!(criticality.severity_floor_for("PL-4"), Some(GapSeverity::Low));
+        assert_eq!(criticality.severity_floor_for("IR-4"), Some(GapSeverity::Critical));
+        assert_eq!(criticality.severity_floor_for("AC-1"), None);
+    }
+}
@@ -0,0 +1,225 @@
+// Modified: 2025-09-24
+
+//! Gap and Baseline Deltas
+//!
+//! Differences between two gap analysis results (gaps opened, resolved or
+//! changed) and between two baselines (controls added, removed or with a
+//! different requirement), so drift can be reported and gated in CI. Controls
+//! are matched on their normalized identifier, so `ac-2` and `AC-2` are the
+//! same control.
+
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::engine::{
+    framework_for, normalize_control_id, Gap, GapAnalysisResult, GapSeverity, ImplementationStatus, RequiredControl,
+    TargetBaseline,
+};
+
+/// Gap present in both results with a different severity or status
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GapChange {
+    pub control_id: String,
+    pub before_severity: GapSeverity,
+    pub after_severity: GapSeverity,
+    pub before_status: ImplementationStatus,
+    pub after_status: ImplementationStatus,
+}
+
+/// Differences between an earlier and a later gap analysis result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GapDelta {
+    pub framework_id: String,
+    /// Gaps only in the later result, by control ID
+    pub opened: Vec<Gap>,
+    /// Gaps only in the earlier result, by control ID
+    pub resolved: Vec<Gap>,
+    /// Gaps in both results whose severity or current status changed
+    pub changed: Vec<GapChange>,
+    /// Gaps in both results without changes
+    pub unchanged: usize,
+}
+
+impl GapDelta {
+    /// Compare two results for the same framework
+    pub fn between(before: &GapAnalysisResult, after: &GapAnalysisResult) -> Result<Self> {
+        if before.framework_id != after.framework_id {
+            return Err(Error::validation(format!(
+                "Cannot compare gap analysis results for {} and {}",
+                before.framework_id, after.framework_id
+            )));
+        }
+        let framework = framework_for(&before.framework_id);
+        let index = |result: &GapAnalysisResult| -> BTreeMap<String, Gap> {
+            result.gaps.iter()
+                .map(|gap| (normalize_control_id(&gap.control_id, framework.as_ref()), gap.clone()))
+                .collect()
+        };
+        let (mut before_gaps, mut after_gaps) = (index(before), index(after));
+
+        let mut delta = GapDelta {
+            framework_id: before.framework_id.clone(),
+            opened: Vec::new(),
+            resolved: Vec::new(),
+            changed: Vec::new(),
+            unchanged: 0,
+        };
+        let control_ids: BTreeSet<String> = before_gaps.keys().chain(after_gaps.keys()).cloned().collect();
+        for control_id in control_ids {
+            match (before_gaps.remove(&control_id), after_gaps.remove(&control_id)) {
+                (Some(old), Some(new)) => {
+                    if old.severity == new.severity && old.current_status == new.current_status {
+                        delta.unchanged += 1;
+                    } else {
+                        delta.changed.push(GapChange {
+                            control_id,
+                            before_severity: old.severity,
+                            after_severity: new.severity,
+                            before_status: old.current_status,
+                            after_status: new.current_status,
+                        });
+                    }
+                }
+                (Some(old), None) => delta.resolved.push(old),
+                (None, Some(new)) => delta.opened.push(new),
+                (None, None) => {}
+            }
+        }
+        Ok(delta)
+    }
+
+    /// Number of opened, resolved and changed gaps
+    pub fn difference_count(&self) -> usize {
+        self.opened.len() + self.resolved.len() + self.changed.len()
+    }
+}
+
+/// Control required by both baselines with a different requirement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineControlChange {
+    pub control_id: String,
+    pub before_status: ImplementationStatus,
+    pub after_status: ImplementationStatus,
+    pub added_enhancements: Vec<String>,
+    pub removed_enhancements: Vec<String>,
+}
+
+/// Differences between an earlier and a later baseline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineDelta {
+    pub framework_id: String,
+    pub before_profile: String,
+    pub after_profile: String,
+    /// Controls only required by the later baseline
+    pub added: Vec<String>,
+    /// Controls only required by the earlier baseline
+    pub removed: Vec<String>,
+    /// Controls whose required status or enhancements changed
+    pub changed: Vec<BaselineControlChange>,
+    /// Controls required identically by both baselines
+    pub unchanged: usize,
+}
+
+impl BaselineDelta {
+    /// Compare two baselines of the same framework
+    pub fn between(before: &TargetBaseline, after: &TargetBaseline) -> Result<Self> {
+        if before.framework_id != after.framework_id {
+            return Err(Error::validation(format!(
+                "Cannot compare baselines for {} and {}",
+                before.framework_id, after.framework_id
+            )));
+        }
+        let framework = framework_for(&before.framework_id);
+        let index = |baseline: &TargetBaseline| -> BTreeMap<String, RequiredControl> {
+            baseline.required_controls.values()
+                .map(|control| (normalize_control_id(&control.control_id, framework.as_ref()), control.clone()))
+                .collect()
+        };
+        let (before_controls, after_controls) = (index(before), index(after));
+
+        let mut delta = BaselineDelta {
+            framework_id: before.framework_id.clone(),
+            before_profile: before.profile_name.clone(),
+            after_profile: after.profile_name.clone(),
+            added: after_controls.keys().filter(|id| !before_controls.contains_key(*id)).cloned().collect(),
+            removed: before_controls.keys().filter(|id| !after_controls.contains_key(*id)).cloned().collect(),
+            changed: Vec::new(),
+            unchanged: 0,
+        };
+        for (control_id, old) in &before_controls {
+            let Some(new) = after_controls.get(control_id) else { continue };
+            let old_enhancements: BTreeSet<&String> = old.enhancements.iter().collect();
+            let new_enhancements: BTreeSet<&String> = new.enhancements.iter().collect();
+            if old.required_status == new.required_status && old_enhancements == new_enhancements {
+                delta.unchanged += 1;
+                continue;
+            }
+            delta.changed.push(BaselineControlChange {
+                control_id: control_id.clone(),
+                before_status: old.required_status.clone(),
+                after_status: new.required_status.clone(),
+                added_enhancements: new_enhancements.difference(&old_enhancements).map(|id| (*id).clone()).collect(),
+                removed_enhancements: old_enhancements.difference(&new_enhancements).map(|id| (*id).clone()).collect(),
+            });
+        }
+        Ok(delta)
+    }
+
+    /// Number of added, removed and changed controls
+    pub fn difference_count(&self) -> usize {
+        self.added.len() + self.removed.len() + self.changed.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::tests::{fixtures, gap};
+    use crate::GapAnalysisEngine;
+
+    #[tokio::test]
+    async fn test_gap_delta_reports_opened_resolved_and_changed_gaps() {
+        let (current, baseline) = fixtures();
+        let engine = GapAnalysisEngine::new();
+        let mut before = engine.analyze_gaps(&current, &baseline).await.unwrap();
+        let mut after = before.clone();
+        before.gaps = vec![gap("AC-1", GapSeverity::High), gap("AC-2", GapSeverity::Medium), gap("AU-2", GapSeverity::Low)];
+        after.gaps = vec![gap("ac-2", GapSeverity::Critical), gap("AU-2", GapSeverity::Low), gap("SC-7", GapSeverity::High)];
+
+        let delta = GapDelta::between(&before, &after).unwrap();
+        assert_eq!(delta.opened.iter().map(|gap| gap.control_id.as_str()).collect::<Vec<_>>(), ["SC-7"]);
+        assert_eq!(delta.resolved.iter().map(|gap| gap.control_id.as_str()).collect::<Vec<_>>(), ["AC-1"]);
+        assert_eq!(delta.changed.len(), 1);
+        assert_eq!(delta.changed[0].control_id, "AC-2");
+        assert_eq!(delta.changed[0].after_severity, GapSeverity::Critical);
+        assert_eq!(delta.unchanged, 1);
+        assert_eq!(delta.difference_count(), 3);
+
+        after.framework_id = "nist-800-171".to_string();
+        assert!(GapDelta::between(&before, &after).is_err());
+    }
+
+    #[test]
+    fn test_baseline_delta_reports_control_and_enhancement_changes() {
+        let (_, before) = fixtures();
+        let mut after = before.clone();
+        after.profile_name = "high".to_string();
+        after.required_controls.remove("AU-2");
+        after.required_controls.get_mut("AC-2").unwrap().enhancements = vec!["AC-2(1)".to_string()];
+        after.required_controls.insert("SC-7".to_string(), RequiredControl {
+            control_id: "SC-7".to_string(),
+            required_status: ImplementationStatus::Implemented,
+            enhancements: Vec::new(),
+            parameters: Default::default(),
+        });
+
+        let delta = BaselineDelta::between(&before, &after).unwrap();
+        assert_eq!(delta.added, ["SC-7"]);
+        assert_eq!(delta.removed, ["AU-2"]);
+        assert_eq!(delta.changed.len(), 1);
+        assert_eq!(delta.changed[0].added_enhancements, ["AC-2(1)"]);
+        assert_eq!(delta.unchanged, 1);
+        assert_eq!(delta.difference_count(), 3);
+    }
+}
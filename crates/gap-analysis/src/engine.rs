@@ -5,9 +5,11 @@
 
 use fedramp_core::{Result, Error};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use crate::criticality::ControlCriticality;
+use crate::inheritance::{ControlInheritance, InheritanceLevel, InheritedControl};
 
 /// Gap analysis engine for comparing implementations against baselines
 #[derive(Debug, Clone)]
@@ -20,6 +22,10 @@ pub struct GapAnalysisEngine {
     pub severity_scorer: SeverityScorer,
     /// Configuration settings
     pub config: GapAnalysisConfig,
+    /// Controls inherited from authorized infrastructure or platform providers
+    pub control_inheritance: Option<ControlInheritance>,
+    /// Control criticality used to floor gap severity
+    pub control_criticality: Option<ControlCriticality>,
 }
 
 /// Baseline comparison algorithms
@@ -112,6 +118,92 @@ pub struct GapAnalysisResult {
     pub summary: GapSummary,
     pub recommendations: Vec<Recommendation>,
     pub metadata: GapAnalysisMetadata,
+    /// Baseline controls credited through inheritance
+    #[serde(default)]
+    pub inherited_controls: Vec<InheritedControl>,
+    /// Systems covered by the analysis; several for a merged result
+    #[serde(default)]
+    pub system_ids: Vec<String>,
+}
+
+impl GapAnalysisResult {
+    /// Consolidate analyses of several systems in one authorization boundary
+    ///
+    /// Gaps are unioned and de-duplicated by control ID. Each merged gap keeps
+    /// the details of its most severe occurrence and lists every system that
+    /// reported it in `contributing_systems`. The summary and recommendations
+    /// are regenerated from the merged gaps so their counts match. All results
+    /// must be for the same framework and baseline profile.
+    pub fn merge(results: &[GapAnalysisResult]) -> Result<GapAnalysisResult> {
+        let first = results.first()
+            .ok_or_else(|| Error::validation("At least one gap analysis result is required to merge"))?;
+        if let Some(other) = results.iter().find(|result| {
+            result.framework_id != first.framework_id || result.baseline_profile != first.baseline_profile
+        }) {
+            return Err(Error::validation(format!(
+                "Cannot merge results for {}:{} with results for {}:{}",
+                other.framework_id, other.baseline_profile, first.framework_id, first.baseline_profile
+            )));
+        }
+
+        let mut merged_gaps: HashMap<String, Gap> = HashMap::new();
+        let mut system_ids: Vec<String> = Vec::new();
+        let mut inherited_controls: HashMap<String, InheritedControl> = HashMap::new();
+
+        for result in results {
+            system_ids.extend(result.system_ids.iter().cloned());
+            for inherited in &result.inherited_controls {
+                inherited_controls.entry(inherited.control_id.clone()).or_insert_with(|| inherited.clone());
+            }
+
+            for gap in &result.gaps {
+                let mut gap = gap.clone();
+                if gap.contributing_systems.is_empty() {
+                    gap.contributing_systems = result.system_ids.clone();
+                }
+
+                match merged_gaps.get_mut(&gap.control_id) {
+                    Some(existing) => {
+                        let mut systems = std::mem::take(&mut existing.contributing_systems);
+                        systems.append(&mut gap.contributing_systems);
+                        if gap.severity.rank() > existing.severity.rank() {
+                            *existing = gap;
+                        }
+                        existing.contributing_systems = sorted_unique(systems);
+                    }
+                    None => {
+                        gap.contributing_systems = sorted_unique(gap.contributing_systems);
+                        merged_gaps.insert(gap.control_id.clone(), gap);
+                    }
+                }
+            }
+        }
+
+        let mut gaps: Vec<Gap> = merged_gaps.into_values().collect();
+        gaps.sort_by(|a, b| a.control_id.cmp(&b.control_id));
+        let mut inherited_controls: Vec<InheritedControl> = inherited_controls.into_values().collect();
+        inherited_controls.sort_by(|a, b| a.control_id.cmp(&b.control_id));
+
+        Ok(GapAnalysisResult {
+            analysis_id: Uuid::new_v4().to_string(),
+            framework_id: first.framework_id.clone(),
+            baseline_profile: first.baseline_profile.clone(),
+            analysis_timestamp: Utc::now(),
+            summary: summarize_gaps(&gaps),
+            recommendations: recommend_for_gaps(&gaps),
+            gaps,
+            metadata: first.metadata.clone(),
+            inherited_controls,
+            system_ids: sorted_unique(system_ids),
+        })
+    }
+}
+
+/// Sort and de-duplicate system IDs
+fn sorted_unique(mut ids: Vec<String>) -> Vec<String> {
+    ids.sort();
+    ids.dedup();
+    ids
 }
 
 /// Individual gap identified in the analysis
@@ -127,6 +219,9 @@ pub struct Gap {
     pub required_status: ImplementationStatus,
     pub impact_assessment: ImpactAssessment,
     pub remediation_guidance: RemediationGuidance,
+    /// Systems that reported this gap
+    #[serde(default)]
+    pub contributing_systems: Vec<String>,
 }
 
 /// Types of gaps that can be identified
@@ -171,8 +266,45 @@ pub enum GapSeverity {
     Informational,
 }
 
+impl GapSeverity {
+    /// The next lower severity level
+    pub fn reduced(&self) -> Self {
+        match self {
+            GapSeverity::Critical => GapSeverity::High,
+            GapSeverity::High => GapSeverity::Medium,
+            GapSeverity::Medium => GapSeverity::Low,
+            GapSeverity::Low | GapSeverity::Informational => GapSeverity::Informational,
+        }
+    }
+
+    /// Numeric level, from 0 for `Informational` to 4 for `Critical`
+    pub fn rank(&self) -> u8 {
+        match self {
+            GapSeverity::Critical => 4,
+            GapSeverity::High => 3,
+            GapSeverity::Medium => 2,
+            GapSeverity::Low => 1,
+            GapSeverity::Informational => 0,
+        }
+    }
+
+    /// Severity scaled by the fraction of the control still to be implemented
+    ///
+    /// Levels are scaled proportionally and rounded up, so any remaining work
+    /// above zero keeps at least `Low` severity.
+    pub fn scaled(&self, remaining_fraction: f64) -> Self {
+        match (self.rank() as f64 * remaining_fraction.clamp(0.0, 1.0)).ceil() as u8 {
+            0 => GapSeverity::Informational,
+            1 => GapSeverity::Low,
+            2 => GapSeverity::Medium,
+            3 => GapSeverity::High,
+            _ => GapSeverity::Critical,
+        }
+    }
+}
+
 /// Implementation status for controls
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub enum ImplementationStatus {
     NotImplemented,
@@ -182,6 +314,37 @@ pub enum ImplementationStatus {
     NotApplicable,
 }
 
+impl From<ImplementationStatus> for fedramp_core::types::ImplementationStatus {
+    fn from(status: ImplementationStatus) -> Self {
+        match status {
+            ImplementationStatus::NotImplemented => Self::NotImplemented,
+            ImplementationStatus::Planned => Self::Planned,
+            ImplementationStatus::PartiallyImplemented => Self::PartiallyImplemented,
+            ImplementationStatus::Implemented => Self::Implemented,
+            ImplementationStatus::NotApplicable => Self::NotApplicable,
+        }
+    }
+}
+
+impl TryFrom<fedramp_core::types::ImplementationStatus> for ImplementationStatus {
+    type Error = Error;
+
+    fn try_from(status: fedramp_core::types::ImplementationStatus) -> Result<Self> {
+        use fedramp_core::types::ImplementationStatus as Shared;
+        match status {
+            Shared::NotImplemented => Ok(Self::NotImplemented),
+            Shared::Planned => Ok(Self::Planned),
+            Shared::PartiallyImplemented => Ok(Self::PartiallyImplemented),
+            Shared::Implemented => Ok(Self::Implemented),
+            Shared::NotApplicable => Ok(Self::NotApplicable),
+            Shared::InProgress | Shared::AlternativeImplementation => Err(Error::validation(format!(
+                "Implementation status '{}' is not supported by gap analysis",
+                status
+            ))),
+        }
+    }
+}
+
 /// Impact assessment for a gap
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImpactAssessment {
@@ -264,33 +427,414 @@ impl GapAnalysisEngine {
             gap_detector: GapDetector::new(),
             severity_scorer: SeverityScorer::new(),
             config: GapAnalysisConfig::default(),
+            control_inheritance: None,
+            control_criticality: None,
         }
     }
 
+    /// Credit controls inherited through the given inheritance mappings
+    pub fn with_control_inheritance(mut self, control_inheritance: ControlInheritance) -> Self {
+        self.control_inheritance = Some(control_inheritance);
+        self
+    }
+
+    /// Floor gap severity on critical controls using the given criticality map
+    pub fn with_control_criticality(mut self, control_criticality: ControlCriticality) -> Self {
+        self.control_criticality = Some(control_criticality);
+        self
+    }
+
     /// Perform comprehensive gap analysis
     pub async fn analyze_gaps(
         &self,
         current_implementation: &CurrentImplementation,
         target_baseline: &TargetBaseline,
     ) -> Result<GapAnalysisResult> {
-        let analysis_id = Uuid::new_v4().to_string();
-        let analysis_timestamp = Utc::now();
+        let scored_gaps = self.detect_scored_gaps(current_implementation, target_baseline).await?;
+        self.build_result(&current_implementation.system_id, target_baseline, scored_gaps)
+    }
+
+    /// Re-run gap analysis for changed controls only
+    ///
+    /// Gaps from `previous_result` are reused for every control not listed in
+    /// `changed_control_ids`; the listed controls are compared again and their
+    /// gaps replace the previous ones. Summary and recommendations are
+    /// regenerated from the merged gaps. An empty change set returns the
+    /// previous result unchanged.
+    pub async fn analyze_gaps_incremental(
+        &self,
+        current_implementation: &CurrentImplementation,
+        target_baseline: &TargetBaseline,
+        previous_result: &GapAnalysisResult,
+        changed_control_ids: &[String],
+    ) -> Result<GapAnalysisResult> {
+        if previous_result.framework_id != target_baseline.framework_id
+            || previous_result.baseline_profile != target_baseline.profile_name
+        {
+            return Err(Error::validation(format!(
+                "Previous result is for {}:{}, cannot update it against baseline {}:{}",
+                previous_result.framework_id,
+                previous_result.baseline_profile,
+                target_baseline.framework_id,
+                target_baseline.profile_name
+            )));
+        }
+
+        if changed_control_ids.is_empty() {
+            return Ok(previous_result.clone());
+        }
+
+        let changed: HashSet<&str> = changed_control_ids.iter().map(String::as_str).collect();
+        let recomputed_gaps = self.detect_changed_gaps(current_implementation, target_baseline, &changed).await?;
+
+        let mut gaps: Vec<Gap> = previous_result.gaps.iter()
+            .filter(|gap| !changed.contains(gap.control_id.as_str()))
+            .cloned()
+            .collect();
+        gaps.extend(recomputed_gaps);
+
+        self.build_result(&current_implementation.system_id, target_baseline, gaps)
+    }
+
+    /// Gap analysis of the controls changed since a point in time
+    ///
+    /// Only controls whose implementation data changed at or after `since`
+    /// (see [`CurrentImplementation::controls_changed_since`]) are compared, so
+    /// the result holds just their gaps, matching what a full run reports for
+    /// those controls.
+    pub async fn analyze_gaps_since(
+        &self,
+        current_implementation: &CurrentImplementation,
+        target_baseline: &TargetBaseline,
+        since: DateTime<Utc>,
+    ) -> Result<GapAnalysisResult> {
+        let changed_control_ids = current_implementation.controls_changed_since(since);
+        let changed: HashSet<&str> = changed_control_ids.iter().map(String::as_str).collect();
+        let gaps = self.detect_changed_gaps(current_implementation, target_baseline, &changed).await?;
+        self.build_result(&current_implementation.system_id, target_baseline, gaps)
+    }
+
+    /// Cross-check implementation control IDs against the target baseline
+    ///
+    /// Gap detection matches control IDs exactly, so an implementation citing
+    /// `AC-02` for a baseline `AC-2` is treated as extraneous. Every ID that is
+    /// not a baseline control or enhancement is returned. When the framework's
+    /// control ID validator normalizes it to a baseline ID, that ID is the
+    /// suggestion; otherwise the most similar baseline ID is suggested if it is
+    /// close enough.
+    pub fn validate_control_references(
+        &self,
+        current_implementation: &CurrentImplementation,
+        target_baseline: &TargetBaseline,
+    ) -> Vec<UnrecognizedControl> {
+        let framework = framework_for(&target_baseline.framework_id);
+
+        let baseline_ids: Vec<&str> = target_baseline.required_controls.values()
+            .flat_map(|control| {
+                std::iter::once(control.control_id.as_str())
+                    .chain(control.enhancements.iter().map(String::as_str))
+            })
+            .collect();
+        let normalized_ids: HashMap<String, &str> = baseline_ids.iter()
+            .map(|id| (normalize_control_id(id, framework.as_ref()), *id))
+            .collect();
+
+        let mut unrecognized: Vec<UnrecognizedControl> = current_implementation.controls.keys()
+            .filter(|control_id| !baseline_ids.contains(&control_id.as_str()))
+            .map(|control_id| UnrecognizedControl {
+                control_id: control_id.clone(),
+                suggestion: normalized_ids.get(&normalize_control_id(control_id, framework.as_ref()))
+                    .map(|id| id.to_string())
+                    .or_else(|| closest_control_id(control_id, &baseline_ids)),
+            })
+            .collect();
+        unrecognized.sort_by(|a, b| a.control_id.cmp(&b.control_id));
+        unrecognized
+    }
+
+    /// Detect and score gaps with both sides of the comparison restricted to the changed controls
+    async fn detect_changed_gaps(
+        &self,
+        current_implementation: &CurrentImplementation,
+        target_baseline: &TargetBaseline,
+        changed: &HashSet<&str>,
+    ) -> Result<Vec<Gap>> {
+        let changed_implementation = CurrentImplementation {
+            system_id: current_implementation.system_id.clone(),
+            controls: current_implementation.controls.iter()
+                .filter(|(control_id, _)| changed.contains(control_id.as_str()))
+                .map(|(control_id, control)| (control_id.clone(), control.clone()))
+                .collect(),
+            last_updated: current_implementation.last_updated,
+        };
+        let changed_baseline = TargetBaseline {
+            framework_id: target_baseline.framework_id.clone(),
+            profile_name: target_baseline.profile_name.clone(),
+            required_controls: target_baseline.required_controls.iter()
+                .filter(|(control_id, _)| changed.contains(control_id.as_str()))
+                .map(|(control_id, control)| (control_id.clone(), control.clone()))
+                .collect(),
+            baseline_metadata: target_baseline.baseline_metadata.clone(),
+        };
+
+        self.detect_scored_gaps(&changed_implementation, &changed_baseline).await
+    }
+
+    /// Compare, detect and score gaps for an implementation against a baseline
+    async fn detect_scored_gaps(
+        &self,
+        current_implementation: &CurrentImplementation,
+        target_baseline: &TargetBaseline,
+    ) -> Result<Vec<Gap>> {
+        let effective_implementation = self.apply_inheritance(current_implementation, target_baseline);
 
         // Step 1: Compare current implementation against baseline
         let comparison_result = self.baseline_comparator
-            .compare(current_implementation, target_baseline).await?;
+            .compare(&effective_implementation, target_baseline).await?;
 
         // Step 2: Detect gaps using framework-specific logic
-        let gaps = self.gap_detector
+        let mut gaps = self.gap_detector
             .detect_gaps(&comparison_result).await?;
+        gaps.extend(self.detect_parameter_gaps(&effective_implementation, target_baseline));
+
+        // Floors apply to the uncredited severity so inheritance and progress still reduce it
+        let gaps = self.apply_severity_floors(gaps);
+        let gaps = self.credit_inherited_gaps(gaps);
+        let gaps = self.credit_partial_completion(gaps, &effective_implementation)?;
 
         // Step 3: Score gap severity and impact
-        let scored_gaps = self.severity_scorer
-            .score_gaps(&gaps).await?;
+        self.severity_scorer.score_gaps(&gaps).await
+    }
+
+    /// Baseline controls covered by the configured inheritance mappings
+    pub fn inherited_controls(&self, target_baseline: &TargetBaseline) -> Vec<InheritedControl> {
+        let Some(inheritance) = &self.control_inheritance else {
+            return Vec::new();
+        };
+
+        let mut inherited: Vec<InheritedControl> = target_baseline.required_controls.keys()
+            .filter_map(|control_id| inheritance.inheritance_for(control_id))
+            .collect();
+        inherited.sort_by(|a, b| a.control_id.cmp(&b.control_id));
+        inherited
+    }
+
+    /// Implementation with inherited baseline controls credited
+    ///
+    /// Fully inherited controls are marked `Implemented`; shared controls that
+    /// are not yet partially implemented are raised to `PartiallyImplemented`.
+    /// Each credited control records the inheritance as evidence.
+    pub fn apply_inheritance(
+        &self,
+        current_implementation: &CurrentImplementation,
+        target_baseline: &TargetBaseline,
+    ) -> CurrentImplementation {
+        let mut effective = current_implementation.clone();
+
+        for inherited in self.inherited_controls(target_baseline) {
+            let control = effective.controls.entry(inherited.control_id.clone())
+                .or_insert_with(|| ControlImplementation {
+                    control_id: inherited.control_id.clone(),
+                    status: ImplementationStatus::NotImplemented,
+                    implementation_date: None,
+                    evidence: Vec::new(),
+                    parameters: HashMap::new(),
+                    completion_fraction: None,
+                    last_modified: None,
+                });
+
+            let credited_status = match (inherited.level, &control.status) {
+                (_, ImplementationStatus::Implemented | ImplementationStatus::NotApplicable) => continue,
+                (InheritanceLevel::Full, _) => ImplementationStatus::Implemented,
+                (InheritanceLevel::Shared, ImplementationStatus::PartiallyImplemented) => continue,
+                (InheritanceLevel::Shared, _) => ImplementationStatus::PartiallyImplemented,
+            };
+
+            control.status = credited_status;
+            control.evidence.push(Evidence {
+                evidence_type: "inheritance".to_string(),
+                description: inherited.provenance(),
+            });
+        }
+
+        effective
+    }
+
+    /// Drop gaps for fully inherited controls and reduce gaps for shared ones
+    fn credit_inherited_gaps(&self, gaps: Vec<Gap>) -> Vec<Gap> {
+        let Some(inheritance) = &self.control_inheritance else {
+            return gaps;
+        };
+
+        gaps.into_iter()
+            .filter_map(|mut gap| match inheritance.inheritance_for(&gap.control_id) {
+                Some(inherited) if inherited.level == InheritanceLevel::Full => None,
+                Some(inherited) => {
+                    gap.severity = gap.severity.reduced();
+                    if gap.gap_type == GapType::Missing {
+                        gap.gap_type = GapType::Partial;
+                    }
+                    gap.description = format!("{} ({})", gap.description, inherited.provenance());
+                    Some(gap)
+                }
+                None => Some(gap),
+            })
+            .collect()
+    }
+
+    /// Raise gaps on critical controls to the severity floor of their criticality
+    ///
+    /// Applied before the inheritance and partial completion credits, which
+    /// may take a gap below its floor.
+    fn apply_severity_floors(&self, gaps: Vec<Gap>) -> Vec<Gap> {
+        let Some(criticality) = &self.control_criticality else {
+            return gaps;
+        };
+
+        gaps.into_iter()
+            .map(|mut gap| {
+                if let Some(floor) = criticality.severity_floor_for(&gap.control_id) {
+                    if gap.severity.rank() < floor.rank() {
+                        gap.severity = floor;
+                    }
+                }
+                gap
+            })
+            .collect()
+    }
+
+    /// Scale gaps for controls reporting a completion fraction
+    ///
+    /// Severity, effort, timeline and budget are reduced in proportion to the
+    /// work already done, and a missing control with some progress becomes a
+    /// partial gap. Gaps for controls without a fraction are left unchanged.
+    fn credit_partial_completion(&self, gaps: Vec<Gap>, implementation: &CurrentImplementation) -> Result<Vec<Gap>> {
+        gaps.into_iter()
+            .map(|mut gap| {
+                let Some(completion) = implementation.controls.get(&gap.control_id)
+                    .and_then(|control| control.completion_fraction)
+                else {
+                    return Ok(gap);
+                };
+                if !(0.0..=1.0).contains(&completion) {
+                    return Err(Error::validation(format!(
+                        "Completion fraction for {} must be between 0.0 and 1.0, got {}",
+                        gap.control_id, completion
+                    )));
+                }
+
+                let remaining = 1.0 - completion;
+                let scale_hours = |hours: u32| (hours as f64 * remaining).ceil() as u32;
+
+                gap.severity = gap.severity.scaled(remaining);
+                if gap.gap_type == GapType::Missing && completion > 0.0 {
+                    gap.gap_type = GapType::Partial;
+                }
+
+                let operational = &mut gap.impact_assessment.operational_impact;
+                operational.implementation_effort.hours = scale_hours(operational.implementation_effort.hours);
+                operational.timeline_impact.days = scale_hours(operational.timeline_impact.days);
+                operational.resource_requirements.budget *= remaining;
+                gap.remediation_guidance.estimated_effort.hours = scale_hours(gap.remediation_guidance.estimated_effort.hours);
+
+                gap.description = format!("{} ({:.0}% complete)", gap.description, completion * 100.0);
+                Ok(gap)
+            })
+            .collect()
+    }
+
+    /// Gaps for present controls whose required parameters have no value
+    ///
+    /// Controls that are implemented or partially implemented must assign every
+    /// organization-defined parameter the baseline requires; a parameter that is
+    /// absent or blank yields one `ParameterMissing` gap per control listing the
+    /// unset parameters. Controls are matched by exact ID, as in gap detection.
+    fn detect_parameter_gaps(&self, implementation: &CurrentImplementation, target_baseline: &TargetBaseline) -> Vec<Gap> {
+        let mut gaps: Vec<Gap> = target_baseline.required_controls.iter()
+            .filter_map(|(control_id, required)| {
+                let control = implementation.controls.get(control_id)?;
+                if !matches!(control.status, ImplementationStatus::Implemented | ImplementationStatus::PartiallyImplemented) {
+                    return None;
+                }
+
+                let mut unset: Vec<(&String, &ParameterRequirement)> = required.parameters.iter()
+                    .filter(|(parameter_id, _)| control.parameters.get(*parameter_id).is_none_or(|value| value.trim().is_empty()))
+                    .collect();
+                if unset.is_empty() {
+                    return None;
+                }
+                unset.sort_by(|a, b| a.0.cmp(b.0));
+
+                let parameter_ids: Vec<&str> = unset.iter().map(|(parameter_id, _)| parameter_id.as_str()).collect();
+                let hours = 4 * unset.len() as u32;
+                Some(Gap {
+                    gap_id: Uuid::new_v4().to_string(),
+                    control_id: control_id.clone(),
+                    gap_type: GapType::ParameterMissing,
+                    severity: GapSeverity::Medium,
+                    confidence: 1.0,
+                    description: format!("{} has unset required parameters: {}", control_id, parameter_ids.join(", ")),
+                    current_status: control.status.clone(),
+                    required_status: required.required_status.clone(),
+                    impact_assessment: ImpactAssessment {
+                        business_impact: BusinessImpact::Low,
+                        compliance_impact: ComplianceImpact {
+                            authorization_risk: AuthorizationRisk::Medium,
+                            audit_findings_risk: AuditFindingsRisk::High,
+                            regulatory_risk: RegulatoryRisk::Low,
+                        },
+                        security_impact: SecurityImpact {
+                            confidentiality_impact: ImpactLevel::Low,
+                            integrity_impact: ImpactLevel::Low,
+                            availability_impact: ImpactLevel::Low,
+                        },
+                        operational_impact: OperationalImpact {
+                            implementation_effort: ImplementationEffort { hours, complexity: "low".to_string() },
+                            resource_requirements: ResourceRequirements { personnel: 1, budget: 0.0 },
+                            timeline_impact: TimelineImpact { days: 7 },
+                        },
+                    },
+                    remediation_guidance: RemediationGuidance {
+                        recommended_actions: unset.iter()
+                            .enumerate()
+                            .map(|(index, (parameter_id, requirement))| RecommendedAction {
+                                action: if requirement.required_value.is_empty() {
+                                    format!("Assign a value to {} parameter {}", control_id, parameter_id)
+                                } else {
+                                    format!("Assign a value to {} parameter {} ({})", control_id, parameter_id, requirement.required_value)
+                                },
+                                priority: index as u32 + 1,
+                            })
+                            .collect(),
+                        implementation_steps: vec![
+                            ImplementationStep { step: "Agree the parameter values with the control owner".to_string(), order: 1 },
+                            ImplementationStep { step: "Record the assigned values in the control implementation".to_string(), order: 2 },
+                        ],
+                        estimated_effort: EstimatedEffort { hours, complexity: "low".to_string() },
+                        priority_score: 0.5,
+                        dependencies: Vec::new(),
+                    },
+                    contributing_systems: Vec::new(),
+                })
+            })
+            .collect();
+        gaps.sort_by(|a, b| a.control_id.cmp(&b.control_id));
+        gaps
+    }
+
+    /// Assemble an analysis result from scored gaps, tagging untagged gaps with the system
+    fn build_result(&self, system_id: &str, target_baseline: &TargetBaseline, mut scored_gaps: Vec<Gap>) -> Result<GapAnalysisResult> {
+        let analysis_id = Uuid::new_v4().to_string();
+        let analysis_timestamp = Utc::now();
+
+        for gap in scored_gaps.iter_mut().filter(|gap| gap.contributing_systems.is_empty()) {
+            gap.contributing_systems.push(system_id.to_string());
+        }
 
         // Step 4: Generate summary and recommendations
         let summary = self.generate_summary(&scored_gaps)?;
         let recommendations = self.generate_recommendations(&scored_gaps)?;
+        let inherited_controls = self.inherited_controls(target_baseline);
 
         Ok(GapAnalysisResult {
             analysis_id,
@@ -305,91 +849,140 @@ impl GapAnalysisEngine {
                 analysis_duration: std::time::Duration::from_secs(0), // TODO: measure actual duration
                 configuration: self.config.clone(),
             },
+            inherited_controls,
+            system_ids: vec![system_id.to_string()],
         })
     }
 
     /// Generate gap analysis summary
     fn generate_summary(&self, gaps: &[Gap]) -> Result<GapSummary> {
-        let total_gaps = gaps.len();
-        
-        let mut gaps_by_severity = HashMap::new();
-        let mut gaps_by_type = HashMap::new();
-        
-        for gap in gaps {
-            *gaps_by_severity.entry(gap.severity.clone()).or_insert(0) += 1;
-            *gaps_by_type.entry(gap.gap_type.clone()).or_insert(0) += 1;
-        }
-
-        // Calculate overall compliance score (simplified)
-        let critical_gaps = gaps_by_severity.get(&GapSeverity::Critical).unwrap_or(&0);
-        let high_gaps = gaps_by_severity.get(&GapSeverity::High).unwrap_or(&0);
-        
-        let compliance_score = if total_gaps == 0 {
-            100.0
-        } else {
-            let penalty = (*critical_gaps as f64 * 10.0) + (*high_gaps as f64 * 5.0);
-            (100.0_f64 - penalty).max(0.0)
-        };
-
-        let readiness_assessment = if compliance_score >= 90.0 {
-            ReadinessAssessment::Ready
-        } else if compliance_score >= 70.0 {
-            ReadinessAssessment::NearReady
-        } else {
-            ReadinessAssessment::NotReady
-        };
-
-        Ok(GapSummary {
-            total_gaps,
-            gaps_by_severity,
-            gaps_by_type,
-            overall_compliance_score: compliance_score,
-            readiness_assessment,
-        })
+        Ok(summarize_gaps(gaps))
     }
 
     /// Generate remediation recommendations
     fn generate_recommendations(&self, gaps: &[Gap]) -> Result<Vec<Recommendation>> {
-        let mut recommendations = Vec::new();
+        Ok(recommend_for_gaps(gaps))
+    }
+}
 
-        // Group gaps by priority and generate recommendations
-        let mut high_priority_gaps: Vec<&Gap> = gaps.iter()
-            .filter(|g| matches!(g.severity, GapSeverity::Critical | GapSeverity::High))
-            .collect();
-        
-        high_priority_gaps.sort_by(|a, b| {
-            b.remediation_guidance.priority_score
-                .partial_cmp(&a.remediation_guidance.priority_score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+/// Summarize gaps by severity and type with an overall compliance score
+fn summarize_gaps(gaps: &[Gap]) -> GapSummary {
+    let total_gaps = gaps.len();
+    
+    let mut gaps_by_severity = HashMap::new();
+    let mut gaps_by_type = HashMap::new();
+    
+    for gap in gaps {
+        *gaps_by_severity.entry(gap.severity.clone()).or_insert(0) += 1;
+        *gaps_by_type.entry(gap.gap_type.clone()).or_insert(0) += 1;
+    }
 
-        for gap in high_priority_gaps.iter().take(10) {
-            recommendations.push(Recommendation {
-                recommendation_id: Uuid::new_v4().to_string(),
-                title: format!("Address {} gap in {}", gap.gap_type, gap.control_id),
-                description: gap.description.clone(),
-                priority: RecommendationPriority::High,
-                estimated_effort: gap.remediation_guidance.estimated_effort.clone(),
-                expected_impact: "Reduces compliance risk and improves security posture".to_string(),
-                implementation_guidance: gap.remediation_guidance.recommended_actions.clone(),
-            });
-        }
+    // Calculate overall compliance score (simplified)
+    let critical_gaps = gaps_by_severity.get(&GapSeverity::Critical).unwrap_or(&0);
+    let high_gaps = gaps_by_severity.get(&GapSeverity::High).unwrap_or(&0);
+    
+    let compliance_score = if total_gaps == 0 {
+        100.0
+    } else {
+        let penalty = (*critical_gaps as f64 * 10.0) + (*high_gaps as f64 * 5.0);
+        (100.0_f64 - penalty).max(0.0)
+    };
+
+    let readiness_assessment = if compliance_score >= 90.0 {
+        ReadinessAssessment::Ready
+    } else if compliance_score >= 70.0 {
+        ReadinessAssessment::NearReady
+    } else {
+        ReadinessAssessment::NotReady
+    };
+
+    GapSummary {
+        total_gaps,
+        gaps_by_severity,
+        gaps_by_type,
+        overall_compliance_score: compliance_score,
+        readiness_assessment,
+    }
+}
+
+/// Recommendations for the ten highest-priority critical and high gaps
+fn recommend_for_gaps(gaps: &[Gap]) -> Vec<Recommendation> {
+    let mut recommendations = Vec::new();
+
+    // Group gaps by priority and generate recommendations
+    let mut high_priority_gaps: Vec<&Gap> = gaps.iter()
+        .filter(|g| matches!(g.severity, GapSeverity::Critical | GapSeverity::High))
+        .collect();
+    
+    high_priority_gaps.sort_by(|a, b| {
+        b.remediation_guidance.priority_score
+            .partial_cmp(&a.remediation_guidance.priority_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
 
-        Ok(recommendations)
+    for gap in high_priority_gaps.iter().take(10) {
+        recommendations.push(Recommendation {
+            recommendation_id: Uuid::new_v4().to_string(),
+            title: format!("Address {} gap in {}", gap.gap_type, gap.control_id),
+            description: gap.description.clone(),
+            priority: RecommendationPriority::High,
+            estimated_effort: gap.remediation_guidance.estimated_effort.clone(),
+            expected_impact: "Reduces compliance risk and improves security posture".to_string(),
+            implementation_guidance: gap.remediation_guidance.recommended_actions.clone(),
+        });
     }
+
+    recommendations
 }
 
 // Additional types and implementations would continue here...
 // Due to length constraints, I'll implement the remaining types in separate files
 
+/// Implementation control ID that does not match any control in the target baseline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnrecognizedControl {
+    /// Control ID as written in the implementation
+    pub control_id: String,
+    /// Closest baseline control ID, if one is similar enough to suggest
+    pub suggestion: Option<String>,
+}
+
+impl std::fmt::Display for UnrecognizedControl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Implemented control '{}' is not in the target baseline", self.control_id)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "; did you mean '{}'?", suggestion)?;
+        }
+        Ok(())
+    }
+}
+
 /// Current implementation state
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrentImplementation {
     pub system_id: String,
     pub controls: HashMap<String, ControlImplementation>,
     pub last_updated: DateTime<Utc>,
 }
 
+impl CurrentImplementation {
+    /// IDs of controls whose implementation data changed at or after `since`, sorted
+    ///
+    /// A control's change time is its `last_modified`, or its
+    /// `implementation_date` when that is unset. Controls with neither are
+    /// treated as changed, since they cannot be shown to be unchanged.
+    pub fn controls_changed_since(&self, since: DateTime<Utc>) -> Vec<String> {
+        let mut changed: Vec<String> = self.controls.iter()
+            .filter(|(_, control)| {
+                control.last_modified.or(control.implementation_date).is_none_or(|modified| modified >= since)
+            })
+            .map(|(control_id, _)| control_id.clone())
+            .collect();
+        changed.sort();
+        changed
+    }
+}
+
 /// Target baseline for comparison
 #[derive(Debug, Clone)]
 pub struct TargetBaseline {
@@ -400,13 +993,19 @@ pub struct TargetBaseline {
 }
 
 /// Individual control implementation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ControlImplementation {
     pub control_id: String,
     pub status: ImplementationStatus,
     pub implementation_date: Option<DateTime<Utc>>,
+    #[serde(default)]
     pub evidence: Vec<Evidence>,
+    #[serde(default)]
     pub parameters: HashMap<String, String>,
+    /// Fraction of the control implemented (0.0 to 1.0), e.g. a narrative's percent complete
+    pub completion_fraction: Option<f64>,
+    /// When the control's implementation data last changed
+    pub last_modified: Option<DateTime<Utc>>,
 }
 
 /// Required control in baseline
@@ -445,7 +1044,7 @@ pub struct RequiredControl {
 #[derive(Debug, Clone, Serialize, Deserialize)] pub struct ToleranceThresholds { pub partial_threshold: f64 }
 #[derive(Debug, Clone, Serialize, Deserialize)] pub struct SeverityWeights { pub critical: f64, pub high: f64, pub medium: f64, pub low: f64 }
 #[derive(Debug, Clone, Serialize, Deserialize)] pub struct PerformanceSettings { pub max_parallel_comparisons: usize }
-#[derive(Debug, Clone)] pub struct Evidence { pub evidence_type: String, pub description: String }
+#[derive(Debug, Clone, Serialize, Deserialize)] pub struct Evidence { pub evidence_type: String, pub description: String }
 #[derive(Debug, Clone)] pub struct ParameterRequirement { pub required_value: String, pub validation_rule: String }
 #[derive(Debug, Clone)] pub struct BaselineMetadata { pub version: String, pub last_updated: DateTime<Utc> }
 #[derive(Debug, Clone, Serialize, Deserialize)] pub struct Recommendation { pub recommendation_id: String, pub title: String, pub description: String, pub priority: RecommendationPriority, pub estimated_effort: EstimatedEffort, pub expected_impact: String, pub implementation_guidance: Vec<RecommendedAction> }
@@ -472,9 +1071,60 @@ impl BaselineComparator {
         }
     }
 
-    pub async fn compare(&self, _current: &CurrentImplementation, _target: &TargetBaseline) -> Result<ComparisonResult> {
-        // Placeholder implementation
-        Ok(ComparisonResult { gaps: Vec::new() })
+    /// Baseline controls the implementation falls short of, sorted by control ID
+    ///
+    /// Controls are matched by exact ID. A control short of its required
+    /// status is `Missing` when not implemented or planned and `Partial` when
+    /// partially implemented; strict comparison counts partial implementations
+    /// as missing. A control meeting its status but not implementing every
+    /// required enhancement is `EnhancementMissing`. Controls not applicable on
+    /// either side are skipped.
+    pub async fn compare(&self, current: &CurrentImplementation, target: &TargetBaseline) -> Result<ComparisonResult> {
+        let mut required_controls: Vec<&RequiredControl> = target.required_controls.values().collect();
+        required_controls.sort_by(|a, b| a.control_id.cmp(&b.control_id));
+
+        let mut shortfalls = Vec::new();
+        for required in required_controls {
+            if required.required_status == ImplementationStatus::NotApplicable {
+                continue;
+            }
+            let current_status = current.controls.get(&required.control_id)
+                .map(|control| control.status.clone())
+                .unwrap_or(ImplementationStatus::NotImplemented);
+            if current_status == ImplementationStatus::NotApplicable {
+                continue;
+            }
+
+            let missing_enhancements: Vec<String> = required.enhancements.iter()
+                .filter(|enhancement| !current.controls.get(*enhancement).is_some_and(|control| {
+                    matches!(control.status, ImplementationStatus::Implemented | ImplementationStatus::NotApplicable)
+                }))
+                .cloned()
+                .collect();
+
+            let gap_type = if status_rank(&current_status) >= status_rank(&required.required_status) {
+                if missing_enhancements.is_empty() {
+                    continue;
+                }
+                GapType::EnhancementMissing
+            } else if current_status == ImplementationStatus::PartiallyImplemented
+                && !matches!(self.comparison_mode, ComparisonMode::Strict)
+            {
+                GapType::Partial
+            } else {
+                GapType::Missing
+            };
+
+            shortfalls.push(ControlShortfall {
+                control_id: required.control_id.clone(),
+                gap_type,
+                current_status,
+                required_status: required.required_status.clone(),
+                missing_enhancements,
+            });
+        }
+
+        Ok(ComparisonResult { framework_id: target.framework_id.clone(), shortfalls })
     }
 }
 
@@ -487,9 +1137,12 @@ impl GapDetector {
         }
     }
 
-    pub async fn detect_gaps(&self, _comparison: &ComparisonResult) -> Result<Vec<Gap>> {
-        // Placeholder implementation
-        Ok(Vec::new())
+    /// One gap per shortfall
+    ///
+    /// Missing controls are `High` severity, partial implementations and
+    /// missing enhancements `Medium`, with effort and remediation steps to match.
+    pub async fn detect_gaps(&self, comparison: &ComparisonResult) -> Result<Vec<Gap>> {
+        Ok(comparison.shortfalls.iter().map(shortfall_gap).collect())
     }
 }
 
@@ -508,7 +1161,717 @@ impl SeverityScorer {
     }
 }
 
+/// Minimum similarity for a baseline control ID to be suggested as a correction
+const SUGGESTION_THRESHOLD: f64 = 0.6;
+
+/// Map a baseline framework identifier (e.g. `nist-800-53`) to a known framework
+pub(crate) fn framework_for(framework_id: &str) -> Option<fedramp_core::models::Framework> {
+    use fedramp_core::models::Framework;
+
+    let compact: String = framework_id.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+    if compact.contains("800171") {
+        Some(Framework::Nist800171)
+    } else if compact.contains("80053") {
+        Some(Framework::Nist80053)
+    } else if compact.starts_with("cis") {
+        Some(Framework::Cis)
+    } else {
+        None
+    }
+}
+
+/// Canonical form of a control ID, falling back to an upper-cased ID without whitespace
+pub(crate) fn normalize_control_id(control_id: &str, framework: Option<&fedramp_core::models::Framework>) -> String {
+    framework
+        .and_then(|framework| fedramp_core::validation::validate_control_id(control_id, framework.clone()).ok())
+        .map(|id| id.to_string())
+        .unwrap_or_else(|| {
+            control_id.chars()
+                .filter(|c| !c.is_whitespace())
+                .collect::<String>()
+                .to_uppercase()
+        })
+}
+
+/// Most similar baseline control ID, compared case-insensitively
+fn closest_control_id(control_id: &str, baseline_ids: &[&str]) -> Option<String> {
+    let needle = control_id.to_uppercase();
+    baseline_ids.iter()
+        .map(|candidate| (strsim::normalized_levenshtein(&needle, &candidate.to_uppercase()), *candidate))
+        .filter(|(similarity, _)| *similarity >= SUGGESTION_THRESHOLD)
+        .max_by(|a, b| a.0.total_cmp(&b.0).then_with(|| b.1.cmp(a.1)))
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+/// Order of implementation progress, from not implemented to implemented
+fn status_rank(status: &ImplementationStatus) -> u8 {
+    match status {
+        ImplementationStatus::NotImplemented => 0,
+        ImplementationStatus::Planned => 1,
+        ImplementationStatus::PartiallyImplemented => 2,
+        ImplementationStatus::Implemented | ImplementationStatus::NotApplicable => 3,
+    }
+}
+
+/// Gap describing a control's shortfall against the baseline
+fn shortfall_gap(shortfall: &ControlShortfall) -> Gap {
+    let control_id = &shortfall.control_id;
+    let enhancements = shortfall.missing_enhancements.join(", ");
+    let (severity, hours, days, budget, description, mut actions) = match shortfall.gap_type {
+        GapType::Missing => (
+            GapSeverity::High,
+            40,
+            30,
+            5000.0,
+            format!("{} is not implemented", control_id),
+            vec![format!("Implement {}", control_id)],
+        ),
+        GapType::Partial => (
+            GapSeverity::Medium,
+            20,
+            14,
+            2500.0,
+            format!("{} is only partially implemented", control_id),
+            vec![format!("Complete the implementation of {}", control_id)],
+        ),
+        _ => (
+            GapSeverity::Medium,
+            8 * shortfall.missing_enhancements.len() as u32,
+            14,
+            1000.0 * shortfall.missing_enhancements.len() as f64,
+            format!("{} is missing required enhancements: {}", control_id, enhancements),
+            Vec::new(),
+        ),
+    };
+    actions.extend(shortfall.missing_enhancements.iter().map(|enhancement| format!("Implement enhancement {}", enhancement)));
+    let complexity = if hours >= 40 { "high" } else { "medium" };
+    let high_impact = severity == GapSeverity::High;
+
+    Gap {
+        gap_id: Uuid::new_v4().to_string(),
+        control_id: control_id.clone(),
+        gap_type: shortfall.gap_type.clone(),
+        severity,
+        confidence: 1.0,
+        description,
+        current_status: shortfall.current_status.clone(),
+        required_status: shortfall.required_status.clone(),
+        impact_assessment: ImpactAssessment {
+            business_impact: if high_impact { BusinessImpact::High } else { BusinessImpact::Medium },
+            compliance_impact: ComplianceImpact {
+                authorization_risk: if high_impact { AuthorizationRisk::High } else { AuthorizationRisk::Medium },
+                audit_findings_risk: AuditFindingsRisk::High,
+                regulatory_risk: RegulatoryRisk::Medium,
+            },
+            security_impact: SecurityImpact {
+                confidentiality_impact: ImpactLevel::Medium,
+                integrity_impact: ImpactLevel::Medium,
+                availability_impact: ImpactLevel::Medium,
+            },
+            operational_impact: OperationalImpact {
+                implementation_effort: ImplementationEffort { hours, complexity: complexity.to_string() },
+                resource_requirements: ResourceRequirements { personnel: if high_impact { 2 } else { 1 }, budget },
+                timeline_impact: TimelineImpact { days },
+            },
+        },
+        remediation_guidance: RemediationGuidance {
+            recommended_actions: actions.into_iter()
+                .enumerate()
+                .map(|(index, action)| RecommendedAction { action, priority: index as u32 + 1 })
+                .collect(),
+            implementation_steps: vec![
+                ImplementationStep { step: format!("Assign an owner for {}", control_id), order: 1 },
+                ImplementationStep { step: "Implement and document the control in the SSP".to_string(), order: 2 },
+                ImplementationStep { step: "Collect evidence of the implementation".to_string(), order: 3 },
+            ],
+            estimated_effort: EstimatedEffort { hours, complexity: complexity.to_string() },
+            priority_score: if high_impact { 0.8 } else { 0.6 },
+            dependencies: Vec::new(),
+        },
+        contributing_systems: Vec::new(),
+    }
+}
+
+/// Baseline controls an implementation falls short of
 #[derive(Debug, Clone)]
 pub struct ComparisonResult {
-    pub gaps: Vec<Gap>,
+    pub framework_id: String,
+    pub shortfalls: Vec<ControlShortfall>,
+}
+
+/// Baseline control the implementation does not fully meet
+#[derive(Debug, Clone)]
+pub struct ControlShortfall {
+    pub control_id: String,
+    pub gap_type: GapType,
+    pub current_status: ImplementationStatus,
+    pub required_status: ImplementationStatus,
+    /// Required enhancements not implemented
+    pub missing_enhancements: Vec<String>,
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn gap(control_id: &str, severity: GapSeverity) -> Gap {
+        Gap {
+            gap_id: Uuid::new_v4().to_string(),
+            control_id: control_id.to_string(),
+            gap_type: GapType::Missing,
+            severity,
+            confidence: 0.9,
+            description: format!("{} is not implemented", control_id),
+            current_status: ImplementationStatus::NotImplemented,
+            required_status: ImplementationStatus::Implemented,
+            impact_assessment: ImpactAssessment {
+                business_impact: BusinessImpact::High,
+                compliance_impact: ComplianceImpact {
+                    authorization_risk: AuthorizationRisk::High,
+                    audit_findings_risk: AuditFindingsRisk::Medium,
+                    regulatory_risk: RegulatoryRisk::Low,
+                },
+                security_impact: SecurityImpact {
+                    confidentiality_impact: ImpactLevel::High,
+                    integrity_impact: ImpactLevel::Medium,
+                    availability_impact: ImpactLevel::Low,
+                },
+                operational_impact: OperationalImpact {
+                    implementation_effort: ImplementationEffort { hours: 40, complexity: "medium".to_string() },
+                    resource_requirements: ResourceRequirements { personnel: 2, budget: 5000.0 },
+                    timeline_impact: TimelineImpact { days: 30 },
+                },
+            },
+            remediation_guidance: RemediationGuidance {
+                recommended_actions: Vec::new(),
+                implementation_steps: Vec::new(),
+                estimated_effort: EstimatedEffort { hours: 40, complexity: "medium".to_string() },
+                priority_score: 0.8,
+                dependencies: Vec::new(),
+            },
+            contributing_systems: Vec::new(),
+        }
+    }
+
+    pub(crate) fn control(control_id: &str, status: ImplementationStatus) -> ControlImplementation {
+        ControlImplementation {
+            control_id: control_id.to_string(),
+            status,
+            implementation_date: None,
+            evidence: Vec::new(),
+            parameters: HashMap::new(),
+            completion_fraction: None,
+            last_modified: None,
+        }
+    }
+
+    pub(crate) fn fixtures() -> (CurrentImplementation, TargetBaseline) {
+        let control_ids = ["AC-1", "AC-2", "AU-2"];
+        let current = CurrentImplementation {
+            system_id: "test-system".to_string(),
+            controls: control_ids.iter()
+                .map(|id| (id.to_string(), control(id, ImplementationStatus::PartiallyImplemented)))
+                .collect(),
+            last_updated: Utc::now(),
+        };
+        let baseline = TargetBaseline {
+            framework_id: "nist-800-53".to_string(),
+            profile_name: "moderate".to_string(),
+            required_controls: control_ids.iter()
+                .map(|id| (id.to_string(), RequiredControl {
+                    control_id: id.to_string(),
+                    required_status: ImplementationStatus::Implemented,
+                    enhancements: Vec::new(),
+                    parameters: HashMap::new(),
+                }))
+                .collect(),
+            baseline_metadata: BaselineMetadata { version: "1.0".to_string(), last_updated: Utc::now() },
+        };
+        (current, baseline)
+    }
+
+    /// Analysis outcome without per-run identifiers and timestamps
+    fn comparable(result: &GapAnalysisResult) -> serde_json::Value {
+        let gaps: Vec<_> = result.gaps.iter()
+            .map(|gap| {
+                let mut value = serde_json::to_value(gap).unwrap();
+                value.as_object_mut().unwrap().remove("gap_id");
+                value
+            })
+            .collect();
+        let recommendations: Vec<_> = result.recommendations.iter()
+            .map(|recommendation| (recommendation.title.clone(), recommendation.description.clone()))
+            .collect();
+        serde_json::json!({
+            "framework_id": result.framework_id,
+            "baseline_profile": result.baseline_profile,
+            "gaps": gaps,
+            "summary": serde_json::to_value(&result.summary).unwrap(),
+            "recommendations": recommendations,
+        })
+    }
+
+    #[test]
+    fn test_implementation_status_round_trips_through_shared_status() {
+        use fedramp_core::types::ImplementationStatus as Shared;
+
+        let statuses = [
+            ImplementationStatus::NotImplemented,
+            ImplementationStatus::Planned,
+            ImplementationStatus::PartiallyImplemented,
+            ImplementationStatus::Implemented,
+            ImplementationStatus::NotApplicable,
+        ];
+        for status in statuses {
+            let shared = Shared::from(status.clone());
+            assert_eq!(serde_json::to_value(&status).unwrap(), serde_json::to_value(shared).unwrap());
+            assert_eq!(ImplementationStatus::try_from(shared).unwrap(), status);
+        }
+
+        assert!(ImplementationStatus::try_from(Shared::InProgress).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_incremental_with_no_changes_returns_previous_result() {
+        let engine = GapAnalysisEngine::new();
+        let (current, baseline) = fixtures();
+        let mut previous = engine.analyze_gaps(&current, &baseline).await.unwrap();
+        previous.gaps = vec![gap("AC-1", GapSeverity::High), gap("AU-2", GapSeverity::Critical)];
+        previous.summary = engine.generate_summary(&previous.gaps).unwrap();
+
+        let result = engine.analyze_gaps_incremental(&current, &baseline, &previous, &[]).await.unwrap();
+
+        assert_eq!(result.analysis_id, previous.analysis_id);
+        assert_eq!(comparable(&result), comparable(&previous));
+    }
+
+    #[tokio::test]
+    async fn test_incremental_with_all_changes_matches_full_run() {
+        let engine = GapAnalysisEngine::new();
+        let (current, baseline) = fixtures();
+        let mut previous = engine.analyze_gaps(&current, &baseline).await.unwrap();
+        previous.gaps = vec![gap("AC-1", GapSeverity::High), gap("AU-2", GapSeverity::Critical)];
+
+        let changed: Vec<String> = baseline.required_controls.keys().cloned().collect();
+        let incremental = engine.analyze_gaps_incremental(&current, &baseline, &previous, &changed).await.unwrap();
+        let full = engine.analyze_gaps(&current, &baseline).await.unwrap();
+
+        assert_eq!(comparable(&incremental), comparable(&full));
+    }
+
+    #[tokio::test]
+    async fn test_incremental_reuses_gaps_for_unchanged_controls() {
+        let engine = GapAnalysisEngine::new();
+        let (mut current, baseline) = fixtures();
+        let mut previous = engine.analyze_gaps(&current, &baseline).await.unwrap();
+        previous.gaps = vec![gap("AC-1", GapSeverity::High), gap("AU-2", GapSeverity::Critical)];
+        let reused_gap_id = previous.gaps[0].gap_id.clone();
+        // AU-2 has since been implemented, closing its gap
+        current.controls.get_mut("AU-2").unwrap().status = ImplementationStatus::Implemented;
+
+        let result = engine
+            .analyze_gaps_incremental(&current, &baseline, &previous, &["AU-2".to_string()])
+            .await
+            .unwrap();
+
+        let control_ids: Vec<&str> = result.gaps.iter().map(|gap| gap.control_id.as_str()).collect();
+        assert_eq!(control_ids, vec!["AC-1"]);
+        assert_eq!(result.gaps[0].gap_id, reused_gap_id);
+        assert_eq!(result.summary.total_gaps, 1);
+    }
+
+    #[tokio::test]
+    async fn test_since_matches_full_run_for_changed_controls() {
+        let engine = GapAnalysisEngine::new();
+        let (mut current, mut baseline) = fixtures();
+        for required in baseline.required_controls.values_mut() {
+            required.parameters.insert("frequency".to_string(), ParameterRequirement {
+                required_value: "monthly".to_string(),
+                validation_rule: "non-empty".to_string(),
+            });
+        }
+        let since = Utc::now() - chrono::Duration::days(7);
+        for (control_id, control) in current.controls.iter_mut() {
+            let days_ago = if control_id == "AC-1" { 30 } else { 1 };
+            control.last_modified = Some(Utc::now() - chrono::Duration::days(days_ago));
+        }
+
+        let incremental = engine.analyze_gaps_since(&current, &baseline, since).await.unwrap();
+        let full = engine.analyze_gaps(&current, &baseline).await.unwrap();
+
+        let gaps_for = |result: &GapAnalysisResult, control_ids: &[&str]| -> Vec<serde_json::Value> {
+            let mut gaps: Vec<_> = result.gaps.iter()
+                .filter(|gap| control_ids.contains(&gap.control_id.as_str()))
+                .map(|gap| {
+                    let mut value = serde_json::to_value(gap).unwrap();
+                    value.as_object_mut().unwrap().remove("gap_id");
+                    value
+                })
+                .collect();
+            gaps.sort_by_key(|gap| gap["control_id"].to_string());
+            gaps
+        };
+        assert_eq!(current.controls_changed_since(since), ["AC-2", "AU-2"]);
+        // A partial and a parameter gap per control
+        assert_eq!(incremental.gaps.len(), 4);
+        assert_eq!(gaps_for(&incremental, &["AC-2", "AU-2"]), gaps_for(&full, &["AC-2", "AU-2"]));
+        assert_eq!(full.gaps.len(), 6);
+    }
+
+    #[test]
+    fn test_controls_without_timestamps_count_as_changed() {
+        let (mut current, _) = fixtures();
+        let since = Utc::now();
+        current.controls.get_mut("AC-1").unwrap().implementation_date = Some(since - chrono::Duration::days(1));
+
+        assert_eq!(current.controls_changed_since(since), ["AC-2", "AU-2"]);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_rejects_result_for_other_baseline() {
+        let engine = GapAnalysisEngine::new();
+        let (current, baseline) = fixtures();
+        let mut previous = engine.analyze_gaps(&current, &baseline).await.unwrap();
+        previous.baseline_profile = "high".to_string();
+
+        let result = engine
+            .analyze_gaps_incremental(&current, &baseline, &previous, &["AC-1".to_string()])
+            .await;
+
+        assert!(matches!(result, Err(Error::Validation { .. })));
+    }
+
+    #[test]
+    fn test_control_references_suggest_baseline_ids() {
+        let (mut current, baseline) = fixtures();
+        current.controls.insert("AC-02".to_string(), control("AC-02", ImplementationStatus::Implemented));
+        current.controls.insert("AU-22".to_string(), control("AU-22", ImplementationStatus::Implemented));
+        current.controls.insert("ZZ-999".to_string(), control("ZZ-999", ImplementationStatus::Implemented));
+
+        let unrecognized = GapAnalysisEngine::new().validate_control_references(&current, &baseline);
+
+        assert_eq!(unrecognized, vec![
+            UnrecognizedControl { control_id: "AC-02".to_string(), suggestion: Some("AC-2".to_string()) },
+            UnrecognizedControl { control_id: "AU-22".to_string(), suggestion: Some("AU-2".to_string()) },
+            UnrecognizedControl { control_id: "ZZ-999".to_string(), suggestion: None },
+        ]);
+        assert_eq!(
+            unrecognized[0].to_string(),
+            "Implemented control 'AC-02' is not in the target baseline; did you mean 'AC-2'?"
+        );
+    }
+
+    fn inheritance() -> ControlInheritance {
+        serde_json::from_value(serde_json::json!({
+            "infrastructure_controls": ["PE-3"],
+            "platform_controls": ["AC-2"],
+            "inheritance_mappings": {
+                "cloud_infrastructure": { "inherited_controls": "infrastructure_controls", "provider_responsibility": "full" },
+                "platform_services": { "inherited_controls": "platform_controls", "provider_responsibility": "shared" }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_inherited_controls_are_credited_in_implementation() {
+        let engine = GapAnalysisEngine::new().with_control_inheritance(inheritance());
+        let (mut current, mut baseline) = fixtures();
+        current.controls.insert("AC-2".to_string(), control("AC-2", ImplementationStatus::NotImplemented));
+        baseline.required_controls.insert("PE-3".to_string(), RequiredControl {
+            control_id: "PE-3".to_string(),
+            required_status: ImplementationStatus::Implemented,
+            enhancements: Vec::new(),
+            parameters: HashMap::new(),
+        });
+
+        let effective = engine.apply_inheritance(&current, &baseline);
+
+        let pe_3 = &effective.controls["PE-3"];
+        assert_eq!(pe_3.status, ImplementationStatus::Implemented);
+        assert_eq!(pe_3.evidence[0].evidence_type, "inheritance");
+        assert_eq!(pe_3.evidence[0].description, "Fully inherited from cloud_infrastructure");
+        assert_eq!(effective.controls["AC-2"].status, ImplementationStatus::PartiallyImplemented);
+        assert_eq!(effective.controls["AU-2"].status, ImplementationStatus::PartiallyImplemented);
+        assert!(effective.controls["AU-2"].evidence.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_inherited_controls_do_not_produce_gaps() {
+        let engine = GapAnalysisEngine::new().with_control_inheritance(inheritance());
+        let (mut current, mut baseline) = fixtures();
+        current.controls.insert("AC-2".to_string(), control("AC-2", ImplementationStatus::NotImplemented));
+        current.controls.insert("AU-2".to_string(), control("AU-2", ImplementationStatus::NotImplemented));
+        baseline.required_controls.insert("PE-3".to_string(), RequiredControl {
+            control_id: "PE-3".to_string(),
+            required_status: ImplementationStatus::Implemented,
+            enhancements: Vec::new(),
+            parameters: HashMap::new(),
+        });
+
+        let result = engine.analyze_gaps(&current, &baseline).await.unwrap();
+
+        // PE-3 is fully inherited and AC-2's shared gap is reduced; AU-2 is not inherited
+        let gaps: Vec<(&str, &GapType, &GapSeverity)> = result.gaps.iter()
+            .map(|gap| (gap.control_id.as_str(), &gap.gap_type, &gap.severity))
+            .collect();
+        assert_eq!(gaps, [
+            ("AC-1", &GapType::Partial, &GapSeverity::Medium),
+            ("AC-2", &GapType::Partial, &GapSeverity::Low),
+            ("AU-2", &GapType::Missing, &GapSeverity::High),
+        ]);
+        assert_eq!(
+            result.gaps[1].description,
+            "AC-2 is only partially implemented (Partially inherited from platform_services (shared responsibility))"
+        );
+        let inherited: Vec<&str> = result.inherited_controls.iter().map(|control| control.control_id.as_str()).collect();
+        assert_eq!(inherited, ["AC-2", "PE-3"]);
+    }
+
+    #[tokio::test]
+    async fn test_comparison_reports_missing_partial_and_enhancement_gaps() {
+        let (mut current, mut baseline) = fixtures();
+        current.controls.get_mut("AC-1").unwrap().status = ImplementationStatus::Implemented;
+        current.controls.get_mut("AC-2").unwrap().status = ImplementationStatus::Implemented;
+        current.controls.insert("AC-2(1)".to_string(), control("AC-2(1)", ImplementationStatus::Implemented));
+        current.controls.remove("AU-2");
+        baseline.required_controls.get_mut("AC-2").unwrap().enhancements = vec!["AC-2(1)".to_string(), "AC-2(2)".to_string()];
+        baseline.required_controls.insert("SC-7".to_string(), RequiredControl {
+            control_id: "SC-7".to_string(),
+            required_status: ImplementationStatus::Implemented,
+            enhancements: Vec::new(),
+            parameters: HashMap::new(),
+        });
+        current.controls.insert("SC-7".to_string(), control("SC-7", ImplementationStatus::PartiallyImplemented));
+
+        let result = GapAnalysisEngine::new().analyze_gaps(&current, &baseline).await.unwrap();
+        let gaps: Vec<(&str, &GapType, &GapSeverity)> = result.gaps.iter()
+            .map(|gap| (gap.control_id.as_str(), &gap.gap_type, &gap.severity))
+            .collect();
+        assert_eq!(gaps, [
+            ("AC-2", &GapType::EnhancementMissing, &GapSeverity::Medium),
+            ("AU-2", &GapType::Missing, &GapSeverity::High),
+            ("SC-7", &GapType::Partial, &GapSeverity::Medium),
+        ]);
+        assert_eq!(result.gaps[0].description, "AC-2 is missing required enhancements: AC-2(2)");
+        assert_eq!(result.summary.total_gaps, 3);
+
+        // Strict comparison gives no credit for partial implementations
+        let mut strict = GapAnalysisEngine::new();
+        strict.baseline_comparator.comparison_mode = ComparisonMode::Strict;
+        let result = strict.analyze_gaps(&current, &baseline).await.unwrap();
+        assert_eq!(result.gaps.iter().find(|gap| gap.control_id == "SC-7").unwrap().gap_type, GapType::Missing);
+    }
+
+    #[test]
+    fn test_partial_completion_scales_gap_proportionally() {
+        let engine = GapAnalysisEngine::new();
+        let (mut current, _) = fixtures();
+        current.controls.get_mut("AC-1").unwrap().completion_fraction = Some(0.75);
+        current.controls.get_mut("AC-2").unwrap().completion_fraction = Some(0.25);
+
+        let gaps = vec![
+            gap("AC-1", GapSeverity::High),
+            gap("AC-2", GapSeverity::High),
+            gap("AU-2", GapSeverity::High),
+        ];
+        let credited = engine.credit_partial_completion(gaps, &current).unwrap();
+        let (mostly_done, barely_started, no_fraction) = (&credited[0], &credited[1], &credited[2]);
+
+        assert_eq!(mostly_done.severity, GapSeverity::Low);
+        assert_eq!(mostly_done.gap_type, GapType::Partial);
+        assert_eq!(mostly_done.remediation_guidance.estimated_effort.hours, 10);
+        assert_eq!(mostly_done.description, "AC-1 is not implemented (75% complete)");
+
+        assert_eq!(barely_started.severity, GapSeverity::High);
+        assert_eq!(barely_started.remediation_guidance.estimated_effort.hours, 30);
+        assert!(
+            mostly_done.impact_assessment.operational_impact.resource_requirements.budget
+                < barely_started.impact_assessment.operational_impact.resource_requirements.budget
+        );
+
+        assert_eq!(no_fraction.severity, GapSeverity::High);
+        assert_eq!(no_fraction.gap_type, GapType::Missing);
+        assert_eq!(no_fraction.remediation_guidance.estimated_effort.hours, 40);
+    }
+
+    #[tokio::test]
+    async fn test_analysis_scales_gaps_by_completion_fraction() {
+        let (mut current, baseline) = fixtures();
+        current.controls.get_mut("AC-1").unwrap().completion_fraction = Some(0.75);
+        current.controls.get_mut("AC-2").unwrap().completion_fraction = Some(0.25);
+
+        let result = GapAnalysisEngine::new().analyze_gaps(&current, &baseline).await.unwrap();
+        let gap_for = |control_id: &str| result.gaps.iter().find(|gap| gap.control_id == control_id).unwrap();
+        let (mostly_done, barely_started, no_fraction) = (gap_for("AC-1"), gap_for("AC-2"), gap_for("AU-2"));
+
+        assert_eq!(mostly_done.severity, GapSeverity::Low);
+        assert_eq!(barely_started.severity, GapSeverity::Medium);
+        assert!(mostly_done.remediation_guidance.estimated_effort.hours < barely_started.remediation_guidance.estimated_effort.hours);
+        assert_eq!(mostly_done.description, "AC-1 is only partially implemented (75% complete)");
+
+        // Without a fraction the partial gap is reported as detected
+        assert_eq!(no_fraction.severity, GapSeverity::Medium);
+        assert_eq!(no_fraction.remediation_guidance.estimated_effort.hours, 20);
+    }
+
+    #[test]
+    fn test_completion_fraction_out_of_range_is_rejected() {
+        let engine = GapAnalysisEngine::new();
+        let (mut current, _) = fixtures();
+        current.controls.get_mut("AC-1").unwrap().completion_fraction = Some(1.5);
+
+        let result = engine.credit_partial_completion(vec![gap("AC-1", GapSeverity::High)], &current);
+        assert!(matches!(result, Err(Error::Validation { .. })));
+    }
+
+    #[test]
+    fn test_critical_control_gap_is_promoted_to_floor() {
+        let criticality = ControlCriticality::default()
+            .with_control("SC-7", "High")
+            .with_control("PL-4", "Low")
+            .with_severity_floor("High", GapSeverity::High)
+            .with_severity_floor("Low", GapSeverity::Low);
+        let engine = GapAnalysisEngine::new().with_control_criticality(criticality);
+
+        let floored = engine.apply_severity_floors(vec![
+            gap("SC-7", GapSeverity::Medium),
+            gap("PL-4", GapSeverity::Medium),
+            gap("AU-2", GapSeverity::Informational),
+            gap("SC-7", GapSeverity::Critical),
+        ]);
+
+        let severities: Vec<GapSeverity> = floored.into_iter().map(|gap| gap.severity).collect();
+        assert_eq!(severities, vec![
+            GapSeverity::High,
+            GapSeverity::Medium,
+            GapSeverity::Informational,
+            GapSeverity::Critical,
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_analysis_promotes_critical_control_gaps() {
+        let criticality = ControlCriticality::default()
+            .with_control("SC-7", "High")
+            .with_control("PL-4", "Low")
+            .with_severity_floor("High", GapSeverity::High)
+            .with_severity_floor("Low", GapSeverity::Low);
+        let engine = GapAnalysisEngine::new().with_control_criticality(criticality);
+        let (mut current, mut baseline) = fixtures();
+        for control_id in ["SC-7", "PL-4"] {
+            current.controls.insert(control_id.to_string(), control(control_id, ImplementationStatus::PartiallyImplemented));
+            baseline.required_controls.insert(control_id.to_string(), RequiredControl {
+                control_id: control_id.to_string(),
+                required_status: ImplementationStatus::Implemented,
+                enhancements: Vec::new(),
+                parameters: HashMap::new(),
+            });
+        }
+
+        let result = engine.analyze_gaps(&current, &baseline).await.unwrap();
+        let severity = |control_id: &str| {
+            result.gaps.iter().find(|gap| gap.control_id == control_id).unwrap().severity.clone()
+        };
+
+        // Both partial gaps are detected as medium; only the critical control's is promoted
+        assert_eq!(severity("SC-7"), GapSeverity::High);
+        assert_eq!(severity("PL-4"), GapSeverity::Medium);
+        assert_eq!(severity("AU-2"), GapSeverity::Medium);
+    }
+
+    #[tokio::test]
+    async fn test_credits_reduce_gaps_raised_to_a_severity_floor() {
+        let criticality = ControlCriticality::default()
+            .with_control("AC-1", "High")
+            .with_control("AC-2", "High")
+            .with_control("AU-2", "High")
+            .with_severity_floor("High", GapSeverity::Critical);
+        let engine = GapAnalysisEngine::new()
+            .with_control_criticality(criticality)
+            .with_control_inheritance(inheritance());
+        let (mut current, baseline) = fixtures();
+        current.controls.get_mut("AC-1").unwrap().completion_fraction = Some(0.75);
+
+        // Each partially implemented control has a medium gap, raised to the critical floor
+        let result = engine.analyze_gaps(&current, &baseline).await.unwrap();
+        let severity = |control_id: &str| {
+            result.gaps.iter().find(|gap| gap.control_id == control_id).unwrap().severity.clone()
+        };
+
+        // Progress and shared inheritance are credited against the floor
+        assert_eq!(severity("AC-1"), GapSeverity::Low);
+        assert_eq!(severity("AC-2"), GapSeverity::High);
+        assert_eq!(severity("AU-2"), GapSeverity::Critical);
+    }
+
+    async fn analyzed(engine: &GapAnalysisEngine, system_id: &str, gaps: Vec<Gap>) -> GapAnalysisResult {
+        let (mut current, baseline) = fixtures();
+        current.system_id = system_id.to_string();
+        let mut result = engine.analyze_gaps(&current, &baseline).await.unwrap();
+        result.gaps = gaps;
+        result
+    }
+
+    #[tokio::test]
+    async fn test_implemented_control_with_unset_parameter_is_a_parameter_gap() {
+        let (mut current, mut baseline) = fixtures();
+        current.controls.get_mut("AC-2").unwrap().status = ImplementationStatus::Implemented;
+        current.controls.get_mut("AC-2").unwrap().parameters.insert("ac-2_prm_1".to_string(), "30 days".to_string());
+        baseline.required_controls.get_mut("AC-2").unwrap().parameters = HashMap::from([
+            ("ac-2_prm_1".to_string(), ParameterRequirement { required_value: "account review period".to_string(), validation_rule: String::new() }),
+            ("ac-2_prm_2".to_string(), ParameterRequirement { required_value: "inactivity period".to_string(), validation_rule: String::new() }),
+        ]);
+        baseline.required_controls.get_mut("AU-2").unwrap().parameters = HashMap::from([
+            ("au-2_prm_1".to_string(), ParameterRequirement { required_value: "auditable events".to_string(), validation_rule: String::new() }),
+        ]);
+        current.controls.get_mut("AU-2").unwrap().status = ImplementationStatus::NotImplemented;
+
+        let result = GapAnalysisEngine::new().analyze_gaps(&current, &baseline).await.unwrap();
+
+        // AU-2 is not implemented, so only its missing control gap is reported
+        let au_2: Vec<&GapType> = result.gaps.iter().filter(|gap| gap.control_id == "AU-2").map(|gap| &gap.gap_type).collect();
+        assert_eq!(au_2, [&GapType::Missing]);
+        let parameter_gaps: Vec<&Gap> = result.gaps.iter().filter(|gap| gap.gap_type == GapType::ParameterMissing).collect();
+        assert_eq!(parameter_gaps.len(), 1);
+        let gap = parameter_gaps[0];
+        assert_eq!(gap.control_id, "AC-2");
+        assert_eq!(gap.description, "AC-2 has unset required parameters: ac-2_prm_2");
+        assert_eq!(gap.remediation_guidance.recommended_actions[0].action, "Assign a value to AC-2 parameter ac-2_prm_2 (inactivity period)");
+        assert_eq!(result.summary.gaps_by_type.get(&GapType::ParameterMissing), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn test_merge_keeps_worst_severity_and_unions_systems() {
+        let engine = GapAnalysisEngine::new();
+        let system_a = analyzed(&engine, "system-a", vec![
+            gap("AC-2", GapSeverity::Medium),
+            gap("AU-2", GapSeverity::Low),
+        ]).await;
+        let system_b = analyzed(&engine, "system-b", vec![gap("AC-2", GapSeverity::High)]).await;
+
+        let merged = GapAnalysisResult::merge(&[system_a, system_b]).unwrap();
+
+        assert_eq!(merged.system_ids, vec!["system-a", "system-b"]);
+        assert_eq!(merged.summary.total_gaps, 2);
+        assert_eq!(merged.gaps[0].control_id, "AC-2");
+        assert_eq!(merged.gaps[0].severity, GapSeverity::High);
+        assert_eq!(merged.gaps[0].contributing_systems, vec!["system-a", "system-b"]);
+        assert_eq!(merged.gaps[1].control_id, "AU-2");
+        assert_eq!(merged.gaps[1].contributing_systems, vec!["system-a"]);
+    }
+
+    #[tokio::test]
+    async fn test_merge_rejects_empty_and_mismatched_results() {
+        let engine = GapAnalysisEngine::new();
+        let moderate = analyzed(&engine, "system-a", Vec::new()).await;
+        let mut high = analyzed(&engine, "system-b", Vec::new()).await;
+        high.baseline_profile = "high".to_string();
+
+        assert!(matches!(GapAnalysisResult::merge(&[]), Err(Error::Validation { .. })));
+        assert!(matches!(GapAnalysisResult::merge(&[moderate, high]), Err(Error::Validation { .. })));
+    }
 }
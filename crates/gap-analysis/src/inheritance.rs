@@ -0,0 +1,141 @@
+// Modified: 2025-09-20
+
+//! Control Inheritance
+//!
+//! Credits controls provided by an authorized infrastructure or platform
+//! provider, using the `control_inheritance` section of the inventory mappings.
+
+use fedramp_core::{Result, Error};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Control inheritance configuration
+///
+/// Mirrors the `control_inheritance` section of `inventory_mappings.json`:
+/// named control lists plus mappings that assign a provider responsibility to
+/// each list.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ControlInheritance {
+    /// Controls provided by the cloud infrastructure
+    #[serde(default)]
+    pub infrastructure_controls: Vec<String>,
+    /// Controls provided by the platform services
+    #[serde(default)]
+    pub platform_controls: Vec<String>,
+    /// Inheritance source name to mapping
+    #[serde(default)]
+    pub inheritance_mappings: HashMap<String, InheritanceMapping>,
+}
+
+/// Inheritance of one named control list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InheritanceMapping {
+    /// Name of the control list (`infrastructure_controls` or `platform_controls`)
+    pub inherited_controls: String,
+    /// Provider responsibility (`full` or `shared`)
+    pub provider_responsibility: String,
+}
+
+/// How much of a control the provider is responsible for
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum InheritanceLevel {
+    /// Customer and provider share the control
+    Shared,
+    /// Provider fully satisfies the control
+    Full,
+}
+
+/// Inheritance credited to a single control
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InheritedControl {
+    pub control_id: String,
+    /// Inheritance mapping the control is inherited through, e.g. `cloud_infrastructure`
+    pub source: String,
+    pub level: InheritanceLevel,
+}
+
+impl InheritedControl {
+    /// Provenance note recorded on the credited implementation
+    pub fn provenance(&self) -> String {
+        match self.level {
+            InheritanceLevel::Full => format!("Fully inherited from {}", self.source),
+            InheritanceLevel::Shared => format!("Partially inherited from {} (shared responsibility)", self.source),
+        }
+    }
+}
+
+impl ControlInheritance {
+    /// Load the `control_inheritance` section from an inventory mappings file
+    pub fn from_inventory_mappings_file(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::document_parsing(format!("Failed to read inventory mappings file: {}", e)))?;
+
+        let document: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|e| Error::document_parsing(format!("Failed to parse inventory mappings JSON: {}", e)))?;
+
+        let section = document
+            .pointer("/inventory_mappings/control_inheritance")
+            .or_else(|| document.get("control_inheritance"))
+            .ok_or_else(|| Error::document_parsing("Inventory mappings have no 'control_inheritance' section"))?;
+
+        serde_json::from_value(section.clone())
+            .map_err(|e| Error::document_parsing(format!("Invalid control_inheritance section: {}", e)))
+    }
+
+    /// Inheritance for a control, if any mapping covers it
+    ///
+    /// Control IDs are compared case-insensitively. When several mappings
+    /// cover the control, the one with the highest provider responsibility wins.
+    pub fn inheritance_for(&self, control_id: &str) -> Option<InheritedControl> {
+        let mut sources: Vec<(&String, &InheritanceMapping)> = self.inheritance_mappings.iter().collect();
+        sources.sort_by(|a, b| a.0.cmp(b.0));
+
+        sources.into_iter()
+            .filter(|(_, mapping)| {
+                self.control_list(&mapping.inherited_controls)
+                    .iter()
+                    .any(|inherited| inherited.eq_ignore_ascii_case(control_id))
+            })
+            .filter_map(|(source, mapping)| {
+                let level = match mapping.provider_responsibility.to_lowercase().as_str() {
+                    "full" => InheritanceLevel::Full,
+                    "shared" | "partial" => InheritanceLevel::Shared,
+                    _ => return None,
+                };
+                Some(InheritedControl {
+                    control_id: control_id.to_string(),
+                    source: source.clone(),
+                    level,
+                })
+            })
+            .max_by_key(|inherited| inherited.level)
+    }
+
+    /// Resolve a control list by the name used in `inherited_controls`
+    fn control_list(&self, name: &str) -> &[String] {
+        match name {
+            "infrastructure_controls" => &self.infrastructure_controls,
+            "platform_controls" => &self.platform_controls,
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inventory_mappings_inheritance_section() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../../mappings/inventory_mappings.json");
+        let inheritance = ControlInheritance::from_inventory_mappings_file(path).unwrap();
+
+        let pe_3 = inheritance.inheritance_for("pe-3").unwrap();
+        assert_eq!(pe_3.level, InheritanceLevel::Full);
+        assert_eq!(pe_3.source, "cloud_infrastructure");
+
+        assert_eq!(inheritance.inheritance_for("AC-2").unwrap().level, InheritanceLevel::Shared);
+        assert!(inheritance.inheritance_for("RA-5").is_none());
+    }
+}
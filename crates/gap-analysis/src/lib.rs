@@ -7,14 +7,31 @@ pub mod engine;
 pub mod baseline;
 pub mod prioritization;
 pub mod remediation;
+pub mod inheritance;
+pub mod criticality;
+pub mod retry;
+pub mod reconciliation;
+pub mod assessment_results;
+pub mod delta;
 
-pub use engine::{GapAnalysisEngine, GapAnalysisResult, Gap, GapType, GapSeverity, ImplementationStatus, TargetBaseline};
-pub use baseline::{BaselineManager, ValidationResult};
-pub use prioritization::{PrioritizationEngine, PrioritizedGap, PriorityCategory, PrioritizationMatrix};
+pub use engine::{GapAnalysisEngine, GapAnalysisResult, Gap, GapType, GapSeverity, ImplementationStatus, TargetBaseline, UnrecognizedControl};
+pub use baseline::{BaselineLoader, BaselineManager, BaselineSource, BaselineStore, ValidationResult};
+pub use prioritization::{PrioritizationEngine, PrioritizedGap, PriorityCategory, PrioritizationMatrix, PrioritizationCriteria, QuadrantThresholds, ScoringStrategy, ScoringBreakdown, WeightedSumStrategy, SeverityBasedStrategy};
 pub use remediation::{RemediationPlanner, RemediationPlan, RemediationItem};
+pub use inheritance::{ControlInheritance, InheritanceLevel, InheritedControl};
+pub use criticality::{ControlCriticality, CriticalityImpact};
+pub use retry::RetryPolicy;
+pub use reconciliation::{reconcile_ssp, SspDiscrepancy, SspReconciliation};
+pub use assessment_results::to_assessment_results;
+pub use delta::{BaselineControlChange, BaselineDelta, GapChange, GapDelta};
 
-use fedramp_core::Result;
+use fedramp_core::{Error, MetricsRecorder, Result, SharedMetricsRecorder};
 use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 
 /// Main gap analysis service integrating all components
 #[derive(Debug, Clone)]
@@ -29,6 +46,8 @@ pub struct GapAnalysisService {
     pub remediation_planner: RemediationPlanner,
     /// Service configuration
     pub config: GapAnalysisServiceConfig,
+    /// Recorder for step and workflow durations
+    pub metrics: Option<SharedMetricsRecorder>,
 }
 
 /// Configuration for the gap analysis service
@@ -46,6 +65,114 @@ pub struct GapAnalysisServiceConfig {
     pub cache_results: bool,
     /// Maximum gaps to analyze in one batch
     pub max_gaps_per_analysis: usize,
+    /// Time limits for individual workflow steps
+    #[serde(default)]
+    pub step_timeouts: StepTimeouts,
+    /// Retry policy for transient baseline store failures
+    #[serde(default)]
+    pub baseline_retry: RetryPolicy,
+}
+
+/// Per-step time limits for the gap analysis workflow, in milliseconds
+///
+/// A step without a limit runs until it completes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct StepTimeouts {
+    pub baseline_loading_ms: Option<u64>,
+    pub gap_analysis_ms: Option<u64>,
+    pub prioritization_ms: Option<u64>,
+    pub matrix_generation_ms: Option<u64>,
+    pub remediation_planning_ms: Option<u64>,
+}
+
+impl StepTimeouts {
+    /// Apply the same limit to every step
+    pub fn uniform(limit_ms: u64) -> Self {
+        Self {
+            baseline_loading_ms: Some(limit_ms),
+            gap_analysis_ms: Some(limit_ms),
+            prioritization_ms: Some(limit_ms),
+            matrix_generation_ms: Some(limit_ms),
+            remediation_planning_ms: Some(limit_ms),
+        }
+    }
+
+    /// Time limit for a workflow step, if one is configured
+    pub fn limit(&self, step: &str) -> Option<Duration> {
+        let limit_ms = match step {
+            "baseline_loading" => self.baseline_loading_ms,
+            "gap_analysis" => self.gap_analysis_ms,
+            "prioritization" => self.prioritization_ms,
+            "matrix_generation" => self.matrix_generation_ms,
+            "remediation_planning" => self.remediation_planning_ms,
+            _ => None,
+        };
+        limit_ms.map(Duration::from_millis)
+    }
+
+    fn entries(&self) -> [(&'static str, Option<u64>); 5] {
+        [
+            ("baseline_loading_ms", self.baseline_loading_ms),
+            ("gap_analysis_ms", self.gap_analysis_ms),
+            ("prioritization_ms", self.prioritization_ms),
+            ("matrix_generation_ms", self.matrix_generation_ms),
+            ("remediation_planning_ms", self.remediation_planning_ms),
+        ]
+    }
+}
+
+/// Run a workflow step under an optional time limit, returning its output and duration
+///
+/// The step runs inside a `workflow_step` span carrying the workflow ID and
+/// step name, so logs from concurrent workflows can be told apart. The
+/// duration of a step that completes is reported to `metrics`.
+async fn run_step<T>(
+    workflow_id: &str,
+    step: &str,
+    limit: Option<Duration>,
+    metrics: Option<&dyn MetricsRecorder>,
+    future: impl Future<Output = Result<T>>,
+) -> Result<(T, Duration)> {
+    let span = tracing::info_span!("workflow_step", workflow_id = %workflow_id, step = %step);
+    let start = Instant::now();
+    let output = match limit {
+        Some(limit) => tokio::time::timeout(limit, future)
+            .instrument(span)
+            .await
+            .map_err(|_| Error::step_timeout(step, start.elapsed()))??,
+        None => future.instrument(span).await?,
+    };
+    let elapsed = start.elapsed();
+    if let Some(metrics) = metrics {
+        metrics.observe_workflow_step(step, elapsed);
+    }
+    Ok((output, elapsed))
+}
+
+/// Run a workflow step's work on the blocking thread pool
+///
+/// Steps compute without yielding, so run inline they would hold the runtime
+/// thread and their time limit could never fire. The work gets a runtime
+/// handle to drive async APIs to completion. A step that times out keeps
+/// running on its blocking thread and its output is discarded. The work runs
+/// in the caller's span and subscriber, so its logs keep the workflow and
+/// step context of [`run_step`].
+async fn blocking_step<T, F>(work: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&tokio::runtime::Handle) -> Result<T> + Send + 'static,
+{
+    let runtime = tokio::runtime::Handle::current();
+    let span = tracing::Span::current();
+    let dispatch = tracing::dispatcher::get_default(|dispatch| dispatch.clone());
+    tokio::task::spawn_blocking(move || {
+        tracing::dispatcher::with_default(&dispatch, || {
+            let _entered = span.enter();
+            work(&runtime)
+        })
+    })
+        .await
+        .map_err(|e| Error::internal(format!("Workflow step task failed: {}", e)))?
 }
 
 /// Complete gap analysis workflow result
@@ -63,6 +190,88 @@ pub struct GapAnalysisWorkflowResult {
     pub workflow_metadata: WorkflowMetadata,
 }
 
+/// Callback invoked with the name of each workflow step as it completes
+pub type StepCompletedCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Per-run options for [`GapAnalysisService::execute_workflow_with_options`]
+#[derive(Clone, Default)]
+pub struct WorkflowOptions {
+    /// Token used to abort the workflow between steps
+    pub cancellation_token: Option<CancellationToken>,
+    /// Progress callback invoked after each completed step
+    pub on_step_completed: Option<StepCompletedCallback>,
+    /// Correlation ID to use as the workflow ID, e.g. an incoming `X-Request-Id`
+    pub workflow_id: Option<String>,
+}
+
+/// Longest caller-supplied workflow ID that is accepted
+const MAX_WORKFLOW_ID_LEN: usize = 128;
+
+impl WorkflowOptions {
+    /// Create options with no cancellation or progress reporting
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Abort the workflow when the token is cancelled
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Report each completed step to the callback
+    pub fn with_step_callback(mut self, callback: StepCompletedCallback) -> Self {
+        self.on_step_completed = Some(callback);
+        self
+    }
+
+    /// Use a caller-supplied correlation ID as the workflow ID
+    ///
+    /// Blank IDs, IDs longer than 128 characters and IDs with control
+    /// characters are ignored and a UUID is generated instead.
+    pub fn with_workflow_id(mut self, workflow_id: impl Into<String>) -> Self {
+        self.workflow_id = Some(workflow_id.into());
+        self
+    }
+
+    /// The caller-supplied workflow ID if usable, otherwise a new UUID
+    fn resolve_workflow_id(&self) -> String {
+        self.workflow_id.as_deref()
+            .map(str::trim)
+            .filter(|id| !id.is_empty() && id.len() <= MAX_WORKFLOW_ID_LEN && !id.chars().any(char::is_control))
+            .map(str::to_string)
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string())
+    }
+
+    /// Fail with a cancellation error if the token has been cancelled
+    fn check_cancelled(&self, steps_completed: &[String]) -> Result<()> {
+        match &self.cancellation_token {
+            Some(token) if token.is_cancelled() => {
+                Err(Error::cancelled("gap analysis workflow", steps_completed.to_vec()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Record a completed step and notify the progress callback
+    fn complete_step(&self, steps_completed: &mut Vec<String>, step: &str) {
+        steps_completed.push(step.to_string());
+        if let Some(callback) = &self.on_step_completed {
+            callback(step);
+        }
+    }
+}
+
+impl std::fmt::Debug for WorkflowOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorkflowOptions")
+            .field("cancellation_token", &self.cancellation_token)
+            .field("on_step_completed", &self.on_step_completed.is_some())
+            .field("workflow_id", &self.workflow_id)
+            .finish()
+    }
+}
+
 /// Workflow execution metadata
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct WorkflowMetadata {
@@ -74,6 +283,11 @@ pub struct WorkflowMetadata {
 }
 
 impl GapAnalysisService {
+    /// Create a builder for configuring the service
+    pub fn builder() -> GapAnalysisServiceBuilder {
+        GapAnalysisServiceBuilder::new()
+    }
+
     /// Create a new gap analysis service
     pub fn new() -> Self {
         Self {
@@ -82,6 +296,7 @@ impl GapAnalysisService {
             prioritization_engine: PrioritizationEngine::new(),
             remediation_planner: RemediationPlanner::new(),
             config: GapAnalysisServiceConfig::default(),
+            metrics: None,
         }
     }
 
@@ -95,6 +310,7 @@ impl GapAnalysisService {
             prioritization_engine: PrioritizationEngine::new(),
             remediation_planner: RemediationPlanner::new(),
             config: GapAnalysisServiceConfig::default(),
+            metrics: None,
         })
     }
 
@@ -105,8 +321,27 @@ impl GapAnalysisService {
         framework_id: Option<String>,
         profile: Option<String>,
     ) -> Result<GapAnalysisWorkflowResult> {
-        let workflow_id = uuid::Uuid::new_v4().to_string();
-        let start_time = std::time::Instant::now();
+        self.execute_workflow_with_options(current_implementation, framework_id, profile, WorkflowOptions::default())
+            .await
+    }
+
+    /// Execute complete gap analysis workflow with cancellation and progress options
+    ///
+    /// The cancellation token is checked before each step. When it fires, the
+    /// workflow stops with [`Error::Cancelled`] listing the steps that completed.
+    /// Steps that exceed their configured [`StepTimeouts`] limit fail with
+    /// [`Error::StepTimeout`].
+    pub async fn execute_workflow_with_options(
+        &mut self,
+        current_implementation: &engine::CurrentImplementation,
+        framework_id: Option<String>,
+        profile: Option<String>,
+        options: WorkflowOptions,
+    ) -> Result<GapAnalysisWorkflowResult> {
+        let workflow_id = options.resolve_workflow_id();
+        let start_time = Instant::now();
+        let timeouts = self.config.step_timeouts.clone();
+        let metrics = self.metrics.as_deref();
         let mut steps_completed = Vec::new();
         let mut warnings = Vec::new();
         let mut performance_metrics = HashMap::new();
@@ -115,24 +350,49 @@ impl GapAnalysisService {
         let framework = framework_id.unwrap_or_else(|| self.config.default_framework.clone());
         let profile_name = profile.unwrap_or_else(|| self.config.default_profile.clone());
 
-        let step_start = std::time::Instant::now();
-        let target_baseline = self.baseline_manager.get_baseline(&framework, &profile_name).await?;
-        performance_metrics.insert("baseline_loading_ms".to_string(), step_start.elapsed().as_millis() as f64);
-        steps_completed.push("baseline_loading".to_string());
+        options.check_cancelled(&steps_completed)?;
+        let (target_baseline, elapsed) = run_step(
+            &workflow_id,
+            "baseline_loading",
+            timeouts.limit("baseline_loading"),
+            metrics,
+            self.baseline_manager.get_baseline(&framework, &profile_name),
+        ).await?;
+        performance_metrics.insert("baseline_loading_ms".to_string(), elapsed.as_millis() as f64);
+        options.complete_step(&mut steps_completed, "baseline_loading");
+
+        // Flag implementation control IDs the baseline does not know, e.g. `AC-02` for `AC-2`
+        warnings.extend(
+            self.engine.validate_control_references(current_implementation, &target_baseline)
+                .iter()
+                .map(ToString::to_string),
+        );
 
         // Step 2: Perform gap analysis
-        let step_start = std::time::Instant::now();
-        let analysis_result = self.engine.analyze_gaps(current_implementation, &target_baseline).await?;
-        performance_metrics.insert("gap_analysis_ms".to_string(), step_start.elapsed().as_millis() as f64);
-        steps_completed.push("gap_analysis".to_string());
+        options.check_cancelled(&steps_completed)?;
+        let engine = self.engine.clone();
+        let (implementation, baseline) = (current_implementation.clone(), target_baseline.clone());
+        let (analysis_result, elapsed) = run_step(
+            &workflow_id,
+            "gap_analysis",
+            timeouts.limit("gap_analysis"),
+            metrics,
+            blocking_step(move |runtime| runtime.block_on(engine.analyze_gaps(&implementation, &baseline))),
+        ).await?;
+        performance_metrics.insert("gap_analysis_ms".to_string(), elapsed.as_millis() as f64);
+        options.complete_step(&mut steps_completed, "gap_analysis");
 
         // Step 3: Prioritize gaps
-        let step_start = std::time::Instant::now();
-        let prioritized_gaps = if self.config.auto_prioritize {
-            self.prioritization_engine.prioritize_gaps(&analysis_result.gaps).await?
-        } else {
+        options.check_cancelled(&steps_completed)?;
+        let (auto_prioritize, prioritization_engine) = (self.config.auto_prioritize, self.prioritization_engine.clone());
+        let gaps = analysis_result.gaps.clone();
+        let (prioritized_gaps, elapsed) = run_step(&workflow_id, "prioritization", timeouts.limit("prioritization"), metrics, blocking_step(move |runtime| {
+            if auto_prioritize {
+                return runtime.block_on(prioritization_engine.prioritize_gaps(&gaps));
+            }
+
             // Convert gaps to prioritized gaps with default priority
-            analysis_result.gaps.iter().enumerate().map(|(index, gap)| {
+            Ok(gaps.iter().enumerate().map(|(index, gap)| {
                 PrioritizedGap {
                     gap: gap.clone(),
                     priority_score: 0.5,
@@ -154,26 +414,36 @@ impl GapAnalysisService {
                         alternative_rankings: HashMap::new(),
                     },
                 }
-            }).collect()
-        };
-        performance_metrics.insert("prioritization_ms".to_string(), step_start.elapsed().as_millis() as f64);
-        steps_completed.push("prioritization".to_string());
+            }).collect())
+        })).await?;
+        performance_metrics.insert("prioritization_ms".to_string(), elapsed.as_millis() as f64);
+        options.complete_step(&mut steps_completed, "prioritization");
 
         // Step 4: Generate prioritization matrix
-        let step_start = std::time::Instant::now();
-        let prioritization_matrix = self.prioritization_engine.generate_prioritization_matrix(&prioritized_gaps)?;
-        performance_metrics.insert("matrix_generation_ms".to_string(), step_start.elapsed().as_millis() as f64);
-        steps_completed.push("matrix_generation".to_string());
+        options.check_cancelled(&steps_completed)?;
+        let prioritization_engine = self.prioritization_engine.clone();
+        let gaps = prioritized_gaps.clone();
+        let (prioritization_matrix, elapsed) = run_step(&workflow_id, "matrix_generation", timeouts.limit("matrix_generation"), metrics, blocking_step(move |_| {
+            prioritization_engine.generate_prioritization_matrix(&gaps)
+        })).await?;
+        performance_metrics.insert("matrix_generation_ms".to_string(), elapsed.as_millis() as f64);
+        options.complete_step(&mut steps_completed, "matrix_generation");
 
         // Step 5: Generate remediation plan (if enabled)
         let remediation_plan = if self.config.auto_generate_plans {
-            let step_start = std::time::Instant::now();
-            let plan = self.remediation_planner.generate_plan(
-                &prioritized_gaps,
-                format!("Remediation Plan for {} - {}", framework, profile_name),
+            options.check_cancelled(&steps_completed)?;
+            let remediation_planner = self.remediation_planner.clone();
+            let gaps = prioritized_gaps.clone();
+            let title = format!("Remediation Plan for {} - {}", framework, profile_name);
+            let (plan, elapsed) = run_step(
+                &workflow_id,
+                "remediation_planning",
+                timeouts.limit("remediation_planning"),
+                metrics,
+                blocking_step(move |runtime| runtime.block_on(remediation_planner.generate_plan(&gaps, title))),
             ).await?;
-            performance_metrics.insert("remediation_planning_ms".to_string(), step_start.elapsed().as_millis() as f64);
-            steps_completed.push("remediation_planning".to_string());
+            performance_metrics.insert("remediation_planning_ms".to_string(), elapsed.as_millis() as f64);
+            options.complete_step(&mut steps_completed, "remediation_planning");
             Some(plan)
         } else {
             None
@@ -181,6 +451,9 @@ impl GapAnalysisService {
 
         let total_execution_time = start_time.elapsed();
         performance_metrics.insert("total_execution_ms".to_string(), total_execution_time.as_millis() as f64);
+        if let Some(metrics) = metrics {
+            metrics.observe_workflow(total_execution_time);
+        }
 
         // Add performance warnings if needed
         if total_execution_time.as_secs() > 30 {
@@ -226,6 +499,7 @@ impl GapAnalysisService {
 
     /// Update service configuration
     pub fn update_config(&mut self, config: GapAnalysisServiceConfig) {
+        self.baseline_manager.set_retry_policy(config.baseline_retry.clone());
         self.config = config;
     }
 
@@ -240,6 +514,256 @@ impl GapAnalysisService {
     }
 }
 
+/// Baseline source selected on a [`GapAnalysisServiceBuilder`]
+#[derive(Debug, Clone)]
+enum BaselineSourceSelection {
+    Json { mappings_path: String },
+    OscalProfile { profile_path: String, framework_id: String, profile_name: String },
+    Custom(Arc<dyn BaselineLoader>),
+}
+
+/// Builder for configuring GapAnalysisService instances
+#[derive(Debug, Clone, Default)]
+pub struct GapAnalysisServiceBuilder {
+    baseline_sources: Vec<BaselineSourceSelection>,
+    config: GapAnalysisServiceConfig,
+    default_target_set: bool,
+    prioritization_criteria: Option<PrioritizationCriteria>,
+    quadrant_thresholds: Option<QuadrantThresholds>,
+    clock: Option<fedramp_core::SharedClock>,
+    metrics: Option<SharedMetricsRecorder>,
+    control_inheritance: Option<ControlInheritance>,
+    control_criticality: Option<ControlCriticality>,
+}
+
+impl GapAnalysisServiceBuilder {
+    /// Create a new builder with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load baselines from a JSON control mappings file
+    pub fn json_baselines(mut self, mappings_path: impl Into<String>) -> Self {
+        self.baseline_sources.push(BaselineSourceSelection::Json {
+            mappings_path: mappings_path.into(),
+        });
+        self
+    }
+
+    /// Load the baseline from an OSCAL profile
+    ///
+    /// Unless [`default_target`](Self::default_target) is also called, the
+    /// profile becomes the service's default framework and profile.
+    pub fn oscal_profile(
+        mut self,
+        profile_path: impl Into<String>,
+        framework_id: impl Into<String>,
+        profile_name: impl Into<String>,
+    ) -> Self {
+        self.baseline_sources.push(BaselineSourceSelection::OscalProfile {
+            profile_path: profile_path.into(),
+            framework_id: framework_id.into(),
+            profile_name: profile_name.into(),
+        });
+        self
+    }
+
+    /// Load baselines with a caller-supplied loader
+    pub fn baseline_loader(mut self, loader: Arc<dyn BaselineLoader>) -> Self {
+        self.baseline_sources.push(BaselineSourceSelection::Custom(loader));
+        self
+    }
+
+    /// Set the default framework and baseline profile
+    pub fn default_target(mut self, framework_id: impl Into<String>, profile: impl Into<String>) -> Self {
+        self.config.default_framework = framework_id.into();
+        self.config.default_profile = profile.into();
+        self.default_target_set = true;
+        self
+    }
+
+    /// Enable or disable automatic prioritization
+    pub fn auto_prioritize(mut self, auto_prioritize: bool) -> Self {
+        self.config.auto_prioritize = auto_prioritize;
+        self
+    }
+
+    /// Enable or disable automatic remediation planning
+    pub fn auto_generate_plans(mut self, auto_generate_plans: bool) -> Self {
+        self.config.auto_generate_plans = auto_generate_plans;
+        self
+    }
+
+    /// Enable or disable result caching
+    pub fn cache_results(mut self, cache_results: bool) -> Self {
+        self.config.cache_results = cache_results;
+        self
+    }
+
+    /// Set the maximum gaps to analyze in one batch
+    pub fn max_gaps_per_analysis(mut self, max_gaps_per_analysis: usize) -> Self {
+        self.config.max_gaps_per_analysis = max_gaps_per_analysis;
+        self
+    }
+
+    /// Set time limits for individual workflow steps
+    pub fn step_timeouts(mut self, step_timeouts: StepTimeouts) -> Self {
+        self.config.step_timeouts = step_timeouts;
+        self
+    }
+
+    /// Retry transient baseline store failures according to the given policy
+    pub fn baseline_retry(mut self, retry_policy: RetryPolicy) -> Self {
+        self.config.baseline_retry = retry_policy;
+        self
+    }
+
+    /// Use custom prioritization criteria weights
+    pub fn prioritization_criteria(mut self, criteria: PrioritizationCriteria) -> Self {
+        self.prioritization_criteria = Some(criteria);
+        self
+    }
+
+    /// Split the prioritization matrix into quadrants at the given thresholds
+    pub fn quadrant_thresholds(mut self, thresholds: QuadrantThresholds) -> Self {
+        self.quadrant_thresholds = Some(thresholds);
+        self
+    }
+
+    /// Use the given time source for baseline cache expiry
+    pub fn clock(mut self, clock: fedramp_core::SharedClock) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Report workflow and step durations to the given recorder
+    pub fn metrics(mut self, metrics: SharedMetricsRecorder) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Credit controls inherited from an infrastructure or platform provider
+    pub fn control_inheritance(mut self, inheritance: ControlInheritance) -> Self {
+        self.control_inheritance = Some(inheritance);
+        self
+    }
+
+    /// Floor the severity of gaps on critical controls
+    pub fn control_criticality(mut self, criticality: ControlCriticality) -> Self {
+        self.control_criticality = Some(criticality);
+        self
+    }
+
+    /// Build the GapAnalysisService instance
+    ///
+    /// Fails if more than one baseline source was selected, if custom
+    /// prioritization criteria are combined with disabled prioritization, or if
+    /// any setting is out of range.
+    pub fn build(self) -> Result<GapAnalysisService> {
+        if self.baseline_sources.len() > 1 {
+            return Err(Error::configuration(
+                "Only one baseline source can be configured: choose JSON baselines, an OSCAL profile, or a custom loader",
+            ));
+        }
+
+        if self.config.max_gaps_per_analysis == 0 {
+            return Err(Error::configuration("max_gaps_per_analysis must be greater than zero"));
+        }
+
+        for (name, limit_ms) in self.config.step_timeouts.entries() {
+            if limit_ms == Some(0) {
+                return Err(Error::configuration(format!("Step timeout '{}' must be greater than zero", name)));
+            }
+        }
+
+        self.config.baseline_retry.validate()?;
+
+        if let Some(criteria) = &self.prioritization_criteria {
+            if !self.config.auto_prioritize {
+                return Err(Error::configuration(
+                    "Custom prioritization criteria require auto-prioritize to be enabled",
+                ));
+            }
+            validate_prioritization_criteria(criteria)?;
+        }
+        if let Some(thresholds) = &self.quadrant_thresholds {
+            thresholds.validate()?;
+        }
+
+        let mut config = self.config;
+        let baseline_manager = match self.baseline_sources.into_iter().next() {
+            Some(BaselineSourceSelection::Json { mappings_path }) => BaselineManager::with_json_loader(mappings_path)?,
+            Some(BaselineSourceSelection::OscalProfile { profile_path, framework_id, profile_name }) => {
+                if !self.default_target_set {
+                    config.default_framework = framework_id.clone();
+                    config.default_profile = profile_name.clone();
+                }
+                BaselineManager::with_oscal_profile(profile_path, framework_id, profile_name)?
+            }
+            Some(BaselineSourceSelection::Custom(loader)) => BaselineManager::with_loader(loader),
+            None => BaselineManager::new(),
+        };
+        let baseline_manager = match self.clock {
+            Some(clock) => baseline_manager.with_clock(clock),
+            None => baseline_manager,
+        };
+        let baseline_manager = baseline_manager.with_retry_policy(config.baseline_retry.clone());
+
+        let mut prioritization_engine = PrioritizationEngine::new();
+        if let Some(criteria) = self.prioritization_criteria {
+            prioritization_engine.criteria = criteria;
+        }
+        if let Some(thresholds) = self.quadrant_thresholds {
+            prioritization_engine = prioritization_engine.with_quadrant_thresholds(thresholds);
+        }
+
+        let engine = match self.control_inheritance {
+            Some(inheritance) => GapAnalysisEngine::new().with_control_inheritance(inheritance),
+            None => GapAnalysisEngine::new(),
+        };
+        let engine = match self.control_criticality {
+            Some(criticality) => engine.with_control_criticality(criticality),
+            None => engine,
+        };
+
+        Ok(GapAnalysisService {
+            engine,
+            baseline_manager,
+            prioritization_engine,
+            remediation_planner: RemediationPlanner::new(),
+            config,
+            metrics: self.metrics,
+        })
+    }
+}
+
+/// Check that every criteria weight is within 0.0 - 1.0 and at least one is non-zero
+fn validate_prioritization_criteria(criteria: &PrioritizationCriteria) -> Result<()> {
+    let weights = [
+        ("risk_weight", criteria.risk_weight),
+        ("business_impact_weight", criteria.business_impact_weight),
+        ("effort_weight", criteria.effort_weight),
+        ("roi_weight", criteria.roi_weight),
+        ("compliance_urgency_weight", criteria.compliance_urgency_weight),
+        ("stakeholder_priority_weight", criteria.stakeholder_priority_weight),
+    ];
+
+    for (name, weight) in weights {
+        if !(0.0..=1.0).contains(&weight) {
+            return Err(Error::configuration(format!(
+                "Prioritization weight '{}' must be between 0.0 and 1.0, got {}",
+                name, weight
+            )));
+        }
+    }
+
+    if weights.iter().all(|(_, weight)| *weight == 0.0) {
+        return Err(Error::configuration("At least one prioritization weight must be non-zero"));
+    }
+
+    Ok(())
+}
+
 /// Service statistics
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ServiceStatistics {
@@ -258,6 +782,8 @@ impl Default for GapAnalysisServiceConfig {
             auto_generate_plans: true,
             cache_results: true,
             max_gaps_per_analysis: 1000,
+            step_timeouts: StepTimeouts::default(),
+            baseline_retry: RetryPolicy::default(),
         }
     }
 }
@@ -267,3 +793,618 @@ impl Default for GapAnalysisService {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OSCAL_PROFILE: &str = r#"{
+        "profile": {
+            "uuid": "8c000726-ba93-480f-a221-8894dc7747f5",
+            "metadata": { "title": "Test Moderate Baseline", "version": "1.0.0" },
+            "imports": [
+                {
+                    "href": "https://example.com/NIST_SP-800-53_rev5_catalog.json",
+                    "include-controls": [ { "with-ids": ["ac-1", "ac-2", "ac-2.1", "au-2"] } ]
+                }
+            ]
+        }
+    }"#;
+
+    fn write_profile(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("{}-{}.json", name, uuid::Uuid::new_v4()));
+        std::fs::write(&path, OSCAL_PROFILE).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[tokio::test]
+    async fn test_builder_with_oscal_baselines_and_custom_weights() {
+        let profile_path = write_profile("builder-oscal-profile");
+        let criteria = PrioritizationCriteria {
+            risk_weight: 0.5,
+            business_impact_weight: 0.2,
+            effort_weight: 0.1,
+            roi_weight: 0.1,
+            compliance_urgency_weight: 0.1,
+            stakeholder_priority_weight: 0.0,
+        };
+
+        let mut service = GapAnalysisService::builder()
+            .oscal_profile(profile_path.clone(), "nist-800-53", "moderate")
+            .auto_generate_plans(false)
+            .max_gaps_per_analysis(250)
+            .prioritization_criteria(criteria)
+            .build()
+            .unwrap();
+
+        assert_eq!(service.config.default_framework, "nist-800-53");
+        assert_eq!(service.config.default_profile, "moderate");
+        assert!(service.config.auto_prioritize);
+        assert!(!service.config.auto_generate_plans);
+        assert_eq!(service.config.max_gaps_per_analysis, 250);
+        assert_eq!(service.prioritization_engine.criteria.risk_weight, 0.5);
+        assert_eq!(service.get_available_profiles("nist-800-53").unwrap(), vec!["moderate".to_string()]);
+
+        let baseline = service.baseline_manager.get_baseline("nist-800-53", "moderate").await.unwrap();
+        assert_eq!(baseline.required_controls.len(), 3);
+        assert_eq!(baseline.required_controls["AC-2"].enhancements, vec!["AC-2(1)".to_string()]);
+
+        std::fs::remove_file(profile_path).unwrap();
+    }
+
+    fn empty_implementation() -> engine::CurrentImplementation {
+        engine::CurrentImplementation {
+            system_id: "test-system".to_string(),
+            controls: HashMap::new(),
+            last_updated: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_workflow_cancelled_before_prioritization() {
+        let profile_path = write_profile("cancel-oscal-profile");
+        let mut service = GapAnalysisService::builder()
+            .oscal_profile(profile_path.clone(), "nist-800-53", "moderate")
+            .build()
+            .unwrap();
+
+        let token = CancellationToken::new();
+        let cancel_after_analysis = token.clone();
+        let options = WorkflowOptions::new()
+            .with_cancellation_token(token)
+            .with_step_callback(std::sync::Arc::new(move |step: &str| {
+                if step == "gap_analysis" {
+                    cancel_after_analysis.cancel();
+                }
+            }));
+
+        let result = service
+            .execute_workflow_with_options(&empty_implementation(), None, None, options)
+            .await;
+
+        match result {
+            Err(Error::Cancelled { completed_steps, .. }) => {
+                assert_eq!(completed_steps, vec!["baseline_loading".to_string(), "gap_analysis".to_string()]);
+            }
+            other => panic!("expected cancellation, got {:?}", other.map(|r| r.workflow_metadata)),
+        }
+
+        std::fs::remove_file(profile_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_workflow_runs_to_completion_without_cancellation() {
+        let profile_path = write_profile("uncancelled-oscal-profile");
+        let mut service = GapAnalysisService::builder()
+            .oscal_profile(profile_path.clone(), "nist-800-53", "moderate")
+            .build()
+            .unwrap();
+
+        let options = WorkflowOptions::new().with_cancellation_token(CancellationToken::new());
+        let result = service
+            .execute_workflow_with_options(&empty_implementation(), None, None, options)
+            .await
+            .unwrap();
+
+        assert_eq!(result.workflow_metadata.steps_completed.len(), 5);
+        std::fs::remove_file(profile_path).unwrap();
+    }
+
+    /// Records the `workflow_id` and `step` fields of every new span
+    #[derive(Clone, Default)]
+    struct SpanRecorder {
+        spans: std::sync::Arc<std::sync::Mutex<Vec<HashMap<String, String>>>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut HashMap<String, String>);
+
+    impl tracing::field::Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0.insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SpanRecorder {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            _id: &tracing::span::Id,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = HashMap::from([("name".to_string(), attrs.metadata().name().to_string())]);
+            attrs.record(&mut FieldVisitor(&mut fields));
+            self.spans.lock().unwrap().push(fields);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_workflow_steps_carry_caller_workflow_id() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let recorder = SpanRecorder::default();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+        let profile_path = write_profile("correlation-oscal-profile");
+        let mut service = GapAnalysisService::builder()
+            .oscal_profile(profile_path.clone(), "nist-800-53", "moderate")
+            .build()
+            .unwrap();
+
+        let options = WorkflowOptions::new().with_workflow_id("req-7f3a");
+        let result = service
+            .execute_workflow_with_options(&empty_implementation(), None, None, options)
+            .await
+            .unwrap();
+        assert_eq!(result.workflow_metadata.workflow_id, "req-7f3a");
+
+        let step_spans: Vec<_> = recorder.spans.lock().unwrap().iter()
+            .filter(|fields| fields["name"] == "workflow_step")
+            .cloned()
+            .collect();
+        assert!(step_spans.iter().all(|fields| fields["workflow_id"] == "req-7f3a"));
+        let steps: Vec<&str> = step_spans.iter().map(|fields| fields["step"].as_str()).collect();
+        assert_eq!(steps, result.workflow_metadata.steps_completed.iter().map(String::as_str).collect::<Vec<_>>());
+
+        std::fs::remove_file(profile_path).unwrap();
+    }
+
+    /// Records the name of the span each event is logged in
+    #[derive(Clone, Default)]
+    struct EventSpanRecorder {
+        spans: std::sync::Arc<std::sync::Mutex<Vec<Option<String>>>>,
+    }
+
+    impl<S> tracing_subscriber::Layer<S> for EventSpanRecorder
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_event(&self, event: &tracing::Event<'_>, ctx: tracing_subscriber::layer::Context<'_, S>) {
+            let span = ctx.event_span(event).map(|span| span.name().to_string());
+            self.spans.lock().unwrap().push(span);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_blocking_step_logs_in_the_step_span() {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let recorder = EventSpanRecorder::default();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+        let step = blocking_step(|_| {
+            tracing::info!("analyzing");
+            Ok(())
+        });
+        run_step("req-7f3a", "gap_analysis", None, None, step).await.unwrap();
+
+        assert_eq!(*recorder.spans.lock().unwrap(), [Some("workflow_step".to_string())]);
+    }
+
+    #[test]
+    fn test_unusable_workflow_id_falls_back_to_uuid() {
+        assert_eq!(WorkflowOptions::new().with_workflow_id(" req-1 ").resolve_workflow_id(), "req-1");
+        for id in ["", "   ", "bad\nid", &"x".repeat(MAX_WORKFLOW_ID_LEN + 1)] {
+            let resolved = WorkflowOptions::new().with_workflow_id(id).resolve_workflow_id();
+            assert!(uuid::Uuid::parse_str(&resolved).is_ok(), "{:?} resolved to {:?}", id, resolved);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_workflow_warns_about_unrecognized_control_ids() {
+        let profile_path = write_profile("unrecognized-oscal-profile");
+        let mut service = GapAnalysisService::builder()
+            .oscal_profile(profile_path.clone(), "nist-800-53", "moderate")
+            .build()
+            .unwrap();
+
+        let mut implementation = empty_implementation();
+        implementation.controls.insert("AC-02".to_string(), engine::ControlImplementation {
+            control_id: "AC-02".to_string(),
+            status: ImplementationStatus::Implemented,
+            implementation_date: None,
+            evidence: Vec::new(),
+            parameters: HashMap::new(),
+            completion_fraction: None,
+            last_modified: None,
+        });
+
+        let result = service.execute_workflow(&implementation, None, None).await.unwrap();
+
+        assert!(result.workflow_metadata.warnings.iter().any(|warning| warning.contains("did you mean 'AC-2'")));
+        std::fs::remove_file(profile_path).unwrap();
+    }
+
+    /// Loader that blocks for a fixed delay before failing
+    #[derive(Debug)]
+    struct SlowBaselineLoader {
+        delay: Duration,
+    }
+
+    impl BaselineLoader for SlowBaselineLoader {
+        fn load_baseline(&self, framework_id: &str, _profile: &str) -> Result<TargetBaseline> {
+            std::thread::sleep(self.delay);
+            Err(Error::not_found(format!("No baseline for framework: {}", framework_id)))
+        }
+
+        fn get_available_profiles(&self, _framework_id: &str) -> Result<Vec<String>> {
+            Ok(Vec::new())
+        }
+
+        fn validate_baseline(&self, _baseline: &TargetBaseline) -> Result<ValidationResult> {
+            Err(Error::internal("not used"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_baseline_loading_times_out() {
+        let mut service = GapAnalysisService::builder()
+            .baseline_loader(Arc::new(SlowBaselineLoader { delay: Duration::from_millis(500) }))
+            .step_timeouts(StepTimeouts {
+                baseline_loading_ms: Some(50),
+                ..StepTimeouts::default()
+            })
+            .build()
+            .unwrap();
+
+        let result = service.execute_workflow(&empty_implementation(), None, None).await;
+
+        match result {
+            Err(Error::StepTimeout { step, elapsed }) => {
+                assert_eq!(step, "baseline_loading");
+                assert!(elapsed >= Duration::from_millis(50));
+                assert!(elapsed < Duration::from_millis(500));
+            }
+            other => panic!("expected step timeout, got {:?}", other.map(|r| r.workflow_metadata)),
+        }
+    }
+
+    /// Baseline loader returning a fixed baseline
+    #[derive(Debug)]
+    struct FixedBaselineLoader {
+        baseline: TargetBaseline,
+    }
+
+    impl BaselineLoader for FixedBaselineLoader {
+        fn load_baseline(&self, _framework_id: &str, _profile: &str) -> Result<TargetBaseline> {
+            Ok(self.baseline.clone())
+        }
+
+        fn get_available_profiles(&self, _framework_id: &str) -> Result<Vec<String>> {
+            Ok(vec![self.baseline.profile_name.clone()])
+        }
+
+        fn validate_baseline(&self, _baseline: &TargetBaseline) -> Result<ValidationResult> {
+            Err(Error::internal("not used"))
+        }
+    }
+
+    /// Scoring strategy that blocks its thread before scoring
+    #[derive(Debug)]
+    struct BlockingStrategy {
+        delay: Duration,
+    }
+
+    impl ScoringStrategy for BlockingStrategy {
+        fn score(&self, gap: &Gap, criteria: &PrioritizationCriteria) -> ScoringBreakdown {
+            std::thread::sleep(self.delay);
+            WeightedSumStrategy.score(gap, criteria)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_blocking_prioritization_times_out() {
+        let (mut implementation, mut baseline) = engine::tests::fixtures();
+        implementation.controls.get_mut("AC-2").unwrap().status = ImplementationStatus::Implemented;
+        baseline.required_controls.get_mut("AC-2").unwrap().parameters.insert(
+            "ac-2_prm_1".to_string(),
+            engine::ParameterRequirement { required_value: "account review period".to_string(), validation_rule: String::new() },
+        );
+        let mut service = GapAnalysisService::builder()
+            .baseline_loader(Arc::new(FixedBaselineLoader { baseline }))
+            .default_target("nist-800-53", "moderate")
+            .step_timeouts(StepTimeouts {
+                prioritization_ms: Some(50),
+                ..StepTimeouts::default()
+            })
+            .build()
+            .unwrap();
+        service.prioritization_engine = service.prioritization_engine.clone()
+            .with_scoring_strategy(Arc::new(BlockingStrategy { delay: Duration::from_millis(500) }));
+
+        let result = service.execute_workflow(&implementation, None, None).await;
+
+        match result {
+            Err(Error::StepTimeout { step, elapsed }) => {
+                assert_eq!(step, "prioritization");
+                assert!(elapsed < Duration::from_millis(500));
+            }
+            other => panic!("expected step timeout, got {:?}", other.map(|r| r.workflow_metadata)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_steps_within_timeout_record_timings() {
+        let profile_path = write_profile("timed-oscal-profile");
+        let mut service = GapAnalysisService::builder()
+            .oscal_profile(profile_path.clone(), "nist-800-53", "moderate")
+            .step_timeouts(StepTimeouts::uniform(10_000))
+            .build()
+            .unwrap();
+
+        let result = service.execute_workflow(&empty_implementation(), None, None).await.unwrap();
+
+        let metrics = &result.workflow_metadata.performance_metrics;
+        for key in ["baseline_loading_ms", "gap_analysis_ms", "prioritization_ms", "matrix_generation_ms"] {
+            assert!(metrics.contains_key(key), "missing timing for {}", key);
+        }
+        std::fs::remove_file(profile_path).unwrap();
+    }
+
+    /// Recorder keeping every observed step and workflow
+    #[derive(Debug, Default)]
+    struct RecordingMetrics {
+        steps: std::sync::Mutex<Vec<String>>,
+        workflows: std::sync::Mutex<usize>,
+    }
+
+    impl MetricsRecorder for RecordingMetrics {
+        fn observe_workflow_step(&self, step: &str, _duration: Duration) {
+            self.steps.lock().unwrap().push(step.to_string());
+        }
+
+        fn observe_workflow(&self, _duration: Duration) {
+            *self.workflows.lock().unwrap() += 1;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_workflow_reports_step_and_total_durations_to_metrics() {
+        let profile_path = write_profile("metered-oscal-profile");
+        let metrics = Arc::new(RecordingMetrics::default());
+        let mut service = GapAnalysisService::builder()
+            .oscal_profile(profile_path.clone(), "nist-800-53", "moderate")
+            .metrics(metrics.clone())
+            .build()
+            .unwrap();
+
+        service.execute_workflow(&empty_implementation(), None, None).await.unwrap();
+
+        assert_eq!(
+            *metrics.steps.lock().unwrap(),
+            vec!["baseline_loading", "gap_analysis", "prioritization", "matrix_generation", "remediation_planning"]
+        );
+        assert_eq!(*metrics.workflows.lock().unwrap(), 1);
+        std::fs::remove_file(profile_path).unwrap();
+    }
+
+    #[test]
+    fn test_builder_rejects_zero_step_timeout() {
+        let result = GapAnalysisService::builder()
+            .step_timeouts(StepTimeouts {
+                gap_analysis_ms: Some(0),
+                ..StepTimeouts::default()
+            })
+            .build();
+
+        assert!(matches!(result, Err(Error::Configuration { .. })));
+    }
+
+    #[test]
+    fn test_builder_rejects_multiple_baseline_sources() {
+        let result = GapAnalysisService::builder()
+            .json_baselines("mappings/control_mappings.json")
+            .oscal_profile("profile.json", "nist-800-53", "moderate")
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_builder_rejects_criteria_without_prioritization() {
+        let result = GapAnalysisService::builder()
+            .auto_prioritize(false)
+            .prioritization_criteria(PrioritizationCriteria::default())
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_builder_rejects_out_of_range_weights() {
+        let criteria = PrioritizationCriteria {
+            risk_weight: 1.5,
+            ..PrioritizationCriteria::default()
+        };
+
+        assert!(GapAnalysisService::builder().prioritization_criteria(criteria).build().is_err());
+        assert!(GapAnalysisService::builder().max_gaps_per_analysis(0).build().is_err());
+    }
+
+    /// In-memory store standing in for an object-storage or database backend
+    #[derive(Debug, Default)]
+    struct InMemoryBaselineStore {
+        baselines: HashMap<(String, String), TargetBaseline>,
+    }
+
+    impl InMemoryBaselineStore {
+        fn with_baseline(mut self, framework_id: &str, profile: &str, control_ids: &[&str]) -> Self {
+            let required_controls = control_ids.iter()
+                .map(|id| (id.to_string(), engine::RequiredControl {
+                    control_id: id.to_string(),
+                    required_status: ImplementationStatus::Implemented,
+                    enhancements: Vec::new(),
+                    parameters: HashMap::new(),
+                }))
+                .collect();
+            let baseline = TargetBaseline {
+                framework_id: framework_id.to_string(),
+                profile_name: profile.to_string(),
+                required_controls,
+                baseline_metadata: engine::BaselineMetadata {
+                    version: "test".to_string(),
+                    last_updated: chrono::Utc::now(),
+                },
+            };
+            self.baselines.insert((framework_id.to_string(), profile.to_string()), baseline);
+            self
+        }
+    }
+
+    impl BaselineStore for InMemoryBaselineStore {
+        fn get(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline> {
+            self.baselines.get(&(framework_id.to_string(), profile.to_string()))
+                .cloned()
+                .ok_or_else(|| Error::not_found(format!("Profile not found: {} for framework {}", profile, framework_id)))
+        }
+
+        fn list_frameworks(&self) -> Result<Vec<String>> {
+            let mut frameworks: Vec<String> = self.baselines.keys().map(|(framework, _)| framework.clone()).collect();
+            frameworks.sort();
+            frameworks.dedup();
+            Ok(frameworks)
+        }
+
+        fn list_profiles(&self, framework_id: &str) -> Result<Vec<String>> {
+            Ok(self.baselines.keys()
+                .filter(|(framework, _)| framework == framework_id)
+                .map(|(_, profile)| profile.clone())
+                .collect())
+        }
+
+        fn validate(&self, baseline: &TargetBaseline) -> Result<ValidationResult> {
+            Ok(ValidationResult {
+                is_valid: true,
+                errors: Vec::new(),
+                warnings: Vec::new(),
+                statistics: baseline::ValidationStatistics {
+                    total_controls: baseline.required_controls.len(),
+                    valid_controls: baseline.required_controls.len(),
+                    invalid_controls: 0,
+                    missing_enhancements: 0,
+                    missing_parameters: 0,
+                },
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_baseline_manager_with_in_memory_store() {
+        let store = InMemoryBaselineStore::default()
+            .with_baseline("nist-800-53", "moderate", &["AC-2", "SC-7"])
+            .with_baseline("nist-800-171", "cui", &["3.1.1"]);
+        let mut manager = BaselineManager::with_store(store);
+
+        assert_eq!(manager.get_available_frameworks().unwrap(), vec!["nist-800-171", "nist-800-53"]);
+        assert_eq!(manager.get_available_profiles("nist-800-53").unwrap(), vec!["moderate"]);
+
+        let baseline = manager.get_baseline("nist-800-53", "moderate").await.unwrap();
+        assert_eq!(baseline.required_controls.len(), 2);
+        assert!(manager.get_baseline("nist-800-53", "high").await.is_err());
+
+        let validation = manager.validate_baselines().await.unwrap();
+        assert!(validation["nist-800-53:moderate"].is_valid);
+    }
+
+    /// Loader standing in for a network-backed store that fails a set number of reads
+    #[derive(Debug)]
+    struct FlakyLoader {
+        store: InMemoryBaselineStore,
+        failures: u32,
+        attempts: std::sync::atomic::AtomicU32,
+    }
+
+    impl FlakyLoader {
+        fn new(failures: u32) -> Self {
+            Self {
+                store: InMemoryBaselineStore::default().with_baseline("nist-800-53", "moderate", &["AC-2"]),
+                failures,
+                attempts: std::sync::atomic::AtomicU32::new(0),
+            }
+        }
+
+        fn attempts(&self) -> u32 {
+            self.attempts.load(std::sync::atomic::Ordering::SeqCst)
+        }
+    }
+
+    impl BaselineLoader for FlakyLoader {
+        fn load_baseline(&self, framework_id: &str, profile: &str) -> Result<TargetBaseline> {
+            let attempt = self.attempts.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            if attempt <= self.failures {
+                return Err(Error::external_service("baseline-store", "503 Service Unavailable"));
+            }
+            self.store.get(framework_id, profile)
+        }
+
+        fn get_available_profiles(&self, framework_id: &str) -> Result<Vec<String>> {
+            self.store.list_profiles(framework_id)
+        }
+
+        fn validate_baseline(&self, baseline: &TargetBaseline) -> Result<ValidationResult> {
+            self.store.validate(baseline)
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_transient_baseline_failures_are_retried() {
+        let loader = Arc::new(FlakyLoader::new(2));
+        let mut service = GapAnalysisService::builder()
+            .baseline_loader(loader.clone())
+            .baseline_retry(RetryPolicy { max_attempts: 3, ..RetryPolicy::default() })
+            .build()
+            .unwrap();
+
+        let baseline = service.baseline_manager.get_baseline("nist-800-53", "moderate").await.unwrap();
+
+        assert_eq!(baseline.required_controls.len(), 1);
+        assert_eq!(loader.attempts(), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_baseline_retries_stop_at_attempt_budget_and_on_not_found() {
+        let loader = Arc::new(FlakyLoader::new(5));
+        let mut service = GapAnalysisService::builder()
+            .baseline_loader(loader.clone())
+            .baseline_retry(RetryPolicy { max_attempts: 3, ..RetryPolicy::default() })
+            .build()
+            .unwrap();
+
+        let result = service.baseline_manager.get_baseline("nist-800-53", "moderate").await;
+        assert!(matches!(result, Err(Error::ExternalService { .. })));
+        assert_eq!(loader.attempts(), 3);
+
+        let loader = Arc::new(FlakyLoader::new(0));
+        let mut manager = BaselineManager::with_loader(loader.clone());
+        assert!(matches!(manager.get_baseline("nist-800-53", "high").await, Err(Error::NotFound { .. })));
+        assert_eq!(loader.attempts(), 1);
+    }
+
+    #[test]
+    fn test_builder_rejects_zero_retry_attempts() {
+        let result = GapAnalysisService::builder()
+            .baseline_retry(RetryPolicy { max_attempts: 0, ..RetryPolicy::default() })
+            .build();
+
+        assert!(matches!(result, Err(Error::Configuration { .. })));
+    }
+}
@@ -6,6 +6,7 @@
 use fedramp_core::{Result, Error};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use crate::engine::{Gap, GapSeverity, BusinessImpact, ImpactAssessment};
 
 /// Prioritization engine for gap remediation
@@ -17,6 +18,8 @@ pub struct PrioritizationEngine {
     pub algorithms: Vec<PrioritizationAlgorithm>,
     /// Configuration settings
     pub config: PrioritizationConfig,
+    /// Strategy used when the algorithm is `Custom`
+    pub custom_strategy: Option<Arc<dyn ScoringStrategy>>,
 }
 
 /// Prioritization criteria with configurable weights
@@ -63,6 +66,45 @@ pub struct PrioritizationConfig {
     pub consensus_threshold: f64,
     /// Priority categories
     pub priority_categories: PriorityCategories,
+    /// Axis boundaries between the prioritization matrix quadrants
+    #[serde(default)]
+    pub quadrant_thresholds: QuadrantThresholds,
+}
+
+/// High/low boundaries of the prioritization matrix axes
+///
+/// A score exactly on a boundary counts as high, i.e. it goes to the
+/// higher-priority side.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QuadrantThresholds {
+    /// Business impact at or above which a gap is high impact (0.0 - 1.0)
+    pub impact: f64,
+    /// Inverted effort score at or above which a gap is low effort (0.0 - 1.0)
+    pub effort: f64,
+}
+
+impl QuadrantThresholds {
+    /// Check that both thresholds are within 0.0 - 1.0
+    pub fn validate(&self) -> Result<()> {
+        for (name, threshold) in [("impact", self.impact), ("effort", self.effort)] {
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(Error::configuration(format!(
+                    "Quadrant threshold '{}' must be between 0.0 and 1.0, got {}",
+                    name, threshold
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for QuadrantThresholds {
+    fn default() -> Self {
+        Self {
+            impact: 0.7,
+            effort: 0.7,
+        }
+    }
 }
 
 /// Priority categories with thresholds
@@ -95,6 +137,84 @@ pub struct PrioritizedGap {
     pub metadata: PrioritizationMetadata,
 }
 
+impl PrioritizedGap {
+    /// Human-readable rationale for the gap's rank
+    ///
+    /// Names the two largest weighted contributions, ties broken by name so the
+    /// text is stable, together with the gap's type, severity and effort, e.g.
+    /// "Ranked #3 (high priority): AC-2 is missing a parameter value (high severity).
+    /// Driven mainly by risk (0.24) and compliance urgency (0.20); moderate effort (80 hours)."
+    pub fn explanation(&self) -> String {
+        let mut contributions: Vec<(&String, &f64)> = self.scoring_breakdown.weighted_contributions.iter()
+            .filter(|(_, value)| **value > 0.0)
+            .collect();
+        contributions.sort_by(|a, b| b.1.partial_cmp(a.1).unwrap_or(std::cmp::Ordering::Equal).then_with(|| a.0.cmp(b.0)));
+
+        let drivers: Vec<String> = contributions.iter()
+            .take(2)
+            .map(|(name, value)| format!("{} ({:.2})", contribution_label(name), value))
+            .collect();
+        let driven_by = match drivers.as_slice() {
+            [] => "No weighted contributions were recorded".to_string(),
+            [only] => format!("Driven mainly by {}", only),
+            [first, second, ..] => format!("Driven mainly by {} and {}", first, second),
+        };
+
+        let category = match self.priority_category {
+            PriorityCategory::Critical => "critical",
+            PriorityCategory::High => "high",
+            PriorityCategory::Medium => "medium",
+            PriorityCategory::Low => "low",
+        };
+        let severity = match self.gap.severity {
+            GapSeverity::Critical => "critical",
+            GapSeverity::High => "high",
+            GapSeverity::Medium => "medium",
+            GapSeverity::Low => "low",
+            GapSeverity::Informational => "informational",
+        };
+        let effort = match self.scoring_breakdown.effort_score {
+            score if score >= 0.8 => "low",
+            score if score >= 0.6 => "moderate",
+            score if score >= 0.4 => "high",
+            _ => "very high",
+        };
+        let gap_type = match self.gap.gap_type {
+            crate::engine::GapType::Missing => "is a missing control",
+            crate::engine::GapType::Partial => "is a partially implemented control",
+            crate::engine::GapType::Outdated => "is an outdated control",
+            crate::engine::GapType::EnhancementMissing => "is missing a control enhancement",
+            crate::engine::GapType::ParameterMissing => "is missing a parameter value",
+            crate::engine::GapType::Insufficient => "is an insufficiently implemented control",
+        };
+
+        format!(
+            "Ranked #{} ({} priority): {} {} ({} severity). {}; {} effort ({} hours).",
+            self.priority_rank,
+            category,
+            self.gap.control_id,
+            gap_type,
+            severity,
+            driven_by,
+            effort,
+            self.gap.remediation_guidance.estimated_effort.hours,
+        )
+    }
+}
+
+/// Readable name of a weighted contribution key
+fn contribution_label(name: &str) -> String {
+    match name {
+        "risk" => "risk".to_string(),
+        "business_impact" => "business impact".to_string(),
+        "effort" => "low implementation effort".to_string(),
+        "roi" => "return on investment".to_string(),
+        "compliance_urgency" => "compliance urgency".to_string(),
+        "stakeholder_priority" => "stakeholder priority".to_string(),
+        other => other.replace('_', " "),
+    }
+}
+
 /// Priority categories
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -165,6 +285,51 @@ pub struct MatrixPosition {
     pub x: f64,
     pub y: f64,
     pub quadrant: String,
+    /// Control the gap belongs to
+    #[serde(default)]
+    pub control_id: String,
+    /// Risk score of the gap (0.0 - 1.0)
+    #[serde(default)]
+    pub risk_score: f64,
+}
+
+/// Quadrant names in chart series order
+const CHART_QUADRANTS: [&str; 4] = ["Quick Wins", "Major Projects", "Fill-ins", "Questionable"];
+
+impl PrioritizationMatrix {
+    /// Export the matrix in the chart schema consumed by the frontend
+    ///
+    /// Produces `{"series": [{"name", "data": [{"x", "y", "label"}]}]}` with one
+    /// series per quadrant (always all four, in a fixed order) and one point per
+    /// gap: risk score on `x`, inverted effort score on `y` and the control ID
+    /// as `label`. Points are sorted by label so the output is stable.
+    pub fn to_chart_json(&self) -> serde_json::Value {
+        let series: Vec<serde_json::Value> = CHART_QUADRANTS.iter()
+            .map(|quadrant| {
+                let mut points: Vec<&MatrixPosition> = self.gap_positions.values()
+                    .filter(|position| position.quadrant == *quadrant)
+                    .collect();
+                points.sort_by(|a, b| {
+                    a.control_id.cmp(&b.control_id)
+                        .then_with(|| a.risk_score.total_cmp(&b.risk_score))
+                        .then_with(|| a.y.total_cmp(&b.y))
+                });
+
+                serde_json::json!({
+                    "name": quadrant,
+                    "data": points.iter()
+                        .map(|position| serde_json::json!({
+                            "x": position.risk_score,
+                            "y": position.y,
+                            "label": position.control_id,
+                        }))
+                        .collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+
+        serde_json::json!({ "series": series })
+    }
 }
 
 /// Priority quadrants for matrix visualization
@@ -180,6 +345,182 @@ pub struct PriorityQuadrants {
     pub questionable: Vec<String>,
 }
 
+/// Scores a gap for prioritization
+///
+/// Implement this to rank gaps by organization-specific logic without editing
+/// the engine; register it with `PrioritizationEngine::with_scoring_strategy`.
+pub trait ScoringStrategy: Send + Sync + std::fmt::Debug {
+    /// Score components of a gap under the given criteria
+    fn score(&self, gap: &Gap, criteria: &PrioritizationCriteria) -> ScoringBreakdown;
+
+    /// Priority score (0.0 - 1.0) from a breakdown, used to rank gaps
+    ///
+    /// Defaults to the weighted sum of the components normalized by the total weight.
+    fn priority_score(&self, breakdown: &ScoringBreakdown, criteria: &PrioritizationCriteria) -> f64 {
+        let weighted_score =
+            (breakdown.risk_score * criteria.risk_weight) +
+            (breakdown.business_impact_score * criteria.business_impact_weight) +
+            (breakdown.effort_score * criteria.effort_weight) +
+            (breakdown.roi_score * criteria.roi_weight) +
+            (breakdown.compliance_urgency_score * criteria.compliance_urgency_weight) +
+            (breakdown.stakeholder_priority_score * criteria.stakeholder_priority_weight);
+
+        // Normalize by total weights
+        let total_weight = criteria.risk_weight +
+            criteria.business_impact_weight +
+            criteria.effort_weight +
+            criteria.roi_weight +
+            criteria.compliance_urgency_weight +
+            criteria.stakeholder_priority_weight;
+
+        if total_weight > 0.0 {
+            weighted_score / total_weight
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Weighted sum of all criteria
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WeightedSumStrategy;
+
+impl ScoringStrategy for WeightedSumStrategy {
+    fn score(&self, gap: &Gap, criteria: &PrioritizationCriteria) -> ScoringBreakdown {
+        ScoringBreakdown::from_components(
+            [
+                risk_score(gap),
+                business_impact_score(gap),
+                effort_score(gap),
+                roi_score(gap),
+                compliance_urgency_score(gap),
+                stakeholder_priority_score(gap),
+            ],
+            criteria,
+        )
+    }
+}
+
+/// Ranking by gap severity alone; the breakdown still reports every criterion
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SeverityBasedStrategy;
+
+impl ScoringStrategy for SeverityBasedStrategy {
+    fn score(&self, gap: &Gap, criteria: &PrioritizationCriteria) -> ScoringBreakdown {
+        WeightedSumStrategy.score(gap, criteria)
+    }
+
+    fn priority_score(&self, breakdown: &ScoringBreakdown, _criteria: &PrioritizationCriteria) -> f64 {
+        breakdown.risk_score
+    }
+}
+
+impl ScoringBreakdown {
+    /// Breakdown from component scores in criteria order: risk, business
+    /// impact, effort, ROI, compliance urgency and stakeholder priority
+    pub fn from_components(scores: [f64; 6], criteria: &PrioritizationCriteria) -> Self {
+        let [risk_score, business_impact_score, effort_score, roi_score, compliance_urgency_score, stakeholder_priority_score] = scores;
+
+        let mut weighted_contributions = HashMap::new();
+        weighted_contributions.insert("risk".to_string(), risk_score * criteria.risk_weight);
+        weighted_contributions.insert("business_impact".to_string(), business_impact_score * criteria.business_impact_weight);
+        weighted_contributions.insert("effort".to_string(), effort_score * criteria.effort_weight);
+        weighted_contributions.insert("roi".to_string(), roi_score * criteria.roi_weight);
+        weighted_contributions.insert("compliance_urgency".to_string(), compliance_urgency_score * criteria.compliance_urgency_weight);
+        weighted_contributions.insert("stakeholder_priority".to_string(), stakeholder_priority_score * criteria.stakeholder_priority_weight);
+
+        Self {
+            risk_score,
+            business_impact_score,
+            effort_score,
+            roi_score,
+            compliance_urgency_score,
+            stakeholder_priority_score,
+            weighted_contributions,
+        }
+    }
+}
+
+/// Risk score component
+fn risk_score(gap: &Gap) -> f64 {
+    match gap.severity {
+        GapSeverity::Critical => 1.0,
+        GapSeverity::High => 0.8,
+        GapSeverity::Medium => 0.6,
+        GapSeverity::Low => 0.4,
+        GapSeverity::Informational => 0.2,
+    }
+}
+
+/// Business impact score component
+fn business_impact_score(gap: &Gap) -> f64 {
+    match gap.impact_assessment.business_impact {
+        BusinessImpact::Critical => 1.0,
+        BusinessImpact::High => 0.8,
+        BusinessImpact::Medium => 0.6,
+        BusinessImpact::Low => 0.4,
+        BusinessImpact::Minimal => 0.2,
+    }
+}
+
+/// Effort score component (inverted - lower effort = higher score)
+fn effort_score(gap: &Gap) -> f64 {
+    let effort_hours = gap.remediation_guidance.estimated_effort.hours;
+    
+    // Normalize effort to 0-1 scale (inverted)
+    if effort_hours <= 8 {
+        1.0 // Very low effort
+    } else if effort_hours <= 40 {
+        0.8 // Low effort
+    } else if effort_hours <= 160 {
+        0.6 // Medium effort
+    } else if effort_hours <= 400 {
+        0.4 // High effort
+    } else {
+        0.2 // Very high effort
+    }
+}
+
+/// ROI score component
+fn roi_score(gap: &Gap) -> f64 {
+    // Simplified ROI calculation based on impact vs effort
+    let impact_score = business_impact_score(gap);
+    let effort_score = effort_score(gap);
+    
+    // ROI = (Impact - Effort) normalized to 0-1
+    ((impact_score + effort_score) / 2.0).clamp(0.0, 1.0)
+}
+
+/// Compliance urgency score component
+fn compliance_urgency_score(gap: &Gap) -> f64 {
+    // Based on gap type and severity
+    let base_score = match gap.gap_type {
+        crate::engine::GapType::Missing => 1.0,
+        crate::engine::GapType::Partial => 0.7,
+        crate::engine::GapType::Outdated => 0.8,
+        crate::engine::GapType::EnhancementMissing => 0.6,
+        crate::engine::GapType::ParameterMissing => 0.5,
+        crate::engine::GapType::Insufficient => 0.7,
+    };
+
+    // Adjust by severity
+    let severity_multiplier = match gap.severity {
+        GapSeverity::Critical => 1.0,
+        GapSeverity::High => 0.9,
+        GapSeverity::Medium => 0.7,
+        GapSeverity::Low => 0.5,
+        GapSeverity::Informational => 0.3,
+    };
+
+    base_score * severity_multiplier
+}
+
+/// Stakeholder priority score component
+fn stakeholder_priority_score(_gap: &Gap) -> f64 {
+    // Placeholder - would integrate with stakeholder input system
+    0.7
+}
+
 impl PrioritizationEngine {
     /// Create a new prioritization engine
     pub fn new() -> Self {
@@ -187,18 +528,26 @@ impl PrioritizationEngine {
             criteria: PrioritizationCriteria::default(),
             algorithms: vec![PrioritizationAlgorithm::WeightedSum],
             config: PrioritizationConfig::default(),
+            custom_strategy: None,
         }
     }
 
+    /// Split the prioritization matrix into quadrants at the given thresholds
+    pub fn with_quadrant_thresholds(mut self, thresholds: QuadrantThresholds) -> Self {
+        self.config.quadrant_thresholds = thresholds;
+        self
+    }
+
     /// Prioritize gaps using configured algorithm
     pub async fn prioritize_gaps(&self, gaps: &[Gap]) -> Result<Vec<PrioritizedGap>> {
+        let strategy = self.scoring_strategy();
         let mut prioritized_gaps = Vec::new();
 
         // Calculate priority scores for each gap
         for gap in gaps {
-            let priority_score = self.calculate_priority_score(gap).await?;
+            let scoring_breakdown = strategy.score(gap, &self.criteria);
+            let priority_score = strategy.priority_score(&scoring_breakdown, &self.criteria);
             let priority_category = self.determine_priority_category(priority_score);
-            let scoring_breakdown = self.calculate_scoring_breakdown(gap).await?;
             
             let prioritized_gap = PrioritizedGap {
                 gap: gap.clone(),
@@ -231,173 +580,27 @@ impl PrioritizationEngine {
         Ok(prioritized_gaps)
     }
 
-    /// Calculate priority score for a single gap
-    async fn calculate_priority_score(&self, gap: &Gap) -> Result<f64> {
-        match self.config.default_algorithm {
-            PrioritizationAlgorithm::WeightedSum => {
-                self.calculate_weighted_sum_score(gap).await
-            }
-            PrioritizationAlgorithm::SeverityBased => {
-                self.calculate_severity_based_score(gap).await
-            }
-            _ => {
-                // Default to weighted sum for other algorithms
-                self.calculate_weighted_sum_score(gap).await
-            }
-        }
+    /// Score gaps with a caller-supplied strategy
+    ///
+    /// Registers the strategy and selects it through `PrioritizationAlgorithm::Custom`.
+    pub fn with_scoring_strategy(mut self, strategy: Arc<dyn ScoringStrategy>) -> Self {
+        self.custom_strategy = Some(strategy);
+        self.config.default_algorithm = PrioritizationAlgorithm::Custom;
+        self
     }
 
-    /// Calculate weighted sum priority score
-    async fn calculate_weighted_sum_score(&self, gap: &Gap) -> Result<f64> {
-        let risk_score = self.calculate_risk_score(gap);
-        let business_impact_score = self.calculate_business_impact_score(gap);
-        let effort_score = self.calculate_effort_score(gap);
-        let roi_score = self.calculate_roi_score(gap);
-        let compliance_urgency_score = self.calculate_compliance_urgency_score(gap);
-        let stakeholder_priority_score = self.calculate_stakeholder_priority_score(gap);
-
-        let weighted_score = 
-            (risk_score * self.criteria.risk_weight) +
-            (business_impact_score * self.criteria.business_impact_weight) +
-            (effort_score * self.criteria.effort_weight) +
-            (roi_score * self.criteria.roi_weight) +
-            (compliance_urgency_score * self.criteria.compliance_urgency_weight) +
-            (stakeholder_priority_score * self.criteria.stakeholder_priority_weight);
-
-        // Normalize by total weights
-        let total_weight = self.criteria.risk_weight +
-            self.criteria.business_impact_weight +
-            self.criteria.effort_weight +
-            self.criteria.roi_weight +
-            self.criteria.compliance_urgency_weight +
-            self.criteria.stakeholder_priority_weight;
-
-        Ok(if total_weight > 0.0 {
-            weighted_score / total_weight
-        } else {
-            0.0
-        })
-    }
-
-    /// Calculate severity-based priority score
-    async fn calculate_severity_based_score(&self, gap: &Gap) -> Result<f64> {
-        match gap.severity {
-            GapSeverity::Critical => Ok(1.0),
-            GapSeverity::High => Ok(0.8),
-            GapSeverity::Medium => Ok(0.6),
-            GapSeverity::Low => Ok(0.4),
-            GapSeverity::Informational => Ok(0.2),
+    /// Strategy for the configured algorithm
+    ///
+    /// AHP and TOPSIS are not implemented yet and, like `Custom` without a
+    /// registered strategy, use the weighted sum.
+    fn scoring_strategy(&self) -> Arc<dyn ScoringStrategy> {
+        match (&self.config.default_algorithm, &self.custom_strategy) {
+            (PrioritizationAlgorithm::SeverityBased, _) => Arc::new(SeverityBasedStrategy),
+            (PrioritizationAlgorithm::Custom, Some(strategy)) => strategy.clone(),
+            _ => Arc::new(WeightedSumStrategy),
         }
     }
 
-    /// Calculate detailed scoring breakdown
-    async fn calculate_scoring_breakdown(&self, gap: &Gap) -> Result<ScoringBreakdown> {
-        let risk_score = self.calculate_risk_score(gap);
-        let business_impact_score = self.calculate_business_impact_score(gap);
-        let effort_score = self.calculate_effort_score(gap);
-        let roi_score = self.calculate_roi_score(gap);
-        let compliance_urgency_score = self.calculate_compliance_urgency_score(gap);
-        let stakeholder_priority_score = self.calculate_stakeholder_priority_score(gap);
-
-        let mut weighted_contributions = HashMap::new();
-        weighted_contributions.insert("risk".to_string(), risk_score * self.criteria.risk_weight);
-        weighted_contributions.insert("business_impact".to_string(), business_impact_score * self.criteria.business_impact_weight);
-        weighted_contributions.insert("effort".to_string(), effort_score * self.criteria.effort_weight);
-        weighted_contributions.insert("roi".to_string(), roi_score * self.criteria.roi_weight);
-        weighted_contributions.insert("compliance_urgency".to_string(), compliance_urgency_score * self.criteria.compliance_urgency_weight);
-        weighted_contributions.insert("stakeholder_priority".to_string(), stakeholder_priority_score * self.criteria.stakeholder_priority_weight);
-
-        Ok(ScoringBreakdown {
-            risk_score,
-            business_impact_score,
-            effort_score,
-            roi_score,
-            compliance_urgency_score,
-            stakeholder_priority_score,
-            weighted_contributions,
-        })
-    }
-
-    /// Calculate risk score component
-    fn calculate_risk_score(&self, gap: &Gap) -> f64 {
-        match gap.severity {
-            GapSeverity::Critical => 1.0,
-            GapSeverity::High => 0.8,
-            GapSeverity::Medium => 0.6,
-            GapSeverity::Low => 0.4,
-            GapSeverity::Informational => 0.2,
-        }
-    }
-
-    /// Calculate business impact score component
-    fn calculate_business_impact_score(&self, gap: &Gap) -> f64 {
-        match gap.impact_assessment.business_impact {
-            BusinessImpact::Critical => 1.0,
-            BusinessImpact::High => 0.8,
-            BusinessImpact::Medium => 0.6,
-            BusinessImpact::Low => 0.4,
-            BusinessImpact::Minimal => 0.2,
-        }
-    }
-
-    /// Calculate effort score component (inverted - lower effort = higher score)
-    fn calculate_effort_score(&self, gap: &Gap) -> f64 {
-        let effort_hours = gap.remediation_guidance.estimated_effort.hours;
-        
-        // Normalize effort to 0-1 scale (inverted)
-        if effort_hours <= 8 {
-            1.0 // Very low effort
-        } else if effort_hours <= 40 {
-            0.8 // Low effort
-        } else if effort_hours <= 160 {
-            0.6 // Medium effort
-        } else if effort_hours <= 400 {
-            0.4 // High effort
-        } else {
-            0.2 // Very high effort
-        }
-    }
-
-    /// Calculate ROI score component
-    fn calculate_roi_score(&self, gap: &Gap) -> f64 {
-        // Simplified ROI calculation based on impact vs effort
-        let impact_score = self.calculate_business_impact_score(gap);
-        let effort_score = self.calculate_effort_score(gap);
-        
-        // ROI = (Impact - Effort) normalized to 0-1
-        ((impact_score + effort_score) / 2.0).min(1.0).max(0.0)
-    }
-
-    /// Calculate compliance urgency score component
-    fn calculate_compliance_urgency_score(&self, gap: &Gap) -> f64 {
-        // Based on gap type and severity
-        let base_score = match gap.gap_type {
-            crate::engine::GapType::Missing => 1.0,
-            crate::engine::GapType::Partial => 0.7,
-            crate::engine::GapType::Outdated => 0.8,
-            crate::engine::GapType::EnhancementMissing => 0.6,
-            crate::engine::GapType::ParameterMissing => 0.5,
-            crate::engine::GapType::Insufficient => 0.7,
-        };
-
-        // Adjust by severity
-        let severity_multiplier = match gap.severity {
-            GapSeverity::Critical => 1.0,
-            GapSeverity::High => 0.9,
-            GapSeverity::Medium => 0.7,
-            GapSeverity::Low => 0.5,
-            GapSeverity::Informational => 0.3,
-        };
-
-        base_score * severity_multiplier
-    }
-
-    /// Calculate stakeholder priority score component
-    fn calculate_stakeholder_priority_score(&self, _gap: &Gap) -> f64 {
-        // Placeholder - would integrate with stakeholder input system
-        0.7
-    }
-
     /// Determine priority category based on score
     fn determine_priority_category(&self, score: f64) -> PriorityCategory {
         if score >= self.config.priority_categories.critical_threshold {
@@ -412,6 +615,9 @@ impl PrioritizationEngine {
     }
 
     /// Generate prioritization matrix for visualization
+    ///
+    /// Gaps are placed in quadrants using `config.quadrant_thresholds`; a gap
+    /// exactly on a boundary goes to the higher-priority side.
     pub fn generate_prioritization_matrix(&self, prioritized_gaps: &[PrioritizedGap]) -> Result<PrioritizationMatrix> {
         let mut gap_positions = HashMap::new();
         let mut quadrants = PriorityQuadrants {
@@ -425,23 +631,25 @@ impl PrioritizationEngine {
             let impact_score = prioritized_gap.scoring_breakdown.business_impact_score;
             let effort_score = prioritized_gap.scoring_breakdown.effort_score;
             
+            let quadrant = self.determine_quadrant(impact_score, effort_score);
+
+            // Categorize into quadrants
+            let quadrant_gaps = match quadrant {
+                Quadrant::QuickWins => &mut quadrants.quick_wins,
+                Quadrant::MajorProjects => &mut quadrants.major_projects,
+                Quadrant::FillIns => &mut quadrants.fill_ins,
+                Quadrant::Questionable => &mut quadrants.questionable,
+            };
+            quadrant_gaps.push(prioritized_gap.gap.gap_id.clone());
+
             let position = MatrixPosition {
                 x: impact_score,
                 y: effort_score,
-                quadrant: self.determine_quadrant(impact_score, effort_score),
+                quadrant: quadrant.name().to_string(),
+                control_id: prioritized_gap.gap.control_id.clone(),
+                risk_score: prioritized_gap.scoring_breakdown.risk_score,
             };
 
-            // Categorize into quadrants
-            if impact_score >= 0.7 && effort_score >= 0.7 {
-                quadrants.quick_wins.push(prioritized_gap.gap.gap_id.clone());
-            } else if impact_score >= 0.7 && effort_score < 0.7 {
-                quadrants.major_projects.push(prioritized_gap.gap.gap_id.clone());
-            } else if impact_score < 0.7 && effort_score >= 0.7 {
-                quadrants.fill_ins.push(prioritized_gap.gap.gap_id.clone());
-            } else {
-                quadrants.questionable.push(prioritized_gap.gap.gap_id.clone());
-            }
-
             gap_positions.insert(prioritized_gap.gap.gap_id.clone(), position);
         }
 
@@ -457,15 +665,34 @@ impl PrioritizationEngine {
     }
 
     /// Determine matrix quadrant for a gap
-    fn determine_quadrant(&self, impact: f64, effort: f64) -> String {
-        if impact >= 0.7 && effort >= 0.7 {
-            "Quick Wins".to_string()
-        } else if impact >= 0.7 && effort < 0.7 {
-            "Major Projects".to_string()
-        } else if impact < 0.7 && effort >= 0.7 {
-            "Fill-ins".to_string()
-        } else {
-            "Questionable".to_string()
+    fn determine_quadrant(&self, impact: f64, effort: f64) -> Quadrant {
+        let thresholds = &self.config.quadrant_thresholds;
+        match (impact >= thresholds.impact, effort >= thresholds.effort) {
+            (true, true) => Quadrant::QuickWins,
+            (true, false) => Quadrant::MajorProjects,
+            (false, true) => Quadrant::FillIns,
+            (false, false) => Quadrant::Questionable,
+        }
+    }
+}
+
+/// Prioritization matrix quadrant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Quadrant {
+    QuickWins,
+    MajorProjects,
+    FillIns,
+    Questionable,
+}
+
+impl Quadrant {
+    /// Quadrant name as stored in `MatrixPosition::quadrant`
+    fn name(&self) -> &'static str {
+        match self {
+            Quadrant::QuickWins => "Quick Wins",
+            Quadrant::MajorProjects => "Major Projects",
+            Quadrant::FillIns => "Fill-ins",
+            Quadrant::Questionable => "Questionable",
         }
     }
 }
@@ -490,6 +717,7 @@ impl Default for PrioritizationConfig {
             use_consensus: false,
             consensus_threshold: 0.8,
             priority_categories: PriorityCategories::default(),
+            quadrant_thresholds: QuadrantThresholds::default(),
         }
     }
 }
@@ -504,3 +732,179 @@ impl Default for PriorityCategories {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(control_id: &str, risk_score: f64, effort_score: f64, quadrant: &str) -> MatrixPosition {
+        MatrixPosition {
+            x: 0.5,
+            y: effort_score,
+            quadrant: quadrant.to_string(),
+            control_id: control_id.to_string(),
+            risk_score,
+        }
+    }
+
+    #[test]
+    fn test_chart_json_schema_snapshot() {
+        let matrix = PrioritizationMatrix {
+            dimensions: MatrixDimensions {
+                x_axis: "Business Impact".to_string(),
+                y_axis: "Implementation Effort (Inverted)".to_string(),
+                size: (10, 10),
+            },
+            gap_positions: HashMap::from([
+                ("gap-3".to_string(), position("SC-7", 0.9, 0.8, "Quick Wins")),
+                ("gap-1".to_string(), position("AC-2", 0.75, 0.9, "Quick Wins")),
+                ("gap-2".to_string(), position("AU-6", 0.4, 0.2, "Questionable")),
+            ]),
+            quadrants: PriorityQuadrants {
+                quick_wins: vec!["gap-1".to_string(), "gap-3".to_string()],
+                major_projects: Vec::new(),
+                fill_ins: Vec::new(),
+                questionable: vec!["gap-2".to_string()],
+            },
+        };
+
+        assert_eq!(matrix.to_chart_json(), serde_json::json!({
+            "series": [
+                {
+                    "name": "Quick Wins",
+                    "data": [
+                        { "x": 0.75, "y": 0.9, "label": "AC-2" },
+                        { "x": 0.9, "y": 0.8, "label": "SC-7" }
+                    ]
+                },
+                { "name": "Major Projects", "data": [] },
+                { "name": "Fill-ins", "data": [] },
+                {
+                    "name": "Questionable",
+                    "data": [
+                        { "x": 0.4, "y": 0.2, "label": "AU-6" }
+                    ]
+                }
+            ]
+        }));
+    }
+
+    fn prioritized(control_id: &str, impact_score: f64, effort_score: f64) -> PrioritizedGap {
+        PrioritizedGap {
+            gap: crate::engine::tests::gap(control_id, GapSeverity::Medium),
+            priority_score: 0.5,
+            priority_category: PriorityCategory::Medium,
+            priority_rank: 1,
+            scoring_breakdown: ScoringBreakdown {
+                risk_score: 0.5,
+                business_impact_score: impact_score,
+                effort_score,
+                roi_score: 0.5,
+                compliance_urgency_score: 0.5,
+                stakeholder_priority_score: 0.5,
+                weighted_contributions: HashMap::new(),
+            },
+            metadata: PrioritizationMetadata {
+                algorithm_used: PrioritizationAlgorithm::WeightedSum,
+                criteria_weights: PrioritizationCriteria::default(),
+                confidence: 0.85,
+                alternative_rankings: HashMap::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_quadrant_thresholds_reclassify_borderline_gap() {
+        let gaps = vec![prioritized("AC-2", 0.6, 0.8)];
+        let gap_id = gaps[0].gap.gap_id.clone();
+
+        let default_matrix = PrioritizationEngine::new().generate_prioritization_matrix(&gaps).unwrap();
+        assert_eq!(default_matrix.quadrants.fill_ins, vec![gap_id.clone()]);
+        assert!(default_matrix.quadrants.quick_wins.is_empty());
+
+        // A gap exactly on the lowered impact boundary counts as high impact
+        let engine = PrioritizationEngine::new()
+            .with_quadrant_thresholds(QuadrantThresholds { impact: 0.6, effort: 0.7 });
+        let matrix = engine.generate_prioritization_matrix(&gaps).unwrap();
+        assert_eq!(matrix.quadrants.quick_wins, vec![gap_id.clone()]);
+        assert_eq!(matrix.gap_positions[&gap_id].quadrant, "Quick Wins");
+    }
+
+    #[tokio::test]
+    async fn test_explanation_names_top_weighted_contribution() {
+        let mut gap = crate::engine::tests::gap("AC-2", GapSeverity::Critical);
+        gap.remediation_guidance.estimated_effort.hours = 80;
+        let prioritized = PrioritizationEngine::new().prioritize_gaps(&[gap]).await.unwrap().remove(0);
+
+        let explanation = prioritized.explanation();
+
+        // Critical severity makes risk the largest contribution under the default weights
+        assert!(explanation.starts_with("Ranked #1 ("), "{}", explanation);
+        assert!(explanation.contains("Driven mainly by risk (0.25) and business impact (0.20)"), "{}", explanation);
+        assert!(explanation.contains("AC-2 is a missing control (critical severity)."), "{}", explanation);
+        assert!(explanation.ends_with("moderate effort (80 hours)."), "{}", explanation);
+        assert_eq!(explanation, prioritized.explanation());
+    }
+
+    #[tokio::test]
+    async fn test_explanation_describes_gap_types_in_prose() {
+        for (gap_type, description) in [
+            (crate::engine::GapType::Outdated, "SC-8 is an outdated control (high severity)"),
+            (crate::engine::GapType::ParameterMissing, "SC-8 is missing a parameter value (high severity)"),
+            (crate::engine::GapType::EnhancementMissing, "SC-8 is missing a control enhancement (high severity)"),
+        ] {
+            let mut gap = crate::engine::tests::gap("SC-8", GapSeverity::High);
+            gap.gap_type = gap_type;
+            let prioritized = PrioritizationEngine::new().prioritize_gaps(&[gap]).await.unwrap().remove(0);
+
+            assert!(prioritized.explanation().contains(description), "{}", prioritized.explanation());
+        }
+    }
+
+    /// Strategy ranking every gap as top priority
+    #[derive(Debug)]
+    struct AlwaysTopStrategy;
+
+    impl ScoringStrategy for AlwaysTopStrategy {
+        fn score(&self, _gap: &Gap, criteria: &PrioritizationCriteria) -> ScoringBreakdown {
+            ScoringBreakdown::from_components([1.0; 6], criteria)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_strategy_replaces_built_in_scoring() {
+        let gaps = vec![
+            crate::engine::tests::gap("AU-6", GapSeverity::Low),
+            crate::engine::tests::gap("AC-2", GapSeverity::Critical),
+        ];
+
+        let built_in = PrioritizationEngine::new().prioritize_gaps(&gaps).await.unwrap();
+        let ranked: Vec<&str> = built_in.iter().map(|gap| gap.gap.control_id.as_str()).collect();
+        assert_eq!(ranked, vec!["AC-2", "AU-6"]);
+
+        let engine = PrioritizationEngine::new().with_scoring_strategy(Arc::new(AlwaysTopStrategy));
+        let custom = engine.prioritize_gaps(&gaps).await.unwrap();
+
+        // Equal scores keep the input order
+        let ranked: Vec<(&str, usize)> = custom.iter().map(|gap| (gap.gap.control_id.as_str(), gap.priority_rank)).collect();
+        assert_eq!(ranked, vec![("AU-6", 1), ("AC-2", 2)]);
+        assert!(custom.iter().all(|gap| gap.priority_score == 1.0 && matches!(gap.priority_category, PriorityCategory::Critical)));
+        assert!(matches!(custom[0].metadata.algorithm_used, PrioritizationAlgorithm::Custom));
+    }
+
+    #[tokio::test]
+    async fn test_severity_based_strategy_scores_by_severity() {
+        let mut engine = PrioritizationEngine::new();
+        engine.config.default_algorithm = PrioritizationAlgorithm::SeverityBased;
+
+        let prioritized = engine.prioritize_gaps(&[crate::engine::tests::gap("AC-2", GapSeverity::High)]).await.unwrap();
+        assert_eq!(prioritized[0].priority_score, 0.8);
+        assert_eq!(prioritized[0].scoring_breakdown.weighted_contributions.len(), 6);
+    }
+
+    #[test]
+    fn test_quadrant_thresholds_out_of_range_are_rejected() {
+        assert!(QuadrantThresholds::default().validate().is_ok());
+        assert!(QuadrantThresholds { impact: 1.2, effort: 0.7 }.validate().is_err());
+    }
+}
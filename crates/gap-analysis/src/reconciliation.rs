@@ -0,0 +1,204 @@
+// Modified: 2025-09-20
+
+//! SSP Reconciliation
+//!
+//! Compares the implemented requirements of an OSCAL system security plan
+//! against a `CurrentImplementation`, so divergence introduced by hand edits
+//! to a generated SSP can be reported. Controls are matched on their
+//! normalized NIST 800-53 identifier, so `ac-2.3` in the SSP matches `AC-2(3)`.
+
+use fedramp_core::models::Framework;
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use crate::engine::{normalize_control_id, CurrentImplementation, ImplementationStatus};
+
+/// A control that differs between the SSP and the current implementation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SspDiscrepancy {
+    /// The SSP describes a control the implementation does not track
+    MissingFromImplementation {
+        control_id: String,
+        ssp_status: Option<ImplementationStatus>,
+    },
+    /// The implementation tracks a control the SSP does not describe
+    MissingFromSsp {
+        control_id: String,
+        implementation_status: ImplementationStatus,
+    },
+    /// Both describe the control with different statuses
+    StatusMismatch {
+        control_id: String,
+        ssp_status: ImplementationStatus,
+        implementation_status: ImplementationStatus,
+    },
+}
+
+impl SspDiscrepancy {
+    /// Normalized control ID the discrepancy is about
+    pub fn control_id(&self) -> &str {
+        match self {
+            SspDiscrepancy::MissingFromImplementation { control_id, .. }
+            | SspDiscrepancy::MissingFromSsp { control_id, .. }
+            | SspDiscrepancy::StatusMismatch { control_id, .. } => control_id,
+        }
+    }
+}
+
+/// Result of reconciling an SSP with the current implementation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SspReconciliation {
+    /// System of the current implementation
+    pub system_id: String,
+    /// Controls described by both, whether or not their statuses match
+    pub matched_controls: usize,
+    /// Discrepancies ordered by control ID
+    pub discrepancies: Vec<SspDiscrepancy>,
+}
+
+impl SspReconciliation {
+    /// Whether the SSP and the implementation agree
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Reconcile the implemented requirements of an OSCAL SSP with the current implementation
+///
+/// The SSP status of a control is read from its `implementation-status`
+/// property, or else from the `implementation-status` state of its first
+/// component. A control whose SSP entry has no status is only checked for
+/// presence. Fails if the SSP has no `control-implementation` section.
+pub fn reconcile_ssp(ssp: &Value, current: &CurrentImplementation) -> Result<SspReconciliation> {
+    let document = ssp.get("system-security-plan").unwrap_or(ssp);
+    let requirements = document
+        .pointer("/control-implementation/implemented-requirements")
+        .and_then(Value::as_array)
+        .ok_or_else(|| Error::validation("SSP has no 'control-implementation.implemented-requirements' section"))?;
+
+    let framework = Some(&Framework::Nist80053);
+    let mut ssp_controls = BTreeMap::new();
+    for requirement in requirements {
+        let control_id = requirement.get("control-id").and_then(Value::as_str)
+            .ok_or_else(|| Error::validation("SSP implemented requirement has no 'control-id'"))?;
+        ssp_controls.insert(normalize_control_id(control_id, framework), ssp_status(requirement));
+    }
+
+    let implemented: BTreeMap<String, &ImplementationStatus> = current.controls.values()
+        .map(|control| (normalize_control_id(&control.control_id, framework), &control.status))
+        .collect();
+
+    let mut discrepancies = Vec::new();
+    let mut matched_controls = 0;
+    for (control_id, ssp_status) in &ssp_controls {
+        match (implemented.get(control_id), ssp_status) {
+            (None, _) => discrepancies.push(SspDiscrepancy::MissingFromImplementation {
+                control_id: control_id.clone(),
+                ssp_status: ssp_status.clone(),
+            }),
+            (Some(implementation_status), Some(ssp_status)) if *implementation_status != ssp_status => {
+                matched_controls += 1;
+                discrepancies.push(SspDiscrepancy::StatusMismatch {
+                    control_id: control_id.clone(),
+                    ssp_status: ssp_status.clone(),
+                    implementation_status: (*implementation_status).clone(),
+                });
+            }
+            (Some(_), _) => matched_controls += 1,
+        }
+    }
+    for (control_id, implementation_status) in &implemented {
+        if !ssp_controls.contains_key(control_id) {
+            discrepancies.push(SspDiscrepancy::MissingFromSsp {
+                control_id: control_id.clone(),
+                implementation_status: (*implementation_status).clone(),
+            });
+        }
+    }
+    discrepancies.sort_by(|a, b| a.control_id().cmp(b.control_id()));
+
+    Ok(SspReconciliation {
+        system_id: current.system_id.clone(),
+        matched_controls,
+        discrepancies,
+    })
+}
+
+/// Status an implemented requirement declares, if any
+fn ssp_status(requirement: &Value) -> Option<ImplementationStatus> {
+    let from_props = requirement.get("props").and_then(Value::as_array)
+        .and_then(|props| props.iter().find(|prop| prop.get("name").and_then(Value::as_str) == Some("implementation-status")))
+        .and_then(|prop| prop.get("value"))
+        .and_then(Value::as_str);
+    let from_component = || requirement.pointer("/by-components/0/implementation-status/state").and_then(Value::as_str);
+
+    from_props.or_else(from_component).and_then(parse_oscal_status)
+}
+
+/// Map an OSCAL implementation state to an implementation status
+fn parse_oscal_status(state: &str) -> Option<ImplementationStatus> {
+    match state.trim().to_lowercase().as_str() {
+        "implemented" | "alternative" => Some(ImplementationStatus::Implemented),
+        "partial" | "partially-implemented" => Some(ImplementationStatus::PartiallyImplemented),
+        "planned" => Some(ImplementationStatus::Planned),
+        "not-applicable" => Some(ImplementationStatus::NotApplicable),
+        "not-implemented" => Some(ImplementationStatus::NotImplemented),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::tests::control;
+    use chrono::Utc;
+    use serde_json::json;
+
+    #[test]
+    fn test_reports_added_and_mismatched_controls() {
+        let current = CurrentImplementation {
+            system_id: "test-system".to_string(),
+            controls: [
+                control("AC-2", ImplementationStatus::Implemented),
+                control("AC-2(3)", ImplementationStatus::PartiallyImplemented),
+                control("AU-2", ImplementationStatus::Implemented),
+            ]
+            .into_iter()
+            .map(|control| (control.control_id.clone(), control))
+            .collect(),
+            last_updated: Utc::now(),
+        };
+        let ssp = json!({
+            "system-security-plan": {
+                "control-implementation": {
+                    "implemented-requirements": [
+                        { "control-id": "ac-2", "props": [{ "name": "implementation-status", "value": "implemented" }] },
+                        { "control-id": "ac-2.3", "by-components": [{ "implementation-status": { "state": "partial" } }] },
+                        { "control-id": "au-2", "props": [{ "name": "implementation-status", "value": "planned" }] },
+                        { "control-id": "sc-7", "props": [{ "name": "implementation-status", "value": "implemented" }] }
+                    ]
+                }
+            }
+        });
+
+        let reconciliation = reconcile_ssp(&ssp, &current).unwrap();
+
+        assert_eq!(reconciliation.matched_controls, 3);
+        assert_eq!(reconciliation.discrepancies, vec![
+            SspDiscrepancy::StatusMismatch {
+                control_id: "AU-2".to_string(),
+                ssp_status: ImplementationStatus::Planned,
+                implementation_status: ImplementationStatus::Implemented,
+            },
+            SspDiscrepancy::MissingFromImplementation {
+                control_id: "SC-7".to_string(),
+                ssp_status: Some(ImplementationStatus::Implemented),
+            },
+        ]);
+        assert_eq!(serde_json::to_value(&reconciliation.discrepancies[0]).unwrap()["kind"], "status_mismatch");
+        assert!(reconcile_ssp(&json!({}), &current).is_err());
+    }
+}
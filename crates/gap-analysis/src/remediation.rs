@@ -53,6 +53,9 @@ pub struct RemediationItem {
     pub status: ItemStatus,
     pub assigned_to: Option<String>,
     pub estimated_effort: EffortEstimate,
+    /// Risk removed by completing the item (0.0 - 1.0), the gap's risk score
+    #[serde(default)]
+    pub risk_reduction: f64,
     pub actual_effort: Option<EffortActual>,
     pub start_date: Option<DateTime<Utc>>,
     pub target_date: DateTime<Utc>,
@@ -415,6 +418,111 @@ pub struct RemediationConfig {
     pub holiday_calendar: String,
 }
 
+impl RemediationPlan {
+    /// Items ordered by payback, the fastest first
+    ///
+    /// Items with equal payback keep their plan order.
+    pub fn sorted_by_roi(&self) -> Vec<&RemediationItem> {
+        let mut items: Vec<&RemediationItem> = self.remediation_items.iter().collect();
+        items.sort_by(|a, b| b.payback().total_cmp(&a.payback()));
+        items
+    }
+
+    /// Task table as a GitHub-flavored Markdown table
+    ///
+    /// Pipes are escaped and line breaks become `<br>`, so each item stays on
+    /// one row. An empty plan gives just the header.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("| {} |\n|{}\n", TASK_TABLE_HEADER.join(" | "), " --- |".repeat(TASK_TABLE_HEADER.len()));
+        for row in self.task_rows() {
+            let cells: Vec<String> = row.iter().map(|cell| escape_markdown_cell(cell)).collect();
+            markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        markdown
+    }
+
+    /// Task table as CSV, with a header row
+    ///
+    /// Fields are quoted as needed, so commas, quotes and line breaks in
+    /// titles survive import. Fields that a spreadsheet would evaluate as a
+    /// formula are prefixed with `'`. An empty plan gives just the header.
+    pub fn to_csv(&self) -> Result<String> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(TASK_TABLE_HEADER)
+            .map_err(|e| Error::internal(format!("Failed to write remediation plan CSV: {}", e)))?;
+        for row in self.task_rows() {
+            writer.write_record(row.iter().map(|cell| escape_csv_formula(cell)))
+                .map_err(|e| Error::internal(format!("Failed to write remediation plan CSV: {}", e)))?;
+        }
+        let bytes = writer.into_inner()
+            .map_err(|e| Error::internal(format!("Failed to write remediation plan CSV: {}", e)))?;
+        String::from_utf8(bytes).map_err(|e| Error::internal(format!("Remediation plan CSV is not UTF-8: {}", e)))
+    }
+
+    /// Unescaped task table cells, one row per item in plan order
+    fn task_rows(&self) -> Vec<[String; 6]> {
+        self.remediation_items.iter().map(|item| {
+            let mut predecessors: Vec<&str> = item.dependencies.iter().map(String::as_str).collect();
+            for dependency in self.dependencies.iter().filter(|dependency| dependency.successor_item == item.item_id) {
+                if !predecessors.contains(&dependency.predecessor_item.as_str()) {
+                    predecessors.push(&dependency.predecessor_item);
+                }
+            }
+            let dependencies: Vec<&str> = predecessors.into_iter()
+                .map(|id| self.remediation_items.iter().find(|other| other.item_id == id).map_or(id, |other| other.control_id.as_str()))
+                .collect();
+
+            [
+                item.title.clone(),
+                item.control_id.clone(),
+                format!("{:.1}", item.estimated_effort.expected_hours),
+                priority_label(&item.priority).to_string(),
+                dependencies.join("; "),
+                item.target_date.format("%Y-%m-%d").to_string(),
+            ]
+        }).collect()
+    }
+}
+
+/// Columns of the exported task table
+const TASK_TABLE_HEADER: [&str; 6] = ["Item", "Control", "Effort (hours)", "Priority", "Dependencies", "Target Date"];
+
+/// Lowercase name of a priority category
+fn priority_label(priority: &PriorityCategory) -> &'static str {
+    match priority {
+        PriorityCategory::Critical => "critical",
+        PriorityCategory::High => "high",
+        PriorityCategory::Medium => "medium",
+        PriorityCategory::Low => "low",
+    }
+}
+
+/// Escape text for a Markdown table cell
+fn escape_markdown_cell(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// Cell text with a leading `'` if a spreadsheet would read it as a formula
+fn escape_csv_formula(text: &str) -> String {
+    if text.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", text)
+    } else {
+        text.to_string()
+    }
+}
+
+impl RemediationItem {
+    /// Risk reduction per hour of expected effort
+    ///
+    /// Effort below one hour counts as one hour, so the metric stays finite.
+    pub fn payback(&self) -> f64 {
+        self.risk_reduction / self.estimated_effort.expected_hours.max(1.0)
+    }
+}
+
 impl RemediationPlanner {
     /// Create a new remediation planner
     pub fn new() -> Self {
@@ -530,6 +638,7 @@ impl RemediationPlanner {
             status: ItemStatus::NotStarted,
             assigned_to: None,
             estimated_effort,
+            risk_reduction: prioritized_gap.scoring_breakdown.risk_score,
             actual_effort: None,
             start_date: None,
             target_date,
@@ -673,3 +782,79 @@ impl Default for RemediationConfig {
 #[derive(Debug, Clone)] pub struct SchedulingAlgorithm;
 #[derive(Debug, Clone)] pub struct DependencyResolver;
 #[derive(Debug, Clone)] pub struct ResourceLeveling;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::tests::gap;
+    use crate::PrioritizationEngine;
+
+    #[tokio::test]
+    async fn test_cheaper_item_with_equal_risk_reduction_sorts_first() {
+        let gaps = vec![gap("AC-2", GapSeverity::High), gap("AU-2", GapSeverity::High)];
+        let prioritized_gaps = PrioritizationEngine::new().prioritize_gaps(&gaps).await.unwrap();
+        let mut plan = RemediationPlanner::new().generate_plan(&prioritized_gaps, "ROI".to_string()).await.unwrap();
+
+        let expensive = plan.remediation_items[0].control_id.clone();
+        plan.remediation_items[0].estimated_effort.expected_hours = 160.0;
+        plan.remediation_items[1].estimated_effort.expected_hours = 40.0;
+
+        let items = plan.sorted_by_roi();
+        assert_eq!(items[0].risk_reduction, items[1].risk_reduction);
+        assert_eq!(items[1].control_id, expensive);
+        assert_eq!(items[0].payback(), 0.8 / 40.0);
+    }
+
+    #[tokio::test]
+    async fn test_csv_has_header_and_one_row_per_item() {
+        let gaps = vec![gap("AC-2", GapSeverity::High), gap("AU-2", GapSeverity::Low)];
+        let prioritized_gaps = PrioritizationEngine::new().prioritize_gaps(&gaps).await.unwrap();
+        let mut plan = RemediationPlanner::new().generate_plan(&prioritized_gaps, "Export".to_string()).await.unwrap();
+        plan.remediation_items[0].title = "Enable MFA, \"admins\" first".to_string();
+        let predecessor = plan.remediation_items[0].item_id.clone();
+        plan.remediation_items[1].dependencies.push(predecessor);
+
+        let csv = plan.to_csv().unwrap();
+
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        assert_eq!(reader.headers().unwrap().iter().collect::<Vec<_>>(), TASK_TABLE_HEADER);
+        let rows: Vec<csv::StringRecord> = reader.records().map(|row| row.unwrap()).collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(&rows[0][0], "Enable MFA, \"admins\" first");
+        assert_eq!(&rows[1][4], plan.remediation_items[0].control_id);
+
+        plan.remediation_items.clear();
+        assert_eq!(plan.to_csv().unwrap().lines().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_csv_neutralizes_formulas() {
+        let prioritized_gaps = PrioritizationEngine::new().prioritize_gaps(&[gap("AC-2", GapSeverity::High)]).await.unwrap();
+        let mut plan = RemediationPlanner::new().generate_plan(&prioritized_gaps, "Export".to_string()).await.unwrap();
+        plan.remediation_items.push(plan.remediation_items[0].clone());
+        plan.remediation_items[0].title = "=HYPERLINK(\"https://example.com\")".to_string();
+        plan.remediation_items[1].title = "@SUM(A1:A2)".to_string();
+
+        let csv = plan.to_csv().unwrap();
+
+        let rows: Vec<csv::StringRecord> = csv::Reader::from_reader(csv.as_bytes()).records().map(|row| row.unwrap()).collect();
+        assert_eq!(&rows[0][0], "'=HYPERLINK(\"https://example.com\")");
+        assert_eq!(&rows[1][0], "'@SUM(A1:A2)");
+        assert_eq!(&rows[0][1], "AC-2");
+    }
+
+    #[tokio::test]
+    async fn test_markdown_escapes_pipes_and_line_breaks() {
+        let prioritized_gaps = PrioritizationEngine::new().prioritize_gaps(&[gap("AC-2", GapSeverity::High)]).await.unwrap();
+        let mut plan = RemediationPlanner::new().generate_plan(&prioritized_gaps, "Export".to_string()).await.unwrap();
+        plan.remediation_items[0].title = "Review a|b\nthen c".to_string();
+
+        let markdown = plan.to_markdown();
+
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "| Item | Control | Effort (hours) | Priority | Dependencies | Target Date |");
+        assert_eq!(lines[1], "| --- | --- | --- | --- | --- | --- |");
+        assert!(lines[2].starts_with("| Review a\\|b<br>then c | AC-2 | 88.0 |"), "{}", lines[2]);
+    }
+}
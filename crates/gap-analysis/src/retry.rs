@@ -0,0 +1,125 @@
+// Modified: 2025-09-20
+
+//! Retry Policy
+//!
+//! Retries baseline store reads that fail with a transient error, such as a
+//! timeout or an unavailable network-backed store, with exponential backoff.
+//! Not-found and parse errors fail immediately since retrying cannot fix them.
+
+use fedramp_core::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Retry policy for transient baseline load failures
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first; `1` disables retries
+    pub max_attempts: u32,
+    /// Delay before the first retry, in milliseconds
+    pub initial_backoff_ms: u64,
+    /// Upper bound on the delay between attempts, in milliseconds
+    pub max_backoff_ms: u64,
+    /// Randomize each delay between half and all of its backoff
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff_ms: 100,
+            max_backoff_ms: 2000,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that makes a single attempt
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Check that at least one attempt is made and the backoff bounds are ordered
+    pub fn validate(&self) -> Result<()> {
+        if self.max_attempts == 0 {
+            return Err(Error::configuration("Retry max_attempts must be at least 1"));
+        }
+        if self.initial_backoff_ms > self.max_backoff_ms {
+            return Err(Error::configuration(format!(
+                "Retry initial_backoff_ms ({}) must not exceed max_backoff_ms ({})",
+                self.initial_backoff_ms, self.max_backoff_ms
+            )));
+        }
+        Ok(())
+    }
+
+    /// Delay before retrying after the given failed attempt, counting from 1
+    ///
+    /// The backoff doubles with each attempt up to `max_backoff_ms`. With
+    /// jitter, the delay is drawn from the upper half of the backoff so
+    /// concurrent callers spread out without retrying immediately.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff_ms = self.initial_backoff_ms
+            .saturating_mul(1u64 << exponent)
+            .min(self.max_backoff_ms);
+
+        let delay_ms = if self.jitter && backoff_ms > 1 {
+            let half = backoff_ms / 2;
+            let random = (uuid::Uuid::new_v4().as_u128() as u64) % (backoff_ms - half + 1);
+            half + random
+        } else {
+            backoff_ms
+        };
+        Duration::from_millis(delay_ms)
+    }
+
+    /// Whether to retry after a failed attempt, counting from 1
+    pub fn should_retry(&self, attempt: u32, error: &Error) -> bool {
+        attempt < self.max_attempts && error.is_transient()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_maximum() {
+        let policy = RetryPolicy {
+            max_attempts: 6,
+            initial_backoff_ms: 100,
+            max_backoff_ms: 500,
+            jitter: false,
+        };
+
+        let delays: Vec<u64> = (1..=5).map(|attempt| policy.backoff(attempt).as_millis() as u64).collect();
+        assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+    }
+
+    #[test]
+    fn test_jittered_backoff_stays_in_upper_half() {
+        let policy = RetryPolicy::default();
+
+        for _ in 0..100 {
+            let delay = policy.backoff(2).as_millis();
+            assert!((100..=200).contains(&delay), "delay {} out of range", delay);
+        }
+    }
+
+    #[test]
+    fn test_only_transient_errors_are_retried_within_budget() {
+        let policy = RetryPolicy::default();
+        let unavailable = Error::external_service("baseline-store", "connection reset");
+
+        assert!(policy.should_retry(1, &unavailable));
+        assert!(policy.should_retry(2, &unavailable));
+        assert!(!policy.should_retry(3, &unavailable));
+        assert!(!policy.should_retry(1, &Error::not_found("nist-800-53:high")));
+        assert!(RetryPolicy { max_attempts: 0, ..RetryPolicy::default() }.validate().is_err());
+    }
+}